@@ -19,7 +19,7 @@
 mod common;
 
 use common::{MockOAuth2Server, sha256_hash};
-use turbomcp_dpop::{DpopKeyPair, DpopProof, DpopValidator};
+use turbomcp_dpop::{DpopError, DpopKeyPair, DpopProof, DpopValidator};
 
 #[tokio::test]
 async fn test_dpop_proof_generation_and_validation_roundtrip() {
@@ -110,13 +110,16 @@ async fn test_dpop_replay_attack_prevention() {
     assert!(first_validation.is_ok(), "First validation should succeed");
 
     // WHEN: We replay the same proof (reuse jti)
-    // Note: In production, validator would track jti values
-    // For this test, we verify the proof structure supports replay detection
     let jti = &first_validation.unwrap().jti;
     assert!(
         !jti.is_empty(),
         "Proof should have unique jti for replay detection"
     );
+    let replay = validator.validate(&proof, Some(access_token)).await;
+    assert!(
+        matches!(replay, Err(DpopError::ReplayAttackDetected { .. })),
+        "Replayed proof must be rejected"
+    );
 
     // Each proof should have a unique jti
     let proof2 = DpopProof::builder()
@@ -238,6 +238,12 @@ pub enum ServerCommands {
         conn: Connection,
     },
 
+    /// Show the capabilities, protocol version, and instructions negotiated during initialize
+    Capabilities {
+        #[command(flatten)]
+        conn: Connection,
+    },
+
     /// Ping server
     Ping {
         #[command(flatten)]
@@ -9,7 +9,7 @@ use thiserror::Error;
 pub enum CliError {
     /// Transport layer errors
     #[error("Transport error: {0}")]
-    Transport(#[from] turbomcp_protocol::Error),
+    Transport(turbomcp_protocol::Error),
 
     /// Invalid command arguments
     #[error("Invalid arguments: {0}")]
@@ -17,7 +17,11 @@ pub enum CliError {
 
     /// Server returned an error
     #[error("Server error [{code}]: {message}")]
-    ServerError { code: i32, message: String },
+    ServerError {
+        code: i32,
+        message: String,
+        data: Option<serde_json::Value>,
+    },
 
     /// Operation timed out
     #[error("Operation '{operation}' timed out after {elapsed:?}")]
@@ -85,7 +89,23 @@ impl CliError {
                 "Check argument format (must be valid JSON)",
                 "Use --help to see expected format",
             ],
-            _ => vec![],
+            _ => self.rpc_code().map(rpc_suggestions).unwrap_or_default(),
+        }
+    }
+
+    /// Get the JSON-RPC error code, for errors returned by the server
+    pub fn rpc_code(&self) -> Option<i32> {
+        match self {
+            Self::ServerError { code, .. } => Some(*code),
+            _ => None,
+        }
+    }
+
+    /// Get the JSON-RPC error `data` sent by the server, if any
+    pub fn rpc_data(&self) -> Option<&serde_json::Value> {
+        match self {
+            Self::ServerError { data, .. } => data.as_ref(),
+            _ => None,
         }
     }
 
@@ -106,6 +126,32 @@ impl CliError {
     }
 }
 
+/// Suggested fixes for common MCP error codes
+fn rpc_suggestions(code: i32) -> Vec<&'static str> {
+    use turbomcp_protocol::error_codes::*;
+
+    match code {
+        TOOL_NOT_FOUND => vec![
+            "Run `turbomcp-cli tools list` to see available tools",
+            "Tool names are case-sensitive",
+        ],
+        PROMPT_NOT_FOUND => vec!["Run `turbomcp-cli prompts list` to see available prompts"],
+        RESOURCE_NOT_FOUND => vec![
+            "Run `turbomcp-cli resources list` to see available resources",
+            "Run `turbomcp-cli resources templates` to see URI templates",
+        ],
+        INVALID_PARAMS => {
+            vec!["Run `turbomcp-cli tools schema <name>` to see the expected arguments"]
+        }
+        METHOD_NOT_FOUND | CAPABILITY_NOT_SUPPORTED => {
+            vec!["Run `turbomcp-cli server info` to check what the server supports"]
+        }
+        AUTHENTICATION_REQUIRED => vec!["Check the server's authentication requirements"],
+        RATE_LIMITED | SERVER_OVERLOADED => vec!["Wait a moment and retry the request"],
+        _ => vec![],
+    }
+}
+
 /// Error categories for colored output
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ErrorCategory {
@@ -151,9 +197,25 @@ impl From<&str> for CliError {
     }
 }
 
+/// Errors decoded from a server's JSON-RPC error response become
+/// [`CliError::ServerError`], keeping its code and `data`; anything else
+/// failed locally or on the way and stays a transport error.
+impl From<turbomcp_protocol::Error> for CliError {
+    fn from(err: turbomcp_protocol::Error) -> Self {
+        match err.context.as_ref().and_then(|ctx| ctx.rpc_code) {
+            Some(code) => Self::ServerError {
+                code,
+                data: err.data().cloned(),
+                message: err.message,
+            },
+            None => Self::Transport(err),
+        }
+    }
+}
+
 impl From<Box<turbomcp_protocol::Error>> for CliError {
     fn from(err: Box<turbomcp_protocol::Error>) -> Self {
-        Self::Transport(*err)
+        Self::from(*err)
     }
 }
 
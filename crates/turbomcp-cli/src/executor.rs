@@ -2,7 +2,7 @@
 
 use crate::cli::*;
 use crate::error::{CliError, CliResult};
-use crate::formatter::Formatter;
+use crate::formatter::{Formatter, InventoryList, NegotiatedCapabilities, ServerInventory};
 use crate::path_security;
 use crate::transport::create_client;
 use std::collections::HashMap;
@@ -303,9 +303,13 @@ impl CommandExecutor {
     async fn execute_server_command(&self, command: ServerCommands) -> CliResult<()> {
         match command {
             ServerCommands::Info { conn } => {
-                let client = create_client(&conn).await?;
-                let result = client.initialize().await?;
-                self.formatter.display_server_info(&result.server_info)
+                let inventory = fetch_server_inventory(&conn).await?;
+                self.formatter.display_server_inventory(&inventory)
+            }
+
+            ServerCommands::Capabilities { conn } => {
+                let negotiated = fetch_negotiated_capabilities(&conn).await?;
+                self.formatter.display_negotiated_capabilities(&negotiated)
             }
 
             ServerCommands::Ping { conn } => {
@@ -395,3 +399,195 @@ impl CommandExecutor {
         crate::new::execute(&args)
     }
 }
+
+/// Connect once, initialize, and list the server's tools, resources, and prompts.
+///
+/// Lists are only requested for capabilities the server advertised, so servers
+/// without e.g. prompt support report an empty prompt list instead of failing.
+pub async fn fetch_server_inventory(conn: &Connection) -> CliResult<ServerInventory> {
+    let client = create_client(conn).await?;
+    let result = client.initialize().await?;
+    let capabilities = &result.server_capabilities;
+
+    let tools = if capabilities.tools.is_some() {
+        client
+            .list_tools()
+            .await?
+            .into_iter()
+            .map(|t| t.name)
+            .collect()
+    } else {
+        Vec::new()
+    };
+    let resources = if capabilities.resources.is_some() {
+        client
+            .list_resources()
+            .await?
+            .into_iter()
+            .map(|r| r.uri)
+            .collect()
+    } else {
+        Vec::new()
+    };
+    let prompts = if capabilities.prompts.is_some() {
+        client
+            .list_prompts()
+            .await?
+            .into_iter()
+            .map(|p| p.name)
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    Ok(ServerInventory {
+        server: result.server_info,
+        tools: InventoryList::new(tools),
+        resources: InventoryList::new(resources),
+        prompts: InventoryList::new(prompts),
+    })
+}
+
+/// Connect, complete `initialize`, and capture what the server advertised.
+pub async fn fetch_negotiated_capabilities(conn: &Connection) -> CliResult<NegotiatedCapabilities> {
+    let client = create_client(conn).await?;
+    let result = client.initialize().await?;
+    let protocol_version = client
+        .protocol_version()
+        .ok_or_else(|| CliError::Other("initialize did not record a protocol version".into()))?;
+
+    Ok(NegotiatedCapabilities {
+        server: result.server_info,
+        protocol_version,
+        capabilities: result.server_capabilities,
+        instructions: client.server_instructions(),
+    })
+}
+
+#[cfg(all(test, feature = "tcp"))]
+mod tests {
+    use super::*;
+    use serde_json::{Value, json};
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::TcpListener;
+
+    /// Minimal line-delimited JSON-RPC server with a fixed inventory.
+    async fn spawn_mock_server() -> std::net::SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let (read, mut write) = socket.into_split();
+            let mut lines = BufReader::new(read).lines();
+
+            while let Ok(Some(line)) = lines.next_line().await {
+                let request: Value = serde_json::from_str(&line).unwrap();
+                let Some(id) = request.get("id").cloned() else {
+                    continue; // notification
+                };
+                let result = match request["method"].as_str().unwrap_or_default() {
+                    "initialize" => json!({
+                        "protocolVersion": request["params"]["protocolVersion"],
+                        "capabilities": {
+                            "tools": { "listChanged": true },
+                            "resources": {},
+                            "prompts": {}
+                        },
+                        "serverInfo": { "name": "mock-server", "version": "0.9.0" },
+                        "instructions": "Call add before subtract."
+                    }),
+                    "tools/list" => json!({ "tools": [
+                        { "name": "add", "inputSchema": { "type": "object" } },
+                        { "name": "subtract", "inputSchema": { "type": "object" } }
+                    ]}),
+                    "resources/list" => json!({ "resources": [
+                        { "uri": "file:///config.json", "name": "config" }
+                    ]}),
+                    "prompts/list" => json!({ "prompts": [] }),
+                    _ => json!({}),
+                };
+                let response = json!({ "jsonrpc": "2.0", "id": id, "result": result });
+                let mut bytes = serde_json::to_vec(&response).unwrap();
+                bytes.push(b'\n');
+                write.write_all(&bytes).await.unwrap();
+            }
+        });
+
+        addr
+    }
+
+    #[tokio::test]
+    async fn test_server_info_lists_inventory() {
+        let addr = spawn_mock_server().await;
+        let conn = Connection {
+            transport: Some(TransportKind::Tcp),
+            url: format!("tcp://{addr}"),
+            command: None,
+            auth: None,
+            timeout: 5,
+        };
+
+        let inventory = fetch_server_inventory(&conn).await.unwrap();
+        assert_eq!(inventory.server.name, "mock-server");
+        assert_eq!(inventory.tools.count, 2);
+        assert_eq!(inventory.resources.names, vec!["file:///config.json"]);
+        assert_eq!(inventory.prompts.count, 0);
+
+        let table = Formatter::new(OutputFormat::Table, false)
+            .render_server_inventory(&inventory)
+            .unwrap();
+        assert!(table.contains("mock-server"));
+        assert!(table.contains("add, subtract"));
+        assert!(table.contains("file:///config.json"));
+
+        let rendered = Formatter::new(OutputFormat::Json, false)
+            .render_server_inventory(&inventory)
+            .unwrap();
+        let json: Value = serde_json::from_str(&rendered).unwrap();
+        assert_eq!(json["server"]["name"], "mock-server");
+        assert_eq!(json["tools"]["count"], 2);
+        assert_eq!(json["tools"]["names"], json!(["add", "subtract"]));
+        assert_eq!(json["resources"]["count"], 1);
+        assert_eq!(json["prompts"]["names"], json!([]));
+    }
+
+    #[tokio::test]
+    async fn test_server_capabilities_prints_negotiated_state() {
+        let addr = spawn_mock_server().await;
+        let conn = Connection {
+            transport: Some(TransportKind::Tcp),
+            url: format!("tcp://{addr}"),
+            command: None,
+            auth: None,
+            timeout: 5,
+        };
+
+        let negotiated = fetch_negotiated_capabilities(&conn).await.unwrap();
+        assert_eq!(negotiated.server.name, "mock-server");
+        assert!(negotiated.capabilities.tools.is_some());
+        assert!(negotiated.capabilities.logging.is_none());
+
+        let table = Formatter::new(OutputFormat::Table, false)
+            .render_negotiated_capabilities(&negotiated)
+            .unwrap();
+        assert!(table.contains(negotiated.protocol_version.as_str()));
+        assert!(table.contains("tools"));
+        assert!(table.contains(r#"{"listChanged":true}"#));
+        assert!(table.contains("Call add before subtract."));
+        assert!(!table.contains("logging"));
+
+        let rendered = Formatter::new(OutputFormat::Json, false)
+            .render_negotiated_capabilities(&negotiated)
+            .unwrap();
+        let json: Value = serde_json::from_str(&rendered).unwrap();
+        assert_eq!(json["server"]["name"], "mock-server");
+        assert_eq!(
+            json["protocolVersion"],
+            negotiated.protocol_version.as_str()
+        );
+        assert_eq!(json["capabilities"]["tools"]["listChanged"], true);
+        assert_eq!(json["capabilities"]["resources"], json!({}));
+        assert_eq!(json["instructions"], "Call add before subtract.");
+    }
+}
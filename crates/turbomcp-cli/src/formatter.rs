@@ -7,6 +7,75 @@ use owo_colors::OwoColorize;
 use serde::Serialize;
 use turbomcp_protocol::types::*;
 
+/// Names of one kind of server component, with their count
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct InventoryList {
+    pub count: usize,
+    pub names: Vec<String>,
+}
+
+impl InventoryList {
+    #[must_use]
+    pub fn new(names: Vec<String>) -> Self {
+        Self {
+            count: names.len(),
+            names,
+        }
+    }
+}
+
+/// Server identity plus the tools, resources, and prompts it exposes
+#[derive(Debug, Clone, Serialize)]
+pub struct ServerInventory {
+    pub server: Implementation,
+    pub tools: InventoryList,
+    pub resources: InventoryList,
+    pub prompts: InventoryList,
+}
+
+/// What the server advertised during `initialize`
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NegotiatedCapabilities {
+    pub server: Implementation,
+    pub protocol_version: ProtocolVersion,
+    pub capabilities: ServerCapabilities,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+}
+
+/// Structured form of a [`CliError`] for machine-readable output
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorReport {
+    /// The error's display message
+    pub message: String,
+    /// The error's [`ErrorCategory`](crate::ErrorCategory), as displayed
+    pub category: String,
+    /// JSON-RPC error code, for errors returned by the server
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<i32>,
+    /// JSON-RPC error `data` sent by the server, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Value>,
+    /// Suggested fixes, empty when there are none
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub suggestions: Vec<&'static str>,
+}
+
+impl ErrorReport {
+    /// Build the report for `error`
+    #[must_use]
+    pub fn new(error: &CliError) -> Self {
+        Self {
+            message: error.to_string(),
+            category: error.category().to_string(),
+            code: error.rpc_code(),
+            data: error.rpc_data().cloned(),
+            suggestions: error.suggestions(),
+        }
+    }
+}
+
 /// Format and display output based on format preference
 pub struct Formatter {
     format: OutputFormat,
@@ -177,29 +246,183 @@ impl Formatter {
         }
     }
 
+    /// Display server info together with its tool/resource/prompt inventory
+    pub fn display_server_inventory(&self, inventory: &ServerInventory) -> CliResult<()> {
+        match self.format {
+            OutputFormat::Human => {
+                self.print_header("Server Information");
+                self.print_kv("Name", &inventory.server.name);
+                self.print_kv("Version", &inventory.server.version);
+                for (label, list) in inventory.sections() {
+                    self.print_kv(label, &list.count.to_string());
+                    for name in &list.names {
+                        println!("    • {name}");
+                    }
+                }
+                Ok(())
+            }
+            _ => {
+                println!("{}", self.render_server_inventory(inventory)?);
+                Ok(())
+            }
+        }
+    }
+
+    /// Render a server inventory in the selected machine-readable or table format
+    pub fn render_server_inventory(&self, inventory: &ServerInventory) -> CliResult<String> {
+        match self.format {
+            OutputFormat::Table | OutputFormat::Human => {
+                let mut table = Table::new();
+                table
+                    .load_preset(UTF8_FULL)
+                    .apply_modifier(UTF8_ROUND_CORNERS)
+                    .set_header(vec!["Kind", "Count", "Names"]);
+
+                for (label, list) in inventory.sections() {
+                    let names = if list.names.is_empty() {
+                        "-".to_string()
+                    } else {
+                        list.names.join(", ")
+                    };
+                    table.add_row(vec![label, &list.count.to_string(), &names]);
+                }
+
+                Ok(format!(
+                    "{} {}\n{table}",
+                    inventory.server.name, inventory.server.version
+                ))
+            }
+            OutputFormat::Json => Ok(serde_json::to_string_pretty(inventory)?),
+            OutputFormat::Compact => Ok(serde_json::to_string(inventory)?),
+            OutputFormat::Yaml => Ok(serde_norway::to_string(inventory)?),
+        }
+    }
+
+    /// Display the capabilities, protocol version, and instructions from `initialize`
+    pub fn display_negotiated_capabilities(
+        &self,
+        negotiated: &NegotiatedCapabilities,
+    ) -> CliResult<()> {
+        match self.format {
+            OutputFormat::Human => {
+                self.print_header("Negotiated Capabilities");
+                self.print_kv("Server", &negotiated.server.name);
+                self.print_kv("Version", &negotiated.server.version);
+                self.print_kv("Protocol", negotiated.protocol_version.as_str());
+                self.print_kv("Capabilities", "");
+                for (name, settings) in negotiated.entries() {
+                    println!("    • {name} {settings}");
+                }
+                if let Some(instructions) = &negotiated.instructions {
+                    self.print_kv("Instructions", instructions);
+                }
+                Ok(())
+            }
+            _ => {
+                println!("{}", self.render_negotiated_capabilities(negotiated)?);
+                Ok(())
+            }
+        }
+    }
+
+    /// Render negotiated capabilities in the selected machine-readable or table format
+    pub fn render_negotiated_capabilities(
+        &self,
+        negotiated: &NegotiatedCapabilities,
+    ) -> CliResult<String> {
+        match self.format {
+            OutputFormat::Table | OutputFormat::Human => {
+                let mut table = Table::new();
+                table
+                    .load_preset(UTF8_FULL)
+                    .apply_modifier(UTF8_ROUND_CORNERS)
+                    .set_header(vec!["Capability", "Settings"]);
+
+                for (name, settings) in negotiated.entries() {
+                    table.add_row(vec![name, settings]);
+                }
+
+                let mut out = format!(
+                    "{} {} (protocol {})\n{table}",
+                    negotiated.server.name, negotiated.server.version, negotiated.protocol_version
+                );
+                if let Some(instructions) = &negotiated.instructions {
+                    out.push_str(&format!("\nInstructions: {instructions}"));
+                }
+                Ok(out)
+            }
+            OutputFormat::Json => Ok(serde_json::to_string_pretty(negotiated)?),
+            OutputFormat::Compact => Ok(serde_json::to_string(negotiated)?),
+            OutputFormat::Yaml => Ok(serde_norway::to_string(negotiated)?),
+        }
+    }
+
     /// Display error with suggestions
+    ///
+    /// Machine-readable formats emit a structured error object instead.
     pub fn display_error(&self, error: &CliError) {
-        if self.colored {
-            eprintln!("{}: {}", "Error".bright_red().bold(), error);
+        eprintln!("{}", self.render_error(error));
+    }
 
-            let suggestions = error.suggestions();
-            if !suggestions.is_empty() {
-                eprintln!("\n{}", "Suggestions:".bright_yellow().bold());
-                for suggestion in suggestions {
-                    eprintln!("  {} {}", "•".bright_blue(), suggestion);
-                }
+    /// Render an error in the selected format
+    ///
+    /// Human and table output show the message, the JSON-RPC code and
+    /// `data` when present, and suggested fixes. JSON, compact, and YAML
+    /// output render [`ErrorReport`] under an `error` key.
+    pub fn render_error(&self, error: &CliError) -> String {
+        let report = ErrorReport::new(error);
+        let structured = serde_json::json!({ "error": report });
+        match self.format {
+            OutputFormat::Json => {
+                serde_json::to_string_pretty(&structured).unwrap_or_else(|_| report.message.clone())
+            }
+            OutputFormat::Compact => {
+                serde_json::to_string(&structured).unwrap_or_else(|_| report.message.clone())
             }
+            OutputFormat::Yaml => {
+                serde_norway::to_string(&structured).unwrap_or_else(|_| report.message.clone())
+            }
+            OutputFormat::Human | OutputFormat::Table => self.render_error_human(&report),
+        }
+    }
+
+    fn render_error_human(&self, report: &ErrorReport) -> String {
+        let mut out = if self.colored {
+            format!("{}: {}", "Error".bright_red().bold(), report.message)
         } else {
-            eprintln!("Error: {error}");
+            format!("Error: {}", report.message)
+        };
+
+        if let Some(code) = report.code {
+            let kind = turbomcp_protocol::ErrorKind::from_i32(code);
+            // Unknown codes map to `Internal`; don't mislabel them.
+            if kind == turbomcp_protocol::ErrorKind::Internal
+                && code != turbomcp_protocol::error_codes::INTERNAL_ERROR
+            {
+                out.push_str(&format!("\n  Code: {code}"));
+            } else {
+                out.push_str(&format!("\n  Code: {code} ({})", kind.description()));
+            }
+        }
+        if let Some(data) = &report.data {
+            out.push_str(&format!("\n  Data: {data}"));
+        }
 
-            let suggestions = error.suggestions();
-            if !suggestions.is_empty() {
-                eprintln!("\nSuggestions:");
-                for suggestion in suggestions {
-                    eprintln!("  • {suggestion}");
+        if !report.suggestions.is_empty() {
+            if self.colored {
+                out.push_str(&format!("\n\n{}", "Suggestions:".bright_yellow().bold()));
+            } else {
+                out.push_str("\n\nSuggestions:");
+            }
+            for suggestion in &report.suggestions {
+                if self.colored {
+                    out.push_str(&format!("\n  {} {suggestion}", "•".bright_blue()));
+                } else {
+                    out.push_str(&format!("\n  • {suggestion}"));
                 }
             }
         }
+        out
     }
 
     // Internal formatting helpers
@@ -328,6 +551,35 @@ impl Formatter {
     }
 }
 
+impl ServerInventory {
+    fn sections(&self) -> [(&'static str, &InventoryList); 3] {
+        [
+            ("Tools", &self.tools),
+            ("Resources", &self.resources),
+            ("Prompts", &self.prompts),
+        ]
+    }
+}
+
+impl NegotiatedCapabilities {
+    /// Advertised capabilities as `(name, settings)` pairs, `-` when a
+    /// capability carries no sub-settings.
+    fn entries(&self) -> Vec<(String, String)> {
+        let Ok(serde_json::Value::Object(map)) = serde_json::to_value(&self.capabilities) else {
+            return Vec::new();
+        };
+        map.into_iter()
+            .map(|(name, settings)| {
+                let settings = match &settings {
+                    serde_json::Value::Object(o) if o.is_empty() => "-".to_string(),
+                    other => other.to_string(),
+                };
+                (name, settings)
+            })
+            .collect()
+    }
+}
+
 /// Format schema summary for table display
 fn format_schema_summary(schema: &ToolInputSchema) -> String {
     if let Some(props) = schema.properties_as_object()
@@ -348,4 +600,59 @@ mod tests {
         let formatter = Formatter::new(OutputFormat::Human, true);
         assert!(formatter.colored);
     }
+
+    #[test]
+    fn test_tool_not_found_error_human_shows_suggestion() {
+        let error = CliError::from(turbomcp_protocol::Error::from_rpc_error(
+            turbomcp_protocol::error_codes::TOOL_NOT_FOUND,
+            "Tool not found: frobnicate",
+            None,
+        ));
+        let rendered = Formatter::new(OutputFormat::Human, false).render_error(&error);
+
+        assert!(rendered.starts_with("Error: "), "{rendered}");
+        assert!(
+            rendered.contains("Code: -32001 (Tool not found)"),
+            "{rendered}"
+        );
+        assert!(
+            rendered.contains("• Run `turbomcp-cli tools list` to see available tools"),
+            "{rendered}"
+        );
+    }
+
+    #[test]
+    fn test_local_error_has_no_rpc_code() {
+        let error = CliError::from(turbomcp_protocol::Error::transport("connection refused"));
+        assert!(matches!(error, CliError::Transport(_)));
+        assert_eq!(error.rpc_code(), None);
+
+        let rendered = Formatter::new(OutputFormat::Json, false).render_error(&error);
+        let value: serde_json::Value = serde_json::from_str(&rendered).unwrap();
+        assert!(value["error"].get("code").is_none());
+        assert_eq!(value["error"]["category"], "Connection");
+    }
+
+    #[test]
+    fn test_tool_not_found_error_json_is_structured() {
+        let error = CliError::ServerError {
+            code: turbomcp_protocol::error_codes::TOOL_NOT_FOUND,
+            message: "Tool not found: frobnicate".to_string(),
+            data: Some(serde_json::json!({ "available": ["search"] })),
+        };
+        let rendered = Formatter::new(OutputFormat::Json, false).render_error(&error);
+        let value: serde_json::Value = serde_json::from_str(&rendered).unwrap();
+
+        assert_eq!(value["error"]["code"], -32001);
+        assert_eq!(
+            value["error"]["message"],
+            "Server error [-32001]: Tool not found: frobnicate"
+        );
+        assert_eq!(value["error"]["category"], "Server");
+        assert_eq!(value["error"]["data"]["available"][0], "search");
+        assert_eq!(
+            value["error"]["suggestions"][0],
+            "Run `turbomcp-cli tools list` to see available tools"
+        );
+    }
 }
@@ -66,7 +66,7 @@ pub use cli::{
 };
 pub use error::{CliError, CliResult, ErrorCategory};
 pub use executor::CommandExecutor;
-pub use formatter::Formatter;
+pub use formatter::{ErrorReport, Formatter};
 
 /// Run the CLI application
 ///
@@ -90,7 +90,14 @@ pub use formatter::Formatter;
 /// }
 /// ```
 pub async fn run() -> CliResult<()> {
-    let cli = Cli::parse();
+    run_with(Cli::parse()).await
+}
+
+/// Run the CLI application with already-parsed arguments
+///
+/// Use this to render errors in the user's selected `--format` with
+/// [`Formatter::display_error`], which needs `cli.format` after parsing.
+pub async fn run_with(cli: Cli) -> CliResult<()> {
     if cli.connection.is_some() {
         eprintln!(
             "Warning: --connection / -c is reserved for future config-file support and currently has no effect."
@@ -1,8 +1,12 @@
+use turbomcp_cli::prelude::*;
+
 #[tokio::main]
 async fn main() {
-    if let Err(e) = turbomcp_cli::run().await {
+    let cli = Cli::parse();
+    let formatter = Formatter::new(cli.format.clone(), !cli.no_color);
+    if let Err(e) = turbomcp_cli::run_with(cli).await {
         // Display error with proper formatting
-        eprintln!("Error: {}", e);
+        formatter.display_error(&e);
         std::process::exit(1);
     }
 }
@@ -5,7 +5,7 @@ use crate::error::{CliError, CliResult};
 use std::collections::HashMap;
 use std::time::Duration;
 use turbomcp_client::Client;
-use turbomcp_protocol::types::Tool;
+use turbomcp_protocol::types::{ProtocolVersion, Tool};
 
 #[cfg(feature = "stdio")]
 use turbomcp_transport::child_process::{ChildProcessConfig, ChildProcessTransport};
@@ -58,6 +58,36 @@ impl UnifiedClient {
         }
     }
 
+    pub fn server_instructions(&self) -> Option<String> {
+        match &self.inner {
+            #[cfg(feature = "stdio")]
+            ClientInner::Stdio(client) => client.server_instructions(),
+            #[cfg(feature = "tcp")]
+            ClientInner::Tcp(client) => client.server_instructions(),
+            #[cfg(all(feature = "unix", unix))]
+            ClientInner::Unix(client) => client.server_instructions(),
+            #[cfg(feature = "http")]
+            ClientInner::Http(client) => client.server_instructions(),
+            #[cfg(feature = "websocket")]
+            ClientInner::WebSocket(client) => client.server_instructions(),
+        }
+    }
+
+    pub fn protocol_version(&self) -> Option<ProtocolVersion> {
+        match &self.inner {
+            #[cfg(feature = "stdio")]
+            ClientInner::Stdio(client) => client.protocol_version(),
+            #[cfg(feature = "tcp")]
+            ClientInner::Tcp(client) => client.protocol_version(),
+            #[cfg(all(feature = "unix", unix))]
+            ClientInner::Unix(client) => client.protocol_version(),
+            #[cfg(feature = "http")]
+            ClientInner::Http(client) => client.protocol_version(),
+            #[cfg(feature = "websocket")]
+            ClientInner::WebSocket(client) => client.protocol_version(),
+        }
+    }
+
     pub async fn list_tools(&self) -> CliResult<Vec<Tool>> {
         match &self.inner {
             #[cfg(feature = "stdio")]
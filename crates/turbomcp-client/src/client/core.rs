@@ -19,6 +19,7 @@
 //! - **`Arc<ClientInner<T>>`** for cheap cloning
 
 use parking_lot::Mutex;
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 
@@ -35,10 +36,11 @@ use turbomcp_protocol::{Error, PROTOCOL_VERSION, Result};
 use turbomcp_transport::{Transport, TransportConfig, TransportMessage};
 
 use super::config::InitializeResult;
+use super::operations::tools::OutputSchema;
 use super::protocol::ProtocolClient;
 use crate::{
     ClientCapabilities,
-    handlers::{HandlerError, HandlerRegistry},
+    handlers::{HandlerError, HandlerRegistry, StaticRootsHandler},
     sampling::SamplingHandler,
 };
 
@@ -59,6 +61,16 @@ pub(super) struct ClientInner<T: Transport + 'static> {
     /// Tracks whether graceful shutdown has already been requested.
     pub(super) shutdown_requested: AtomicBool,
 
+    /// `instructions` from the latest initialize result, if the server sent
+    /// any (mutex so a repeated initialize replaces them)
+    pub(super) server_instructions: Mutex<Option<String>>,
+
+    /// Protocol version agreed in the latest initialize result
+    pub(super) protocol_version: Mutex<Option<ProtocolVersion>>,
+
+    /// Whether the latest initialize request advertised `roots.listChanged`
+    pub(super) roots_list_changed: AtomicBool,
+
     /// Optional sampling handler (mutex for dynamic updates)
     pub(super) sampling_handler: Arc<Mutex<Option<Arc<dyn SamplingHandler>>>>,
 
@@ -68,6 +80,12 @@ pub(super) struct ClientInner<T: Transport + 'static> {
     /// ✅ Semaphore for bounded concurrency of request/notification handlers
     /// Limits concurrent server-initiated request handlers to prevent resource exhaustion
     pub(super) handler_semaphore: Arc<Semaphore>,
+
+    /// Whether `tools/call` results are checked against the tool's output schema
+    pub(super) validate_tool_output: AtomicBool,
+
+    /// `outputSchema` of each tool seen in `tools/list`, keyed by tool name
+    pub(super) tool_output_schemas: Mutex<HashMap<String, Arc<OutputSchema>>>,
 }
 
 /// The core MCP client implementation
@@ -199,9 +217,14 @@ impl<T: Transport + 'static> Client<T> {
                 capabilities: capabilities.clone(),
                 initialized: AtomicBool::new(false),
                 shutdown_requested: AtomicBool::new(false),
+                server_instructions: Mutex::new(None),
+                protocol_version: Mutex::new(None),
+                roots_list_changed: AtomicBool::new(false),
                 sampling_handler: Arc::new(Mutex::new(None)),
                 handlers: Arc::new(Mutex::new(HandlerRegistry::new())),
                 handler_semaphore: Arc::new(Semaphore::new(capabilities.max_concurrent_handlers)), // ✅ Configurable concurrent handlers
+                validate_tool_output: AtomicBool::new(false),
+                tool_output_schemas: Mutex::new(HashMap::new()),
             }),
         };
 
@@ -251,9 +274,14 @@ impl<T: Transport + 'static> Client<T> {
                 capabilities: capabilities.clone(),
                 initialized: AtomicBool::new(false),
                 shutdown_requested: AtomicBool::new(false),
+                server_instructions: Mutex::new(None),
+                protocol_version: Mutex::new(None),
+                roots_list_changed: AtomicBool::new(false),
                 sampling_handler: Arc::new(Mutex::new(None)),
                 handlers: Arc::new(Mutex::new(HandlerRegistry::new())),
                 handler_semaphore: Arc::new(Semaphore::new(capabilities.max_concurrent_handlers)), // ✅ Configurable concurrent handlers
+                validate_tool_output: AtomicBool::new(false),
+                tool_output_schemas: Mutex::new(HashMap::new()),
             }),
         };
 
@@ -750,12 +778,10 @@ impl<T: Transport + 'static> Client<T> {
                 // Clone the handler Arc to avoid holding mutex across await
                 let handler_opt = self.inner.handlers.lock().roots.clone();
 
-                let roots_result = if let Some(handler) = handler_opt {
-                    handler.handle_roots_request().await
-                } else {
-                    // No handler - return empty list per MCP spec
-                    Ok(Vec::new())
-                };
+                // No handler - answer with an empty list per MCP spec
+                let handler =
+                    handler_opt.unwrap_or_else(|| Arc::new(StaticRootsHandler::default()));
+                let roots_result = handler.handle_roots_request().await;
 
                 match roots_result {
                     Ok(roots) => {
@@ -852,6 +878,9 @@ impl<T: Transport + 'static> Client<T> {
     /// - `notifications/message` - Log messages from server
     /// - `notifications/resources/updated` - Resource content changed
     /// - `notifications/resources/list_changed` - Resource list changed
+    ///
+    /// Any other method is passed to the custom notification handler, if one
+    /// is registered.
     async fn handle_notification(&self, notification: JsonRpcNotification) -> Result<()> {
         match notification.method.as_str() {
             "notifications/progress" => {
@@ -992,8 +1021,19 @@ impl<T: Transport + 'static> Client<T> {
             }
 
             _ => {
-                // Unknown notification type
-                tracing::debug!("Received unknown notification: {}", notification.method);
+                // Application-defined notification, e.g. a server broadcast
+                let handler_opt = self.inner.handlers.lock().get_custom_notification_handler();
+
+                if let Some(handler) = handler_opt {
+                    if let Err(e) = handler
+                        .handle_custom_notification(notification.method, notification.params)
+                        .await
+                    {
+                        tracing::error!("Custom notification handler error: {}", e);
+                    }
+                } else {
+                    tracing::debug!("Received unknown notification: {}", notification.method);
+                }
             }
         }
 
@@ -1076,7 +1116,72 @@ impl<T: Transport + 'static> Client<T> {
     /// # }
     /// ```
     pub async fn initialize(&self) -> Result<InitializeResult> {
-        // Build client capabilities based on registered handlers (automatic detection)
+        self.initialize_with(Self::default_client_info(), self.default_capabilities())
+            .await
+    }
+
+    /// Initialize the connection, advertising a custom client identity
+    ///
+    /// Like [`Client::initialize`], but sends `client_info` and
+    /// `capabilities` in the handshake instead of the defaults. Use
+    /// [`Client::default_capabilities`] as a starting point to keep the
+    /// capabilities detected from registered handlers.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transport connection fails or the server
+    /// rejects the initialization request.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use turbomcp_client::Client;
+    /// # use turbomcp_protocol::types::Implementation;
+    /// # use turbomcp_transport::stdio::StdioTransport;
+    /// # async fn example() -> turbomcp_protocol::Result<()> {
+    /// let client = Client::new(StdioTransport::new());
+    ///
+    /// let info = Implementation {
+    ///     name: "my-app".to_string(),
+    ///     version: "2.1.0".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let capabilities = client.default_capabilities();
+    /// client.initialize_with(info, capabilities).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn initialize_with(
+        &self,
+        client_info: Implementation,
+        capabilities: ProtocolClientCapabilities,
+    ) -> Result<InitializeResult> {
+        let request = InitializeRequest {
+            protocol_version: PROTOCOL_VERSION.into(),
+            capabilities,
+            client_info,
+            meta: None,
+        };
+
+        self.initialize_with_request(request).await
+    }
+
+    /// The client identity [`Client::initialize`] sends: `turbomcp-client`
+    /// at this crate's version.
+    #[must_use]
+    pub fn default_client_info() -> Implementation {
+        Implementation {
+            name: "turbomcp-client".to_string(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            title: Some("TurboMCP Client".to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// The capabilities [`Client::initialize`] sends, detected from the
+    /// registered sampling, elicitation, and roots handlers.
+    #[must_use]
+    pub fn default_capabilities(&self) -> ProtocolClientCapabilities {
         let mut client_caps = ProtocolClientCapabilities::default();
 
         // Detect sampling capability from handler
@@ -1094,19 +1199,7 @@ impl<T: Transport + 'static> Client<T> {
             client_caps.roots = Some(roots_caps);
         }
 
-        let request = InitializeRequest {
-            protocol_version: PROTOCOL_VERSION.into(),
-            capabilities: client_caps,
-            client_info: turbomcp_protocol::types::Implementation {
-                name: "turbomcp-client".to_string(),
-                version: env!("CARGO_PKG_VERSION").to_string(),
-                title: Some("TurboMCP Client".to_string()),
-                ..Default::default()
-            },
-            meta: None,
-        };
-
-        self.initialize_with_request(request).await
+        client_caps
     }
 
     /// Whether [`Client::initialize`] has completed for this client.
@@ -1120,6 +1213,25 @@ impl<T: Transport + 'static> Client<T> {
         self.inner.initialized.load(Ordering::Relaxed)
     }
 
+    /// Usage instructions the server sent in its initialize result.
+    ///
+    /// Servers use `instructions` to tell the model how to use their tools
+    /// and resources; hosts typically add them to the system prompt. Returns
+    /// `None` before [`Client::initialize`] completes or when the server sent
+    /// none. A repeated initialize replaces them.
+    #[must_use]
+    pub fn server_instructions(&self) -> Option<String> {
+        self.inner.server_instructions.lock().clone()
+    }
+
+    /// Protocol version the server agreed to in its initialize result.
+    ///
+    /// Returns `None` before [`Client::initialize`] completes.
+    #[must_use]
+    pub fn protocol_version(&self) -> Option<ProtocolVersion> {
+        self.inner.protocol_version.lock().clone()
+    }
+
     /// Initialize the MCP session with an explicit initialize request.
     ///
     /// This is the opt-in path for draft protocol versions and capability
@@ -1147,14 +1259,28 @@ impl<T: Transport + 'static> Client<T> {
             tracing::info!("Transport connected successfully");
         }
 
+        let roots_list_changed = request
+            .capabilities
+            .roots
+            .as_ref()
+            .and_then(|roots| roots.list_changed)
+            .unwrap_or(false);
         let protocol_response: ProtocolInitializeResult = self
             .inner
             .protocol
             .request("initialize", Some(serde_json::to_value(request)?))
             .await?;
 
+        // Let transport-level code see the negotiated version.
+        transport.set_peer_protocol_version(protocol_response.protocol_version.as_str());
+
         // AtomicBool: lock-free store with Ordering::Relaxed
         self.inner.initialized.store(true, Ordering::Relaxed);
+        self.inner
+            .roots_list_changed
+            .store(roots_list_changed, Ordering::Relaxed);
+        *self.inner.server_instructions.lock() = protocol_response.instructions;
+        *self.inner.protocol_version.lock() = Some(protocol_response.protocol_version);
 
         // Send initialized notification
         self.inner
@@ -1294,6 +1420,81 @@ impl<T: Transport + 'static> Client<T> {
             .await
     }
 
+    /// `_meta` hints from the most recent server response that carried any.
+    ///
+    /// Exposes pagination cursors and rate-limit hints (see [`ResponseMeta`])
+    /// that servers attach to results or error data.
+    ///
+    /// [`ResponseMeta`]: crate::ResponseMeta
+    #[must_use]
+    pub fn last_response_meta(&self) -> Option<crate::ResponseMeta> {
+        self.inner.protocol.last_meta()
+    }
+
+    /// Set how many times an error response carrying a `_meta` backoff hint is
+    /// retried after waiting the hinted delay. `0` disables hinted retries.
+    ///
+    /// Only idempotent requests (lists, reads, `prompts/get`, `ping`, ...) are
+    /// retried on a hint; `tools/call` errors are always returned to the caller.
+    pub fn set_max_hinted_retries(&self, retries: u32) {
+        self.inner.protocol.set_max_hinted_retries(retries);
+    }
+
+    /// Retry error responses whose JSON-RPC code is listed in
+    /// `config.retriable_error_codes()`, backing off per `config`.
+    ///
+    /// Applies to every request this client sends. A server backoff hint in
+    /// the error's `_meta` still takes precedence over the computed delay.
+    pub fn set_error_code_retry(&self, config: turbomcp_transport::resilience::RetryConfig) {
+        self.inner.protocol.set_error_code_retry(config);
+    }
+
+    /// Deliver responses in the order their requests were sent.
+    ///
+    /// Over multiplexed transports responses can arrive out of request order;
+    /// by default each is delivered as soon as it arrives. When enabled, a
+    /// response is held until every request sent before it on this connection
+    /// has been answered, timed out, or cancelled. Applies to requests sent
+    /// after the call.
+    pub fn set_ordered_responses(&self, enabled: bool) {
+        self.inner.protocol.set_ordered_responses(enabled);
+    }
+
+    /// Check `structured_content` of tool results against the tool's
+    /// `outputSchema`.
+    ///
+    /// Schemas are taken from the most recent `tools/list` response, so call
+    /// [`list_tools`](Self::list_tools) first; results of tools without a
+    /// cached schema are not checked. A mismatch fails the call with an error
+    /// naming the tool and every violation. Disabled by default.
+    pub fn set_output_schema_validation(&self, enabled: bool) {
+        self.inner
+            .validate_tool_output
+            .store(enabled, Ordering::Relaxed);
+    }
+
+    /// Capabilities of the underlying transport.
+    ///
+    /// Lets applications choose a code path based on whether the connected
+    /// transport supports streaming or bidirectional requests, and on its
+    /// maximum message size.
+    #[must_use]
+    pub fn transport_capabilities(&self) -> &turbomcp_transport::TransportCapabilities {
+        self.inner.protocol.transport().capabilities()
+    }
+
+    /// Subscribe to connection lifecycle events from the underlying transport.
+    ///
+    /// The receiver observes `Connected`, `Disconnected`, and `Error` events
+    /// emitted after the call, which is useful for status indicators and
+    /// alerting. Returns `None` if the transport does not publish events.
+    #[must_use]
+    pub fn subscribe_events(
+        &self,
+    ) -> Option<tokio::sync::broadcast::Receiver<turbomcp_transport::TransportEvent>> {
+        self.inner.protocol.transport().subscribe_events()
+    }
+
     /// Get the client's capabilities configuration
     #[must_use]
     pub fn capabilities(&self) -> &ClientCapabilities {
@@ -1473,6 +1674,7 @@ impl<T: Transport + 'static> Client<T> {
 
 #[cfg(test)]
 mod tests {
+    use super::super::test_transport::{Reply, ScriptedTransport};
     use super::*;
     use std::future::Future;
     use std::pin::Pin;
@@ -1533,6 +1735,249 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "stdio")]
+    #[tokio::test]
+    async fn test_stdio_transport_capabilities_exposed() {
+        let client = Client::new(turbomcp_transport::stdio::StdioTransport::new());
+        let capabilities = client.transport_capabilities();
+
+        assert!(capabilities.supports_streaming);
+        assert!(capabilities.supports_bidirectional);
+        assert_eq!(
+            capabilities.max_message_size,
+            Some(turbomcp_protocol::MAX_MESSAGE_SIZE)
+        );
+    }
+
+    #[cfg(feature = "http")]
+    #[tokio::test]
+    async fn test_http_transport_capabilities_exposed() {
+        use turbomcp_transport::streamable_http_client::{
+            StreamableHttpClientConfig, StreamableHttpClientTransport,
+        };
+
+        let transport =
+            StreamableHttpClientTransport::new(StreamableHttpClientConfig::default()).unwrap();
+        let client = Client::new(transport);
+
+        assert!(client.transport_capabilities().supports_streaming);
+        assert!(client.transport_capabilities().max_message_size.is_some());
+    }
+
+    /// Answers `initialize` with whatever server instructions `instructions`
+    /// holds at the time.
+    fn init_transport(instructions: Arc<Mutex<Option<String>>>) -> ScriptedTransport {
+        ScriptedTransport::new(move |method, _| match method {
+            "initialize" => Reply::Result(serde_json::json!({
+                "protocolVersion": "2025-06-18",
+                "capabilities": {},
+                "serverInfo": {"name": "mock-server", "version": "1.0.0"},
+                "instructions": instructions.lock().clone()
+            })),
+            _ => Reply::Silent,
+        })
+    }
+
+    fn init_client() -> Client<ScriptedTransport> {
+        Client::new(init_transport(Arc::default()))
+    }
+
+    /// Params of the last `initialize` request the client sent.
+    fn initialize_params(client: &Client<ScriptedTransport>) -> serde_json::Value {
+        client
+            .inner
+            .protocol
+            .transport()
+            .sent_params("initialize")
+            .pop()
+            .expect("initialize sent")
+    }
+
+    #[tokio::test]
+    async fn test_server_instructions_exposed_after_initialize() {
+        let instructions = Arc::new(Mutex::new(Some(
+            "Call `search` before `fetch`.".to_string(),
+        )));
+        let client = Client::new(init_transport(instructions));
+        assert_eq!(client.server_instructions(), None);
+
+        client.initialize().await.expect("initialize");
+
+        assert_eq!(
+            client.server_instructions().as_deref(),
+            Some("Call `search` before `fetch`.")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reinitialize_replaces_server_instructions() {
+        let instructions = Arc::new(Mutex::new(Some("first".to_string())));
+        let client = Client::new(init_transport(Arc::clone(&instructions)));
+        client.initialize().await.expect("initialize");
+
+        *instructions.lock() = Some("second".to_string());
+        client.initialize().await.expect("re-initialize");
+        assert_eq!(client.server_instructions().as_deref(), Some("second"));
+
+        *instructions.lock() = None;
+        client.initialize().await.expect("re-initialize");
+        assert_eq!(client.server_instructions(), None);
+    }
+
+    #[tokio::test]
+    async fn test_transport_records_negotiated_protocol_version() {
+        let client = init_client();
+        let transport = client.inner.protocol.transport();
+        assert_eq!(transport.peer_protocol_version(), None);
+        assert_eq!(client.protocol_version(), None);
+
+        client.initialize().await.expect("initialize");
+
+        // The mock server answers with an older version than the client offers.
+        assert_eq!(
+            transport.peer_protocol_version().as_deref(),
+            Some("2025-06-18")
+        );
+        assert_eq!(
+            client
+                .protocol_version()
+                .as_ref()
+                .map(ProtocolVersion::as_str),
+            Some("2025-06-18")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_initialize_with_custom_client_identity() {
+        let client = init_client();
+        let info = Implementation {
+            name: "acme-agent".to_string(),
+            version: "4.2.0".to_string(),
+            ..Default::default()
+        };
+        let capabilities = ProtocolClientCapabilities {
+            roots: Some(RootsCapabilities {
+                list_changed: Some(true),
+            }),
+            ..Default::default()
+        };
+
+        client
+            .initialize_with(info, capabilities)
+            .await
+            .expect("initialize");
+
+        let params = initialize_params(&client);
+        assert_eq!(params["clientInfo"]["name"], "acme-agent");
+        assert_eq!(params["clientInfo"]["version"], "4.2.0");
+        assert_eq!(params["capabilities"]["roots"]["listChanged"], true);
+        assert!(params["capabilities"].get("sampling").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_initialize_sends_default_client_identity() {
+        let client = init_client();
+        client.initialize().await.expect("initialize");
+
+        let params = initialize_params(&client);
+        assert_eq!(params["clientInfo"]["name"], "turbomcp-client");
+        assert_eq!(params["clientInfo"]["version"], env!("CARGO_PKG_VERSION"));
+    }
+
+    /// Deliver a server-initiated `roots/list` and return the client's reply.
+    async fn request_roots(client: &Client<ScriptedTransport>) -> serde_json::Value {
+        let request = JsonRpcRequest::new(
+            "roots/list".to_string(),
+            None,
+            turbomcp_protocol::MessageId::from(7),
+        );
+        client.handle_request(request).await.expect("roots/list");
+        client
+            .inner
+            .protocol
+            .transport()
+            .sent()
+            .pop()
+            .expect("roots/list response")
+    }
+
+    #[tokio::test]
+    async fn test_roots_handler_answers_server_roots_request() {
+        let client = init_client();
+        client.set_roots_handler(Arc::new(StaticRootsHandler::new(vec![Root {
+            uri: "file:///workspace".into(),
+            name: Some("workspace".to_string()),
+            _meta: None,
+        }])));
+
+        let response = request_roots(&client).await;
+        assert_eq!(response["id"], 7);
+        assert_eq!(response["result"]["roots"][0]["uri"], "file:///workspace");
+        assert_eq!(response["result"]["roots"][0]["name"], "workspace");
+    }
+
+    #[tokio::test]
+    async fn test_roots_request_without_handler_returns_empty_list() {
+        let client = init_client();
+        let response = request_roots(&client).await;
+        assert_eq!(response["result"]["roots"], serde_json::json!([]));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_notification_is_ignored() {
+        let client = init_client();
+        client.initialize().await.expect("initialize");
+
+        let notification = JsonRpcNotification::new(
+            "notifications/from_the_future".to_string(),
+            Some(serde_json::json!({"detail": 1})),
+        );
+        client
+            .handle_notification(notification)
+            .await
+            .expect("unknown notifications are dropped");
+        assert!(client.is_initialized());
+    }
+
+    /// Records every custom notification it receives.
+    #[derive(Debug, Default)]
+    struct CapturingCustomHandler {
+        received: Mutex<Vec<(String, Option<serde_json::Value>)>>,
+    }
+
+    impl crate::handlers::CustomNotificationHandler for CapturingCustomHandler {
+        fn handle_custom_notification(
+            &self,
+            method: String,
+            params: Option<serde_json::Value>,
+        ) -> Pin<Box<dyn Future<Output = crate::handlers::HandlerResult<()>> + Send + '_>> {
+            self.received.lock().push((method, params));
+            Box::pin(async { Ok(()) })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_custom_notification_routes_to_handler() {
+        let client = init_client();
+        client.initialize().await.expect("initialize");
+        let handler = Arc::new(CapturingCustomHandler::default());
+        client.set_custom_notification_handler(handler.clone());
+
+        let notification = JsonRpcNotification::new(
+            "notifications/job/completed".to_string(),
+            Some(serde_json::json!({"job": 42})),
+        );
+        client
+            .handle_notification(notification)
+            .await
+            .expect("custom notification handled");
+
+        let received = handler.received.lock();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].0, "notifications/job/completed");
+        assert_eq!(received[0].1, Some(serde_json::json!({"job": 42})));
+    }
+
     #[tokio::test]
     async fn test_with_capabilities_and_config_uses_handler_limit() {
         let capabilities = ClientCapabilities {
@@ -1558,4 +2003,138 @@ mod tests {
 
         assert!(client.inner.shutdown_requested.load(Ordering::Relaxed));
     }
+
+    /// Records the request it receives and declines it.
+    #[derive(Debug, Default)]
+    struct CapturingElicitationHandler {
+        received: Mutex<Option<crate::handlers::ElicitationRequest>>,
+    }
+
+    impl crate::handlers::ElicitationHandler for CapturingElicitationHandler {
+        fn handle_elicitation(
+            &self,
+            request: crate::handlers::ElicitationRequest,
+        ) -> Pin<
+            Box<
+                dyn Future<
+                        Output = crate::handlers::HandlerResult<
+                            crate::handlers::ElicitationResponse,
+                        >,
+                    > + Send
+                    + '_,
+            >,
+        > {
+            *self.received.lock() = Some(request);
+            Box::pin(async { Ok(crate::handlers::ElicitationResponse::decline()) })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_elicitation_schema_reaches_handler_intact() {
+        use crate::handlers::{ElicitationFieldOption, ElicitationFieldType};
+
+        let schema = serde_json::json!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "title": "Name",
+                    "description": "Display name",
+                    "maxLength": 40,
+                    "default": "anonymous"
+                },
+                "plan": {
+                    "type": "string",
+                    "description": "Subscription plan",
+                    "oneOf": [
+                        {"const": "free", "title": "Free"},
+                        {"const": "pro", "title": "Pro"}
+                    ],
+                    "default": "free"
+                },
+                "region": {
+                    "type": "string",
+                    "enum": ["eu", "us"],
+                    "enumNames": ["Europe", "United States"]
+                },
+                "seats": {"type": "integer", "minimum": 1, "maximum": 50, "default": 5},
+                "tags": {
+                    "type": "array",
+                    "items": {"type": "string", "enum": ["a", "b", "c"]},
+                    "maxItems": 2,
+                    "default": ["a"]
+                },
+                "newsletter": {"type": "boolean", "default": true}
+            },
+            "required": ["name", "plan"]
+        });
+
+        let client = Client::new(NoopTransport::default());
+        let handler = Arc::new(CapturingElicitationHandler::default());
+        client.set_elicitation_handler(handler.clone());
+
+        let request = JsonRpcRequest {
+            jsonrpc: JsonRpcVersion,
+            id: turbomcp_protocol::MessageId::from("elicit-1"),
+            method: "elicitation/create".to_string(),
+            params: Some(serde_json::json!({
+                "mode": "form",
+                "message": "Set up your account",
+                "requestedSchema": schema.clone(),
+            })),
+        };
+        client.handle_request(request).await.expect("handled");
+
+        let received = handler.received.lock().take().expect("handler called");
+        assert_eq!(received.requested_schema(), Some(&schema));
+
+        let fields = received.fields().expect("form fields");
+        let field = |name: &str| fields.iter().find(|f| f.name == name).expect(name);
+        assert_eq!(fields.len(), 6);
+
+        let name = field("name");
+        assert_eq!(name.field_type, ElicitationFieldType::String);
+        assert_eq!(name.title.as_deref(), Some("Name"));
+        assert_eq!(name.description.as_deref(), Some("Display name"));
+        assert_eq!(name.default, Some(serde_json::json!("anonymous")));
+        assert_eq!(name.max, Some(40.0));
+        assert!(name.required && name.options.is_empty());
+
+        let plan = field("plan");
+        assert!(plan.required);
+        assert_eq!(plan.default, Some(serde_json::json!("free")));
+        assert_eq!(
+            plan.options,
+            vec![
+                ElicitationFieldOption {
+                    value: "free".into(),
+                    label: Some("Free".into())
+                },
+                ElicitationFieldOption {
+                    value: "pro".into(),
+                    label: Some("Pro".into())
+                },
+            ]
+        );
+
+        let region = field("region");
+        assert!(!region.required);
+        assert_eq!(region.options[1].value, "us");
+        assert_eq!(region.options[1].label.as_deref(), Some("United States"));
+
+        let seats = field("seats");
+        assert_eq!(seats.field_type, ElicitationFieldType::Integer);
+        assert_eq!((seats.min, seats.max), (Some(1.0), Some(50.0)));
+        assert_eq!(seats.default, Some(serde_json::json!(5)));
+
+        let tags = field("tags");
+        assert_eq!(tags.field_type, ElicitationFieldType::MultiSelect);
+        assert_eq!(tags.options.len(), 3);
+        assert_eq!(tags.max, Some(2.0));
+        assert_eq!(tags.default, Some(serde_json::json!(["a"])));
+
+        let newsletter = field("newsletter");
+        assert_eq!(newsletter.field_type, ElicitationFieldType::Boolean);
+        assert_eq!(newsletter.default, Some(serde_json::json!(true)));
+    }
 }
@@ -28,9 +28,18 @@
 //!
 //! This ensures that there's only ONE consumer of `transport.receive()`,
 //! eliminating race conditions by centralizing all message routing.
+//!
+//! ## Response Ordering
+//!
+//! Responses are delivered as they arrive, which over multiplexed transports
+//! (HTTP, WebSocket, concurrent server handlers) may differ from the order the
+//! requests were sent. With [`MessageDispatcher::set_ordered_responses`]
+//! enabled, a reordering buffer holds each response until every earlier
+//! request on the connection has been answered or abandoned, so responses are
+//! delivered in FIFO request order.
 
 use parking_lot::Mutex;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 
 use tokio::sync::{Notify, oneshot};
@@ -103,6 +112,11 @@ pub(super) struct MessageDispatcher {
     /// it sends it through the channel.
     response_waiters: Arc<Mutex<HashMap<MessageId, oneshot::Sender<JsonRpcResponse>>>>,
 
+    /// Reordering buffer for FIFO response delivery (disabled by default)
+    ///
+    /// Lock order: `reorder` before `response_waiters`.
+    reorder: Arc<Mutex<ReorderBuffer>>,
+
     /// Optional handler for server-initiated requests (elicitation, sampling)
     ///
     /// This is set by the Client to handle incoming requests from the server.
@@ -137,6 +151,7 @@ impl MessageDispatcher {
     pub fn new<T: Transport + 'static>(transport: Arc<T>) -> Arc<Self> {
         let dispatcher = Arc::new(Self {
             response_waiters: Arc::new(Mutex::new(HashMap::new())),
+            reorder: Arc::new(Mutex::new(ReorderBuffer::default())),
             request_handler: Arc::new(Mutex::new(None)),
             notification_handler: Arc::new(Mutex::new(None)),
             shutdown: Arc::new(Notify::new()),
@@ -201,6 +216,10 @@ impl MessageDispatcher {
     /// ```
     pub fn wait_for_response(&self, id: MessageId) -> oneshot::Receiver<JsonRpcResponse> {
         let (tx, rx) = oneshot::channel();
+        let mut reorder = self.reorder.lock();
+        if reorder.enabled {
+            reorder.order.push_back(id.clone());
+        }
         self.response_waiters.lock().insert(id.clone(), tx);
         tracing::trace!("Registered response waiter for request ID: {:?}", id);
         rx
@@ -227,10 +246,32 @@ impl MessageDispatcher {
 
     /// Remove a previously-registered response waiter.
     pub fn remove_response_waiter(&self, id: &MessageId) {
-        self.response_waiters.lock().remove(id);
+        let mut reorder = self.reorder.lock();
+        let mut waiters = self.response_waiters.lock();
+        waiters.remove(id);
+        // An abandoned request no longer holds back the responses after it.
+        if reorder.forget(id) {
+            reorder.release(&mut waiters);
+        }
         tracing::trace!("Removed response waiter for request ID: {:?}", id);
     }
 
+    /// Deliver responses in the order their requests were sent
+    ///
+    /// Applies to requests registered after the call. Disabling releases any
+    /// held responses immediately.
+    pub fn set_ordered_responses(&self, enabled: bool) {
+        let mut reorder = self.reorder.lock();
+        reorder.enabled = enabled;
+        if !enabled {
+            let mut waiters = self.response_waiters.lock();
+            reorder.order.clear();
+            for (id, response) in reorder.held.drain() {
+                deliver(&mut waiters, &id, response);
+            }
+        }
+    }
+
     #[cfg(test)]
     pub fn response_waiter_count(&self) -> usize {
         self.response_waiters.lock().len()
@@ -244,6 +285,10 @@ impl MessageDispatcher {
     /// This method is called automatically when the Client is dropped,
     /// ensuring proper cleanup of background resources.
     pub fn shutdown(&self) {
+        let mut reorder = self.reorder.lock();
+        reorder.order.clear();
+        reorder.held.clear();
+        drop(reorder);
         self.response_waiters.lock().clear();
         self.shutdown.notify_one();
         tracing::info!("Message dispatcher shutdown initiated");
@@ -261,6 +306,7 @@ impl MessageDispatcher {
     /// * `transport` - Arc reference to the transport
     fn spawn_routing_task<T: Transport + 'static>(dispatcher: Arc<Self>, transport: Arc<T>) {
         let response_waiters = dispatcher.response_waiters.clone();
+        let reorder = dispatcher.reorder.clone();
         let request_handler = dispatcher.request_handler.clone();
         let notification_handler = dispatcher.notification_handler.clone();
         let shutdown = dispatcher.shutdown.clone();
@@ -294,6 +340,7 @@ impl MessageDispatcher {
                                 if let Err(e) = Self::route_message(
                                     msg,
                                     &response_waiters,
+                                    &reorder,
                                     &request_handler,
                                     &notification_handler,
                                 ).await {
@@ -375,6 +422,7 @@ impl MessageDispatcher {
     ///
     /// * `msg` - The raw transport message to route
     /// * `response_waiters` - Map of request IDs to oneshot senders
+    /// * `reorder` - Buffer holding responses that arrived ahead of earlier requests
     /// * `request_handler` - Optional request handler
     /// * `notification_handler` - Optional notification handler
     ///
@@ -385,6 +433,7 @@ impl MessageDispatcher {
     async fn route_message(
         msg: TransportMessage,
         response_waiters: &Arc<Mutex<HashMap<MessageId, oneshot::Sender<JsonRpcResponse>>>>,
+        reorder: &Arc<Mutex<ReorderBuffer>>,
         request_handler: &Arc<Mutex<Option<RequestHandler>>>,
         notification_handler: &Arc<Mutex<Option<NotificationHandler>>>,
     ) -> Result<()> {
@@ -396,12 +445,16 @@ impl MessageDispatcher {
             JsonRpcMessage::Response(response) => {
                 // Route to waiting request() call
                 // ResponseId is Option<RequestId> where RequestId = MessageId
-                if let Some(request_id) = &response.id.0 {
-                    if let Some(tx) = response_waiters.lock().remove(request_id) {
-                        tracing::trace!("Routing response to request ID: {:?}", request_id);
-                        // Send response through oneshot channel
-                        // Ignore error if receiver was dropped (request timed out)
-                        let _ = tx.send(response);
+                if let Some(request_id) = response.id.0.clone() {
+                    let mut reorder = reorder.lock();
+                    let mut waiters = response_waiters.lock();
+                    if reorder.order.contains(&request_id) {
+                        // Hold until every earlier request has been answered
+                        tracing::trace!("Buffering response to request ID: {:?}", request_id);
+                        reorder.held.insert(request_id, response);
+                        reorder.release(&mut waiters);
+                    } else if waiters.contains_key(&request_id) {
+                        deliver(&mut waiters, &request_id, response);
                     } else {
                         tracing::warn!(
                             "Received response for unknown/expired request ID: {:?}",
@@ -468,6 +521,7 @@ impl std::fmt::Debug for MessageDispatcher {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MessageDispatcher")
             .field("response_waiters", &"<Arc<Mutex<HashMap>>>")
+            .field("reorder", &*self.reorder.lock())
             .field("request_handler", &"<Arc<Mutex<Option<Handler>>>>")
             .field("notification_handler", &"<Arc<Mutex<Option<Handler>>>>")
             .field("shutdown", &"<Arc<Notify>>")
@@ -475,6 +529,55 @@ impl std::fmt::Debug for MessageDispatcher {
     }
 }
 
+/// Send a response to its waiting `request()` call, if it is still waiting
+fn deliver(
+    waiters: &mut HashMap<MessageId, oneshot::Sender<JsonRpcResponse>>,
+    id: &MessageId,
+    response: JsonRpcResponse,
+) {
+    if let Some(tx) = waiters.remove(id) {
+        tracing::trace!("Routing response to request ID: {:?}", id);
+        // Ignore error if receiver was dropped (request timed out)
+        let _ = tx.send(response);
+    }
+}
+
+/// Responses held back so they are delivered in request order
+#[derive(Debug, Default)]
+struct ReorderBuffer {
+    /// Whether new requests are delivered in FIFO order
+    enabled: bool,
+    /// Outstanding ordered requests, oldest first
+    order: VecDeque<MessageId>,
+    /// Responses that arrived before an earlier request's response
+    held: HashMap<MessageId, JsonRpcResponse>,
+}
+
+impl ReorderBuffer {
+    /// Stop tracking `id`, returning whether it was tracked
+    fn forget(&mut self, id: &MessageId) -> bool {
+        self.held.remove(id);
+        let Some(position) = self.order.iter().position(|queued| queued == id) else {
+            return false;
+        };
+        self.order.remove(position);
+        true
+    }
+
+    /// Deliver held responses from the front of the queue until reaching a
+    /// request that is still unanswered
+    fn release(&mut self, waiters: &mut HashMap<MessageId, oneshot::Sender<JsonRpcResponse>>) {
+        while let Some(front) = self.order.front() {
+            let Some(response) = self.held.remove(front) else {
+                break;
+            };
+            if let Some(id) = self.order.pop_front() {
+                deliver(waiters, &id, response);
+            }
+        }
+    }
+}
+
 /// RAII guard that removes a registered response waiter on drop unless
 /// [`Self::disarm`] has been called.
 ///
@@ -516,6 +619,7 @@ impl Drop for WaiterGuard {
 
 #[cfg(test)]
 mod tests {
+    use super::super::test_transport::ScriptedTransport;
     use super::*;
     use std::future::Future;
     use std::pin::Pin;
@@ -577,6 +681,79 @@ mod tests {
         }
     }
 
+    /// Queue an empty successful response to request `id`.
+    fn respond(transport: &ScriptedTransport, id: &str) {
+        transport.push_incoming(serde_json::json!({ "jsonrpc": "2.0", "id": id, "result": {} }));
+    }
+
+    /// Register waiters for `ids` and record the order their responses land in.
+    fn record_deliveries(
+        dispatcher: &Arc<MessageDispatcher>,
+        ids: &[&str],
+    ) -> Arc<Mutex<Vec<String>>> {
+        let delivered = Arc::new(Mutex::new(Vec::new()));
+        for id in ids {
+            let rx = dispatcher.wait_for_response(MessageId::from(*id));
+            let delivered = Arc::clone(&delivered);
+            let id = id.to_string();
+            tokio::spawn(async move {
+                if rx.await.is_ok() {
+                    delivered.lock().push(id);
+                }
+            });
+        }
+        delivered
+    }
+
+    async fn settle() {
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    }
+
+    #[tokio::test]
+    async fn test_ordered_responses_are_delivered_in_request_order() {
+        let transport = Arc::new(ScriptedTransport::default());
+        let dispatcher = MessageDispatcher::new(Arc::clone(&transport));
+        dispatcher.set_ordered_responses(true);
+        let delivered = record_deliveries(&dispatcher, &["1", "2", "3"]);
+
+        // Responses 3 and 2 arrive first and are held back for 1.
+        respond(&transport, "3");
+        respond(&transport, "2");
+        settle().await;
+        assert!(delivered.lock().is_empty());
+
+        respond(&transport, "1");
+        settle().await;
+        assert_eq!(*delivered.lock(), ["1", "2", "3"]);
+
+        // An abandoned request stops holding back the ones after it.
+        let delivered = record_deliveries(&dispatcher, &["4", "5"]);
+        respond(&transport, "5");
+        settle().await;
+        assert!(delivered.lock().is_empty());
+        dispatcher.remove_response_waiter(&MessageId::from("4"));
+        settle().await;
+        assert_eq!(*delivered.lock(), ["5"]);
+
+        dispatcher.shutdown();
+    }
+
+    #[tokio::test]
+    async fn test_unordered_responses_are_delivered_on_arrival() {
+        let transport = Arc::new(ScriptedTransport::default());
+        let dispatcher = MessageDispatcher::new(Arc::clone(&transport));
+        let delivered = record_deliveries(&dispatcher, &["1", "2"]);
+
+        respond(&transport, "2");
+        settle().await;
+        assert_eq!(*delivered.lock(), ["2"]);
+        respond(&transport, "1");
+        settle().await;
+        assert_eq!(*delivered.lock(), ["2", "1"]);
+
+        dispatcher.shutdown();
+    }
+
     #[tokio::test]
     async fn test_dispatcher_creation() {
         let dispatcher = MessageDispatcher::new(Arc::new(NoopTransport::default()));
@@ -483,14 +483,14 @@ impl SessionManager<turbomcp_transport::resilience::TurboTransport> {
     ///
     /// // Use explicit configuration for clarity
     /// use std::time::Duration;
+    /// let mut retry = RetryConfig::default();
+    /// retry.max_attempts = 5;
+    /// retry.base_delay = Duration::from_millis(200);
+    ///
     /// manager.add_resilient_server(
     ///     "github",
     ///     StdioTransport::new(),
-    ///     RetryConfig {
-    ///         max_attempts: 5,
-    ///         base_delay: Duration::from_millis(200),
-    ///         ..Default::default()
-    ///     },
+    ///     retry,
     ///     CircuitBreakerConfig {
     ///         failure_threshold: 3,
     ///         timeout: Duration::from_secs(30),
@@ -0,0 +1,108 @@
+//! Server-provided `_meta` hints
+//!
+//! Servers may attach `_meta` to results (or to `error.data` on failures) to
+//! hint at pagination and rate limiting. This module parses the common fields
+//! into [`ResponseMeta`] so the client can surface them to callers and honor
+//! backoff hints when retrying.
+//!
+//! Recognized keys:
+//!
+//! | Key | Meaning |
+//! |-----|---------|
+//! | `nextCursor` | Pagination cursor for the next page |
+//! | `retryAfterMs` | Milliseconds to wait before retrying |
+//! | `retryAfter` | Seconds to wait before retrying (used if `retryAfterMs` is absent) |
+//! | `rateLimitRemaining` | Requests remaining in the current rate-limit window |
+
+use std::time::Duration;
+
+use serde_json::{Map, Value};
+use turbomcp_protocol::error::{MAX_RETRY_AFTER, retry_after_from_meta};
+use turbomcp_protocol::jsonrpc::JsonRpcResponse;
+
+/// Upper bound on a server-requested backoff.
+///
+/// A misbehaving server must not be able to park a client indefinitely, so
+/// hints larger than this are clamped.
+pub const MAX_SERVER_BACKOFF: Duration = MAX_RETRY_AFTER;
+
+/// Common `_meta` hints parsed from a server response
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ResponseMeta {
+    /// Pagination cursor for the next page, if the server provided one
+    pub next_cursor: Option<String>,
+    /// How long the server asked the client to wait before retrying
+    pub retry_after: Option<Duration>,
+    /// Requests remaining in the server's current rate-limit window
+    pub rate_limit_remaining: Option<u64>,
+    /// The raw `_meta` object, including fields not parsed above
+    pub raw: Map<String, Value>,
+}
+
+impl ResponseMeta {
+    /// Parse hints from a `_meta` object.
+    ///
+    /// Returns `None` if `meta` is not a JSON object.
+    #[must_use]
+    pub fn from_meta(meta: &Value) -> Option<Self> {
+        let raw = meta.as_object()?;
+
+        let retry_after = retry_after_from_meta(meta);
+
+        Some(Self {
+            next_cursor: raw
+                .get("nextCursor")
+                .and_then(Value::as_str)
+                .map(str::to_string),
+            retry_after,
+            rate_limit_remaining: raw.get("rateLimitRemaining").and_then(Value::as_u64),
+            raw: raw.clone(),
+        })
+    }
+
+    /// Extract hints from a JSON-RPC response.
+    ///
+    /// Looks at `result._meta` for successful responses and `error.data._meta`
+    /// for error responses.
+    #[must_use]
+    pub fn from_response(response: &JsonRpcResponse) -> Option<Self> {
+        let meta = match response.error() {
+            Some(error) => error.data.as_ref()?.get("_meta")?,
+            None => response.result()?.get("_meta")?,
+        };
+        Self::from_meta(meta)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parses_common_hints() {
+        let meta = ResponseMeta::from_meta(&json!({
+            "nextCursor": "page-2",
+            "retryAfterMs": 250,
+            "rateLimitRemaining": 0,
+            "x-custom": true
+        }))
+        .unwrap();
+
+        assert_eq!(meta.next_cursor.as_deref(), Some("page-2"));
+        assert_eq!(meta.retry_after, Some(Duration::from_millis(250)));
+        assert_eq!(meta.rate_limit_remaining, Some(0));
+        assert_eq!(meta.raw["x-custom"], json!(true));
+    }
+
+    #[test]
+    fn test_retry_after_seconds_is_clamped() {
+        let meta = ResponseMeta::from_meta(&json!({ "retryAfter": 3600 })).unwrap();
+        assert_eq!(meta.retry_after, Some(MAX_SERVER_BACKOFF));
+    }
+
+    #[test]
+    fn test_non_object_meta_is_ignored() {
+        assert!(ResponseMeta::from_meta(&json!("nope")).is_none());
+    }
+}
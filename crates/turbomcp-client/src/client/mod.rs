@@ -6,6 +6,7 @@
 //! - `core`: Main `Client<T>` implementation and connection management
 //! - `protocol`: ProtocolClient for JSON-RPC communication
 //! - `dispatcher`: Message routing for bidirectional communication
+//! - `meta`: Server-provided `_meta` pagination and rate hints
 //! - `config`: Configuration types and utilities
 //! - `operations`: MCP operations (tools, resources, prompts, etc.)
 //! - `systems`: Supporting systems (handlers, plugins, connection)
@@ -17,8 +18,11 @@ pub(crate) mod config;
 pub(crate) mod core;
 mod dispatcher;
 mod manager;
+pub mod meta;
 mod protocol;
 mod tasks_test;
+#[cfg(test)]
+mod test_transport;
 
 // Operations modules
 pub mod operations;
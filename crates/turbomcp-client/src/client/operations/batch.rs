@@ -0,0 +1,190 @@
+//! Deadline-aware batch operations for MCP client
+//!
+//! A batch issues several `tools/call` requests concurrently under one
+//! overall deadline. Calls that finish in time return their results; calls
+//! still outstanding when the deadline passes are cancelled with
+//! `notifications/cancelled` and reported as timeout errors, so one slow tool
+//! cannot hold back the rest of the batch.
+
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+use turbomcp_protocol::types::{CallToolRequest, CallToolResult};
+use turbomcp_protocol::{Error, Result};
+
+impl<T: turbomcp_transport::Transport + 'static> super::super::core::Client<T> {
+    /// Call several tools concurrently, bounded by an overall deadline
+    ///
+    /// Each entry in `calls` is a tool name and its arguments. The returned
+    /// vector has one result per call, in the same order as `calls`. When
+    /// `deadline` elapses, completed results are kept, and every call still
+    /// pending is cancelled on the server and returned as an error for which
+    /// [`Error::is_timeout`] is true. Completed results are handled exactly as
+    /// [`call_tool`](Self::call_tool) handles them, including output schema
+    /// validation when it is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use turbomcp_client::Client;
+    /// # use turbomcp_transport::stdio::StdioTransport;
+    /// # use std::time::Duration;
+    /// # async fn example() -> turbomcp_protocol::Result<()> {
+    /// let mut client = Client::new(StdioTransport::new());
+    /// client.initialize().await?;
+    ///
+    /// let results = client
+    ///     .call_tools_with_deadline(
+    ///         vec![("search".to_string(), None), ("fetch".to_string(), None)],
+    ///         Duration::from_secs(5),
+    ///     )
+    ///     .await?;
+    /// for result in results {
+    ///     match result {
+    ///         Ok(result) => println!("Result: {:?}", result.content),
+    ///         Err(e) if e.is_timeout() => println!("Timed out"),
+    ///         Err(e) => println!("Failed: {e}"),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn call_tools_with_deadline(
+        &self,
+        calls: Vec<(String, Option<HashMap<String, serde_json::Value>>)>,
+        deadline: Duration,
+    ) -> Result<Vec<Result<CallToolResult>>> {
+        if !self.inner.initialized.load(Ordering::Relaxed) {
+            return Err(Error::invalid_request("Client not initialized"));
+        }
+
+        let deadline = tokio::time::Instant::now() + deadline;
+        let pending = calls.into_iter().map(|(name, arguments)| async move {
+            let request_data = CallToolRequest {
+                name: name.clone(),
+                arguments: Some(arguments.unwrap_or_default()),
+                task: None,
+                _meta: None,
+            };
+            let raw_result: serde_json::Value = self
+                .inner
+                .protocol
+                .request_until(
+                    "tools/call",
+                    Some(serde_json::to_value(&request_data)?),
+                    deadline,
+                )
+                .await?;
+            self.tool_call_result(&name, raw_result)
+        });
+
+        Ok(futures::future::join_all(pending).await)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::core::Client;
+    use super::super::super::test_transport::{Reply, ScriptedTransport};
+    use std::time::Duration;
+    use turbomcp_protocol::PROTOCOL_VERSION;
+    use turbomcp_protocol::types::ContentBlock;
+
+    /// Answers `initialize`, `tools/list` (only `fast_a` declares an output
+    /// schema), and every `tools/call` except `slow`, which is never
+    /// answered.
+    fn batch_client() -> Client<ScriptedTransport> {
+        Client::new(ScriptedTransport::new(|method, params| match method {
+            "initialize" => Reply::Result(serde_json::json!({
+                "protocolVersion": PROTOCOL_VERSION,
+                "capabilities": {"tools": {}},
+                "serverInfo": {"name": "mock-server", "version": "1.0.0"}
+            })),
+            "tools/list" => Reply::Result(serde_json::json!({"tools": [{
+                "name": "fast_a",
+                "inputSchema": {"type": "object"},
+                "outputSchema": {"type": "object"}
+            }]})),
+            "tools/call" if params["name"] == "slow" => Reply::Silent,
+            "tools/call" => Reply::Result(
+                serde_json::json!({"content": [{"type": "text", "text": params["name"]}]}),
+            ),
+            _ => Reply::Silent,
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_batch_returns_partial_results_on_deadline() {
+        let client = batch_client();
+        client.initialize().await.expect("initialize");
+
+        let results = client
+            .call_tools_with_deadline(
+                vec![
+                    ("fast_a".to_string(), None),
+                    ("slow".to_string(), None),
+                    ("fast_b".to_string(), None),
+                ],
+                Duration::from_millis(200),
+            )
+            .await
+            .expect("batch");
+
+        assert_eq!(results.len(), 3);
+        for (result, expected) in [(&results[0], "fast_a"), (&results[2], "fast_b")] {
+            let result = result.as_ref().expect("fast tool completes");
+            assert!(
+                matches!(&result.content[0], ContentBlock::Text(text) if text.text == expected)
+            );
+        }
+        let err = results[1].as_ref().expect_err("slow tool times out");
+        assert!(err.is_timeout(), "unexpected error: {err}");
+
+        let transport = client.inner.protocol.transport();
+        let slow_id = transport
+            .sent()
+            .into_iter()
+            .find(|message| message["params"]["name"] == "slow")
+            .expect("slow sent")["id"]
+            .clone();
+        let cancelled: Vec<_> = transport
+            .sent_params("notifications/cancelled")
+            .into_iter()
+            .map(|params| params["requestId"].clone())
+            .collect();
+        assert_eq!(cancelled, vec![slow_id]);
+    }
+
+    #[tokio::test]
+    async fn test_batch_validates_tool_output() {
+        let client = batch_client();
+        client.initialize().await.expect("initialize");
+        client.set_output_schema_validation(true);
+        client.list_tools().await.expect("list tools");
+
+        let results = client
+            .call_tools_with_deadline(
+                vec![("fast_a".to_string(), None), ("fast_b".to_string(), None)],
+                Duration::from_secs(5),
+            )
+            .await
+            .expect("batch");
+
+        let err = results[0]
+            .as_ref()
+            .expect_err("fast_a has no structured content");
+        assert!(err.to_string().contains("output schema"), "{err}");
+        assert!(results[1].is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_batch_requires_initialization() {
+        let client = batch_client();
+        let err = client
+            .call_tools_with_deadline(vec![], Duration::from_secs(1))
+            .await
+            .expect_err("uninitialized");
+        assert!(err.to_string().contains("not initialized"));
+    }
+}
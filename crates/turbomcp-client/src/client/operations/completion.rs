@@ -48,12 +48,16 @@ impl<T: turbomcp_transport::Transport + 'static> super::super::core::Client<T> {
 
     /// Request completion suggestions from the server
     ///
-    /// Simple completion interface for basic autocompletion needs.
-    /// Uses a prompt-based reference with hardcoded "partial" argument name.
+    /// Sends `completion/complete` for any completion reference (prompt or
+    /// resource template) and returns the server's suggestions.
+    /// [`complete_prompt`](Self::complete_prompt) and
+    /// [`complete_resource`](Self::complete_resource) are shorthands that also
+    /// accept resolved-argument context.
     ///
     /// # Arguments
     ///
-    /// * `handler_name` - The completion handler name
+    /// * `reference` - The prompt or resource template being completed
+    /// * `argument_name` - Name of the argument being completed
     /// * `argument_value` - The partial value to complete
     ///
     /// # Examples
@@ -61,26 +65,26 @@ impl<T: turbomcp_transport::Transport + 'static> super::super::core::Client<T> {
     /// ```rust,no_run
     /// # use turbomcp_client::Client;
     /// # use turbomcp_transport::stdio::StdioTransport;
+    /// # use turbomcp_protocol::types::{CompletionReference, ResourceTemplateReferenceData};
     /// # async fn example() -> turbomcp_protocol::Result<()> {
     /// let mut client = Client::new(StdioTransport::new());
     /// client.initialize().await?;
     ///
-    /// let result = client.complete("complete_path", "/usr/b").await?;
+    /// let reference = CompletionReference::ResourceTemplate(ResourceTemplateReferenceData {
+    ///     uri: "file:///{path}".to_string(),
+    /// });
+    /// let result = client.complete(reference, "path", "/usr/b").await?;
     /// println!("Completions: {:?}", result.completion.values);
     /// # Ok(())
     /// # }
     /// ```
     pub async fn complete(
         &self,
-        handler_name: &str,
+        reference: CompletionReference,
+        argument_name: &str,
         argument_value: &str,
     ) -> Result<CompletionResponse> {
-        let reference = CompletionReference::Prompt(PromptReferenceData {
-            name: handler_name.to_string(),
-            title: None,
-        });
-
-        self.complete_internal("partial", argument_value, reference, None)
+        self.complete_internal(argument_name, argument_value, reference, None)
             .await
     }
 
@@ -190,3 +194,63 @@ impl<T: turbomcp_transport::Transport + 'static> super::super::core::Client<T> {
             .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::core::Client;
+    use super::super::super::test_transport::{Reply, ScriptedTransport};
+    use super::*;
+
+    /// Answers every `completion/complete` request with fixed suggestions.
+    fn completion_transport() -> ScriptedTransport {
+        ScriptedTransport::new(|method, _| {
+            assert_eq!(method, "completion/complete");
+            Reply::Result(serde_json::json!({
+                "completion": {
+                    "values": ["/usr/bin", "/usr/bin/env"],
+                    "total": 5,
+                    "hasMore": true
+                }
+            }))
+        })
+    }
+
+    #[tokio::test]
+    async fn complete_sends_reference_and_parses_suggestions() {
+        let client = Client::new(completion_transport());
+        client.inner.initialized.store(true, Ordering::Relaxed);
+
+        let reference = CompletionReference::ResourceTemplate(ResourceTemplateReferenceData {
+            uri: "file:///{path}".to_string(),
+        });
+        let response = client
+            .complete(reference, "path", "/usr/b")
+            .await
+            .expect("completion");
+
+        assert_eq!(response.completion.values, vec!["/usr/bin", "/usr/bin/env"]);
+        assert_eq!(response.completion.total, Some(5));
+        assert_eq!(response.completion.has_more, Some(true));
+
+        let requests = client
+            .inner
+            .protocol
+            .transport()
+            .sent_params("completion/complete");
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0]["ref"]["type"], "ref/resource");
+        assert_eq!(requests[0]["ref"]["uri"], "file:///{path}");
+        assert_eq!(requests[0]["argument"]["name"], "path");
+        assert_eq!(requests[0]["argument"]["value"], "/usr/b");
+    }
+
+    #[tokio::test]
+    async fn complete_requires_initialization() {
+        let client = Client::new(completion_transport());
+        let reference = CompletionReference::Prompt(PromptReferenceData {
+            name: "greet".to_string(),
+            title: None,
+        });
+        assert!(client.complete(reference, "name", "A").await.is_err());
+    }
+}
@@ -4,8 +4,9 @@
 //! that process server-initiated operations and notifications.
 
 use crate::handlers::{
-    CancellationHandler, ElicitationHandler, LogHandler, ProgressHandler, PromptListChangedHandler,
-    ResourceListChangedHandler, ResourceUpdateHandler, RootsHandler, ToolListChangedHandler,
+    CancellationHandler, CustomNotificationHandler, ElicitationHandler, LogHandler,
+    ProgressHandler, PromptListChangedHandler, ResourceListChangedHandler, ResourceUpdateHandler,
+    RootsHandler, ToolListChangedHandler,
 };
 use std::sync::Arc;
 
@@ -284,6 +285,27 @@ impl<T: turbomcp_transport::Transport + 'static> super::super::core::Client<T> {
         self.inner.handlers.lock().has_progress_handler()
     }
 
+    /// Register a handler for application-defined notifications
+    ///
+    /// Notifications whose method is not part of the MCP specification, such
+    /// as those sent by a server broadcast, are routed to this handler.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - The custom notification handler implementation
+    pub fn set_custom_notification_handler(&self, handler: Arc<dyn CustomNotificationHandler>) {
+        self.inner
+            .handlers
+            .lock()
+            .set_custom_notification_handler(handler);
+    }
+
+    /// Check if a custom notification handler is registered
+    #[must_use]
+    pub fn has_custom_notification_handler(&self) -> bool {
+        self.inner.handlers.lock().has_custom_notification_handler()
+    }
+
     /// Check if a tool list changed handler is registered
     #[must_use]
     pub fn has_tool_list_changed_handler(&self) -> bool {
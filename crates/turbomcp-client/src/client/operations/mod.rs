@@ -3,20 +3,25 @@
 //! This module contains focused operation modules for CLIENT-INITIATED MCP operations:
 //!
 //! - `tools`: Tool operations (list, call)
+//! - `batch`: Concurrent tool calls under an overall deadline
 //! - `resources`: Resource operations (list, read, templates, subscribe/unsubscribe)
 //! - `prompts`: Prompt operations (list, get)
 //! - `completion`: Argument autocompletion operations
 //! - `sampling`: LLM sampling handler registration (SERVER->CLIENT)
 //! - `connection`: Connection utilities (ping, set_log_level)
 //! - `handlers`: Event handler registration for SERVER->CLIENT requests
+//! - `roots`: Runtime root updates with `notifications/roots/list_changed`
 //!
 //! Note: `roots/list` is a SERVER->CLIENT request (not a client operation).
-//! The client should implement a roots handler to respond to server requests.
+//! The client should implement a roots handler to respond to server requests,
+//! or use `set_roots` to serve a fixed list.
 
+pub mod batch;
 pub mod completion;
 pub mod connection;
 pub mod handlers;
 pub mod prompts;
 pub mod resources;
+pub mod roots;
 pub mod sampling;
 pub mod tools;
@@ -190,4 +190,142 @@ impl<T: turbomcp_transport::Transport + 'static> super::super::core::Client<T> {
             )
             .await
     }
+
+    /// Get a prompt after checking arguments against its declaration
+    ///
+    /// Looks up `name` via [`list_prompts`](Self::list_prompts) and rejects
+    /// the call client-side, without sending `prompts/get`, if the prompt is
+    /// unknown or any argument it declares as required is missing from
+    /// `arguments`. Otherwise behaves like [`get_prompt`](Self::get_prompt).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The client is not initialized
+    /// - The server does not list a prompt called `name`
+    /// - A required argument is missing
+    /// - Either request fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use turbomcp_client::Client;
+    /// # use turbomcp_transport::stdio::StdioTransport;
+    /// # use std::collections::HashMap;
+    /// # async fn example() -> turbomcp_protocol::Result<()> {
+    /// let client = Client::new(StdioTransport::new());
+    /// client.initialize().await?;
+    ///
+    /// let mut args = HashMap::new();
+    /// args.insert("name".to_string(), serde_json::json!("Alice"));
+    /// let result = client.get_prompt_with_args("greeting", args).await?;
+    /// println!("Rendered {} messages", result.messages.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_prompt_with_args(
+        &self,
+        name: &str,
+        arguments: PromptInput,
+    ) -> Result<GetPromptResult> {
+        let prompt = self
+            .list_prompts()
+            .await?
+            .into_iter()
+            .find(|prompt| prompt.name == name)
+            .ok_or_else(|| Error::prompt_not_found(name))?;
+
+        let missing: Vec<&str> = prompt
+            .arguments
+            .iter()
+            .flatten()
+            .filter(|arg| arg.required.unwrap_or(false) && !arguments.contains_key(&arg.name))
+            .map(|arg| arg.name.as_str())
+            .collect();
+        if !missing.is_empty() {
+            return Err(Error::invalid_params(format!(
+                "Prompt '{name}' is missing required arguments: {}",
+                missing.join(", ")
+            )));
+        }
+
+        self.get_prompt(name, Some(arguments)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::core::Client;
+    use super::super::super::test_transport::{Reply, ScriptedTransport};
+    use super::*;
+    use std::collections::HashMap;
+
+    /// Serves one `greeting` prompt (required `name`, optional `tone`) and
+    /// renders it on `prompts/get`.
+    fn initialized_client() -> Client<ScriptedTransport> {
+        let client = Client::new(ScriptedTransport::new(|method, params| {
+            Reply::Result(match method {
+                "prompts/list" => serde_json::json!({
+                    "prompts": [{
+                        "name": "greeting",
+                        "arguments": [
+                            {"name": "name", "required": true},
+                            {"name": "tone"}
+                        ]
+                    }]
+                }),
+                "prompts/get" => {
+                    let who = params["arguments"]["name"].as_str().unwrap_or_default();
+                    serde_json::json!({
+                        "messages": [{
+                            "role": "user",
+                            "content": {"type": "text", "text": format!("Hello, {who}!")}
+                        }]
+                    })
+                }
+                other => panic!("unexpected request {other}"),
+            })
+        }));
+        client.inner.initialized.store(true, Ordering::Relaxed);
+        client
+    }
+
+    fn sent_methods(client: &Client<ScriptedTransport>) -> Vec<String> {
+        client.inner.protocol.transport().sent_methods()
+    }
+
+    #[tokio::test]
+    async fn get_prompt_with_args_rejects_missing_required_argument() {
+        let client = initialized_client();
+        let mut args = HashMap::new();
+        args.insert("tone".to_string(), serde_json::json!("warm"));
+
+        let err = client
+            .get_prompt_with_args("greeting", args)
+            .await
+            .expect_err("missing `name` must be rejected");
+
+        assert!(
+            err.to_string().contains("missing required arguments: name"),
+            "unexpected error: {err}"
+        );
+        assert_eq!(sent_methods(&client), vec!["prompts/list"]);
+    }
+
+    #[tokio::test]
+    async fn get_prompt_with_args_returns_rendered_messages() {
+        let client = initialized_client();
+        let mut args = HashMap::new();
+        args.insert("name".to_string(), serde_json::json!("Alice"));
+
+        let result = client
+            .get_prompt_with_args("greeting", args)
+            .await
+            .expect("prompt");
+
+        assert_eq!(result.messages.len(), 1);
+        let rendered = serde_json::to_value(&result.messages[0]).expect("message json");
+        assert_eq!(rendered["content"]["text"], "Hello, Alice!");
+        assert_eq!(sent_methods(&client), vec!["prompts/list", "prompts/get"]);
+    }
 }
@@ -5,9 +5,12 @@
 
 use std::sync::atomic::Ordering;
 
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
 use turbomcp_protocol::types::{
-    Cursor, ListResourceTemplatesRequest, ListResourceTemplatesResult, ListResourcesRequest,
-    ListResourcesResult, ReadResourceRequest, ReadResourceResult, Resource, ResourceTemplate,
+    ByteRange, Cursor, ListResourceTemplatesRequest, ListResourceTemplatesResult,
+    ListResourcesRequest, ListResourcesResult, ReadResourceRequest, ReadResourceResult, Resource,
+    ResourceContents, ResourceLink, ResourceTemplate,
 };
 use turbomcp_protocol::{Error, Result};
 
@@ -137,6 +140,142 @@ impl<T: turbomcp_transport::Transport + 'static> super::super::core::Client<T> {
     /// # }
     /// ```
     pub async fn read_resource(&self, uri: &str) -> Result<ReadResourceResult> {
+        self.send_read_resource(uri, None, None).await
+    }
+
+    /// Read a resource as raw bytes
+    ///
+    /// Returns the bytes of the first content block together with its MIME
+    /// type. Text contents are returned as UTF-8 bytes and base64 blobs are
+    /// decoded, so callers don't need to care which form the server chose.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the read fails, the server returns no contents,
+    /// or a blob is not valid base64.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use turbomcp_client::Client;
+    /// # use turbomcp_transport::stdio::StdioTransport;
+    /// # async fn example() -> turbomcp_protocol::Result<()> {
+    /// let mut client = Client::new(StdioTransport::new());
+    /// client.initialize().await?;
+    ///
+    /// let (bytes, mime_type) = client.read_resource_bytes("file:///logo.png").await?;
+    /// println!("{} bytes of {:?}", bytes.len(), mime_type);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_resource_bytes(&self, uri: &str) -> Result<(Vec<u8>, Option<String>)> {
+        let result = self.read_resource(uri).await?;
+        match result.contents.into_iter().next() {
+            Some(ResourceContents::Text(text)) => Ok((text.text.into_bytes(), text.mime_type)),
+            Some(ResourceContents::Blob(blob)) => {
+                let bytes = STANDARD.decode(&blob.blob).map_err(|e| {
+                    Error::internal(format!("Resource '{uri}' has an invalid base64 blob: {e}"))
+                })?;
+                Ok((bytes, blob.mime_type))
+            }
+            None => Err(Error::internal(format!(
+                "Resource '{uri}' returned no contents"
+            ))),
+        }
+    }
+
+    /// Follow a resource link returned in a tool result
+    ///
+    /// Tools may return `resource_link` content blocks that reference a
+    /// resource instead of inlining it. This reads the linked resource's URI.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use turbomcp_client::Client;
+    /// # use turbomcp_transport::stdio::StdioTransport;
+    /// # async fn example() -> turbomcp_protocol::Result<()> {
+    /// let mut client = Client::new(StdioTransport::new());
+    /// client.initialize().await?;
+    ///
+    /// let result = client.call_tool("export", None, None).await?;
+    /// for link in result.resource_links() {
+    ///     let contents = client.read_resource_link(link).await?;
+    ///     println!("{}: {:?}", link.name, contents.contents);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_resource_link(&self, link: &ResourceLink) -> Result<ReadResourceResult> {
+        self.read_resource(&link.uri).await
+    }
+
+    /// Read a byte range of a resource
+    ///
+    /// Asks the server for at most `range.length` bytes starting at
+    /// `range.offset`. Servers that support partial reads return the slice and
+    /// report the full size in `_meta.range.total`; servers that don't return
+    /// the whole resource.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client is not initialized, the URI is empty,
+    /// the range starts past the end of the resource, or the request fails.
+    pub async fn read_resource_range(
+        &self,
+        uri: &str,
+        range: ByteRange,
+    ) -> Result<ReadResourceResult> {
+        self.send_read_resource(uri, Some(range), None).await
+    }
+
+    /// Read a resource only if it changed since a previous read
+    ///
+    /// Pass the ETag from an earlier result (see
+    /// [`ReadResourceResult::etag`]). If the server still has the same
+    /// version it answers with an empty, not-modified result, which
+    /// [`ReadResourceResult::is_not_modified`] reports; otherwise the full
+    /// contents are returned along with the new ETag. Servers that don't tag
+    /// their resources always return the contents.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client is not initialized, the URI is empty,
+    /// or the request fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use turbomcp_client::Client;
+    /// # use turbomcp_transport::stdio::StdioTransport;
+    /// # async fn example() -> turbomcp_protocol::Result<()> {
+    /// let mut client = Client::new(StdioTransport::new());
+    /// client.initialize().await?;
+    ///
+    /// let first = client.read_resource("file:///config.toml").await?;
+    /// if let Some(etag) = first.etag() {
+    ///     let again = client.read_resource_if_none_match("file:///config.toml", etag).await?;
+    ///     if again.is_not_modified() {
+    ///         println!("config unchanged");
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_resource_if_none_match(
+        &self,
+        uri: &str,
+        etag: &str,
+    ) -> Result<ReadResourceResult> {
+        self.send_read_resource(uri, None, Some(etag)).await
+    }
+
+    async fn send_read_resource(
+        &self,
+        uri: &str,
+        range: Option<ByteRange>,
+        if_none_match: Option<&str>,
+    ) -> Result<ReadResourceResult> {
         if !self.inner.initialized.load(Ordering::Relaxed) {
             return Err(Error::invalid_request("Client not initialized"));
         }
@@ -148,7 +287,8 @@ impl<T: turbomcp_transport::Transport + 'static> super::super::core::Client<T> {
         // Send read_resource request
         let request = ReadResourceRequest {
             uri: uri.into(),
-            _meta: None,
+            range,
+            _meta: if_none_match.map(|etag| serde_json::json!({ "ifNoneMatch": etag })),
         };
 
         let response: ReadResourceResult = self
@@ -247,135 +387,78 @@ impl<T: turbomcp_transport::Transport + 'static> super::super::core::Client<T> {
 #[cfg(test)]
 mod tests {
     use super::super::super::core::Client;
+    use super::super::super::test_transport::{Reply, ScriptedTransport};
     use super::*;
-    use std::collections::VecDeque;
-    use std::future::Future;
-    use std::pin::Pin;
-    use std::sync::Mutex;
-    use turbomcp_protocol::MessageId;
-    use turbomcp_transport::{
-        Transport, TransportCapabilities, TransportError, TransportMessage, TransportMetrics,
-        TransportResult, TransportState, TransportType,
-    };
-
-    #[derive(Debug)]
-    struct TemplateTransport {
-        capabilities: TransportCapabilities,
-        responses: Mutex<VecDeque<TransportMessage>>,
-    }
-
-    impl TemplateTransport {
-        fn new() -> Self {
-            Self {
-                capabilities: TransportCapabilities::default(),
-                responses: Mutex::new(VecDeque::new()),
-            }
-        }
-    }
-
-    impl Transport for TemplateTransport {
-        fn transport_type(&self) -> TransportType {
-            TransportType::Stdio
-        }
-
-        fn capabilities(&self) -> &TransportCapabilities {
-            &self.capabilities
-        }
-
-        fn state(&self) -> Pin<Box<dyn Future<Output = TransportState> + Send + '_>> {
-            Box::pin(async { TransportState::Connected })
-        }
-
-        fn connect(&self) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
-            Box::pin(async { Ok(()) })
-        }
+    use turbomcp_protocol::types::{CallToolResult, Content, ResourceContents};
 
-        fn disconnect(&self) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
-            Box::pin(async { Ok(()) })
-        }
+    /// Bytes served by the binary test resource; not valid UTF-8.
+    const LOGO_PNG: &[u8] = &[0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0xff];
 
-        fn send(
-            &self,
-            message: TransportMessage,
-        ) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
-            let request: serde_json::Value = match serde_json::from_slice(&message.payload) {
-                Ok(request) => request,
-                Err(e) => {
-                    return Box::pin(async move {
-                        Err(TransportError::SerializationFailed(e.to_string()))
-                    });
-                }
-            };
-
-            assert_eq!(request["method"], "resources/templates/list");
-            let response = serde_json::json!({
-                "jsonrpc": "2.0",
-                "id": request["id"].clone(),
-                "result": {
-                    "resourceTemplates": [
-                        {
-                            "uriTemplate": "repo://{owner}/{name}",
-                            "name": "repo",
-                            "title": "Repository",
-                            "description": "Repository metadata",
-                            "mimeType": "application/json",
-                            "icons": [
-                                {
-                                    "src": "https://example.com/repo.png",
-                                    "mimeType": "image/png",
-                                    "sizes": ["64x64"]
+    /// Serves a tool linking to a CSV report, the report itself, a binary
+    /// logo, and one fully described resource template.
+    fn resource_server() -> ScriptedTransport {
+        ScriptedTransport::new(|method, params| {
+            Reply::Result(match method {
+                "tools/call" => serde_json::to_value(CallToolResult::contents(vec![
+                    Content::text("Report ready"),
+                    Content::link("file:///reports/q3.csv", "q3.csv"),
+                ]))
+                .expect("tool result"),
+                "resources/read" => match params["uri"].as_str() {
+                    Some("file:///images/logo.png") => serde_json::json!({
+                        "contents": [{
+                            "uri": "file:///images/logo.png",
+                            "mimeType": "image/png",
+                            "blob": STANDARD.encode(LOGO_PNG)
+                        }]
+                    }),
+                    uri => {
+                        assert_eq!(uri, Some("file:///reports/q3.csv"));
+                        serde_json::json!({
+                            "contents": [{
+                                "uri": "file:///reports/q3.csv",
+                                "mimeType": "text/csv",
+                                "text": "quarter,revenue\nq3,42"
+                            }]
+                        })
+                    }
+                },
+                method => {
+                    assert_eq!(method, "resources/templates/list");
+                    serde_json::json!({
+                        "resourceTemplates": [
+                            {
+                                "uriTemplate": "repo://{owner}/{name}",
+                                "name": "repo",
+                                "title": "Repository",
+                                "description": "Repository metadata",
+                                "mimeType": "application/json",
+                                "icons": [
+                                    {
+                                        "src": "https://example.com/repo.png",
+                                        "mimeType": "image/png",
+                                        "sizes": ["64x64"]
+                                    }
+                                ],
+                                "annotations": {
+                                    "audience": ["user"],
+                                    "priority": 0.7,
+                                    "lastModified": "2026-05-08T12:00:00Z"
+                                },
+                                "_meta": {
+                                    "x-test": true
                                 }
-                            ],
-                            "annotations": {
-                                "audience": ["user"],
-                                "priority": 0.7,
-                                "lastModified": "2026-05-08T12:00:00Z"
-                            },
-                            "_meta": {
-                                "x-test": true
                             }
-                        }
-                    ]
-                }
-            });
-            let payload = match serde_json::to_vec(&response) {
-                Ok(payload) => payload,
-                Err(e) => {
-                    return Box::pin(async move {
-                        Err(TransportError::SerializationFailed(e.to_string()))
-                    });
+                        ]
+                    })
                 }
-            };
-            self.responses
-                .lock()
-                .expect("response queue poisoned")
-                .push_back(TransportMessage::new(
-                    MessageId::from("response-1"),
-                    payload.into(),
-                ));
-            Box::pin(async { Ok(()) })
-        }
-
-        fn receive(
-            &self,
-        ) -> Pin<Box<dyn Future<Output = TransportResult<Option<TransportMessage>>> + Send + '_>>
-        {
-            let response = self
-                .responses
-                .lock()
-                .expect("response queue poisoned")
-                .pop_front();
-            Box::pin(async move { Ok(response) })
-        }
-
-        fn metrics(&self) -> Pin<Box<dyn Future<Output = TransportMetrics> + Send + '_>> {
-            Box::pin(async { TransportMetrics::default() })
-        }
+            })
+        })
     }
 
     #[tokio::test]
     async fn list_resource_templates_preserves_full_template_metadata() {
-        let client = Client::new(TemplateTransport::new());
+        let client = Client::new(resource_server());
         client.inner.initialized.store(true, Ordering::Relaxed);
 
         let templates = client
@@ -403,4 +486,48 @@ mod tests {
             serde_json::json!(true)
         );
     }
+
+    #[tokio::test]
+    async fn tool_result_resource_link_can_be_followed() {
+        let client = Client::new(resource_server());
+        client.inner.initialized.store(true, Ordering::Relaxed);
+
+        let result = client
+            .call_tool("report", None, None)
+            .await
+            .expect("tool result");
+        let links: Vec<_> = result.resource_links().collect();
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].uri, "file:///reports/q3.csv");
+        assert_eq!(links[0].name, "q3.csv");
+
+        let contents = client
+            .read_resource_link(links[0])
+            .await
+            .expect("linked resource");
+        assert!(matches!(
+            &contents.contents[0],
+            ResourceContents::Text(text) if text.text == "quarter,revenue\nq3,42"
+        ));
+    }
+
+    #[tokio::test]
+    async fn read_resource_bytes_decodes_text_and_blob() {
+        let client = Client::new(resource_server());
+        client.inner.initialized.store(true, Ordering::Relaxed);
+
+        let (bytes, mime_type) = client
+            .read_resource_bytes("file:///reports/q3.csv")
+            .await
+            .expect("text resource");
+        assert_eq!(bytes, b"quarter,revenue\nq3,42");
+        assert_eq!(mime_type.as_deref(), Some("text/csv"));
+
+        let (bytes, mime_type) = client
+            .read_resource_bytes("file:///images/logo.png")
+            .await
+            .expect("binary resource");
+        assert_eq!(bytes, LOGO_PNG);
+        assert_eq!(mime_type.as_deref(), Some("image/png"));
+    }
 }
@@ -0,0 +1,197 @@
+//! Roots operations for MCP client
+//!
+//! Roots are owned by the client: servers ask for them with `roots/list`
+//! (SERVER->CLIENT) and the client announces changes with
+//! `notifications/roots/list_changed` (CLIENT->SERVER). This module lets
+//! applications replace the advertised roots at runtime, e.g. when a
+//! workspace is opened.
+
+use crate::handlers::StaticRootsHandler;
+use std::sync::Arc;
+use std::sync::atomic::Ordering;
+use turbomcp_protocol::Result;
+use turbomcp_protocol::types::Root;
+
+impl<T: turbomcp_transport::Transport + 'static> super::super::core::Client<T> {
+    /// Replace the roots this client exposes and notify the server
+    ///
+    /// Installs a roots handler answering `roots/list` with `roots` (replacing
+    /// any handler set with [`set_roots_handler`](Self::set_roots_handler)).
+    /// If the client is initialized and advertised `roots.listChanged` in its
+    /// `initialize` request, it then sends `notifications/roots/list_changed`
+    /// so the server re-lists.
+    ///
+    /// No notification is sent before initialization (the new roots are
+    /// served from the first `roots/list`) or when `listChanged` was not
+    /// advertised, e.g. because no roots handler was registered at
+    /// initialize time; the server then sees the new roots the next time it
+    /// asks.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the notification cannot be sent.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use turbomcp_client::Client;
+    /// # use turbomcp_protocol::types::Root;
+    /// # use turbomcp_transport::stdio::StdioTransport;
+    /// # async fn example() -> turbomcp_protocol::Result<()> {
+    /// let client = Client::new(StdioTransport::new());
+    /// client.initialize().await?;
+    ///
+    /// // A workspace was opened
+    /// client
+    ///     .set_roots(vec![Root {
+    ///         uri: "file:///home/user/workspace".into(),
+    ///         name: Some("workspace".to_string()),
+    ///         _meta: None,
+    ///     }])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_roots(&self, roots: Vec<Root>) -> Result<()> {
+        self.inner
+            .handlers
+            .lock()
+            .set_roots_handler(Arc::new(StaticRootsHandler::new(roots)));
+
+        if !self.inner.initialized.load(Ordering::Relaxed)
+            || !self.inner.roots_list_changed.load(Ordering::Relaxed)
+        {
+            return Ok(());
+        }
+
+        self.inner
+            .protocol
+            .notify("notifications/roots/list_changed", None)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::core::Client;
+    use super::super::super::test_transport::{Reply, ScriptedTransport};
+    use super::*;
+    use std::time::Duration;
+    use turbomcp_protocol::PROTOCOL_VERSION;
+
+    /// A server that only answers `initialize`.
+    fn client() -> Client<ScriptedTransport> {
+        Client::new(ScriptedTransport::new(|method, _| match method {
+            "initialize" => Reply::Result(serde_json::json!({
+                "protocolVersion": PROTOCOL_VERSION,
+                "capabilities": {},
+                "serverInfo": {"name": "mock-server", "version": "1.0.0"}
+            })),
+            _ => Reply::Silent,
+        }))
+    }
+
+    fn root(uri: &str) -> Root {
+        Root {
+            uri: uri.into(),
+            name: None,
+            _meta: None,
+        }
+    }
+
+    fn sent_methods(client: &Client<ScriptedTransport>) -> Vec<String> {
+        client.inner.protocol.transport().sent_methods()
+    }
+
+    /// Send a server-originated `roots/list` and return the URIs the client
+    /// answers with.
+    async fn server_lists_roots(client: &Client<ScriptedTransport>) -> Vec<String> {
+        let transport = client.inner.protocol.transport();
+        transport.push_incoming(serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": "list-roots",
+            "method": "roots/list"
+        }));
+
+        let response = tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                if let Some(response) = transport
+                    .sent()
+                    .into_iter()
+                    .find(|message| message["id"] == "list-roots")
+                {
+                    return response;
+                }
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("roots/list response");
+
+        response["result"]["roots"]
+            .as_array()
+            .expect("roots")
+            .iter()
+            .map(|root| root["uri"].as_str().expect("uri").to_string())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn set_roots_notifies_server_and_updates_listing() {
+        let client = client();
+        client
+            .set_roots(vec![root("file:///old")])
+            .await
+            .expect("set roots");
+        client.initialize().await.expect("initialize");
+
+        client
+            .set_roots(vec![root("file:///workspace"), root("file:///notes")])
+            .await
+            .expect("set roots");
+
+        assert_eq!(
+            sent_methods(&client),
+            vec![
+                "initialize",
+                "notifications/initialized",
+                "notifications/roots/list_changed"
+            ]
+        );
+        assert_eq!(
+            server_lists_roots(&client).await,
+            vec!["file:///workspace", "file:///notes"]
+        );
+    }
+
+    #[tokio::test]
+    async fn set_roots_without_advertised_list_changed_does_not_notify() {
+        // No roots handler at initialize time, so `roots` is not advertised.
+        let client = client();
+        client.initialize().await.expect("initialize");
+
+        client
+            .set_roots(vec![root("file:///workspace")])
+            .await
+            .expect("set roots");
+
+        assert_eq!(
+            sent_methods(&client),
+            vec!["initialize", "notifications/initialized"]
+        );
+        assert_eq!(server_lists_roots(&client).await, vec!["file:///workspace"]);
+    }
+
+    #[tokio::test]
+    async fn set_roots_before_initialize_does_not_notify() {
+        let client = client();
+
+        client
+            .set_roots(vec![root("file:///workspace")])
+            .await
+            .expect("set roots");
+
+        assert!(sent_methods(&client).is_empty());
+        assert!(client.has_roots_handler());
+    }
+}
@@ -5,6 +5,7 @@
 
 use std::collections::HashMap;
 use std::sync::atomic::Ordering;
+use std::sync::{Arc, OnceLock};
 
 use turbomcp_protocol::types::{
     CallToolRequest, CallToolResult, CreateTaskResult, Cursor, ListToolsRequest, ListToolsResult,
@@ -15,6 +16,29 @@ use turbomcp_protocol::{Error, Result};
 /// Maximum number of pagination pages to prevent infinite loops from misbehaving servers.
 const MAX_PAGINATION_PAGES: usize = 1000;
 
+/// A tool's `outputSchema`, compiled the first time a result is validated.
+pub(crate) struct OutputSchema {
+    schema: serde_json::Value,
+    validator: OnceLock<std::result::Result<jsonschema::Validator, String>>,
+}
+
+impl OutputSchema {
+    fn new(schema: serde_json::Value) -> Self {
+        Self {
+            schema,
+            validator: OnceLock::new(),
+        }
+    }
+
+    /// The compiled schema, or why it does not compile.
+    fn validator(&self) -> std::result::Result<&jsonschema::Validator, &str> {
+        self.validator
+            .get_or_init(|| jsonschema::validator_for(&self.schema).map_err(|e| e.to_string()))
+            .as_ref()
+            .map_err(String::as_str)
+    }
+}
+
 /// Response shape for `tools/call`.
 #[derive(Debug, Clone)]
 pub enum CallToolResponse {
@@ -94,7 +118,65 @@ impl<T: turbomcp_transport::Transport + 'static> super::super::core::Client<T> {
         } else {
             None
         };
-        self.inner.protocol.request("tools/list", params).await
+        let result: ListToolsResult = self.inner.protocol.request("tools/list", params).await?;
+        self.cache_output_schemas(&result.tools);
+        Ok(result)
+    }
+
+    /// Remember each tool's `outputSchema` for result validation.
+    fn cache_output_schemas(&self, tools: &[Tool]) {
+        let mut schemas = self.inner.tool_output_schemas.lock();
+        for tool in tools {
+            match tool
+                .output_schema
+                .as_ref()
+                .and_then(|schema| serde_json::to_value(schema).ok())
+            {
+                Some(schema) => {
+                    // Keep an unchanged schema so it is not compiled again.
+                    if schemas
+                        .get(&tool.name)
+                        .is_none_or(|cached| cached.schema != schema)
+                    {
+                        schemas.insert(tool.name.clone(), Arc::new(OutputSchema::new(schema)));
+                    }
+                }
+                None => {
+                    schemas.remove(&tool.name);
+                }
+            }
+        }
+    }
+
+    /// Check `result` against the cached output schema of tool `name`.
+    fn validate_tool_output(&self, name: &str, result: &CallToolResult) -> Result<()> {
+        if !self.inner.validate_tool_output.load(Ordering::Relaxed) || result.is_error == Some(true)
+        {
+            return Ok(());
+        }
+        let Some(schema) = self.inner.tool_output_schemas.lock().get(name).cloned() else {
+            return Ok(());
+        };
+        let Some(structured) = result.structured_content.as_ref() else {
+            return Err(Error::internal(format!(
+                "Tool '{name}' declares an output schema but returned no structured content"
+            )));
+        };
+        let validator = schema.validator().map_err(|e| {
+            Error::internal(format!("Tool '{name}' has an invalid output schema: {e}"))
+        })?;
+        let violations: Vec<String> = validator
+            .iter_errors(structured)
+            .map(|e| e.to_string())
+            .collect();
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::internal(format!(
+                "Tool '{name}' returned structured content that violates its output schema: {}",
+                violations.join("; ")
+            )))
+        }
     }
 
     /// List available tool names from the MCP server
@@ -223,12 +305,26 @@ impl<T: turbomcp_transport::Transport + 'static> super::super::core::Client<T> {
                     Error::internal(format!("Failed to deserialize CreateTaskResult: {e}"))
                 })
         } else {
-            serde_json::from_value(raw_result)
+            self.tool_call_result(name, raw_result)
                 .map(CallToolResponse::Result)
-                .map_err(|e| Error::internal(format!("Failed to deserialize CallToolResult: {e}")))
         }
     }
 
+    /// Turn the raw response to a non-task `tools/call` of tool `name` into
+    /// its result, validating it against the tool's output schema.
+    ///
+    /// Every way of calling a tool goes through here.
+    pub(super) fn tool_call_result(
+        &self,
+        name: &str,
+        raw_result: serde_json::Value,
+    ) -> Result<CallToolResult> {
+        let result: CallToolResult = serde_json::from_value(raw_result)
+            .map_err(|e| Error::internal(format!("Failed to deserialize CallToolResult: {e}")))?;
+        self.validate_tool_output(name, &result)?;
+        Ok(result)
+    }
+
     /// Call a tool using MCP task-augmented execution.
     ///
     /// Returns the created task handle. Retrieve the final result with the
@@ -247,3 +343,61 @@ impl<T: turbomcp_transport::Transport + 'static> super::super::core::Client<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::core::Client;
+    use super::super::super::test_transport::{Reply, ScriptedTransport};
+    use super::*;
+
+    /// Serves one tool, `forecast`, whose result violates its output schema.
+    fn forecast_server() -> ScriptedTransport {
+        ScriptedTransport::new(|method, _| {
+            Reply::Result(match method {
+                "tools/list" => serde_json::json!({
+                    "tools": [{
+                        "name": "forecast",
+                        "inputSchema": { "type": "object" },
+                        "outputSchema": {
+                            "type": "object",
+                            "properties": { "temperature": { "type": "number" } },
+                            "required": ["temperature"]
+                        }
+                    }]
+                }),
+                method => {
+                    assert_eq!(method, "tools/call");
+                    serde_json::json!({
+                        "content": [{ "type": "text", "text": "hot" }],
+                        "structuredContent": { "temperature": "hot" }
+                    })
+                }
+            })
+        })
+    }
+
+    #[tokio::test]
+    async fn output_schema_validation_rejects_mismatched_structured_content() {
+        let client = Client::new(forecast_server());
+        client.inner.initialized.store(true, Ordering::Relaxed);
+        client.list_tools().await.expect("tools");
+
+        // Off by default: the result is returned as-is.
+        let result = client
+            .call_tool("forecast", None, None)
+            .await
+            .expect("unvalidated result");
+        assert_eq!(
+            result.structured_content,
+            Some(serde_json::json!({ "temperature": "hot" }))
+        );
+
+        client.set_output_schema_validation(true);
+        let error = client
+            .call_tool("forecast", None, None)
+            .await
+            .expect_err("schema violation");
+        assert!(error.message.contains("forecast"), "{}", error.message);
+        assert!(error.message.contains("output schema"), "{}", error.message);
+    }
+}
@@ -28,13 +28,40 @@
 //! eliminating the race condition.
 
 use std::sync::Arc;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
 
-use turbomcp_protocol::jsonrpc::{JsonRpcRequest, JsonRpcVersion};
+use parking_lot::Mutex;
+use turbomcp_protocol::jsonrpc::{JsonRpcError, JsonRpcRequest, JsonRpcResponse, JsonRpcVersion};
 use turbomcp_protocol::{Error, Result};
+use turbomcp_transport::resilience::RetryConfig;
 use turbomcp_transport::{Transport, TransportConfig, TransportMessage};
 
 use super::dispatcher::MessageDispatcher;
+use super::meta::ResponseMeta;
+
+/// Default number of retries for error responses that carry a server backoff hint
+pub(super) const DEFAULT_HINTED_RETRIES: u32 = 3;
+
+/// Whether `method` can be resent after a server backoff hint
+///
+/// Only reads and requests whose repetition has no further effect qualify.
+/// A failed `tools/call` may already have had side effects, so it is never
+/// resent on a hint alone.
+fn is_idempotent(method: &str) -> bool {
+    method == "ping"
+        || method.ends_with("/list")
+        || matches!(
+            method,
+            "resources/read"
+                | "resources/subscribe"
+                | "resources/unsubscribe"
+                | "prompts/get"
+                | "completion/complete"
+                | "logging/setLevel"
+                | "tasks/get"
+                | "tasks/result"
+        )
+}
 
 /// JSON-RPC protocol handler for MCP communication
 ///
@@ -58,6 +85,12 @@ pub(super) struct ProtocolClient<T: Transport> {
     next_id: AtomicU64,
     /// Transport configuration for timeout enforcement (v2.2.0+)
     config: TransportConfig,
+    /// `_meta` hints from the most recent response that carried any
+    last_meta: Mutex<Option<ResponseMeta>>,
+    /// How many times to retry an error response that carries a backoff hint
+    max_hinted_retries: AtomicU32,
+    /// Backoff policy for error responses with a retriable JSON-RPC code
+    error_code_retry: Mutex<Option<RetryConfig>>,
 }
 
 impl<T: Transport + 'static> ProtocolClient<T> {
@@ -73,9 +106,37 @@ impl<T: Transport + 'static> ProtocolClient<T> {
             dispatcher,
             next_id: AtomicU64::new(1),
             config,
+            last_meta: Mutex::new(None),
+            max_hinted_retries: AtomicU32::new(DEFAULT_HINTED_RETRIES),
+            error_code_retry: Mutex::new(None),
         }
     }
 
+    /// Set how many times an error response carrying a `_meta` backoff hint
+    /// is retried after waiting the hinted delay. `0` disables hinted retries.
+    pub(super) fn set_max_hinted_retries(&self, retries: u32) {
+        self.max_hinted_retries.store(retries, Ordering::Relaxed);
+    }
+
+    /// Retry error responses whose code is in `config.retriable_error_codes()`
+    /// with the configured exponential backoff. An empty code list disables
+    /// code-based retries.
+    pub(super) fn set_error_code_retry(&self, config: RetryConfig) {
+        *self.error_code_retry.lock() =
+            (!config.retriable_error_codes().is_empty()).then_some(config);
+    }
+
+    /// Deliver responses in the order their requests were sent, holding back
+    /// any that arrive ahead of an earlier request's response.
+    pub(super) fn set_ordered_responses(&self, enabled: bool) {
+        self.dispatcher.set_ordered_responses(enabled);
+    }
+
+    /// `_meta` hints from the most recent response that carried any
+    pub(super) fn last_meta(&self) -> Option<ResponseMeta> {
+        self.last_meta.lock().clone()
+    }
+
     /// Get the message dispatcher for handler registration
     ///
     /// This allows the Client to register request/notification handlers
@@ -121,9 +182,33 @@ impl<T: Transport + 'static> ProtocolClient<T> {
         &self,
         method: &str,
         params: Option<serde_json::Value>,
+    ) -> Result<R> {
+        self.request_with_deadline(method, params, None).await
+    }
+
+    /// Send a JSON-RPC request that must complete by `deadline`
+    ///
+    /// Behaves like [`request`](Self::request), except that if the response
+    /// has not arrived by `deadline` the request is cancelled with
+    /// `notifications/cancelled` and a timeout error is returned.
+    pub(super) async fn request_until<R: serde::de::DeserializeOwned>(
+        &self,
+        method: &str,
+        params: Option<serde_json::Value>,
+        deadline: tokio::time::Instant,
+    ) -> Result<R> {
+        self.request_with_deadline(method, params, Some(deadline))
+            .await
+    }
+
+    async fn request_with_deadline<R: serde::de::DeserializeOwned>(
+        &self,
+        method: &str,
+        params: Option<serde_json::Value>,
+        deadline: Option<tokio::time::Instant>,
     ) -> Result<R> {
         // Wrap the entire operation in total timeout (if configured)
-        let operation = self.request_inner(method, params);
+        let operation = self.request_inner(method, params, deadline);
 
         if let Some(total_timeout) = self.config.timeouts.total {
             match tokio::time::timeout(total_timeout, operation).await {
@@ -133,7 +218,7 @@ impl<T: Transport + 'static> ProtocolClient<T> {
                         operation: format!("{}()", method),
                         timeout: total_timeout,
                     };
-                    Err(Error::transport(err.to_string()))
+                    Err(Error::timeout(err.to_string()))
                 }
             }
         } else {
@@ -142,11 +227,91 @@ impl<T: Transport + 'static> ProtocolClient<T> {
     }
 
     /// Inner request implementation without total timeout wrapper
+    ///
+    /// Error responses to idempotent methods whose `_meta` carries a backoff
+    /// hint (`retryAfterMs` / `retryAfter`) are retried after the hinted
+    /// delay, up to `max_hinted_retries` times. Other methods, such as
+    /// `tools/call`, surface the error and leave retrying to the caller.
+    ///
+    /// Error responses without a hint whose code was configured as retriable
+    /// via [`set_error_code_retry`](Self::set_error_code_retry) are retried
+    /// with exponential backoff, up to `max_attempts` times.
+    ///
+    /// With a `deadline`, no retry is attempted whose delay would reach it.
     async fn request_inner<R: serde::de::DeserializeOwned>(
         &self,
         method: &str,
         params: Option<serde_json::Value>,
+        deadline: Option<tokio::time::Instant>,
     ) -> Result<R> {
+        let before_deadline = |delay: std::time::Duration| {
+            deadline.is_none_or(|deadline| tokio::time::Instant::now() + delay < deadline)
+        };
+        let mut attempt = 0;
+        loop {
+            let response = self.send_and_wait(method, params.clone(), deadline).await?;
+
+            let meta = ResponseMeta::from_response(&response);
+            if let Some(meta) = &meta {
+                *self.last_meta.lock() = Some(meta.clone());
+            }
+
+            // Handle JSON-RPC errors
+            if let Some(error) = response.error() {
+                let retry_after = meta.and_then(|m| m.retry_after);
+                if let Some(delay) = retry_after
+                    && is_idempotent(method)
+                    && attempt < self.max_hinted_retries.load(Ordering::Relaxed)
+                    && before_deadline(delay)
+                {
+                    attempt += 1;
+                    tracing::debug!(
+                        method = %method,
+                        attempt,
+                        delay_ms = delay.as_millis() as u64,
+                        "Server requested backoff, retrying"
+                    );
+                    tokio::time::sleep(delay).await;
+                    continue;
+                }
+                let backoff = self
+                    .error_code_retry
+                    .lock()
+                    .as_ref()
+                    .filter(|config| config.should_retry_code(error.code, attempt))
+                    .map(|config| config.calculate_delay(attempt + 1))
+                    .filter(|delay| before_deadline(*delay));
+                if let Some(delay) = backoff {
+                    attempt += 1;
+                    tracing::debug!(
+                        method = %method,
+                        attempt,
+                        code = error.code,
+                        delay_ms = delay.as_millis() as u64,
+                        "Retriable error code, retrying"
+                    );
+                    tokio::time::sleep(delay).await;
+                    continue;
+                }
+                return Err(rpc_error(method, error));
+            }
+
+            // Deserialize result
+            return serde_json::from_value(response.result().unwrap_or_default().clone())
+                .map_err(|e| Error::internal(format!("Failed to deserialize response: {e}")));
+        }
+    }
+
+    /// Send a single request and wait for its raw JSON-RPC response
+    ///
+    /// The wait is bounded by the configured request timeout and `deadline`,
+    /// whichever comes first; either one cancels the request on expiry.
+    async fn send_and_wait(
+        &self,
+        method: &str,
+        params: Option<serde_json::Value>,
+        deadline: Option<tokio::time::Instant>,
+    ) -> Result<JsonRpcResponse> {
         // Generate unique request ID
         let id = self.next_id.fetch_add(1, Ordering::Relaxed);
         let request_id = turbomcp_protocol::MessageId::from(id.to_string());
@@ -181,11 +346,25 @@ impl<T: Transport + 'static> ProtocolClient<T> {
             .send(message)
             .await
             .map_err(|e| Error::transport(format!("Transport send failed: {e}")))?;
+        // A batching transport may still hold the request; push it out before
+        // waiting on the response.
+        self.transport
+            .flush()
+            .await
+            .map_err(|e| Error::transport(format!("Transport flush failed: {e}")))?;
 
         // Step 3: Wait for response via oneshot channel with request timeout
         // The dispatcher's background task will send the response when it arrives
-        let response = if let Some(request_timeout) = self.config.timeouts.request {
-            match tokio::time::timeout(request_timeout, response_receiver).await {
+        let until_deadline = deadline
+            .map(|deadline| deadline.saturating_duration_since(tokio::time::Instant::now()));
+        let (wait, by_deadline) = match (self.config.timeouts.request, until_deadline) {
+            (Some(timeout), Some(remaining)) if remaining < timeout => (Some(remaining), true),
+            (Some(timeout), _) => (Some(timeout), false),
+            (None, Some(remaining)) => (Some(remaining), true),
+            (None, None) => (None, false),
+        };
+        let response = if let Some(wait) = wait {
+            match tokio::time::timeout(wait, response_receiver).await {
                 Ok(Ok(response)) => response,
                 Ok(Err(_)) => {
                     return Err(Error::transport("Response channel closed".to_string()));
@@ -194,14 +373,22 @@ impl<T: Transport + 'static> ProtocolClient<T> {
                     // Best-effort `notifications/cancelled` so a compliant
                     // server can stop in-flight work. Failure to send is
                     // logged and ignored — the local timeout still wins.
-                    let _ = self
-                        .send_cancellation(&request_id, Some("client request timeout"))
-                        .await;
+                    let reason = if by_deadline {
+                        "client deadline exceeded"
+                    } else {
+                        "client request timeout"
+                    };
+                    let _ = self.send_cancellation(&request_id, Some(reason)).await;
+                    if by_deadline {
+                        return Err(Error::timeout(format!(
+                            "{method}() did not complete before the deadline"
+                        )));
+                    }
                     let err = turbomcp_transport::TransportError::RequestTimeout {
                         operation: format!("{}()", method),
-                        timeout: request_timeout,
+                        timeout: wait,
                     };
-                    return Err(Error::transport(err.to_string()));
+                    return Err(Error::timeout(err.to_string()));
                 }
             }
         } else {
@@ -213,14 +400,7 @@ impl<T: Transport + 'static> ProtocolClient<T> {
         // Response arrived — disarm the guard so it doesn't double-remove.
         waiter_guard.disarm();
 
-        // Handle JSON-RPC errors
-        if let Some(error) = response.error() {
-            return Err(Error::from_rpc_code(error.code, &error.message));
-        }
-
-        // Deserialize result
-        serde_json::from_value(response.result().unwrap_or_default().clone())
-            .map_err(|e| Error::internal(format!("Failed to deserialize response: {e}")))
+        Ok(response)
     }
 
     /// Send a `notifications/cancelled` notification for the given request id.
@@ -289,8 +469,65 @@ impl<T: Transport + 'static> ProtocolClient<T> {
     }
 }
 
+/// Convert a JSON-RPC error response into an [`Error`].
+///
+/// The server's exact code and structured `data` are kept on the error; read
+/// them back with [`Error::rpc_code`] and [`Error::data`].
+///
+/// Failed `initialize` handshakes carry structured diagnostics in `data`
+/// (what the client offered and what the server required); fold them into the
+/// message so the caller sees why the handshake was refused.
+fn rpc_error(method: &str, error: &JsonRpcError) -> Error {
+    let message = match (method, &error.data) {
+        ("initialize", Some(data)) => describe_initialize_failure(&error.message, data),
+        _ => error.message.clone(),
+    };
+    Error::from_rpc_error(error.code, message, error.data.clone())
+}
+
+fn describe_initialize_failure(message: &str, data: &serde_json::Value) -> String {
+    let detail = match data.get("reason").and_then(|reason| reason.as_str()) {
+        Some("missingCapabilities") => format!(
+            "server requires [{}]; client offered [{}]",
+            capability_names(&data["required"]).join(", "),
+            capability_names(&data["offered"]).join(", ")
+        ),
+        Some("unsupportedProtocolVersion") => format!(
+            "client requested {}; server supports [{}]",
+            data["requested"].as_str().unwrap_or("no version"),
+            string_list(&data["supported"]).join(", ")
+        ),
+        _ => return message.to_string(),
+    };
+    format!("Initialize failed: {message} ({detail})")
+}
+
+/// Names of the capabilities enabled in a `{roots, sampling, extensions,
+/// experimental}` summary, e.g. `["roots", "extensions/trace"]`.
+fn capability_names(caps: &serde_json::Value) -> Vec<String> {
+    let mut names: Vec<String> = ["roots", "sampling"]
+        .into_iter()
+        .filter(|name| caps[*name].as_bool() == Some(true))
+        .map(str::to_string)
+        .collect();
+    for group in ["extensions", "experimental"] {
+        let mut members = string_list(&caps[group]);
+        members.sort_unstable();
+        names.extend(members.into_iter().map(|name| format!("{group}/{name}")));
+    }
+    names
+}
+
+fn string_list(value: &serde_json::Value) -> Vec<&str> {
+    value
+        .as_array()
+        .map(|items| items.iter().filter_map(|item| item.as_str()).collect())
+        .unwrap_or_default()
+}
+
 #[cfg(test)]
 mod tests {
+    use super::super::test_transport::{Reply, ScriptedTransport};
     use super::*;
     use std::pin::Pin;
     use std::sync::atomic::{AtomicBool, Ordering};
@@ -376,14 +613,246 @@ mod tests {
         }
     }
 
+    /// Rejects the first `failures` requests with `error`, then succeeds.
+    fn failing_transport(failures: u64, error: serde_json::Value) -> ScriptedTransport {
+        let attempts = AtomicU64::new(0);
+        ScriptedTransport::new(move |_, _| {
+            if attempts.fetch_add(1, Ordering::SeqCst) < failures {
+                Reply::Error(error.clone())
+            } else {
+                Reply::Result(
+                    serde_json::json!({ "ok": true, "_meta": { "rateLimitRemaining": 9 } }),
+                )
+            }
+        })
+    }
+
+    /// Rejects the first request with a `_meta` backoff hint.
+    fn backoff_transport() -> ScriptedTransport {
+        failing_transport(
+            1,
+            serde_json::json!({
+                "code": -32000,
+                "message": "rate limited",
+                "data": { "_meta": { "retryAfterMs": 200 } }
+            }),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_retry_waits_for_server_backoff_hint() {
+        let client = ProtocolClient::with_config(backoff_transport(), TransportConfig::default());
+
+        let started = std::time::Instant::now();
+        let result: serde_json::Value = client.request("tools/list", None).await.unwrap();
+
+        assert_eq!(result["ok"], serde_json::json!(true));
+        assert!(started.elapsed() >= Duration::from_millis(200));
+        assert_eq!(client.transport().sent().len(), 2);
+        assert_eq!(client.last_meta().unwrap().rate_limit_remaining, Some(9));
+
+        client.dispatcher.shutdown();
+    }
+
+    #[tokio::test]
+    async fn test_backoff_hint_not_retried_for_tool_calls() {
+        let client = ProtocolClient::with_config(backoff_transport(), TransportConfig::default());
+
+        let result: Result<serde_json::Value> = client
+            .request("tools/call", Some(serde_json::json!({ "name": "charge" })))
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(client.transport().sent().len(), 1);
+        assert_eq!(
+            client.last_meta().unwrap().retry_after,
+            Some(Duration::from_millis(200))
+        );
+
+        client.dispatcher.shutdown();
+    }
+
+    #[tokio::test]
+    async fn test_backoff_hint_not_retried_when_disabled() {
+        let client = ProtocolClient::with_config(backoff_transport(), TransportConfig::default());
+        client.set_max_hinted_retries(0);
+
+        let result: Result<serde_json::Value> = client.request("tools/list", None).await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            client.last_meta().unwrap().retry_after,
+            Some(Duration::from_millis(200))
+        );
+
+        client.dispatcher.shutdown();
+    }
+
+    fn overloaded_transport(failures: u64) -> ScriptedTransport {
+        failing_transport(
+            failures,
+            serde_json::json!({ "code": -32010, "message": "server overloaded" }),
+        )
+    }
+
+    fn overloaded_retry_config() -> RetryConfig {
+        let mut config = RetryConfig::default().with_retriable_error_codes([-32010]);
+        config.base_delay = Duration::from_millis(10);
+        config.jitter_factor = 0.0;
+        config
+    }
+
+    #[tokio::test]
+    async fn test_initialize_failure_names_conflicting_capability() {
+        let client = ProtocolClient::with_config(
+            failing_transport(
+                1,
+                serde_json::json!({
+                    "code": -32600,
+                    "message": "Missing required client capabilities: sampling",
+                    "data": {
+                        "reason": "missingCapabilities",
+                        "missing": ["sampling"],
+                        "required": {
+                            "roots": true, "sampling": true,
+                            "extensions": [], "experimental": []
+                        },
+                        "offered": {
+                            "roots": true, "sampling": false,
+                            "extensions": [], "experimental": []
+                        }
+                    }
+                }),
+            ),
+            TransportConfig::default(),
+        );
+
+        let err = client
+            .request::<serde_json::Value>("initialize", None)
+            .await
+            .unwrap_err();
+
+        let message = err.to_string();
+        assert!(message.contains("capabilities: sampling"), "{message}");
+        assert!(
+            message.contains("server requires [roots, sampling]; client offered [roots]"),
+            "{message}"
+        );
+        assert_eq!(err.jsonrpc_code(), -32600);
+
+        client.dispatcher.shutdown();
+    }
+
+    #[test]
+    fn test_initialize_version_diagnostics_are_described() {
+        let error = JsonRpcError {
+            code: -32600,
+            message: "Unsupported protocol version: 1999-01-01".to_string(),
+            data: Some(serde_json::json!({
+                "reason": "unsupportedProtocolVersion",
+                "requested": "1999-01-01",
+                "supported": ["2025-06-18", "2025-11-25"]
+            })),
+        };
+        let message = rpc_error("initialize", &error).to_string();
+        assert!(
+            message
+                .contains("client requested 1999-01-01; server supports [2025-06-18, 2025-11-25]"),
+            "{message}"
+        );
+
+        // Other methods keep the server's message as-is.
+        assert!(
+            !rpc_error("tools/list", &error)
+                .to_string()
+                .contains("server supports")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_server_error_code_and_data_are_exposed() {
+        let data = serde_json::json!({
+            "fields": [{ "field": "email", "message": "must contain '@'" }],
+            "retryable": false
+        });
+        let client = ProtocolClient::with_config(
+            failing_transport(
+                1,
+                serde_json::json!({
+                    "code": -32050,
+                    "message": "Validation failed",
+                    "data": data.clone()
+                }),
+            ),
+            TransportConfig::default(),
+        );
+
+        let err = client
+            .request::<serde_json::Value>("tools/call", None)
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.message, "Validation failed");
+        assert_eq!(err.rpc_code(), -32050);
+        assert_eq!(err.data(), Some(&data));
+        assert_eq!(err.data().unwrap()["fields"][0]["field"], "email");
+
+        client.dispatcher.shutdown();
+    }
+
+    #[tokio::test]
+    async fn test_retriable_error_code_retried_until_success() {
+        let client =
+            ProtocolClient::with_config(overloaded_transport(2), TransportConfig::default());
+        client.set_error_code_retry(overloaded_retry_config());
+
+        let started = std::time::Instant::now();
+        let result: serde_json::Value = client.request("tools/list", None).await.unwrap();
+
+        assert_eq!(result["ok"], serde_json::json!(true));
+        assert_eq!(client.transport().sent().len(), 3);
+        // 10ms, then 20ms of exponential backoff
+        assert!(started.elapsed() >= Duration::from_millis(30));
+
+        client.dispatcher.shutdown();
+    }
+
+    #[tokio::test]
+    async fn test_error_code_not_retried_unless_configured() {
+        let client =
+            ProtocolClient::with_config(overloaded_transport(2), TransportConfig::default());
+
+        let result: Result<serde_json::Value> = client.request("tools/list", None).await;
+
+        assert_eq!(result.unwrap_err().jsonrpc_code(), -32010);
+        assert_eq!(client.transport().sent().len(), 1);
+
+        client.dispatcher.shutdown();
+    }
+
+    #[tokio::test]
+    async fn test_error_code_retries_bounded_by_max_attempts() {
+        let client =
+            ProtocolClient::with_config(overloaded_transport(10), TransportConfig::default());
+        let mut config = overloaded_retry_config();
+        config.max_attempts = 2;
+        client.set_error_code_retry(config);
+
+        let result: Result<serde_json::Value> = client.request("tools/list", None).await;
+
+        assert!(result.is_err());
+        assert_eq!(client.transport().sent().len(), 3);
+
+        client.dispatcher.shutdown();
+    }
+
     #[tokio::test]
     async fn test_request_timeout_cleans_up_waiter() {
+        let mut timeouts = turbomcp_transport::config::TimeoutConfig::default();
+        timeouts.request = Some(Duration::from_millis(10));
+        timeouts.total = Some(Duration::from_millis(25));
         let config = TransportConfig {
-            timeouts: turbomcp_transport::config::TimeoutConfig {
-                request: Some(Duration::from_millis(10)),
-                total: Some(Duration::from_millis(25)),
-                ..Default::default()
-            },
+            timeouts,
             ..Default::default()
         };
         let client = ProtocolClient::with_config(MockTransport::ok(), config);
@@ -395,6 +864,27 @@ mod tests {
         client.dispatcher.shutdown();
     }
 
+    #[tokio::test]
+    async fn test_request_timeout_yields_timeout_error() {
+        let mut timeouts = turbomcp_transport::config::TimeoutConfig::default();
+        timeouts.request = Some(Duration::from_millis(10));
+        timeouts.total = None;
+        let config = TransportConfig {
+            timeouts,
+            ..Default::default()
+        };
+        let client = ProtocolClient::with_config(MockTransport::ok(), config);
+
+        let err = client
+            .request::<serde_json::Value>("tools/list", None)
+            .await
+            .unwrap_err();
+        assert!(err.is_timeout(), "expected timeout, got {err:?}");
+        assert_eq!(err.kind, turbomcp_protocol::ErrorKind::Timeout);
+
+        client.dispatcher.shutdown();
+    }
+
     #[tokio::test]
     async fn test_send_failure_cleans_up_waiter() {
         let client =
@@ -0,0 +1,166 @@
+//! Scripted in-memory transport shared by the client unit tests
+//!
+//! [`ScriptedTransport`] plays the server side of a connection: every request
+//! the client sends is answered by a responder closure keyed on the method
+//! name, every outgoing message is recorded, and tests can queue
+//! server-originated messages with [`ScriptedTransport::push_incoming`].
+
+use parking_lot::Mutex;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use turbomcp_protocol::MessageId;
+use turbomcp_transport::{
+    Transport, TransportCapabilities, TransportMessage, TransportMetrics, TransportResult,
+    TransportState, TransportType,
+};
+
+/// How the scripted server answers a request.
+#[derive(Debug, Clone)]
+pub(crate) enum Reply {
+    /// Answer with a JSON-RPC `result`.
+    Result(serde_json::Value),
+    /// Answer with a JSON-RPC `error` object.
+    Error(serde_json::Value),
+    /// Never answer, e.g. to exercise timeouts and cancellation.
+    Silent,
+}
+
+type Responder = dyn Fn(&str, &serde_json::Value) -> Reply + Send + Sync;
+
+/// Transport whose server side is a responder closure.
+///
+/// The responder receives the method and params of each request; responses,
+/// notifications, and requests alike are appended to [`sent`](Self::sent).
+pub(crate) struct ScriptedTransport {
+    capabilities: TransportCapabilities,
+    responder: Box<Responder>,
+    incoming: Mutex<VecDeque<TransportMessage>>,
+    sent: Mutex<Vec<serde_json::Value>>,
+    peer_protocol_version: Mutex<Option<String>>,
+}
+
+impl ScriptedTransport {
+    /// Answer each request with `responder(method, params)`.
+    pub(crate) fn new(
+        responder: impl Fn(&str, &serde_json::Value) -> Reply + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            capabilities: TransportCapabilities::default(),
+            responder: Box::new(responder),
+            incoming: Mutex::new(VecDeque::new()),
+            sent: Mutex::new(Vec::new()),
+            peer_protocol_version: Mutex::new(None),
+        }
+    }
+
+    /// Queue a server-originated message for the client to receive.
+    pub(crate) fn push_incoming(&self, message: serde_json::Value) {
+        self.incoming.lock().push_back(TransportMessage::new(
+            MessageId::from("incoming"),
+            serde_json::to_vec(&message).expect("json message").into(),
+        ));
+    }
+
+    /// Every message the client has sent, in order.
+    pub(crate) fn sent(&self) -> Vec<serde_json::Value> {
+        self.sent.lock().clone()
+    }
+
+    /// Methods of the requests and notifications the client has sent.
+    pub(crate) fn sent_methods(&self) -> Vec<String> {
+        self.sent
+            .lock()
+            .iter()
+            .filter_map(|message| message["method"].as_str().map(str::to_string))
+            .collect()
+    }
+
+    /// Params of every message the client has sent with `method`.
+    pub(crate) fn sent_params(&self, method: &str) -> Vec<serde_json::Value> {
+        self.sent
+            .lock()
+            .iter()
+            .filter(|message| message["method"] == method)
+            .map(|message| message["params"].clone())
+            .collect()
+    }
+}
+
+impl Default for ScriptedTransport {
+    /// A server that never answers.
+    fn default() -> Self {
+        Self::new(|_, _| Reply::Silent)
+    }
+}
+
+impl std::fmt::Debug for ScriptedTransport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ScriptedTransport")
+            .field("sent", &self.sent.lock().len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Transport for ScriptedTransport {
+    fn transport_type(&self) -> TransportType {
+        TransportType::Stdio
+    }
+
+    fn capabilities(&self) -> &TransportCapabilities {
+        &self.capabilities
+    }
+
+    fn state(&self) -> Pin<Box<dyn Future<Output = TransportState> + Send + '_>> {
+        Box::pin(async { TransportState::Connected })
+    }
+
+    fn connect(&self) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn disconnect(&self) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn send(
+        &self,
+        message: TransportMessage,
+    ) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
+        let message: serde_json::Value =
+            serde_json::from_slice(&message.payload).expect("json message");
+        self.sent.lock().push(message.clone());
+
+        // Only requests (a method and an id) get an answer.
+        if let (Some(method), false) = (message["method"].as_str(), message["id"].is_null()) {
+            let (field, value) = match (self.responder)(method, &message["params"]) {
+                Reply::Result(result) => ("result", result),
+                Reply::Error(error) => ("error", error),
+                Reply::Silent => return Box::pin(async { Ok(()) }),
+            };
+            let mut response = serde_json::json!({ "jsonrpc": "2.0", "id": message["id"] });
+            response[field] = value;
+            self.push_incoming(response);
+        }
+        Box::pin(async { Ok(()) })
+    }
+
+    fn receive(
+        &self,
+    ) -> Pin<Box<dyn Future<Output = TransportResult<Option<TransportMessage>>> + Send + '_>> {
+        let message = self.incoming.lock().pop_front();
+        Box::pin(async move { Ok(message) })
+    }
+
+    fn metrics(&self) -> Pin<Box<dyn Future<Output = TransportMetrics> + Send + '_>> {
+        Box::pin(async { TransportMetrics::default() })
+    }
+
+    fn peer_protocol_version(&self) -> Option<String> {
+        self.peer_protocol_version.lock().clone()
+    }
+
+    fn set_peer_protocol_version(&self, version: &str) {
+        *self.peer_protocol_version.lock() = Some(version.to_string());
+    }
+}
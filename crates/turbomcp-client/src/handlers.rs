@@ -270,12 +270,47 @@ impl ElicitationRequest {
     /// Deserialize the requested schema into a typed [`turbomcp_protocol::types::ElicitationSchema`].
     ///
     /// Returns `None` for URL-mode elicitations or on deserialization failure.
+    /// The typed schema has no representation for SEP-1330 enum fields
+    /// (`oneOf` / multi-select arrays); use [`fields`](Self::fields) to render
+    /// a form from the complete schema.
     #[must_use]
     pub fn schema(&self) -> Option<turbomcp_protocol::types::ElicitationSchema> {
         let raw = self.requested_schema()?;
         serde_json::from_value(raw.clone()).ok()
     }
 
+    /// Describe the requested form as a list of fields for rendering a UI.
+    ///
+    /// Read from the raw `requestedSchema`, so every field keeps its type,
+    /// title, description, default, enum options (legacy `enum`/`enumNames`
+    /// and SEP-1330 `oneOf`/`anyOf`), and whether it is required. Fields are
+    /// returned in schema property order.
+    ///
+    /// Returns `None` for URL-mode elicitations or a schema without
+    /// `properties`.
+    #[must_use]
+    pub fn fields(&self) -> Option<Vec<ElicitationField>> {
+        let schema = self.requested_schema()?;
+        let properties = schema.get("properties")?.as_object()?;
+        let required: Vec<&str> = schema
+            .get("required")
+            .and_then(serde_json::Value::as_array)
+            .map(|names| names.iter().filter_map(serde_json::Value::as_str).collect())
+            .unwrap_or_default();
+        Some(
+            properties
+                .iter()
+                .map(|(name, property)| {
+                    ElicitationField::from_property(
+                        name,
+                        property,
+                        required.contains(&name.as_str()),
+                    )
+                })
+                .collect(),
+        )
+    }
+
     /// Get access to underlying protocol parameters if needed
     ///
     /// For advanced use cases where you need the raw protocol type.
@@ -291,6 +326,148 @@ impl ElicitationRequest {
     }
 }
 
+/// Value type of an [`ElicitationField`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ElicitationFieldType {
+    /// Free text, or a single choice when the field has options
+    String,
+    /// Floating-point number
+    Number,
+    /// Whole number
+    Integer,
+    /// Checkbox / toggle
+    Boolean,
+    /// Multiple choice among the field's options
+    MultiSelect,
+    /// A `type` this client does not recognize (kept as-is)
+    Other,
+}
+
+/// One selectable option of an enum [`ElicitationField`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElicitationFieldOption {
+    /// Value sent back in the elicitation response
+    pub value: String,
+    /// Display label, when the schema provides one
+    pub label: Option<String>,
+}
+
+/// UI-oriented description of one field of a form elicitation
+///
+/// Produced by [`ElicitationRequest::fields`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElicitationField {
+    /// Property name (the key in the response content)
+    pub name: String,
+    /// Value type
+    pub field_type: ElicitationFieldType,
+    /// Human-readable title
+    pub title: Option<String>,
+    /// Help text
+    pub description: Option<String>,
+    /// Whether the schema lists this field as required
+    pub required: bool,
+    /// Default value, exactly as sent by the server
+    pub default: Option<serde_json::Value>,
+    /// Allowed values for enum fields; empty for free-form fields
+    pub options: Vec<ElicitationFieldOption>,
+    /// String `format` (`email`, `uri`, `date`, `date-time`)
+    pub format: Option<String>,
+    /// Lower bound: `minimum` for numbers, `minLength` for strings,
+    /// `minItems` for multi-selects
+    pub min: Option<f64>,
+    /// Upper bound: `maximum` for numbers, `maxLength` for strings,
+    /// `maxItems` for multi-selects
+    pub max: Option<f64>,
+}
+
+impl ElicitationField {
+    fn from_property(name: &str, property: &serde_json::Value, required: bool) -> Self {
+        let text = |key: &str| property.get(key).and_then(|v| v.as_str()).map(String::from);
+        let number = |key: &str| property.get(key).and_then(serde_json::Value::as_f64);
+
+        let field_type = match property.get("type").and_then(|v| v.as_str()) {
+            Some("string") => ElicitationFieldType::String,
+            Some("number") => ElicitationFieldType::Number,
+            Some("integer") => ElicitationFieldType::Integer,
+            Some("boolean") => ElicitationFieldType::Boolean,
+            Some("array") => ElicitationFieldType::MultiSelect,
+            _ => ElicitationFieldType::Other,
+        };
+        let (options, min, max) = match field_type {
+            ElicitationFieldType::MultiSelect => {
+                let items = property.get("items").unwrap_or(&serde_json::Value::Null);
+                (enum_options(items), number("minItems"), number("maxItems"))
+            }
+            ElicitationFieldType::String => (
+                enum_options(property),
+                number("minLength"),
+                number("maxLength"),
+            ),
+            _ => (Vec::new(), number("minimum"), number("maximum")),
+        };
+
+        Self {
+            name: name.to_string(),
+            field_type,
+            title: text("title"),
+            description: text("description"),
+            required,
+            default: property.get("default").cloned(),
+            options,
+            format: text("format"),
+            min,
+            max,
+        }
+    }
+}
+
+/// Collect enum options from `oneOf`/`anyOf` (`const` + `title`) or from
+/// `enum` with optional legacy `enumNames` labels.
+fn enum_options(schema: &serde_json::Value) -> Vec<ElicitationFieldOption> {
+    let titled = schema
+        .get("oneOf")
+        .or_else(|| schema.get("anyOf"))
+        .and_then(serde_json::Value::as_array);
+    if let Some(choices) = titled {
+        return choices
+            .iter()
+            .filter_map(|choice| {
+                Some(ElicitationFieldOption {
+                    value: choice.get("const")?.as_str()?.to_string(),
+                    label: choice
+                        .get("title")
+                        .and_then(|v| v.as_str())
+                        .map(String::from),
+                })
+            })
+            .collect();
+    }
+
+    let labels = schema
+        .get("enumNames")
+        .and_then(serde_json::Value::as_array);
+    schema
+        .get("enum")
+        .and_then(serde_json::Value::as_array)
+        .map(|values| {
+            values
+                .iter()
+                .enumerate()
+                .filter_map(|(i, value)| {
+                    Some(ElicitationFieldOption {
+                        value: value.as_str()?.to_string(),
+                        label: labels
+                            .and_then(|labels| labels.get(i))
+                            .and_then(|v| v.as_str())
+                            .map(String::from),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 // Re-export protocol action enum (no need to duplicate)
 pub use turbomcp_protocol::types::ElicitationAction;
 
@@ -651,6 +828,54 @@ pub trait RootsHandler: Send + Sync + std::fmt::Debug {
     ) -> Pin<Box<dyn Future<Output = HandlerResult<Vec<turbomcp_protocol::types::Root>>> + Send + '_>>;
 }
 
+/// Roots handler that answers `roots/list` with a fixed list
+///
+/// The default instance exposes no roots, which is also what the client
+/// answers when no roots handler is registered.
+/// [`Client::set_roots`](crate::Client::set_roots) installs one of these.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use turbomcp_client::handlers::{HandlerRegistry, StaticRootsHandler};
+/// use turbomcp_protocol::types::Root;
+///
+/// let mut registry = HandlerRegistry::new();
+/// registry.set_roots_handler(Arc::new(StaticRootsHandler::new(vec![Root {
+///     uri: "file:///workspace".into(),
+///     name: Some("workspace".to_string()),
+///     _meta: None,
+/// }])));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct StaticRootsHandler {
+    roots: Vec<turbomcp_protocol::types::Root>,
+}
+
+impl StaticRootsHandler {
+    /// Create a handler answering `roots/list` with `roots`
+    #[must_use]
+    pub fn new(roots: Vec<turbomcp_protocol::types::Root>) -> Self {
+        Self { roots }
+    }
+
+    /// The roots this handler returns
+    #[must_use]
+    pub fn roots(&self) -> &[turbomcp_protocol::types::Root] {
+        &self.roots
+    }
+}
+
+impl RootsHandler for StaticRootsHandler {
+    fn handle_roots_request(
+        &self,
+    ) -> Pin<Box<dyn Future<Output = HandlerResult<Vec<turbomcp_protocol::types::Root>>> + Send + '_>>
+    {
+        Box::pin(async move { Ok(self.roots.clone()) })
+    }
+}
+
 // ============================================================================
 // CANCELLATION HANDLER TRAIT
 // ============================================================================
@@ -899,6 +1124,57 @@ pub trait ProgressHandler: Send + Sync + std::fmt::Debug {
     ) -> Pin<Box<dyn Future<Output = HandlerResult<()>> + Send + '_>>;
 }
 
+// ============================================================================
+// CUSTOM NOTIFICATION HANDLER TRAIT
+// ============================================================================
+
+/// Handler for notifications outside the MCP specification
+///
+/// Servers may broadcast application-defined notifications such as
+/// `notifications/job/completed`. Any notification whose method the client
+/// does not recognize is routed here with its raw parameters.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use turbomcp_client::handlers::{CustomNotificationHandler, HandlerResult};
+/// use std::future::Future;
+/// use std::pin::Pin;
+///
+/// #[derive(Debug)]
+/// struct MyCustomHandler;
+///
+/// impl CustomNotificationHandler for MyCustomHandler {
+///     fn handle_custom_notification(
+///         &self,
+///         method: String,
+///         params: Option<serde_json::Value>,
+///     ) -> Pin<Box<dyn Future<Output = HandlerResult<()>> + Send + '_>> {
+///         Box::pin(async move {
+///             println!("{method}: {params:?}");
+///             Ok(())
+///         })
+///     }
+/// }
+/// ```
+pub trait CustomNotificationHandler: Send + Sync + std::fmt::Debug {
+    /// Handle a notification with an application-defined method
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - The notification method name
+    /// * `params` - The notification parameters, if any
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the notification was processed successfully.
+    fn handle_custom_notification(
+        &self,
+        method: String,
+        params: Option<serde_json::Value>,
+    ) -> Pin<Box<dyn Future<Output = HandlerResult<()>> + Send + '_>>;
+}
+
 // ============================================================================
 // HANDLER REGISTRY FOR CLIENT
 // ============================================================================
@@ -936,6 +1212,9 @@ pub struct HandlerRegistry {
 
     /// Progress handler for progress notifications
     pub progress: Option<Arc<dyn ProgressHandler>>,
+
+    /// Handler for application-defined notifications
+    pub custom_notification: Option<Arc<dyn CustomNotificationHandler>>,
 }
 
 impl HandlerRegistry {
@@ -1002,6 +1281,12 @@ impl HandlerRegistry {
         self.progress = Some(handler);
     }
 
+    /// Register a custom notification handler
+    pub fn set_custom_notification_handler(&mut self, handler: Arc<dyn CustomNotificationHandler>) {
+        debug!("Registering custom notification handler");
+        self.custom_notification = Some(handler);
+    }
+
     /// Check if a roots handler is registered
     #[must_use]
     pub fn has_roots_handler(&self) -> bool {
@@ -1080,6 +1365,18 @@ impl HandlerRegistry {
         self.progress.clone()
     }
 
+    /// Check if a custom notification handler is registered
+    #[must_use]
+    pub fn has_custom_notification_handler(&self) -> bool {
+        self.custom_notification.is_some()
+    }
+
+    /// Get the custom notification handler if registered
+    #[must_use]
+    pub fn get_custom_notification_handler(&self) -> Option<Arc<dyn CustomNotificationHandler>> {
+        self.custom_notification.clone()
+    }
+
     /// Handle a roots/list request from the server
     pub async fn handle_roots_request(&self) -> HandlerResult<Vec<turbomcp_protocol::types::Root>> {
         match &self.roots {
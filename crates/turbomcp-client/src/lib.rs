@@ -163,6 +163,7 @@ pub mod sampling;
 pub mod middleware;
 
 // Re-export key types for convenience
+pub use client::meta::ResponseMeta;
 pub use client::operations::tools::CallToolResponse;
 pub use client::{ConnectionInfo, ConnectionState, ManagerConfig, ServerGroup, SessionManager};
 
@@ -184,8 +185,13 @@ pub use handlers::{
     // Cancellation (current MCP spec)
     CancellationHandler,
     CancelledNotification,
+    // Application-defined notifications
+    CustomNotificationHandler,
     ElicitationAction,
     // Elicitation
+    ElicitationField,
+    ElicitationFieldOption,
+    ElicitationFieldType,
     ElicitationHandler,
     ElicitationRequest,
     ElicitationResponse,
@@ -206,6 +212,7 @@ pub use handlers::{
     ResourceUpdatedNotification,
     // Roots
     RootsHandler,
+    StaticRootsHandler,
     ToolListChangedHandler,
 };
 
@@ -550,11 +557,14 @@ fn protocol_transport_config(
     turbomcp_transport::TransportConfig {
         connect_timeout: timeout,
         keep_alive: Some(Duration::from_millis(connection_config.keepalive_ms)),
-        timeouts: turbomcp_transport::config::TimeoutConfig {
-            connect: timeout,
-            request: Some(timeout),
-            total: Some(timeout),
-            read: Some(timeout),
+        timeouts: {
+            let mut timeouts =
+                turbomcp_transport::config::TimeoutConfig::default().with_write(Some(timeout));
+            timeouts.connect = timeout;
+            timeouts.request = Some(timeout);
+            timeouts.total = Some(timeout);
+            timeouts.read = Some(timeout);
+            timeouts
         },
         ..Default::default()
     }
@@ -637,11 +647,14 @@ pub struct ClientBuilder {
     log_handler: Option<Arc<dyn crate::handlers::LogHandler>>,
     resource_update_handler: Option<Arc<dyn crate::handlers::ResourceUpdateHandler>>,
     progress_handler: Option<Arc<dyn crate::handlers::ProgressHandler>>,
+    custom_notification_handler: Option<Arc<dyn crate::handlers::CustomNotificationHandler>>,
     // Robustness configuration
     enable_resilience: bool,
     retry_config: Option<turbomcp_transport::resilience::RetryConfig>,
     circuit_breaker_config: Option<turbomcp_transport::resilience::CircuitBreakerConfig>,
     health_check_config: Option<turbomcp_transport::resilience::HealthCheckConfig>,
+    ordered_responses: bool,
+    output_schema_validation: bool,
 }
 
 // Default implementation is now derived
@@ -773,6 +786,10 @@ impl ClientBuilder {
 
     /// Set maximum retry attempts
     ///
+    /// Also bounds how many times the client retries an idempotent request
+    /// whose error response carries a server backoff hint in `_meta` (see
+    /// [`ResponseMeta`]). `tools/call` is never retried on a hint.
+    ///
     /// # Arguments
     ///
     /// * `max_retries` - Maximum number of retries
@@ -804,6 +821,32 @@ impl ClientBuilder {
         self
     }
 
+    /// Deliver responses in the order their requests were sent
+    ///
+    /// See [`Client::set_ordered_responses`].
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to hold responses that arrive out of order
+    #[must_use]
+    pub fn with_ordered_responses(mut self, enabled: bool) -> Self {
+        self.ordered_responses = enabled;
+        self
+    }
+
+    /// Validate tool results against the tool's declared output schema
+    ///
+    /// See [`Client::set_output_schema_validation`].
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to reject `structured_content` that violates the schema
+    #[must_use]
+    pub fn with_output_schema_validation(mut self, enabled: bool) -> Self {
+        self.output_schema_validation = enabled;
+        self
+    }
+
     // ============================================================================
     // ROBUSTNESS & RESILIENCE CONFIGURATION
     // ============================================================================
@@ -838,6 +881,10 @@ impl ClientBuilder {
     ///
     /// * `config` - Retry configuration
     ///
+    /// Error responses whose JSON-RPC code is listed in
+    /// `retriable_error_codes` are retried by the client with the same
+    /// backoff, up to `max_attempts` times.
+    ///
     /// # Examples
     ///
     /// ```rust,no_run
@@ -846,18 +893,14 @@ impl ClientBuilder {
     /// use turbomcp_transport::stdio::StdioTransport;
     /// use std::time::Duration;
     ///
+    /// let mut retry = RetryConfig::default().with_retriable_error_codes([-32010]); // SERVER_OVERLOADED
+    /// retry.max_attempts = 5;
+    /// retry.base_delay = Duration::from_millis(100);
+    /// retry.max_delay = Duration::from_secs(30);
+    ///
     /// let client = ClientBuilder::new()
     ///     .enable_resilience()
-    ///     .with_retry_config(RetryConfig {
-    ///         max_attempts: 5,
-    ///         base_delay: Duration::from_millis(100),
-    ///         max_delay: Duration::from_secs(30),
-    ///         backoff_multiplier: 2.0,
-    ///         jitter_factor: 0.1,
-    ///         retry_on_connection_error: true,
-    ///         retry_on_timeout: true,
-    ///         custom_retry_conditions: Vec::new(),
-    ///     })
+    ///     .with_retry_config(retry)
     ///     .build(StdioTransport::new());
     /// ```
     #[must_use]
@@ -993,6 +1036,19 @@ impl ClientBuilder {
         self
     }
 
+    /// Register a handler for application-defined notifications
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - The custom notification handler implementation
+    pub fn with_custom_notification_handler(
+        mut self,
+        handler: Arc<dyn crate::handlers::CustomNotificationHandler>,
+    ) -> Self {
+        self.custom_notification_handler = Some(handler);
+        self
+    }
+
     // ============================================================================
     // BUILD METHODS
     // ============================================================================
@@ -1039,6 +1095,9 @@ impl ClientBuilder {
             self.capabilities,
             protocol_transport_config(&self.connection_config),
         );
+        client.set_max_hinted_retries(self.connection_config.max_retries);
+        client.set_ordered_responses(self.ordered_responses);
+        client.set_output_schema_validation(self.output_schema_validation);
 
         // Register handlers
         if let Some(handler) = self.elicitation_handler {
@@ -1053,6 +1112,9 @@ impl ClientBuilder {
         if let Some(handler) = self.progress_handler {
             client.set_progress_handler(handler);
         }
+        if let Some(handler) = self.custom_notification_handler {
+            client.set_custom_notification_handler(handler);
+        }
 
         Ok(client)
     }
@@ -1087,12 +1149,12 @@ impl ClientBuilder {
     /// use std::time::Duration;
     ///
     /// # async fn example() -> turbomcp_protocol::Result<()> {
+    /// let mut retry = RetryConfig::default();
+    /// retry.max_attempts = 5;
+    /// retry.base_delay = Duration::from_millis(200);
+    ///
     /// let client = ClientBuilder::new()
-    ///     .with_retry_config(RetryConfig {
-    ///         max_attempts: 5,
-    ///         base_delay: Duration::from_millis(200),
-    ///         ..Default::default()
-    ///     })
+    ///     .with_retry_config(retry)
     ///     .with_circuit_breaker_config(CircuitBreakerConfig {
     ///         failure_threshold: 3,
     ///         timeout: Duration::from_secs(30),
@@ -1115,13 +1177,13 @@ impl ClientBuilder {
         use turbomcp_transport::resilience::TurboTransport;
 
         // Get configurations or use defaults
-        let retry_config =
-            self.retry_config
-                .unwrap_or_else(|| turbomcp_transport::resilience::RetryConfig {
-                    max_attempts: self.connection_config.max_retries.max(1),
-                    base_delay: Duration::from_millis(self.connection_config.retry_delay_ms),
-                    ..Default::default()
-                });
+        let retry_config = self.retry_config.unwrap_or_else(|| {
+            let mut config = turbomcp_transport::resilience::RetryConfig::default();
+            config.max_attempts = self.connection_config.max_retries.max(1);
+            config.base_delay = Duration::from_millis(self.connection_config.retry_delay_ms);
+            config
+        });
+        let error_code_retry = retry_config.clone();
         let circuit_config = self.circuit_breaker_config.unwrap_or_default();
         let health_config = self.health_check_config.unwrap_or_else(|| {
             turbomcp_transport::resilience::HealthCheckConfig {
@@ -1144,6 +1206,10 @@ impl ClientBuilder {
             self.capabilities,
             protocol_transport_config(&self.connection_config),
         );
+        client.set_max_hinted_retries(self.connection_config.max_retries);
+        client.set_ordered_responses(self.ordered_responses);
+        client.set_output_schema_validation(self.output_schema_validation);
+        client.set_error_code_retry(error_code_retry);
 
         // Register handlers
         if let Some(handler) = self.elicitation_handler {
@@ -1158,6 +1224,9 @@ impl ClientBuilder {
         if let Some(handler) = self.progress_handler {
             client.set_progress_handler(handler);
         }
+        if let Some(handler) = self.custom_notification_handler {
+            client.set_custom_notification_handler(handler);
+        }
 
         Ok(client)
     }
@@ -1195,6 +1264,9 @@ impl ClientBuilder {
             self.capabilities,
             protocol_transport_config(&self.connection_config),
         );
+        client.set_max_hinted_retries(self.connection_config.max_retries);
+        client.set_ordered_responses(self.ordered_responses);
+        client.set_output_schema_validation(self.output_schema_validation);
 
         // Register synchronous handlers only
         if let Some(handler) = self.elicitation_handler {
@@ -1209,6 +1281,9 @@ impl ClientBuilder {
         if let Some(handler) = self.progress_handler {
             client.set_progress_handler(handler);
         }
+        if let Some(handler) = self.custom_notification_handler {
+            client.set_custom_notification_handler(handler);
+        }
 
         client
     }
@@ -1236,6 +1311,7 @@ impl ClientBuilder {
             || self.log_handler.is_some()
             || self.resource_update_handler.is_some()
             || self.progress_handler.is_some()
+            || self.custom_notification_handler.is_some()
     }
 }
 
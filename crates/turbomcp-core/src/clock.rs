@@ -0,0 +1,173 @@
+//! Pluggable time source.
+//!
+//! Time-dependent logic (DPoP proof lifetimes, session expiry, rate-limit
+//! windows, retry backoff) reads the current time through the [`Clock`] trait
+//! instead of calling `SystemTime::now()` / `Instant::now()` directly. Production
+//! code uses [`SystemClock`]; tests inject a [`MockClock`] and advance it
+//! explicitly, so expiry paths can be exercised without real sleeping.
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use turbomcp_core::clock::{Clock, MockClock};
+//!
+//! let clock = MockClock::new();
+//! let start = clock.instant();
+//! clock.advance(Duration::from_secs(90));
+//! assert_eq!(clock.instant() - start, Duration::from_secs(90));
+//! ```
+
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// A source of wall-clock and monotonic time.
+pub trait Clock: Send + Sync + fmt::Debug {
+    /// Current wall-clock time.
+    fn now(&self) -> SystemTime;
+
+    /// Current monotonic time, for measuring elapsed durations.
+    fn instant(&self) -> Instant;
+
+    /// Current wall-clock time as seconds since the Unix epoch.
+    ///
+    /// Returns `0` if the clock reads earlier than the epoch.
+    fn unix_timestamp(&self) -> i64 {
+        self.now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs() as i64)
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for Arc<C> {
+    fn now(&self) -> SystemTime {
+        (**self).now()
+    }
+
+    fn instant(&self) -> Instant {
+        (**self).instant()
+    }
+
+    fn unix_timestamp(&self) -> i64 {
+        (**self).unix_timestamp()
+    }
+}
+
+/// The real system clock. This is the default everywhere a [`Clock`] is accepted.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl SystemClock {
+    /// Shared handle to the system clock, for fields typed `Arc<dyn Clock>`.
+    #[must_use]
+    pub fn shared() -> Arc<dyn Clock> {
+        Arc::new(Self)
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+
+    fn instant(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// A manually driven clock for tests.
+///
+/// Both [`Clock::now`] and [`Clock::instant`] start at the moment the clock was
+/// created (or at the wall-clock time given to [`MockClock::at`]) and only move
+/// when [`MockClock::advance`] is called. Clones share the same offset, so a
+/// clone handed to the code under test observes every `advance` made by the test.
+#[derive(Clone)]
+pub struct MockClock {
+    base_time: SystemTime,
+    base_instant: Instant,
+    offset_nanos: Arc<AtomicU64>,
+}
+
+impl MockClock {
+    /// Create a mock clock frozen at the current system time.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::at(SystemTime::now())
+    }
+
+    /// Create a mock clock frozen at the given wall-clock time.
+    #[must_use]
+    pub fn at(time: SystemTime) -> Self {
+        Self {
+            base_time: time,
+            base_instant: Instant::now(),
+            offset_nanos: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Move the clock forward by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        let nanos = u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX);
+        self.offset_nanos.fetch_add(nanos, Ordering::SeqCst);
+    }
+
+    /// Total time this clock has been advanced since creation.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_nanos(self.offset_nanos.load(Ordering::SeqCst))
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for MockClock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockClock")
+            .field("base_time", &self.base_time)
+            .field("elapsed", &self.elapsed())
+            .finish()
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> SystemTime {
+        self.base_time + self.elapsed()
+    }
+
+    fn instant(&self) -> Instant {
+        self.base_instant + self.elapsed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mock_clock_only_moves_when_advanced() {
+        let clock = MockClock::at(UNIX_EPOCH + Duration::from_secs(1_000));
+        let instant = clock.instant();
+        assert_eq!(clock.unix_timestamp(), 1_000);
+
+        clock.advance(Duration::from_secs(61));
+        assert_eq!(clock.unix_timestamp(), 1_061);
+        assert_eq!(clock.instant() - instant, Duration::from_secs(61));
+    }
+
+    #[test]
+    fn test_mock_clock_clones_share_offset() {
+        let clock = MockClock::new();
+        let shared: Arc<dyn Clock> = Arc::new(clock.clone());
+        let before = shared.now();
+
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(
+            shared.now().duration_since(before).unwrap(),
+            Duration::from_secs(5)
+        );
+    }
+}
@@ -33,7 +33,16 @@ use crate::session::Cancellable;
 #[cfg(feature = "std")]
 use std::time::Instant;
 
-use turbomcp_types::{ClientCapabilities, CreateMessageRequest, CreateMessageResult, ElicitResult};
+use turbomcp_types::{
+    ByteRange, ClientCapabilities, CreateMessageRequest, CreateMessageResult, ElicitAction,
+    ElicitResult,
+};
+
+/// `_meta` flag asking a tool to validate its call without executing it.
+///
+/// Only `#[tool(supports_dry_run)]` tools honor it; see
+/// [`RequestContext::is_dry_run`].
+pub const DRY_RUN_META: &str = "dry_run";
 
 /// Transport type identifier.
 ///
@@ -79,6 +88,20 @@ impl TransportType {
         matches!(self, Self::Stdio | Self::Unix | Self::Channel)
     }
 
+    /// Returns true if the transport can stream partial results (progress,
+    /// SSE events, line-delimited frames) while a request is in flight.
+    #[inline]
+    pub fn supports_streaming(&self) -> bool {
+        !matches!(self, Self::Wasm | Self::Unknown)
+    }
+
+    /// Returns true if the transport can carry server-initiated requests
+    /// (sampling, elicitation) back to the client.
+    #[inline]
+    pub fn supports_bidirectional(&self) -> bool {
+        !matches!(self, Self::Wasm | Self::Unknown)
+    }
+
     /// Returns the transport name as a string.
     pub fn as_str(&self) -> &'static str {
         match self {
@@ -100,6 +123,27 @@ impl core::fmt::Display for TransportType {
     }
 }
 
+/// What [`RequestContext::elicit_form`] does when the elicitation cannot be
+/// delivered to a client.
+///
+/// Elicitations fail to deliver when no bidirectional session is attached or
+/// when the client did not advertise form elicitation support.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Default, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ElicitationFallback {
+    /// Return the `capability_not_supported` error (default).
+    #[default]
+    Fail,
+    /// Resolve the elicitation locally from the schema's `default` values.
+    ///
+    /// Intended for automated and test runs. The result is `accept` with the
+    /// defaults as content when every required field has a default, and
+    /// `decline` otherwise.
+    UseDefaults,
+}
+
 /// Canonical per-request context.
 ///
 /// Carries request identity, transport information, authentication principal,
@@ -148,6 +192,20 @@ pub struct RequestContext {
     /// `hashbrown::HashMap` so it stays available in `no_std` / WASM builds.
     pub headers: Option<HashbrownMap<String, String>>,
 
+    /// Maximum message size in bytes accepted by the transport, if known.
+    pub max_message_size: Option<usize>,
+
+    /// Byte range requested by a `resources/read` call, if any.
+    ///
+    /// Resource handlers that support partial reads honor it; others ignore it.
+    pub byte_range: Option<ByteRange>,
+
+    /// `_meta` object sent with a `tools/call` request, if any.
+    pub request_meta: Option<Value>,
+
+    /// Behavior of form elicitations that cannot reach a client.
+    pub elicitation_fallback: ElicitationFallback,
+
     /// Wall-clock moment at which the server began processing the request.
     ///
     /// Used for `elapsed()` measurements and tracing spans.
@@ -161,6 +219,13 @@ pub struct RequestContext {
     /// in here (via the `Cancellable` blanket impl in `turbomcp-server`).
     #[cfg(feature = "std")]
     pub cancellation_token: Option<Arc<dyn Cancellable>>,
+
+    /// Longest a server-to-client request (sampling, elicitation) may wait
+    /// for the client's response before failing with a timeout.
+    ///
+    /// `None` waits until the client answers or the request is cancelled.
+    #[cfg(feature = "std")]
+    pub client_request_timeout: Option<core::time::Duration>,
 }
 
 // ====================================================================
@@ -320,6 +385,34 @@ impl RequestContext {
         self
     }
 
+    /// Record the transport's maximum accepted message size.
+    #[must_use]
+    pub fn with_max_message_size(mut self, max_message_size: usize) -> Self {
+        self.max_message_size = Some(max_message_size);
+        self
+    }
+
+    /// Attach the byte range requested by `resources/read`.
+    #[must_use]
+    pub fn with_byte_range(mut self, range: ByteRange) -> Self {
+        self.byte_range = Some(range);
+        self
+    }
+
+    /// Attach the `_meta` object sent with `tools/call`.
+    #[must_use]
+    pub fn with_request_meta(mut self, meta: Value) -> Self {
+        self.request_meta = Some(meta);
+        self
+    }
+
+    /// Set how undeliverable form elicitations are resolved.
+    #[must_use]
+    pub fn with_elicitation_fallback(mut self, fallback: ElicitationFallback) -> Self {
+        self.elicitation_fallback = fallback;
+        self
+    }
+
     /// Mark the request start time.
     #[cfg(feature = "std")]
     #[must_use]
@@ -335,6 +428,14 @@ impl RequestContext {
         self.cancellation_token = Some(token);
         self
     }
+
+    /// Bound how long sampling and elicitation wait for the client.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn with_client_request_timeout(mut self, timeout: core::time::Duration) -> Self {
+        self.client_request_timeout = Some(timeout);
+        self
+    }
 }
 
 // ====================================================================
@@ -386,6 +487,48 @@ impl RequestContext {
         self.transport
     }
 
+    /// Returns true if the transport can stream partial results for this request.
+    #[inline]
+    pub fn supports_streaming(&self) -> bool {
+        self.transport.supports_streaming()
+    }
+
+    /// Returns true if server-to-client requests (sampling, elicitation) can
+    /// be issued from this request: the transport must support them and a
+    /// session must be attached.
+    #[inline]
+    pub fn supports_bidirectional(&self) -> bool {
+        self.transport.supports_bidirectional() && self.session.is_some()
+    }
+
+    /// Maximum message size in bytes accepted by the transport, if known.
+    #[inline]
+    pub fn max_message_size(&self) -> Option<usize> {
+        self.max_message_size
+    }
+
+    /// Byte range requested by `resources/read`, if any.
+    #[inline]
+    pub fn byte_range(&self) -> Option<ByteRange> {
+        self.byte_range
+    }
+
+    /// `_meta` object sent with `tools/call`, if any.
+    #[inline]
+    pub fn request_meta(&self) -> Option<&Value> {
+        self.request_meta.as_ref()
+    }
+
+    /// Whether the `tools/call` `_meta` sets [`DRY_RUN_META`] to `true`.
+    #[inline]
+    pub fn is_dry_run(&self) -> bool {
+        self.request_meta
+            .as_ref()
+            .and_then(|meta| meta.get(DRY_RUN_META))
+            .and_then(Value::as_bool)
+            .unwrap_or(false)
+    }
+
     /// Authenticated user ID, if present.
     #[inline]
     pub fn user_id(&self) -> Option<&str> {
@@ -485,6 +628,32 @@ impl RequestContext {
             .is_some_and(|c| c.is_cancelled())
     }
 
+    /// Spawn a task whose lifetime is tied to this request's cancellation.
+    ///
+    /// The task runs on the current tokio runtime. If the request is
+    /// cancelled, the child is dropped at its next await point and the handle
+    /// resolves to `None`; otherwise it resolves to `Some(output)`. Without a
+    /// tokio-backed cancellation token the child runs to completion.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside a tokio runtime.
+    #[cfg(feature = "std")]
+    pub fn spawn_child<F>(&self, fut: F) -> tokio::task::JoinHandle<Option<F::Output>>
+    where
+        F: core::future::Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        match self
+            .cancellation_token
+            .as_ref()
+            .and_then(|c| c.cancellation_token())
+        {
+            Some(token) => tokio::spawn(token.run_until_cancelled_owned(fut)),
+            None => tokio::spawn(async move { Some(fut.await) }),
+        }
+    }
+
     /// Authenticated roles, sourced from the principal or from metadata.
     ///
     /// Looks at (in order): `principal.roles`, `metadata["auth"].roles[]`.
@@ -529,31 +698,51 @@ impl RequestContext {
     ///
     /// Requires a bidirectional session; returns
     /// [`McpError::capability_not_supported`] on unidirectional transports.
+    /// A request with `max_tokens` of 0 is rejected with
+    /// [`McpError::invalid_params`] before it is sent.
     pub async fn sample(&self, request: CreateMessageRequest) -> McpResult<CreateMessageResult> {
         let session = self.require_session("sampling/createMessage")?;
+        if request.max_tokens == 0 {
+            return Err(McpError::invalid_params(
+                "sampling/createMessage requires max_tokens greater than 0",
+            ));
+        }
         self.require_sampling_capability(session, &request).await?;
         let params = serde_json::to_value(request).map_err(|e| {
             McpError::invalid_params(alloc::format!("Failed to serialize sampling request: {e}"))
         })?;
-        let result = session.call("sampling/createMessage", params).await?;
+        let result = self
+            .call_client(session, "sampling/createMessage", params)
+            .await?;
         serde_json::from_value(result)
             .map_err(|e| McpError::internal(alloc::format!("Failed to parse sampling result: {e}")))
     }
 
     /// Request form-based user input from the client.
+    ///
+    /// If the elicitation cannot be delivered and the context's
+    /// [`ElicitationFallback`] is [`UseDefaults`](ElicitationFallback::UseDefaults),
+    /// it is resolved from the schema's default values instead of failing.
     pub async fn elicit_form(
         &self,
         message: impl Into<String>,
         schema: Value,
     ) -> McpResult<ElicitResult> {
-        let session = self.require_session("elicitation/create")?;
-        self.require_elicitation_capability(session, "form").await?;
+        let session = match self.require_session("elicitation/create") {
+            Ok(session) => session,
+            Err(e) => return self.elicit_fallback(&schema, e),
+        };
+        if let Err(e) = self.require_elicitation_capability(session, "form").await {
+            return self.elicit_fallback(&schema, e);
+        }
         let params = serde_json::json!({
             "mode": "form",
             "message": message.into(),
             "requestedSchema": schema,
         });
-        let result = session.call("elicitation/create", params).await?;
+        let result = self
+            .call_client(session, "elicitation/create", params)
+            .await?;
         serde_json::from_value(result).map_err(|e| {
             McpError::internal(alloc::format!("Failed to parse elicitation result: {e}"))
         })
@@ -574,7 +763,9 @@ impl RequestContext {
             "url": url.into(),
             "elicitationId": elicitation_id.into(),
         });
-        let result = session.call("elicitation/create", params).await?;
+        let result = self
+            .call_client(session, "elicitation/create", params)
+            .await?;
         serde_json::from_value(result).map_err(|e| {
             McpError::internal(alloc::format!("Failed to parse elicitation result: {e}"))
         })
@@ -586,6 +777,57 @@ impl RequestContext {
         session.notify(method.as_ref(), params).await
     }
 
+    /// Send a server-to-client request, giving up if this request is
+    /// cancelled first or the client does not answer within
+    /// [`client_request_timeout`](Self::client_request_timeout).
+    ///
+    /// Dropping the pending call lets the transport discard its correlation
+    /// entry and tell the client the request is no longer needed.
+    async fn call_client(
+        &self,
+        session: &Arc<dyn McpSession>,
+        method: &str,
+        params: Value,
+    ) -> McpResult<Value> {
+        #[cfg(feature = "std")]
+        let call = async {
+            let Some(limit) = self.client_request_timeout else {
+                return session.call(method, params).await;
+            };
+            tokio::time::timeout(limit, session.call(method, params))
+                .await
+                .unwrap_or_else(|_| {
+                    Err(McpError::timeout(alloc::format!(
+                        "{method} timed out after {}ms waiting for the client",
+                        limit.as_millis()
+                    )))
+                })
+        };
+        #[cfg(not(feature = "std"))]
+        let call = session.call(method, params);
+
+        #[cfg(feature = "std")]
+        if let Some(token) = self
+            .cancellation_token
+            .as_ref()
+            .and_then(|c| c.cancellation_token())
+        {
+            return token.run_until_cancelled(call).await.unwrap_or_else(|| {
+                Err(McpError::cancelled(alloc::format!(
+                    "{method} abandoned: request was cancelled"
+                )))
+            });
+        }
+        call.await
+    }
+
+    fn elicit_fallback(&self, schema: &Value, error: McpError) -> McpResult<ElicitResult> {
+        match self.elicitation_fallback {
+            ElicitationFallback::Fail => Err(error),
+            ElicitationFallback::UseDefaults => Ok(elicit_result_from_defaults(schema)),
+        }
+    }
+
     fn require_session(&self, op: &str) -> McpResult<&Arc<dyn McpSession>> {
         self.session.as_ref().ok_or_else(|| {
             McpError::capability_not_supported(alloc::format!(
@@ -664,6 +906,46 @@ fn client_supports_task_sampling(caps: &ClientCapabilities) -> bool {
         .is_some()
 }
 
+/// Resolve a form elicitation from the `default` of each schema property.
+///
+/// Accepts when at least one default exists and every required property has
+/// one; declines otherwise.
+fn elicit_result_from_defaults(schema: &Value) -> ElicitResult {
+    let decline = ElicitResult {
+        action: ElicitAction::Decline,
+        content: None,
+        meta: None,
+    };
+
+    let Some(properties) = schema.get("properties").and_then(Value::as_object) else {
+        return decline;
+    };
+    let defaults: serde_json::Map<String, Value> = properties
+        .iter()
+        .filter_map(|(name, prop)| Some((name.clone(), prop.get("default")?.clone())))
+        .collect();
+
+    let required_met = schema
+        .get("required")
+        .and_then(Value::as_array)
+        .is_none_or(|required| {
+            required
+                .iter()
+                .filter_map(Value::as_str)
+                .all(|name| defaults.contains_key(name))
+        });
+
+    if defaults.is_empty() || !required_met {
+        return decline;
+    }
+
+    ElicitResult {
+        action: ElicitAction::Accept,
+        content: Some(Value::Object(defaults)),
+        meta: None,
+    }
+}
+
 // ====================================================================
 // Tests
 // ====================================================================
@@ -697,6 +979,23 @@ mod tests {
         assert!(!TransportType::Http.is_local());
     }
 
+    #[test]
+    fn test_transport_capability_flags() {
+        let http = RequestContext::http().with_max_message_size(1024);
+        assert!(http.supports_streaming());
+        assert_eq!(http.max_message_size(), Some(1024));
+
+        let stdio = RequestContext::stdio();
+        assert!(stdio.supports_streaming());
+        assert!(TransportType::Stdio.supports_bidirectional());
+        // No session attached, so server-to-client requests are unavailable.
+        assert!(!stdio.supports_bidirectional());
+        assert_eq!(stdio.max_message_size(), None);
+
+        assert!(!TransportType::Wasm.supports_streaming());
+        assert!(!TransportType::Wasm.supports_bidirectional());
+    }
+
     #[test]
     fn test_request_context_new() {
         let ctx = RequestContext::with_id_and_transport("test-123", TransportType::Http);
@@ -791,6 +1090,38 @@ mod tests {
         assert_eq!(ctx.header("missing"), None);
     }
 
+    #[cfg(feature = "std")]
+    #[tokio::test]
+    async fn test_spawn_child_stops_when_request_cancelled() {
+        use core::sync::atomic::{AtomicBool, Ordering};
+
+        struct SetOnDrop(Arc<AtomicBool>);
+        impl Drop for SetOnDrop {
+            fn drop(&mut self) {
+                self.0.store(true, Ordering::SeqCst);
+            }
+        }
+
+        let token = tokio_util::sync::CancellationToken::new();
+        let ctx = RequestContext::new().with_cancellation_token(Arc::new(token.clone()));
+
+        let dropped = Arc::new(AtomicBool::new(false));
+        let guard = SetOnDrop(Arc::clone(&dropped));
+        let child = ctx.spawn_child(async move {
+            let _guard = guard;
+            core::future::pending::<()>().await;
+        });
+
+        token.cancel();
+        assert_eq!(child.await.unwrap(), None);
+        assert!(dropped.load(Ordering::SeqCst));
+
+        // Without cancellation the child's output is returned
+        let ctx = RequestContext::new()
+            .with_cancellation_token(Arc::new(tokio_util::sync::CancellationToken::new()));
+        assert_eq!(ctx.spawn_child(async { 7 }).await.unwrap(), Some(7));
+    }
+
     #[cfg(feature = "std")]
     #[tokio::test]
     async fn test_sampling_without_session_fails() {
@@ -802,4 +1133,121 @@ mod tests {
             .unwrap_err();
         assert_eq!(err.kind, crate::error::ErrorKind::CapabilityNotSupported);
     }
+
+    #[cfg(feature = "std")]
+    #[tokio::test]
+    async fn test_sampling_rejects_zero_max_tokens() {
+        use crate::session::SessionFuture;
+        use turbomcp_types::CreateMessageRequest;
+
+        #[derive(Debug)]
+        struct UnreachableClient;
+
+        impl McpSession for UnreachableClient {
+            fn call<'a>(&'a self, method: &'a str, _params: Value) -> SessionFuture<'a, Value> {
+                panic!("{method} should not reach the client")
+            }
+
+            fn notify<'a>(&'a self, _method: &'a str, _params: Value) -> SessionFuture<'a, ()> {
+                Box::pin(async { Ok(()) })
+            }
+        }
+
+        let ctx = RequestContext::stdio().with_session(Arc::new(UnreachableClient));
+        let request = CreateMessageRequest::builder().user("hello").build();
+        let err = ctx.sample(request).await.unwrap_err();
+        assert_eq!(err.kind, crate::error::ErrorKind::InvalidParams);
+        assert!(err.message.contains("max_tokens"), "{err}");
+    }
+
+    #[cfg(feature = "std")]
+    #[tokio::test]
+    async fn test_non_interactive_elicitation_resolves_from_defaults() {
+        let schema = serde_json::json!({
+            "type": "object",
+            "properties": {
+                "name": { "type": "string", "default": "ci-bot" },
+                "confirm": { "type": "boolean", "default": true },
+                "note": { "type": "string" }
+            },
+            "required": ["name", "confirm"]
+        });
+
+        // Default mode keeps failing without a session.
+        let err = RequestContext::stdio()
+            .elicit_form("Who are you?", schema.clone())
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind, crate::error::ErrorKind::CapabilityNotSupported);
+
+        let ctx =
+            RequestContext::stdio().with_elicitation_fallback(ElicitationFallback::UseDefaults);
+        let result = ctx.elicit_form("Who are you?", schema).await.unwrap();
+        assert_eq!(result.action, ElicitAction::Accept);
+        assert_eq!(
+            result.content,
+            Some(serde_json::json!({ "name": "ci-bot", "confirm": true }))
+        );
+
+        // A required field without a default cannot be answered.
+        let schema = serde_json::json!({
+            "type": "object",
+            "properties": { "token": { "type": "string" } },
+            "required": ["token"]
+        });
+        let result = ctx.elicit_form("Token?", schema).await.unwrap();
+        assert_eq!(result.action, ElicitAction::Decline);
+        assert!(result.content.is_none());
+    }
+
+    #[cfg(feature = "std")]
+    #[tokio::test]
+    async fn test_sample_times_out_against_silent_client() {
+        use crate::session::SessionFuture;
+        use core::sync::atomic::{AtomicUsize, Ordering};
+        use turbomcp_types::CreateMessageRequest;
+
+        /// Never answers; counts calls still awaiting a response.
+        #[derive(Debug, Default)]
+        struct SilentClient {
+            pending: Arc<AtomicUsize>,
+        }
+
+        struct Pending(Arc<AtomicUsize>);
+        impl Drop for Pending {
+            fn drop(&mut self) {
+                self.0.fetch_sub(1, Ordering::SeqCst);
+            }
+        }
+
+        impl McpSession for SilentClient {
+            fn call<'a>(&'a self, _method: &'a str, _params: Value) -> SessionFuture<'a, Value> {
+                self.pending.fetch_add(1, Ordering::SeqCst);
+                let pending = Pending(Arc::clone(&self.pending));
+                Box::pin(async move {
+                    let _pending = pending;
+                    core::future::pending().await
+                })
+            }
+
+            fn notify<'a>(&'a self, _method: &'a str, _params: Value) -> SessionFuture<'a, ()> {
+                Box::pin(async { Ok(()) })
+            }
+        }
+
+        let client = SilentClient::default();
+        let pending = Arc::clone(&client.pending);
+        let ctx = RequestContext::stdio()
+            .with_session(Arc::new(client))
+            .with_client_request_timeout(core::time::Duration::from_millis(50));
+
+        let request = CreateMessageRequest::builder()
+            .user("hello")
+            .max_tokens(16)
+            .build();
+        let err = ctx.sample(request).await.unwrap_err();
+        assert_eq!(err.kind, crate::error::ErrorKind::Timeout);
+        assert!(err.message.contains("sampling/createMessage"), "{err}");
+        assert_eq!(pending.load(Ordering::SeqCst), 0);
+    }
 }
@@ -74,6 +74,13 @@ pub struct ErrorContext {
     /// Request ID for tracing
     #[serde(skip_serializing_if = "Option::is_none")]
     pub request_id: Option<String>,
+    /// JSON-RPC error code as received from a peer, kept because codes
+    /// outside the known set all map to [`ErrorKind::Internal`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rpc_code: Option<i32>,
+    /// Structured `data` of a JSON-RPC error (field errors, retry hints, ...)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Value>,
 }
 
 /// Error classification for programmatic handling.
@@ -431,6 +438,49 @@ impl McpError {
         Self::new(ErrorKind::from_i32(code), message)
     }
 
+    /// Create an error from a JSON-RPC error object received from a peer,
+    /// keeping its exact code and structured `data`
+    #[must_use]
+    pub fn from_rpc_error(
+        code: i32,
+        message: impl Into<String>,
+        data: Option<serde_json::Value>,
+    ) -> Self {
+        let mut err = Self::from_rpc_code(code, message);
+        let ctx = err
+            .context
+            .get_or_insert_with(|| alloc::boxed::Box::new(ErrorContext::default()));
+        ctx.rpc_code = Some(code);
+        ctx.data = data;
+        err
+    }
+
+    /// Attach structured data, sent as the JSON-RPC error's `data` member
+    #[must_use]
+    pub fn with_data(mut self, data: serde_json::Value) -> Self {
+        let ctx = self
+            .context
+            .get_or_insert_with(|| alloc::boxed::Box::new(ErrorContext::default()));
+        ctx.data = Some(data);
+        self
+    }
+
+    /// Structured data carried by this error, if any
+    #[must_use]
+    pub fn data(&self) -> Option<&serde_json::Value> {
+        self.context.as_ref().and_then(|ctx| ctx.data.as_ref())
+    }
+
+    /// JSON-RPC error code as received from the peer, or the code for this
+    /// error's kind when it was not decoded from a JSON-RPC error
+    #[must_use]
+    pub fn rpc_code(&self) -> i32 {
+        self.context
+            .as_ref()
+            .and_then(|ctx| ctx.rpc_code)
+            .unwrap_or_else(|| self.jsonrpc_code())
+    }
+
     /// Set the operation context
     #[must_use]
     pub fn with_operation(mut self, operation: impl Into<String>) -> Self {
@@ -468,6 +518,15 @@ impl McpError {
         self
     }
 
+    /// Check if this error is a timeout
+    ///
+    /// Covers transport-level request timeouts as well as operations that
+    /// exceeded their deadline, so retry logic can single them out.
+    #[must_use]
+    pub const fn is_timeout(&self) -> bool {
+        matches!(self.kind, ErrorKind::Timeout)
+    }
+
     /// Check if this error is retryable
     #[must_use]
     pub const fn is_retryable(&self) -> bool {
@@ -706,6 +765,23 @@ impl From<std::io::Error> for McpError {
     }
 }
 
+/// Extension trait for converting `Result` errors into [`McpError`]
+pub trait McpErrorExt<T> {
+    /// Convert the error into an [`ErrorKind::Timeout`] error, prefixed
+    /// with `context`
+    ///
+    /// # Errors
+    ///
+    /// Returns the original error as a timeout with added context.
+    fn timeout_error(self, context: impl fmt::Display) -> McpResult<T>;
+}
+
+impl<T, E: fmt::Display> McpErrorExt<T> for Result<T, E> {
+    fn timeout_error(self, context: impl fmt::Display) -> McpResult<T> {
+        self.map_err(|e| McpError::timeout(alloc::format!("{context}: {e}")))
+    }
+}
+
 /// Convenience macro for creating errors with location
 #[macro_export]
 macro_rules! mcp_err {
@@ -744,6 +820,19 @@ mod tests {
         assert_eq!(ctx.request_id, Some("req-123".to_string()));
     }
 
+    #[test]
+    fn test_rpc_error_keeps_code_and_data() {
+        let data = serde_json::json!({"fields": [{"name": "email", "error": "required"}]});
+        let err = McpError::from_rpc_error(-32050, "Validation failed", Some(data.clone()));
+        assert_eq!(err.kind, ErrorKind::Internal);
+        assert_eq!(err.rpc_code(), -32050);
+        assert_eq!(err.data(), Some(&data));
+
+        let plain = McpError::invalid_params("x");
+        assert_eq!(plain.rpc_code(), -32602);
+        assert!(plain.data().is_none());
+    }
+
     #[test]
     fn test_jsonrpc_codes() {
         assert_eq!(McpError::tool_not_found("x").jsonrpc_code(), -32001);
@@ -758,6 +847,21 @@ mod tests {
         assert!(!McpError::invalid_params("x").is_retryable());
     }
 
+    #[test]
+    fn test_is_timeout() {
+        assert!(McpError::timeout("x").is_timeout());
+        assert!(!McpError::transport("x").is_timeout());
+    }
+
+    #[test]
+    fn test_timeout_error_ext() {
+        let result: Result<(), &str> = Err("deadline elapsed");
+        let err = result.timeout_error("tools/call").unwrap_err();
+        assert!(err.is_timeout());
+        assert_eq!(err.message, "tools/call: deadline elapsed");
+        assert_eq!(Ok::<_, &str>(1).timeout_error("x").unwrap(), 1);
+    }
+
     #[test]
     fn test_http_status() {
         assert_eq!(McpError::tool_not_found("x").http_status(), 404);
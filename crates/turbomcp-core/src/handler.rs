@@ -410,8 +410,11 @@ pub trait McpHandler: Clone + MaybeSend + MaybeSync + 'static {
     ///
     /// Called in response to `resources/subscribe` requests. The default
     /// implementation returns `capability_not_supported`. Servers that
-    /// advertise `resources.subscribe = true` MUST override this method —
-    /// the router calls it whenever a client invokes `resources/subscribe`.
+    /// advertise `resources.subscribe = true` from
+    /// [`server_capabilities`](Self::server_capabilities) MUST override this
+    /// method. The router only calls it when that capability is advertised;
+    /// otherwise `resources/subscribe` is rejected with
+    /// `CAPABILITY_NOT_SUPPORTED` before reaching the handler.
     ///
     /// # Arguments
     ///
@@ -515,6 +518,14 @@ pub trait McpHandler: Clone + MaybeSend + MaybeSync + 'static {
     fn on_shutdown(&self) -> impl Future<Output = McpResult<()>> + MaybeSend {
         async { Ok(()) }
     }
+
+    /// Called when a client session ends, e.g. an HTTP session terminated
+    /// with `DELETE`.
+    ///
+    /// Override this to drop state kept per session id.
+    ///
+    /// Default implementation does nothing.
+    fn on_session_closed(&self, _session_id: &str) {}
 }
 
 #[cfg(test)]
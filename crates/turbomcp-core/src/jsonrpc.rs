@@ -474,6 +474,45 @@ impl From<JsonRpcErrorCode> for JsonRpcError {
 // Request IDs are validated at this boundary so routers can rely on
 // `id: None` meaning "field absent", not "null or malformed".
 
+/// How strictly incoming messages must declare `"jsonrpc": "2.0"`.
+///
+/// Some non-compliant peers omit the version field or send another value.
+/// [`Strict`](Self::Strict) rejects such messages as invalid requests;
+/// [`Lenient`](Self::Lenient) accepts them and normalizes the version to
+/// `"2.0"` for interop with buggy clients.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JsonRpcStrictness {
+    /// Reject messages whose `jsonrpc` field is missing or not `"2.0"`.
+    #[default]
+    Strict,
+    /// Accept such messages and treat them as JSON-RPC 2.0.
+    Lenient,
+}
+
+impl JsonRpcStrictness {
+    /// Normalize the `jsonrpc` field of a raw message object.
+    ///
+    /// In lenient mode a missing or non-`"2.0"` version is replaced with
+    /// `"2.0"` and `true` is returned. Strict mode never modifies the message.
+    pub fn normalize(self, message: &mut Value) -> bool {
+        if self == Self::Strict {
+            return false;
+        }
+        let Value::Object(object) = message else {
+            return false;
+        };
+        if object.get("jsonrpc").and_then(Value::as_str) == Some(JSONRPC_VERSION) {
+            return false;
+        }
+        object.insert(
+            "jsonrpc".to_string(),
+            Value::String(JSONRPC_VERSION.to_string()),
+        );
+        true
+    }
+}
+
 /// Incoming JSON-RPC message - can be request or notification.
 ///
 /// This is the "wire format" type used by routers to parse incoming messages.
@@ -693,9 +732,9 @@ impl JsonRpcOutgoing {
 impl From<crate::error::McpError> for JsonRpcError {
     fn from(err: crate::error::McpError) -> Self {
         Self {
-            code: err.jsonrpc_code(),
-            message: err.message.clone(),
-            data: None,
+            code: err.rpc_code(),
+            data: err.data().cloned(),
+            message: err.message,
         }
     }
 }
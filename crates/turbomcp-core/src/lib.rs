@@ -48,6 +48,7 @@
 //! ## Module Organization
 //!
 //! - [`auth`]: Authentication traits and types (portable across native/WASM)
+//! - `clock`: Pluggable time source for testable time-dependent logic (requires `std`)
 //! - [`handler`]: Unified MCP handler trait
 //! - [`context`]: Request context types
 //! - [`marker`]: Platform-adaptive marker traits
@@ -85,6 +86,8 @@ extern crate alloc;
 
 // Core modules - unified v3 architecture
 pub mod auth;
+#[cfg(feature = "std")]
+pub mod clock;
 pub mod context;
 pub mod error;
 pub mod handler;
@@ -104,7 +107,7 @@ pub mod session;
 pub mod rkyv_types;
 
 // Re-export commonly used types at crate root
-pub use error::{ErrorKind, McpError, McpResult};
+pub use error::{ErrorKind, McpError, McpErrorExt, McpResult};
 pub use jsonrpc::{
     // Strict typed API
     JSONRPC_VERSION,
@@ -116,6 +119,7 @@ pub use jsonrpc::{
     JsonRpcOutgoing,
     JsonRpcRequest,
     JsonRpcResponse,
+    JsonRpcStrictness,
     JsonRpcVersion,
     RequestId,
     ResponseId,
@@ -131,7 +135,7 @@ pub use auth::{
     AuthError, Authenticator, Credential, CredentialExtractor, HeaderExtractor, JwtAlgorithm,
     JwtConfig, Principal, StandardClaims,
 };
-pub use context::{RequestContext, TransportType};
+pub use context::{ElicitationFallback, RequestContext, TransportType};
 pub use handler::McpHandler;
 pub use marker::{MaybeSend, MaybeSync};
 pub use session::{Cancellable, McpSession, SessionFuture};
@@ -33,8 +33,8 @@ use crate::PROTOCOL_VERSION;
 use crate::context::RequestContext;
 use crate::error::McpError;
 use crate::handler::McpHandler;
-use crate::jsonrpc::{JsonRpcIncoming, JsonRpcOutgoing};
-use turbomcp_types::ServerInfo;
+use crate::jsonrpc::{JsonRpcIncoming, JsonRpcOutgoing, JsonRpcStrictness};
+use turbomcp_types::{ByteRange, ResourceResult, ServerInfo};
 
 /// Configuration for request routing.
 ///
@@ -137,6 +137,16 @@ pub async fn route_request<H: McpHandler>(
                 .unwrap_or_default();
             let args = params.get("arguments").cloned().unwrap_or_default();
 
+            // Request `_meta` is exposed to handlers that read the raw call.
+            let meta_ctx;
+            let ctx = match params.get("_meta") {
+                None | Some(Value::Null) => ctx,
+                Some(meta) => {
+                    meta_ctx = ctx.clone().with_request_meta(meta.clone());
+                    &meta_ctx
+                }
+            };
+
             match handler.call_tool(name, args, ctx).await {
                 Ok(result) => match serde_json::to_value(&result) {
                     Ok(result_value) => JsonRpcOutgoing::success(id, result_value),
@@ -172,17 +182,51 @@ pub async fn route_request<H: McpHandler>(
                 .and_then(|v| v.as_str())
                 .unwrap_or_default();
 
-            match handler.read_resource(uri, ctx).await {
-                Ok(result) => match serde_json::to_value(&result) {
-                    Ok(result_value) => JsonRpcOutgoing::success(id, result_value),
-                    Err(e) => JsonRpcOutgoing::error(
-                        id,
-                        McpError::internal(alloc::format!(
-                            "Failed to serialize resource result: {}",
-                            e
-                        )),
-                    ),
+            // Optional byte range; handlers that support partial reads pick
+            // it up from the context.
+            let ranged_ctx;
+            let ctx = match params.get("range") {
+                None | Some(Value::Null) => ctx,
+                Some(range) => match serde_json::from_value::<ByteRange>(range.clone()) {
+                    Ok(range) => {
+                        ranged_ctx = ctx.clone().with_byte_range(range);
+                        &ranged_ctx
+                    }
+                    Err(e) => {
+                        return JsonRpcOutgoing::error(
+                            id,
+                            McpError::invalid_params(alloc::format!("Invalid range: {}", e)),
+                        );
+                    }
                 },
+            };
+
+            // Conditional read: an unchanged resource is answered with a
+            // not-modified marker instead of its contents.
+            let if_none_match = params
+                .get("_meta")
+                .and_then(|meta| meta.get("ifNoneMatch"))
+                .and_then(|v| v.as_str());
+
+            match handler.read_resource(uri, ctx).await {
+                Ok(result) => {
+                    let result = match if_none_match {
+                        Some(tag) if result.etag_matches(tag) => {
+                            ResourceResult::not_modified(result.etag().unwrap_or(tag))
+                        }
+                        _ => result,
+                    };
+                    match serde_json::to_value(&result) {
+                        Ok(result_value) => JsonRpcOutgoing::success(id, result_value),
+                        Err(e) => JsonRpcOutgoing::error(
+                            id,
+                            McpError::internal(alloc::format!(
+                                "Failed to serialize resource result: {}",
+                                e
+                            )),
+                        ),
+                    }
+                }
                 Err(err) => JsonRpcOutgoing::error(id, err),
             }
         }
@@ -279,6 +323,12 @@ pub async fn route_request<H: McpHandler>(
 
         // Resource subscriptions
         "resources/subscribe" => {
+            if !resource_subscriptions_enabled(handler) {
+                return JsonRpcOutgoing::error(
+                    id,
+                    McpError::capability_not_supported("resources/subscribe"),
+                );
+            }
             let params = request.params.unwrap_or_default();
             let Some(uri) = params.get("uri").and_then(|v| v.as_str()) else {
                 return JsonRpcOutgoing::error(id, McpError::invalid_params("Missing uri"));
@@ -290,6 +340,12 @@ pub async fn route_request<H: McpHandler>(
         }
 
         "resources/unsubscribe" => {
+            if !resource_subscriptions_enabled(handler) {
+                return JsonRpcOutgoing::error(
+                    id,
+                    McpError::capability_not_supported("resources/unsubscribe"),
+                );
+            }
             let params = request.params.unwrap_or_default();
             let Some(uri) = params.get("uri").and_then(|v| v.as_str()) else {
                 return JsonRpcOutgoing::error(id, McpError::invalid_params("Missing uri"));
@@ -371,13 +427,35 @@ fn build_initialize_result<H: McpHandler>(
     Value::Object(result)
 }
 
+/// Whether the handler advertises `resources.subscribe`.
+///
+/// Subscription requests are only routed to the handler when the capability
+/// was advertised during `initialize`; otherwise the client is told the
+/// capability is unsupported rather than relying on the handler's default.
+fn resource_subscriptions_enabled<H: McpHandler>(handler: &H) -> bool {
+    handler
+        .server_capabilities()
+        .resources
+        .and_then(|resources| resources.subscribe)
+        .unwrap_or(false)
+}
+
 /// Parse a JSON string into a JSON-RPC incoming request.
 ///
 /// This is a convenience function for parsing incoming messages.
 pub fn parse_request(input: &str) -> Result<JsonRpcIncoming, McpError> {
+    parse_request_with_strictness(input, JsonRpcStrictness::Strict)
+}
+
+/// Parse a JSON string into a JSON-RPC incoming request, applying `strictness`
+/// to the `jsonrpc` version field.
+pub fn parse_request_with_strictness(
+    input: &str,
+    strictness: JsonRpcStrictness,
+) -> Result<JsonRpcIncoming, McpError> {
     let value: serde_json::Value =
         serde_json::from_str(input).map_err(|e| McpError::parse_error(e.to_string()))?;
-    parse_request_from_value(value)
+    parse_request_from_value_with_strictness(value, strictness)
 }
 
 /// Parse a pre-parsed `serde_json::Value` into a JSON-RPC incoming request.
@@ -385,6 +463,16 @@ pub fn parse_request(input: &str) -> Result<JsonRpcIncoming, McpError> {
 /// parse once into `Value` (to detect server-to-client responses), then use this
 /// to convert into the typed request without re-parsing the source string.
 pub fn parse_request_from_value(value: serde_json::Value) -> Result<JsonRpcIncoming, McpError> {
+    parse_request_from_value_with_strictness(value, JsonRpcStrictness::Strict)
+}
+
+/// Parse a pre-parsed `serde_json::Value` into a JSON-RPC incoming request,
+/// applying `strictness` to the `jsonrpc` version field.
+pub fn parse_request_from_value_with_strictness(
+    mut value: serde_json::Value,
+    strictness: JsonRpcStrictness,
+) -> Result<JsonRpcIncoming, McpError> {
+    strictness.normalize(&mut value);
     let request: JsonRpcIncoming =
         serde_json::from_value(value).map_err(|e| McpError::invalid_request(e.to_string()))?;
     if !request.is_valid_version() {
@@ -491,6 +579,31 @@ mod tests {
         assert_eq!(error.kind, ErrorKind::InvalidRequest);
     }
 
+    #[test]
+    fn test_parse_request_missing_version_strict_and_lenient() {
+        let input = r#"{"id": 1, "method": "ping"}"#;
+
+        let error = parse_request_with_strictness(input, JsonRpcStrictness::Strict).unwrap_err();
+        assert_eq!(error.kind, ErrorKind::InvalidRequest);
+        assert_eq!(
+            parse_request(input).unwrap_err().kind,
+            ErrorKind::InvalidRequest
+        );
+
+        let request = parse_request_with_strictness(input, JsonRpcStrictness::Lenient).unwrap();
+        assert_eq!(request.jsonrpc, "2.0");
+        assert_eq!(request.method, "ping");
+        assert_eq!(request.id, Some(serde_json::json!(1)));
+
+        // Lenient mode also normalizes a wrong version
+        let request = parse_request_with_strictness(
+            r#"{"jsonrpc": "1.0", "method": "notifications/initialized"}"#,
+            JsonRpcStrictness::Lenient,
+        )
+        .unwrap();
+        assert!(request.is_valid_version());
+    }
+
     #[test]
     fn test_parse_request_rejects_invalid_id_as_invalid_request() {
         let input = r#"{"jsonrpc": "2.0", "id": null, "method": "ping"}"#;
@@ -829,6 +942,284 @@ mod tests {
         assert_eq!(templates[0]["name"], "file");
     }
 
+    #[tokio::test]
+    async fn test_route_resource_read_byte_range() {
+        const LOG: &str = "alpha\nbravo\ncharlie\n";
+
+        #[derive(Clone)]
+        struct LogHandler;
+
+        impl McpHandler for LogHandler {
+            fn server_info(&self) -> ServerInfo {
+                ServerInfo::new("range-router", "1.0.0")
+            }
+
+            fn list_tools(&self) -> Vec<Tool> {
+                vec![]
+            }
+
+            fn list_resources(&self) -> Vec<Resource> {
+                vec![Resource::new("file:///app.log", "log")]
+            }
+
+            fn list_prompts(&self) -> Vec<Prompt> {
+                vec![]
+            }
+
+            async fn call_tool<'a>(
+                &'a self,
+                _name: &'a str,
+                _args: Value,
+                _ctx: &'a RequestContext,
+            ) -> McpResult<ToolResult> {
+                unreachable!("tool calls are not used in this test")
+            }
+
+            async fn read_resource<'a>(
+                &'a self,
+                uri: &'a str,
+                ctx: &'a RequestContext,
+            ) -> McpResult<ResourceResult> {
+                match ctx.byte_range() {
+                    Some(range) => ResourceResult::text_range(uri, LOG, range)
+                        .ok_or_else(|| McpError::invalid_params("range out of bounds")),
+                    None => Ok(ResourceResult::text(uri, LOG)),
+                }
+            }
+
+            async fn get_prompt<'a>(
+                &'a self,
+                _name: &'a str,
+                _args: Option<Value>,
+                _ctx: &'a RequestContext,
+            ) -> McpResult<PromptResult> {
+                unreachable!("prompt reads are not used in this test")
+            }
+        }
+
+        let read = |range: Value| JsonRpcIncoming {
+            jsonrpc: "2.0".to_string(),
+            id: Some(serde_json::json!(1)),
+            method: "resources/read".to_string(),
+            params: Some(serde_json::json!({ "uri": "file:///app.log", "range": range })),
+        };
+        let ctx = RequestContext::stdio();
+
+        let response = route_request(
+            &LogHandler,
+            read(serde_json::json!({ "offset": 6, "length": 5 })),
+            &ctx,
+            &RouteConfig::default(),
+        )
+        .await;
+        let result = response.result.expect("ranged read result");
+        assert_eq!(result["contents"][0]["text"], "bravo");
+        assert_eq!(result["_meta"]["range"]["total"], LOG.len());
+
+        let response = route_request(
+            &LogHandler,
+            read(serde_json::json!({ "offset": 1000 })),
+            &ctx,
+            &RouteConfig::default(),
+        )
+        .await;
+        assert!(response.error.is_some());
+
+        let response = route_request(
+            &LogHandler,
+            read(serde_json::json!({ "offset": -1 })),
+            &ctx,
+            &RouteConfig::default(),
+        )
+        .await;
+        assert!(response.error.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_route_resource_read_if_none_match() {
+        #[derive(Clone)]
+        struct TaggedHandler;
+
+        impl McpHandler for TaggedHandler {
+            fn server_info(&self) -> ServerInfo {
+                ServerInfo::new("etag-router", "1.0.0")
+            }
+
+            fn list_tools(&self) -> Vec<Tool> {
+                vec![]
+            }
+
+            fn list_resources(&self) -> Vec<Resource> {
+                vec![Resource::new("file:///config.toml", "config")]
+            }
+
+            fn list_prompts(&self) -> Vec<Prompt> {
+                vec![]
+            }
+
+            async fn call_tool<'a>(
+                &'a self,
+                _name: &'a str,
+                _args: Value,
+                _ctx: &'a RequestContext,
+            ) -> McpResult<ToolResult> {
+                unreachable!("tool calls are not used in this test")
+            }
+
+            async fn read_resource<'a>(
+                &'a self,
+                uri: &'a str,
+                _ctx: &'a RequestContext,
+            ) -> McpResult<ResourceResult> {
+                Ok(ResourceResult::text(uri, "debug = true").with_etag("\"v7\""))
+            }
+
+            async fn get_prompt<'a>(
+                &'a self,
+                _name: &'a str,
+                _args: Option<Value>,
+                _ctx: &'a RequestContext,
+            ) -> McpResult<PromptResult> {
+                unreachable!("prompt reads are not used in this test")
+            }
+        }
+
+        let read = |params: Value| JsonRpcIncoming {
+            jsonrpc: "2.0".to_string(),
+            id: Some(serde_json::json!(1)),
+            method: "resources/read".to_string(),
+            params: Some(params),
+        };
+        let ctx = RequestContext::stdio();
+        let config = RouteConfig::default();
+
+        // Unconditional reads return the content and its ETag.
+        let response = route_request(
+            &TaggedHandler,
+            read(serde_json::json!({ "uri": "file:///config.toml" })),
+            &ctx,
+            &config,
+        )
+        .await;
+        let result = response.result.expect("read result");
+        assert_eq!(result["contents"][0]["text"], "debug = true");
+        assert_eq!(result["_meta"]["etag"], "\"v7\"");
+
+        // A matching ETag yields a not-modified marker without contents.
+        let response = route_request(
+            &TaggedHandler,
+            read(serde_json::json!({
+                "uri": "file:///config.toml",
+                "_meta": { "ifNoneMatch": "\"v7\"" }
+            })),
+            &ctx,
+            &config,
+        )
+        .await;
+        let result = response.result.expect("not-modified result");
+        assert_eq!(result["contents"], serde_json::json!([]));
+        assert_eq!(result["_meta"]["notModified"], true);
+        assert_eq!(result["_meta"]["etag"], "\"v7\"");
+
+        // A stale ETag gets the current content.
+        let response = route_request(
+            &TaggedHandler,
+            read(serde_json::json!({
+                "uri": "file:///config.toml",
+                "_meta": { "ifNoneMatch": "\"v6\"" }
+            })),
+            &ctx,
+            &config,
+        )
+        .await;
+        let result = response.result.expect("modified result");
+        assert_eq!(result["contents"][0]["text"], "debug = true");
+        assert!(result["_meta"].get("notModified").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_route_tools_call_exposes_request_meta() {
+        #[derive(Clone)]
+        struct MetaHandler;
+
+        impl McpHandler for MetaHandler {
+            fn server_info(&self) -> ServerInfo {
+                ServerInfo::new("meta-router", "1.0.0")
+            }
+
+            fn list_tools(&self) -> Vec<Tool> {
+                vec![Tool::new("trace", "Echo the request _meta")]
+            }
+
+            fn list_resources(&self) -> Vec<Resource> {
+                vec![]
+            }
+
+            fn list_prompts(&self) -> Vec<Prompt> {
+                vec![]
+            }
+
+            async fn call_tool<'a>(
+                &'a self,
+                _name: &'a str,
+                _args: Value,
+                ctx: &'a RequestContext,
+            ) -> McpResult<ToolResult> {
+                let trace = ctx
+                    .request_meta()
+                    .and_then(|meta| meta.get("traceId"))
+                    .and_then(Value::as_str)
+                    .unwrap_or("none");
+                Ok(ToolResult::text(trace))
+            }
+
+            async fn read_resource<'a>(
+                &'a self,
+                _uri: &'a str,
+                _ctx: &'a RequestContext,
+            ) -> McpResult<ResourceResult> {
+                unreachable!("resource reads are not used in this test")
+            }
+
+            async fn get_prompt<'a>(
+                &'a self,
+                _name: &'a str,
+                _args: Option<Value>,
+                _ctx: &'a RequestContext,
+            ) -> McpResult<PromptResult> {
+                unreachable!("prompt reads are not used in this test")
+            }
+        }
+
+        let call = |params: Value| JsonRpcIncoming {
+            jsonrpc: "2.0".to_string(),
+            id: Some(serde_json::json!(1)),
+            method: "tools/call".to_string(),
+            params: Some(params),
+        };
+        let ctx = RequestContext::stdio();
+
+        let response = route_request(
+            &MetaHandler,
+            call(serde_json::json!({ "name": "trace", "_meta": { "traceId": "abc" } })),
+            &ctx,
+            &RouteConfig::default(),
+        )
+        .await;
+        let result = response.result.expect("tool result");
+        assert_eq!(result["content"][0]["text"], "abc");
+
+        let response = route_request(
+            &MetaHandler,
+            call(serde_json::json!({ "name": "trace" })),
+            &ctx,
+            &RouteConfig::default(),
+        )
+        .await;
+        let result = response.result.expect("tool result");
+        assert_eq!(result["content"][0]["text"], "none");
+    }
+
     #[tokio::test]
     async fn test_route_tools_call() {
         let handler = TestHandler;
@@ -916,6 +1307,121 @@ mod tests {
         assert_eq!(error.code, -32601); // METHOD_NOT_FOUND
     }
 
+    /// Accepts every subscription; `advertise` controls whether
+    /// `resources.subscribe` is set in its capabilities.
+    #[derive(Clone)]
+    struct SubscribeHandler {
+        advertise: bool,
+    }
+
+    #[allow(clippy::manual_async_fn)]
+    impl McpHandler for SubscribeHandler {
+        fn server_info(&self) -> ServerInfo {
+            ServerInfo::new("subscribe-router", "1.0.0")
+        }
+
+        fn server_capabilities(&self) -> ServerCapabilities {
+            ServerCapabilities {
+                resources: Some(turbomcp_types::ResourcesCapabilities {
+                    subscribe: self.advertise.then_some(true),
+                    list_changed: Some(true),
+                }),
+                ..Default::default()
+            }
+        }
+
+        fn list_tools(&self) -> Vec<Tool> {
+            vec![]
+        }
+
+        fn list_resources(&self) -> Vec<Resource> {
+            vec![Resource::new("file:///log.txt", "log")]
+        }
+
+        fn list_prompts(&self) -> Vec<Prompt> {
+            vec![]
+        }
+
+        fn call_tool<'a>(
+            &'a self,
+            _name: &'a str,
+            _args: Value,
+            _ctx: &'a RequestContext,
+        ) -> impl Future<Output = McpResult<ToolResult>> + MaybeSend + 'a {
+            async move { unreachable!("tool calls are not used in this test") }
+        }
+
+        fn read_resource<'a>(
+            &'a self,
+            _uri: &'a str,
+            _ctx: &'a RequestContext,
+        ) -> impl Future<Output = McpResult<ResourceResult>> + MaybeSend + 'a {
+            async move { unreachable!("resource reads are not used in this test") }
+        }
+
+        fn get_prompt<'a>(
+            &'a self,
+            _name: &'a str,
+            _args: Option<Value>,
+            _ctx: &'a RequestContext,
+        ) -> impl Future<Output = McpResult<PromptResult>> + MaybeSend + 'a {
+            async move { unreachable!("prompt reads are not used in this test") }
+        }
+
+        fn subscribe<'a>(
+            &'a self,
+            _uri: &'a str,
+            _ctx: &'a RequestContext,
+        ) -> impl Future<Output = McpResult<()>> + MaybeSend + 'a {
+            async move { Ok(()) }
+        }
+
+        fn unsubscribe<'a>(
+            &'a self,
+            _uri: &'a str,
+            _ctx: &'a RequestContext,
+        ) -> impl Future<Output = McpResult<()>> + MaybeSend + 'a {
+            async move { Ok(()) }
+        }
+    }
+
+    fn subscription_request(method: &str) -> JsonRpcIncoming {
+        JsonRpcIncoming {
+            jsonrpc: "2.0".to_string(),
+            id: Some(serde_json::json!(1)),
+            method: method.to_string(),
+            params: Some(serde_json::json!({"uri": "file:///log.txt"})),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_route_subscribe_rejected_when_not_advertised() {
+        let handler = SubscribeHandler { advertise: false };
+        let ctx = RequestContext::stdio();
+        let config = RouteConfig::default();
+
+        for method in ["resources/subscribe", "resources/unsubscribe"] {
+            let response =
+                route_request(&handler, subscription_request(method), &ctx, &config).await;
+            let error = response.error.expect("subscription should be rejected");
+            assert_eq!(error.code, -32006); // CAPABILITY_NOT_SUPPORTED
+        }
+    }
+
+    #[tokio::test]
+    async fn test_route_subscribe_accepted_when_advertised() {
+        let handler = SubscribeHandler { advertise: true };
+        let ctx = RequestContext::stdio();
+        let config = RouteConfig::default();
+
+        for method in ["resources/subscribe", "resources/unsubscribe"] {
+            let response =
+                route_request(&handler, subscription_request(method), &ctx, &config).await;
+            assert!(response.error.is_none(), "{method} should succeed");
+            assert_eq!(response.result, Some(serde_json::json!({})));
+        }
+    }
+
     #[tokio::test]
     async fn test_route_with_custom_protocol_version() {
         let handler = TestHandler;
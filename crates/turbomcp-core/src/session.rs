@@ -86,6 +86,15 @@ pub trait McpSession: Debug + MaybeSend + MaybeSync {
 pub trait Cancellable: Debug + MaybeSend + MaybeSync {
     /// Returns `true` if cancellation has been requested.
     fn is_cancelled(&self) -> bool;
+
+    /// A tokio token that fires when cancellation is requested, if available.
+    ///
+    /// [`crate::RequestContext::spawn_child`] uses it to abort child tasks as
+    /// soon as the request is cancelled. Poll-only handles return `None`.
+    #[cfg(feature = "std")]
+    fn cancellation_token(&self) -> Option<tokio_util::sync::CancellationToken> {
+        None
+    }
 }
 
 #[cfg(feature = "std")]
@@ -93,4 +102,8 @@ impl Cancellable for tokio_util::sync::CancellationToken {
     fn is_cancelled(&self) -> bool {
         tokio_util::sync::CancellationToken::is_cancelled(self)
     }
+
+    fn cancellation_token(&self) -> Option<tokio_util::sync::CancellationToken> {
+        Some(self.clone())
+    }
 }
@@ -0,0 +1,67 @@
+//! Benchmark for `DpopValidator::validate_batch` against a loop of single
+//! validations over the same proofs
+//!
+//! Run with:
+//! ```bash
+//! cargo bench -p turbomcp-dpop --bench batch_validation
+//! ```
+
+use criterion::{BatchSize, BenchmarkId, Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+use turbomcp_dpop::{DpopProof, DpopProofGenerator, DpopProofInput, DpopValidator};
+
+/// Generate `count` distinct proofs as compact JWTs
+fn generate_jwts(runtime: &tokio::runtime::Runtime, count: usize) -> Vec<String> {
+    runtime.block_on(async {
+        let generator = DpopProofGenerator::new_simple().await.unwrap();
+        let mut jwts = Vec::with_capacity(count);
+        for _ in 0..count {
+            let proof = generator
+                .generate_proof("POST", "https://api.example.com/resource", None)
+                .await
+                .unwrap();
+            jwts.push(proof.to_jwt_string());
+        }
+        jwts
+    })
+}
+
+/// Each iteration gets a fresh validator, so the same `jti`s are never replays
+fn bench_batch_vs_single(c: &mut Criterion) {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let mut group = c.benchmark_group("dpop_validation");
+
+    for count in [16, 128] {
+        let jwts = generate_jwts(&runtime, count);
+
+        group.bench_with_input(BenchmarkId::new("single_loop", count), &jwts, |b, jwts| {
+            b.to_async(&runtime).iter_batched(
+                DpopValidator::new,
+                |validator| async move {
+                    for jwt in jwts {
+                        let proof = DpopProof::from_jwt_string(jwt).unwrap();
+                        black_box(validator.validate(&proof, None).await.unwrap());
+                    }
+                },
+                BatchSize::SmallInput,
+            )
+        });
+
+        group.bench_with_input(BenchmarkId::new("validate_batch", count), &jwts, |b, jwts| {
+            let inputs: Vec<_> = jwts.iter().map(DpopProofInput::new).collect();
+            b.to_async(&runtime).iter_batched(
+                DpopValidator::new,
+                |validator| {
+                    let inputs = &inputs;
+                    async move { black_box(validator.validate_batch(inputs).await) }
+                },
+                BatchSize::SmallInput,
+            )
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_batch_vs_single);
+criterion_main!(benches);
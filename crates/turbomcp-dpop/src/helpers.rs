@@ -9,31 +9,38 @@ use jsonwebtoken::jwk::{AlgorithmParameters, CommonParameters, Jwk, KeyAlgorithm
 use jsonwebtoken::jwk::{EllipticCurve, EllipticCurveKeyParameters, EllipticCurveKeyType};
 // RSA support removed in v3.0 due to RUSTSEC-2023-0071 timing vulnerability
 use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey};
-use p256::SecretKey;
 use p256::pkcs8::EncodePrivateKey;
 
 use crate::Result;
 use crate::errors::DpopError;
-use crate::types::{DpopAlgorithm, DpopPrivateKey, DpopPublicKey};
+use crate::types::{
+    DpopAlgorithm, DpopHeader, DpopJwk, DpopPayload, DpopPrivateKey, DpopPublicKey,
+};
 
 /// Convert DpopAlgorithm to jsonwebtoken Algorithm
 ///
-/// Only ES256 is supported as of TurboMCP v3.0+
-pub fn algorithm_to_jwt(algorithm: DpopAlgorithm) -> Algorithm {
+/// jsonwebtoken has no ES512 support, so ES512 proofs are signed and verified
+/// directly with the `p521` crate and this returns an error for them.
+pub fn algorithm_to_jwt(algorithm: DpopAlgorithm) -> Result<Algorithm> {
     match algorithm {
-        DpopAlgorithm::ES256 => Algorithm::ES256,
+        DpopAlgorithm::ES256 => Ok(Algorithm::ES256),
+        DpopAlgorithm::ES384 => Ok(Algorithm::ES384),
+        DpopAlgorithm::ES512 => Err(DpopError::CryptographicError {
+            reason: "ES512 is not supported by jsonwebtoken".to_string(),
+        }),
     }
 }
 
 /// Convert jsonwebtoken Algorithm to DpopAlgorithm
 ///
-/// Returns error for unsupported algorithms (only ES256 allowed as of TurboMCP v3.0+)
+/// Returns error for unsupported algorithms (only ECDSA is allowed)
 pub fn jwt_to_algorithm(algorithm: Algorithm) -> Result<DpopAlgorithm> {
     match algorithm {
         Algorithm::ES256 => Ok(DpopAlgorithm::ES256),
+        Algorithm::ES384 => Ok(DpopAlgorithm::ES384),
         other => Err(DpopError::InvalidProofStructure {
             reason: format!(
-                "Unsupported DPoP algorithm: {:?}. Only ES256 is supported (RSA removed due to RUSTSEC-2023-0071)",
+                "Unsupported DPoP algorithm: {:?}. Only ES256, ES384 and ES512 are supported (RSA removed due to RUSTSEC-2023-0071)",
                 other
             ),
         }),
@@ -45,34 +52,36 @@ pub fn jwt_to_algorithm(algorithm: Algorithm) -> Result<DpopAlgorithm> {
 /// This handles the conversion from our DpopPrivateKey enum to jsonwebtoken's EncodingKey,
 /// including necessary format conversions (SEC1 → PKCS#8 for EC keys).
 ///
-/// Only supports ES256 (ECDSA P-256) as of TurboMCP v3.0+
+/// P-521 keys are rejected because jsonwebtoken cannot sign ES512.
 ///
 /// # Security Note
 ///
-/// For EC keys, we convert from SEC1 format (raw 32 bytes) to PKCS#8 DER format as required
-/// by jsonwebtoken.
+/// For EC keys, we convert from SEC1 format (raw scalar bytes) to PKCS#8 DER format as
+/// required by jsonwebtoken.
 pub fn private_key_to_encoding_key(key: &DpopPrivateKey) -> Result<EncodingKey> {
-    match key {
-        DpopPrivateKey::EcdsaP256 { key_bytes } => {
-            // Convert SEC1 private key bytes to p256 SecretKey
-            let secret_key = SecretKey::from_bytes(key_bytes.into()).map_err(|e| {
-                DpopError::CryptographicError {
-                    reason: format!("Invalid EC private key: {}", e),
-                }
-            })?;
-
-            // Convert to PKCS#8 DER format (required by jsonwebtoken)
-            let pkcs8_der =
-                secret_key
-                    .to_pkcs8_der()
-                    .map_err(|e| DpopError::CryptographicError {
-                        reason: format!("Failed to convert EC key to PKCS#8: {}", e),
-                    })?;
-
-            // Create EncodingKey from DER bytes
-            Ok(EncodingKey::from_ec_der(pkcs8_der.as_bytes()))
+    let pkcs8_der = match key {
+        DpopPrivateKey::EcdsaP256 { key_bytes } => p256::SecretKey::from_slice(key_bytes)
+            .map_err(|e| DpopError::CryptographicError {
+                reason: format!("Invalid EC private key: {}", e),
+            })?
+            .to_pkcs8_der(),
+        DpopPrivateKey::EcdsaP384 { key_bytes } => p384::SecretKey::from_slice(key_bytes)
+            .map_err(|e| DpopError::CryptographicError {
+                reason: format!("Invalid EC private key: {}", e),
+            })?
+            .to_pkcs8_der(),
+        DpopPrivateKey::EcdsaP521 { .. } => {
+            return Err(DpopError::CryptographicError {
+                reason: "P-521 keys cannot be converted to a jsonwebtoken EncodingKey".to_string(),
+            });
         }
     }
+    .map_err(|e| DpopError::CryptographicError {
+        reason: format!("Failed to convert EC key to PKCS#8: {}", e),
+    })?;
+
+    // Create EncodingKey from DER bytes
+    Ok(EncodingKey::from_ec_der(pkcs8_der.as_bytes()))
 }
 
 /// Convert public key to jsonwebtoken JWK
@@ -80,45 +89,38 @@ pub fn private_key_to_encoding_key(key: &DpopPrivateKey) -> Result<EncodingKey>
 /// This creates a RFC 7517 compliant JWK from our DpopPublicKey enum.
 /// The JWK will be embedded in the DPoP proof header per RFC 9449.
 ///
-/// Only supports ES256 (ECDSA P-256) as of TurboMCP v3.0+
-///
 /// # Security Note
 ///
 /// JWK coordinates are base64url-encoded per RFC 7517 Section 6.
 pub fn public_key_to_jwk(key: &DpopPublicKey) -> Result<Jwk> {
-    match key {
-        DpopPublicKey::EcdsaP256 { x, y } => {
-            // Validate coordinate lengths (P-256 uses 32 bytes)
-            if x.len() != 32 || y.len() != 32 {
-                return Err(DpopError::CryptographicError {
-                    reason: format!("Invalid EC key coordinates: x={}, y={}", x.len(), y.len()),
-                });
-            }
+    let (curve, key_algorithm) = match key {
+        DpopPublicKey::EcdsaP256 { .. } => (EllipticCurve::P256, Some(KeyAlgorithm::ES256)),
+        DpopPublicKey::EcdsaP384 { .. } => (EllipticCurve::P384, Some(KeyAlgorithm::ES384)),
+        // jsonwebtoken has no ES512 key algorithm; `alg` is optional in a JWK
+        DpopPublicKey::EcdsaP521 { .. } => (EllipticCurve::P521, None),
+    };
 
-            // Base64url encode coordinates per RFC 7517
-            let x_b64 = URL_SAFE_NO_PAD.encode(x);
-            let y_b64 = URL_SAFE_NO_PAD.encode(y);
-
-            Ok(Jwk {
-                common: CommonParameters {
-                    public_key_use: Some(PublicKeyUse::Signature),
-                    key_operations: None,
-                    key_algorithm: Some(KeyAlgorithm::ES256),
-                    key_id: None,
-                    x509_url: None,
-                    x509_chain: None,
-                    x509_sha1_fingerprint: None,
-                    x509_sha256_fingerprint: None,
-                },
-                algorithm: AlgorithmParameters::EllipticCurve(EllipticCurveKeyParameters {
-                    key_type: EllipticCurveKeyType::EC,
-                    curve: EllipticCurve::P256,
-                    x: x_b64,
-                    y: y_b64,
-                }),
-            })
-        }
-    }
+    // Base64url encode coordinates per RFC 7517
+    let (x, y) = key.coordinates();
+
+    Ok(Jwk {
+        common: CommonParameters {
+            public_key_use: Some(PublicKeyUse::Signature),
+            key_operations: None,
+            key_algorithm,
+            key_id: None,
+            x509_url: None,
+            x509_chain: None,
+            x509_sha1_fingerprint: None,
+            x509_sha256_fingerprint: None,
+        },
+        algorithm: AlgorithmParameters::EllipticCurve(EllipticCurveKeyParameters {
+            key_type: EllipticCurveKeyType::EC,
+            curve,
+            x: URL_SAFE_NO_PAD.encode(x),
+            y: URL_SAFE_NO_PAD.encode(y),
+        }),
+    })
 }
 
 /// Convert JWK to jsonwebtoken DecodingKey
@@ -126,19 +128,18 @@ pub fn public_key_to_jwk(key: &DpopPublicKey) -> Result<Jwk> {
 /// This extracts the public key from a JWK and creates a DecodingKey for signature verification.
 /// Used during DPoP proof validation to verify the signature using the embedded public key.
 ///
-/// Only supports ES256 (ECDSA P-256) as of TurboMCP v3.0+
-///
 /// # Security Note
 ///
-/// This function validates key parameters and only supports P-256 for EC keys.
+/// This function validates key parameters and only supports the P-256 and P-384
+/// curves, the ones jsonwebtoken can verify. ES512 proofs are verified by
+/// [`verify_proof_signature`] without a `DecodingKey`.
 pub fn jwk_to_decoding_key(jwk: &Jwk) -> Result<DecodingKey> {
     match &jwk.algorithm {
         AlgorithmParameters::EllipticCurve(ec_params) => {
-            // Validate curve (only P-256 supported for DPoP)
-            if ec_params.curve != EllipticCurve::P256 {
+            if !matches!(ec_params.curve, EllipticCurve::P256 | EllipticCurve::P384) {
                 return Err(DpopError::InvalidProofStructure {
                     reason: format!(
-                        "Unsupported elliptic curve: {:?} (only P-256 supported)",
+                        "Unsupported elliptic curve: {:?} (only P-256 and P-384 supported)",
                         ec_params.curve
                     ),
                 });
@@ -154,26 +155,267 @@ pub fn jwk_to_decoding_key(jwk: &Jwk) -> Result<DecodingKey> {
         }
         other => Err(DpopError::InvalidProofStructure {
             reason: format!(
-                "Unsupported JWK algorithm parameters: {:?}. Only ES256 (ECDSA P-256) is supported (RSA removed due to RUSTSEC-2023-0071)",
+                "Unsupported JWK algorithm parameters: {:?}. Only EC keys are supported (RSA removed due to RUSTSEC-2023-0071)",
                 other
             ),
         }),
     }
 }
 
+/// Header fields of a compact JWS before they are checked against DPoP rules
+#[derive(serde::Deserialize)]
+struct RawProofHeader {
+    typ: Option<String>,
+    alg: String,
+    jwk: Option<serde_json::Value>,
+}
+
+/// Split a compact JWS into its three segments
+fn split_jws(jwt: &str) -> Result<[&str; 3]> {
+    let parts: Vec<&str> = jwt.split('.').collect();
+    <[&str; 3]>::try_from(parts).map_err(|parts| DpopError::InvalidProofStructure {
+        reason: format!("Invalid JWT format: expected 3 parts, got {}", parts.len()),
+    })
+}
+
+/// Decode and check the header of a DPoP proof without verifying its signature
+///
+/// Enforces `typ` = `dpop+jwt`, restricts `alg` to ES256, ES384 and ES512
+/// (rejecting RSA, HMAC and `none`), requires an embedded public JWK and checks
+/// that the JWK's curve is the one `alg` signs with.
+pub(crate) fn decode_proof_header(jwt: &str) -> Result<DpopHeader> {
+    let [header_b64, _, _] = split_jws(jwt)?;
+    let header_bytes =
+        URL_SAFE_NO_PAD
+            .decode(header_b64)
+            .map_err(|e| DpopError::InvalidProofStructure {
+                reason: format!("Failed to decode JWT header: {}", e),
+            })?;
+    let raw: RawProofHeader =
+        serde_json::from_slice(&header_bytes).map_err(|e| DpopError::InvalidProofStructure {
+            reason: format!("Failed to decode JWT header: {}", e),
+        })?;
+
+    if raw.typ.as_deref() != Some(crate::DPOP_JWT_TYPE) {
+        return Err(DpopError::InvalidProofStructure {
+            reason: format!(
+                "Invalid JWT type: expected '{}', got '{:?}'",
+                crate::DPOP_JWT_TYPE,
+                raw.typ
+            ),
+        });
+    }
+
+    let algorithm = DpopAlgorithm::from_jws_name(&raw.alg).ok_or_else(|| {
+        DpopError::InvalidProofStructure {
+            reason: format!(
+                "Unsupported DPoP algorithm: {}. Only ES256, ES384 and ES512 are supported (RSA removed due to RUSTSEC-2023-0071)",
+                raw.alg
+            ),
+        }
+    })?;
+
+    let jwk_value = raw.jwk.ok_or_else(|| DpopError::InvalidProofStructure {
+        reason: "Missing JWK in DPoP proof header".to_string(),
+    })?;
+    let jwk: DpopJwk =
+        serde_json::from_value(jwk_value).map_err(|e| DpopError::InvalidProofStructure {
+            reason: format!("Invalid JWK in header: {}", e),
+        })?;
+
+    let header = DpopHeader {
+        typ: crate::DPOP_JWT_TYPE.to_string(),
+        algorithm,
+        jwk,
+    };
+    check_jwk_curve(&header)?;
+    Ok(header)
+}
+
+/// Reject a header whose JWK curve does not match its `alg`
+///
+/// Without this check a proof could claim ES256 while carrying a P-384 key,
+/// and the thumbprint bound to the token would not describe the signing key.
+pub(crate) fn check_jwk_curve(header: &DpopHeader) -> Result<()> {
+    let expected = header.algorithm.curve();
+    if header.jwk.curve() != expected {
+        return Err(DpopError::InvalidProofStructure {
+            reason: format!(
+                "JWK curve {} does not match algorithm {} (expected {})",
+                header.jwk.curve(),
+                header.algorithm,
+                expected
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Verify the signature of a DPoP proof against the JWK embedded in `header`
+///
+/// `header` must come from [`decode_proof_header`] on the same `jwt`. Returns
+/// the decoded payload once the signature has been verified.
+pub(crate) fn verify_proof_signature(jwt: &str, header: &DpopHeader) -> Result<DpopPayload> {
+    let DpopJwk::Ec { x, y, .. } = &header.jwk;
+
+    if header.algorithm == DpopAlgorithm::ES512 {
+        return verify_es512(jwt, x, y);
+    }
+
+    let decoding_key =
+        DecodingKey::from_ec_components(x, y).map_err(|e| DpopError::CryptographicError {
+            reason: format!("Failed to create decoding key from JWK: {}", e),
+        })?;
+
+    // DPoP proofs use `iat` for freshness, not `exp`; `iat` is required per
+    // RFC 9449 §4.2. jsonwebtoken treats a missing `exp` as an error by default.
+    let mut validation = jsonwebtoken::Validation::new(algorithm_to_jwt(header.algorithm)?);
+    validation.validate_exp = false;
+    validation.set_required_spec_claims(&["iat"]);
+
+    jsonwebtoken::decode::<DpopPayload>(jwt, &decoding_key, &validation)
+        .map(|token| token.claims)
+        .map_err(|e| DpopError::ProofValidationFailed {
+            reason: format!("JWT signature validation failed: {}", e),
+        })
+}
+
+fn verify_es512(jwt: &str, x: &str, y: &str) -> Result<DpopPayload> {
+    use p521::ecdsa::signature::Verifier;
+    use p521::ecdsa::{Signature, VerifyingKey};
+
+    let [header_b64, payload_b64, signature_b64] = split_jws(jwt)?;
+
+    let decode = |value: &str, what: &str| {
+        URL_SAFE_NO_PAD
+            .decode(value)
+            .map_err(|e| DpopError::InvalidProofStructure {
+                reason: format!("Invalid base64url in JWT {}: {}", what, e),
+            })
+    };
+    let x = decode(x, "JWK x coordinate")?;
+    let y = decode(y, "JWK y coordinate")?;
+    let signature = decode(signature_b64, "signature")?;
+    let payload = decode(payload_b64, "payload")?;
+
+    let mut sec1 = Vec::with_capacity(1 + x.len() + y.len());
+    sec1.push(0x04);
+    sec1.extend_from_slice(&x);
+    sec1.extend_from_slice(&y);
+    let verifying_key =
+        VerifyingKey::from_sec1_bytes(&sec1).map_err(|e| DpopError::CryptographicError {
+            reason: format!("Failed to create decoding key from JWK: {}", e),
+        })?;
+
+    let signature =
+        Signature::from_slice(&signature).map_err(|e| DpopError::ProofValidationFailed {
+            reason: format!("JWT signature validation failed: {}", e),
+        })?;
+    let signing_input = format!("{}.{}", header_b64, payload_b64);
+    verifying_key
+        .verify(signing_input.as_bytes(), &signature)
+        .map_err(|e| DpopError::ProofValidationFailed {
+            reason: format!("JWT signature validation failed: {}", e),
+        })?;
+
+    serde_json::from_slice(&payload).map_err(|e| DpopError::ProofValidationFailed {
+        reason: format!("JWT signature validation failed: invalid claims: {}", e),
+    })
+}
+
+/// Sign a DPoP proof, returning the compact JWS
+///
+/// ES256 and ES384 are signed with jsonwebtoken; ES512 is signed directly
+/// with the `p521` crate.
+pub(crate) fn sign_proof(
+    header: &DpopHeader,
+    payload: &DpopPayload,
+    private_key: &DpopPrivateKey,
+    public_key: &DpopPublicKey,
+) -> Result<String> {
+    if public_key.algorithm() != header.algorithm {
+        return Err(DpopError::CryptographicError {
+            reason: format!(
+                "Key on curve {} cannot sign {} proofs",
+                public_key.algorithm().curve(),
+                header.algorithm
+            ),
+        });
+    }
+
+    if let DpopPrivateKey::EcdsaP521 { key_bytes } = private_key {
+        return sign_es512(header, payload, key_bytes);
+    }
+
+    // Create jsonwebtoken Header with DPoP-specific fields
+    let mut jwt_header = jsonwebtoken::Header::new(algorithm_to_jwt(header.algorithm)?);
+    jwt_header.typ = Some(crate::DPOP_JWT_TYPE.to_string());
+
+    // Embed JWK in header (RFC 9449 requirement)
+    jwt_header.jwk = Some(public_key_to_jwk(public_key)?);
+
+    let encoding_key = private_key_to_encoding_key(private_key)?;
+    jsonwebtoken::encode(&jwt_header, payload, &encoding_key).map_err(|e| {
+        DpopError::CryptographicError {
+            reason: format!("JWT signing failed: {}", e),
+        }
+    })
+}
+
+fn sign_es512(header: &DpopHeader, payload: &DpopPayload, key_bytes: &[u8; 66]) -> Result<String> {
+    use p521::ecdsa::signature::Signer;
+    use p521::ecdsa::{Signature, SigningKey};
+
+    let to_segment = |value: serde_json::Result<Vec<u8>>| {
+        value
+            .map(|json| URL_SAFE_NO_PAD.encode(json))
+            .map_err(|e| DpopError::SerializationError {
+                reason: format!("Failed to serialize JWT: {}", e),
+            })
+    };
+    let signing_input = format!(
+        "{}.{}",
+        to_segment(serde_json::to_vec(header))?,
+        to_segment(serde_json::to_vec(payload))?
+    );
+
+    let signing_key =
+        SigningKey::from_slice(key_bytes).map_err(|e| DpopError::CryptographicError {
+            reason: format!("Invalid EC private key: {}", e),
+        })?;
+    let signature: Signature = signing_key.sign(signing_input.as_bytes());
+
+    Ok(format!(
+        "{}.{}",
+        signing_input,
+        URL_SAFE_NO_PAD.encode(signature.to_bytes())
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_algorithm_conversion() {
-        // Only ES256 is supported
-        assert_eq!(algorithm_to_jwt(DpopAlgorithm::ES256), Algorithm::ES256);
+        assert_eq!(
+            algorithm_to_jwt(DpopAlgorithm::ES256).unwrap(),
+            Algorithm::ES256
+        );
+        assert_eq!(
+            algorithm_to_jwt(DpopAlgorithm::ES384).unwrap(),
+            Algorithm::ES384
+        );
+        assert!(algorithm_to_jwt(DpopAlgorithm::ES512).is_err());
 
         assert_eq!(
             jwt_to_algorithm(Algorithm::ES256).unwrap(),
             DpopAlgorithm::ES256
         );
+        assert_eq!(
+            jwt_to_algorithm(Algorithm::ES384).unwrap(),
+            DpopAlgorithm::ES384
+        );
 
         // All other algorithms should error (including RSA variants)
         assert!(jwt_to_algorithm(Algorithm::RS256).is_err());
@@ -182,6 +424,262 @@ mod tests {
         assert!(jwt_to_algorithm(Algorithm::HS384).is_err());
     }
 
+    #[tokio::test]
+    async fn test_validator_honours_custom_clock_tolerance() {
+        use std::time::{Duration, UNIX_EPOCH};
+
+        let generator = crate::DpopProofGenerator::new_simple().await.unwrap();
+        let mut proof = generator
+            .generate_proof("POST", "https://api.example.com/resource", None)
+            .await
+            .unwrap();
+        let now = 1_700_000_000;
+        let clock = std::sync::Arc::new(crate::MockClock::at(
+            UNIX_EPOCH + Duration::from_secs(now as u64),
+        ));
+
+        let validator = DpopValidator::new()
+            .with_clock_tolerance(10)
+            .with_clock(clock);
+        assert_eq!(validator.clock_tolerance(), 10);
+
+        // Each accepted proof needs its own jti or the second is a replay.
+        proof.payload.iat = now - 8;
+        assert!(validator.validate(&proof, None).await.is_ok());
+        proof.payload.jti = uuid::Uuid::new_v4().to_string();
+        proof.payload.iat = now + 8;
+        assert!(validator.validate(&proof, None).await.is_ok());
+
+        proof.payload.jti = uuid::Uuid::new_v4().to_string();
+        proof.payload.iat = now - 12;
+        assert!(matches!(
+            validator.validate(&proof, None).await,
+            Err(crate::DpopError::ClockSkewTooLarge {
+                max_skew_seconds: 10,
+                ..
+            })
+        ));
+        proof.payload.iat = now + 12;
+        assert!(validator.validate(&proof, None).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_validator_rejects_reused_jti() {
+        let generator = crate::DpopProofGenerator::new_simple().await.unwrap();
+        let uri = "https://api.example.com/resource";
+        let proof = generator.generate_proof("POST", uri, None).await.unwrap();
+
+        let store: std::sync::Arc<dyn crate::NonceStore> =
+            std::sync::Arc::new(crate::MemoryNonceStore::new());
+        let validator = DpopValidator::new().with_nonce_store(store.clone());
+
+        validator.validate(&proof, None).await.unwrap();
+        assert!(store.contains(&proof.payload.jti).await.unwrap());
+        assert!(matches!(
+            validator.validate(&proof, None).await,
+            Err(crate::DpopError::ReplayAttackDetected { .. })
+        ));
+
+        // A second validator on the same store sees the replay too
+        let other = DpopValidator::new().with_nonce_store(store);
+        assert!(matches!(
+            other.validate(&proof, None).await,
+            Err(crate::DpopError::ReplayAttackDetected { .. })
+        ));
+
+        // A proof rejected for another reason does not consume its jti
+        let mut bound = generator
+            .generate_proof("POST", uri, Some("token"))
+            .await
+            .unwrap();
+        assert!(validator.validate(&bound, Some("other")).await.is_err());
+        bound.payload.ath = None;
+        validator.validate(&bound, None).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_validate_batch_preserves_order() {
+        let generator = crate::DpopProofGenerator::new_simple().await.unwrap();
+        let uri = "https://api.example.com/resource";
+        let first = generator.generate_proof("POST", uri, None).await.unwrap();
+        let bound = generator
+            .generate_proof("GET", uri, Some("token"))
+            .await
+            .unwrap();
+        let last = generator.generate_proof("PUT", uri, None).await.unwrap();
+
+        let inputs = vec![
+            DpopProofInput::new(first.to_jwt_string()),
+            DpopProofInput::new("not.a.jwt"),
+            DpopProofInput::new(bound.to_jwt_string()).with_access_token("other"),
+            DpopProofInput::new(first.to_jwt_string()),
+            DpopProofInput::new(bound.to_jwt_string()).with_access_token("token"),
+            DpopProofInput::new(last.to_jwt_string()),
+        ];
+        let results = DpopValidator::new().validate_batch(&inputs).await;
+
+        assert_eq!(results.len(), inputs.len());
+        assert_eq!(results[0].as_ref().unwrap().jti, first.payload.jti);
+        assert!(matches!(
+            results[1],
+            Err(crate::DpopError::InvalidProofStructure { .. })
+        ));
+        assert!(matches!(
+            results[2],
+            Err(crate::DpopError::AccessTokenHashFailed { .. })
+        ));
+        assert!(matches!(
+            results[3],
+            Err(crate::DpopError::ReplayAttackDetected { .. })
+        ));
+        assert_eq!(results[4].as_ref().unwrap().htm, "GET");
+        assert_eq!(results[5].as_ref().unwrap().jti, last.payload.jti);
+    }
+
+    #[tokio::test]
+    async fn test_validator_skew_window_boundaries_with_mock_clock() {
+        use std::time::{Duration, UNIX_EPOCH};
+
+        const T0: i64 = 1_700_000_000;
+        let max = crate::MAX_CLOCK_SKEW_SECONDS;
+
+        let clock = std::sync::Arc::new(crate::MockClock::at(
+            UNIX_EPOCH + Duration::from_secs(T0 as u64),
+        ));
+        let validator = DpopValidator::new()
+            .with_clock_tolerance(max)
+            .with_clock(clock.clone());
+
+        let generator = crate::DpopProofGenerator::new_simple().await.unwrap();
+        let mut proof = generator
+            .generate_proof("POST", "https://api.example.com/resource", None)
+            .await
+            .unwrap();
+        let mut validate_at = |iat: i64| {
+            proof.payload.jti = uuid::Uuid::new_v4().to_string();
+            proof.payload.iat = iat;
+            let proof = proof.clone();
+            let validator = validator.clone();
+            async move { validator.validate(&proof, None).await }
+        };
+
+        // Exactly ±300 seconds is inside the window; one more second is not
+        assert!(validate_at(T0 - max).await.is_ok());
+        assert!(validate_at(T0 + max).await.is_ok());
+        assert!(matches!(
+            validate_at(T0 - max - 1).await,
+            Err(crate::DpopError::ClockSkewTooLarge {
+                skew_seconds: 301,
+                max_skew_seconds: 300,
+            })
+        ));
+        assert!(matches!(
+            validate_at(T0 + max + 1).await,
+            Err(crate::DpopError::ClockSkewTooLarge {
+                skew_seconds: -301,
+                ..
+            })
+        ));
+
+        // Advancing the clock ages a fixed proof out of the window
+        clock.advance(Duration::from_secs(max as u64));
+        assert!(validate_at(T0).await.is_ok());
+        clock.advance(Duration::from_secs(1));
+        assert!(validate_at(T0).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_validator_clock_drives_default_nonce_store() {
+        use std::time::{Duration, UNIX_EPOCH};
+
+        // Far from the real clock: replay detection must still use mock time
+        let clock = std::sync::Arc::new(crate::MockClock::at(
+            UNIX_EPOCH + Duration::from_secs(1_000_000),
+        ));
+        let validator = DpopValidator::new().with_clock(clock);
+
+        let generator = crate::DpopProofGenerator::new_simple().await.unwrap();
+        let mut proof = generator
+            .generate_proof("POST", "https://api.example.com/resource", None)
+            .await
+            .unwrap();
+        proof.payload.iat = 1_000_000;
+
+        validator.validate(&proof, None).await.unwrap();
+        assert!(matches!(
+            validator.validate(&proof, None).await,
+            Err(crate::DpopError::ReplayAttackDetected { .. })
+        ));
+    }
+
+    /// RFC 7638's own example (§3.1) is an RSA key, which this crate does not
+    /// support; its vector `NzbLsXh8uDCcd-6MNwXF4W_7noWXFZAfHkxZsRGC9Xs` is
+    /// unreachable here. RFC 9449 uses the same procedure for the P-256 key of
+    /// its proof examples (§4.1) and gives the resulting `cnf.jkt` in §6.1.
+    #[test]
+    fn test_jkt_thumbprint_known_answer() {
+        let decode =
+            |s: &str| -> [u8; 32] { URL_SAFE_NO_PAD.decode(s).unwrap().try_into().unwrap() };
+        let key_pair = crate::DpopKeyPair {
+            id: "rfc9449".to_string(),
+            private_key: DpopPrivateKey::EcdsaP256 {
+                key_bytes: [0u8; 32],
+            },
+            public_key: DpopPublicKey::EcdsaP256 {
+                x: decode("l8tFrhx-34tV3hRICRDY9zCkDlpBhF42UQUfWVAWBFs"),
+                y: decode("9VE4jf_Ok_o64zbTTlcuNJajHmt6v9TDVrU0CdvGRDA"),
+            },
+            thumbprint: String::new(),
+            algorithm: DpopAlgorithm::ES256,
+            created_at: std::time::SystemTime::now(),
+            expires_at: None,
+            metadata: crate::DpopKeyMetadata::default(),
+        };
+
+        assert_eq!(
+            jkt_thumbprint(&key_pair),
+            "0ZcOCORZNYy-DWpqq30jZyJGHTN0d2HglBV3uiguA4I"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_verify_token_binding() {
+        let key_pair = crate::DpopKeyPair::generate_p256().unwrap();
+        let other = crate::DpopKeyPair::generate_p256().unwrap();
+        assert_eq!(jkt_thumbprint(&key_pair), key_pair.thumbprint);
+
+        let proof = DpopProofParams::builder()
+            .http_method("GET")
+            .http_uri("https://api.example.com/resource")
+            .build()
+            .build_with_key(&key_pair)
+            .await
+            .unwrap();
+        let claims = DpopValidator::new().validate(&proof, None).await.unwrap();
+
+        verify_token_binding(&claims, &jkt_thumbprint(&key_pair)).unwrap();
+        assert!(matches!(
+            verify_token_binding(&claims, &jkt_thumbprint(&other)),
+            Err(DpopError::ThumbprintMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_validator_clock_tolerance_is_bounded() {
+        let validator = DpopValidator::new().with_clock_tolerance(3600);
+        assert_eq!(validator.clock_tolerance(), crate::MAX_CLOCK_SKEW_SECONDS);
+        assert_eq!(
+            DpopValidator::new()
+                .with_clock_tolerance(-5)
+                .clock_tolerance(),
+            0
+        );
+        assert_eq!(
+            DpopValidator::default().clock_tolerance(),
+            crate::DEFAULT_CLOCK_SKEW_SECONDS
+        );
+    }
+
     #[test]
     fn test_ec_key_coordinate_validation() {
         // Valid EC key should work
@@ -253,45 +751,214 @@ impl DpopProofParams {
 /// - Timestamp validation with clock skew tolerance
 /// - Access token binding (ath claim)
 /// - Required claim presence
+/// - Replay detection (`jti` reuse) through a [`NonceStore`](crate::NonceStore)
+///
+/// Clones share the same nonce store.
 #[derive(Debug, Clone)]
 pub struct DpopValidator {
     /// Clock skew tolerance in seconds
     clock_tolerance_secs: i64,
+    /// Seen `jti` values
+    nonce_store: std::sync::Arc<dyn crate::proof::NonceStore>,
+    /// Whether `nonce_store` was supplied by the caller
+    custom_nonce_store: bool,
+    /// Time source for the `iat` skew check
+    clock: std::sync::Arc<dyn crate::Clock>,
 }
 
 impl DpopValidator {
     /// Create a new validator with default settings
     ///
-    /// Default clock tolerance: 60 seconds
+    /// Default clock tolerance: [`DEFAULT_CLOCK_SKEW_SECONDS`](crate::DEFAULT_CLOCK_SKEW_SECONDS).
+    /// Replay detection uses a fresh [`MemoryNonceStore`](crate::MemoryNonceStore).
     #[must_use]
     pub fn new() -> Self {
         Self {
-            clock_tolerance_secs: 60,
+            clock_tolerance_secs: crate::DEFAULT_CLOCK_SKEW_SECONDS,
+            nonce_store: std::sync::Arc::new(crate::proof::MemoryNonceStore::new()),
+            custom_nonce_store: false,
+            clock: crate::SystemClock::shared(),
         }
     }
 
+    /// Use a custom time source for timestamp validation
+    ///
+    /// Defaults to [`SystemClock`](crate::SystemClock). Tests can inject a
+    /// [`MockClock`](crate::MockClock) to step across the skew window
+    /// deterministically. The clock only affects validation: proofs carry the
+    /// `iat` of whoever generated them, so a
+    /// [`DpopProofGenerator`](crate::DpopProofGenerator) needs its own
+    /// [`with_clock`](crate::DpopProofGenerator::with_clock) to stamp proofs
+    /// with the same time. The default in-memory nonce store follows this
+    /// clock; a store set with [`with_nonce_store`](Self::with_nonce_store)
+    /// keeps its own time source.
+    #[must_use]
+    pub fn with_clock(mut self, clock: std::sync::Arc<dyn crate::Clock>) -> Self {
+        if !self.custom_nonce_store {
+            self.nonce_store = std::sync::Arc::new(
+                crate::proof::MemoryNonceStore::new().with_clock(clock.clone()),
+            );
+        }
+        self.clock = clock;
+        self
+    }
+
+    /// Use `store` to remember accepted proof `jti` values
+    ///
+    /// Share one store across every validator (and, with a networked
+    /// backend, every node) that accepts proofs for the same clients.
+    #[must_use]
+    pub fn with_nonce_store(mut self, store: std::sync::Arc<dyn crate::proof::NonceStore>) -> Self {
+        self.nonce_store = store;
+        self.custom_nonce_store = true;
+        self
+    }
+
     /// Create a validator with custom clock tolerance
+    ///
+    /// A proof is accepted while its `iat` is at most `seconds` away from the
+    /// server clock in either direction. The value is clamped to
+    /// `0..=`[`MAX_CLOCK_SKEW_SECONDS`](crate::MAX_CLOCK_SKEW_SECONDS), so a
+    /// misconfiguration cannot widen the replay window past the RFC 9449 bound.
     #[must_use]
     pub fn with_clock_tolerance(mut self, seconds: i64) -> Self {
-        self.clock_tolerance_secs = seconds;
+        self.clock_tolerance_secs = seconds.clamp(0, crate::MAX_CLOCK_SKEW_SECONDS);
         self
     }
 
+    /// Clock skew tolerance applied to `iat`, in seconds
+    #[must_use]
+    pub fn clock_tolerance(&self) -> i64 {
+        self.clock_tolerance_secs
+    }
+
     /// Validate a DPoP proof
     ///
     /// Performs comprehensive validation including:
     /// - JWT header type is "dpop+jwt"
-    /// - JWK is present in header
+    /// - JWK is present in header and on the curve named by `alg`
     /// - Timestamp is recent (within clock tolerance)
     /// - Access token binding if token provided
+    /// - The `jti` has not been accepted before; it is remembered until the
+    ///   proof's `iat` falls outside the clock tolerance
     ///
     /// # Errors
-    /// Returns error if validation fails
+    /// Returns error if validation fails, including
+    /// [`DpopError::ReplayAttackDetected`](crate::DpopError::ReplayAttackDetected)
+    /// for a reused `jti`
     pub async fn validate(
         &self,
         proof: &crate::types::DpopProof,
         access_token: Option<&str>,
     ) -> crate::Result<ValidatedDpopClaims> {
+        self.check_proof(proof, access_token)?;
+
+        // Record the jti last, so a proof rejected above does not burn it
+        self.record_jti(&proof.payload).await?;
+
+        Ok(ValidatedDpopClaims::from_proof(proof))
+    }
+
+    /// Validate many serialized proofs at once
+    ///
+    /// Each proof is parsed and its signature verified with
+    /// [`DpopProof::from_jwt_string`](crate::DpopProof::from_jwt_string), then
+    /// put through the same checks as [`validate`](Self::validate). The
+    /// signature work is spread across tokio's blocking pool, and the `jti`
+    /// values of every proof that passes are recorded with a single
+    /// [`NonceStore::insert_many_if_absent`](crate::NonceStore::insert_many_if_absent)
+    /// call. A `jti` repeated within the batch is accepted once.
+    ///
+    /// Results are returned in the order of `proofs`.
+    pub async fn validate_batch(
+        &self,
+        proofs: &[DpopProofInput],
+    ) -> Vec<crate::Result<ValidatedDpopClaims>> {
+        if proofs.is_empty() {
+            return Vec::new();
+        }
+
+        let workers = std::thread::available_parallelism()
+            .map_or(1, std::num::NonZeroUsize::get)
+            .min(proofs.len());
+        let chunk_size = proofs.len().div_ceil(workers);
+
+        let tasks: Vec<_> = proofs
+            .chunks(chunk_size)
+            .map(|chunk| {
+                let validator = self.clone();
+                let chunk = chunk.to_vec();
+                tokio::task::spawn_blocking(move || {
+                    chunk
+                        .iter()
+                        .map(|input| validator.check_jwt(input))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        let mut checked = Vec::with_capacity(proofs.len());
+        for (task, chunk) in tasks.into_iter().zip(proofs.chunks(chunk_size)) {
+            match task.await {
+                Ok(results) => checked.extend(results),
+                Err(e) => checked.extend(chunk.iter().map(|_| {
+                    Err(crate::errors::DpopError::InternalError {
+                        reason: format!("Batch validation task failed: {}", e),
+                    })
+                })),
+            }
+        }
+
+        // One nonce-store round trip for every proof that passed the checks
+        let entries: Vec<(String, i64)> = checked
+            .iter()
+            .filter_map(|result| result.as_ref().ok())
+            .map(|claims| (claims.jti.clone(), self.jti_expiry(claims.iat)))
+            .collect();
+        let inserted = match self.nonce_store.insert_many_if_absent(&entries).await {
+            Ok(flags) if flags.len() == entries.len() => flags,
+            Ok(flags) => {
+                let err = crate::errors::DpopError::StorageError {
+                    reason: format!(
+                        "Nonce store returned {} results for {} entries",
+                        flags.len(),
+                        entries.len()
+                    ),
+                };
+                return checked
+                    .into_iter()
+                    .map(|r| r.and(Err(err.clone())))
+                    .collect();
+            }
+            Err(err) => {
+                return checked
+                    .into_iter()
+                    .map(|r| r.and(Err(err.clone())))
+                    .collect();
+            }
+        };
+
+        let mut inserted = inserted.into_iter();
+        checked
+            .into_iter()
+            .map(|result| {
+                result.and_then(|claims| {
+                    if inserted.next().unwrap_or(false) {
+                        Ok(claims)
+                    } else {
+                        Err(crate::errors::DpopError::ReplayAttackDetected { nonce: claims.jti })
+                    }
+                })
+            })
+            .collect()
+    }
+
+    /// Every check in [`validate`](Self::validate) that needs no shared state
+    fn check_proof(
+        &self,
+        proof: &crate::types::DpopProof,
+        access_token: Option<&str>,
+    ) -> crate::Result<()> {
         // Validate header
         self.validate_header(&proof.header)?;
 
@@ -306,13 +973,14 @@ impl DpopValidator {
             self.validate_access_token_binding(proof, token)?;
         }
 
-        Ok(ValidatedDpopClaims {
-            htm: proof.payload.htm.clone(),
-            htu: proof.payload.htu.clone(),
-            ath: proof.payload.ath.clone(),
-            jti: proof.payload.jti.clone(),
-            iat: proof.payload.iat,
-        })
+        Ok(())
+    }
+
+    /// Parse, verify and check one batch input
+    fn check_jwt(&self, input: &DpopProofInput) -> crate::Result<ValidatedDpopClaims> {
+        let proof = crate::types::DpopProof::from_jwt_string(&input.jwt)?;
+        self.check_proof(&proof, input.access_token.as_deref())?;
+        Ok(ValidatedDpopClaims::from_proof(&proof))
     }
 
     fn validate_header(&self, header: &crate::types::DpopHeader) -> crate::Result<()> {
@@ -326,14 +994,15 @@ impl DpopValidator {
                 ),
             });
         }
-        Ok(())
+        // The embedded key must be on the curve `alg` names
+        check_jwk_curve(header)
     }
 
     fn validate_timestamp(&self, payload: &crate::types::DpopPayload) -> crate::Result<()> {
-        use std::time::{SystemTime, UNIX_EPOCH};
-
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
+        let now = self
+            .clock
+            .now()
+            .duration_since(std::time::UNIX_EPOCH)
             .map_err(|e| crate::errors::DpopError::InternalError {
                 reason: format!("System time error: {}", e),
             })?
@@ -351,6 +1020,23 @@ impl DpopValidator {
         Ok(())
     }
 
+    /// How long a `jti` must be remembered: the proof stays acceptable while
+    /// `iat` is within the tolerance
+    fn jti_expiry(&self, iat: i64) -> i64 {
+        iat.saturating_add(self.clock_tolerance_secs)
+    }
+
+    async fn record_jti(&self, payload: &crate::types::DpopPayload) -> crate::Result<()> {
+        let exp = self.jti_expiry(payload.iat);
+        if self.nonce_store.insert_if_absent(&payload.jti, exp).await? {
+            Ok(())
+        } else {
+            Err(crate::errors::DpopError::ReplayAttackDetected {
+                nonce: payload.jti.clone(),
+            })
+        }
+    }
+
     fn validate_required_claims(&self, payload: &crate::types::DpopPayload) -> crate::Result<()> {
         if payload.jti.is_empty() {
             return Err(crate::errors::DpopError::InvalidProofStructure {
@@ -403,10 +1089,38 @@ impl Default for DpopValidator {
     }
 }
 
+/// One serialized proof for [`DpopValidator::validate_batch`]
+#[derive(Debug, Clone)]
+pub struct DpopProofInput {
+    /// Compact JWS from the request's `DPoP` header
+    pub jwt: String,
+    /// Access token presented with the proof, checked against `ath`
+    pub access_token: Option<String>,
+}
+
+impl DpopProofInput {
+    /// A proof presented without an access token
+    #[must_use]
+    pub fn new(jwt: impl Into<String>) -> Self {
+        Self {
+            jwt: jwt.into(),
+            access_token: None,
+        }
+    }
+
+    /// Bind the proof to `access_token`
+    #[must_use]
+    pub fn with_access_token(mut self, access_token: impl Into<String>) -> Self {
+        self.access_token = Some(access_token.into());
+        self
+    }
+}
+
 /// Validated DPoP proof claims
 ///
 /// Contains the validated claims from a DPoP proof after successful validation.
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct ValidatedDpopClaims {
     /// HTTP method
     pub htm: String,
@@ -418,4 +1132,53 @@ pub struct ValidatedDpopClaims {
     pub jti: String,
     /// Issued at timestamp
     pub iat: i64,
+    /// RFC 7638 thumbprint of the proof's public key, for `cnf.jkt` checks
+    pub jkt: String,
+}
+
+impl ValidatedDpopClaims {
+    fn from_proof(proof: &crate::types::DpopProof) -> Self {
+        Self {
+            htm: proof.payload.htm.clone(),
+            htu: proof.payload.htu.clone(),
+            ath: proof.payload.ath.clone(),
+            jti: proof.payload.jti.clone(),
+            iat: proof.payload.iat,
+            jkt: crate::types::jwk_thumbprint(&proof.header.jwk),
+        }
+    }
+}
+
+/// Compute the `jkt` (RFC 7638 JWK SHA-256 thumbprint) of a key pair's public key
+///
+/// This is the value an authorization server puts in the access token's
+/// `cnf.jkt` claim to bind the token to the key (RFC 9449 §6.1). The canonical
+/// JWK uses the required EC members in lexicographic order: `crv`, `kty`, `x`, `y`.
+/// It is recomputed from the public key rather than read from
+/// [`DpopKeyPair::thumbprint`](crate::DpopKeyPair::thumbprint).
+#[must_use]
+pub fn jkt_thumbprint(key_pair: &crate::types::DpopKeyPair) -> String {
+    crate::types::jwk_thumbprint(&crate::types::DpopJwk::from_public_key(
+        &key_pair.public_key,
+    ))
+}
+
+/// Check that a validated proof was signed by the key an access token is bound to
+///
+/// `expected_jkt` is the `cnf.jkt` claim of the access token presented with
+/// the proof (RFC 9449 §6.1). The comparison runs in constant time.
+///
+/// # Errors
+/// Returns [`DpopError::ThumbprintMismatch`] if the proof's key thumbprint differs.
+pub fn verify_token_binding(claims: &ValidatedDpopClaims, expected_jkt: &str) -> Result<()> {
+    use subtle::ConstantTimeEq;
+
+    if claims.jkt.as_bytes().ct_eq(expected_jkt.as_bytes()).into() {
+        Ok(())
+    } else {
+        Err(DpopError::ThumbprintMismatch {
+            expected: expected_jkt.to_string(),
+            actual: claims.jkt.clone(),
+        })
+    }
 }
@@ -9,6 +9,20 @@ use std::time::Duration;
 use tokio;
 use tracing::trace;
 
+/// Reject algorithms other than ES256, the only one HSM backends implement
+pub fn ensure_es256(algorithm: DpopAlgorithm, backend_name: &str) -> Result<()> {
+    match algorithm {
+        DpopAlgorithm::ES256 => Ok(()),
+        other => Err(unsupported_algorithm(other, backend_name)),
+    }
+}
+
+fn unsupported_algorithm(algorithm: DpopAlgorithm, backend_name: &str) -> DpopError {
+    DpopError::CryptographicError {
+        reason: format!("{backend_name} HSM backend supports only ES256, not {algorithm}"),
+    }
+}
+
 /// Compute RFC 7638 compliant JWK thumbprint for any public key
 ///
 /// This implements the canonical JWK thumbprint computation as specified in RFC 7638,
@@ -51,6 +65,7 @@ pub fn compute_jwk_thumbprint(
                 x_b64, y_b64
             )
         }
+        _ => return Err(unsupported_algorithm(algorithm, backend_name)),
     };
 
     // RFC 7638 Section 3: Compute SHA-256 hash of canonical JWK UTF-8 bytes
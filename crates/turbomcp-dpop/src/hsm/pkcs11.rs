@@ -536,6 +536,7 @@ impl HsmOperations for Pkcs11HsmManager {
         Box::pin(async move {
             let start_time = Instant::now();
             debug!("Generating {:?} key pair in PKCS#11 HSM", algorithm);
+            common::ensure_es256(algorithm, "PKCS#11")?;
 
             // Clone session pool for moving into blocking task
             let session_pool = self.session_pool.clone();
@@ -489,6 +489,7 @@ impl HsmOperations for YubiHsmManager {
             let start_time = Instant::now();
 
             debug!("Generating {:?} key pair in YubiHSM 2", algorithm);
+            common::ensure_es256(algorithm, "YubiHSM")?;
 
             self.ensure_connection().await?;
 
@@ -9,7 +9,6 @@ use std::pin::Pin;
 use std::sync::Arc;
 use std::time::{Duration, SystemTime};
 
-use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
 use p256::elliptic_curve::rand_core::OsRng;
 use tokio::sync::{Notify, RwLock};
 use tokio::task::JoinHandle;
@@ -65,23 +64,13 @@ impl DpopKeyManager {
 
     /// Generate a new DPoP key pair
     ///
-    /// Only ES256 (ECDSA P-256) is supported as of TurboMCP v3.0+
-    /// The algorithm parameter is kept for API compatibility but must be ES256
+    /// Supports ES256 (P-256), ES384 (P-384) and ES512 (P-521). RSA is not
+    /// available (removed due to RUSTSEC-2023-0071).
     pub async fn generate_key_pair(&self, algorithm: DpopAlgorithm) -> Result<DpopKeyPair> {
-        // Validate algorithm (only ES256 supported)
-        if algorithm != DpopAlgorithm::ES256 {
-            return Err(DpopError::CryptographicError {
-                reason: format!(
-                    "Unsupported algorithm: {}. Only ES256 is supported (RSA removed due to RUSTSEC-2023-0071)",
-                    algorithm
-                ),
-            });
-        }
-
         let key_id = Uuid::new_v4().to_string();
         let now = SystemTime::now();
 
-        let (private_key, public_key) = generate_es256_key_pair()?;
+        let (private_key, public_key) = generate_ec_key_pair(algorithm)?;
 
         let key_pair = DpopKeyPair {
             id: key_id.clone(),
@@ -406,79 +395,114 @@ impl Default for MemoryKeyStorage {
     }
 }
 
-/// Generate ES256 (ECDSA P-256) key pair
-fn generate_es256_key_pair() -> Result<(DpopPrivateKey, DpopPublicKey)> {
-    use p256::ecdsa::{SigningKey, VerifyingKey};
-
-    // Generate random signing key
-    let signing_key = SigningKey::random(&mut OsRng);
-    let verifying_key = VerifyingKey::from(&signing_key);
-
-    // Extract private key bytes
-    let private_bytes = signing_key.to_bytes();
-    let private_key = DpopPrivateKey::EcdsaP256 {
-        key_bytes: private_bytes.into(),
-    };
-
-    // Extract public key coordinates
-    let public_point = verifying_key.to_encoded_point(false); // Uncompressed format
-    let x_coord = public_point
-        .x()
-        .ok_or_else(|| DpopError::CryptographicError {
-            reason: "Failed to extract X coordinate from P-256 key".to_string(),
-        })?;
-    let x_bytes: [u8; 32] = (*x_coord).into();
-
-    let y_coord = public_point
-        .y()
-        .ok_or_else(|| DpopError::CryptographicError {
-            reason: "Failed to extract Y coordinate from P-256 key".to_string(),
-        })?;
-    let y_bytes: [u8; 32] = (*y_coord).into();
-
-    let public_key = DpopPublicKey::EcdsaP256 {
-        x: x_bytes,
-        y: y_bytes,
-    };
-
-    Ok((private_key, public_key))
+/// Generate an ECDSA key pair on the curve used by `algorithm`
+pub(crate) fn generate_ec_key_pair(
+    algorithm: DpopAlgorithm,
+) -> Result<(DpopPrivateKey, DpopPublicKey)> {
+    match algorithm {
+        DpopAlgorithm::ES256 => {
+            let signing_key = p256::ecdsa::SigningKey::random(&mut OsRng);
+            let point = p256::ecdsa::VerifyingKey::from(&signing_key).to_encoded_point(false);
+            ec_key_pair_from_parts(
+                algorithm,
+                &signing_key.to_bytes(),
+                point.x().map(|c| c.as_slice()),
+                point.y().map(|c| c.as_slice()),
+            )
+        }
+        DpopAlgorithm::ES384 => {
+            let signing_key = p384::ecdsa::SigningKey::random(&mut OsRng);
+            let point = p384::ecdsa::VerifyingKey::from(&signing_key).to_encoded_point(false);
+            ec_key_pair_from_parts(
+                algorithm,
+                &signing_key.to_bytes(),
+                point.x().map(|c| c.as_slice()),
+                point.y().map(|c| c.as_slice()),
+            )
+        }
+        DpopAlgorithm::ES512 => {
+            let signing_key = p521::ecdsa::SigningKey::random(&mut OsRng);
+            let point = p521::ecdsa::VerifyingKey::from(&signing_key).to_encoded_point(false);
+            ec_key_pair_from_parts(
+                algorithm,
+                &signing_key.to_bytes(),
+                point.x().map(|c| c.as_slice()),
+                point.y().map(|c| c.as_slice()),
+            )
+        }
+    }
+}
+
+/// Wrap raw scalar and (uncompressed) point coordinates in the key variants
+/// matching `algorithm`
+fn ec_key_pair_from_parts(
+    algorithm: DpopAlgorithm,
+    scalar: &[u8],
+    x: Option<&[u8]>,
+    y: Option<&[u8]>,
+) -> Result<(DpopPrivateKey, DpopPublicKey)> {
+    fn fixed<const N: usize>(bytes: &[u8], what: &str, curve: &str) -> Result<[u8; N]> {
+        bytes.try_into().map_err(|_| DpopError::CryptographicError {
+            reason: format!("Unexpected {what} length for {curve} key"),
+        })
+    }
+
+    let curve = algorithm.curve();
+    let x = x.ok_or_else(|| DpopError::CryptographicError {
+        reason: format!("Failed to extract X coordinate from {curve} key"),
+    })?;
+    let y = y.ok_or_else(|| DpopError::CryptographicError {
+        reason: format!("Failed to extract Y coordinate from {curve} key"),
+    })?;
+
+    Ok(match algorithm {
+        DpopAlgorithm::ES256 => (
+            DpopPrivateKey::EcdsaP256 {
+                key_bytes: fixed(scalar, "scalar", curve)?,
+            },
+            DpopPublicKey::EcdsaP256 {
+                x: fixed(x, "X coordinate", curve)?,
+                y: fixed(y, "Y coordinate", curve)?,
+            },
+        ),
+        DpopAlgorithm::ES384 => (
+            DpopPrivateKey::EcdsaP384 {
+                key_bytes: fixed(scalar, "scalar", curve)?,
+            },
+            DpopPublicKey::EcdsaP384 {
+                x: fixed(x, "X coordinate", curve)?,
+                y: fixed(y, "Y coordinate", curve)?,
+            },
+        ),
+        DpopAlgorithm::ES512 => (
+            DpopPrivateKey::EcdsaP521 {
+                key_bytes: fixed(scalar, "scalar", curve)?,
+            },
+            DpopPublicKey::EcdsaP521 {
+                x: fixed(x, "X coordinate", curve)?,
+                y: fixed(y, "Y coordinate", curve)?,
+            },
+        ),
+    })
 }
 
 /// Compute JWK thumbprint for a public key
 ///
 /// RFC 7638 requires lexicographic ordering of JSON keys for canonical representation.
-/// This function manually constructs the canonical JSON to ensure proper ordering.
-///
-/// Only supports ES256 (ECDSA P-256) as of TurboMCP v3.0+
+/// The `crv` member is taken from the key's curve, so keys on different curves with
+/// identical coordinates still produce distinct thumbprints.
 fn compute_thumbprint(public_key: &DpopPublicKey, algorithm: DpopAlgorithm) -> Result<String> {
-    use sha2::{Digest, Sha256};
-
-    // RFC 7638 requires lexicographic ordering: crv, kty, x, y (for EC keys)
-    // We manually construct the JSON to guarantee this ordering
-    let canonical_json = match (public_key, algorithm) {
-        (DpopPublicKey::EcdsaP256 { x, y }, DpopAlgorithm::ES256) => {
-            let x_b64 = URL_SAFE_NO_PAD.encode(x);
-            let y_b64 = URL_SAFE_NO_PAD.encode(y);
-
-            // Escape JSON string values (base64url strings don't contain special chars, but ensure safety)
-            let x_escaped = x_b64.replace('\\', "\\\\").replace('"', "\\\"");
-            let y_escaped = y_b64.replace('\\', "\\\\").replace('"', "\\\"");
-
-            // Construct canonical JSON with guaranteed lexicographic key ordering
-            format!(
-                r#"{{"crv":"P-256","kty":"EC","x":"{}","y":"{}"}}"#,
-                x_escaped, y_escaped
-            )
-        }
-    };
-
-    // Compute SHA-256 hash
-    let mut hasher = Sha256::new();
-    hasher.update(canonical_json.as_bytes());
-    let hash = hasher.finalize();
+    if public_key.algorithm() != algorithm {
+        return Err(DpopError::CryptographicError {
+            reason: format!(
+                "Key on curve {} cannot be used with algorithm {}",
+                public_key.algorithm().curve(),
+                algorithm
+            ),
+        });
+    }
 
-    // Return base64url-encoded thumbprint
-    Ok(URL_SAFE_NO_PAD.encode(hash))
+    crate::types::compute_jwk_thumbprint(&crate::types::DpopJwk::from_public_key(public_key))
 }
 
 /// Constant-time string comparison to prevent timing attacks
@@ -840,7 +864,6 @@ mod tests {
     async fn test_key_generation_algorithms() {
         let key_manager = DpopKeyManager::new_memory().await.unwrap();
 
-        // Test ES256 - only supported algorithm
         let es256_key = key_manager
             .generate_key_pair(DpopAlgorithm::ES256)
             .await
@@ -850,6 +873,34 @@ mod tests {
             es256_key.private_key,
             DpopPrivateKey::EcdsaP256 { .. }
         ));
+
+        let es384_key = key_manager
+            .generate_key_pair(DpopAlgorithm::ES384)
+            .await
+            .unwrap();
+        assert_eq!(es384_key.algorithm, DpopAlgorithm::ES384);
+        assert!(matches!(
+            es384_key.private_key,
+            DpopPrivateKey::EcdsaP384 { .. }
+        ));
+        assert!(matches!(
+            es384_key.public_key,
+            DpopPublicKey::EcdsaP384 { .. }
+        ));
+
+        let es512_key = key_manager
+            .generate_key_pair(DpopAlgorithm::ES512)
+            .await
+            .unwrap();
+        assert_eq!(es512_key.algorithm, DpopAlgorithm::ES512);
+        assert!(matches!(
+            es512_key.private_key,
+            DpopPrivateKey::EcdsaP521 { .. }
+        ));
+        assert!(matches!(
+            es512_key.public_key,
+            DpopPublicKey::EcdsaP521 { .. }
+        ));
     }
 
     #[tokio::test]
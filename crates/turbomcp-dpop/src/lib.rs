@@ -9,16 +9,17 @@
 //! ## Core Features
 //!
 //! - ✅ **RFC 9449 Compliance** - Full specification implementation
-//! - ✅ **Cryptographic Security** - ES256 (ECDSA P-256) only for maximum security
+//! - ✅ **Cryptographic Security** - ECDSA only: ES256 (default), ES384 and ES512
 //! - ✅ **Token Binding** - Prevents stolen token usage
-//! - ✅ **Replay Protection** - Nonce tracking and timestamp validation
+//! - ✅ **Replay Protection** - Pluggable `jti` stores (`NonceStore`) and timestamp validation
 //! - ✅ **Production Features** - HSM integration, audit logging, key rotation
 //!
 //! ## Security Notice
 //!
 //! **TurboMCP v3.0+** removes RSA algorithm support (RS256, PS256) to eliminate
-//! timing attack vulnerabilities (RUSTSEC-2023-0071). Only ES256 (ECDSA P-256) is supported.
-//! ES256 provides superior security, faster performance, and smaller key sizes.
+//! timing attack vulnerabilities (RUSTSEC-2023-0071). Only ECDSA is supported: ES256
+//! (P-256, the default), ES384 (P-384) and ES512 (P-521). Proofs whose embedded JWK is
+//! not on the curve named by `alg` are rejected. HSM backends support ES256 only.
 //!
 //! ## Architecture
 //!
@@ -64,7 +65,12 @@ pub use proof::*;
 pub use types::*;
 
 // Re-export builder and validator from helpers
-pub use helpers::{DpopProofParams, DpopProofParamsBuilder, DpopValidator, ValidatedDpopClaims};
+pub use helpers::{
+    DpopProofInput, DpopProofParams, DpopProofParamsBuilder, DpopValidator, ValidatedDpopClaims,
+};
+
+// Re-export the time source used for proof timestamps and lifetime checks
+pub use turbomcp_core::clock::{Clock, MockClock, SystemClock};
 
 /// DPoP result type
 pub type Result<T> = std::result::Result<T, DpopError>;
@@ -14,6 +14,7 @@ use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
 use sha2::{Digest, Sha256};
 use tokio::sync::RwLock;
 use tracing::debug;
+use turbomcp_core::clock::{Clock, SystemClock};
 use uuid::Uuid;
 
 use super::{
@@ -58,6 +59,8 @@ pub struct DpopProofGenerator {
     clock_skew_tolerance: Duration,
     /// Default proof lifetime
     proof_lifetime: Duration,
+    /// Time source for `iat` claims and lifetime checks
+    clock: Arc<dyn Clock>,
 }
 
 impl DpopProofGenerator {
@@ -73,18 +76,40 @@ impl DpopProofGenerator {
         nonce_tracker: Arc<dyn NonceTracker>,
     ) -> Self {
         // Default to the *recommended* skew tolerance (60s), not the upper-bound
-        // `MAX_CLOCK_SKEW_SECONDS` (300s). Operators that need a wider window can
-        // override via the `clock_skew_tolerance` field; the hard cap remains
-        // available via `MAX_CLOCK_SKEW_SECONDS` for callers that need it.
-        let _ = MAX_CLOCK_SKEW_SECONDS; // referenced for the doc-link.
+        // `MAX_CLOCK_SKEW_SECONDS` (300s). Operators that need a different window
+        // can override it with `with_clock_skew_tolerance`, which is capped at
+        // `MAX_CLOCK_SKEW_SECONDS`.
         Self {
             key_manager,
             nonce_tracker,
             clock_skew_tolerance: Duration::from_secs(DEFAULT_CLOCK_SKEW_SECONDS as u64),
             proof_lifetime: Duration::from_secs(DEFAULT_PROOF_LIFETIME_SECONDS),
+            clock: SystemClock::shared(),
         }
     }
 
+    /// Use a custom time source for proof timestamps and validation.
+    ///
+    /// Defaults to [`SystemClock`]. Tests can inject a
+    /// [`MockClock`](turbomcp_core::clock::MockClock) to exercise expiry
+    /// without sleeping.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Set the clock skew tolerance applied to `iat` during validation.
+    ///
+    /// Defaults to [`DEFAULT_CLOCK_SKEW_SECONDS`]. Values above
+    /// [`MAX_CLOCK_SKEW_SECONDS`] are capped to it.
+    #[must_use]
+    pub fn with_clock_skew_tolerance(mut self, tolerance: Duration) -> Self {
+        self.clock_skew_tolerance =
+            tolerance.min(Duration::from_secs(MAX_CLOCK_SKEW_SECONDS as u64));
+        self
+    }
+
     /// Create a simple proof generator for basic use cases
     ///
     /// Uses in-memory storage for key management and nonce tracking.
@@ -161,7 +186,9 @@ impl DpopProofGenerator {
         let jti = Uuid::new_v4().to_string();
 
         // Current timestamp
-        let now = SystemTime::now()
+        let now = self
+            .clock
+            .now()
             .duration_since(UNIX_EPOCH)
             .map_err(|_| DpopError::InternalError {
                 reason: "System clock before Unix epoch".to_string(),
@@ -189,15 +216,17 @@ impl DpopProofGenerator {
         // Create JWK from public key for the DpopHeader
         // Note: This creates our custom DpopJwk for the proof structure
         // The actual JWT signing uses jsonwebtoken::Jwk (created in sign_jwt)
-        // Only ES256 (ECDSA P-256) is supported
-        let jwk = match (&key_pair.public_key, key_pair.algorithm) {
-            (DpopPublicKey::EcdsaP256 { x, y }, DpopAlgorithm::ES256) => DpopJwk::Ec {
-                use_: "sig".to_string(),
-                crv: "P-256".to_string(),
-                x: URL_SAFE_NO_PAD.encode(x),
-                y: URL_SAFE_NO_PAD.encode(y),
-            },
-        };
+        if key_pair.public_key.algorithm() != key_pair.algorithm {
+            return Err(DpopError::CryptographicError {
+                reason: format!(
+                    "Key pair {} is on curve {} but declares algorithm {}",
+                    key_pair.id,
+                    key_pair.public_key.algorithm().curve(),
+                    key_pair.algorithm
+                ),
+            });
+        }
+        let jwk = DpopJwk::from_public_key(&key_pair.public_key);
 
         // Create JWT header
         let header = DpopHeader {
@@ -411,7 +440,9 @@ impl DpopProofGenerator {
 
     /// Validate proof timestamp and expiration
     fn validate_timestamp(&self, proof: &DpopProof) -> Result<()> {
-        let now = SystemTime::now()
+        let now = self
+            .clock
+            .now()
             .duration_since(UNIX_EPOCH)
             .map_err(|_| DpopError::InternalError {
                 reason: "System clock before Unix epoch".to_string(),
@@ -483,17 +514,14 @@ impl DpopProofGenerator {
         Ok(())
     }
 
-    /// Validate cryptographic signature using industry-standard jsonwebtoken
+    /// Validate cryptographic signature
     ///
-    /// This replaces custom signature verification with jsonwebtoken::decode().
-    /// Security improvements:
-    /// - Eliminates ~200 lines of custom crypto verification code
-    /// - Uses battle-tested library (9.3M+ downloads)
+    /// ES256 and ES384 are verified with jsonwebtoken::decode(); ES512, which
+    /// jsonwebtoken lacks, is verified with the `p521` crate.
     /// - Proper algorithm validation (prevents "none" algorithm attack)
     /// - Industry-standard verification (RFC 7515)
     async fn validate_signature(&self, proof: &DpopProof) -> Result<()> {
-        use crate::helpers::jwk_to_decoding_key;
-        use jsonwebtoken::{Validation, decode, decode_header};
+        use crate::helpers::{decode_proof_header, verify_proof_signature};
 
         // Get the JWT string from the proof
         // CRITICAL: We must use the exact JWT string that was signed, not reconstruct it
@@ -502,103 +530,38 @@ impl DpopProofGenerator {
 
         tracing::debug!(jwt_len = jwt.len(), "Validating JWT signature");
 
-        // 1. Decode header (peek, no signature verification yet)
-        let header = decode_header(&jwt).map_err(|e| DpopError::InvalidProofStructure {
-            reason: format!("Failed to decode JWT header: {}", e),
-        })?;
-
-        // 2. Validate algorithm is allowed (whitelist - prevents "none" algorithm attack)
-        // Only ES256 is supported as of TurboMCP v3.0+ (RSA removed due to RUSTSEC-2023-0071)
-        const ALLOWED_ALGS: &[jsonwebtoken::Algorithm] = &[jsonwebtoken::Algorithm::ES256];
-        if !ALLOWED_ALGS.contains(&header.alg) {
-            return Err(DpopError::InvalidProofStructure {
-                reason: format!(
-                    "Algorithm {:?} not allowed for DPoP. Only ES256 is supported (RSA removed due to RUSTSEC-2023-0071)",
-                    header.alg
-                ),
-            });
-        }
-
-        // 3. Validate typ field
-        if header.typ.as_deref() != Some(DPOP_JWT_TYPE) {
-            return Err(DpopError::InvalidProofStructure {
-                reason: format!(
-                    "Invalid JWT typ: expected '{}', got '{:?}'",
-                    DPOP_JWT_TYPE, header.typ
-                ),
-            });
-        }
+        // 1. Decode header (peek, no signature verification yet). This whitelists
+        //    ES256/ES384/ES512 (prevents "none" and RSA), checks typ, and requires
+        //    the embedded JWK to be on the curve that alg names.
+        let header = decode_proof_header(&jwt)?;
 
-        // 4. Extract JWK from header (BEFORE signature verification)
-        let jwk = header.jwk.ok_or_else(|| DpopError::InvalidProofStructure {
-            reason: "DPoP proof missing JWK in header".to_string(),
-        })?;
-
-        // 5. Create decoding key from JWK
-        let decoding_key = jwk_to_decoding_key(&jwk)?;
-
-        // 6. Configure validation
-        let mut validation = Validation::new(header.alg);
-        validation.validate_exp = false; // DPoP uses iat, not exp
-        validation.set_required_spec_claims(&["iat"]); // Require iat claim
-        validation.leeway = 60; // 60 seconds clock skew tolerance (MCP spec)
-
-        // 7. Decode and VERIFY SIGNATURE
-        // This is the critical security step - jsonwebtoken verifies the signature
-        let _token_data = decode::<DpopPayload>(&jwt, &decoding_key, &validation).map_err(|e| {
-            DpopError::ProofValidationFailed {
-                reason: format!("JWT signature verification failed: {}", e),
-            }
-        })?;
+        // 2. Verify the signature against the embedded JWK
+        verify_proof_signature(&jwt, &header)?;
 
         tracing::debug!(
-            algorithm = ?header.alg,
-            "Successfully verified DPoP JWT signature using jsonwebtoken"
+            algorithm = %header.algorithm,
+            "Successfully verified DPoP JWT signature"
         );
 
         Ok(())
     }
 
-    /// Sign a JWT with the given private key using industry-standard jsonwebtoken
+    /// Sign a JWT with the given private key
     ///
-    /// This replaces custom JWT construction with the battle-tested jsonwebtoken crate.
-    /// Security improvements:
-    /// - Eliminates ~400 lines of custom crypto code
-    /// - Uses proven library (9.3M+ downloads)
-    /// - Automatic security updates via dependency
-    /// - Industry-standard JWT construction (RFC 7515)
+    /// ES256 and ES384 use the jsonwebtoken crate (RFC 7515); ES512 is signed
+    /// with the `p521` crate because jsonwebtoken does not implement it.
     async fn sign_jwt(
         &self,
         header: &DpopHeader,
         payload: &DpopPayload,
         private_key: &DpopPrivateKey,
-        public_key: &DpopPublicKey, // Added public_key parameter
+        public_key: &DpopPublicKey,
     ) -> Result<String> {
-        use crate::helpers::{algorithm_to_jwt, private_key_to_encoding_key, public_key_to_jwk};
-        use jsonwebtoken::{Header, encode};
-
-        // Create jsonwebtoken Header with DPoP-specific fields
-        let mut jwt_header = Header::new(algorithm_to_jwt(header.algorithm));
-        jwt_header.typ = Some(DPOP_JWT_TYPE.to_string());
-
-        // Embed JWK in header (RFC 9449 requirement)
-        // Create jsonwebtoken::Jwk directly from public key (not from custom DpopJwk)
-        let jwk = public_key_to_jwk(public_key)?;
-        jwt_header.jwk = Some(jwk);
-
-        // Create EncodingKey from private key
-        let encoding_key = private_key_to_encoding_key(private_key)?;
-
-        // Sign JWT using jsonwebtoken (handles all RFC 7515 mechanics)
-        let jwt = encode(&jwt_header, payload, &encoding_key).map_err(|e| {
-            DpopError::CryptographicError {
-                reason: format!("JWT signing failed: {}", e),
-            }
-        })?;
+        let jwt = crate::helpers::sign_proof(header, payload, private_key, public_key)?;
 
         tracing::debug!(
             algorithm = ?header.algorithm,
-            "Signed DPoP JWT using jsonwebtoken"
+            "Signed DPoP JWT"
         );
 
         Ok(jwt)
@@ -621,6 +584,12 @@ pub struct DpopValidationResult {
 }
 
 /// Trait for nonce tracking to prevent replay attacks
+///
+/// This is the interface [`DpopProofGenerator`] validates against. Both
+/// built-in trackers are thin adapters over a [`NonceStore`], which is the
+/// single replay primitive shared with [`DpopValidator`](crate::DpopValidator);
+/// [`NonceStorage`](crate::NonceStorage) remains the metadata-rich audit
+/// backend and is not consulted for replay decisions.
 pub trait NonceTracker: Send + Sync + std::fmt::Debug {
     /// Track a nonce as used
     fn track_nonce(
@@ -647,17 +616,16 @@ pub const DEFAULT_NONCE_CAPACITY: usize = 1_000_000;
 
 /// In-memory nonce tracker for development and testing.
 ///
-/// Implements time-ordered eviction with a hard capacity cap. Inline cleanup runs when
-/// the map crosses 80 % of capacity so steady-state load doesn't accumulate expired
-/// entries. For multi-process deployments, prefer the Redis-backed tracker.
+/// Backed by a [`MemoryNonceStore`], so it shares its time-ordered eviction and
+/// hard capacity cap. Each tracked nonce is remembered for `max_nonce_age` from
+/// the moment it is tracked. For multi-process deployments, prefer the
+/// Redis-backed tracker.
 #[derive(Debug)]
 pub struct MemoryNonceTracker {
-    /// Set of used nonces with their timestamps
-    used_nonces: Arc<RwLock<HashMap<String, i64>>>,
-    /// Maximum age for nonces (after which they can be cleaned up)
+    /// Seen nonces
+    store: MemoryNonceStore,
+    /// How long a tracked nonce is remembered
     max_nonce_age: Duration,
-    /// Hard cap on resident entries — prevents memory exhaustion via unique-JTI flooding.
-    capacity: usize,
 }
 
 impl MemoryNonceTracker {
@@ -671,56 +639,36 @@ impl MemoryNonceTracker {
     #[must_use]
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
-            used_nonces: Arc::new(RwLock::new(HashMap::with_capacity(capacity.min(64 * 1024)))),
+            store: MemoryNonceStore::with_capacity(capacity),
             max_nonce_age: Duration::from_secs(600), // 10 minutes
-            capacity: capacity.max(1),
         }
     }
+
+    /// Use a custom time source for expiry decisions
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.store = self.store.with_clock(clock);
+        self
+    }
 }
 
 impl NonceTracker for MemoryNonceTracker {
     fn track_nonce(
         &self,
         nonce: &str,
-        issued_at: i64,
+        _issued_at: i64,
     ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + '_>> {
         let nonce = nonce.to_string();
-        let max_nonce_age = self.max_nonce_age;
-        let capacity = self.capacity;
+        let exp = self
+            .store
+            .now_secs()
+            .saturating_add(self.max_nonce_age.as_secs() as i64);
         Box::pin(async move {
-            let mut nonces = self.used_nonces.write().await;
-
-            if nonces.contains_key(&nonce) {
-                return Err(DpopError::ReplayAttackDetected { nonce });
+            if self.store.insert_if_absent(&nonce, exp).await? {
+                Ok(())
+            } else {
+                Err(DpopError::ReplayAttackDetected { nonce })
             }
-
-            // High-water cleanup at 80% so steady-state insertions amortize the work
-            // across many calls instead of stalling at the boundary.
-            if nonces.len() * 5 >= capacity * 4 {
-                let now_secs = SystemTime::now()
-                    .duration_since(UNIX_EPOCH)
-                    .map_err(|_| DpopError::InternalError {
-                        reason: "System clock before Unix epoch".to_string(),
-                    })?
-                    .as_secs() as i64;
-                let cutoff = now_secs - max_nonce_age.as_secs() as i64;
-                nonces.retain(|_, &mut ts| ts > cutoff);
-
-                // Still over capacity after age-based eviction? Drop oldest entries.
-                // Worst case (every entry within max_nonce_age) we trim down to the cap.
-                if nonces.len() >= capacity {
-                    let to_drop = nonces.len() - capacity + 1;
-                    let mut entries: Vec<(String, i64)> =
-                        nonces.iter().map(|(k, v)| (k.clone(), *v)).collect();
-                    entries.sort_by_key(|(_, ts)| *ts);
-                    for (key, _) in entries.into_iter().take(to_drop) {
-                        nonces.remove(&key);
-                    }
-                }
-            }
-
-            nonces.insert(nonce, issued_at);
-            Ok(())
         })
     }
 
@@ -728,43 +676,200 @@ impl NonceTracker for MemoryNonceTracker {
         &self,
         nonce: &str,
     ) -> Pin<Box<dyn Future<Output = Result<bool>> + Send + '_>> {
-        let nonce = nonce.to_string();
-        Box::pin(async move {
-            // O(1) hashed lookup: nonces are server-generated (UUIDs in our generator,
-            // server-supplied opaque strings otherwise) — there is no per-character
-            // secret to leak through `HashMap::get` timing here. The previous O(n)
-            // constant-time scan was a CPU-amplification vector under unique-JTI floods.
-            Ok(self.used_nonces.read().await.contains_key(&nonce))
-        })
+        self.store.contains(nonce)
     }
 
     fn cleanup_expired_nonces(&self) -> Pin<Box<dyn Future<Output = Result<usize>> + Send + '_>> {
-        let max_nonce_age = self.max_nonce_age;
-        Box::pin(async move {
-            let cutoff = SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .map_err(|_| DpopError::InternalError {
-                    reason: "System clock before Unix epoch".to_string(),
-                })?
-                .as_secs() as i64
-                - max_nonce_age.as_secs() as i64;
+        Box::pin(async move { Ok(self.store.cleanup_expired().await) })
+    }
+}
+
+impl Default for MemoryNonceTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
-            let mut nonces = self.used_nonces.write().await;
-            let initial_count = nonces.len();
+/// Pluggable store of seen proof `jti` values for replay protection
+///
+/// [`DpopValidator`](crate::DpopValidator) records the `jti` of every accepted
+/// proof here and rejects a later proof carrying the same `jti` as a replay.
+/// [`MemoryNonceStore`] is the in-process default; `RedisNonceStorage`
+/// implements this trait behind the `redis-storage` feature, and any shared
+/// cache (DynamoDB, memcached, ...) can be plugged in the same way.
+pub trait NonceStore: Send + Sync + std::fmt::Debug {
+    /// Record `jti` until `exp` (Unix seconds) unless it is already present
+    ///
+    /// Returns `true` if the `jti` was inserted and `false` if an unexpired
+    /// entry already existed. Check and insert must be atomic, so that two
+    /// concurrent proofs with the same `jti` cannot both be accepted.
+    fn insert_if_absent(
+        &self,
+        jti: &str,
+        exp: i64,
+    ) -> Pin<Box<dyn Future<Output = Result<bool>> + Send + '_>>;
 
-            nonces.retain(|_, &mut timestamp| timestamp > cutoff);
+    /// Whether an unexpired entry for `jti` exists
+    fn contains(&self, jti: &str) -> Pin<Box<dyn Future<Output = Result<bool>> + Send + '_>>;
 
-            Ok(initial_count - nonces.len())
+    /// Record several `(jti, exp)` pairs in one call
+    ///
+    /// Returns one flag per entry, in order, with the meaning of
+    /// [`insert_if_absent`](Self::insert_if_absent); a `jti` repeated within
+    /// `entries` is inserted once and reported present afterwards. The default
+    /// calls `insert_if_absent` per entry. Networked stores should override it
+    /// to use a single round trip.
+    fn insert_many_if_absent<'a>(
+        &'a self,
+        entries: &'a [(String, i64)],
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<bool>>> + Send + 'a>> {
+        Box::pin(async move {
+            let mut inserted = Vec::with_capacity(entries.len());
+            for (jti, exp) in entries {
+                inserted.push(self.insert_if_absent(jti, *exp).await?);
+            }
+            Ok(inserted)
         })
     }
 }
 
-impl Default for MemoryNonceTracker {
+/// In-memory [`NonceStore`] with time-based eviction
+///
+/// Entries live until their `exp` passes; expired entries are ignored by
+/// lookups and swept once the map crosses 80 % of its capacity (or on
+/// [`cleanup_expired`](Self::cleanup_expired)). If the map is still full
+/// after the sweep, the entries closest to expiry are dropped to honour the
+/// hard cap. Replay protection is per process; use a shared store when
+/// several nodes validate proofs for the same clients.
+#[derive(Debug)]
+pub struct MemoryNonceStore {
+    /// `jti` to expiry (Unix seconds)
+    entries: RwLock<HashMap<String, i64>>,
+    /// Hard cap on resident entries
+    capacity: usize,
+    /// Time source for expiry decisions
+    clock: Arc<dyn Clock>,
+}
+
+impl MemoryNonceStore {
+    /// Create a store with [`DEFAULT_NONCE_CAPACITY`]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_capacity(DEFAULT_NONCE_CAPACITY)
+    }
+
+    /// Create a store holding at most `capacity` entries
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: RwLock::new(HashMap::with_capacity(capacity.min(64 * 1024))),
+            capacity: capacity.max(1),
+            clock: SystemClock::shared(),
+        }
+    }
+
+    /// Use a custom time source for expiry decisions
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Remove expired entries, returning how many were removed
+    pub async fn cleanup_expired(&self) -> usize {
+        let now = self.now_secs();
+        let mut entries = self.entries.write().await;
+        let before = entries.len();
+        entries.retain(|_, &mut exp| exp >= now);
+        before - entries.len()
+    }
+
+    fn now_secs(&self) -> i64 {
+        self.clock
+            .now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs() as i64)
+    }
+
+    /// Insert under an already-held write lock
+    fn insert_locked(
+        &self,
+        entries: &mut HashMap<String, i64>,
+        jti: &str,
+        exp: i64,
+        now: i64,
+    ) -> bool {
+        if entries.get(jti).is_some_and(|&seen| seen >= now) {
+            return false;
+        }
+
+        if entries.len() * 5 >= self.capacity * 4 {
+            entries.retain(|_, &mut seen| seen >= now);
+
+            if entries.len() >= self.capacity {
+                let to_drop = entries.len() - self.capacity + 1;
+                let mut by_expiry: Vec<(String, i64)> =
+                    entries.iter().map(|(k, v)| (k.clone(), *v)).collect();
+                by_expiry.sort_by_key(|(_, seen)| *seen);
+                for (key, _) in by_expiry.into_iter().take(to_drop) {
+                    entries.remove(&key);
+                }
+            }
+        }
+
+        entries.insert(jti.to_string(), exp);
+        true
+    }
+}
+
+impl Default for MemoryNonceStore {
     fn default() -> Self {
         Self::new()
     }
 }
 
+impl NonceStore for MemoryNonceStore {
+    fn insert_if_absent(
+        &self,
+        jti: &str,
+        exp: i64,
+    ) -> Pin<Box<dyn Future<Output = Result<bool>> + Send + '_>> {
+        let jti = jti.to_string();
+        Box::pin(async move {
+            let now = self.now_secs();
+            let mut entries = self.entries.write().await;
+            Ok(self.insert_locked(&mut entries, &jti, exp, now))
+        })
+    }
+
+    fn insert_many_if_absent<'a>(
+        &'a self,
+        batch: &'a [(String, i64)],
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<bool>>> + Send + 'a>> {
+        Box::pin(async move {
+            let now = self.now_secs();
+            let mut entries = self.entries.write().await;
+            Ok(batch
+                .iter()
+                .map(|(jti, exp)| self.insert_locked(&mut entries, jti, *exp, now))
+                .collect())
+        })
+    }
+
+    fn contains(&self, jti: &str) -> Pin<Box<dyn Future<Output = Result<bool>> + Send + '_>> {
+        let jti = jti.to_string();
+        Box::pin(async move {
+            let now = self.now_secs();
+            Ok(self
+                .entries
+                .read()
+                .await
+                .get(&jti)
+                .is_some_and(|&exp| exp >= now))
+        })
+    }
+}
+
 /// Redis-based nonce tracker for distributed deployments
 ///
 /// This implementation provides Redis-backed nonce tracking with DPoP replay
@@ -858,39 +963,16 @@ impl NonceTracker for RedisNonceTracker {
         issued_at: i64,
     ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + '_>> {
         let nonce = nonce.to_string();
-        let default_client_id = self.default_client_id.clone();
-        let storage = self.storage.clone();
+        let key = format!("{}:{}", self.default_client_id, nonce);
+        // Remembered for five minutes past `iat`, the longest a proof stays valid
+        let exp = issued_at.saturating_add(300);
 
         Box::pin(async move {
-            // Convert timestamp to system time for TTL calculation
-            let current_time = SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .map_err(|_| DpopError::InternalError {
-                    reason: "System clock before Unix epoch".to_string(),
-                })?
-                .as_secs() as i64;
-
-            // Calculate appropriate TTL based on issued_at vs current time
-            let age = current_time.saturating_sub(issued_at);
-            let remaining_ttl = Duration::from_secs(300_u64.saturating_sub(age as u64)); // 5 minutes max
-
-            // Store nonce with comprehensive metadata
-            let stored = storage
-                .store_nonce(
-                    &nonce,
-                    &format!("jti-{}", nonce), // JTI based on nonce for simplicity
-                    "POST", // Default method - would need to be passed through in real usage
-                    "https://api.turbomcp.org/default", // Default URI - would need actual URI
-                    &default_client_id,
-                    Some(remaining_ttl),
-                )
-                .await?;
-
-            if !stored {
-                return Err(DpopError::ReplayAttackDetected { nonce });
+            if NonceStore::insert_if_absent(&self.storage, &key, exp).await? {
+                Ok(())
+            } else {
+                Err(DpopError::ReplayAttackDetected { nonce })
             }
-
-            Ok(())
         })
     }
 
@@ -898,11 +980,8 @@ impl NonceTracker for RedisNonceTracker {
         &self,
         nonce: &str,
     ) -> Pin<Box<dyn Future<Output = Result<bool>> + Send + '_>> {
-        let nonce = nonce.to_string();
-        let default_client_id = self.default_client_id.clone();
-        let storage = self.storage.clone();
-
-        Box::pin(async move { storage.is_nonce_used(&nonce, &default_client_id).await })
+        let key = format!("{}:{}", self.default_client_id, nonce);
+        Box::pin(async move { NonceStore::contains(&self.storage, &key).await })
     }
 
     fn cleanup_expired_nonces(&self) -> Pin<Box<dyn Future<Output = Result<usize>> + Send + '_>> {
@@ -1065,6 +1144,154 @@ mod tests {
         assert_eq!(result.key_algorithm, DpopAlgorithm::ES256);
     }
 
+    /// Re-encode `jwt` with `header` in place of its own header segment
+    fn with_header(jwt: &str, header: serde_json::Value) -> String {
+        let mut parts = jwt.splitn(2, '.');
+        let _ = parts.next();
+        format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(serde_json::to_vec(&header).unwrap()),
+            parts.next().unwrap()
+        )
+    }
+
+    #[tokio::test]
+    async fn test_proof_round_trip_per_curve() {
+        let key_manager = Arc::new(DpopKeyManager::new_memory().await.unwrap());
+        let proof_gen = DpopProofGenerator::new(key_manager.clone());
+        let uri = "https://api.example.com/token";
+
+        for algorithm in [
+            DpopAlgorithm::ES256,
+            DpopAlgorithm::ES384,
+            DpopAlgorithm::ES512,
+        ] {
+            let key_pair = key_manager.generate_key_pair(algorithm).await.unwrap();
+            let proof = proof_gen
+                .generate_proof_with_key("POST", uri, None, Some(&key_pair))
+                .await
+                .unwrap();
+            assert_eq!(proof.header.algorithm, algorithm);
+            assert_eq!(proof.header.jwk.curve(), algorithm.curve());
+
+            let jwt = proof.to_jwt_string();
+            let header: serde_json::Value = serde_json::from_slice(
+                &URL_SAFE_NO_PAD
+                    .decode(jwt.split('.').next().unwrap())
+                    .unwrap(),
+            )
+            .unwrap();
+            assert_eq!(header["alg"], algorithm.as_str());
+            assert_eq!(header["jwk"]["crv"], algorithm.curve());
+
+            let result = proof_gen
+                .parse_and_validate_jwt(&jwt, "POST", uri, None, ProofContext::TokenEndpoint)
+                .await
+                .unwrap_or_else(|e| panic!("{algorithm} proof rejected: {e}"));
+            assert_eq!(result.key_algorithm, algorithm);
+            assert_eq!(result.thumbprint, key_pair.thumbprint);
+
+            crate::helpers::DpopValidator::new()
+                .validate(&proof, None)
+                .await
+                .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cross_curve_proof_is_rejected() {
+        let key_manager = Arc::new(DpopKeyManager::new_memory().await.unwrap());
+        let proof_gen = DpopProofGenerator::new(key_manager.clone());
+        let uri = "https://api.example.com/token";
+
+        let p256 = key_manager
+            .generate_key_pair(DpopAlgorithm::ES256)
+            .await
+            .unwrap();
+        let p384 = key_manager
+            .generate_key_pair(DpopAlgorithm::ES384)
+            .await
+            .unwrap();
+        let proof = proof_gen
+            .generate_proof_with_key("POST", uri, None, Some(&p256))
+            .await
+            .unwrap();
+        let p384_jwk = DpopJwk::from_public_key(&p384.public_key);
+
+        // Header claims ES256 but carries a P-384 key
+        let forged = with_header(
+            &proof.to_jwt_string(),
+            serde_json::json!({ "typ": DPOP_JWT_TYPE, "alg": "ES256", "jwk": p384_jwk }),
+        );
+        let err = proof_gen
+            .parse_and_validate_jwt(&forged, "POST", uri, None, ProofContext::TokenEndpoint)
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(&err, DpopError::InvalidProofStructure { reason } if reason.contains("does not match")),
+            "unexpected error: {err}"
+        );
+
+        let mut mismatched = proof.clone();
+        mismatched.header.jwk = p384_jwk;
+        assert!(matches!(
+            crate::helpers::DpopValidator::new()
+                .validate(&mismatched, None)
+                .await,
+            Err(DpopError::InvalidProofStructure { .. })
+        ));
+
+        // A key pair whose declared algorithm disagrees with its curve cannot sign
+        let mut confused = p384.clone();
+        confused.algorithm = DpopAlgorithm::ES256;
+        assert!(
+            proof_gen
+                .generate_proof_with_key("POST", uri, None, Some(&confused))
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rsa_proof_header_is_rejected() {
+        let key_manager = Arc::new(DpopKeyManager::new_memory().await.unwrap());
+        let proof_gen = DpopProofGenerator::new(key_manager);
+        let uri = "https://api.example.com/token";
+
+        let proof = proof_gen.generate_proof("POST", uri, None).await.unwrap();
+        let forged = with_header(
+            &proof.to_jwt_string(),
+            serde_json::json!({ "typ": DPOP_JWT_TYPE, "alg": "RS256", "jwk": proof.header.jwk }),
+        );
+        let err = proof_gen
+            .parse_and_validate_jwt(&forged, "POST", uri, None, ProofContext::TokenEndpoint)
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(&err, DpopError::InvalidProofStructure { reason } if reason.contains("RUSTSEC-2023-0071")),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_proof_expires_when_mock_clock_advances_past_lifetime() {
+        use turbomcp_core::clock::MockClock;
+
+        let key_manager = Arc::new(DpopKeyManager::new_memory().await.unwrap());
+        let clock = MockClock::new();
+        let proof_gen = DpopProofGenerator::new(key_manager).with_clock(Arc::new(clock.clone()));
+
+        let uri = "https://api.example.com/token";
+        let proof = proof_gen.generate_proof("POST", uri, None).await.unwrap();
+
+        clock.advance(Duration::from_secs(DEFAULT_PROOF_LIFETIME_SECONDS + 1));
+
+        let result = proof_gen
+            .validate_proof(&proof, "POST", uri, None, ProofContext::TokenEndpoint)
+            .await;
+        assert!(matches!(result, Err(DpopError::ProofExpired { .. })));
+    }
+
     #[tokio::test]
     async fn test_access_token_binding() {
         let key_manager = Arc::new(DpopKeyManager::new_memory().await.unwrap());
@@ -1189,6 +1416,69 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_memory_nonce_store_expires_and_cleans_up() {
+        use turbomcp_core::clock::MockClock;
+
+        let clock = Arc::new(MockClock::new());
+        let store = MemoryNonceStore::new().with_clock(clock.clone());
+        let now = clock.now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64;
+
+        assert!(store.insert_if_absent("short", now + 10).await.unwrap());
+        assert!(store.insert_if_absent("long", now + 100).await.unwrap());
+        assert!(!store.insert_if_absent("short", now + 10).await.unwrap());
+        assert!(store.contains("short").await.unwrap());
+
+        clock.advance(Duration::from_secs(11));
+        assert!(!store.contains("short").await.unwrap());
+        assert!(store.contains("long").await.unwrap());
+        assert_eq!(store.cleanup_expired().await, 1);
+        assert_eq!(store.cleanup_expired().await, 0);
+
+        // Once expired, the jti may be recorded again
+        assert!(store.insert_if_absent("short", now + 30).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_memory_nonce_store_evicts_expired_entries_at_capacity() {
+        use turbomcp_core::clock::MockClock;
+
+        let clock = Arc::new(MockClock::new());
+        let store = MemoryNonceStore::with_capacity(2).with_clock(clock.clone());
+        let now = clock.now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64;
+
+        assert!(store.insert_if_absent("a", now + 1).await.unwrap());
+        assert!(store.insert_if_absent("b", now + 60).await.unwrap());
+        clock.advance(Duration::from_secs(2));
+
+        // "a" has expired, so it goes first and "b" survives
+        assert!(store.insert_if_absent("c", now + 60).await.unwrap());
+        assert!(store.contains("b").await.unwrap());
+        assert!(store.contains("c").await.unwrap());
+        assert_eq!(store.cleanup_expired().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_memory_nonce_store_concurrent_inserts_admit_one() {
+        let store = Arc::new(MemoryNonceStore::new());
+        let exp = store.now_secs() + 60;
+
+        let handles: Vec<_> = (0..32)
+            .map(|_| {
+                let store = store.clone();
+                tokio::spawn(async move { store.insert_if_absent("raced-jti", exp).await })
+            })
+            .collect();
+
+        let mut inserted = 0;
+        for handle in handles {
+            if handle.await.unwrap().unwrap() {
+                inserted += 1;
+            }
+        }
+        assert_eq!(inserted, 1);
+    }
+
     #[tokio::test]
     async fn test_concurrent_replay_allows_only_one_validation() {
         let key_manager = Arc::new(DpopKeyManager::new_memory().await.unwrap());
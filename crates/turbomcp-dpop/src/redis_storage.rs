@@ -3,9 +3,11 @@
 //! This module provides Redis-backed storage for DPoP nonce
 //! tracking and replay protection when the `redis-storage` feature is enabled.
 
-use super::{DpopError, NonceStorage, Result};
+use super::{DpopError, NonceStorage, NonceStore, Result};
 use redis::{AsyncCommands, Client, RedisResult};
 use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::pin::Pin;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tracing::{debug, error, trace, warn};
 
@@ -127,6 +129,20 @@ impl RedisNonceStorage {
         }
     }
 
+    /// Execute a non-idempotent Redis operation exactly once
+    ///
+    /// `SET NX` writes must not go through [`with_retries`](Self::with_retries):
+    /// if a first attempt lands but its reply is lost, the retry finds the key
+    /// and reports the caller's own proof as a replay.
+    async fn run_once<T>(operation: impl Future<Output = RedisResult<T>>) -> Result<T> {
+        operation.await.map_err(|e| {
+            error!("Redis operation failed: {}", e);
+            DpopError::StorageError {
+                reason: format!("Redis operation failed: {}", e),
+            }
+        })
+    }
+
     /// Generate unique key for nonce storage
     fn nonce_key(&self, nonce: &str, client_id: &str) -> String {
         format!("{}{}__{}", self.nonce_prefix, client_id, nonce)
@@ -137,6 +153,20 @@ impl RedisNonceStorage {
         format!("{}{}__{}", self.jti_prefix, client_id, jti)
     }
 
+    /// Generate key for a [`NonceStore`] entry
+    fn store_key(&self, jti: &str) -> String {
+        format!("{}seen:{}", self.jti_prefix, jti)
+    }
+
+    /// TTL for a [`NonceStore`] entry expiring at `exp` (Unix seconds)
+    ///
+    /// Redis rejects a zero TTL, so an already-expired entry lives one second.
+    fn store_ttl(exp: i64) -> u64 {
+        exp.saturating_sub(Self::current_timestamp() as i64)
+            .max(1)
+            .unsigned_abs()
+    }
+
     /// Current timestamp as Unix seconds
     fn current_timestamp() -> u64 {
         SystemTime::now()
@@ -317,6 +347,83 @@ impl NonceStorage for RedisNonceStorage {
     }
 }
 
+/// Replay-protection store backed by `SET NX EX`, so the check-and-insert is
+/// atomic across every node sharing the Redis instance
+impl NonceStore for RedisNonceStorage {
+    fn insert_if_absent(
+        &self,
+        jti: &str,
+        exp: i64,
+    ) -> Pin<Box<dyn Future<Output = Result<bool>> + Send + '_>> {
+        let key = self.store_key(jti);
+        let ttl_secs = Self::store_ttl(exp);
+        let client = self.client.clone();
+
+        Box::pin(async move {
+            Self::run_once(async move {
+                let mut conn = client.get_multiplexed_async_connection().await?;
+                let set: Option<String> = redis::cmd("SET")
+                    .arg(&key)
+                    .arg(exp)
+                    .arg("NX")
+                    .arg("EX")
+                    .arg(ttl_secs)
+                    .query_async(&mut conn)
+                    .await?;
+                Ok(set.is_some())
+            })
+            .await
+        })
+    }
+
+    fn contains(&self, jti: &str) -> Pin<Box<dyn Future<Output = Result<bool>> + Send + '_>> {
+        let key = self.store_key(jti);
+        let client = self.client.clone();
+
+        Box::pin(async move {
+            self.with_retries(|| {
+                let client = client.clone();
+                let key = key.clone();
+
+                Box::pin(async move {
+                    let mut conn = client.get_multiplexed_async_connection().await?;
+                    conn.exists(&key).await
+                })
+            })
+            .await
+        })
+    }
+
+    /// Pipelines one `SET NX EX` per entry, so a batch costs one round trip
+    fn insert_many_if_absent<'a>(
+        &'a self,
+        entries: &'a [(String, i64)],
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<bool>>> + Send + 'a>> {
+        let mut pipe = redis::pipe();
+        for (jti, exp) in entries {
+            pipe.cmd("SET")
+                .arg(self.store_key(jti))
+                .arg(*exp)
+                .arg("NX")
+                .arg("EX")
+                .arg(Self::store_ttl(*exp));
+        }
+        let client = self.client.clone();
+
+        Box::pin(async move {
+            if entries.is_empty() {
+                return Ok(Vec::new());
+            }
+            Self::run_once(async move {
+                let mut conn = client.get_multiplexed_async_connection().await?;
+                let set: Vec<Option<String>> = pipe.query_async(&mut conn).await?;
+                Ok(set.into_iter().map(|reply| reply.is_some()).collect())
+            })
+            .await
+        })
+    }
+}
+
 /// Redis storage implementation when feature is disabled
 /// This provides clear error messages for misconfiguration
 #[cfg(not(feature = "redis-storage"))]
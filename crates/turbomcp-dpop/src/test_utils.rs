@@ -6,7 +6,7 @@
 //! These utilities are designed for testing scenarios only and should never
 //! be used in production code.
 
-use super::{DpopAlgorithm, DpopKeyPair, NonceStorage, Result, StorageStats};
+use super::{DpopAlgorithm, DpopError, DpopKeyPair, NonceStorage, Result, StorageStats};
 use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
 use p256::ecdsa::{SigningKey, VerifyingKey};
 use p256::elliptic_curve::rand_core::OsRng;
@@ -157,6 +157,9 @@ impl MockKeyManager {
 
                 Ok(dpop_key)
             }
+            other => Err(DpopError::CryptographicError {
+                reason: format!("Test key manager only generates ES256 keys, not {other}"),
+            }),
         }
     }
 
@@ -175,7 +178,7 @@ impl MockKeyManager {
 
         // Create test JWT header
         let header = json!({
-            "alg": "ES256",
+            "alg": key_pair.algorithm.as_str(),
             "typ": "dpop+jwt",
             "jwk": self.create_test_jwk(&key_pair.public_key, &key_pair.algorithm)?
         });
@@ -254,16 +257,14 @@ impl MockKeyManager {
         public_key: &super::DpopPublicKey,
         _algorithm: &DpopAlgorithm,
     ) -> Result<serde_json::Value> {
-        // Only ES256 with ECDSA P-256 is supported
-        match public_key {
-            super::DpopPublicKey::EcdsaP256 { x, y } => Ok(json!({
-                "kty": "EC",
-                "crv": "P-256",
-                "x": URL_SAFE_NO_PAD.encode(x),
-                "y": URL_SAFE_NO_PAD.encode(y),
-                "use": "sig"
-            })),
-        }
+        let (x, y) = public_key.coordinates();
+        Ok(json!({
+            "kty": "EC",
+            "crv": public_key.algorithm().curve(),
+            "x": URL_SAFE_NO_PAD.encode(x),
+            "y": URL_SAFE_NO_PAD.encode(y),
+            "use": "sig"
+        }))
     }
 }
 
@@ -15,39 +15,85 @@ use zeroize::Zeroize;
 
 /// DPoP cryptographic algorithm as defined in RFC 9449
 ///
-/// This implementation uses only ES256 (ECDSA P-256) for maximum security.
-/// RSA algorithms (RS256, PS256) have been removed due to timing attack
-/// vulnerabilities in the rsa crate (RUSTSEC-2023-0071).
+/// This implementation supports only ECDSA over the NIST curves P-256, P-384
+/// and P-521. RSA algorithms (RS256, PS256) have been removed due to timing
+/// attack vulnerabilities in the rsa crate (RUSTSEC-2023-0071).
 ///
-/// ES256 is the recommended algorithm in RFC 9449 and provides:
-/// - Superior security against timing attacks
-/// - Faster performance than RSA
-/// - Smaller key and signature sizes
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+/// ES256 is the default and the algorithm recommended by RFC 9449. ES384 and
+/// ES512 are available for deployments whose policy mandates larger curves.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum DpopAlgorithm {
-    /// Elliptic Curve Digital Signature Algorithm with P-256 curve and SHA-256 (RFC 7518)
-    /// This is the only supported algorithm for DPoP in TurboMCP v3.0+
+    /// ECDSA using P-256 and SHA-256 (RFC 7518)
+    #[default]
     #[serde(rename = "ES256")]
     ES256,
+    /// ECDSA using P-384 and SHA-384 (RFC 7518)
+    #[serde(rename = "ES384")]
+    ES384,
+    /// ECDSA using P-521 and SHA-512 (RFC 7518)
+    #[serde(rename = "ES512")]
+    ES512,
 }
 
 impl DpopAlgorithm {
     /// Get the algorithm name as specified in RFC 7518
     #[must_use]
     pub fn as_str(self) -> &'static str {
-        "ES256"
+        match self {
+            Self::ES256 => "ES256",
+            Self::ES384 => "ES384",
+            Self::ES512 => "ES512",
+        }
+    }
+
+    /// Parse a JWS `alg` value.
+    ///
+    /// Returns `None` for anything other than ES256, ES384 or ES512, including
+    /// the RSA and HMAC algorithms DPoP proofs must not use.
+    #[must_use]
+    pub fn from_jws_name(alg: &str) -> Option<Self> {
+        match alg {
+            "ES256" => Some(Self::ES256),
+            "ES384" => Some(Self::ES384),
+            "ES512" => Some(Self::ES512),
+            _ => None,
+        }
+    }
+
+    /// JWK `crv` value of the curve this algorithm signs with
+    #[must_use]
+    pub fn curve(self) -> &'static str {
+        match self {
+            Self::ES256 => "P-256",
+            Self::ES384 => "P-384",
+            Self::ES512 => "P-521",
+        }
+    }
+
+    /// Length in bytes of one curve coordinate (and of a private scalar)
+    #[must_use]
+    pub fn coordinate_len(self) -> usize {
+        match self {
+            Self::ES256 => 32,
+            Self::ES384 => 48,
+            Self::ES512 => 66,
+        }
     }
 
     /// Get recommended key size for the algorithm
     #[must_use]
     pub fn recommended_key_size(self) -> u32 {
-        256 // P-256 curve
+        match self {
+            Self::ES256 => 256,
+            Self::ES384 => 384,
+            Self::ES512 => 521,
+        }
     }
 
     /// Check if algorithm is suitable for production use
     #[must_use]
     pub fn is_production_ready(self) -> bool {
-        // ES256 is production-ready and the recommended algorithm
+        // All supported ECDSA curves are production-ready
         true
     }
 }
@@ -122,56 +168,27 @@ impl DpopKeyPair {
     /// # Errors
     /// Returns error if key generation fails
     pub fn generate_p256() -> Result<Self, crate::errors::DpopError> {
-        use p256::ecdsa::{SigningKey, VerifyingKey};
-        use p256::elliptic_curve::rand_core::OsRng;
-        use sha2::{Digest, Sha256};
-
-        let signing_key = SigningKey::random(&mut OsRng);
-        let verifying_key = VerifyingKey::from(&signing_key);
-
-        // Get private key bytes
-        let private_bytes = signing_key.to_bytes();
-        let mut key_bytes = [0u8; 32];
-        key_bytes.copy_from_slice(private_bytes.as_ref());
-
-        // Extract x and y coordinates from the public key
-        let public_point = verifying_key.to_encoded_point(false);
-        let x_bytes =
-            public_point
-                .x()
-                .ok_or_else(|| crate::errors::DpopError::CryptographicError {
-                    reason: "Failed to extract x coordinate from P-256 public key".to_string(),
-                })?;
-        let y_bytes =
-            public_point
-                .y()
-                .ok_or_else(|| crate::errors::DpopError::CryptographicError {
-                    reason: "Failed to extract y coordinate from P-256 public key".to_string(),
-                })?;
-
-        let mut x = [0u8; 32];
-        let mut y = [0u8; 32];
-        x.copy_from_slice(x_bytes);
-        y.copy_from_slice(y_bytes);
-
-        // Calculate JWK thumbprint per RFC 7638
-        let jwk_json = serde_json::json!({
-            "crv": "P-256",
-            "kty": "EC",
-            "x": base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(x),
-            "y": base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(y),
-        });
-        let jwk_canonical = jwk_json.to_string();
-        let mut hasher = Sha256::new();
-        hasher.update(jwk_canonical.as_bytes());
-        let thumbprint = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(hasher.finalize());
+        Self::generate(DpopAlgorithm::ES256)
+    }
+
+    /// Generate a new key pair for `algorithm`
+    ///
+    /// Like [`generate_p256`](Self::generate_p256), but for any supported
+    /// curve. For production use with key rotation and management, use
+    /// `DpopKeyManager`.
+    ///
+    /// # Errors
+    /// Returns error if key generation fails
+    pub fn generate(algorithm: DpopAlgorithm) -> Result<Self, crate::errors::DpopError> {
+        let (private_key, public_key) = crate::keys::generate_ec_key_pair(algorithm)?;
+        let thumbprint = compute_jwk_thumbprint(&DpopJwk::from_public_key(&public_key))?;
 
         Ok(Self {
             id: uuid::Uuid::new_v4().to_string(),
-            private_key: DpopPrivateKey::EcdsaP256 { key_bytes },
-            public_key: DpopPublicKey::EcdsaP256 { x, y },
+            private_key,
+            public_key,
             thumbprint,
-            algorithm: DpopAlgorithm::ES256,
+            algorithm,
             created_at: SystemTime::now(),
             expires_at: None,
             metadata: DpopKeyMetadata::default(),
@@ -181,8 +198,9 @@ impl DpopKeyPair {
 
 /// Private key material for DPoP operations
 ///
-/// This implementation only supports ECDSA P-256 keys for maximum security.
-/// RSA support has been removed due to timing attack vulnerabilities (RUSTSEC-2023-0071).
+/// This implementation only supports ECDSA keys on the NIST P-256, P-384 and
+/// P-521 curves. RSA support has been removed due to timing attack
+/// vulnerabilities (RUSTSEC-2023-0071).
 #[derive(Debug, Clone)]
 pub enum DpopPrivateKey {
     /// ECDSA P-256 private key
@@ -190,12 +208,24 @@ pub enum DpopPrivateKey {
         /// P-256 private key in SEC1 format
         key_bytes: [u8; 32],
     },
+    /// ECDSA P-384 private key
+    EcdsaP384 {
+        /// P-384 private key in SEC1 format
+        key_bytes: [u8; 48],
+    },
+    /// ECDSA P-521 private key
+    EcdsaP521 {
+        /// P-521 private key in SEC1 format
+        key_bytes: [u8; 66],
+    },
 }
 
 impl Zeroize for DpopPrivateKey {
     fn zeroize(&mut self) {
         match self {
             Self::EcdsaP256 { key_bytes } => key_bytes.zeroize(),
+            Self::EcdsaP384 { key_bytes } => key_bytes.zeroize(),
+            Self::EcdsaP521 { key_bytes } => key_bytes.zeroize(),
         }
     }
 }
@@ -208,8 +238,9 @@ impl Drop for DpopPrivateKey {
 
 /// Public key material for DPoP operations
 ///
-/// This implementation only supports ECDSA P-256 keys for maximum security.
-/// RSA support has been removed due to timing attack vulnerabilities (RUSTSEC-2023-0071).
+/// This implementation only supports ECDSA keys on the NIST P-256, P-384 and
+/// P-521 curves. RSA support has been removed due to timing attack
+/// vulnerabilities (RUSTSEC-2023-0071).
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum DpopPublicKey {
     /// ECDSA P-256 public key
@@ -219,6 +250,42 @@ pub enum DpopPublicKey {
         /// Y coordinate of the public key point
         y: [u8; 32],
     },
+    /// ECDSA P-384 public key
+    EcdsaP384 {
+        /// X coordinate of the public key point
+        x: [u8; 48],
+        /// Y coordinate of the public key point
+        y: [u8; 48],
+    },
+    /// ECDSA P-521 public key
+    EcdsaP521 {
+        /// X coordinate of the public key point
+        x: [u8; 66],
+        /// Y coordinate of the public key point
+        y: [u8; 66],
+    },
+}
+
+impl DpopPublicKey {
+    /// Algorithm that signs with this key's curve
+    #[must_use]
+    pub fn algorithm(&self) -> DpopAlgorithm {
+        match self {
+            Self::EcdsaP256 { .. } => DpopAlgorithm::ES256,
+            Self::EcdsaP384 { .. } => DpopAlgorithm::ES384,
+            Self::EcdsaP521 { .. } => DpopAlgorithm::ES512,
+        }
+    }
+
+    /// The `x` and `y` coordinates of the public key point
+    #[must_use]
+    pub fn coordinates(&self) -> (&[u8], &[u8]) {
+        match self {
+            Self::EcdsaP256 { x, y } => (x, y),
+            Self::EcdsaP384 { x, y } => (x, y),
+            Self::EcdsaP521 { x, y } => (x, y),
+        }
+    }
 }
 
 /// Key usage metadata for auditing and management
@@ -292,8 +359,8 @@ pub struct DpopPayload {
 
 /// JSON Web Key representation for DPoP public keys
 ///
-/// This implementation only supports ECDSA P-256 keys for maximum security.
-/// RSA support has been removed due to timing attack vulnerabilities (RUSTSEC-2023-0071).
+/// This implementation only supports EC keys. RSA support has been removed due
+/// to timing attack vulnerabilities (RUSTSEC-2023-0071).
 #[derive(Debug, Clone, Serialize, PartialEq, Eq)]
 #[serde(tag = "kty")]
 pub enum DpopJwk {
@@ -307,7 +374,7 @@ pub enum DpopJwk {
         #[serde(rename = "use")]
         use_: String,
 
-        /// Elliptic curve name - always "P-256" for ES256
+        /// Elliptic curve name: "P-256", "P-384" or "P-521"
         crv: String,
 
         /// X coordinate (base64url-encoded)
@@ -362,6 +429,28 @@ impl<'de> Deserialize<'de> for DpopJwk {
     }
 }
 
+impl DpopJwk {
+    /// Build the public JWK for `key`
+    #[must_use]
+    pub fn from_public_key(key: &DpopPublicKey) -> Self {
+        let (x, y) = key.coordinates();
+        Self::Ec {
+            use_: "sig".to_string(),
+            crv: key.algorithm().curve().to_string(),
+            x: URL_SAFE_NO_PAD.encode(x),
+            y: URL_SAFE_NO_PAD.encode(y),
+        }
+    }
+
+    /// JWK `crv` value
+    #[must_use]
+    pub fn curve(&self) -> &str {
+        match self {
+            Self::Ec { crv, .. } => crv,
+        }
+    }
+}
+
 fn required_jwk_string<E>(
     object: &serde_json::Map<String, serde_json::Value>,
     field: &'static str,
@@ -470,7 +559,11 @@ impl DpopProof {
     /// Create minimal valid JWT as fallback (should never be needed in production)
     fn create_minimal_jwt_fallback(&self) -> String {
         // Create a minimal but valid DPoP JWT header
-        let minimal_header = format!(r#"{{"typ":"{}","alg":"ES256"}}"#, super::DPOP_JWT_TYPE);
+        let minimal_header = format!(
+            r#"{{"typ":"{}","alg":"{}"}}"#,
+            super::DPOP_JWT_TYPE,
+            self.header.algorithm
+        );
         let minimal_payload = "{}";
 
         let encoded_header = URL_SAFE_NO_PAD.encode(minimal_header);
@@ -487,100 +580,15 @@ impl DpopProof {
     ///
     /// Requires the `jwt-validation` feature to be enabled.
     pub fn from_jwt_string(jwt: &str) -> super::Result<Self> {
-        use jsonwebtoken::{Algorithm, Validation, decode, decode_header};
-
-        // Use jsonwebtoken crate to decode header (no validation yet)
-        let jwt_header =
-            decode_header(jwt).map_err(|e| super::DpopError::InvalidProofStructure {
-                reason: format!("Failed to decode JWT header: {}", e),
-            })?;
-
-        // Validate this is a DPoP JWT
-        if jwt_header.typ.as_deref() != Some(super::DPOP_JWT_TYPE) {
-            return Err(super::DpopError::InvalidProofStructure {
-                reason: format!(
-                    "Invalid JWT type: expected '{}', got '{:?}'",
-                    super::DPOP_JWT_TYPE,
-                    jwt_header.typ
-                ),
-            });
-        }
-
-        // Convert jsonwebtoken::Header to our DpopHeader
-        // Only ES256 is supported in TurboMCP v3.0+
-        let algorithm = match jwt_header.alg {
-            Algorithm::ES256 => DpopAlgorithm::ES256,
-            other => {
-                return Err(super::DpopError::InvalidProofStructure {
-                    reason: format!(
-                        "Unsupported DPoP algorithm: {:?}. Only ES256 is supported (RSA removed due to RUSTSEC-2023-0071)",
-                        other
-                    ),
-                });
-            }
-        };
-
-        // Extract JWK from header - convert from jsonwebtoken::Jwk to our DpopJwk
-        let jwk_value = jwt_header
-            .jwk
-            .ok_or_else(|| super::DpopError::InvalidProofStructure {
-                reason: "Missing JWK in DPoP proof header".to_string(),
-            })?;
-
-        // Convert jsonwebtoken::Jwk to serde_json::Value first, then to our DpopJwk
-        let jwk_json = serde_json::to_value(&jwk_value).map_err(|e| {
-            super::DpopError::InvalidProofStructure {
-                reason: format!("Failed to serialize JWK: {}", e),
-            }
-        })?;
-
-        let jwk: DpopJwk = serde_json::from_value(jwk_json).map_err(|e| {
-            super::DpopError::InvalidProofStructure {
-                reason: format!("Invalid JWK in header: {}", e),
-            }
-        })?;
-
-        let header = DpopHeader {
-            typ: super::DPOP_JWT_TYPE.to_string(),
-            algorithm,
-            jwk,
-        };
+        // Checks typ, restricts alg to the ECDSA algorithms and requires the
+        // embedded JWK to be on the curve that alg names
+        let header = crate::helpers::decode_proof_header(jwt)?;
 
-        // CRITICAL SECURITY: Create proper DecodingKey from embedded JWK for signature validation
-        let decoding_key = create_decoding_key_from_jwk(&header.jwk).map_err(|e| {
-            super::DpopError::CryptographicError {
-                reason: format!("Failed to create decoding key from JWK: {}", e),
-            }
-        })?;
-
-        // Configure validation for DPoP JWTs (RFC 9449):
-        // - DPoP proofs use `iat` for freshness, not `exp` — disable exp validation
-        // - DPoP proofs have no `aud` claim — disable audience validation
-        // - `iat` is required per RFC 9449 §4.2
-        // Leaving these as the Validation::new() defaults would reject every valid
-        // DPoP proof because jsonwebtoken treats a missing `exp` as an error by default,
-        // and it would require an `aud` claim that DPoP proofs never carry.
-        let mut validation = Validation::new(jwt_header.alg);
-        validation.validate_exp = false; // DPoP uses iat, not exp
-        validation.set_required_spec_claims(&["iat"]); // Require iat per RFC 9449 §4.2
-
-        // Decode and validate JWT signature using the embedded public key
-        let token_data = decode::<DpopPayload>(jwt, &decoding_key, &validation).map_err(|e| {
-            super::DpopError::ProofValidationFailed {
-                reason: format!("JWT signature validation failed: {}", e),
-            }
-        })?;
+        // CRITICAL SECURITY: verify the signature with the embedded JWK before
+        // trusting any claim
+        let payload = crate::helpers::verify_proof_signature(jwt, &header)?;
 
-        let payload = token_data.claims;
-
-        // Extract signature from JWT (jsonwebtoken doesn't expose this directly)
-        let parts: Vec<&str> = jwt.split('.').collect();
-        if parts.len() != 3 {
-            return Err(super::DpopError::InvalidProofStructure {
-                reason: format!("Invalid JWT format: expected 3 parts, got {}", parts.len()),
-            });
-        }
-        let signature = parts[2].to_string();
+        let signature = jwt.rsplit('.').next().unwrap_or_default().to_string();
 
         // Create proof with cached JWT string for performance
         Ok(Self::new_with_jwt(
@@ -669,9 +677,12 @@ pub fn generate_ticket_id() -> TicketId {
 ///
 /// RFC 7638 requires lexicographic ordering of JSON keys for canonical representation.
 /// This function manually constructs the canonical JSON to ensure proper ordering.
-///
-/// Only supports ES256 (ECDSA P-256) as of TurboMCP v3.0+
 pub fn compute_jwk_thumbprint(jwk: &DpopJwk) -> super::Result<String> {
+    Ok(jwk_thumbprint(jwk))
+}
+
+/// Infallible core of [`compute_jwk_thumbprint`]
+pub(crate) fn jwk_thumbprint(jwk: &DpopJwk) -> String {
     use sha2::{Digest, Sha256};
 
     // RFC 7638 requires lexicographic ordering: crv, kty, x, y (for EC keys)
@@ -697,7 +708,7 @@ pub fn compute_jwk_thumbprint(jwk: &DpopJwk) -> super::Result<String> {
     let hash = hasher.finalize();
 
     // Return base64url-encoded thumbprint
-    Ok(URL_SAFE_NO_PAD.encode(hash))
+    URL_SAFE_NO_PAD.encode(hash)
 }
 
 /// Validate HTTP method format
@@ -713,27 +724,6 @@ fn is_valid_http_uri(uri: &str) -> bool {
     uri.starts_with("https://") || uri.starts_with("http://")
 }
 
-/// Create jsonwebtoken DecodingKey from DPoP JWK
-///
-/// This function converts our DpopJwk to a DecodingKey that can be used
-/// with the jsonwebtoken crate for signature verification. This is critical
-/// for proper DPoP security as per RFC 9449 requirements.
-///
-/// Only supports ES256 (ECDSA P-256) as of TurboMCP v3.0+
-fn create_decoding_key_from_jwk(
-    jwk: &DpopJwk,
-) -> Result<jsonwebtoken::DecodingKey, Box<dyn std::error::Error>> {
-    use jsonwebtoken::DecodingKey;
-
-    match jwk {
-        DpopJwk::Ec { x, y, .. } => {
-            // Use jsonwebtoken's EC components method (expects base64url-encoded strings)
-            DecodingKey::from_ec_components(x, y)
-                .map_err(|e| format!("Failed to create EC decoding key: {}", e).into())
-        }
-    }
-}
-
 /// Statistics about nonce storage usage and performance
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StorageStats {
@@ -834,6 +824,21 @@ mod tests {
         assert_eq!(DpopAlgorithm::ES256.as_str(), "ES256");
         assert_eq!(DpopAlgorithm::ES256.recommended_key_size(), 256);
         assert!(DpopAlgorithm::ES256.is_production_ready());
+        assert_eq!(DpopAlgorithm::default(), DpopAlgorithm::ES256);
+
+        assert_eq!(DpopAlgorithm::ES384.as_str(), "ES384");
+        assert_eq!(DpopAlgorithm::ES384.curve(), "P-384");
+        assert_eq!(DpopAlgorithm::ES512.as_str(), "ES512");
+        assert_eq!(DpopAlgorithm::ES512.curve(), "P-521");
+        assert_eq!(DpopAlgorithm::ES512.recommended_key_size(), 521);
+
+        for alg in ["RS256", "PS256", "HS256", "none"] {
+            assert_eq!(DpopAlgorithm::from_jws_name(alg), None);
+        }
+        assert_eq!(
+            DpopAlgorithm::from_jws_name("ES384"),
+            Some(DpopAlgorithm::ES384)
+        );
     }
 
     #[test]
@@ -4,13 +4,15 @@
 
 use crate::error::{GrpcError, GrpcResult, status_to_mcp_error};
 use crate::proto::{self, mcp_service_client::McpServiceClient};
+use std::sync::Arc;
 use std::time::Duration;
-use tonic::transport::{Channel, Endpoint};
-use tracing::{debug, info, instrument};
+use tonic::transport::{Certificate, Channel, ClientTlsConfig, Endpoint, Identity};
+use tracing::{debug, error, info, instrument};
 use turbomcp_protocol::types::{
     CallToolResult, ClientCapabilities, GetPromptResult, InitializeResult, ResourceContent,
     ServerCapabilities,
 };
+use turbomcp_transport_traits::{ReloadableTlsIdentity, TlsIdentity};
 use turbomcp_types::{Implementation, Prompt, Resource, ResourceTemplate, Tool};
 
 /// gRPC client for MCP servers
@@ -18,6 +20,14 @@ use turbomcp_types::{Implementation, Prompt, Resource, ResourceTemplate, Tool};
 pub struct McpGrpcClient {
     /// The gRPC client
     client: McpServiceClient<Channel>,
+    /// Endpoint without TLS, kept to open a new channel after an identity reload
+    endpoint: Endpoint,
+    /// CA certificate used to verify the server
+    tls_ca_pem: Option<Vec<u8>>,
+    /// Client certificate for mutual TLS
+    client_identity: Option<ReloadableTlsIdentity>,
+    /// Generation of the client identity the current channel was built with
+    identity_generation: u64,
     /// Client implementation info
     client_info: Implementation,
     /// Client capabilities to advertise during initialization
@@ -45,6 +55,15 @@ pub struct McpGrpcClientConfig {
     pub connect_timeout: Duration,
     /// Request timeout
     pub request_timeout: Duration,
+    /// PEM CA certificate used to verify the server; enables TLS
+    pub tls_ca_pem: Option<Vec<u8>>,
+    /// Client certificate for mutual TLS; enables TLS
+    ///
+    /// Calling [`ReloadableTlsIdentity::reload`] on a clone of this handle
+    /// rotates the certificate without restarting. Each client opens a new
+    /// channel with the new identity on its next request; channels already
+    /// open are left to finish the requests they carry.
+    pub client_identity: Option<ReloadableTlsIdentity>,
 }
 
 impl Default for McpGrpcClientConfig {
@@ -56,6 +75,8 @@ impl Default for McpGrpcClientConfig {
             capabilities: ClientCapabilities::default(),
             connect_timeout: Duration::from_secs(10),
             request_timeout: Duration::from_secs(30),
+            tls_ca_pem: None,
+            client_identity: None,
         }
     }
 }
@@ -88,17 +109,91 @@ impl McpGrpcClient {
         addr: impl AsRef<str>,
         config: McpGrpcClientConfig,
     ) -> GrpcResult<Self> {
-        let endpoint = Endpoint::from_shared(addr.as_ref().to_string())
+        let (endpoint, generation) = Self::base_endpoint(addr.as_ref(), &config)?;
+        let channel = Self::tls_endpoint(&endpoint, &config, generation.as_ref())?
+            .connect()
+            .await?;
+
+        info!(addr = %addr.as_ref(), "Connected to gRPC server");
+
+        Ok(Self::from_channel(channel, endpoint, generation, config))
+    }
+
+    /// Create a client that connects on first use
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the address or TLS configuration is invalid.
+    pub fn connect_lazy_with_config(
+        addr: impl AsRef<str>,
+        config: McpGrpcClientConfig,
+    ) -> GrpcResult<Self> {
+        let (endpoint, generation) = Self::base_endpoint(addr.as_ref(), &config)?;
+        let channel = Self::tls_endpoint(&endpoint, &config, generation.as_ref())?.connect_lazy();
+        Ok(Self::from_channel(channel, endpoint, generation, config))
+    }
+
+    fn base_endpoint(
+        addr: &str,
+        config: &McpGrpcClientConfig,
+    ) -> GrpcResult<(Endpoint, Option<(u64, Arc<TlsIdentity>)>)> {
+        let endpoint = Endpoint::from_shared(addr.to_string())
             .map_err(|e| GrpcError::config(format!("Invalid endpoint: {e}")))?
             .connect_timeout(config.connect_timeout)
             .timeout(config.request_timeout);
+        let identity = config
+            .client_identity
+            .as_ref()
+            .map(ReloadableTlsIdentity::current);
+        Ok((endpoint, identity))
+    }
 
-        let channel = endpoint.connect().await?;
+    /// Apply TLS to `endpoint` when a CA or client identity is configured.
+    fn tls_endpoint(
+        endpoint: &Endpoint,
+        config: &McpGrpcClientConfig,
+        identity: Option<&(u64, Arc<TlsIdentity>)>,
+    ) -> GrpcResult<Endpoint> {
+        Self::apply_tls(
+            endpoint,
+            config.tls_ca_pem.as_deref(),
+            identity.map(|(_, id)| &**id),
+        )
+    }
 
-        info!(addr = %addr.as_ref(), "Connected to gRPC server");
+    fn apply_tls(
+        endpoint: &Endpoint,
+        ca_pem: Option<&[u8]>,
+        identity: Option<&TlsIdentity>,
+    ) -> GrpcResult<Endpoint> {
+        if ca_pem.is_none() && identity.is_none() {
+            return Ok(endpoint.clone());
+        }
+        let mut tls = ClientTlsConfig::new();
+        if let Some(ca_pem) = ca_pem {
+            tls = tls.ca_certificate(Certificate::from_pem(ca_pem));
+        }
+        if let Some(identity) = identity {
+            tls = tls.identity(Identity::from_pem(
+                &identity.cert_chain_pem,
+                &identity.key_pem,
+            ));
+        }
+        Ok(endpoint.clone().tls_config(tls)?)
+    }
 
-        Ok(Self {
+    fn from_channel(
+        channel: Channel,
+        endpoint: Endpoint,
+        identity: Option<(u64, Arc<TlsIdentity>)>,
+        config: McpGrpcClientConfig,
+    ) -> Self {
+        Self {
             client: McpServiceClient::new(channel),
+            endpoint,
+            tls_ca_pem: config.tls_ca_pem,
+            client_identity: config.client_identity,
+            identity_generation: identity.map_or(0, |(generation, _)| generation),
             client_info: Implementation {
                 name: config.name,
                 title: None,
@@ -111,7 +206,34 @@ impl McpGrpcClient {
             server_info: None,
             server_capabilities: None,
             protocol_version: config.protocol_version,
-        })
+        }
+    }
+
+    /// The service client for the next request.
+    ///
+    /// Opens a new channel when the client identity has been reloaded so new
+    /// connections present the new certificate. If the new identity is
+    /// invalid, the error is logged and the current channel kept.
+    fn client(&mut self) -> &mut McpServiceClient<Channel> {
+        if let Some(reloadable) = &self.client_identity {
+            let (generation, identity) = reloadable.current();
+            if generation != self.identity_generation {
+                match Self::apply_tls(&self.endpoint, self.tls_ca_pem.as_deref(), Some(&identity)) {
+                    Ok(endpoint) => {
+                        info!(generation, "Reloaded TLS client identity");
+                        self.client = McpServiceClient::new(endpoint.connect_lazy());
+                    }
+                    Err(e) => {
+                        error!(
+                            generation,
+                            "Failed to apply reloaded TLS client identity: {e}"
+                        );
+                    }
+                }
+                self.identity_generation = generation;
+            }
+        }
+        &mut self.client
     }
 
     /// Initialize the MCP session
@@ -128,7 +250,7 @@ impl McpGrpcClient {
         };
 
         let response = self
-            .client
+            .client()
             .initialize(request)
             .await
             .map_err(|s| GrpcError::Mcp(status_to_mcp_error(&s)))?;
@@ -161,7 +283,7 @@ impl McpGrpcClient {
     /// Returns an error if the ping fails.
     #[instrument(skip(self))]
     pub async fn ping(&mut self) -> GrpcResult<()> {
-        self.client
+        self.client()
             .ping(proto::PingRequest {})
             .await
             .map_err(|s| GrpcError::Mcp(status_to_mcp_error(&s)))?;
@@ -178,7 +300,7 @@ impl McpGrpcClient {
     #[instrument(skip(self))]
     pub async fn list_tools(&mut self) -> GrpcResult<Vec<Tool>> {
         let response = self
-            .client
+            .client()
             .list_tools(proto::ListToolsRequest { cursor: None })
             .await
             .map_err(|s| GrpcError::Mcp(status_to_mcp_error(&s)))?;
@@ -209,7 +331,7 @@ impl McpGrpcClient {
         let arguments_bytes = arguments.map(|v| serde_json::to_vec(&v)).transpose()?;
 
         let response = self
-            .client
+            .client()
             .call_tool(proto::CallToolRequest {
                 name: name.as_ref().to_string(),
                 arguments: arguments_bytes,
@@ -229,7 +351,7 @@ impl McpGrpcClient {
     #[instrument(skip(self))]
     pub async fn list_resources(&mut self) -> GrpcResult<Vec<Resource>> {
         let response = self
-            .client
+            .client()
             .list_resources(proto::ListResourcesRequest { cursor: None })
             .await
             .map_err(|s| GrpcError::Mcp(status_to_mcp_error(&s)))?;
@@ -249,7 +371,7 @@ impl McpGrpcClient {
     #[instrument(skip(self))]
     pub async fn list_resource_templates(&mut self) -> GrpcResult<Vec<ResourceTemplate>> {
         let response = self
-            .client
+            .client()
             .list_resource_templates(proto::ListResourceTemplatesRequest { cursor: None })
             .await
             .map_err(|s| GrpcError::Mcp(status_to_mcp_error(&s)))?;
@@ -280,7 +402,7 @@ impl McpGrpcClient {
         uri: impl AsRef<str>,
     ) -> GrpcResult<Vec<ResourceContent>> {
         let response = self
-            .client
+            .client()
             .read_resource(proto::ReadResourceRequest {
                 uri: uri.as_ref().to_string(),
             })
@@ -302,7 +424,7 @@ impl McpGrpcClient {
     #[instrument(skip(self))]
     pub async fn list_prompts(&mut self) -> GrpcResult<Vec<Prompt>> {
         let response = self
-            .client
+            .client()
             .list_prompts(proto::ListPromptsRequest { cursor: None })
             .await
             .map_err(|s| GrpcError::Mcp(status_to_mcp_error(&s)))?;
@@ -333,7 +455,7 @@ impl McpGrpcClient {
         let arguments_bytes = arguments.map(|v| serde_json::to_vec(&v)).transpose()?;
 
         let response = self
-            .client
+            .client()
             .get_prompt(proto::GetPromptRequest {
                 name: name.as_ref().to_string(),
                 arguments: arguments_bytes,
@@ -374,4 +496,44 @@ mod tests {
         assert_eq!(config.protocol_version, "2025-11-25");
         assert_eq!(config.connect_timeout, Duration::from_secs(10));
     }
+
+    fn test_identity(name: &str) -> TlsIdentity {
+        let (cert, key): (&[u8], &[u8]) = match name {
+            "a" => (
+                include_bytes!("../testdata/client-a.crt"),
+                include_bytes!("../testdata/client-a.key"),
+            ),
+            _ => (
+                include_bytes!("../testdata/client-b.crt"),
+                include_bytes!("../testdata/client-b.key"),
+            ),
+        };
+        TlsIdentity::from_pem(cert, key)
+    }
+
+    #[tokio::test]
+    async fn test_client_identity_reload_opens_new_channel() {
+        let identity = ReloadableTlsIdentity::new(test_identity("a"));
+        let mut client = McpGrpcClient::connect_lazy_with_config(
+            "https://localhost:50051",
+            McpGrpcClientConfig {
+                tls_ca_pem: Some(include_bytes!("../testdata/client-a.crt").to_vec()),
+                client_identity: Some(identity.clone()),
+                ..Default::default()
+            },
+        )
+        .expect("lazy client with identity a");
+
+        // A clone made before the rotation keeps its channel
+        let mut existing = client.clone();
+
+        identity.reload(test_identity("b"));
+        client.client();
+        assert_eq!(client.identity_generation, 1);
+        assert_eq!(existing.identity_generation, 0);
+
+        // The older clone picks up the new identity only when it next sends
+        existing.client();
+        assert_eq!(existing.identity_generation, 1);
+    }
 }
@@ -9,7 +9,8 @@
 //! - **Server**: gRPC server implementation with streaming notifications
 //! - **Client**: gRPC client wrapper with explicit session initialization
 //! - **Tower Integration**: Composable middleware via Tower
-//! - **TLS**: Configured through tonic's transport builders
+//! - **TLS**: Server TLS through tonic's transport builders; client mTLS with
+//!   hot-reloadable certificates via `McpGrpcClientConfig::client_identity`
 //!
 //! # Quick Start
 //!
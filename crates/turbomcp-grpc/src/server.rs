@@ -694,4 +694,63 @@ mod tests {
         assert_eq!(server.server_info.version, "1.0.0");
         assert_eq!(server.protocol_version, "2025-11-25");
     }
+
+    #[cfg(feature = "client")]
+    struct StaticResourceHandler;
+
+    #[cfg(feature = "client")]
+    impl ResourceHandler for StaticResourceHandler {
+        fn read_resource(
+            &self,
+            uri: &str,
+        ) -> Pin<Box<dyn Future<Output = GrpcResult<Vec<ResourceContent>>> + Send + '_>> {
+            let uri = uri.to_string();
+            Box::pin(async move {
+                Ok(vec![ResourceContent::Text(
+                    turbomcp_types::TextResourceContents {
+                        uri,
+                        mime_type: Some("text/plain".to_string()),
+                        text: "hello over grpc".to_string(),
+                        meta: None,
+                    },
+                )])
+            })
+        }
+    }
+
+    #[cfg(feature = "client")]
+    #[tokio::test]
+    async fn test_read_resource_round_trip_over_grpc() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let service = McpGrpcServer::builder()
+            .server_info("resource-server", "1.0.0")
+            .add_resource(Resource::new("file:///greeting.txt", "greeting"))
+            .resource_handler(StaticResourceHandler)
+            .build()
+            .into_service();
+
+        let server = tokio::spawn(async move {
+            tonic::transport::Server::builder()
+                .add_service(service)
+                .serve_with_incoming(tokio_stream::wrappers::TcpListenerStream::new(listener))
+                .await
+        });
+
+        let mut client = crate::client::McpGrpcClient::connect(format!("http://{addr}"))
+            .await
+            .unwrap();
+
+        let resources = client.list_resources().await.unwrap();
+        assert_eq!(resources.len(), 1);
+        assert_eq!(resources[0].uri, "file:///greeting.txt");
+
+        let contents = client.read_resource("file:///greeting.txt").await.unwrap();
+        assert_eq!(contents.len(), 1);
+        assert_eq!(contents[0].uri(), "file:///greeting.txt");
+        assert_eq!(contents[0].text(), Some("hello over grpc"));
+
+        server.abort();
+    }
 }
@@ -96,6 +96,7 @@ pub use transport::{RetryPolicy, StreamableHttpClientConfig, StreamableHttpClien
 
 // Re-export common types from traits crate for convenience
 pub use turbomcp_transport_traits::{
-    LimitsConfig, TlsConfig, TlsVersion, Transport, TransportCapabilities, TransportError,
-    TransportMessage, TransportMetrics, TransportResult, TransportState, TransportType,
+    LimitsConfig, ReloadableTlsIdentity, TimeoutConfig, TlsConfig, TlsIdentity, TlsVersion,
+    Transport, TransportCapabilities, TransportError, TransportMessage, TransportMetrics,
+    TransportResult, TransportState, TransportType,
 };
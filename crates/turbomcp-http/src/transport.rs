@@ -5,7 +5,7 @@
 //! - Accept header negotiation (application/json, text/event-stream)
 //! - Handles SSE responses from POST requests
 //! - Backward-compatible handling for legacy SSE "endpoint" events
-//! - Auto-reconnect with exponential backoff
+//! - Auto-reconnect with exponential backoff, honoring server retry hints
 //! - Last-Event-ID resumability
 //! - Session management with Mcp-Session-Id
 //! - Protocol version headers
@@ -18,14 +18,16 @@ use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::time::Duration;
-use tokio::sync::{Mutex, RwLock, mpsc};
+use tokio::sync::{Mutex, RwLock, broadcast, mpsc};
 use tracing::{debug, error, info, warn};
 
 use turbomcp_protocol::MessageId;
+use turbomcp_protocol::error::{MAX_RETRY_AFTER, retry_after_from_meta};
 use turbomcp_transport_traits::{
-    LimitsConfig, TlsConfig, TlsVersion, Transport, TransportCapabilities, TransportError,
-    TransportEventEmitter, TransportMessage, TransportMetrics, TransportResult, TransportState,
-    TransportType, validate_request_size, validate_response_size,
+    LimitsConfig, ReloadableTlsIdentity, TimeoutConfig, TlsConfig, TlsIdentity, TlsVersion,
+    Transport, TransportCapabilities, TransportError, TransportEvent, TransportEventEmitter,
+    TransportMessage, TransportMetrics, TransportResult, TransportState, TransportType,
+    validate_request_size, validate_response_size,
 };
 
 /// Retry policy for auto-reconnect
@@ -107,6 +109,31 @@ impl RetryPolicy {
     }
 }
 
+/// Delay requested by a `Retry-After` header given in delay-seconds.
+fn retry_after_header(headers: &header::HeaderMap) -> Option<Duration> {
+    let seconds: u64 = headers
+        .get(header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()?;
+    Some(Duration::from_secs(seconds).min(MAX_RETRY_AFTER))
+}
+
+/// Delay requested in the `_meta` of a request, notification, result, or
+/// error data.
+fn retry_after_meta(message: &serde_json::Value) -> Option<Duration> {
+    let meta = [
+        message.get("params"),
+        message.get("result"),
+        message.get("error").and_then(|error| error.get("data")),
+    ]
+    .into_iter()
+    .find_map(|container| container?.get("_meta"))?;
+    retry_after_from_meta(meta)
+}
+
 /// Streamable HTTP client configuration
 #[derive(Clone, Debug)]
 pub struct StreamableHttpClientConfig {
@@ -120,6 +147,12 @@ pub struct StreamableHttpClientConfig {
     pub timeout: Duration,
 
     /// Auto-reconnect policy
+    ///
+    /// A server can lengthen the wait before the next reconnect with a
+    /// `Retry-After` header (in seconds), an SSE `retry:` field (in
+    /// milliseconds), or `retryAfter` / `retryAfterMs` in a message's `_meta`
+    /// (see [`retry_after_from_meta`]); the client then waits for the longer
+    /// of the hint, capped at [`MAX_RETRY_AFTER`], and its own backoff.
     pub retry_policy: RetryPolicy,
 
     /// Authentication token
@@ -149,6 +182,14 @@ pub struct StreamableHttpClientConfig {
     /// TLS/HTTPS configuration (v2.2.0+)
     pub tls: TlsConfig,
 
+    /// Client certificate for mutual TLS.
+    ///
+    /// Calling [`ReloadableTlsIdentity::reload`] on a clone of this handle
+    /// rotates the certificate without restarting: requests made afterwards
+    /// open connections with the new identity, while connections already
+    /// established (such as a running SSE stream) keep the old one.
+    pub client_identity: Option<ReloadableTlsIdentity>,
+
     /// Idle timeout between SSE chunks.
     ///
     /// Guards against a silent TCP half-open where the server stops writing
@@ -156,6 +197,13 @@ pub struct StreamableHttpClientConfig {
     /// the SSE task breaks and the reconnect loop takes over. Set generously —
     /// the SSE protocol tolerates long idle periods between events. Default: 5 minutes.
     pub sse_read_timeout: Duration,
+
+    /// Advertise `Accept-Encoding: gzip, br, zstd` and transparently decode
+    /// compressed responses.
+    ///
+    /// Independent of the transport-level `compression` feature. Size limits
+    /// apply to the decoded body. Default: enabled.
+    pub response_compression: bool,
 }
 
 impl Default for StreamableHttpClientConfig {
@@ -171,7 +219,9 @@ impl Default for StreamableHttpClientConfig {
             protocol_version: "2025-11-25".to_string(),
             limits: LimitsConfig::default(),
             tls: TlsConfig::default(),
+            client_identity: None,
             sse_read_timeout: Duration::from_secs(300),
+            response_compression: true,
         }
     }
 }
@@ -179,11 +229,16 @@ impl Default for StreamableHttpClientConfig {
 /// Streamable HTTP client transport
 pub struct StreamableHttpClientTransport {
     config: StreamableHttpClientConfig,
-    http_client: HttpClient,
+
+    /// Socket-level deadlines the HTTP client is built with
+    timeouts: TimeoutConfig,
+
+    /// HTTP client and the client identity generation it was built with
+    http_client: RwLock<(u64, HttpClient)>,
     state: Arc<RwLock<TransportState>>,
     capabilities: TransportCapabilities,
     metrics: Arc<RwLock<TransportMetrics>>,
-    _event_emitter: TransportEventEmitter,
+    event_emitter: TransportEventEmitter,
 
     /// Legacy SSE message endpoint if a server sends an `endpoint` event.
     ///
@@ -195,6 +250,9 @@ pub struct StreamableHttpClientTransport {
     /// Session ID from server
     session_id: Arc<RwLock<Option<String>>>,
 
+    /// Protocol version negotiated with the server during `initialize`
+    peer_protocol_version: std::sync::Mutex<Option<String>>,
+
     /// Last event ID for resumability
     last_event_id: Arc<RwLock<Option<String>>>,
 
@@ -227,6 +285,25 @@ impl StreamableHttpClientTransport {
     /// platform verifier). Pre-3.1 this was an `expect` and would panic the calling
     /// process; v3.1 propagates it instead.
     pub fn new(config: StreamableHttpClientConfig) -> TransportResult<Self> {
+        let mut timeouts = TimeoutConfig::default().with_write(None);
+        timeouts.read = None;
+        Self::with_timeouts(config, timeouts)
+    }
+
+    /// Create a transport that applies socket-level deadlines separately from
+    /// the request timeout.
+    ///
+    /// `connect` bounds establishing a connection and `read` bounds the gap
+    /// between reads of a response body, including SSE streams, so keep it
+    /// above the server's keep-alive interval. `request` and `total` are
+    /// enforced by the protocol client, and `write` is not applied: reqwest
+    /// has no write-phase deadline, so request bodies are bounded by
+    /// [`StreamableHttpClientConfig::timeout`]. [`new`](Self::new) uses a 30s
+    /// connect deadline and no read deadline.
+    pub fn with_timeouts(
+        config: StreamableHttpClientConfig,
+        timeouts: TimeoutConfig,
+    ) -> TransportResult<Self> {
         let (sse_tx, sse_rx) = mpsc::channel(1000);
         let (response_tx, response_rx) = mpsc::channel(100);
         let (event_emitter, _) = TransportEventEmitter::new();
@@ -240,13 +317,64 @@ impl StreamableHttpClientTransport {
             );
         }
 
+        let (identity_generation, identity) = match &config.client_identity {
+            Some(reloadable) => {
+                let (generation, identity) = reloadable.current();
+                (generation, Some(identity))
+            }
+            None => (0, None),
+        };
+        let http_client = Self::build_http_client(&config, &timeouts, identity.as_deref())?;
+
+        Ok(Self {
+            config,
+            timeouts,
+            http_client: RwLock::new((identity_generation, http_client)),
+            state: Arc::new(RwLock::new(TransportState::Disconnected)),
+            capabilities: TransportCapabilities {
+                max_message_size: Some(turbomcp_protocol::MAX_MESSAGE_SIZE),
+                supports_compression: false,
+                supports_streaming: true,
+                supports_bidirectional: true,
+                supports_multiplexing: false,
+                compression_algorithms: Vec::new(),
+                custom: HashMap::new(),
+            },
+            metrics: Arc::new(RwLock::new(TransportMetrics::default())),
+            event_emitter,
+            message_endpoint: Arc::new(RwLock::new(None)),
+            session_id: Arc::new(RwLock::new(None)),
+            peer_protocol_version: std::sync::Mutex::new(None),
+            last_event_id: Arc::new(RwLock::new(None)),
+            sse_receiver: Arc::new(Mutex::new(sse_rx)),
+            sse_sender: sse_tx,
+            response_receiver: Arc::new(Mutex::new(response_rx)),
+            response_sender: response_tx,
+            sse_task_handle: Arc::new(Mutex::new(None)),
+        })
+    }
+
+    /// Build the reqwest client for `config` and `timeouts`, presenting
+    /// `identity` if given.
+    fn build_http_client(
+        config: &StreamableHttpClientConfig,
+        timeouts: &TimeoutConfig,
+        identity: Option<&TlsIdentity>,
+    ) -> TransportResult<HttpClient> {
         // Build HTTP client with TLS configuration
         // IMPORTANT: Must explicitly call use_rustls_tls() because cargo features are additive
         // and other dependencies may bring in native-tls. Without this, TLS 1.3 minimum fails.
         // See: https://github.com/seanmonstar/reqwest/issues/1314
         let mut client_builder = HttpClient::builder()
             .use_rustls_tls()
-            .timeout(config.timeout);
+            .timeout(config.timeout)
+            .connect_timeout(timeouts.connect)
+            .gzip(config.response_compression)
+            .brotli(config.response_compression)
+            .zstd(config.response_compression);
+        if let Some(read) = timeouts.read {
+            client_builder = client_builder.read_timeout(read);
+        }
 
         // Redirect policy: when carrying a bearer token, only follow same-origin redirects
         // so the `Authorization: Bearer …` header (preserved by reqwest across redirects)
@@ -336,38 +464,59 @@ impl StreamableHttpClientTransport {
             }
         }
 
-        let http_client = client_builder.build().map_err(|e| {
+        // Present the client certificate for mutual TLS
+        if let Some(identity) = identity {
+            let identity = reqwest::Identity::from_pem(&identity.to_pem_bundle()).map_err(|e| {
+                TransportError::ConfigurationError(format!("Invalid TLS client identity: {e}"))
+            })?;
+            client_builder = client_builder.identity(identity);
+        }
+
+        client_builder.build().map_err(|e| {
             TransportError::ConfigurationError(format!(
                 "Failed to build HTTP client (likely bad TLS configuration): {e}"
             ))
-        })?;
-
-        Ok(Self {
-            config,
-            http_client,
-            state: Arc::new(RwLock::new(TransportState::Disconnected)),
-            capabilities: TransportCapabilities {
-                max_message_size: Some(turbomcp_protocol::MAX_MESSAGE_SIZE),
-                supports_compression: false,
-                supports_streaming: true,
-                supports_bidirectional: true,
-                supports_multiplexing: false,
-                compression_algorithms: Vec::new(),
-                custom: HashMap::new(),
-            },
-            metrics: Arc::new(RwLock::new(TransportMetrics::default())),
-            _event_emitter: event_emitter,
-            message_endpoint: Arc::new(RwLock::new(None)),
-            session_id: Arc::new(RwLock::new(None)),
-            last_event_id: Arc::new(RwLock::new(None)),
-            sse_receiver: Arc::new(Mutex::new(sse_rx)),
-            sse_sender: sse_tx,
-            response_receiver: Arc::new(Mutex::new(response_rx)),
-            response_sender: response_tx,
-            sse_task_handle: Arc::new(Mutex::new(None)),
         })
     }
 
+    /// HTTP client for the next request.
+    ///
+    /// Rebuilds the client when the client identity has been reloaded, so new
+    /// connections present the new certificate. Clones of the previous client
+    /// (and their pooled connections) stay usable until dropped. If the new
+    /// identity is invalid, the error is logged and the previous client kept.
+    async fn current_http_client(&self) -> HttpClient {
+        let Some(reloadable) = &self.config.client_identity else {
+            return self.http_client.read().await.1.clone();
+        };
+
+        {
+            let cached = self.http_client.read().await;
+            if cached.0 == reloadable.generation() {
+                return cached.1.clone();
+            }
+        }
+
+        let mut cached = self.http_client.write().await;
+        let (generation, identity) = reloadable.current();
+        if cached.0 != generation {
+            match Self::build_http_client(&self.config, &self.timeouts, Some(&identity)) {
+                Ok(client) => {
+                    info!(generation, "Reloaded TLS client identity");
+                    cached.1 = client;
+                }
+                Err(e) => {
+                    error!(
+                        generation,
+                        "Failed to apply reloaded TLS client identity: {e}"
+                    );
+                }
+            }
+            cached.0 = generation;
+        }
+        cached.1.clone()
+    }
+
     /// Get full endpoint URL
     fn get_endpoint_url(&self) -> String {
         format!("{}{}", self.config.base_url, self.config.endpoint_path)
@@ -451,7 +600,7 @@ impl StreamableHttpClientTransport {
 
         let endpoint_url = self.get_endpoint_url();
         let config = self.config.clone();
-        let http_client = self.http_client.clone();
+        let http_client = self.current_http_client().await;
         let state = Arc::clone(&self.state);
         let sse_sender = self.sse_sender.clone();
         let session_id = Arc::clone(&self.session_id);
@@ -490,18 +639,25 @@ impl StreamableHttpClientTransport {
         message_endpoint: Arc<RwLock<Option<String>>>,
     ) {
         let mut attempt = 0u32;
+        let mut retry_hint: Option<Duration> = None;
 
         loop {
             // Check if we should retry
-            if let Some(delay) = config.retry_policy.delay(attempt) {
-                if attempt > 0 {
-                    warn!("Reconnecting in {:?} (attempt {})", delay, attempt + 1);
-                    tokio::time::sleep(delay).await;
-                }
-            } else {
+            let Some(delay) = config.retry_policy.delay(attempt) else {
                 error!("Max retry attempts reached, giving up");
                 *state.write().await = TransportState::Disconnected;
                 break;
+            };
+            // A server hint applies to the next reconnect only, and overrides
+            // a shorter backoff or the immediate reconnect after a clean close.
+            let wait = match retry_hint.take() {
+                Some(hint) if attempt > 0 => Some(hint.max(delay)),
+                Some(hint) => Some(hint),
+                None => (attempt > 0).then_some(delay),
+            };
+            if let Some(wait) = wait {
+                warn!("Reconnecting in {:?} (attempt {})", wait, attempt + 1);
+                tokio::time::sleep(wait).await;
             }
 
             // Build request with proper headers
@@ -536,6 +692,8 @@ impl StreamableHttpClientTransport {
             // Connect to SSE endpoint
             match http_client.get(&endpoint_url).headers(headers).send().await {
                 Ok(response) => {
+                    retry_hint = retry_after_header(response.headers());
+
                     if response.status() == reqwest::StatusCode::METHOD_NOT_ALLOWED {
                         info!(
                             "Server returned HTTP 405 for GET {}. Continuing without standalone SSE polling.",
@@ -605,6 +763,7 @@ impl StreamableHttpClientTransport {
                                         &sse_sender,
                                         &last_event_id,
                                         &message_endpoint,
+                                        &mut retry_hint,
                                     )
                                     .await
                                     {
@@ -647,6 +806,7 @@ impl StreamableHttpClientTransport {
         sse_sender: &mpsc::Sender<TransportMessage>,
         last_event_id: &Arc<RwLock<Option<String>>>,
         message_endpoint: &Arc<RwLock<Option<String>>>,
+        retry_hint: &mut Option<Duration>,
     ) -> TransportResult<()> {
         let lines: Vec<&str> = event_str.lines().collect();
         let mut event_type: Option<String> = None;
@@ -666,6 +826,11 @@ impl StreamableHttpClientTransport {
                     "event" => event_type = Some(value.to_string()),
                     "data" => event_data.push(value.to_string()),
                     "id" => event_id = Some(value.to_string()),
+                    "retry" => {
+                        if let Ok(millis) = value.parse() {
+                            *retry_hint = Some(Duration::from_millis(millis).min(MAX_RETRY_AFTER));
+                        }
+                    }
                     _ => {}
                 }
             }
@@ -731,6 +896,9 @@ impl StreamableHttpClientTransport {
                     serde_json::from_str(&data_str).map_err(|e| {
                         TransportError::SerializationFailed(format!("Invalid JSON: {}", e))
                     })?;
+                if let Some(hint) = retry_after_meta(&json_value) {
+                    *retry_hint = Some(hint);
+                }
 
                 let message = TransportMessage::new(
                     MessageId::from("sse-message".to_string()),
@@ -876,7 +1044,8 @@ impl Transport for StreamableHttpClientTransport {
 
             // Send POST request
             let response = self
-                .http_client
+                .current_http_client()
+                .await
                 .post(&url)
                 .headers(headers)
                 .header(header::CONTENT_TYPE, "application/json")
@@ -1092,6 +1261,24 @@ impl Transport for StreamableHttpClientTransport {
         Box::pin(async move { self.metrics.read().await.clone() })
     }
 
+    fn subscribe_events(&self) -> Option<broadcast::Receiver<TransportEvent>> {
+        Some(self.event_emitter.subscribe())
+    }
+
+    fn peer_protocol_version(&self) -> Option<String> {
+        self.peer_protocol_version
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clone()
+    }
+
+    fn set_peer_protocol_version(&self, version: &str) {
+        *self
+            .peer_protocol_version
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(version.to_string());
+    }
+
     fn connect(&self) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
         Box::pin(async move {
             info!("Connecting to {}", self.get_endpoint_url());
@@ -1102,6 +1289,8 @@ impl Transport for StreamableHttpClientTransport {
             self.start_sse_connection().await?;
 
             *self.state.write().await = TransportState::Connected;
+            self.event_emitter
+                .emit_connected(TransportType::Http, self.get_endpoint_url());
 
             info!("Connected successfully");
             Ok(())
@@ -1127,10 +1316,21 @@ impl Transport for StreamableHttpClientTransport {
                     headers.insert("Mcp-Session-Id", session_value);
                 }
 
-                let _ = self.http_client.delete(&url).headers(headers).send().await;
+                let _ = self
+                    .current_http_client()
+                    .await
+                    .delete(&url)
+                    .headers(headers)
+                    .send()
+                    .await;
             }
 
             *self.state.write().await = TransportState::Disconnected;
+            self.event_emitter.emit_disconnected(
+                TransportType::Http,
+                self.get_endpoint_url(),
+                None,
+            );
 
             info!("Disconnected");
             Ok(())
@@ -1141,6 +1341,7 @@ impl Transport for StreamableHttpClientTransport {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use turbomcp_protocol::error::{RETRY_AFTER_META_KEY, RETRY_AFTER_MS_META_KEY};
 
     #[test]
     fn test_retry_policy_fixed() {
@@ -1298,4 +1499,129 @@ mod tests {
             serde_json::from_slice(&message.payload).expect("valid queued JSON");
         assert_eq!(value["jsonrpc"], "2.0");
     }
+
+    #[test]
+    fn test_retry_after_hints() {
+        let mut headers = header::HeaderMap::new();
+        assert_eq!(retry_after_header(&headers), None);
+        headers.insert(header::RETRY_AFTER, header::HeaderValue::from_static("5"));
+        assert_eq!(retry_after_header(&headers), Some(Duration::from_secs(5)));
+        headers.insert(
+            header::RETRY_AFTER,
+            header::HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        assert_eq!(retry_after_header(&headers), None);
+
+        let notification = serde_json::json!({
+            "jsonrpc": "2.0",
+            "method": "notifications/message",
+            "params": { "_meta": { RETRY_AFTER_META_KEY: 2.5 } }
+        });
+        assert_eq!(
+            retry_after_meta(&notification),
+            Some(Duration::from_millis(2500))
+        );
+        let negative = serde_json::json!({ "result": { "_meta": { RETRY_AFTER_META_KEY: -1 } } });
+        assert_eq!(retry_after_meta(&negative), None);
+        let error = serde_json::json!({
+            "error": { "code": -32000, "message": "overloaded", "data": { "_meta": { RETRY_AFTER_MS_META_KEY: 750 } } }
+        });
+        assert_eq!(retry_after_meta(&error), Some(Duration::from_millis(750)));
+        let huge = serde_json::json!({ "result": { "_meta": { RETRY_AFTER_META_KEY: 1e12 } } });
+        assert_eq!(retry_after_meta(&huge), Some(MAX_RETRY_AFTER));
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_waits_for_server_retry_after() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        // Virtual time: the 5s hint elapses without a real sleep
+        tokio::time::pause();
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind");
+        let addr = listener.local_addr().expect("local addr");
+        let (arrived_tx, mut arrived) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            for response in [
+                "HTTP/1.1 503 Service Unavailable\r\nRetry-After: 5\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                "HTTP/1.1 405 Method Not Allowed\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+            ] {
+                let (mut socket, _) = listener.accept().await.expect("accept");
+                let mut request = Vec::new();
+                let mut buf = [0u8; 1024];
+                while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                    let n = socket.read(&mut buf).await.expect("read");
+                    if n == 0 {
+                        break;
+                    }
+                    request.extend_from_slice(&buf[..n]);
+                }
+                let _ = arrived_tx.send(tokio::time::Instant::now());
+                socket.write_all(response.as_bytes()).await.expect("write");
+                let _ = socket.shutdown().await;
+            }
+        });
+
+        // The client's own backoff is far shorter than the server's hint.
+        let transport = StreamableHttpClientTransport::new(StreamableHttpClientConfig {
+            base_url: format!("http://{addr}"),
+            retry_policy: RetryPolicy::Fixed {
+                interval: Duration::from_millis(10),
+                max_attempts: Some(3),
+            },
+            ..Default::default()
+        })
+        .expect("client");
+        *transport.session_id.write().await = Some("session-1".to_string());
+        transport.connect().await.expect("connect");
+
+        let first = arrived.recv().await.expect("initial SSE request");
+        let second = arrived.recv().await.expect("reconnect request");
+        assert!(
+            second - first >= Duration::from_secs(5),
+            "reconnected after {:?}",
+            second - first
+        );
+    }
+
+    fn test_identity(name: &str) -> TlsIdentity {
+        let (cert, key): (&[u8], &[u8]) = match name {
+            "a" => (
+                include_bytes!("../testdata/client-a.crt"),
+                include_bytes!("../testdata/client-a.key"),
+            ),
+            _ => (
+                include_bytes!("../testdata/client-b.crt"),
+                include_bytes!("../testdata/client-b.key"),
+            ),
+        };
+        TlsIdentity::from_pem(cert, key)
+    }
+
+    #[tokio::test]
+    async fn test_client_identity_reload_rebuilds_client() {
+        let identity = ReloadableTlsIdentity::new(test_identity("a"));
+        let transport = StreamableHttpClientTransport::new(StreamableHttpClientConfig {
+            client_identity: Some(identity.clone()),
+            ..Default::default()
+        })
+        .expect("client with identity a");
+
+        // A client handed out before the rotation, e.g. to a running SSE task
+        let existing = transport.current_http_client().await;
+        assert_eq!(transport.http_client.read().await.0, 0);
+
+        identity.reload(test_identity("b"));
+        let _new = transport.current_http_client().await;
+        assert_eq!(transport.http_client.read().await.0, 1);
+
+        // The earlier client is still usable with its own connection pool
+        assert!(existing.get("https://example.invalid/").build().is_ok());
+
+        // An invalid identity is skipped and the last good client kept
+        identity.reload(TlsIdentity::from_pem("not a cert", "not a key"));
+        let _kept = transport.current_http_client().await;
+        assert_eq!(transport.http_client.read().await.0, 2);
+    }
 }
@@ -83,6 +83,7 @@ mod tool;
 /// - `name = "server-name"` - Server name (defaults to struct name)
 /// - `version = "1.0.0"` - Server version (defaults to "1.0.0")
 /// - `description = "..."` - Server description
+/// - `icon = "https://..."` - Server icon URL or `data:` URI, checked at compile time
 ///
 /// # Example
 ///
@@ -151,6 +152,34 @@ pub fn server(args: TokenStream, input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// # Raw Requests
+///
+/// A tool that takes `CallToolRequest` receives the call as sent, including
+/// `_meta` and arguments of any shape, instead of typed parameters. No
+/// argument extraction or schema is generated; a `RequestContext` parameter
+/// may still be added.
+///
+/// ```ignore
+/// #[tool]
+/// async fn trace(&self, request: CallToolRequest, ctx: &RequestContext) -> String {
+///     request._meta.map(|m| m.to_string()).unwrap_or_default()
+/// }
+/// ```
+///
+/// # Dry Runs
+///
+/// A call whose `_meta` sets `"dry_run": true` asks the tool to validate
+/// without executing. Tools marked `supports_dry_run` extract and check their
+/// arguments, then return `ToolResult::dry_run` instead of running the body.
+/// Other tools reject the call with a tool error.
+///
+/// ```ignore
+/// #[tool(supports_dry_run)]
+/// async fn delete_branch(&self, name: String) -> McpResult<String> {
+///     // Only reached when `_meta.dry_run` is absent or false.
+/// }
+/// ```
+///
 /// # Cancellation
 ///
 /// Per MCP §Cancellation, a client may send `notifications/cancelled` to
@@ -40,6 +40,8 @@ pub struct ServerInfo {
     pub version: String,
     /// Server description
     pub description: Option<String>,
+    /// Server icon (HTTP(S) URL or data: URI)
+    pub icon: Option<String>,
     /// Tool handlers
     pub tools: Vec<ToolInfo>,
     /// Resource handlers
@@ -111,6 +113,8 @@ pub struct ServerAttrs {
     pub version: Option<String>,
     /// Server description
     pub description: Option<String>,
+    /// Server icon (HTTP(S) URL or data: URI)
+    pub icon: Option<String>,
 }
 
 impl ServerAttrs {
@@ -119,12 +123,14 @@ impl ServerAttrs {
         let mut name = None;
         let mut version = None;
         let mut description = None;
+        let mut icon = None;
 
         if args.is_empty() {
             return Ok(Self {
                 name,
                 version,
                 description,
+                icon,
             });
         }
 
@@ -138,6 +144,16 @@ impl ServerAttrs {
             } else if meta.path.is_ident("description") {
                 let value: syn::LitStr = meta.value()?.parse()?;
                 description = Some(value.value());
+            } else if meta.path.is_ident("icon") {
+                let value: syn::LitStr = meta.value()?.parse()?;
+                let src = value.value();
+                if !turbomcp_types::Icon::is_valid_src(&src) {
+                    return Err(syn::Error::new(
+                        value.span(),
+                        "`icon` must be an http(s) URL or a data: URI",
+                    ));
+                }
+                icon = Some(src);
             } else if meta.path.is_ident("transports") {
                 // v3: The `transports` attribute was removed.
                 //
@@ -165,6 +181,7 @@ impl ServerAttrs {
             name,
             version,
             description,
+            icon,
         })
     }
 }
@@ -196,6 +213,7 @@ pub fn analyze_impl(impl_block: &ItemImpl, attrs: &ServerAttrs) -> Result<Server
         .unwrap_or_else(|| struct_name.to_string());
     let version = attrs.version.clone().unwrap_or_else(|| "1.0.0".to_string());
     let description = attrs.description.clone();
+    let icon = attrs.icon.clone();
 
     let mut tools = Vec::new();
     let mut resources = Vec::new();
@@ -260,6 +278,7 @@ pub fn analyze_impl(impl_block: &ItemImpl, attrs: &ServerAttrs) -> Result<Server
         name,
         version,
         description,
+        icon,
         tools,
         resources,
         prompts,
@@ -578,6 +597,11 @@ pub fn generate_mcp_handler(info: &ServerInfo, impl_block: &ItemImpl) -> TokenSt
     } else {
         quote! {}
     };
+    let icon_code = if let Some(src) = &info.icon {
+        quote! { .with_icon(#turbomcp::__macro_support::turbomcp_types::Icon::new(#src)) }
+    } else {
+        quote! {}
+    };
 
     // Generate tool listing code
     // Uses #turbomcp::__macro_support:: paths so users don't need internal crates
@@ -761,20 +785,57 @@ pub fn generate_mcp_handler(info: &ServerInfo, impl_block: &ItemImpl) -> TokenSt
     let tool_dispatch_code = info.tools.iter().map(|tool| {
         let tool_name = &tool.name;
         let fn_name = syn::Ident::new(&tool.name, proc_macro2::Span::call_site());
-        let extraction = generate_extraction_code(&tool.parameters, &turbomcp);
+        let extraction = if tool.raw_request {
+            // Raw-request tools get the call as sent, bypassing typed extraction.
+            quote! {
+                let request = #turbomcp::__macro_support::turbomcp_protocol::CallToolRequest {
+                    name: name.clone(),
+                    arguments: Some(args.clone().into_iter().collect()),
+                    _meta: ctx.request_meta().cloned(),
+                    ..::std::default::Default::default()
+                };
+            }
+        } else {
+            generate_extraction_code(&tool.parameters, &turbomcp)
+        };
         let call_args = generate_call_args(&tool.sig);
+        // Dry runs stop after argument extraction, so validation still runs
+        // but the body does not. Tools that did not opt in refuse the flag
+        // rather than execute side effects the caller asked to skip.
+        let dry_run = if tool.supports_dry_run {
+            quote! {
+                if ctx.is_dry_run() {
+                    return Ok(#turbomcp::__macro_support::turbomcp_types::ToolResult::dry_run(&name));
+                }
+            }
+        } else {
+            quote! {}
+        };
+        let dry_run_refusal = if tool.supports_dry_run {
+            quote! {}
+        } else {
+            quote! {
+                if ctx.is_dry_run() {
+                    return Ok(#turbomcp::__macro_support::turbomcp_types::ToolResult::error(
+                        format!("Tool '{}' does not support dry runs", name)
+                    ));
+                }
+            }
+        };
 
         quote! {
             #tool_name => {
-                let outcome: ::std::result::Result<_, #turbomcp::__macro_support::turbomcp_core::error::McpError> = async {
+                #dry_run_refusal
+                let outcome: ::std::result::Result<#turbomcp::__macro_support::turbomcp_types::ToolResult, #turbomcp::__macro_support::turbomcp_core::error::McpError> = async {
                     #extraction
-                    Ok(self.#fn_name(#call_args).await)
+                    #dry_run
+                    Ok(#turbomcp::__macro_support::turbomcp_types::IntoToolResult::into_tool_result(
+                        self.#fn_name(#call_args).await
+                    ))
                 }.await;
 
                 match outcome {
-                    Ok(result) => Ok(
-                        #turbomcp::__macro_support::turbomcp_types::IntoToolResult::into_tool_result(result)
-                    ),
+                    Ok(result) => Ok(result),
                     Err(e) if e.kind == #turbomcp::__macro_support::turbomcp_core::error::ErrorKind::InvalidParams => {
                         // SEP-1303: validation failure → tool execution error.
                         Ok(#turbomcp::__macro_support::turbomcp_types::ToolResult::error(e.message.clone()))
@@ -892,6 +953,7 @@ pub fn generate_mcp_handler(info: &ServerInfo, impl_block: &ItemImpl) -> TokenSt
             fn server_info(&self) -> #turbomcp::__macro_support::turbomcp_types::ServerInfo {
                 #turbomcp::__macro_support::turbomcp_types::ServerInfo::new(#name, #version)
                     #description_code
+                    #icon_code
             }
 
             fn list_tools(&self) -> Vec<#turbomcp::__macro_support::turbomcp_types::Tool> {
@@ -67,6 +67,9 @@ pub struct ToolInfo {
     pub sig: Signature,
     /// Parameters extracted from signature
     pub parameters: Vec<ParameterInfo>,
+    /// Whether the handler takes the raw `CallToolRequest` instead of typed
+    /// parameters. Argument extraction is skipped for such tools.
+    pub raw_request: bool,
     /// Tags for categorization (e.g., ["admin", "dangerous"])
     pub tags: Vec<String>,
     /// Version string (e.g., "2.0.0")
@@ -80,6 +83,8 @@ pub struct ToolInfo {
     /// Optional output-schema source type. The macro emits
     /// `schemars::schema_for!(ty)` and stores the result as `Tool.outputSchema`.
     pub output_schema: Option<Type>,
+    /// Whether the tool honors a `dry_run` flag in the request `_meta`.
+    pub supports_dry_run: bool,
 }
 
 /// Boolean hints copied verbatim into `ToolAnnotations`.
@@ -130,6 +135,8 @@ pub struct ToolAttrs {
     pub annotations: ToolAnnotationFlags,
     /// Output-schema source type (`output_schema = MyType`).
     pub output_schema: Option<Type>,
+    /// Bare `supports_dry_run` flag.
+    pub supports_dry_run: bool,
 }
 
 impl ToolAttrs {
@@ -184,6 +191,8 @@ impl ToolAttrs {
                 // `output_schema = SomeType` — accept any syn::Type so generics
                 // and qualified paths work.
                 attrs.output_schema = Some(meta.value()?.parse::<Type>()?);
+            } else if meta.path.is_ident("supports_dry_run") {
+                attrs.supports_dry_run = true;
             } else {
                 // Unknown key — surface a clear compile-time error instead of
                 // silently dropping it. A typo like `descriptio = "..."` would
@@ -195,7 +204,7 @@ impl ToolAttrs {
                     .map(|i| i.to_string())
                     .unwrap_or_else(|| "<unknown>".to_string());
                 return Err(meta.error(format!(
-                    "unknown #[tool] attribute key `{key}`; expected one of `description`, `tags`, `version`, `title`, `icons`, `read_only`, `destructive`, `idempotent`, `open_world`, `output_schema`",
+                    "unknown #[tool] attribute key `{key}`; expected one of `description`, `tags`, `version`, `title`, `icons`, `read_only`, `destructive`, `idempotent`, `open_world`, `output_schema`, `supports_dry_run`",
                 )));
             }
             Ok(())
@@ -230,6 +239,7 @@ impl ToolAttrs {
         attrs.annotations.destructive = parse_bool_value(&token_str, "destructive");
         attrs.annotations.idempotent = parse_bool_value(&token_str, "idempotent");
         attrs.annotations.open_world = parse_bool_value(&token_str, "open_world");
+        attrs.supports_dry_run = has_flag(&token_str, "supports_dry_run");
 
         Ok(attrs)
     }
@@ -379,6 +389,16 @@ pub fn parse_bool_value(token_str: &str, key: &str) -> Option<bool> {
     None
 }
 
+/// Whether a bare `key` flag appears at the top level of a stringified token
+/// stream. Used by the alternative attribute parser.
+pub fn has_flag(token_str: &str, key: &str) -> bool {
+    syn::parse_str::<proc_macro2::TokenStream>(token_str).is_ok_and(|tokens| {
+        tokens
+            .into_iter()
+            .any(|token| matches!(token, proc_macro2::TokenTree::Ident(ident) if ident == key))
+    })
+}
+
 impl ToolInfo {
     /// Extract tool info from a function.
     pub fn from_fn(item: &ItemFn, attrs: ToolAttrs) -> Result<Self, syn::Error> {
@@ -390,18 +410,30 @@ impl ToolInfo {
 
         // Analyze parameters
         let parameters = analyze_parameters(&item.sig)?;
+        let raw_request = item.sig.inputs.iter().any(
+            |input| matches!(input, FnArg::Typed(PatType { ty, .. }) if is_call_tool_request_type(ty)),
+        );
+        if raw_request && !parameters.is_empty() {
+            return Err(syn::Error::new_spanned(
+                &item.sig.inputs,
+                "A tool taking `CallToolRequest` cannot also take typed parameters; \
+                 read them from `request.arguments` instead",
+            ));
+        }
 
         Ok(Self {
             name,
             description,
             sig: item.sig.clone(),
             parameters,
+            raw_request,
             tags: attrs.tags,
             version: attrs.version,
             title: attrs.title,
             icons: attrs.icons,
             annotations: attrs.annotations,
             output_schema: attrs.output_schema,
+            supports_dry_run: attrs.supports_dry_run,
         })
     }
 }
@@ -445,8 +477,8 @@ fn analyze_parameters(sig: &Signature) -> Result<Vec<ParameterInfo>, syn::Error>
                 if let Pat::Ident(pat_ident) = pat.as_ref() {
                     let param_name = pat_ident.ident.to_string();
 
-                    // Skip context parameters
-                    if is_context_type(ty) {
+                    // Skip context and raw request parameters
+                    if is_context_type(ty) || is_call_tool_request_type(ty) {
                         continue;
                     }
 
@@ -506,6 +538,18 @@ fn is_context_type(ty: &Type) -> bool {
     }
 }
 
+/// Check if a type is the raw `CallToolRequest`.
+fn is_call_tool_request_type(ty: &Type) -> bool {
+    match ty {
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|seg| seg.ident == "CallToolRequest"),
+        _ => false,
+    }
+}
+
 /// Check if a type is Option<T>.
 fn is_option_type(ty: &Type) -> bool {
     if let Type::Path(type_path) = ty {
@@ -809,6 +853,8 @@ pub fn generate_call_args(sig: &Signature) -> TokenStream {
                 if let Pat::Ident(pat_ident) = pat.as_ref() {
                     if is_context_type(ty) {
                         args.push(quote! { ctx });
+                    } else if is_call_tool_request_type(ty) {
+                        args.push(quote! { request });
                     } else {
                         let name = &pat_ident.ident;
                         args.push(quote! { #name });
@@ -872,4 +918,27 @@ mod tests {
         let ty: Type = parse_quote!(String);
         assert!(!is_context_type(&ty));
     }
+
+    #[test]
+    fn test_raw_request_tool_skips_typed_parameters() {
+        let item: ItemFn = parse_quote! {
+            async fn raw(&self, request: CallToolRequest, ctx: &RequestContext) -> String {
+                request.name
+            }
+        };
+        let info = ToolInfo::from_fn(&item, ToolAttrs::default()).unwrap();
+        assert!(info.raw_request);
+        assert!(info.parameters.is_empty());
+        assert_eq!(
+            generate_call_args(&info.sig).to_string(),
+            quote! { request, ctx }.to_string()
+        );
+
+        let mixed: ItemFn = parse_quote! {
+            async fn mixed(&self, request: CallToolRequest, name: String) -> String {
+                name
+            }
+        };
+        assert!(ToolInfo::from_fn(&mixed, ToolAttrs::default()).is_err());
+    }
 }
@@ -36,7 +36,8 @@ use serde::{Serialize, de::DeserializeOwned};
 
 // Re-export wire codec types
 pub use turbomcp_wire::{
-    AnyCodec, Codec, CodecError, CodecResult, JsonCodec, StreamingJsonDecoder,
+    AnyCodec, Codec, CodecError, CodecResult, DEFAULT_MAX_JSON_DEPTH, JsonCodec,
+    StreamingJsonDecoder, check_json_depth,
 };
 
 #[cfg(feature = "wire-simd")]
@@ -181,6 +182,15 @@ impl ProtocolCodec {
         }
     }
 
+    /// Set the maximum JSON nesting depth accepted by [`Self::decode`].
+    ///
+    /// Defaults to [`DEFAULT_MAX_JSON_DEPTH`]. Has no effect on MessagePack.
+    #[must_use]
+    pub fn with_max_json_depth(mut self, max_depth: usize) -> Self {
+        self.inner = self.inner.with_max_json_depth(max_depth);
+        self
+    }
+
     /// Get the codec type
     #[must_use]
     pub fn codec_type(&self) -> CodecType {
@@ -1,14 +1,48 @@
 //! Error handling types for MCP protocol.
 //!
 //! v3.0: The primary error type is now `McpError` from `turbomcp-core`.
-//! This module only contains supplementary types like `RetryInfo`.
+//! This module only contains supplementary types like `RetryInfo` and the
+//! server-provided `_meta` retry hint keys.
 //!
 //! For error handling, use:
 //! - `turbomcp_protocol::McpError` - The unified error type
 //! - `turbomcp_protocol::ErrorKind` - Error classification
 //! - `turbomcp_protocol::McpResult<T>` - Result alias
 
+use std::time::Duration;
+
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// `_meta` key carrying a server's retry hint in milliseconds
+pub const RETRY_AFTER_MS_META_KEY: &str = "retryAfterMs";
+
+/// `_meta` key carrying a server's retry hint in seconds, consulted when
+/// [`RETRY_AFTER_MS_META_KEY`] is absent
+pub const RETRY_AFTER_META_KEY: &str = "retryAfter";
+
+/// Upper bound on a server-requested retry delay
+///
+/// A misbehaving server must not be able to park a client indefinitely, so
+/// hints larger than this are clamped.
+pub const MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+/// Parse the retry delay a server requested in a `_meta` object
+///
+/// Reads [`RETRY_AFTER_MS_META_KEY`], falling back to [`RETRY_AFTER_META_KEY`],
+/// and clamps the result to [`MAX_RETRY_AFTER`]. Returns `None` if neither key
+/// holds a non-negative number.
+#[must_use]
+pub fn retry_after_from_meta(meta: &Value) -> Option<Duration> {
+    let delay = match meta.get(RETRY_AFTER_MS_META_KEY).and_then(Value::as_u64) {
+        Some(millis) => Duration::from_millis(millis),
+        None => {
+            let seconds = meta.get(RETRY_AFTER_META_KEY)?.as_f64()?;
+            Duration::try_from_secs_f64(seconds.min(MAX_RETRY_AFTER.as_secs_f64())).ok()?
+        }
+    };
+    Some(delay.min(MAX_RETRY_AFTER))
+}
 
 /// Information about retry attempts
 ///
@@ -151,6 +185,26 @@ impl Default for RetryInfo {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_retry_after_from_meta() {
+        use serde_json::json;
+
+        assert_eq!(
+            retry_after_from_meta(&json!({ "retryAfterMs": 250, "retryAfter": 9 })),
+            Some(Duration::from_millis(250))
+        );
+        assert_eq!(
+            retry_after_from_meta(&json!({ "retryAfter": 2.5 })),
+            Some(Duration::from_millis(2500))
+        );
+        assert_eq!(
+            retry_after_from_meta(&json!({ "retryAfter": 3600 })),
+            Some(MAX_RETRY_AFTER)
+        );
+        assert_eq!(retry_after_from_meta(&json!({ "retryAfter": -1 })), None);
+        assert_eq!(retry_after_from_meta(&json!({})), None);
+    }
+
     #[test]
     fn test_retry_info_creation() {
         let retry_info = RetryInfo::new(5);
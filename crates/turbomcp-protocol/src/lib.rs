@@ -207,7 +207,9 @@
 pub use turbomcp_core as mcp_core;
 
 // v3.0: McpError is THE error type - re-export at crate root
-pub use turbomcp_core::error::{ErrorContext as McpErrorContext, ErrorKind, McpError, McpResult};
+pub use turbomcp_core::error::{
+    ErrorContext as McpErrorContext, ErrorKind, McpError, McpErrorExt, McpResult,
+};
 /// v3.0 Result alias using McpError
 pub type Result<T> = McpResult<T>;
 /// v3.0 Error alias for migration (prefer McpError directly)
@@ -351,7 +353,9 @@ pub use capabilities::{
     },
 };
 
-pub use versioning::adapter::{VersionAdapter, adapter_for_version};
+pub use versioning::adapter::{
+    VersionAdapter, adapter_for_version, deserialize_content, serialize_content,
+};
 pub use versioning::{VersionCompatibility, VersionManager, VersionRequirement};
 
 // Re-export constants from core (single source of truth - DRY)
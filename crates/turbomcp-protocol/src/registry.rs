@@ -17,11 +17,27 @@
 //! - `turbomcp_server::registry::HandlerRegistry` - Server-specific registry for MCP protocol handlers
 //!
 //! This is the foundation - use [`EnhancedRegistry`](crate::enhanced_registry::EnhancedRegistry) if you need advanced features.
+//!
+//! ## Lazy Components
+//!
+//! Components with expensive async setup (database pools, warmed caches) can
+//! be registered with [`Registry::register_lazy`] so they don't block server
+//! construction. The setup future runs once, on the first
+//! [`Registry::resolve`]; concurrent first callers all await that same run.
+//!
+//! ```rust,ignore
+//! registry.register_lazy("db", || async { Database::connect(url).await })?;
+//!
+//! // Later, inside a handler:
+//! let db: Arc<Database> = registry.resolve("db").await?;
+//! ```
 
 use std::any::{Any, TypeId};
 use std::collections::HashMap;
+use std::future::Future;
 use std::sync::Arc;
 
+use futures::future::{BoxFuture, FutureExt, Shared};
 use parking_lot::RwLock;
 use thiserror::Error;
 
@@ -30,6 +46,7 @@ use thiserror::Error;
 /// These errors are domain-specific for registry operations and are converted
 /// to the main [`Error`](crate::Error) type when crossing API boundaries.
 #[derive(Error, Debug, Clone)]
+#[non_exhaustive]
 pub enum RegistryError {
     /// Component not found
     #[error("Component {0} not found")]
@@ -42,6 +59,10 @@ pub enum RegistryError {
     /// Type mismatch
     #[error("Type mismatch for component {0}")]
     TypeMismatch(String),
+
+    /// Lazy component whose initialization has not completed
+    #[error("Component {0} is not initialized yet")]
+    NotInitialized(String),
 }
 
 // Conversion to McpError for API boundary crossing (v3.0)
@@ -62,6 +83,11 @@ impl From<RegistryError> for crate::McpError {
                 name
             ))
             .with_component("registry"),
+            RegistryError::NotInitialized(name) => crate::McpError::internal(format!(
+                "Component '{}' in registry is not initialized yet",
+                name
+            ))
+            .with_component("registry"),
         }
     }
 }
@@ -76,6 +102,14 @@ pub struct Registry {
     type_map: RwLock<HashMap<String, TypeId>>,
 }
 
+/// Storage for a component registered with [`Registry::register_lazy`].
+///
+/// The shared future runs the setup at most once and hands every awaiting
+/// caller the same instance.
+struct LazyComponent<T> {
+    init: Shared<BoxFuture<'static, Arc<T>>>,
+}
+
 /// Registry builder for fluent configuration
 #[derive(Debug)]
 pub struct RegistryBuilder {
@@ -105,15 +139,44 @@ impl Registry {
     where
         T: 'static + Send + Sync,
     {
-        let name = name.into();
-        let type_id = TypeId::of::<T>();
+        self.insert(name.into(), TypeId::of::<T>(), Arc::new(component))
+    }
 
+    /// Register a component whose construction future runs on first use
+    ///
+    /// `init` is not called until the component is first
+    /// [`resolve`](Self::resolve)d. Concurrent first callers await the same
+    /// initialization, so `init` runs exactly once.
+    pub fn register_lazy<T, F, Fut>(
+        &self,
+        name: impl Into<String>,
+        init: F,
+    ) -> Result<(), RegistryError>
+    where
+        T: 'static + Send + Sync,
+        F: FnOnce() -> Fut + Send + 'static,
+        Fut: Future<Output = T> + Send + 'static,
+    {
+        let init = async move { Arc::new(init().await) }.boxed().shared();
+        self.insert(
+            name.into(),
+            TypeId::of::<T>(),
+            Arc::new(LazyComponent { init }),
+        )
+    }
+
+    fn insert(
+        &self,
+        name: String,
+        type_id: TypeId,
+        component: Arc<dyn Any + Send + Sync>,
+    ) -> Result<(), RegistryError> {
         {
             let mut components = self.components.write();
             if components.contains_key(&name) {
                 return Err(RegistryError::AlreadyExists(name));
             }
-            components.insert(name.clone(), Arc::new(component));
+            components.insert(name.clone(), component);
         }
 
         {
@@ -125,21 +188,58 @@ impl Registry {
     }
 
     /// Get a component by name and type
+    ///
+    /// A lazy component is returned only once it has been initialized;
+    /// until then this fails with [`RegistryError::NotInitialized`]. Use
+    /// [`resolve`](Self::resolve) to trigger initialization.
     pub fn get<T>(&self, name: &str) -> Result<Arc<T>, RegistryError>
     where
         T: 'static + Send + Sync,
     {
-        let component = {
-            let components = self.components.read();
-            components
-                .get(name)
-                .ok_or_else(|| RegistryError::NotFound(name.to_string()))?
-                .clone()
-        }; // Lock is dropped here
+        let component = self.lookup(name)?;
+        let component = match component.downcast::<T>() {
+            Ok(component) => return Ok(component),
+            Err(component) => component,
+        };
 
         component
-            .downcast::<T>()
-            .map_err(|_| RegistryError::TypeMismatch(name.to_string()))
+            .downcast::<LazyComponent<T>>()
+            .map_err(|_| RegistryError::TypeMismatch(name.to_string()))?
+            .init
+            .peek()
+            .cloned()
+            .ok_or_else(|| RegistryError::NotInitialized(name.to_string()))
+    }
+
+    /// Get a component by name and type, initializing it if it is lazy
+    ///
+    /// Eagerly registered components are returned immediately. For a
+    /// component registered with [`register_lazy`](Self::register_lazy), the
+    /// first call runs its construction future; concurrent callers wait for
+    /// that same run rather than starting their own.
+    pub async fn resolve<T>(&self, name: &str) -> Result<Arc<T>, RegistryError>
+    where
+        T: 'static + Send + Sync,
+    {
+        let component = self.lookup(name)?;
+        let component = match component.downcast::<T>() {
+            Ok(component) => return Ok(component),
+            Err(component) => component,
+        };
+
+        let lazy = component
+            .downcast::<LazyComponent<T>>()
+            .map_err(|_| RegistryError::TypeMismatch(name.to_string()))?;
+        // Await a clone so the registry lock is never held across the await.
+        Ok(lazy.init.clone().await)
+    }
+
+    fn lookup(&self, name: &str) -> Result<Arc<dyn Any + Send + Sync>, RegistryError> {
+        let components = self.components.read();
+        components
+            .get(name)
+            .cloned()
+            .ok_or_else(|| RegistryError::NotFound(name.to_string()))
     }
 
     /// Check if a component exists
@@ -190,6 +290,23 @@ impl RegistryBuilder {
         Ok(self)
     }
 
+    /// Register a component whose construction future runs on first use
+    ///
+    /// See [`Registry::register_lazy`].
+    pub fn register_lazy<T, F, Fut>(
+        self,
+        name: impl Into<String>,
+        init: F,
+    ) -> Result<Self, RegistryError>
+    where
+        T: 'static + Send + Sync,
+        F: FnOnce() -> Fut + Send + 'static,
+        Fut: Future<Output = T> + Send + 'static,
+    {
+        self.registry.register_lazy(name, init)?;
+        Ok(self)
+    }
+
     /// Build the final registry
     pub fn build(self) -> Registry {
         self.registry
@@ -382,4 +499,44 @@ mod tests {
 
         assert_eq!(names, vec!["alpha", "beta"]);
     }
+
+    #[tokio::test]
+    async fn test_lazy_component_initializes_once() {
+        let registry = Registry::new();
+        let inits = Arc::new(AtomicU32::new(0));
+        let counter = Arc::clone(&inits);
+        registry
+            .register_lazy("pool", move || async move {
+                counter.fetch_add(1, Ordering::SeqCst);
+                // Give every concurrent caller time to start waiting.
+                tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+                TestService::new(7)
+            })
+            .unwrap();
+
+        // Registering the component does not run its setup.
+        assert_eq!(inits.load(Ordering::SeqCst), 0);
+        assert!(matches!(
+            registry.get::<TestService>("pool"),
+            Err(RegistryError::NotInitialized(_))
+        ));
+
+        let resolved =
+            futures::future::join_all((0..8).map(|_| registry.resolve::<TestService>("pool")))
+                .await;
+        assert_eq!(inits.load(Ordering::SeqCst), 1);
+        let first = resolved[0].as_ref().unwrap();
+        assert_eq!(first.get_id(), 7);
+        for service in &resolved {
+            assert!(Arc::ptr_eq(first, service.as_ref().unwrap()));
+        }
+
+        // Once initialized, plain lookups see the same instance.
+        let service: Arc<TestService> = registry.get("pool").unwrap();
+        assert!(Arc::ptr_eq(first, &service));
+        assert!(matches!(
+            registry.resolve::<String>("pool").await,
+            Err(RegistryError::TypeMismatch(_))
+        ));
+    }
 }
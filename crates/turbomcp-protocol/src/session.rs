@@ -73,6 +73,7 @@ use dashmap::DashMap;
 use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
 use tokio::time::{Interval, interval};
+use turbomcp_core::clock::{Clock, SystemClock};
 
 use crate::context::{
     ClientIdExtractor, ClientSession, CompletionContext, ElicitationContext, RequestInfo,
@@ -152,6 +153,8 @@ pub struct SessionManager {
     pending_elicitations: Arc<DashMap<String, Vec<ElicitationContext>>>,
     /// Active completions by client ID
     active_completions: Arc<DashMap<String, Vec<CompletionContext>>>,
+    /// Time source for expiry decisions
+    clock: Arc<dyn Clock>,
 }
 
 /// Internal statistics tracking
@@ -196,6 +199,12 @@ impl SessionManager {
     /// Create a new session manager
     #[must_use]
     pub fn new(config: SessionConfig) -> Self {
+        Self::with_clock(config, SystemClock::shared())
+    }
+
+    /// Create a new session manager that reads time from the given clock
+    #[must_use]
+    pub fn with_clock(config: SessionConfig, clock: Arc<dyn Clock>) -> Self {
         Self {
             config,
             sessions: Arc::new(DashMap::new()),
@@ -206,6 +215,7 @@ impl SessionManager {
             stats: Arc::new(RwLock::new(SessionStats::default())),
             pending_elicitations: Arc::new(DashMap::new()),
             active_completions: Arc::new(DashMap::new()),
+            clock,
         }
     }
 
@@ -231,6 +241,7 @@ impl SessionManager {
         let stats = self.stats.clone();
         let pending_elicitations = self.pending_elicitations.clone();
         let active_completions = self.active_completions.clone();
+        let clock = self.clock.clone();
 
         tokio::spawn(async move {
             let mut timer = interval(config.cleanup_interval);
@@ -243,6 +254,7 @@ impl SessionManager {
                     &stats,
                     &pending_elicitations,
                     &active_completions,
+                    clock.as_ref(),
                 );
             }
         });
@@ -400,7 +412,7 @@ impl SessionManager {
             top_methods.truncate(10);
 
             // Calculate request rate (requests per minute over last hour)
-            let one_hour_ago = Utc::now() - Duration::hours(1);
+            let one_hour_ago = self.now() - Duration::hours(1);
             let recent_requests = history
                 .iter()
                 .filter(|req| req.timestamp > one_hour_ago)
@@ -599,6 +611,10 @@ impl SessionManager {
 
     // Private helper methods
 
+    fn now(&self) -> DateTime<Utc> {
+        self.clock.now().into()
+    }
+
     fn cleanup_expired_sessions(
         sessions: &Arc<DashMap<String, ClientSession>>,
         config: &SessionConfig,
@@ -606,8 +622,10 @@ impl SessionManager {
         stats: &Arc<RwLock<SessionStats>>,
         pending_elicitations: &Arc<DashMap<String, Vec<ElicitationContext>>>,
         active_completions: &Arc<DashMap<String, Vec<CompletionContext>>>,
+        clock: &dyn Clock,
     ) {
-        let cutoff_time = Utc::now() - config.session_timeout;
+        let now: DateTime<Utc> = clock.now().into();
+        let cutoff_time = now - config.session_timeout;
         let mut expired_sessions = Vec::new();
 
         for entry in sessions.iter() {
@@ -629,7 +647,7 @@ impl SessionManager {
 
                 // Record event
                 let event = SessionEvent {
-                    timestamp: Utc::now(),
+                    timestamp: now,
                     client_id,
                     event_type: SessionEventType::Expired,
                     metadata: HashMap::new(),
@@ -651,7 +669,7 @@ impl SessionManager {
         metadata: HashMap<String, serde_json::Value>,
     ) {
         let event = SessionEvent {
-            timestamp: Utc::now(),
+            timestamp: self.now(),
             client_id,
             event_type,
             metadata,
@@ -694,7 +712,7 @@ impl SessionManager {
 
                 // Record eviction as termination event
                 let event = SessionEvent {
-                    timestamp: Utc::now(),
+                    timestamp: self.now(),
                     client_id: client_id.clone(),
                     event_type: SessionEventType::Terminated,
                     metadata: {
@@ -817,6 +835,32 @@ mod tests {
         assert_eq!(analytics.active_sessions, 0);
     }
 
+    #[tokio::test]
+    async fn test_session_expires_when_mock_clock_advances() {
+        use turbomcp_core::clock::MockClock;
+
+        let clock = MockClock::new();
+        let config = SessionConfig {
+            session_timeout: Duration::minutes(5),
+            ..SessionConfig::default()
+        };
+        let manager = SessionManager::with_clock(config, Arc::new(clock.clone()));
+
+        let _ = manager.get_or_create_session("client-1".to_string(), "http".to_string());
+        clock.advance(StdDuration::from_secs(6 * 60));
+
+        SessionManager::cleanup_expired_sessions(
+            &manager.sessions,
+            &manager.config,
+            &manager.session_history,
+            &manager.stats,
+            &manager.pending_elicitations,
+            &manager.active_completions,
+            manager.clock.as_ref(),
+        );
+        assert!(manager.get_session("client-1").is_none());
+    }
+
     #[tokio::test]
     async fn test_parameter_sanitization() {
         let manager = SessionManager::new(SessionConfig::default());
@@ -7,7 +7,9 @@
 
 use serde::{Deserialize, Serialize};
 
-pub use turbomcp_types::{Resource, ResourceAnnotations, ResourceTemplate, validate_uri_template};
+pub use turbomcp_types::{
+    ByteRange, Resource, ResourceAnnotations, ResourceTemplate, validate_uri_template,
+};
 
 use super::{
     content::ResourceContent,
@@ -68,6 +70,10 @@ pub struct ListResourceTemplatesResult {
 pub struct ReadResourceRequest {
     /// Resource URI
     pub uri: Uri,
+    /// Optional byte range to read; handlers that don't support partial
+    /// reads return the full resource
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub range: Option<ByteRange>,
     /// Optional metadata per the current MCP specification
     #[serde(skip_serializing_if = "Option::is_none")]
     pub _meta: Option<serde_json::Value>,
@@ -83,6 +89,25 @@ pub struct ReadResourceResult {
     pub _meta: Option<serde_json::Value>,
 }
 
+impl ReadResourceResult {
+    /// ETag the server reported for this version of the resource
+    pub fn etag(&self) -> Option<&str> {
+        self._meta.as_ref()?.get("etag")?.as_str()
+    }
+
+    /// Whether the server answered a conditional read with "not modified"
+    ///
+    /// A not-modified result carries no contents; the caller's cached copy
+    /// is still current.
+    pub fn is_not_modified(&self) -> bool {
+        self._meta
+            .as_ref()
+            .and_then(|meta| meta.get("notModified"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false)
+    }
+}
+
 /// Subscribe request
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SubscribeRequest {
@@ -11,8 +11,8 @@
 use serde::{Deserialize, Serialize};
 
 pub use turbomcp_types::{
-    CreateMessageRequest, CreateMessageResult, IncludeContext, ModelHint, ModelPreferences,
-    SamplingMessage, ToolChoice, ToolChoiceMode,
+    CreateMessageRequest, CreateMessageRequestBuilder, CreateMessageResult, IncludeContext,
+    ModelHint, ModelPreferences, SamplingMessage, ToolChoice, ToolChoiceMode,
 };
 
 /// Known stop reasons per MCP 2025-11-25 spec.
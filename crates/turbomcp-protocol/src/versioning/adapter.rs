@@ -21,10 +21,22 @@
 //! let adapter = adapter_for_version(&ProtocolVersion::V2025_06_18);
 //! assert_eq!(adapter.version(), &ProtocolVersion::V2025_06_18);
 //! ```
+//!
+//! Content blocks built outside a routed response (for example, pushed in a
+//! notification) can be shaped for the session with [`serialize_content`]:
+//!
+//! ```rust
+//! use turbomcp_protocol::versioning::adapter::serialize_content;
+//! use turbomcp_types::{Content, ProtocolVersion};
+//!
+//! let content = Content::text("hello");
+//! let value = serialize_content(&content, &ProtocolVersion::V2025_06_18).unwrap();
+//! assert_eq!(value["type"], "text");
+//! ```
 
 use serde_json::Value;
 use std::collections::HashSet;
-use turbomcp_types::ProtocolVersion;
+use turbomcp_types::{Content, ProtocolVersion};
 
 use crate::types::capabilities::ServerCapabilities;
 
@@ -47,6 +59,16 @@ pub trait VersionAdapter: Send + Sync + std::fmt::Debug {
     /// The `method` parameter indicates which RPC method produced this result.
     fn filter_result(&self, method: &str, result: Value) -> Value;
 
+    /// Filter a single content block (`ContentBlock` JSON) for the target
+    /// version.
+    ///
+    /// [`filter_result`](Self::filter_result) applies this to the content of
+    /// `tools/call` and `prompts/get` results. The default leaves the block
+    /// unchanged.
+    fn filter_content(&self, content: &mut Value) {
+        let _ = content;
+    }
+
     /// Validate that an incoming method is supported in the target version.
     ///
     /// Returns `Ok(())` if the method exists in the target spec,
@@ -167,10 +189,22 @@ impl VersionAdapter for V2025_06_18Adapter {
                 strip_from_array(&mut result, "resourceTemplates", &["icons"]);
                 result
             }
+            "tools/call" | "prompts/get" => {
+                filter_result_content(self, &mut result);
+                result
+            }
             _ => result,
         }
     }
 
+    fn filter_content(&self, content: &mut Value) {
+        // Resource links share the `Resource` shape, which gained `icons`
+        // in 2025-11-25.
+        if content.get("type").and_then(Value::as_str) == Some("resource_link") {
+            strip_keys(content, &["icons"]);
+        }
+    }
+
     fn validate_method(&self, method: &str) -> Result<(), String> {
         if METHODS_2025_11_25_ONLY.contains(method) {
             Err(format!(
@@ -242,10 +276,53 @@ pub fn adapter_for_version(version: &ProtocolVersion) -> &'static dyn VersionAda
     }
 }
 
+/// Serialize a content block in the shape defined by `version`.
+///
+/// Fields the negotiated version does not define are omitted, exactly as
+/// they are from routed results.
+pub fn serialize_content(
+    content: &Content,
+    version: &ProtocolVersion,
+) -> Result<Value, serde_json::Error> {
+    let mut value = serde_json::to_value(content)?;
+    adapter_for_version(version).filter_content(&mut value);
+    Ok(value)
+}
+
+/// Deserialize a content block received from a peer speaking `version`.
+///
+/// Fields the negotiated version does not define are ignored rather than
+/// trusted, so the result matches what [`serialize_content`] would send.
+pub fn deserialize_content(
+    mut value: Value,
+    version: &ProtocolVersion,
+) -> Result<Content, serde_json::Error> {
+    adapter_for_version(version).filter_content(&mut value);
+    serde_json::from_value(value)
+}
+
 // =============================================================================
 // Helpers
 // =============================================================================
 
+/// Apply [`VersionAdapter::filter_content`] to the content blocks of a
+/// `tools/call` result (`content`) or `prompts/get` result
+/// (`messages[].content`).
+fn filter_result_content<A: VersionAdapter + ?Sized>(adapter: &A, result: &mut Value) {
+    if let Some(Value::Array(blocks)) = result.get_mut("content") {
+        for block in blocks.iter_mut() {
+            adapter.filter_content(block);
+        }
+    }
+    if let Some(Value::Array(messages)) = result.get_mut("messages") {
+        for message in messages.iter_mut() {
+            if let Some(block) = message.get_mut("content") {
+                adapter.filter_content(block);
+            }
+        }
+    }
+}
+
 /// Strip keys from a JSON object.
 fn strip_keys(value: &mut Value, keys: &[&str]) {
     if let Value::Object(map) = value {
@@ -564,6 +641,32 @@ mod tests {
         assert!(template.get("icons").is_none(), "icons should be stripped");
     }
 
+    #[test]
+    fn test_content_shape_follows_negotiated_version() {
+        use turbomcp_types::{Icon, ResourceLink};
+
+        let mut link = ResourceLink::new("file:///reports/q3.csv", "q3.csv");
+        link.icons = Some(vec![Icon::new("https://example.com/csv.png")]);
+        let content = Content::from(link);
+
+        let current = serialize_content(&content, &ProtocolVersion::V2025_11_25).unwrap();
+        let previous = serialize_content(&content, &ProtocolVersion::V2025_06_18).unwrap();
+        assert_eq!(current["icons"][0]["src"], "https://example.com/csv.png");
+        assert!(previous.get("icons").is_none(), "{previous}");
+        assert_eq!(previous["type"], "resource_link");
+        assert_eq!(previous["uri"], "file:///reports/q3.csv");
+
+        let parsed = deserialize_content(current.clone(), &ProtocolVersion::V2025_06_18).unwrap();
+        assert!(parsed.as_resource_link().unwrap().icons.is_none());
+        let parsed = deserialize_content(current.clone(), &ProtocolVersion::V2025_11_25).unwrap();
+        assert_eq!(parsed, content);
+
+        // Routed results are shaped the same way.
+        let result = json!({ "content": [current], "isError": false });
+        let filtered = V2025_06_18Adapter.filter_result("tools/call", result);
+        assert_eq!(filtered["content"][0], previous);
+    }
+
     #[test]
     fn test_draft_passthrough() {
         let adapter = DraftAdapter;
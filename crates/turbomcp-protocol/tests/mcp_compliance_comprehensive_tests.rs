@@ -177,6 +177,7 @@ mod mcp_compliance_tests {
         // Test ReadResourceRequest
         let read_request = ReadResourceRequest {
             uri: "file://test.txt".into(),
+            range: None,
             _meta: Some(json!({"read_meta": "test"})),
         };
         let serialized = serde_json::to_string(&read_request).unwrap();
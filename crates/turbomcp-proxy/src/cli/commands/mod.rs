@@ -22,7 +22,7 @@ pub enum Command {
 
     /// Serve a proxy server to bridge MCP transports
     #[command(visible_alias = "s")]
-    Serve(serve::ServeCommand),
+    Serve(Box<serve::ServeCommand>),
 
     /// Generate optimized Rust proxy code
     #[command(visible_alias = "g")]
@@ -2,10 +2,12 @@
 //!
 //! Runs the proxy server to bridge MCP servers across transports.
 
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 use std::sync::Arc;
+use std::time::Duration;
 
 use axum::{
-    extract::{Request, State},
+    extract::{ConnectInfo, Request, State},
     http::{HeaderMap, HeaderName, StatusCode, header},
     middleware::{self, Next},
     response::{IntoResponse, Response},
@@ -16,6 +18,7 @@ use secrecy::{ExposeSecret, SecretString};
 use tracing::{info, warn};
 use turbomcp_auth::jwt::{JwtValidator, StandardClaims};
 use turbomcp_server::{McpServerExt, ServerConfig};
+use turbomcp_transport::security::{RateLimitConfig, RateLimiter};
 
 use crate::cli::args::BackendArgs;
 use crate::error::{ProxyError, ProxyResult};
@@ -148,6 +151,20 @@ pub struct ServeCommand {
     /// installed in addition to the strict request-time check.
     #[arg(long = "allowed-origin", value_name = "ORIGIN")]
     pub allowed_origins: Vec<String>,
+
+    // ═══════════════════════════════════════════════════
+    // RATE LIMITING (Frontend HTTP Server Protection)
+    // ═══════════════════════════════════════════════════
+    /// Maximum requests per client IP per rate-limit window
+    ///
+    /// When set, requests over the limit are rejected with 429 at the
+    /// frontend and never reach the backend. Disabled by default.
+    #[arg(long, value_name = "N")]
+    pub rate_limit: Option<usize>,
+
+    /// Rate-limit window in seconds (used with --rate-limit)
+    #[arg(long, value_name = "SECS", default_value_t = 60)]
+    pub rate_limit_window_secs: u64,
 }
 
 #[derive(Clone, Debug)]
@@ -250,6 +267,60 @@ impl FrontendJwtAuth {
     }
 }
 
+async fn frontend_rate_limit_middleware(
+    State(limiter): State<Arc<RateLimiter>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    // Requests without connection info (e.g. in-process routers) share a
+    // single bucket rather than bypassing the limit.
+    let client_ip = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map_or(IpAddr::V4(Ipv4Addr::UNSPECIFIED), |info| info.0.ip());
+    match limiter.check_rate_limit(client_ip) {
+        Ok(()) => next.run(request).await,
+        Err(err) => {
+            warn!(client_ip = %client_ip, error = %err, "Rejecting rate-limited frontend request");
+            (
+                StatusCode::TOO_MANY_REQUESTS,
+                [
+                    (header::CONTENT_TYPE, "application/json".to_string()),
+                    (
+                        header::RETRY_AFTER,
+                        limiter.config().window.as_secs().max(1).to_string(),
+                    ),
+                ],
+                r#"{"error":"rate_limited"}"#,
+            )
+                .into_response()
+        }
+    }
+}
+
+/// Wrap a frontend router with the edge guards: authentication, then rate
+/// limiting outermost so over-limit clients are turned away before any
+/// credential validation or backend work happens.
+fn apply_edge_guards(
+    mut app: axum::Router,
+    auth: Option<FrontendAuth>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+) -> axum::Router {
+    if let Some(auth) = auth {
+        app = app.layer(middleware::from_fn_with_state(
+            auth,
+            frontend_auth_middleware,
+        ));
+    }
+    if let Some(limiter) = rate_limiter {
+        app = app.layer(middleware::from_fn_with_state(
+            limiter,
+            frontend_rate_limit_middleware,
+        ));
+    }
+    app
+}
+
 fn bearer_token(headers: &HeaderMap) -> Option<&str> {
     let value = headers.get(header::AUTHORIZATION)?.to_str().ok()?;
     let (scheme, token) = value.split_once(' ')?;
@@ -434,6 +505,26 @@ impl ServeCommand {
         }))
     }
 
+    fn build_rate_limiter(&self) -> ProxyResult<Option<Arc<RateLimiter>>> {
+        let Some(max_requests) = self.rate_limit else {
+            return Ok(None);
+        };
+        if max_requests == 0 || self.rate_limit_window_secs == 0 {
+            return Err(ProxyError::configuration(
+                "--rate-limit and --rate-limit-window-secs must be greater than zero",
+            ));
+        }
+
+        info!(
+            "Enabling frontend rate limiting ({} requests per {}s per client)",
+            max_requests, self.rate_limit_window_secs
+        );
+        let mut config = RateLimitConfig::new();
+        config.set_max_requests(max_requests);
+        config.set_window(Duration::from_secs(self.rate_limit_window_secs));
+        Ok(Some(Arc::new(RateLimiter::new(config))))
+    }
+
     /// Execute with HTTP frontend
     ///
     /// Exposes a backend MCP server over HTTP/SSE for web clients.
@@ -501,12 +592,7 @@ impl ServeCommand {
             allowlist.clone(),
             crate::runtime::origin_guard,
         ));
-        if let Some(auth) = frontend_auth {
-            app = app.layer(middleware::from_fn_with_state(
-                auth,
-                frontend_auth_middleware,
-            ));
-        }
+        app = apply_edge_guards(app, frontend_auth, self.build_rate_limiter()?);
         if let Some(cors) = crate::runtime::build_cors_layer(&allowlist) {
             app = app.layer(cors);
         }
@@ -533,7 +619,7 @@ impl ServeCommand {
 
         axum::serve(
             listener,
-            app.into_make_service_with_connect_info::<std::net::SocketAddr>(),
+            app.into_make_service_with_connect_info::<SocketAddr>(),
         )
         .await
         .map_err(|e| ProxyError::backend(format!("HTTP server error: {e}")))?;
@@ -703,6 +789,8 @@ mod tests {
             api_key: None,
             require_auth: false,
             allowed_origins: Vec::new(),
+            rate_limit: None,
+            rate_limit_window_secs: 60,
         }
     }
 
@@ -736,6 +824,8 @@ mod tests {
             api_key: None,
             require_auth: false,
             allowed_origins: Vec::new(),
+            rate_limit: None,
+            rate_limit_window_secs: 60,
         };
 
         let config = cmd.create_backend_config();
@@ -776,6 +866,8 @@ mod tests {
             api_key: None,
             require_auth: false,
             allowed_origins: Vec::new(),
+            rate_limit: None,
+            rate_limit_window_secs: 60,
         };
 
         let config = cmd.create_backend_config();
@@ -822,6 +914,80 @@ mod tests {
         assert!(normalize_endpoint_path("/api/mcp?debug=true").is_err());
     }
 
+    #[test]
+    fn rate_limit_rejects_zero_window() {
+        let mut cmd = base_command();
+        cmd.rate_limit = Some(10);
+        cmd.rate_limit_window_secs = 0;
+
+        assert!(cmd.build_rate_limiter().is_err());
+    }
+
+    fn guarded_router(
+        hits: Arc<std::sync::atomic::AtomicUsize>,
+        auth: Option<FrontendAuth>,
+        rate_limiter: Option<Arc<RateLimiter>>,
+    ) -> axum::Router {
+        let backend = axum::Router::new().route(
+            "/mcp",
+            axum::routing::post(move || async move {
+                hits.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                "forwarded"
+            }),
+        );
+        apply_edge_guards(backend, auth, rate_limiter)
+    }
+
+    fn mcp_request(api_key: Option<&str>) -> Request {
+        let mut builder = axum::http::Request::post("/mcp");
+        if let Some(key) = api_key {
+            builder = builder.header("x-api-key", key);
+        }
+        builder.body(axum::body::Body::empty()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn unauthenticated_request_rejected_before_backend() {
+        use tower::ServiceExt;
+
+        let mut cmd = base_command();
+        cmd.require_auth = true;
+        cmd.api_key = Some("test_key_abcdefghijklmnopqrstuvwxyz123456".to_string());
+        let hits = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let app = guarded_router(hits.clone(), cmd.build_frontend_auth().unwrap(), None);
+
+        let response = app.clone().oneshot(mcp_request(None)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 0);
+
+        let response = app
+            .oneshot(mcp_request(Some(
+                "test_key_abcdefghijklmnopqrstuvwxyz123456",
+            )))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn over_limit_request_rejected_before_backend() {
+        use tower::ServiceExt;
+
+        let mut cmd = base_command();
+        cmd.rate_limit = Some(1);
+        let hits = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let app = guarded_router(hits.clone(), None, cmd.build_rate_limiter().unwrap());
+
+        let response = app.clone().oneshot(mcp_request(None)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = app.oneshot(mcp_request(None)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert!(response.headers().contains_key(header::RETRY_AFTER));
+        assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
     #[cfg(unix)]
     #[test]
     fn test_unix_backend_config() {
@@ -852,6 +1018,8 @@ mod tests {
             api_key: None,
             require_auth: false,
             allowed_origins: Vec::new(),
+            rate_limit: None,
+            rate_limit_window_secs: 60,
         };
 
         let config = cmd.create_backend_config();
@@ -103,7 +103,8 @@ pub mod prelude {
 
     // Proxy components
     pub use crate::proxy::{
-        AtomicMetrics, BackendConnector, BackendTransport, IdTranslator, ProxyMetrics, ProxyService,
+        AtomicMetrics, BackendConnector, BackendTransport, ConnectionId, IdTranslator,
+        ProxyMetrics, ProxyService,
     };
 
     // Frontend transports
@@ -4,6 +4,10 @@
 //! This is critical for request/response correlation when multiple frontend
 //! clients send requests through the proxy to a single backend server.
 //!
+//! Frontend IDs are namespaced by [`ConnectionId`]: two clients that both send
+//! id `1` get distinct backend IDs, and each response is routed back to the
+//! connection that issued the request.
+//!
 //! # Security Features
 //!
 //! - Bounded memory: Maximum 10,000 mappings to prevent unbounded growth
@@ -25,6 +29,34 @@ const MAX_MAPPINGS: usize = 10_000;
 /// Mapping timeout - entries older than this are evicted (prevents memory leaks)
 const MAPPING_TIMEOUT: Duration = Duration::from_secs(300); // 5 minutes
 
+/// Identifies one frontend connection sharing the backend
+///
+/// Obtained from [`IdTranslator::register_connection`]. Frontend message IDs
+/// are only unique within a connection, so every mapping is keyed by the
+/// connection as well as the ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConnectionId(u64);
+
+impl ConnectionId {
+    /// Connection used by the single-client [`IdTranslator::allocate`] API
+    pub const DEFAULT: Self = Self(0);
+
+    /// Raw connection number (for logging)
+    #[must_use]
+    pub const fn as_u64(self) -> u64 {
+        self.0
+    }
+}
+
+impl std::fmt::Display for ConnectionId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "conn-{}", self.0)
+    }
+}
+
+/// Frontend message ID scoped to the connection that sent it
+type FrontendKey = (ConnectionId, MessageId);
+
 /// Mapping entry with creation timestamp for timeout-based eviction
 #[derive(Debug, Clone)]
 struct MappingEntry {
@@ -40,6 +72,8 @@ struct MappingEntry {
 /// - Frontend clients use their own ID schemes (strings, numbers)
 /// - Backend server expects sequential or specific IDs
 /// - Translator maintains bidirectional mapping for correlation
+/// - Frontend IDs are namespaced per [`ConnectionId`], so concurrent clients
+///   reusing the same ID never collide on the backend
 ///
 /// # Security
 ///
@@ -49,15 +83,18 @@ struct MappingEntry {
 /// - Race-free: Atomic operations prevent TOCTOU bugs
 #[derive(Debug, Clone)]
 pub struct IdTranslator {
-    /// Frontend ID → `MappingEntry` (contains backend ID + timestamp)
-    frontend_to_backend: Arc<DashMap<MessageId, MappingEntry>>,
+    /// (Connection, frontend ID) → `MappingEntry` (contains backend ID + timestamp)
+    frontend_to_backend: Arc<DashMap<FrontendKey, MappingEntry>>,
 
-    /// Backend ID → Frontend ID mapping (for reverse lookup)
-    backend_to_frontend: Arc<DashMap<MessageId, MessageId>>,
+    /// Backend ID → (connection, frontend ID) mapping (for reverse lookup)
+    backend_to_frontend: Arc<DashMap<MessageId, FrontendKey>>,
 
     /// Counter for generating sequential backend IDs
     next_backend_id: Arc<AtomicU64>,
 
+    /// Counter for generating connection IDs (0 is reserved for `ConnectionId::DEFAULT`)
+    next_connection_id: Arc<AtomicU64>,
+
     /// Maximum number of concurrent mappings
     max_mappings: usize,
 
@@ -84,15 +121,40 @@ impl IdTranslator {
             frontend_to_backend: Arc::new(DashMap::new()),
             backend_to_frontend: Arc::new(DashMap::new()),
             next_backend_id: Arc::new(AtomicU64::new(1)),
+            next_connection_id: Arc::new(AtomicU64::new(1)),
             max_mappings,
             mapping_timeout,
         }
     }
 
+    /// Register a new frontend connection
+    ///
+    /// Each frontend connection should register once and pass the returned
+    /// [`ConnectionId`] to [`allocate_for`](Self::allocate_for). Call
+    /// [`release_connection`](Self::release_connection) when it disconnects.
+    #[must_use]
+    pub fn register_connection(&self) -> ConnectionId {
+        ConnectionId(self.next_connection_id.fetch_add(1, Ordering::Relaxed))
+    }
+
+    /// Allocate a backend ID for a frontend request on the default connection
+    ///
+    /// Equivalent to `allocate_for(ConnectionId::DEFAULT, frontend_id)`. Use
+    /// [`allocate_for`](Self::allocate_for) when several frontends share the
+    /// translator.
+    ///
+    /// # Errors
+    ///
+    /// See [`allocate_for`](Self::allocate_for).
+    pub fn allocate(&self, frontend_id: MessageId) -> ProxyResult<MessageId> {
+        self.allocate_for(ConnectionId::DEFAULT, frontend_id)
+    }
+
     /// Allocate a backend ID for a frontend request
     ///
     /// # Arguments
     ///
+    /// * `connection` - The frontend connection that sent the request
     /// * `frontend_id` - The frontend message ID
     ///
     /// # Returns
@@ -104,7 +166,11 @@ impl IdTranslator {
     /// Returns `ProxyError::RateLimitExceeded` if:
     /// - Too many concurrent mappings (server overloaded)
     /// - Cannot evict expired mappings to make room
-    pub fn allocate(&self, frontend_id: MessageId) -> ProxyResult<MessageId> {
+    pub fn allocate_for(
+        &self,
+        connection: ConnectionId,
+        frontend_id: MessageId,
+    ) -> ProxyResult<MessageId> {
         // Evict expired entries first to make room
         self.evict_expired();
 
@@ -130,9 +196,9 @@ impl IdTranslator {
         };
 
         // Store bidirectional mapping
-        self.frontend_to_backend.insert(frontend_id.clone(), entry);
-        self.backend_to_frontend
-            .insert(backend_id.clone(), frontend_id);
+        let key = (connection, frontend_id);
+        self.frontend_to_backend.insert(key.clone(), entry);
+        self.backend_to_frontend.insert(backend_id.clone(), key);
 
         Ok(backend_id)
     }
@@ -150,7 +216,32 @@ impl IdTranslator {
     pub fn get_frontend_id(&self, backend_id: &MessageId) -> Option<MessageId> {
         self.backend_to_frontend
             .get(backend_id)
-            .map(|entry| entry.value().clone())
+            .map(|entry| entry.value().1.clone())
+    }
+
+    /// Route a backend response back to its frontend
+    ///
+    /// Returns the connection and frontend ID the response belongs to and
+    /// removes the mapping, since a request receives exactly one response.
+    ///
+    /// # Arguments
+    ///
+    /// * `backend_id` - The backend message ID from the response
+    #[must_use]
+    pub fn route_response(&self, backend_id: &MessageId) -> Option<(ConnectionId, MessageId)> {
+        let (_, key) = self.backend_to_frontend.remove(backend_id)?;
+        self.frontend_to_backend
+            .remove_if(&key, |_k, v| &v.backend_id == backend_id);
+        Some(key)
+    }
+
+    /// Release a mapping on the default connection after response is sent
+    ///
+    /// # Arguments
+    ///
+    /// * `frontend_id` - The frontend message ID to release
+    pub fn release(&self, frontend_id: &MessageId) {
+        self.release_for(ConnectionId::DEFAULT, frontend_id);
     }
 
     /// Release a mapping after response is sent
@@ -160,17 +251,30 @@ impl IdTranslator {
     ///
     /// # Arguments
     ///
+    /// * `connection` - The frontend connection that sent the request
     /// * `frontend_id` - The frontend message ID to release
-    pub fn release(&self, frontend_id: &MessageId) {
+    pub fn release_for(&self, connection: ConnectionId, frontend_id: &MessageId) {
+        let key = (connection, frontend_id.clone());
         // Atomically remove frontend mapping and get backend ID
-        if let Some((_, entry)) = self.frontend_to_backend.remove(frontend_id) {
-            // Atomically remove backend mapping, but only if it still points to this frontend_id
+        if let Some((_, entry)) = self.frontend_to_backend.remove(&key) {
+            // Atomically remove backend mapping, but only if it still points to this frontend key
             // This prevents race conditions where the mapping might have changed
             self.backend_to_frontend
-                .remove_if(&entry.backend_id, |_k, v| v == frontend_id);
+                .remove_if(&entry.backend_id, |_k, v| *v == key);
         }
     }
 
+    /// Release every mapping owned by a frontend connection
+    ///
+    /// Call this when a frontend disconnects so its in-flight requests do not
+    /// linger until the mapping timeout.
+    pub fn release_connection(&self, connection: ConnectionId) {
+        self.frontend_to_backend
+            .retain(|(conn, _), _| *conn != connection);
+        self.backend_to_frontend
+            .retain(|_, (conn, _)| *conn != connection);
+    }
+
     /// Evict expired mappings based on timeout
     ///
     /// This is called automatically by `allocate()` to prevent unbounded growth.
@@ -184,7 +288,7 @@ impl IdTranslator {
 
         // Clean up orphaned backend mappings (where frontend mapping no longer exists)
         self.backend_to_frontend
-            .retain(|_backend_id, frontend_id| self.frontend_to_backend.contains_key(frontend_id));
+            .retain(|_backend_id, key| self.frontend_to_backend.contains_key(key));
     }
 
     /// Spawn a background task to periodically evict expired mappings
@@ -372,6 +476,79 @@ mod tests {
         assert_eq!(translator.mapping_count(), 0);
     }
 
+    #[tokio::test]
+    async fn test_concurrent_frontends_with_same_id_route_back_correctly() {
+        use tokio::sync::mpsc;
+
+        let translator = Arc::new(IdTranslator::new());
+        let (backend_tx, mut backend_rx) = mpsc::unbounded_channel::<(MessageId, String)>();
+
+        // Backend: answers each request, in reverse arrival order
+        let backend = tokio::spawn(async move {
+            let mut pending = Vec::new();
+            while let Some(request) = backend_rx.recv().await {
+                pending.push(request);
+                if pending.len() == 2 {
+                    break;
+                }
+            }
+            pending.reverse();
+            pending
+        });
+
+        let frontends: Vec<_> = ["alice", "bob"]
+            .into_iter()
+            .map(|name| {
+                let translator = Arc::clone(&translator);
+                let backend_tx = backend_tx.clone();
+                tokio::spawn(async move {
+                    let connection = translator.register_connection();
+                    let backend_id = translator
+                        .allocate_for(connection, MessageId::Number(1))
+                        .unwrap();
+                    backend_tx.send((backend_id, name.to_string())).unwrap();
+                    (connection, name)
+                })
+            })
+            .collect();
+
+        let mut connections = std::collections::HashMap::new();
+        for frontend in frontends {
+            let (connection, name) = frontend.await.unwrap();
+            connections.insert(connection, name);
+        }
+        assert_eq!(connections.len(), 2, "each frontend gets its own namespace");
+        assert_eq!(translator.mapping_count(), 2, "id 1 must not collide");
+
+        for (backend_id, payload) in backend.await.unwrap() {
+            let (connection, frontend_id) = translator.route_response(&backend_id).unwrap();
+            assert_eq!(frontend_id, MessageId::Number(1));
+            assert_eq!(connections[&connection], payload);
+        }
+
+        // Responses clean up their mappings
+        assert_eq!(translator.mapping_count(), 0);
+    }
+
+    #[test]
+    fn test_release_connection_drops_only_its_mappings() {
+        let translator = IdTranslator::new();
+        let a = translator.register_connection();
+        let b = translator.register_connection();
+
+        translator.allocate_for(a, MessageId::Number(1)).unwrap();
+        translator.allocate_for(a, MessageId::Number(2)).unwrap();
+        let kept = translator.allocate_for(b, MessageId::Number(1)).unwrap();
+
+        translator.release_connection(a);
+
+        assert_eq!(translator.mapping_count(), 1);
+        assert_eq!(
+            translator.route_response(&kept),
+            Some((b, MessageId::Number(1)))
+        );
+    }
+
     #[test]
     fn test_eviction_after_limit_makes_room() {
         // Create translator with short timeout and small limit
@@ -35,6 +35,6 @@ pub use auth::{JwtSigner, ProxyAuthConfig};
 pub use backend::{BackendConfig, BackendConnector, BackendTransport};
 pub use backends::HttpBackend;
 pub use frontends::StdioFrontend;
-pub use id_translator::IdTranslator;
+pub use id_translator::{ConnectionId, IdTranslator};
 pub use metrics::{AtomicMetrics, ProxyMetrics};
 pub use service::ProxyService;
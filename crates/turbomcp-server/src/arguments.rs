@@ -0,0 +1,290 @@
+//! Pluggable tool argument deserialization.
+//!
+//! MCP clients are expected to send `tools/call` arguments as a JSON object,
+//! but some send other shapes: positional arrays, or form-encoded strings.
+//! An [`ArgumentDeserializer`] maps the raw `arguments` value into the object
+//! shape a tool's input schema expects before the tool deserializes and
+//! validates it. [`PositionalArguments`] is the built-in adapter for arrays,
+//! keyed by the order of the tool's schema properties.
+//!
+//! Register deserializers with
+//! [`ServerBuilder::with_argument_deserializer`](crate::ServerBuilder::with_argument_deserializer).
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use serde_json::{Map, Value};
+use turbomcp_core::context::RequestContext;
+use turbomcp_core::error::{McpError, McpResult};
+use turbomcp_types::{Tool, ToolResult};
+
+use super::middleware::{McpMiddleware, Next};
+
+/// Maps raw `tools/call` arguments into the shape a tool expects.
+///
+/// Deserializers should return arguments they do not recognize unchanged so
+/// that several can be chained. Returning an error rejects the call.
+///
+/// Closures of type `Fn(&Tool, Value) -> McpResult<Value>` implement this
+/// trait.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// // Accept `"a=1&b=2"` style arguments.
+/// MyServer.builder()
+///     .with_argument_deserializer(|_tool: &Tool, args: Value| match args {
+///         Value::String(form) => Ok(parse_form(&form)),
+///         other => Ok(other),
+///     })
+///     .serve()
+///     .await?;
+/// ```
+pub trait ArgumentDeserializer: Send + Sync + 'static {
+    /// Convert `arguments` for a call to `tool`.
+    fn deserialize(&self, tool: &Tool, arguments: Value) -> McpResult<Value>;
+}
+
+impl<F> ArgumentDeserializer for F
+where
+    F: Fn(&Tool, Value) -> McpResult<Value> + Send + Sync + 'static,
+{
+    fn deserialize(&self, tool: &Tool, arguments: Value) -> McpResult<Value> {
+        self(tool, arguments)
+    }
+}
+
+/// Maps a JSON array of arguments onto the tool's parameters by position.
+///
+/// The n-th element becomes the value of the n-th property declared in the
+/// tool's input schema. Trailing parameters may be omitted; extra elements
+/// are rejected. Non-array arguments pass through unchanged.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PositionalArguments;
+
+impl ArgumentDeserializer for PositionalArguments {
+    fn deserialize(&self, tool: &Tool, arguments: Value) -> McpResult<Value> {
+        let Value::Array(values) = arguments else {
+            return Ok(arguments);
+        };
+        let names: Vec<&String> = tool
+            .input_schema
+            .properties
+            .as_ref()
+            .and_then(Value::as_object)
+            .map(|properties| properties.keys().collect())
+            .unwrap_or_default();
+        if values.len() > names.len() {
+            return Err(McpError::invalid_params(format!(
+                "Tool '{}' takes {} positional arguments but {} were given",
+                tool.name,
+                names.len(),
+                values.len()
+            )));
+        }
+        let object: Map<String, Value> = names.into_iter().cloned().zip(values).collect();
+        Ok(Value::Object(object))
+    }
+}
+
+/// Ordered set of [`ArgumentDeserializer`]s, applied first to last.
+#[derive(Clone, Default)]
+pub struct ArgumentDeserializers {
+    deserializers: Vec<Arc<dyn ArgumentDeserializer>>,
+}
+
+impl ArgumentDeserializers {
+    /// Append a deserializer; it runs after the ones already registered.
+    pub fn push(&mut self, deserializer: impl ArgumentDeserializer) {
+        self.deserializers.push(Arc::new(deserializer));
+    }
+
+    /// Whether no deserializers are registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.deserializers.is_empty()
+    }
+
+    /// Number of registered deserializers.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.deserializers.len()
+    }
+
+    /// Run every deserializer against `arguments` for a call to `tool`.
+    pub fn apply(&self, tool: &Tool, arguments: Value) -> McpResult<Value> {
+        self.deserializers
+            .iter()
+            .try_fold(arguments, |arguments, deserializer| {
+                deserializer.deserialize(tool, arguments)
+            })
+    }
+}
+
+impl fmt::Debug for ArgumentDeserializers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArgumentDeserializers")
+            .field("len", &self.deserializers.len())
+            .finish()
+    }
+}
+
+/// Runs the deserializers on `tools/call` arguments before the next
+/// middleware or the handler sees them.
+///
+/// Calls to tools the handler does not list are passed through unchanged so
+/// the handler reports them as unknown.
+impl McpMiddleware for ArgumentDeserializers {
+    fn on_call_tool<'a>(
+        &'a self,
+        name: &'a str,
+        args: Value,
+        ctx: &'a RequestContext,
+        next: Next<'a>,
+    ) -> Pin<Box<dyn Future<Output = McpResult<ToolResult>> + Send + 'a>> {
+        Box::pin(async move {
+            let args = match next.list_tools().iter().find(|tool| tool.name == name) {
+                Some(tool) => self.apply(tool, args)?,
+                None => args,
+            };
+            next.call_tool(name, args, ctx).await
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::McpServerExt;
+    use crate::router::{JsonRpcIncoming, route_request};
+    use serde_json::json;
+    use turbomcp_core::handler::McpHandler;
+    use turbomcp_core::marker::MaybeSend;
+    use turbomcp_types::{
+        Prompt, PromptResult, Resource, ResourceResult, ServerInfo, ToolInputSchema,
+    };
+
+    #[derive(Clone)]
+    struct AddHandler;
+
+    #[allow(clippy::manual_async_fn)]
+    impl McpHandler for AddHandler {
+        fn server_info(&self) -> ServerInfo {
+            ServerInfo::new("arguments-test", "1.0.0")
+        }
+
+        fn list_tools(&self) -> Vec<Tool> {
+            let mut add = Tool::new("add", "Add two numbers");
+            add.input_schema = ToolInputSchema {
+                properties: Some(json!({
+                    "a": {"type": "number"},
+                    "b": {"type": "number"}
+                })),
+                required: Some(vec!["a".to_string(), "b".to_string()]),
+                ..ToolInputSchema::default()
+            };
+            vec![add]
+        }
+
+        fn list_resources(&self) -> Vec<Resource> {
+            vec![]
+        }
+
+        fn list_prompts(&self) -> Vec<Prompt> {
+            vec![]
+        }
+
+        fn call_tool<'a>(
+            &'a self,
+            _name: &'a str,
+            args: Value,
+            _ctx: &'a RequestContext,
+        ) -> impl std::future::Future<Output = McpResult<ToolResult>> + MaybeSend + 'a {
+            async move {
+                let (Some(a), Some(b)) = (args["a"].as_f64(), args["b"].as_f64()) else {
+                    return Err(McpError::invalid_params(format!(
+                        "expected an object with a and b, got {args}"
+                    )));
+                };
+                Ok(ToolResult::text((a + b).to_string()))
+            }
+        }
+
+        fn read_resource<'a>(
+            &'a self,
+            uri: &'a str,
+            _ctx: &'a RequestContext,
+        ) -> impl std::future::Future<Output = McpResult<ResourceResult>> + MaybeSend + 'a {
+            let uri = uri.to_string();
+            async move { Err(McpError::resource_not_found(&uri)) }
+        }
+
+        fn get_prompt<'a>(
+            &'a self,
+            name: &'a str,
+            _args: Option<Value>,
+            _ctx: &'a RequestContext,
+        ) -> impl std::future::Future<Output = McpResult<PromptResult>> + MaybeSend + 'a {
+            let name = name.to_string();
+            async move { Err(McpError::prompt_not_found(&name)) }
+        }
+    }
+
+    fn call_add(arguments: Value) -> JsonRpcIncoming {
+        JsonRpcIncoming {
+            jsonrpc: "2.0".to_string(),
+            id: Some(json!(1)),
+            method: "tools/call".to_string(),
+            params: Some(json!({"name": "add", "arguments": arguments})),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_positional_array_calls_two_argument_tool() {
+        let handler = AddHandler
+            .builder()
+            .with_argument_deserializer(PositionalArguments)
+            .served_handler();
+        let ctx = RequestContext::stdio();
+
+        let response = route_request(&handler, call_add(json!([2, 3])), &ctx).await;
+        let result = response.result.expect("positional call succeeds");
+        assert_eq!(result["content"][0]["text"], "5");
+
+        // Object arguments are untouched.
+        let response = route_request(&handler, call_add(json!({"a": 1, "b": 1})), &ctx).await;
+        assert_eq!(response.result.unwrap()["content"][0]["text"], "2");
+    }
+
+    #[tokio::test]
+    async fn test_positional_rejects_extra_arguments() {
+        let handler = AddHandler
+            .builder()
+            .with_argument_deserializer(PositionalArguments)
+            .served_handler();
+        let ctx = RequestContext::stdio();
+
+        let response = route_request(&handler, call_add(json!([1, 2, 3])), &ctx).await;
+        let error = response.error.expect("too many arguments");
+        assert!(error.message.contains("takes 2 positional arguments"));
+    }
+
+    #[tokio::test]
+    async fn test_without_deserializers_arrays_reach_the_tool() {
+        let handler = AddHandler.builder().served_handler();
+        let response =
+            route_request(&handler, call_add(json!([2, 3])), &RequestContext::stdio()).await;
+        assert!(response.error.is_some());
+    }
+
+    #[test]
+    fn test_builder_registers_deserializers() {
+        let builder = AddHandler
+            .builder()
+            .with_argument_deserializer(PositionalArguments)
+            .with_argument_deserializer(|_tool: &Tool, args: Value| Ok(args));
+        assert_eq!(builder.argument_deserializers().len(), 2);
+    }
+}
@@ -0,0 +1,232 @@
+//! Server-to-client notification broadcast.
+//!
+//! Transports register each connection with the configured
+//! [`NotificationBroadcaster`] once its `initialize` handshake succeeds, and
+//! unregister it when the connection closes. Application code keeps a clone
+//! of the broadcaster and pushes application-defined notifications to every
+//! connected client, or to the clients a filter selects:
+//!
+//! ```rust,ignore
+//! let broadcaster = NotificationBroadcaster::new();
+//! let config = ServerConfig::builder()
+//!     .notification_broadcaster(broadcaster.clone())
+//!     .build();
+//!
+//! // Later, from anywhere in the application:
+//! broadcaster
+//!     .broadcast("notifications/job/completed", json!({"job": 42}))
+//!     .await;
+//! ```
+//!
+//! Only transports with a server-to-client channel take part: STDIO, TCP,
+//! Unix sockets, WebSocket and HTTP sessions with an SSE stream.
+
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Weak};
+
+use dashmap::DashMap;
+use parking_lot::Mutex;
+use serde_json::Value;
+use turbomcp_core::session::McpSession;
+
+use crate::initialize::InitializeClient;
+use crate::list_cache::ListCacheInvalidator;
+
+/// Sends notifications to the initialized sessions of a server.
+///
+/// Cloning is cheap; every clone shares the same set of sessions.
+#[derive(Clone, Default)]
+pub struct NotificationBroadcaster {
+    inner: Arc<BroadcasterInner>,
+}
+
+#[derive(Default)]
+struct BroadcasterInner {
+    sessions: DashMap<u64, RegisteredSession>,
+    next_id: AtomicU64,
+    /// List caches cleared by broadcast `list_changed` notifications.
+    list_caches: Mutex<Vec<ListCacheInvalidator>>,
+}
+
+struct RegisteredSession {
+    session: Arc<dyn McpSession>,
+    client: InitializeClient,
+}
+
+impl NotificationBroadcaster {
+    /// Create a broadcaster with no registered sessions.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register an initialized session.
+    ///
+    /// The session receives broadcasts until the returned registration is
+    /// dropped.
+    #[must_use = "the session is unregistered when the registration is dropped"]
+    pub fn register(
+        &self,
+        session: Arc<dyn McpSession>,
+        client: InitializeClient,
+    ) -> BroadcastRegistration {
+        let id = self.inner.next_id.fetch_add(1, Ordering::Relaxed);
+        self.inner
+            .sessions
+            .insert(id, RegisteredSession { session, client });
+        BroadcastRegistration {
+            id,
+            inner: Arc::downgrade(&self.inner),
+        }
+    }
+
+    /// Number of registered sessions.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.inner.sessions.len()
+    }
+
+    /// Whether no sessions are registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.inner.sessions.is_empty()
+    }
+
+    /// Clear `cache` whenever a `list_changed` notification is broadcast.
+    pub(crate) fn invalidate_on_list_changed(&self, cache: ListCacheInvalidator) {
+        self.inner.list_caches.lock().push(cache);
+    }
+
+    /// Send a notification to every registered session.
+    ///
+    /// Returns the number of sessions the notification was delivered to.
+    /// Sessions whose connection is closing are skipped.
+    pub async fn broadcast(&self, method: &str, params: Value) -> usize {
+        self.broadcast_filtered(method, params, |_| true).await
+    }
+
+    /// Send a notification to the registered sessions whose client matches
+    /// `filter`.
+    ///
+    /// Returns the number of sessions the notification was delivered to.
+    pub async fn broadcast_filtered<F>(&self, method: &str, params: Value, filter: F) -> usize
+    where
+        F: Fn(&InitializeClient) -> bool,
+    {
+        for cache in self.inner.list_caches.lock().iter() {
+            cache.invalidate_for_notification(method);
+        }
+
+        // Snapshot the targets so no map shard is locked across an await.
+        let targets: Vec<Arc<dyn McpSession>> = self
+            .inner
+            .sessions
+            .iter()
+            .filter(|entry| filter(&entry.client))
+            .map(|entry| Arc::clone(&entry.session))
+            .collect();
+
+        let mut delivered = 0;
+        for session in targets {
+            match session.notify(method, params.clone()).await {
+                Ok(()) => delivered += 1,
+                Err(error) => {
+                    tracing::debug!(method, %error, "Skipping session during broadcast");
+                }
+            }
+        }
+        delivered
+    }
+}
+
+impl fmt::Debug for NotificationBroadcaster {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NotificationBroadcaster")
+            .field("sessions", &self.len())
+            .finish()
+    }
+}
+
+/// Keeps a session registered with a [`NotificationBroadcaster`]; dropping
+/// it unregisters the session.
+pub struct BroadcastRegistration {
+    id: u64,
+    inner: Weak<BroadcasterInner>,
+}
+
+impl fmt::Debug for BroadcastRegistration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BroadcastRegistration")
+            .field("id", &self.id)
+            .finish()
+    }
+}
+
+impl Drop for BroadcastRegistration {
+    fn drop(&mut self) {
+        if let Some(inner) = self.inner.upgrade() {
+            inner.sessions.remove(&self.id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use turbomcp_core::error::McpError;
+    use turbomcp_core::session::SessionFuture;
+    use turbomcp_types::{ClientCapabilities, Implementation, ProtocolVersion};
+
+    #[derive(Debug, Default)]
+    struct RecordingSession {
+        sent: Mutex<Vec<(String, Value)>>,
+    }
+
+    impl McpSession for RecordingSession {
+        fn call<'a>(&'a self, _method: &'a str, _params: Value) -> SessionFuture<'a, Value> {
+            Box::pin(async { Err(McpError::internal("not supported")) })
+        }
+
+        fn notify<'a>(&'a self, method: &'a str, params: Value) -> SessionFuture<'a, ()> {
+            self.sent.lock().push((method.to_string(), params));
+            Box::pin(async { Ok(()) })
+        }
+    }
+
+    fn client(name: &str) -> InitializeClient {
+        InitializeClient {
+            client_info: Implementation::new(name, "1.0.0"),
+            capabilities: ClientCapabilities::default(),
+            protocol_version: ProtocolVersion::LATEST,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_filters_and_unregisters() {
+        let broadcaster = NotificationBroadcaster::new();
+        let alpha = Arc::new(RecordingSession::default());
+        let beta = Arc::new(RecordingSession::default());
+        let _alpha_registration = broadcaster.register(alpha.clone(), client("alpha"));
+        let beta_registration = broadcaster.register(beta.clone(), client("beta"));
+
+        let delivered = broadcaster
+            .broadcast_filtered("notifications/job/completed", Value::Null, |client| {
+                client.client_info.name == "alpha"
+            })
+            .await;
+        assert_eq!(delivered, 1);
+        assert_eq!(alpha.sent.lock().len(), 1);
+        assert!(beta.sent.lock().is_empty());
+
+        drop(beta_registration);
+        assert_eq!(broadcaster.len(), 1);
+        assert_eq!(
+            broadcaster
+                .broadcast("notifications/job/completed", Value::Null)
+                .await,
+            1
+        );
+        assert!(beta.sent.lock().is_empty());
+    }
+}
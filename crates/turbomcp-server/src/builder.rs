@@ -86,13 +86,27 @@
 
 use std::time::Duration;
 
-use turbomcp_core::error::McpResult;
+use futures::StreamExt;
+use futures::stream::FuturesUnordered;
+use tokio::sync::watch;
+use turbomcp_core::error::{McpError, McpResult};
 use turbomcp_core::handler::McpHandler;
+use turbomcp_types::{Icon, ToolResult};
 
+use super::arguments::{ArgumentDeserializer, ArgumentDeserializers};
 use super::config::{
-    ConnectionLimits, OriginValidationConfig, ProtocolConfig, RateLimitConfig, ServerConfig,
-    ServerConfigBuilder,
+    OriginValidationConfig, ProtocolConfig, RateLimitConfig, ServerConfig, ServerConfigBuilder,
 };
+use super::diagnostics::ServerDiagnostics;
+use super::drain::DrainCoordinator;
+use super::list_cache::{ListCache, ListCacheInvalidator};
+use super::middleware::{LoggingMiddleware, MiddlewareStack};
+use super::output_transform::OutputTransforms;
+use super::panic_isolation::PanicIsolation;
+use super::resource_dir::{ResourceDir, ResourceDirOptions, ResourceDirs};
+use super::served::{ServedHandler, ServedLayers};
+use super::server_icon::valid_icons;
+use super::tool_schema::SchemaTransforms;
 
 /// Transport configuration for the server.
 ///
@@ -191,6 +205,22 @@ impl Transport {
     pub fn unix(path: impl Into<String>) -> Self {
         Self::Unix { path: path.into() }
     }
+
+    /// Short name of this transport (e.g. `"stdio"`, `"http"`).
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Stdio => "stdio",
+            #[cfg(feature = "http")]
+            Self::Http { .. } => "http",
+            #[cfg(feature = "websocket")]
+            Self::WebSocket { .. } => "websocket",
+            #[cfg(feature = "tcp")]
+            Self::Tcp { .. } => "tcp",
+            #[cfg(all(feature = "unix", unix))]
+            Self::Unix { .. } => "unix",
+        }
+    }
 }
 
 /// Server builder for configuring and running MCP servers.
@@ -218,6 +248,15 @@ pub struct ServerBuilder<H: McpHandler> {
     transport: Transport,
     config: ServerConfigBuilder,
     graceful_shutdown: Option<Duration>,
+    tool_schema_metadata: bool,
+    icons: Vec<Icon>,
+    panic_isolation: bool,
+    resource_dirs: Vec<ResourceDir>,
+    argument_deserializers: ArgumentDeserializers,
+    schema_transforms: SchemaTransforms,
+    output_transforms: OutputTransforms,
+    list_cache: Option<ListCache>,
+    drain: DrainCoordinator,
 }
 
 impl<H: McpHandler> ServerBuilder<H> {
@@ -228,6 +267,15 @@ impl<H: McpHandler> ServerBuilder<H> {
             transport: Transport::default(),
             config: ServerConfig::builder(),
             graceful_shutdown: None,
+            tool_schema_metadata: true,
+            icons: Vec::new(),
+            panic_isolation: true,
+            resource_dirs: Vec::new(),
+            argument_deserializers: ArgumentDeserializers::default(),
+            schema_transforms: SchemaTransforms::default(),
+            output_transforms: OutputTransforms::default(),
+            list_cache: None,
+            drain: DrainCoordinator::default(),
         }
     }
 
@@ -259,11 +307,9 @@ impl<H: McpHandler> ServerBuilder<H> {
     /// ```
     #[must_use]
     pub fn with_rate_limit(mut self, max_requests: u32, window: Duration) -> Self {
-        self.config = self.config.rate_limit(RateLimitConfig {
-            max_requests,
-            window,
-            per_client: true,
-        });
+        self.config = self
+            .config
+            .rate_limit(RateLimitConfig::new(max_requests, window));
         self
     }
 
@@ -307,12 +353,45 @@ impl<H: McpHandler> ServerBuilder<H> {
     /// ```
     #[must_use]
     pub fn with_connection_limit(mut self, max: usize) -> Self {
-        self.config = self.config.connection_limits(ConnectionLimits {
-            max_tcp_connections: max,
-            max_websocket_connections: max,
-            max_http_concurrent: max,
-            max_unix_connections: max,
-        });
+        let limits = self.config.connection_limits_mut();
+        limits.max_tcp_connections = max;
+        limits.max_websocket_connections = max;
+        limits.max_http_concurrent = max;
+        limits.max_unix_connections = max;
+        self
+    }
+
+    /// Close connections that have been open longer than `lifetime`.
+    ///
+    /// Applies to TCP, Unix, and WebSocket transports. Clients are expected
+    /// to reconnect; in-flight requests are cancelled when the connection
+    /// is recycled.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// builder.with_max_connection_lifetime(Duration::from_secs(3600))
+    /// ```
+    #[must_use]
+    pub fn with_max_connection_lifetime(mut self, lifetime: Duration) -> Self {
+        self.config = self.config.max_connection_lifetime(lifetime);
+        self
+    }
+
+    /// Close connections that send or receive no message for `timeout`.
+    ///
+    /// Applies to TCP, Unix, and WebSocket transports. A connection serving
+    /// a request is not idle, however long the handler runs; the timeout
+    /// starts again once the last response is sent.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// builder.with_idle_timeout(Duration::from_secs(300))
+    /// ```
+    #[must_use]
+    pub fn with_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.config = self.config.idle_timeout(timeout);
         self
     }
 
@@ -332,6 +411,17 @@ impl<H: McpHandler> ServerBuilder<H> {
         self
     }
 
+    /// Count in-flight requests of [`serve_multi`](Self::serve_multi) in
+    /// `coordinator`.
+    ///
+    /// Keep a clone to watch per-transport in-flight counts with
+    /// [`DrainCoordinator::in_flight`] while the server runs.
+    #[must_use]
+    pub fn with_drain_coordinator(mut self, coordinator: DrainCoordinator) -> Self {
+        self.drain = coordinator;
+        self
+    }
+
     /// Configure protocol version negotiation.
     ///
     /// Use `ProtocolConfig::multi_version()` to accept clients requesting
@@ -372,6 +462,214 @@ impl<H: McpHandler> ServerBuilder<H> {
         self
     }
 
+    /// Control whether listed tool input schemas get `$schema` and `title`.
+    ///
+    /// When enabled (the default), every tool returned by `tools/list`
+    /// declares the JSON Schema 2020-12 dialect and a `title` derived from
+    /// the tool name (`get_weather` → `Get Weather`), unless the tool already
+    /// sets them.
+    #[must_use]
+    pub fn with_tool_schema_metadata(mut self, enabled: bool) -> Self {
+        self.tool_schema_metadata = enabled;
+        self
+    }
+
+    /// Whether tool input schemas get `$schema` and `title` metadata.
+    #[must_use]
+    pub fn tool_schema_metadata(&self) -> bool {
+        self.tool_schema_metadata
+    }
+
+    /// Rewrite every listed tool schema with `transform`.
+    ///
+    /// The transform receives each tool's input schema, and its output schema
+    /// if present, as raw JSON after the built-in metadata has been added.
+    /// Use it to enforce house style across all tools. Transforms run in
+    /// registration order. See [`SchemaTransforms`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// MyServer.builder()
+    ///     .schema_transform(|schema| {
+    ///         schema["additionalProperties"] = false.into();
+    ///     })
+    ///     .serve()
+    ///     .await?;
+    /// ```
+    #[must_use]
+    pub fn schema_transform(
+        mut self,
+        transform: impl Fn(&mut serde_json::Value) + Send + Sync + 'static,
+    ) -> Self {
+        self.schema_transforms.push(transform);
+        self
+    }
+
+    /// The registered tool schema transforms.
+    #[must_use]
+    pub fn schema_transforms(&self) -> &SchemaTransforms {
+        &self.schema_transforms
+    }
+
+    /// Post-process every tool result with `transform` before it is sent.
+    ///
+    /// The transform receives the tool name and the result, and may rewrite
+    /// its content blocks and structured content, e.g. to append a disclaimer
+    /// or strip internal fields. Tool results fetched with `tasks/result` are
+    /// transformed too, with no tool name. Transforms run in registration
+    /// order. See [`OutputTransforms`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// MyServer.builder()
+    ///     .output_transform(|_tool: Option<&str>, result: &mut ToolResult| {
+    ///         result.content.push(Content::text("Verify before use."));
+    ///     })
+    ///     .serve()
+    ///     .await?;
+    /// ```
+    #[must_use]
+    pub fn output_transform(
+        mut self,
+        transform: impl Fn(Option<&str>, &mut ToolResult) + Send + Sync + 'static,
+    ) -> Self {
+        self.output_transforms.push(transform);
+        self
+    }
+
+    /// The registered tool output transforms.
+    #[must_use]
+    pub fn output_transforms(&self) -> &OutputTransforms {
+        &self.output_transforms
+    }
+
+    /// Control whether handler panics are caught.
+    ///
+    /// When enabled (the default), a panicking tool, resource, or prompt
+    /// handler is logged and answered with a redacted error instead of
+    /// unwinding through the transport. See [`PanicIsolation`](crate::PanicIsolation).
+    #[must_use]
+    pub fn with_panic_isolation(mut self, enabled: bool) -> Self {
+        self.panic_isolation = enabled;
+        self
+    }
+
+    /// Whether handler panics are caught.
+    #[must_use]
+    pub fn panic_isolation(&self) -> bool {
+        self.panic_isolation
+    }
+
+    /// Cache the handler's tool, resource, resource template, and prompt
+    /// lists.
+    ///
+    /// Off by default. Concurrent list requests that miss the cache share one
+    /// computation, and a list is recomputed after its `list_changed`
+    /// notification is sent. See [`ListCacheInvalidator`] for changes that
+    /// are not announced.
+    #[must_use]
+    pub fn with_list_cache(mut self, enabled: bool) -> Self {
+        if !enabled {
+            self.list_cache = None;
+        } else if self.list_cache.is_none() {
+            self.list_cache = Some(ListCache::default());
+        }
+        self
+    }
+
+    /// Handle that clears the list cache, if one is enabled.
+    #[must_use]
+    pub fn list_cache_invalidator(&self) -> Option<ListCacheInvalidator> {
+        self.list_cache.as_ref().map(ListCache::invalidator)
+    }
+
+    /// Register a hook that maps raw `tools/call` arguments into the object
+    /// shape the tool expects, before the tool deserializes them.
+    ///
+    /// Deserializers run in registration order. Use [`PositionalArguments`]
+    /// to accept positional arrays. See [`ArgumentDeserializer`].
+    ///
+    /// ```rust,ignore
+    /// MyServer.builder()
+    ///     .with_argument_deserializer(PositionalArguments)
+    ///     .serve()
+    ///     .await?;
+    /// ```
+    ///
+    /// [`PositionalArguments`]: crate::PositionalArguments
+    /// [`ArgumentDeserializer`]: crate::ArgumentDeserializer
+    #[must_use]
+    pub fn with_argument_deserializer(mut self, deserializer: impl ArgumentDeserializer) -> Self {
+        self.argument_deserializers.push(deserializer);
+        self
+    }
+
+    /// The registered tool argument deserializers.
+    #[must_use]
+    pub fn argument_deserializers(&self) -> &ArgumentDeserializers {
+        &self.argument_deserializers
+    }
+
+    /// Advertise an icon in the `serverInfo` returned from `initialize`.
+    ///
+    /// `src` must be an `http://`/`https://` URL or a `data:` URI; other
+    /// sources are logged and left out of `serverInfo`. Can be called more
+    /// than once; icons are added after any the handler already reports.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// builder.icon("https://example.com/logo.png")
+    /// ```
+    #[must_use]
+    pub fn icon(mut self, src: impl Into<String>) -> Self {
+        self.icons.push(Icon::new(src));
+        self
+    }
+
+    /// Icons added with [`icon`](Self::icon).
+    #[must_use]
+    pub fn icons(&self) -> &[Icon] {
+        &self.icons
+    }
+
+    /// Serve the files under a directory as resources.
+    ///
+    /// Each file is listed as `root_uri` followed by its path relative to
+    /// `path` (for example `docs://guides/setup.md`) and read from disk on
+    /// `resources/read`. Reads cannot escape the directory. `path` must be an
+    /// existing directory, otherwise [`serve`](Self::serve) fails with a
+    /// configuration error. See [`ResourceDirOptions`] for filters.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// builder.resource_dir(
+    ///     "docs://",
+    ///     "./docs",
+    ///     ResourceDirOptions::new().include("*.md"),
+    /// )
+    /// ```
+    #[must_use]
+    pub fn resource_dir(
+        mut self,
+        root_uri: impl Into<String>,
+        path: impl Into<std::path::PathBuf>,
+        options: ResourceDirOptions,
+    ) -> Self {
+        self.resource_dirs
+            .push(ResourceDir::new(root_uri, path, options));
+        self
+    }
+
+    /// Directories added with [`resource_dir`](Self::resource_dir).
+    #[must_use]
+    pub fn resource_dirs(&self) -> &[ResourceDir] {
+        &self.resource_dirs
+    }
+
     /// Apply a custom server configuration.
     ///
     /// This replaces any previously set configuration options.
@@ -393,11 +691,25 @@ impl<H: McpHandler> ServerBuilder<H> {
             .connection_limits(config.connection_limits)
             .required_capabilities(config.required_capabilities)
             .max_message_size(config.max_message_size)
-            .origin_validation(config.origin_validation);
+            .origin_validation(config.origin_validation)
+            .stdio_buffer_depth(config.stdio_buffer_depth)
+            .elicitation_fallback(config.elicitation_fallback)
+            .context_extractors(config.context_extractors)
+            .jsonrpc_strictness(config.jsonrpc_strictness)
+            .http_compression(config.http_compression)
+            .duplicate_request_ids(config.duplicate_request_ids)
+            .initialize_hooks(config.initialize_hooks)
+            .max_pending_server_requests(config.max_pending_server_requests)
+            .client_request_timeout(config.client_request_timeout)
+            .meta_allowlist(config.meta_allowlist)
+            .notification_broadcaster(config.notification_broadcaster);
 
         if let Some(rate_limit) = config.rate_limit {
             builder = builder.rate_limit(rate_limit);
         }
+        if let Some(sampling) = config.log_sampling {
+            builder = builder.log_sampling(sampling);
+        }
 
         self.config = builder;
         self
@@ -406,7 +718,10 @@ impl<H: McpHandler> ServerBuilder<H> {
     /// Run the server with the configured transport.
     ///
     /// This is the main entry point that starts the server and blocks
-    /// until shutdown.
+    /// until shutdown. Ctrl+C or SIGTERM stops the transport gracefully:
+    /// it stops accepting input and answers requests already in flight,
+    /// waiting at most the [`with_graceful_shutdown`](Self::with_graceful_shutdown)
+    /// timeout when one is set.
     ///
     /// # Example
     ///
@@ -416,52 +731,194 @@ impl<H: McpHandler> ServerBuilder<H> {
     ///     .serve()
     ///     .await?;
     /// ```
-    #[allow(unused_variables)]
     pub async fn serve(self) -> McpResult<()> {
-        // Config is used by transport-specific features (http, websocket, tcp, unix)
-        // STDIO doesn't use config, so this may be unused if only stdio is enabled
-        let config = self.config.build();
+        let transport = self.transport.clone();
+        let graceful_shutdown = self.graceful_shutdown;
+        let (handler, config) = self.into_served_handler()?;
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        let signal_task = tokio::spawn(async move {
+            super::transport::shutdown_signal().await;
+            tracing::info!("Received shutdown signal, stopping server...");
+            let _ = shutdown_tx.send(true);
+        });
 
-        match self.transport {
-            Transport::Stdio => {
-                #[cfg(feature = "stdio")]
-                {
-                    super::transport::stdio::run_with_config(&self.handler, &config).await
-                }
-                #[cfg(not(feature = "stdio"))]
-                {
-                    Err(turbomcp_core::error::McpError::internal(
-                        "STDIO transport not available. Enable the 'stdio' feature.",
-                    ))
+        let result =
+            run_transport(&handler, transport, &config, graceful_shutdown, shutdown_rx).await;
+
+        signal_task.abort();
+        result
+    }
+
+    /// Serve several transports concurrently from one server instance.
+    ///
+    /// Every transport shares the same handler, so tools, resources, prompts,
+    /// and any state they hold are identical whichever way a client connects.
+    /// The transport set with [`transport`](Self::transport) is ignored.
+    ///
+    /// Ctrl+C or SIGTERM stops all transports together. If one transport fails (e.g. its
+    /// address is already in use), the others are stopped and its error is
+    /// returned. A transport that finishes cleanly, such as STDIO reaching
+    /// end of input, leaves the others running. Lifecycle hooks
+    /// (`on_initialize`/`on_shutdown`) run once per transport.
+    ///
+    /// With [`with_graceful_shutdown`](Self::with_graceful_shutdown) set,
+    /// shutdown first drains every transport together: new requests are
+    /// refused, and the transports stop once none has a request in flight or
+    /// the timeout elapses, whichever comes first.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// MyServer.builder()
+    ///     .serve_multi(vec![Transport::stdio(), Transport::http("0.0.0.0:8080")])
+    ///     .await?;
+    /// ```
+    pub async fn serve_multi(self, transports: Vec<Transport>) -> McpResult<()> {
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        let signal_task = tokio::spawn(async move {
+            super::transport::shutdown_signal().await;
+            tracing::info!("Received shutdown signal, stopping all transports...");
+            let _ = shutdown_tx.send(true);
+        });
+
+        let result = self
+            .serve_multi_with_shutdown(transports, shutdown_rx)
+            .await;
+
+        signal_task.abort();
+        result
+    }
+
+    /// Like [`serve_multi`](Self::serve_multi), but stops all transports when
+    /// `shutdown` receives `true` instead of on Ctrl+C or SIGTERM.
+    pub async fn serve_multi_with_shutdown(
+        self,
+        transports: Vec<Transport>,
+        shutdown: watch::Receiver<bool>,
+    ) -> McpResult<()> {
+        if transports.is_empty() {
+            return Err(McpError::invalid_params(
+                "serve_multi requires at least one transport",
+            ));
+        }
+        let graceful_shutdown = self.graceful_shutdown;
+        let drain = self.drain.clone();
+        let (handler, config) = self.into_served_handler()?;
+
+        // A failing transport stops the rest through this sender.
+        let (stop_tx, stop_rx) = watch::channel(false);
+        let mut forward = shutdown.clone();
+        let forward_stop = stop_tx.clone();
+        let forward_drain = drain.clone();
+        let forward_task = tokio::spawn(async move {
+            while forward.changed().await.is_ok() {
+                if *forward.borrow() {
+                    if let Some(timeout) = graceful_shutdown {
+                        drain_transports(&forward_drain, timeout).await;
+                    }
+                    let _ = forward_stop.send(true);
+                    break;
                 }
             }
+        });
+        if *shutdown.borrow() {
+            let _ = stop_tx.send(true);
+        }
 
-            #[cfg(feature = "http")]
-            Transport::Http { addr } => {
-                super::transport::http::run_with_shutdown(
-                    &self.handler,
-                    &addr,
-                    &config,
-                    self.graceful_shutdown,
-                )
-                .await
-            }
+        let mut running: FuturesUnordered<_> = transports
+            .into_iter()
+            .map(|transport| {
+                let name = transport.name();
+                let stop = stop_rx.clone();
+                let handler = handler.clone().with_drain(&drain, name);
+                let config = &config;
+                async move {
+                    let result =
+                        run_transport(&handler, transport, config, graceful_shutdown, stop).await;
+                    (name, result)
+                }
+            })
+            .collect();
 
-            #[cfg(feature = "websocket")]
-            Transport::WebSocket { addr } => {
-                super::transport::websocket::run_with_config(&self.handler, &addr, &config).await
+        let mut first_error = None;
+        while let Some((name, result)) = running.next().await {
+            match result {
+                Ok(()) => tracing::info!(transport = name, "transport stopped"),
+                Err(error) => {
+                    tracing::error!(transport = name, error = %error, "transport failed");
+                    let _ = stop_tx.send(true);
+                    first_error.get_or_insert(error);
+                }
             }
+        }
 
-            #[cfg(feature = "tcp")]
-            Transport::Tcp { addr } => {
-                super::transport::tcp::run_with_config(&self.handler, &addr, &config).await
-            }
+        forward_task.abort();
+        first_error.map_or(Ok(()), Err)
+    }
 
-            #[cfg(all(feature = "unix", unix))]
-            Transport::Unix { path } => {
-                super::transport::unix::run_with_config(&self.handler, &path, &config).await
-            }
+    /// Validate the builder and wrap the handler with the built-in layers.
+    fn into_served_handler(self) -> McpResult<(ServedHandler<H>, ServerConfig)> {
+        for dir in &self.resource_dirs {
+            dir.validate()?;
         }
+        Ok(self.into_parts())
+    }
+
+    /// Wrap the handler with the built-in layers and build the configuration.
+    pub(crate) fn into_parts(self) -> (ServedHandler<H>, ServerConfig) {
+        let handler = self.served_handler();
+        let config = self.config.build();
+        if let Some(cache) = &self.list_cache {
+            config
+                .notification_broadcaster
+                .invalidate_on_list_changed(cache.invalidator());
+        }
+        (handler, config)
+    }
+
+    /// Wrap a clone of the handler with the built-in layers.
+    ///
+    /// This is the only place the served handler is assembled, so every way
+    /// of serving the builder exposes the same tools, resources, and info.
+    pub(crate) fn served_handler(&self) -> ServedHandler<H> {
+        let mut stack = MiddlewareStack::new(self.handler.clone());
+        if let Some(sampling) = self.config.log_sampling_policy() {
+            stack = stack.with_middleware(LoggingMiddleware::new(sampling));
+        }
+        if !self.output_transforms.is_empty() {
+            stack = stack.with_middleware(self.output_transforms.clone());
+        }
+        if !self.argument_deserializers.is_empty() {
+            stack = stack.with_middleware(self.argument_deserializers.clone());
+        }
+        if self.panic_isolation {
+            stack = stack.with_middleware(PanicIsolation);
+        }
+        ServedHandler::new(
+            stack,
+            ServedLayers {
+                tool_schema_metadata: self.tool_schema_metadata,
+                schema_transforms: self.schema_transforms.clone(),
+                output_transforms: self.output_transforms.clone(),
+                icons: valid_icons(&self.icons),
+                resource_dirs: ResourceDirs::new(self.resource_dirs.clone()),
+                list_cache: self.list_cache.clone(),
+            },
+        )
+    }
+
+    /// Summarize what this server will expose once it starts serving.
+    ///
+    /// Reports registered tool/resource/prompt names, advertised capabilities,
+    /// the selected transport, compiled-in transport features, and the active
+    /// configuration. Call before [`serve`](Self::serve) to log startup state.
+    ///
+    /// The summary is taken from the handler as it will be served, so it
+    /// includes what the builder adds, such as directory resources.
+    #[must_use]
+    pub fn diagnostics(&self) -> ServerDiagnostics {
+        let config = self.config.clone().build();
+        ServerDiagnostics::collect(&self.served_handler(), &self.transport, &config)
     }
 
     /// Get the underlying handler.
@@ -506,13 +963,14 @@ impl<H: McpHandler> ServerBuilder<H> {
     pub fn into_axum_router(self) -> axum::Router {
         use std::sync::Arc;
 
-        let config = self.config.build();
+        // Missing directories list nothing and are logged when listed.
+        let (handler, config) = self.into_parts();
         let rate_limiter = config
             .rate_limit
             .as_ref()
             .map(|cfg| Arc::new(crate::config::RateLimiter::new(cfg.clone())));
 
-        crate::transport::http::build_router(self.handler, rate_limiter, Some(config))
+        crate::transport::http::build_router(handler, rate_limiter, Some(config))
     }
 
     /// Convert to a Tower service for custom server integration.
@@ -599,9 +1057,152 @@ pub trait McpServerExt: McpHandler + Sized {
 /// Blanket implementation for all McpHandler types.
 impl<T: McpHandler> McpServerExt for T {}
 
+/// Run one transport until it finishes or `stop` receives `true`.
+///
+/// On stop, each transport takes its own graceful path: listeners close and
+/// in-flight requests are answered. With a `graceful_shutdown` timeout, a
+/// transport that takes longer than that to stop is abandoned.
+async fn run_transport<H: McpHandler>(
+    handler: &H,
+    transport: Transport,
+    config: &ServerConfig,
+    graceful_shutdown: Option<Duration>,
+    stop: watch::Receiver<bool>,
+) -> McpResult<()> {
+    match transport {
+        Transport::Stdio => {
+            #[cfg(feature = "stdio")]
+            {
+                until_stopped(
+                    super::transport::stdio::run_with_shutdown(handler, config, stop.clone()),
+                    stop,
+                    graceful_shutdown,
+                )
+                .await
+            }
+            #[cfg(not(feature = "stdio"))]
+            {
+                let _ = (handler, config, graceful_shutdown, stop);
+                Err(McpError::internal(
+                    "STDIO transport not available. Enable the 'stdio' feature.",
+                ))
+            }
+        }
+
+        #[cfg(feature = "http")]
+        Transport::Http { addr } => {
+            until_stopped(
+                super::transport::http::run_until(
+                    handler,
+                    &addr,
+                    config,
+                    super::transport::shutdown_requested(stop.clone()),
+                ),
+                stop,
+                graceful_shutdown,
+            )
+            .await
+        }
+
+        #[cfg(feature = "websocket")]
+        Transport::WebSocket { addr } => {
+            until_stopped(
+                super::transport::websocket::run_with_shutdown(
+                    handler,
+                    &addr,
+                    config,
+                    stop.clone(),
+                ),
+                stop,
+                graceful_shutdown,
+            )
+            .await
+        }
+
+        #[cfg(feature = "tcp")]
+        Transport::Tcp { addr } => {
+            until_stopped(
+                super::transport::tcp::run_with_shutdown(handler, &addr, config, stop.clone()),
+                stop,
+                graceful_shutdown,
+            )
+            .await
+        }
+
+        #[cfg(all(feature = "unix", unix))]
+        Transport::Unix { path } => {
+            until_stopped(
+                super::transport::unix::run_with_shutdown(handler, &path, config, stop.clone()),
+                stop,
+                graceful_shutdown,
+            )
+            .await
+        }
+    }
+}
+
+/// Wait up to `timeout` for every transport tracked by `drain` to go idle,
+/// logging what was left in flight.
+async fn drain_transports(drain: &DrainCoordinator, timeout: Duration) {
+    tracing::info!(
+        in_flight = drain.in_flight().total(),
+        "Draining in-flight requests on all transports"
+    );
+    let remaining = drain.drain(timeout).await;
+    if remaining.total() == 0 {
+        tracing::info!("All transports drained");
+        return;
+    }
+    for (transport, in_flight) in remaining.by_transport().filter(|(_, n)| *n > 0) {
+        tracing::warn!(
+            transport,
+            in_flight,
+            "Requests still in flight after drain timeout"
+        );
+    }
+    tracing::warn!(
+        in_flight = remaining.total(),
+        "Drain timed out; stopping transports"
+    );
+}
+
+/// Drive `run` to completion. Once `stop` receives `true`, the transport
+/// is given up to `grace` to finish stopping before it is abandoned.
+#[cfg(any(
+    feature = "stdio",
+    feature = "http",
+    feature = "tcp",
+    all(feature = "unix", unix)
+))]
+async fn until_stopped(
+    run: impl Future<Output = McpResult<()>>,
+    stop: watch::Receiver<bool>,
+    grace: Option<Duration>,
+) -> McpResult<()> {
+    let mut run = std::pin::pin!(run);
+    tokio::select! {
+        result = &mut run => return result,
+        () = super::transport::shutdown_requested(stop) => {}
+    }
+    let Some(grace) = grace else {
+        return run.await;
+    };
+    match tokio::time::timeout(grace, run).await {
+        Ok(result) => result,
+        Err(_) => {
+            tracing::warn!(
+                timeout = ?grace,
+                "Transport did not stop within the graceful shutdown timeout"
+            );
+            Ok(())
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::LogSampling;
     use serde_json::Value;
     use turbomcp_core::context::RequestContext as CoreRequestContext;
     use turbomcp_core::error::McpError;
@@ -729,4 +1330,73 @@ mod tests {
         let recovered = builder.into_handler();
         assert_eq!(recovered.server_info().name, "test");
     }
+
+    #[test]
+    fn test_serve_futures_are_send() {
+        fn assert_send<T: Send>(_: &T) {}
+
+        assert_send(&TestHandler.builder().serve());
+        assert_send(&TestHandler.builder().serve_multi(vec![Transport::stdio()]));
+    }
+
+    /// `io::Write` sink that appends to a shared buffer.
+    #[derive(Clone, Default)]
+    struct LogCapture(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+
+    impl std::io::Write for LogCapture {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_served_handler_logs_under_configured_sampling() {
+        let logs = LogCapture::default();
+        let writer = logs.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer(move || writer.clone())
+            .with_ansi(false)
+            .finish();
+        let _default = tracing::subscriber::set_default(subscriber);
+        let ctx = CoreRequestContext::stdio();
+
+        let unlogged = TestHandler.builder().served_handler();
+        unlogged.call_tool("test", Value::Null, &ctx).await.unwrap();
+        assert!(logs.0.lock().unwrap().is_empty());
+
+        let served = TestHandler
+            .builder()
+            .with_config(
+                ServerConfig::builder()
+                    .log_sampling(LogSampling::ErrorsOnly)
+                    .build(),
+            )
+            .served_handler();
+        served.call_tool("test", Value::Null, &ctx).await.unwrap();
+        assert!(served.read_resource("file:///missing", &ctx).await.is_err());
+
+        let output = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+        assert!(!output.contains("request completed"), "{output}");
+        assert_eq!(output.matches("request failed").count(), 1, "{output}");
+    }
+
+    #[cfg(feature = "stdio")]
+    #[tokio::test]
+    async fn test_stdio_stops_on_shutdown() {
+        let (_shutdown_tx, shutdown_rx) = watch::channel(true);
+        let served = TestHandler
+            .builder()
+            .with_graceful_shutdown(Duration::from_secs(1))
+            .serve_multi_with_shutdown(vec![Transport::stdio()], shutdown_rx);
+
+        tokio::time::timeout(Duration::from_secs(5), served)
+            .await
+            .expect("stdio should stop without waiting for end of input")
+            .unwrap();
+    }
 }
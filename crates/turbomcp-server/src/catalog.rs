@@ -0,0 +1,248 @@
+//! Runtime catalog of registered components.
+//!
+//! [`McpHandlerExt::describe_all`](crate::McpHandlerExt::describe_all) returns
+//! a [`ComponentCatalog`] listing every tool, resource, resource template, and
+//! prompt a handler registers, with the full wire definition (descriptions,
+//! schemas, annotations) plus the tags, version, and OAuth scopes read from
+//! each component's `_meta`. It is `Serialize`, so admin UIs and documentation
+//! generators can consume it as JSON:
+//!
+//! ```rust,ignore
+//! use turbomcp::prelude::*;
+//!
+//! let catalog = MyServer.describe_all();
+//! std::fs::write("catalog.json", serde_json::to_vec_pretty(&catalog)?)?;
+//! ```
+//!
+//! Components declare the scopes a caller needs under
+//! [`REQUIRED_SCOPES_META`]:
+//!
+//! ```rust,ignore
+//! let mut tool = Tool::new("delete_user", "Delete a user");
+//! tool.meta = Some([(REQUIRED_SCOPES_META.to_string(), json!(["users:write"]))].into());
+//! ```
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+use serde_json::Value;
+use turbomcp_core::handler::McpHandler;
+use turbomcp_types::{ComponentMeta, Prompt, Resource, ResourceTemplate, Tool};
+
+/// `_meta` key listing the OAuth scopes required to use a component.
+pub const REQUIRED_SCOPES_META: &str = "requiredScopes";
+
+/// Every component a handler registers, each sorted by its identifier.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentCatalog {
+    /// Registered tools, by name.
+    pub tools: Vec<CatalogEntry<Tool>>,
+    /// Registered resources, by URI.
+    pub resources: Vec<CatalogEntry<Resource>>,
+    /// Registered resource templates, by URI template.
+    pub resource_templates: Vec<CatalogEntry<ResourceTemplate>>,
+    /// Registered prompts, by name.
+    pub prompts: Vec<CatalogEntry<Prompt>>,
+}
+
+/// One component's definition together with metadata parsed from its `_meta`.
+///
+/// The definition's fields are serialized inline, so an entry reads as the
+/// component's `*/list` JSON with `tags`, `version`, and `requiredScopes`
+/// added.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CatalogEntry<T> {
+    /// The component exactly as advertised to clients.
+    #[serde(flatten)]
+    pub definition: T,
+    /// Tags from `_meta.tags`.
+    pub tags: Vec<String>,
+    /// Version from `_meta.version`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// Scopes from [`REQUIRED_SCOPES_META`]; empty if none are required.
+    pub required_scopes: Vec<String>,
+}
+
+impl<T> CatalogEntry<T> {
+    fn new(definition: T, meta: Option<&HashMap<String, Value>>) -> Self {
+        let ComponentMeta { tags, version } = ComponentMeta::from_meta_value(meta);
+        let required_scopes = meta
+            .and_then(|meta| meta.get(REQUIRED_SCOPES_META))
+            .and_then(Value::as_array)
+            .map(|scopes| {
+                scopes
+                    .iter()
+                    .filter_map(|scope| scope.as_str().map(ToString::to_string))
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self {
+            definition,
+            tags,
+            version,
+            required_scopes,
+        }
+    }
+}
+
+impl ComponentCatalog {
+    /// Collect the catalog of everything `handler` registers.
+    pub fn collect<H: McpHandler>(handler: &H) -> Self {
+        let mut tools: Vec<_> = handler
+            .list_tools()
+            .into_iter()
+            .map(|tool| {
+                let meta = tool.meta.clone();
+                CatalogEntry::new(tool, meta.as_ref())
+            })
+            .collect();
+        tools.sort_by(|a, b| a.definition.name.cmp(&b.definition.name));
+
+        let mut resources: Vec<_> = handler
+            .list_resources()
+            .into_iter()
+            .map(|resource| {
+                let meta = resource.meta.clone();
+                CatalogEntry::new(resource, meta.as_ref())
+            })
+            .collect();
+        resources.sort_by(|a, b| a.definition.uri.cmp(&b.definition.uri));
+
+        let mut resource_templates: Vec<_> = handler
+            .list_resource_templates()
+            .into_iter()
+            .map(|template| {
+                let meta = template.meta.clone();
+                CatalogEntry::new(template, meta.as_ref())
+            })
+            .collect();
+        resource_templates
+            .sort_by(|a, b| a.definition.uri_template.cmp(&b.definition.uri_template));
+
+        let mut prompts: Vec<_> = handler
+            .list_prompts()
+            .into_iter()
+            .map(|prompt| {
+                let meta = prompt.meta.clone();
+                CatalogEntry::new(prompt, meta.as_ref())
+            })
+            .collect();
+        prompts.sort_by(|a, b| a.definition.name.cmp(&b.definition.name));
+
+        Self {
+            tools,
+            resources,
+            resource_templates,
+            prompts,
+        }
+    }
+
+    /// Look up a tool entry by name.
+    #[must_use]
+    pub fn tool(&self, name: &str) -> Option<&CatalogEntry<Tool>> {
+        self.tools
+            .iter()
+            .find(|entry| entry.definition.name == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::McpHandlerExt;
+    use serde_json::json;
+    use turbomcp_core::context::RequestContext;
+    use turbomcp_core::error::{McpError, McpResult};
+    use turbomcp_types::{PromptResult, ResourceResult, ServerInfo, ToolAnnotations, ToolResult};
+
+    #[derive(Clone)]
+    struct CatalogHandler;
+
+    #[allow(clippy::manual_async_fn)]
+    impl McpHandler for CatalogHandler {
+        fn server_info(&self) -> ServerInfo {
+            ServerInfo::new("catalog-test", "1.0.0")
+        }
+
+        fn list_tools(&self) -> Vec<Tool> {
+            let mut delete = Tool::new("delete_user", "Delete a user");
+            delete.annotations = Some(ToolAnnotations::default().with_destructive(true));
+            delete.meta = Some(HashMap::from([
+                ("tags".to_string(), json!(["admin"])),
+                (REQUIRED_SCOPES_META.to_string(), json!(["users:write"])),
+            ]));
+            let mut lookup = Tool::new("lookup_user", "Find a user");
+            lookup.annotations = Some(ToolAnnotations::default().with_read_only(true));
+            vec![lookup, delete]
+        }
+
+        fn list_resources(&self) -> Vec<Resource> {
+            vec![Resource::new("file:///users.csv", "users")]
+        }
+
+        fn list_prompts(&self) -> Vec<Prompt> {
+            vec![]
+        }
+
+        fn call_tool<'a>(
+            &'a self,
+            _name: &'a str,
+            _args: Value,
+            _ctx: &'a RequestContext,
+        ) -> impl std::future::Future<Output = McpResult<ToolResult>> + Send + 'a {
+            async { Ok(ToolResult::text("ok")) }
+        }
+
+        fn read_resource<'a>(
+            &'a self,
+            uri: &'a str,
+            _ctx: &'a RequestContext,
+        ) -> impl std::future::Future<Output = McpResult<ResourceResult>> + Send + 'a {
+            let uri = uri.to_string();
+            async move { Err(McpError::resource_not_found(&uri)) }
+        }
+
+        fn get_prompt<'a>(
+            &'a self,
+            name: &'a str,
+            _args: Option<Value>,
+            _ctx: &'a RequestContext,
+        ) -> impl std::future::Future<Output = McpResult<PromptResult>> + Send + 'a {
+            let name = name.to_string();
+            async move { Err(McpError::prompt_not_found(&name)) }
+        }
+    }
+
+    #[test]
+    fn test_catalog_describes_annotations_and_scopes() {
+        let catalog = CatalogHandler.describe_all();
+
+        let names: Vec<_> = catalog
+            .tools
+            .iter()
+            .map(|entry| entry.definition.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["delete_user", "lookup_user"]);
+
+        let delete = catalog.tool("delete_user").expect("delete_user");
+        assert_eq!(delete.tags, vec!["admin"]);
+        assert_eq!(delete.required_scopes, vec!["users:write"]);
+        let lookup = catalog.tool("lookup_user").expect("lookup_user");
+        assert!(lookup.tags.is_empty());
+        assert!(lookup.required_scopes.is_empty());
+
+        let json = serde_json::to_value(&catalog).unwrap();
+        assert_eq!(json["tools"][0]["name"], "delete_user");
+        assert_eq!(json["tools"][0]["description"], "Delete a user");
+        assert_eq!(json["tools"][0]["inputSchema"]["type"], "object");
+        assert_eq!(json["tools"][0]["annotations"]["destructiveHint"], true);
+        assert_eq!(json["tools"][0]["requiredScopes"], json!(["users:write"]));
+        assert_eq!(json["tools"][1]["annotations"]["readOnlyHint"], true);
+        assert_eq!(json["resources"][0]["uri"], "file:///users.csv");
+        assert_eq!(json["resourceTemplates"], json!([]));
+        assert_eq!(json["prompts"], json!([]));
+    }
+}
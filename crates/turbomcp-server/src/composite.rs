@@ -112,6 +112,28 @@ impl<H: McpHandler> HandlerWrapper<H> {
     ) -> McpResult<PromptResult> {
         self.handler.get_prompt(name, args, ctx).await
     }
+
+    fn supports_subscriptions(&self) -> bool {
+        self.handler
+            .server_capabilities()
+            .resources
+            .and_then(|resources| resources.subscribe)
+            .unwrap_or(false)
+    }
+
+    async fn subscribe(&self, uri: &str, ctx: &RequestContext) -> McpResult<()> {
+        if !self.supports_subscriptions() {
+            return Err(McpError::capability_not_supported("resources/subscribe"));
+        }
+        self.handler.subscribe(uri, ctx).await
+    }
+
+    async fn unsubscribe(&self, uri: &str, ctx: &RequestContext) -> McpResult<()> {
+        if !self.supports_subscriptions() {
+            return Err(McpError::capability_not_supported("resources/unsubscribe"));
+        }
+        self.handler.unsubscribe(uri, ctx).await
+    }
 }
 
 impl<H: McpHandler> Clone for HandlerWrapper<H> {
@@ -147,6 +169,16 @@ trait DynHandler: Send + Sync {
         args: Option<serde_json::Value>,
         ctx: &'a RequestContext,
     ) -> std::pin::Pin<Box<dyn std::future::Future<Output = McpResult<PromptResult>> + Send + 'a>>;
+    fn dyn_subscribe<'a>(
+        &'a self,
+        uri: &'a str,
+        ctx: &'a RequestContext,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = McpResult<()>> + Send + 'a>>;
+    fn dyn_unsubscribe<'a>(
+        &'a self,
+        uri: &'a str,
+        ctx: &'a RequestContext,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = McpResult<()>> + Send + 'a>>;
 }
 
 impl<H: McpHandler> DynHandler for HandlerWrapper<H> {
@@ -202,6 +234,22 @@ impl<H: McpHandler> DynHandler for HandlerWrapper<H> {
     {
         Box::pin(self.get_prompt(name, args, ctx))
     }
+
+    fn dyn_subscribe<'a>(
+        &'a self,
+        uri: &'a str,
+        ctx: &'a RequestContext,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = McpResult<()>> + Send + 'a>> {
+        Box::pin(self.subscribe(uri, ctx))
+    }
+
+    fn dyn_unsubscribe<'a>(
+        &'a self,
+        uri: &'a str,
+        ctx: &'a RequestContext,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = McpResult<()>> + Send + 'a>> {
+        Box::pin(self.unsubscribe(uri, ctx))
+    }
 }
 
 /// Internal struct to hold a mounted handler with its prefix.
@@ -602,6 +650,44 @@ impl McpHandler for CompositeHandler {
                 .await
         }
     }
+
+    fn subscribe<'a>(
+        &'a self,
+        uri: &'a str,
+        ctx: &'a RequestContext,
+    ) -> impl std::future::Future<Output = McpResult<()>> + turbomcp_core::marker::MaybeSend + 'a
+    {
+        async move {
+            let (prefix, original_uri) = self
+                .parse_prefixed_uri(uri)
+                .ok_or_else(|| McpError::resource_not_found(uri))?;
+
+            let handler = self
+                .find_handler(prefix)
+                .ok_or_else(|| McpError::resource_not_found(uri))?;
+
+            handler.handler.dyn_subscribe(original_uri, ctx).await
+        }
+    }
+
+    fn unsubscribe<'a>(
+        &'a self,
+        uri: &'a str,
+        ctx: &'a RequestContext,
+    ) -> impl std::future::Future<Output = McpResult<()>> + turbomcp_core::marker::MaybeSend + 'a
+    {
+        async move {
+            let (prefix, original_uri) = self
+                .parse_prefixed_uri(uri)
+                .ok_or_else(|| McpError::resource_not_found(uri))?;
+
+            let handler = self
+                .find_handler(prefix)
+                .ok_or_else(|| McpError::resource_not_found(uri))?;
+
+            handler.handler.dyn_unsubscribe(original_uri, ctx).await
+        }
+    }
 }
 
 #[cfg(test)]
@@ -822,6 +908,103 @@ mod tests {
         assert!(capabilities.prompts.is_some());
     }
 
+    /// News handler that also accepts resource subscriptions.
+    #[derive(Clone)]
+    struct LiveNewsHandler;
+
+    impl McpHandler for LiveNewsHandler {
+        fn server_info(&self) -> ServerInfo {
+            NewsHandler.server_info()
+        }
+
+        fn server_capabilities(&self) -> ServerCapabilities {
+            let mut capabilities = NewsHandler.server_capabilities();
+            if let Some(resources) = capabilities.resources.as_mut() {
+                resources.subscribe = Some(true);
+            }
+            capabilities
+        }
+
+        fn list_tools(&self) -> Vec<Tool> {
+            NewsHandler.list_tools()
+        }
+
+        fn list_resources(&self) -> Vec<Resource> {
+            NewsHandler.list_resources()
+        }
+
+        fn list_prompts(&self) -> Vec<Prompt> {
+            NewsHandler.list_prompts()
+        }
+
+        fn call_tool<'a>(
+            &'a self,
+            name: &'a str,
+            args: serde_json::Value,
+            ctx: &'a RequestContext,
+        ) -> impl Future<Output = McpResult<ToolResult>> + MaybeSend + 'a {
+            async move { NewsHandler.call_tool(name, args, ctx).await }
+        }
+
+        fn read_resource<'a>(
+            &'a self,
+            uri: &'a str,
+            ctx: &'a RequestContext,
+        ) -> impl Future<Output = McpResult<ResourceResult>> + MaybeSend + 'a {
+            async move { NewsHandler.read_resource(uri, ctx).await }
+        }
+
+        fn get_prompt<'a>(
+            &'a self,
+            name: &'a str,
+            args: Option<serde_json::Value>,
+            ctx: &'a RequestContext,
+        ) -> impl Future<Output = McpResult<PromptResult>> + MaybeSend + 'a {
+            async move { NewsHandler.get_prompt(name, args, ctx).await }
+        }
+
+        fn subscribe<'a>(
+            &'a self,
+            uri: &'a str,
+            _ctx: &'a RequestContext,
+        ) -> impl Future<Output = McpResult<()>> + MaybeSend + 'a {
+            async move {
+                if uri == "feed/top" {
+                    Ok(())
+                } else {
+                    Err(McpError::resource_not_found(uri))
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_advertised_and_routed_per_handler() {
+        let plain = CompositeHandler::new("main", "1.0.0")
+            .mount(WeatherHandler, "weather")
+            .mount(NewsHandler, "news");
+        let resources = plain.server_capabilities().resources.unwrap();
+        assert_eq!(resources.subscribe, None);
+
+        let server = CompositeHandler::new("main", "1.0.0")
+            .mount(WeatherHandler, "weather")
+            .mount(LiveNewsHandler, "news");
+        let resources = server.server_capabilities().resources.unwrap();
+        assert_eq!(resources.subscribe, Some(true));
+
+        let ctx = RequestContext::default();
+        server.subscribe("news://feed/top", &ctx).await.unwrap();
+
+        let err = server
+            .subscribe("weather://api/current", &ctx)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.kind,
+            turbomcp_core::error::ErrorKind::CapabilityNotSupported
+        );
+    }
+
     #[tokio::test]
     async fn test_call_tool_routed() {
         let server = CompositeHandler::new("main", "1.0.0")
@@ -5,6 +5,7 @@
 //! - Rate limiting
 //! - Connection limits
 //! - Capability requirements
+//! - Request logging sampling
 
 use std::collections::HashSet;
 use std::sync::Arc;
@@ -13,6 +14,13 @@ use std::time::{Duration, Instant};
 
 use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
+use turbomcp_core::context::ElicitationFallback;
+use turbomcp_core::error::McpError;
+pub use turbomcp_core::jsonrpc::JsonRpcStrictness;
+
+use crate::broadcast::NotificationBroadcaster;
+use crate::context::{RequestContextExtractor, RequestContextExtractors};
+use crate::initialize::{InitializeHook, InitializeHooks};
 
 // Re-export from core (single source of truth - DRY)
 pub use turbomcp_core::SUPPORTED_VERSIONS as SUPPORTED_PROTOCOL_VERSIONS;
@@ -30,6 +38,22 @@ pub const DEFAULT_RATE_LIMIT_WINDOW: Duration = Duration::from_secs(1);
 /// Default maximum message size (10MB).
 pub const DEFAULT_MAX_MESSAGE_SIZE: usize = 10 * 1024 * 1024;
 
+/// Default number of stdin chunks buffered between the STDIO reader thread and
+/// the async runtime.
+pub const DEFAULT_STDIO_BUFFER_DEPTH: usize = 64;
+
+/// Default maximum number of server-to-client requests (sampling,
+/// elicitation, roots) awaiting a response on one connection.
+pub const DEFAULT_MAX_PENDING_SERVER_REQUESTS: usize = 64;
+
+/// Default number of outbound messages queued for one connection before
+/// senders wait for the client to catch up.
+pub const DEFAULT_WRITE_HIGH_WATER_MARK: usize = 32;
+
+/// Default time a write may stay blocked on a slow client before the
+/// connection is closed.
+pub const DEFAULT_SLOW_CLIENT_TIMEOUT: Duration = Duration::from_secs(30);
+
 /// Origin validation configuration for HTTP transports.
 #[derive(Debug, Clone)]
 pub struct OriginValidationConfig {
@@ -81,6 +105,38 @@ pub struct ServerConfig {
     pub max_message_size: usize,
     /// HTTP origin validation policy.
     pub origin_validation: OriginValidationConfig,
+    /// Sampling policy for request/response logging. When set,
+    /// [`ServerBuilder`](crate::ServerBuilder) logs tool calls, resource
+    /// reads, and prompts under it (default: no request logging).
+    pub log_sampling: Option<LogSampling>,
+    /// Number of stdin chunks the STDIO reader thread may buffer ahead of the
+    /// runtime before it blocks (default: 64).
+    pub stdio_buffer_depth: usize,
+    /// How form elicitations that cannot reach a client are resolved.
+    pub elicitation_fallback: ElicitationFallback,
+    /// Hooks that populate request context fields from transport headers.
+    pub context_extractors: RequestContextExtractors,
+    /// Whether incoming messages must carry `"jsonrpc": "2.0"` (default: strict).
+    pub jsonrpc_strictness: JsonRpcStrictness,
+    /// Whether HTTP responses are compressed when the client sends
+    /// `Accept-Encoding` (gzip, zstd, or br; default: enabled).
+    pub http_compression: bool,
+    /// What happens when a client reuses a request ID (default: reject).
+    pub duplicate_request_ids: DuplicateRequestIds,
+    /// Hooks that adjust the `initialize` response per client.
+    pub initialize_hooks: InitializeHooks,
+    /// Maximum server-to-client requests awaiting a response per connection
+    /// (default: 64). Further requests fail immediately with
+    /// `ServerOverloaded` until a response arrives.
+    pub max_pending_server_requests: usize,
+    /// Longest sampling and elicitation requests wait for the client before
+    /// failing with a timeout (default: `None`, which waits indefinitely).
+    /// Elicitation waits on a person, so leave room for them to answer.
+    pub client_request_timeout: Option<Duration>,
+    /// Which `_meta` keys incoming messages may carry (default: all).
+    pub meta_allowlist: MetaAllowlist,
+    /// Registry of initialized sessions used to broadcast notifications.
+    pub notification_broadcaster: NotificationBroadcaster,
 }
 
 impl Default for ServerConfig {
@@ -92,6 +148,18 @@ impl Default for ServerConfig {
             required_capabilities: RequiredCapabilities::default(),
             max_message_size: DEFAULT_MAX_MESSAGE_SIZE,
             origin_validation: OriginValidationConfig::default(),
+            log_sampling: None,
+            stdio_buffer_depth: DEFAULT_STDIO_BUFFER_DEPTH,
+            elicitation_fallback: ElicitationFallback::default(),
+            context_extractors: RequestContextExtractors::default(),
+            jsonrpc_strictness: JsonRpcStrictness::default(),
+            http_compression: true,
+            duplicate_request_ids: DuplicateRequestIds::default(),
+            initialize_hooks: InitializeHooks::default(),
+            max_pending_server_requests: DEFAULT_MAX_PENDING_SERVER_REQUESTS,
+            client_request_timeout: None,
+            meta_allowlist: MetaAllowlist::default(),
+            notification_broadcaster: NotificationBroadcaster::default(),
         }
     }
 }
@@ -119,6 +187,18 @@ pub struct ServerConfigBuilder {
     required_capabilities: Option<RequiredCapabilities>,
     max_message_size: Option<usize>,
     origin_validation: Option<OriginValidationConfig>,
+    log_sampling: Option<LogSampling>,
+    stdio_buffer_depth: Option<usize>,
+    elicitation_fallback: Option<ElicitationFallback>,
+    context_extractors: RequestContextExtractors,
+    jsonrpc_strictness: Option<JsonRpcStrictness>,
+    http_compression: Option<bool>,
+    duplicate_request_ids: Option<DuplicateRequestIds>,
+    initialize_hooks: InitializeHooks,
+    max_pending_server_requests: Option<usize>,
+    client_request_timeout: Option<Duration>,
+    meta_allowlist: Option<MetaAllowlist>,
+    notification_broadcaster: Option<NotificationBroadcaster>,
 }
 
 impl ServerConfigBuilder {
@@ -143,6 +223,24 @@ impl ServerConfigBuilder {
         self
     }
 
+    /// Close connections older than `lifetime` (TCP, Unix, WebSocket).
+    #[must_use]
+    pub fn max_connection_lifetime(mut self, lifetime: Duration) -> Self {
+        self.connection_limits_mut().max_connection_lifetime = Some(lifetime);
+        self
+    }
+
+    /// Close connections idle for longer than `timeout` (TCP, Unix, WebSocket).
+    #[must_use]
+    pub fn idle_timeout(mut self, timeout: Duration) -> Self {
+        self.connection_limits_mut().idle_timeout = Some(timeout);
+        self
+    }
+
+    pub(crate) fn connection_limits_mut(&mut self) -> &mut ConnectionLimits {
+        self.connection_limits.get_or_insert_with(Default::default)
+    }
+
     /// Set required client capabilities.
     #[must_use]
     pub fn required_capabilities(mut self, caps: RequiredCapabilities) -> Self {
@@ -211,6 +309,162 @@ impl ServerConfigBuilder {
         self
     }
 
+    /// Log requests under a sampling policy.
+    ///
+    /// [`ServerBuilder`](crate::ServerBuilder) applies it to tool calls,
+    /// resource reads, and prompts, as does
+    /// [`LoggingMiddleware::from_config`](crate::LoggingMiddleware::from_config).
+    /// `OneIn(0)` is treated as `OneIn(1)` by `build` and rejected by
+    /// `try_build`. Default: requests are not logged.
+    #[must_use]
+    pub fn log_sampling(mut self, sampling: LogSampling) -> Self {
+        self.log_sampling = Some(sampling);
+        self
+    }
+
+    /// The request logging policy set with [`log_sampling`](Self::log_sampling).
+    pub(crate) fn log_sampling_policy(&self) -> Option<LogSampling> {
+        self.log_sampling
+    }
+
+    /// Set how many stdin chunks the STDIO reader thread may buffer ahead of
+    /// the runtime.
+    ///
+    /// STDIO reads run on a dedicated thread so a blocked stdin never stalls
+    /// the async runtime; this bounds the channel between them.
+    /// Default: 64.
+    #[must_use]
+    pub fn stdio_buffer_depth(mut self, depth: usize) -> Self {
+        self.stdio_buffer_depth = Some(depth);
+        self
+    }
+
+    /// Set how form elicitations are resolved when no client can answer them.
+    ///
+    /// [`ElicitationFallback::UseDefaults`] lets non-interactive servers
+    /// (automated runs, tests) answer their own elicitations from the schema's
+    /// default values instead of failing.
+    /// Default: [`ElicitationFallback::Fail`].
+    #[must_use]
+    pub fn elicitation_fallback(mut self, fallback: ElicitationFallback) -> Self {
+        self.elicitation_fallback = Some(fallback);
+        self
+    }
+
+    /// Register a hook that populates request context fields from headers.
+    ///
+    /// The HTTP transport runs every registered extractor, in order, for each
+    /// request before dispatching it to the handler.
+    #[must_use]
+    pub fn context_extractor(mut self, extractor: impl RequestContextExtractor) -> Self {
+        self.context_extractors.push(extractor);
+        self
+    }
+
+    /// Replace the registered context extractors.
+    #[must_use]
+    pub fn context_extractors(mut self, extractors: RequestContextExtractors) -> Self {
+        self.context_extractors = extractors;
+        self
+    }
+
+    /// Set how strictly the `jsonrpc` version field is checked.
+    ///
+    /// [`JsonRpcStrictness::Lenient`] accepts messages with a missing or wrong
+    /// version and treats them as JSON-RPC 2.0, for interop with
+    /// non-compliant clients.
+    /// Default: [`JsonRpcStrictness::Strict`].
+    #[must_use]
+    pub fn jsonrpc_strictness(mut self, strictness: JsonRpcStrictness) -> Self {
+        self.jsonrpc_strictness = Some(strictness);
+        self
+    }
+
+    /// Enable or disable HTTP response compression.
+    ///
+    /// When enabled, Streamable HTTP responses are encoded with gzip, zstd,
+    /// or br according to the request's `Accept-Encoding` header. SSE streams
+    /// are never compressed. This is independent of the transport-level
+    /// `compression` feature, which compresses message payloads.
+    /// Default: enabled.
+    #[must_use]
+    pub fn http_compression(mut self, enabled: bool) -> Self {
+        self.http_compression = Some(enabled);
+        self
+    }
+
+    /// Set how requests that reuse a request ID are handled.
+    ///
+    /// Default: [`DuplicateRequestIds::Reject`].
+    #[must_use]
+    pub fn duplicate_request_ids(mut self, policy: DuplicateRequestIds) -> Self {
+        self.duplicate_request_ids = Some(policy);
+        self
+    }
+
+    /// Register a hook that decides what `initialize` advertises per client.
+    ///
+    /// Hooks run in registration order on every successful handshake, after
+    /// the capabilities have been computed from the handler.
+    #[must_use]
+    pub fn initialize_hook(mut self, hook: impl InitializeHook) -> Self {
+        self.initialize_hooks.push(hook);
+        self
+    }
+
+    /// Replace the registered initialize hooks.
+    #[must_use]
+    pub fn initialize_hooks(mut self, hooks: InitializeHooks) -> Self {
+        self.initialize_hooks = hooks;
+        self
+    }
+
+    /// Set how many server-to-client requests may await a response on one
+    /// connection.
+    ///
+    /// A slow client could otherwise accumulate unbounded pending sampling
+    /// or elicitation requests. Once the limit is reached, new requests fail
+    /// fast with `ServerOverloaded`; requests already sent are unaffected.
+    /// Default: 64.
+    #[must_use]
+    pub fn max_pending_server_requests(mut self, max: usize) -> Self {
+        self.max_pending_server_requests = Some(max);
+        self
+    }
+
+    /// Set how long sampling and elicitation requests wait for the client.
+    ///
+    /// When the limit passes, `RequestContext::sample` and the elicitation
+    /// helpers return a `Timeout` error and the pending request is dropped.
+    /// `None`, the default, waits until the client answers or the request is
+    /// cancelled. Elicitation waits on a person filling in a form, so choose a
+    /// limit that leaves them time to answer.
+    #[must_use]
+    pub fn client_request_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.client_request_timeout = timeout;
+        self
+    }
+
+    /// Restrict which `_meta` keys incoming messages may carry.
+    ///
+    /// Default: every key is allowed.
+    #[must_use]
+    pub fn meta_allowlist(mut self, allowlist: MetaAllowlist) -> Self {
+        self.meta_allowlist = Some(allowlist);
+        self
+    }
+
+    /// Register initialized sessions with `broadcaster`.
+    ///
+    /// Keep a clone to push notifications to connected clients with
+    /// [`NotificationBroadcaster::broadcast`]. Default: a fresh broadcaster,
+    /// reachable as `config.notification_broadcaster`.
+    #[must_use]
+    pub fn notification_broadcaster(mut self, broadcaster: NotificationBroadcaster) -> Self {
+        self.notification_broadcaster = Some(broadcaster);
+        self
+    }
+
     /// Build the server configuration with sensible defaults.
     ///
     /// This method always succeeds and uses defaults for any unset fields.
@@ -224,6 +478,27 @@ impl ServerConfigBuilder {
             required_capabilities: self.required_capabilities.unwrap_or_default(),
             max_message_size: self.max_message_size.unwrap_or(DEFAULT_MAX_MESSAGE_SIZE),
             origin_validation: self.origin_validation.unwrap_or_default(),
+            log_sampling: self.log_sampling.map(|sampling| match sampling {
+                LogSampling::OneIn(0) => LogSampling::OneIn(1),
+                sampling => sampling,
+            }),
+            stdio_buffer_depth: self
+                .stdio_buffer_depth
+                .unwrap_or(DEFAULT_STDIO_BUFFER_DEPTH)
+                .max(1),
+            elicitation_fallback: self.elicitation_fallback.unwrap_or_default(),
+            context_extractors: self.context_extractors,
+            jsonrpc_strictness: self.jsonrpc_strictness.unwrap_or_default(),
+            http_compression: self.http_compression.unwrap_or(true),
+            duplicate_request_ids: self.duplicate_request_ids.unwrap_or_default(),
+            initialize_hooks: self.initialize_hooks,
+            max_pending_server_requests: self
+                .max_pending_server_requests
+                .unwrap_or(DEFAULT_MAX_PENDING_SERVER_REQUESTS)
+                .max(1),
+            client_request_timeout: self.client_request_timeout,
+            meta_allowlist: self.meta_allowlist.unwrap_or_default(),
+            notification_broadcaster: self.notification_broadcaster.unwrap_or_default(),
         }
     }
 
@@ -277,6 +552,27 @@ impl ServerConfigBuilder {
             }
         }
 
+        let log_sampling = self.log_sampling;
+        if log_sampling == Some(LogSampling::OneIn(0)) {
+            return Err(ConfigValidationError::InvalidLogSampling {
+                reason: "sampling rate cannot be 0".to_string(),
+            });
+        }
+
+        let stdio_buffer_depth = self
+            .stdio_buffer_depth
+            .unwrap_or(DEFAULT_STDIO_BUFFER_DEPTH);
+        if stdio_buffer_depth == 0 {
+            return Err(ConfigValidationError::InvalidStdioBufferDepth);
+        }
+
+        let max_pending_server_requests = self
+            .max_pending_server_requests
+            .unwrap_or(DEFAULT_MAX_PENDING_SERVER_REQUESTS);
+        if max_pending_server_requests == 0 {
+            return Err(ConfigValidationError::InvalidMaxPendingServerRequests);
+        }
+
         // Validate connection limits
         let connection_limits = self.connection_limits.unwrap_or_default();
         if connection_limits.max_tcp_connections == 0
@@ -296,6 +592,18 @@ impl ServerConfigBuilder {
             required_capabilities: self.required_capabilities.unwrap_or_default(),
             max_message_size,
             origin_validation: self.origin_validation.unwrap_or_default(),
+            log_sampling,
+            stdio_buffer_depth,
+            elicitation_fallback: self.elicitation_fallback.unwrap_or_default(),
+            context_extractors: self.context_extractors,
+            jsonrpc_strictness: self.jsonrpc_strictness.unwrap_or_default(),
+            http_compression: self.http_compression.unwrap_or(true),
+            duplicate_request_ids: self.duplicate_request_ids.unwrap_or_default(),
+            initialize_hooks: self.initialize_hooks,
+            max_pending_server_requests,
+            client_request_timeout: self.client_request_timeout,
+            meta_allowlist: self.meta_allowlist.unwrap_or_default(),
+            notification_broadcaster: self.notification_broadcaster.unwrap_or_default(),
         })
     }
 }
@@ -325,6 +633,21 @@ pub enum ConfigValidationError {
         /// Description of the validation failure.
         reason: String,
     },
+
+    /// Invalid STDIO buffer depth.
+    #[error("Invalid stdio_buffer_depth: must be at least 1")]
+    InvalidStdioBufferDepth,
+
+    /// Invalid limit on pending server-to-client requests.
+    #[error("Invalid max_pending_server_requests: must be at least 1")]
+    InvalidMaxPendingServerRequests,
+
+    /// Invalid log sampling configuration.
+    #[error("Invalid log sampling: {reason}")]
+    InvalidLogSampling {
+        /// Description of the validation failure.
+        reason: String,
+    },
 }
 
 /// Protocol version configuration.
@@ -409,7 +732,11 @@ impl ProtocolConfig {
 }
 
 /// Rate limiting configuration.
+///
+/// Start from [`RateLimitConfig::new`] (or `Default`) and pick the algorithm
+/// with [`strategy`](Self::strategy).
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct RateLimitConfig {
     /// Maximum requests per window.
     pub max_requests: u32,
@@ -417,6 +744,8 @@ pub struct RateLimitConfig {
     pub window: Duration,
     /// Whether to rate limit per client (by user_id or IP).
     pub per_client: bool,
+    /// Algorithm deciding whether a request fits in the limit.
+    pub(crate) strategy: RateLimitStrategy,
 }
 
 impl Default for RateLimitConfig {
@@ -425,6 +754,7 @@ impl Default for RateLimitConfig {
             max_requests: DEFAULT_RATE_LIMIT,
             window: DEFAULT_RATE_LIMIT_WINDOW,
             per_client: true,
+            strategy: RateLimitStrategy::default(),
         }
     }
 }
@@ -437,6 +767,7 @@ impl RateLimitConfig {
             max_requests,
             window,
             per_client: true,
+            strategy: RateLimitStrategy::default(),
         }
     }
 
@@ -446,10 +777,197 @@ impl RateLimitConfig {
         self.per_client = enabled;
         self
     }
+
+    /// Set the rate-limiting algorithm.
+    #[must_use]
+    pub fn strategy(mut self, strategy: RateLimitStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+}
+
+/// Builds a custom [`RateLimitAlgorithm`] from `(max_requests, window, now)`.
+pub type RateLimitAlgorithmFactory =
+    Arc<dyn Fn(u32, Duration, Instant) -> Box<dyn RateLimitAlgorithm> + Send + Sync>;
+
+/// Which algorithm a [`RateLimiter`] uses.
+#[derive(Clone, Default)]
+pub enum RateLimitStrategy {
+    /// [`TokenBucket`]: bursts up to `max_requests`, refilling over `window`.
+    #[default]
+    TokenBucket,
+    /// [`SlidingWindow`]: at most `max_requests` in any `window`-long span.
+    SlidingWindow,
+    /// A caller-supplied algorithm.
+    Custom(RateLimitAlgorithmFactory),
+}
+
+impl RateLimitStrategy {
+    /// Use a custom algorithm, built once per rate-limited client.
+    #[must_use]
+    pub fn custom<F, A>(factory: F) -> Self
+    where
+        F: Fn(u32, Duration, Instant) -> A + Send + Sync + 'static,
+        A: RateLimitAlgorithm + 'static,
+    {
+        Self::Custom(Arc::new(move |max_requests, window, now| {
+            Box::new(factory(max_requests, window, now))
+        }))
+    }
+
+    /// Create fresh algorithm state for one client.
+    #[must_use]
+    pub fn build(
+        &self,
+        max_requests: u32,
+        window: Duration,
+        now: Instant,
+    ) -> Box<dyn RateLimitAlgorithm> {
+        match self {
+            Self::TokenBucket => Box::new(TokenBucket::new(max_requests, window, now)),
+            Self::SlidingWindow => Box::new(SlidingWindow::new(max_requests, window)),
+            Self::Custom(factory) => factory(max_requests, window, now),
+        }
+    }
+}
+
+impl std::fmt::Debug for RateLimitStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TokenBucket => f.write_str("TokenBucket"),
+            Self::SlidingWindow => f.write_str("SlidingWindow"),
+            Self::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+/// Which requests [`LoggingMiddleware`](crate::LoggingMiddleware) logs.
+///
+/// Failed requests are always logged, whatever the policy.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LogSampling {
+    /// Log every request.
+    #[default]
+    All,
+    /// Log one in every N requests.
+    OneIn(u32),
+    /// Log only failed requests.
+    ErrorsOnly,
+    /// Log only requests that took longer than the threshold.
+    SlowerThan(Duration),
+}
+
+/// How a session handles a request whose ID the client has already used.
+///
+/// JSON-RPC requires request IDs to be unique within a session, and a reused
+/// ID makes responses and `notifications/cancelled` ambiguous.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DuplicateRequestIds {
+    /// Answer the duplicate with `INVALID_REQUEST` (-32600), including when
+    /// the earlier request has already completed.
+    #[default]
+    Reject,
+    /// Hold the duplicate until the earlier request with the same ID has
+    /// completed, then handle it. IDs may be reused once their request is
+    /// done, and each response is sent before the next one with its ID.
+    /// Cancellation targets the request currently running.
+    Queue,
+}
+
+/// What happens to a `_meta` key that is not on the [`MetaAllowlist`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DisallowedMeta {
+    /// Remove the key and handle the request without it.
+    #[default]
+    Drop,
+    /// Answer the request with `INVALID_PARAMS` (-32602). Notifications
+    /// carrying the key are discarded.
+    Reject,
+}
+
+/// Which `params._meta` keys incoming messages may carry.
+///
+/// Client `_meta` is passed to handlers as request metadata and drives
+/// features such as progress tokens, idempotency keys, and trace context.
+/// Restricting it keeps clients from injecting context a deployment does not
+/// expect. The default allows every key.
+///
+/// ```rust,ignore
+/// let config = ServerConfig::builder()
+///     .meta_allowlist(
+///         MetaAllowlist::only(["progressToken", "traceparent"]).on_disallowed(DisallowedMeta::Reject),
+///     )
+///     .build();
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MetaAllowlist {
+    /// Allowed keys; `None` allows every key.
+    keys: Option<HashSet<String>>,
+    on_disallowed: DisallowedMeta,
+}
+
+impl MetaAllowlist {
+    /// Allow every `_meta` key (the default).
+    #[must_use]
+    pub fn allow_all() -> Self {
+        Self::default()
+    }
+
+    /// Allow only `keys`; others are dropped unless
+    /// [`on_disallowed`](Self::on_disallowed) says otherwise.
+    #[must_use]
+    pub fn only(keys: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            keys: Some(keys.into_iter().map(Into::into).collect()),
+            on_disallowed: DisallowedMeta::default(),
+        }
+    }
+
+    /// Set what happens to keys that are not allowed.
+    #[must_use]
+    pub fn on_disallowed(mut self, policy: DisallowedMeta) -> Self {
+        self.on_disallowed = policy;
+        self
+    }
+
+    /// Whether `key` may be passed through.
+    #[must_use]
+    pub fn is_allowed(&self, key: &str) -> bool {
+        self.keys.as_ref().is_none_or(|keys| keys.contains(key))
+    }
+
+    /// Remove disallowed keys from `params._meta`, or fail if the policy is
+    /// [`DisallowedMeta::Reject`].
+    pub fn apply(&self, params: Option<&mut serde_json::Value>) -> Result<(), McpError> {
+        if self.keys.is_none() {
+            return Ok(());
+        }
+        let Some(serde_json::Value::Object(meta)) =
+            params.and_then(|params| params.get_mut("_meta"))
+        else {
+            return Ok(());
+        };
+        match self.on_disallowed {
+            DisallowedMeta::Drop => {
+                meta.retain(|key, _| self.is_allowed(key));
+                Ok(())
+            }
+            DisallowedMeta::Reject => match meta.keys().find(|key| !self.is_allowed(key)) {
+                Some(key) => Err(McpError::invalid_params(format!(
+                    "_meta key '{key}' is not allowed"
+                ))),
+                None => Ok(()),
+            },
+        }
+    }
 }
 
 /// Connection limits.
+///
+/// Start from [`ConnectionLimits::new`] or `Default` and adjust the other
+/// settings with the `with_*` setters.
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct ConnectionLimits {
     /// Maximum concurrent TCP connections.
     pub max_tcp_connections: usize,
@@ -459,6 +977,14 @@ pub struct ConnectionLimits {
     pub max_http_concurrent: usize,
     /// Maximum concurrent Unix socket connections.
     pub max_unix_connections: usize,
+    /// Set with [`with_max_connection_lifetime`](Self::with_max_connection_lifetime).
+    pub(crate) max_connection_lifetime: Option<Duration>,
+    /// Set with [`with_idle_timeout`](Self::with_idle_timeout).
+    pub(crate) idle_timeout: Option<Duration>,
+    /// Set with [`with_write_high_water_mark`](Self::with_write_high_water_mark).
+    pub(crate) write_high_water_mark: usize,
+    /// Set with [`with_slow_client_timeout`](Self::with_slow_client_timeout).
+    pub(crate) slow_client_timeout: Option<Duration>,
 }
 
 impl Default for ConnectionLimits {
@@ -468,6 +994,10 @@ impl Default for ConnectionLimits {
             max_websocket_connections: DEFAULT_MAX_CONNECTIONS,
             max_http_concurrent: DEFAULT_MAX_CONNECTIONS,
             max_unix_connections: DEFAULT_MAX_CONNECTIONS,
+            max_connection_lifetime: None,
+            idle_timeout: None,
+            write_high_water_mark: DEFAULT_WRITE_HIGH_WATER_MARK,
+            slow_client_timeout: Some(DEFAULT_SLOW_CLIENT_TIMEOUT),
         }
     }
 }
@@ -481,8 +1011,48 @@ impl ConnectionLimits {
             max_websocket_connections: max_connections,
             max_http_concurrent: max_connections,
             max_unix_connections: max_connections,
+            max_connection_lifetime: None,
+            idle_timeout: None,
+            write_high_water_mark: DEFAULT_WRITE_HIGH_WATER_MARK,
+            slow_client_timeout: Some(DEFAULT_SLOW_CLIENT_TIMEOUT),
         }
     }
+
+    /// Close TCP, Unix, and WebSocket connections `lifetime` after they were
+    /// accepted, so long-lived clients reconnect and free their resources.
+    #[must_use]
+    pub fn with_max_connection_lifetime(mut self, lifetime: Option<Duration>) -> Self {
+        self.max_connection_lifetime = lifetime;
+        self
+    }
+
+    /// Close TCP, Unix, and WebSocket connections that go `timeout` without
+    /// sending or receiving a message. Time spent serving an in-flight
+    /// request does not count as idle.
+    #[must_use]
+    pub fn with_idle_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.idle_timeout = timeout;
+        self
+    }
+
+    /// Queue up to `high_water_mark` outbound messages per connection before
+    /// notifications and server-to-client requests wait for the client to
+    /// read. Applies to STDIO, TCP, Unix, and HTTP SSE streams; values below
+    /// 1 are treated as 1.
+    #[must_use]
+    pub fn with_write_high_water_mark(mut self, high_water_mark: usize) -> Self {
+        self.write_high_water_mark = high_water_mark;
+        self
+    }
+
+    /// Close a connection whose pending write makes no progress for
+    /// `timeout`, so a client that stops reading cannot pin server memory or
+    /// stall its notifiers forever. `None` waits indefinitely.
+    #[must_use]
+    pub fn with_slow_client_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.slow_client_timeout = timeout;
+        self
+    }
 }
 
 /// Required client capabilities.
@@ -638,14 +1208,14 @@ impl CapabilityValidation {
     }
 }
 
-/// Rate limiter using token bucket algorithm.
+/// Rate limiter applying the configured [`RateLimitStrategy`].
 #[derive(Debug)]
 pub struct RateLimiter {
     config: RateLimitConfig,
-    /// Global bucket for non-per-client limiting.
-    global_bucket: Mutex<TokenBucket>,
-    /// Per-client buckets (keyed by client ID).
-    client_buckets: Mutex<std::collections::HashMap<String, TokenBucket>>,
+    /// Global state for non-per-client limiting.
+    global_bucket: Mutex<ClientLimit>,
+    /// Per-client state (keyed by client ID).
+    client_buckets: Mutex<std::collections::HashMap<String, ClientLimit>>,
     /// Last cleanup timestamp for automatic cleanup.
     last_cleanup: Mutex<Instant>,
 }
@@ -654,10 +1224,11 @@ impl RateLimiter {
     /// Create a new rate limiter.
     #[must_use]
     pub fn new(config: RateLimitConfig) -> Self {
+        let now = Instant::now();
         Self {
-            global_bucket: Mutex::new(TokenBucket::new(config.max_requests, config.window)),
+            global_bucket: Mutex::new(ClientLimit::new(&config, now)),
             client_buckets: Mutex::new(std::collections::HashMap::new()),
-            last_cleanup: Mutex::new(Instant::now()),
+            last_cleanup: Mutex::new(now),
             config,
         }
     }
@@ -676,19 +1247,20 @@ impl RateLimiter {
             *self.last_cleanup.lock() = Instant::now();
         }
 
+        let now = Instant::now();
         if self.config.per_client {
             if let Some(id) = client_id {
                 let mut buckets = self.client_buckets.lock();
-                let bucket = buckets.entry(id.to_string()).or_insert_with(|| {
-                    TokenBucket::new(self.config.max_requests, self.config.window)
-                });
-                bucket.try_acquire()
+                let bucket = buckets
+                    .entry(id.to_string())
+                    .or_insert_with(|| ClientLimit::new(&self.config, now));
+                bucket.try_acquire(now)
             } else {
                 // No client ID, use global bucket
-                self.global_bucket.lock().try_acquire()
+                self.global_bucket.lock().try_acquire(now)
             }
         } else {
-            self.global_bucket.lock().try_acquire()
+            self.global_bucket.lock().try_acquire(now)
         }
     }
 
@@ -706,32 +1278,69 @@ impl RateLimiter {
     }
 }
 
-/// Token bucket for rate limiting.
+/// One client's rate-limit state and when it was last used.
 #[derive(Debug)]
-struct TokenBucket {
+struct ClientLimit {
+    algorithm: Box<dyn RateLimitAlgorithm>,
+    last_access: Instant,
+}
+
+impl ClientLimit {
+    fn new(config: &RateLimitConfig, now: Instant) -> Self {
+        Self {
+            algorithm: config
+                .strategy
+                .build(config.max_requests, config.window, now),
+            last_access: now,
+        }
+    }
+
+    fn try_acquire(&mut self, now: Instant) -> bool {
+        self.last_access = now;
+        self.algorithm.try_acquire(now)
+    }
+}
+
+/// Per-client state of a rate-limiting algorithm.
+///
+/// [`RateLimiter`] keeps one instance per client (or one global instance)
+/// and asks it whether each incoming request fits. Implement this to plug a
+/// custom algorithm in through [`RateLimitStrategy::custom`].
+pub trait RateLimitAlgorithm: Send + std::fmt::Debug {
+    /// Record a request arriving at `now`, returning whether it is allowed.
+    fn try_acquire(&mut self, now: Instant) -> bool;
+}
+
+/// Token bucket: holds up to `max_requests` tokens and refills at
+/// `max_requests / window`.
+///
+/// Allows a full burst after an idle period, then a steady trickle.
+#[derive(Debug)]
+pub struct TokenBucket {
     tokens: f64,
     max_tokens: f64,
     refill_rate: f64, // tokens per second
     last_refill: Instant,
-    last_access: Instant,
 }
 
 impl TokenBucket {
-    fn new(max_requests: u32, window: Duration) -> Self {
+    /// Create a full bucket.
+    #[must_use]
+    pub fn new(max_requests: u32, window: Duration, now: Instant) -> Self {
         let max_tokens = max_requests as f64;
         let refill_rate = max_tokens / window.as_secs_f64();
         Self {
             tokens: max_tokens,
             max_tokens,
             refill_rate,
-            last_refill: Instant::now(),
-            last_access: Instant::now(),
+            last_refill: now,
         }
     }
+}
 
-    fn try_acquire(&mut self) -> bool {
-        let now = Instant::now();
-        let elapsed = now.duration_since(self.last_refill);
+impl RateLimitAlgorithm for TokenBucket {
+    fn try_acquire(&mut self, now: Instant) -> bool {
+        let elapsed = now.saturating_duration_since(self.last_refill);
 
         // Only refill if meaningful time has passed (reduces syscalls on burst traffic)
         if elapsed >= Duration::from_millis(10) {
@@ -740,8 +1349,6 @@ impl TokenBucket {
             self.last_refill = now;
         }
 
-        self.last_access = now;
-
         if self.tokens >= 1.0 {
             self.tokens -= 1.0;
             true
@@ -751,6 +1358,49 @@ impl TokenBucket {
     }
 }
 
+/// Sliding window: allows at most `max_requests` in any `window`-long span.
+///
+/// Stricter than [`TokenBucket`] right after a burst, since nothing frees up
+/// until the burst ages out of the window. Keeps one timestamp per allowed
+/// request still inside the window.
+#[derive(Debug)]
+pub struct SlidingWindow {
+    max_requests: usize,
+    window: Duration,
+    allowed: std::collections::VecDeque<Instant>,
+}
+
+impl SlidingWindow {
+    /// Create an empty window.
+    #[must_use]
+    pub fn new(max_requests: u32, window: Duration) -> Self {
+        Self {
+            max_requests: max_requests as usize,
+            window,
+            allowed: std::collections::VecDeque::new(),
+        }
+    }
+}
+
+impl RateLimitAlgorithm for SlidingWindow {
+    fn try_acquire(&mut self, now: Instant) -> bool {
+        while self
+            .allowed
+            .front()
+            .is_some_and(|&at| now.saturating_duration_since(at) >= self.window)
+        {
+            self.allowed.pop_front();
+        }
+
+        if self.allowed.len() < self.max_requests {
+            self.allowed.push_back(now);
+            true
+        } else {
+            false
+        }
+    }
+}
+
 /// Connection counter for tracking active connections.
 ///
 /// This is designed to be wrapped in `Arc` and shared across async tasks.
@@ -950,6 +1600,70 @@ mod tests {
         assert!(!limiter.check(None)); // Should be rate limited
     }
 
+    /// Outcomes of requests at the given offsets (ms) for one strategy.
+    fn rate_limit_outcomes(strategy: &RateLimitStrategy, offsets_ms: &[u64]) -> Vec<bool> {
+        let start = Instant::now();
+        let mut algorithm = strategy.build(2, Duration::from_secs(1), start);
+        offsets_ms
+            .iter()
+            .map(|&ms| algorithm.try_acquire(start + Duration::from_millis(ms)))
+            .collect()
+    }
+
+    #[test]
+    fn test_sliding_window_rejects_burst_token_bucket_allows() {
+        // Half a window after a full burst the bucket has refilled one token,
+        // but both burst requests are still inside the sliding window.
+        let offsets = [0, 0, 500];
+        assert_eq!(
+            rate_limit_outcomes(&RateLimitStrategy::TokenBucket, &offsets),
+            [true, true, true]
+        );
+        assert_eq!(
+            rate_limit_outcomes(&RateLimitStrategy::SlidingWindow, &offsets),
+            [true, true, false]
+        );
+    }
+
+    #[test]
+    fn test_token_bucket_rejects_burst_sliding_window_allows() {
+        // The bucket spends its refill on the 600ms burst and is short a
+        // token at 1000ms, when the 0ms request has left the sliding window.
+        let offsets = [0, 600, 600, 1000];
+        assert_eq!(
+            rate_limit_outcomes(&RateLimitStrategy::TokenBucket, &offsets),
+            [true, true, true, false]
+        );
+        assert_eq!(
+            rate_limit_outcomes(&RateLimitStrategy::SlidingWindow, &offsets),
+            [true, true, false, true]
+        );
+    }
+
+    #[test]
+    fn test_rate_limiter_uses_configured_strategy() {
+        #[derive(Debug)]
+        struct DenyAll;
+
+        impl RateLimitAlgorithm for DenyAll {
+            fn try_acquire(&mut self, _now: Instant) -> bool {
+                false
+            }
+        }
+
+        let config = RateLimitConfig::new(2, Duration::from_secs(1))
+            .strategy(RateLimitStrategy::custom(|_, _, _| DenyAll));
+        assert!(!RateLimiter::new(config).check(Some("client")));
+
+        let config = RateLimitConfig::new(2, Duration::from_secs(60))
+            .strategy(RateLimitStrategy::SlidingWindow);
+        let limiter = RateLimiter::new(config);
+        assert!(limiter.check(Some("a")));
+        assert!(limiter.check(Some("a")));
+        assert!(!limiter.check(Some("a")));
+        assert!(limiter.check(Some("b")));
+    }
+
     #[test]
     fn test_connection_counter() {
         let counter = Arc::new(ConnectionCounter::new(2));
@@ -985,6 +1699,107 @@ mod tests {
         assert!(config.origin_validation.allowed_origins.is_empty());
     }
 
+    #[test]
+    fn test_jsonrpc_strictness_defaults_to_strict() {
+        assert_eq!(
+            ServerConfig::default().jsonrpc_strictness,
+            JsonRpcStrictness::Strict
+        );
+        let config = ServerConfig::builder()
+            .jsonrpc_strictness(JsonRpcStrictness::Lenient)
+            .try_build()
+            .unwrap();
+        assert_eq!(config.jsonrpc_strictness, JsonRpcStrictness::Lenient);
+    }
+
+    #[test]
+    fn test_duplicate_request_ids_defaults_to_reject() {
+        assert_eq!(
+            ServerConfig::default().duplicate_request_ids,
+            DuplicateRequestIds::Reject
+        );
+        let config = ServerConfig::builder()
+            .duplicate_request_ids(DuplicateRequestIds::Queue)
+            .try_build()
+            .unwrap();
+        assert_eq!(config.duplicate_request_ids, DuplicateRequestIds::Queue);
+    }
+
+    #[test]
+    fn test_meta_allowlist_strips_disallowed_keys() {
+        let mut params = serde_json::json!({
+            "name": "lookup",
+            "_meta": { "progressToken": 7, "x-injected": "admin" }
+        });
+        MetaAllowlist::allow_all().apply(Some(&mut params)).unwrap();
+        assert_eq!(params["_meta"]["x-injected"], "admin");
+
+        let allowlist = MetaAllowlist::only(["progressToken"]);
+        allowlist.apply(Some(&mut params)).unwrap();
+        assert_eq!(params["_meta"], serde_json::json!({ "progressToken": 7 }));
+        assert_eq!(params["name"], "lookup");
+
+        let mut params = serde_json::json!({ "_meta": { "x-injected": "admin" } });
+        let err = allowlist
+            .on_disallowed(DisallowedMeta::Reject)
+            .apply(Some(&mut params))
+            .unwrap_err();
+        assert!(err.message.contains("x-injected"), "{}", err.message);
+    }
+
+    #[test]
+    fn test_max_pending_server_requests_must_be_positive() {
+        assert_eq!(
+            ServerConfig::default().max_pending_server_requests,
+            DEFAULT_MAX_PENDING_SERVER_REQUESTS
+        );
+        assert!(matches!(
+            ServerConfig::builder()
+                .max_pending_server_requests(0)
+                .try_build(),
+            Err(ConfigValidationError::InvalidMaxPendingServerRequests)
+        ));
+    }
+
+    #[test]
+    fn test_log_sampling_one_in_zero() {
+        let config = ServerConfig::builder()
+            .log_sampling(LogSampling::OneIn(0))
+            .build();
+        assert_eq!(config.log_sampling, Some(LogSampling::OneIn(1)));
+        assert!(matches!(
+            ServerConfig::builder()
+                .log_sampling(LogSampling::OneIn(0))
+                .try_build(),
+            Err(ConfigValidationError::InvalidLogSampling { .. })
+        ));
+        assert_eq!(ServerConfig::default().log_sampling, None);
+    }
+
+    #[test]
+    fn test_client_request_timeout_defaults_and_overrides() {
+        assert_eq!(ServerConfig::default().client_request_timeout, None);
+        assert_eq!(ServerConfig::builder().build().client_request_timeout, None);
+        let config = ServerConfig::builder()
+            .client_request_timeout(Some(Duration::from_secs(5)))
+            .try_build()
+            .unwrap();
+        assert_eq!(config.client_request_timeout, Some(Duration::from_secs(5)));
+        let config = ServerConfig::builder().client_request_timeout(None).build();
+        assert_eq!(config.client_request_timeout, None);
+    }
+
+    #[test]
+    fn test_http_compression_defaults_to_enabled() {
+        assert!(ServerConfig::default().http_compression);
+        assert!(ServerConfig::builder().build().http_compression);
+        let config = ServerConfig::builder()
+            .http_compression(false)
+            .try_build()
+            .unwrap();
+        assert!(!config.http_compression);
+    }
+
     #[test]
     fn test_builder_origin_validation_overrides() {
         let config = ServerConfig::builder()
@@ -1024,11 +1839,7 @@ mod tests {
     #[test]
     fn test_builder_try_build_invalid_rate_limit() {
         let result = ServerConfig::builder()
-            .rate_limit(RateLimitConfig {
-                max_requests: 0, // Invalid
-                window: Duration::from_secs(1),
-                per_client: true,
-            })
+            .rate_limit(RateLimitConfig::new(0, Duration::from_secs(1))) // Invalid
             .try_build();
         assert!(result.is_err());
         assert!(matches!(
@@ -1040,11 +1851,7 @@ mod tests {
     #[test]
     fn test_builder_try_build_zero_window() {
         let result = ServerConfig::builder()
-            .rate_limit(RateLimitConfig {
-                max_requests: 100,
-                window: Duration::ZERO, // Invalid
-                per_client: true,
-            })
+            .rate_limit(RateLimitConfig::new(100, Duration::ZERO)) // Invalid
             .try_build();
         assert!(result.is_err());
         assert!(matches!(
@@ -1053,6 +1860,26 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_builder_connection_timeouts() {
+        let config = ServerConfig::builder()
+            .idle_timeout(Duration::from_secs(30))
+            .max_connection_lifetime(Duration::from_secs(3600))
+            .build();
+        assert_eq!(
+            config.connection_limits.idle_timeout,
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            config.connection_limits.max_connection_lifetime,
+            Some(Duration::from_secs(3600))
+        );
+        assert_eq!(
+            config.connection_limits.max_tcp_connections,
+            DEFAULT_MAX_CONNECTIONS
+        );
+    }
+
     #[test]
     fn test_builder_try_build_invalid_connection_limits() {
         let result = ServerConfig::builder()
@@ -1061,6 +1888,7 @@ mod tests {
                 max_websocket_connections: 0,
                 max_http_concurrent: 0,
                 max_unix_connections: 0,
+                ..Default::default()
             })
             .try_build();
         assert!(result.is_err());
@@ -10,15 +10,96 @@
 //! The `Cancellable` blanket impl for `tokio_util::sync::CancellationToken`
 //! lives in `turbomcp-core` (gated on the `std` feature) so the orphan rule
 //! doesn't force us into a newtype wrapper here.
+//!
+//! [`RequestContextExtractor`] is the server's hook for copying
+//! deployment-specific request data (request ids, locale, feature flags) from
+//! transport headers into the context before the handler runs.
+
+use std::fmt;
+use std::sync::Arc;
 
-pub use turbomcp_core::context::{RequestContext, TransportType};
+pub use turbomcp_core::context::{ElicitationFallback, RequestContext, TransportType};
 #[allow(unused_imports)]
 pub use turbomcp_core::session::{Cancellable, McpSession, SessionFuture};
 
+/// Populates custom fields of a [`RequestContext`] from the incoming request.
+///
+/// Extractors run once per request after the transport has filled in the
+/// built-in fields, including [`RequestContext::headers`] for HTTP. Read
+/// headers with [`RequestContext::header`] and store values with
+/// [`RequestContext::insert_metadata`]; handlers then read them back with
+/// [`RequestContext::get_metadata`].
+///
+/// Closures of type `Fn(&mut RequestContext)` implement this trait.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let config = ServerConfig::builder()
+///     .context_extractor(|ctx: &mut RequestContext| {
+///         if let Some(id) = ctx.header("x-request-id").map(str::to_owned) {
+///             ctx.insert_metadata("request_id", id);
+///         }
+///     })
+///     .build();
+/// ```
+pub trait RequestContextExtractor: Send + Sync + 'static {
+    /// Copy request data into `ctx`.
+    fn extract(&self, ctx: &mut RequestContext);
+}
+
+impl<F> RequestContextExtractor for F
+where
+    F: Fn(&mut RequestContext) + Send + Sync + 'static,
+{
+    fn extract(&self, ctx: &mut RequestContext) {
+        self(ctx);
+    }
+}
+
+/// Ordered set of [`RequestContextExtractor`]s, applied first to last.
+#[derive(Clone, Default)]
+pub struct RequestContextExtractors {
+    extractors: Vec<Arc<dyn RequestContextExtractor>>,
+}
+
+impl RequestContextExtractors {
+    /// Append an extractor; it runs after the ones already registered.
+    pub fn push(&mut self, extractor: impl RequestContextExtractor) {
+        self.extractors.push(Arc::new(extractor));
+    }
+
+    /// Whether no extractors are registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.extractors.is_empty()
+    }
+
+    /// Number of registered extractors.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.extractors.len()
+    }
+
+    /// Run every extractor against `ctx`.
+    pub fn apply(&self, ctx: &mut RequestContext) {
+        for extractor in &self.extractors {
+            extractor.extract(ctx);
+        }
+    }
+}
+
+impl fmt::Debug for RequestContextExtractors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RequestContextExtractors")
+            .field("len", &self.extractors.len())
+            .finish()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::sync::Arc;
     use tokio_util::sync::CancellationToken;
 
     #[test]
@@ -0,0 +1,234 @@
+//! Startup diagnostics.
+//!
+//! [`ServerDiagnostics`] is a structured snapshot of what a server will expose
+//! once it starts serving: registered components, advertised capabilities,
+//! the selected transport, compiled-in transport features, and the active
+//! configuration limits. It is `Serialize`, so applications can log it as JSON
+//! or expose it from an admin endpoint.
+//!
+//! ```rust,ignore
+//! use turbomcp::prelude::*;
+//!
+//! let builder = MyServer.builder().transport(Transport::http("0.0.0.0:8080"));
+//! tracing::info!(diagnostics = %serde_json::to_string(&builder.diagnostics())?, "starting");
+//! builder.serve().await?;
+//! ```
+
+use serde::Serialize;
+use turbomcp_core::handler::McpHandler;
+use turbomcp_types::ServerCapabilities;
+
+use super::builder::Transport;
+use super::config::{RequiredCapabilities, ServerConfig};
+
+/// Structured summary of a configured server.
+#[derive(Debug, Clone, Serialize)]
+pub struct ServerDiagnostics {
+    /// Server name from `server_info()`.
+    pub name: String,
+    /// Server version from `server_info()`.
+    pub version: String,
+    /// Names of registered tools.
+    pub tools: Vec<String>,
+    /// URIs of registered resources.
+    pub resources: Vec<String>,
+    /// URI templates of registered resource templates.
+    pub resource_templates: Vec<String>,
+    /// Names of registered prompts.
+    pub prompts: Vec<String>,
+    /// Capabilities advertised during `initialize`.
+    pub capabilities: ServerCapabilities,
+    /// The transport the server will run on (e.g. `"stdio"`, `"http"`).
+    pub transport: &'static str,
+    /// Transport features compiled into this build.
+    pub transport_features: Vec<&'static str>,
+    /// Protocol versions the server accepts.
+    pub protocol_versions: Vec<String>,
+    /// Client capabilities the server requires, including `roots`.
+    pub required_capabilities: RequiredCapabilities,
+    /// Whether request rate limiting is configured.
+    pub rate_limited: bool,
+    /// Maximum accepted message size in bytes.
+    pub max_message_size: usize,
+}
+
+impl ServerDiagnostics {
+    /// Collect diagnostics from a handler, its transport, and its configuration.
+    pub fn collect<H: McpHandler>(
+        handler: &H,
+        transport: &Transport,
+        config: &ServerConfig,
+    ) -> Self {
+        let info = handler.server_info();
+
+        Self {
+            name: info.name,
+            version: info.version,
+            tools: handler.list_tools().into_iter().map(|t| t.name).collect(),
+            resources: handler
+                .list_resources()
+                .into_iter()
+                .map(|r| r.uri)
+                .collect(),
+            resource_templates: handler
+                .list_resource_templates()
+                .into_iter()
+                .map(|t| t.uri_template)
+                .collect(),
+            prompts: handler.list_prompts().into_iter().map(|p| p.name).collect(),
+            capabilities: handler.server_capabilities(),
+            transport: transport.name(),
+            transport_features: enabled_transport_features(),
+            protocol_versions: config
+                .protocol
+                .supported_versions
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            required_capabilities: config.required_capabilities.clone(),
+            rate_limited: config.rate_limit.is_some(),
+            max_message_size: config.max_message_size,
+        }
+    }
+
+    /// Number of registered tools.
+    #[must_use]
+    pub fn tool_count(&self) -> usize {
+        self.tools.len()
+    }
+
+    /// Number of registered resources.
+    #[must_use]
+    pub fn resource_count(&self) -> usize {
+        self.resources.len()
+    }
+
+    /// Number of registered prompts.
+    #[must_use]
+    pub fn prompt_count(&self) -> usize {
+        self.prompts.len()
+    }
+}
+
+fn enabled_transport_features() -> Vec<&'static str> {
+    let mut features = Vec::new();
+    if cfg!(feature = "stdio") {
+        features.push("stdio");
+    }
+    if cfg!(feature = "http") {
+        features.push("http");
+    }
+    if cfg!(feature = "websocket") {
+        features.push("websocket");
+    }
+    if cfg!(feature = "tcp") {
+        features.push("tcp");
+    }
+    if cfg!(all(feature = "unix", unix)) {
+        features.push("unix");
+    }
+    if cfg!(feature = "channel") {
+        features.push("channel");
+    }
+    features
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::McpServerExt;
+    use serde_json::Value;
+    use turbomcp_core::context::RequestContext;
+    use turbomcp_core::error::{McpError, McpResult};
+    use turbomcp_types::{
+        Prompt, PromptResult, Resource, ResourceResult, ServerInfo, Tool, ToolResult,
+    };
+
+    #[derive(Clone)]
+    struct DiagnosticsHandler;
+
+    #[allow(clippy::manual_async_fn)]
+    impl McpHandler for DiagnosticsHandler {
+        fn server_info(&self) -> ServerInfo {
+            ServerInfo::new("diagnostics-test", "2.0.0")
+        }
+
+        fn list_tools(&self) -> Vec<Tool> {
+            vec![Tool::new("add", "Add"), Tool::new("subtract", "Subtract")]
+        }
+
+        fn list_resources(&self) -> Vec<Resource> {
+            vec![Resource::new("file:///config.json", "config")]
+        }
+
+        fn list_prompts(&self) -> Vec<Prompt> {
+            vec![]
+        }
+
+        fn call_tool<'a>(
+            &'a self,
+            _name: &'a str,
+            _args: Value,
+            _ctx: &'a RequestContext,
+        ) -> impl std::future::Future<Output = McpResult<ToolResult>> + Send + 'a {
+            async { Ok(ToolResult::text("ok")) }
+        }
+
+        fn read_resource<'a>(
+            &'a self,
+            uri: &'a str,
+            _ctx: &'a RequestContext,
+        ) -> impl std::future::Future<Output = McpResult<ResourceResult>> + Send + 'a {
+            let uri = uri.to_string();
+            async move { Err(McpError::resource_not_found(&uri)) }
+        }
+
+        fn get_prompt<'a>(
+            &'a self,
+            name: &'a str,
+            _args: Option<Value>,
+            _ctx: &'a RequestContext,
+        ) -> impl std::future::Future<Output = McpResult<PromptResult>> + Send + 'a {
+            let name = name.to_string();
+            async move { Err(McpError::prompt_not_found(&name)) }
+        }
+    }
+
+    #[test]
+    fn test_diagnostics_reflect_registered_components() {
+        let diagnostics = DiagnosticsHandler
+            .builder()
+            .with_rate_limit(10, std::time::Duration::from_secs(1))
+            .diagnostics();
+
+        assert_eq!(diagnostics.name, "diagnostics-test");
+        assert_eq!(diagnostics.tool_count(), 2);
+        assert_eq!(diagnostics.tools, vec!["add", "subtract"]);
+        assert_eq!(diagnostics.resource_count(), 1);
+        assert_eq!(diagnostics.resources, vec!["file:///config.json"]);
+        assert_eq!(diagnostics.prompt_count(), 0);
+        assert_eq!(diagnostics.transport, "stdio");
+        assert!(diagnostics.capabilities.tools.is_some());
+        assert!(diagnostics.capabilities.resources.is_some());
+        assert!(diagnostics.rate_limited);
+
+        let json = serde_json::to_value(&diagnostics).unwrap();
+        assert_eq!(json["tools"].as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_diagnostics_include_builder_resources() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::write(root.path().join("guide.md"), "# Guide").unwrap();
+
+        let diagnostics = DiagnosticsHandler
+            .builder()
+            .resource_dir("docs://", root.path(), crate::ResourceDirOptions::new())
+            .diagnostics();
+
+        assert_eq!(
+            diagnostics.resources,
+            vec!["file:///config.json", "docs://guide.md"]
+        );
+    }
+}
@@ -0,0 +1,174 @@
+//! Coordinated drain of in-flight requests across transports.
+//!
+//! [`ServerBuilder::serve_multi`](crate::ServerBuilder::serve_multi) counts
+//! the requests each transport is handling in a shared [`DrainCoordinator`].
+//! On shutdown it stops admitting new requests, waits until every transport
+//! is idle or the graceful-shutdown timeout elapses, and only then stops the
+//! transports, each of which writes its remaining responses before it
+//! returns. Requests that arrive while draining are refused with
+//! `ServerOverloaded` (`-32010`).
+
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::Duration;
+
+use parking_lot::Mutex;
+use tokio::sync::Notify;
+use turbomcp_core::error::{ErrorKind, McpError, McpResult};
+
+/// Tracks in-flight requests per transport and waits for them on shutdown.
+///
+/// Cloning is cheap; every clone shares the same counters.
+#[derive(Clone, Default)]
+pub struct DrainCoordinator {
+    inner: Arc<DrainInner>,
+}
+
+#[derive(Default)]
+struct DrainInner {
+    transports: Mutex<Vec<(&'static str, Arc<AtomicUsize>)>>,
+    draining: AtomicBool,
+    idle: Notify,
+}
+
+impl DrainCoordinator {
+    /// Create a coordinator with no tracked transports.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a counter for `transport` and return it.
+    pub(crate) fn track(&self, transport: &'static str) -> Arc<AtomicUsize> {
+        let counter = Arc::new(AtomicUsize::new(0));
+        self.inner
+            .transports
+            .lock()
+            .push((transport, Arc::clone(&counter)));
+        counter
+    }
+
+    /// Whether a drain has started; new requests are refused once it has.
+    #[must_use]
+    pub fn is_draining(&self) -> bool {
+        self.inner.draining.load(Ordering::SeqCst)
+    }
+
+    /// Snapshot of the requests each transport is currently handling.
+    #[must_use]
+    pub fn in_flight(&self) -> InFlightReport {
+        let transports = self
+            .inner
+            .transports
+            .lock()
+            .iter()
+            .map(|(name, counter)| (*name, counter.load(Ordering::SeqCst)))
+            .collect();
+        InFlightReport { transports }
+    }
+
+    /// Stop admitting requests and wait up to `timeout` for every transport
+    /// to finish the requests it is handling.
+    ///
+    /// Returns the requests still in flight when the wait ended, which total
+    /// zero if every transport drained in time.
+    pub async fn drain(&self, timeout: Duration) -> InFlightReport {
+        self.inner.draining.store(true, Ordering::SeqCst);
+        let idle = async {
+            loop {
+                let notified = self.inner.idle.notified();
+                tokio::pin!(notified);
+                notified.as_mut().enable();
+                if self.in_flight().total() == 0 {
+                    break;
+                }
+                notified.await;
+            }
+        };
+        let _ = tokio::time::timeout(timeout, idle).await;
+        self.in_flight()
+    }
+
+    /// Count a request on `counter`, or refuse it if draining has started.
+    fn admit(&self, counter: &Arc<AtomicUsize>) -> McpResult<InFlightGuard> {
+        counter.fetch_add(1, Ordering::SeqCst);
+        let guard = InFlightGuard {
+            counter: Arc::clone(counter),
+            inner: Arc::clone(&self.inner),
+        };
+        if self.is_draining() {
+            return Err(McpError::new(
+                ErrorKind::ServerOverloaded,
+                "Server is shutting down",
+            ));
+        }
+        Ok(guard)
+    }
+}
+
+impl fmt::Debug for DrainCoordinator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DrainCoordinator")
+            .field("in_flight", &self.in_flight())
+            .field("draining", &self.is_draining())
+            .finish()
+    }
+}
+
+/// Requests in flight on each transport, as reported by a
+/// [`DrainCoordinator`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InFlightReport {
+    transports: Vec<(&'static str, usize)>,
+}
+
+impl InFlightReport {
+    /// Requests in flight across all transports.
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.transports.iter().map(|(_, count)| count).sum()
+    }
+
+    /// `(transport name, requests in flight)` for each transport.
+    pub fn by_transport(&self) -> impl Iterator<Item = (&'static str, usize)> + '_ {
+        self.transports.iter().copied()
+    }
+}
+
+/// Releases one in-flight slot when the request finishes.
+pub(crate) struct InFlightGuard {
+    counter: Arc<AtomicUsize>,
+    inner: Arc<DrainInner>,
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        if self.counter.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.inner.idle.notify_waiters();
+        }
+    }
+}
+
+/// One transport's in-flight counter in a [`DrainCoordinator`].
+#[derive(Debug, Clone)]
+pub(crate) struct DrainTracker {
+    coordinator: DrainCoordinator,
+    counter: Arc<AtomicUsize>,
+}
+
+impl DrainTracker {
+    /// Count requests under `transport` in `coordinator`.
+    pub(crate) fn new(coordinator: &DrainCoordinator, transport: &'static str) -> Self {
+        Self {
+            counter: coordinator.track(transport),
+            coordinator: coordinator.clone(),
+        }
+    }
+
+    /// Count a request until the returned guard is dropped, or refuse it if
+    /// draining has started.
+    pub(crate) fn admit(&self) -> McpResult<InFlightGuard> {
+        self.coordinator.admit(&self.counter)
+    }
+}
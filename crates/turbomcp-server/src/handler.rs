@@ -156,6 +156,12 @@ pub trait McpHandlerExt: McpHandler {
         request: Value,
         ctx: RequestContext,
     ) -> impl Future<Output = McpResult<Value>> + Send;
+
+    /// Describe every registered tool, resource, resource template, and
+    /// prompt, with schemas, annotations, tags, and required scopes.
+    ///
+    /// See [`ComponentCatalog`](crate::ComponentCatalog).
+    fn describe_all(&self) -> crate::ComponentCatalog;
 }
 
 /// Blanket implementation of McpHandlerExt for all McpHandler types.
@@ -217,6 +223,10 @@ impl<T: McpHandler> McpHandlerExt for T {
                 .map_err(|e| McpError::internal(format!("Failed to serialize response: {e}")))
         }
     }
+
+    fn describe_all(&self) -> crate::ComponentCatalog {
+        crate::ComponentCatalog::collect(self)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,340 @@
+//! Aggregated health endpoint.
+//!
+//! [`HealthAggregator`] combines transport health, application probes, and
+//! session-store availability into one report and serves it as `GET /healthz`.
+//! The endpoint answers `200 OK` when every component is healthy and
+//! `503 Service Unavailable` otherwise; the JSON body lists each component so
+//! the failing one can be identified without reading logs.
+//!
+//! Merge the health router next to the MCP router:
+//!
+//! ```rust,ignore
+//! use turbomcp_server::HealthAggregator;
+//! use turbomcp_transport::HealthStatus;
+//!
+//! let health = HealthAggregator::new()
+//!     .transport("http", move || {
+//!         let transport = transport.clone();
+//!         async move { transport.get_health_info().await.status }
+//!     })
+//!     .session_store("redis", move || {
+//!         let redis = redis.clone();
+//!         async move { redis.ping().await.map_err(|e| e.to_string()) }
+//!     })
+//!     .probe("database", || async { Ok(()) });
+//!
+//! let app = MyServer.builder().into_axum_router().merge(health.router());
+//! ```
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::Router;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use serde::Serialize;
+use turbomcp_transport::HealthStatus;
+
+/// Default time a single component check may take before it counts as failed
+pub const DEFAULT_PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+type ProbeFuture = Pin<Box<dyn Future<Output = Result<(), String>> + Send>>;
+type ProbeFn = Arc<dyn Fn() -> ProbeFuture + Send + Sync>;
+
+/// What a health component checks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ComponentKind {
+    /// Transport connection health
+    Transport,
+    /// Session storage availability
+    SessionStore,
+    /// Application-defined readiness probe
+    Probe,
+}
+
+/// Result of checking one component
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ComponentHealth {
+    /// Name the component was registered under
+    pub name: String,
+    /// What the component checks
+    pub kind: ComponentKind,
+    /// Whether the check passed
+    pub healthy: bool,
+    /// Failure reason, if the check failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+/// Combined result of every registered component
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HealthReport {
+    /// `true` only when every component is healthy
+    pub healthy: bool,
+    /// Per-component results, in registration order
+    pub components: Vec<ComponentHealth>,
+}
+
+impl HealthReport {
+    /// Components whose checks failed
+    pub fn failing(&self) -> impl Iterator<Item = &ComponentHealth> {
+        self.components
+            .iter()
+            .filter(|component| !component.healthy)
+    }
+
+    /// HTTP status for this report: `200` when healthy, `503` otherwise
+    #[must_use]
+    pub fn status_code(&self) -> StatusCode {
+        if self.healthy {
+            StatusCode::OK
+        } else {
+            StatusCode::SERVICE_UNAVAILABLE
+        }
+    }
+}
+
+impl IntoResponse for HealthReport {
+    fn into_response(self) -> Response {
+        (self.status_code(), axum::Json(self)).into_response()
+    }
+}
+
+#[derive(Clone)]
+struct Component {
+    name: String,
+    kind: ComponentKind,
+    check: ProbeFn,
+}
+
+/// Combines transport, session-store, and application checks into one report
+///
+/// Checks run concurrently on every [`check`](Self::check); each one is
+/// bounded by the probe timeout so a hung dependency reports as failed
+/// instead of stalling the endpoint. Clones share the registered checks.
+#[derive(Clone)]
+pub struct HealthAggregator {
+    components: Arc<Vec<Component>>,
+    timeout: Duration,
+}
+
+impl std::fmt::Debug for HealthAggregator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HealthAggregator")
+            .field(
+                "components",
+                &self
+                    .components
+                    .iter()
+                    .map(|component| component.name.as_str())
+                    .collect::<Vec<_>>(),
+            )
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+impl Default for HealthAggregator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HealthAggregator {
+    /// Create an aggregator with no components
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            components: Arc::new(Vec::new()),
+            timeout: DEFAULT_PROBE_TIMEOUT,
+        }
+    }
+
+    /// Set how long each component check may take before it counts as failed
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Register a transport whose [`HealthStatus`] is reported by `status`
+    ///
+    /// `Healthy` and `Degrading` (failures seen but below the threshold) pass;
+    /// every other status fails, including `Unknown` before the first check.
+    #[must_use]
+    pub fn transport<F, Fut>(self, name: impl Into<String>, status: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = HealthStatus> + Send + 'static,
+    {
+        self.push(name, ComponentKind::Transport, move || {
+            let status = status();
+            async move {
+                match status.await {
+                    HealthStatus::Healthy | HealthStatus::Degrading => Ok(()),
+                    other => Err(format!("transport status {other:?}")),
+                }
+            }
+        })
+    }
+
+    /// Register a session-store availability check
+    ///
+    /// `check` should perform a cheap round trip to the store and return the
+    /// error text if it is unreachable.
+    #[must_use]
+    pub fn session_store<F, Fut>(self, name: impl Into<String>, check: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), String>> + Send + 'static,
+    {
+        self.push(name, ComponentKind::SessionStore, check)
+    }
+
+    /// Register an application readiness probe
+    #[must_use]
+    pub fn probe<F, Fut>(self, name: impl Into<String>, check: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), String>> + Send + 'static,
+    {
+        self.push(name, ComponentKind::Probe, check)
+    }
+
+    fn push<F, Fut>(mut self, name: impl Into<String>, kind: ComponentKind, check: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), String>> + Send + 'static,
+    {
+        Arc::make_mut(&mut self.components).push(Component {
+            name: name.into(),
+            kind,
+            check: Arc::new(move || Box::pin(check())),
+        });
+        self
+    }
+
+    /// Run every check and collect the results
+    pub async fn check(&self) -> HealthReport {
+        let timeout = self.timeout;
+        let components =
+            futures::future::join_all(self.components.iter().map(|component| async move {
+                let result = match tokio::time::timeout(timeout, (component.check)()).await {
+                    Ok(result) => result,
+                    Err(_) => Err(format!("check timed out after {timeout:?}")),
+                };
+                ComponentHealth {
+                    name: component.name.clone(),
+                    kind: component.kind,
+                    healthy: result.is_ok(),
+                    detail: result.err(),
+                }
+            }))
+            .await;
+
+        HealthReport {
+            healthy: components.iter().all(|component| component.healthy),
+            components,
+        }
+    }
+
+    /// Axum router serving the aggregated report at `GET /healthz`
+    pub fn router(self) -> Router {
+        Router::new()
+            .route("/healthz", get(handle_healthz))
+            .with_state(self)
+    }
+}
+
+async fn handle_healthz(State(health): State<HealthAggregator>) -> HealthReport {
+    let report = health.check().await;
+    for component in report.failing() {
+        tracing::warn!(
+            component = %component.name,
+            detail = component.detail.as_deref().unwrap_or_default(),
+            "Health check failed"
+        );
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::{Body, to_bytes};
+    use tower::ServiceExt;
+
+    async fn get_healthz(health: HealthAggregator) -> (StatusCode, serde_json::Value) {
+        let request = axum::http::Request::builder()
+            .uri("/healthz")
+            .body(Body::empty())
+            .expect("request");
+        let response = health.router().oneshot(request).await.expect("response");
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body");
+        (status, serde_json::from_slice(&body).expect("json body"))
+    }
+
+    #[tokio::test]
+    async fn test_all_components_healthy_returns_200() {
+        let health = HealthAggregator::new()
+            .transport("http", || async { HealthStatus::Healthy })
+            .session_store("sessions", || async { Ok(()) })
+            .probe("database", || async { Ok(()) });
+
+        let (status, body) = get_healthz(health).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["healthy"], true);
+        assert_eq!(body["components"].as_array().map(Vec::len), Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_failing_probe_returns_503_naming_component() {
+        let health = HealthAggregator::new()
+            .transport("http", || async { HealthStatus::Healthy })
+            .session_store("sessions", || async { Ok(()) })
+            .probe("database", || async {
+                Err("connection refused".to_string())
+            });
+
+        let (status, body) = get_healthz(health).await;
+
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["healthy"], false);
+        let failing: Vec<_> = body["components"]
+            .as_array()
+            .expect("components")
+            .iter()
+            .filter(|component| component["healthy"] == false)
+            .collect();
+        assert_eq!(failing.len(), 1);
+        assert_eq!(failing[0]["name"], "database");
+        assert_eq!(failing[0]["kind"], "probe");
+        assert_eq!(failing[0]["detail"], "connection refused");
+    }
+
+    #[tokio::test]
+    async fn test_unhealthy_transport_and_slow_probe_fail() {
+        let health = HealthAggregator::new()
+            .with_timeout(Duration::from_millis(20))
+            .transport("tcp", || async { HealthStatus::Unhealthy })
+            .probe("slow", || async {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+                Ok(())
+            });
+
+        let report = health.check().await;
+
+        assert!(!report.healthy);
+        let failing: Vec<_> = report.failing().map(|c| c.name.as_str()).collect();
+        assert_eq!(failing, vec!["tcp", "slow"]);
+    }
+}
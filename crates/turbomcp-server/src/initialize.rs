@@ -0,0 +1,122 @@
+//! Per-client customization of the `initialize` response.
+//!
+//! The server computes its advertised capabilities from the handler. An
+//! [`InitializeHook`] sees which client is connecting and may change what
+//! that client is told, for example to roll a feature out to some clients
+//! only:
+//!
+//! ```rust,ignore
+//! let config = ServerConfig::builder()
+//!     .initialize_hook(|client: &InitializeClient, mut advertised: InitializeAdvertisement| {
+//!         if client.client_info.name == "legacy-client" {
+//!             advertised.capabilities.completions = None;
+//!         }
+//!         advertised
+//!     })
+//!     .build();
+//! ```
+//!
+//! Hooks change only the handshake; requests for a hidden capability are
+//! still routed to the handler.
+
+use std::fmt;
+use std::sync::Arc;
+
+use turbomcp_types::{ClientCapabilities, Implementation, ProtocolVersion, ServerCapabilities};
+
+/// The connecting client, as described by its `initialize` request.
+#[derive(Debug, Clone)]
+pub struct InitializeClient {
+    /// `clientInfo` from the request.
+    pub client_info: Implementation,
+    /// Capabilities the client declared.
+    pub capabilities: ClientCapabilities,
+    /// Protocol version negotiated for the session.
+    pub protocol_version: ProtocolVersion,
+}
+
+/// What the `initialize` response tells the client about the server.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InitializeAdvertisement {
+    /// Server capabilities, computed from the handler by default.
+    pub capabilities: ServerCapabilities,
+    /// Usage instructions for the client's model; none by default.
+    pub instructions: Option<String>,
+}
+
+/// Decides what the `initialize` response advertises to a given client.
+///
+/// The hook receives the advertisement computed so far and returns the one
+/// to send. Closures of type
+/// `Fn(&InitializeClient, InitializeAdvertisement) -> InitializeAdvertisement`
+/// implement this trait.
+pub trait InitializeHook: Send + Sync + 'static {
+    /// Return the advertisement to send to `client`.
+    fn on_initialize(
+        &self,
+        client: &InitializeClient,
+        advertised: InitializeAdvertisement,
+    ) -> InitializeAdvertisement;
+}
+
+impl<F> InitializeHook for F
+where
+    F: Fn(&InitializeClient, InitializeAdvertisement) -> InitializeAdvertisement
+        + Send
+        + Sync
+        + 'static,
+{
+    fn on_initialize(
+        &self,
+        client: &InitializeClient,
+        advertised: InitializeAdvertisement,
+    ) -> InitializeAdvertisement {
+        self(client, advertised)
+    }
+}
+
+/// Ordered set of [`InitializeHook`]s; each receives the previous one's
+/// result.
+#[derive(Clone, Default)]
+pub struct InitializeHooks {
+    hooks: Vec<Arc<dyn InitializeHook>>,
+}
+
+impl InitializeHooks {
+    /// Append a hook; it runs after the ones already registered.
+    pub fn push(&mut self, hook: impl InitializeHook) {
+        self.hooks.push(Arc::new(hook));
+    }
+
+    /// Whether no hooks are registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.hooks.is_empty()
+    }
+
+    /// Number of registered hooks.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.hooks.len()
+    }
+
+    /// Run every hook, threading the advertisement through them in order.
+    #[must_use]
+    pub fn apply(
+        &self,
+        client: &InitializeClient,
+        advertised: InitializeAdvertisement,
+    ) -> InitializeAdvertisement {
+        self.hooks.iter().fold(advertised, |advertised, hook| {
+            hook.on_initialize(client, advertised)
+        })
+    }
+}
+
+impl fmt::Debug for InitializeHooks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InitializeHooks")
+            .field("len", &self.hooks.len())
+            .finish()
+    }
+}
@@ -92,13 +92,29 @@
 // to improve API documentation quality for enterprise adoption
 
 // Core modules
+mod arguments;
+mod broadcast;
 mod builder;
+mod catalog;
 mod composite;
 mod config;
 mod context;
+mod diagnostics;
+mod drain;
 mod handler;
+#[cfg(feature = "http")]
+mod health;
+mod initialize;
+mod list_cache;
 pub mod middleware;
+mod output_transform;
+mod panic_isolation;
+mod resource_dir;
 mod router;
+mod served;
+mod server_icon;
+mod tool_schema;
+mod trace_context;
 mod visibility;
 
 /// Transport implementations for different protocols.
@@ -113,22 +129,49 @@ pub use visibility::{
 pub use composite::CompositeHandler;
 
 /// Typed middleware for MCP request processing.
-pub use middleware::{McpMiddleware, MiddlewareStack, Next};
+pub use middleware::{
+    ChunkedUploadMiddleware, DEFAULT_UPLOAD_ARGUMENT, IDEMPOTENCY_KEY_META, IdempotencyMiddleware,
+    LoggingMiddleware, McpMiddleware, MetricsMiddleware, MiddlewareStack, Next,
+    OPENMETRICS_CONTENT_TYPE, OperationMetrics, ServerMetrics, SizeAccountingMiddleware,
+    SizeMetrics, ToolCallMetrics, UPLOAD_META,
+};
 
 // Public exports
+pub use arguments::{ArgumentDeserializer, ArgumentDeserializers, PositionalArguments};
+pub use broadcast::{BroadcastRegistration, NotificationBroadcaster};
 pub use builder::{McpServerExt, ServerBuilder, Transport};
+pub use catalog::{CatalogEntry, ComponentCatalog, REQUIRED_SCOPES_META};
 pub use config::{
     CapabilityValidation, ClientCapabilities, ConfigValidationError, ConnectionCounter,
-    ConnectionGuard, ConnectionLimits, OriginValidationConfig, ProtocolConfig, ProtocolVersion,
-    RateLimitConfig, RateLimiter, RequiredCapabilities, SUPPORTED_PROTOCOL_VERSIONS, ServerConfig,
-    ServerConfigBuilder,
+    ConnectionGuard, ConnectionLimits, DisallowedMeta, DuplicateRequestIds, JsonRpcStrictness,
+    LogSampling, MetaAllowlist, OriginValidationConfig, ProtocolConfig, ProtocolVersion,
+    RateLimitAlgorithm, RateLimitAlgorithmFactory, RateLimitConfig, RateLimitStrategy, RateLimiter,
+    RequiredCapabilities, SUPPORTED_PROTOCOL_VERSIONS, ServerConfig, ServerConfigBuilder,
+    SlidingWindow, TokenBucket,
+};
+pub use context::{
+    ElicitationFallback, RequestContext, RequestContextExtractor, RequestContextExtractors,
+    TransportType,
 };
-pub use context::{RequestContext, TransportType};
+pub use diagnostics::ServerDiagnostics;
+pub use drain::{DrainCoordinator, InFlightReport};
 pub use handler::McpHandlerExt;
+#[cfg(feature = "http")]
+pub use health::{
+    ComponentHealth, ComponentKind, DEFAULT_PROBE_TIMEOUT, HealthAggregator, HealthReport,
+};
+pub use initialize::{InitializeAdvertisement, InitializeClient, InitializeHook, InitializeHooks};
+pub use list_cache::ListCacheInvalidator;
+pub use output_transform::OutputTransforms;
+pub use panic_isolation::PanicIsolation;
+pub use resource_dir::{DEFAULT_MAX_RESOURCE_FILE_SIZE, ResourceDir, ResourceDirOptions};
 pub use router::{
-    JsonRpcIncoming, JsonRpcOutgoing, apply_adapter_to_response, parse_request, route_request,
-    route_request_versioned, route_request_with_config, serialize_response,
+    JsonRpcIncoming, JsonRpcOutgoing, apply_adapter_to_response, parse_request,
+    parse_request_with_strictness, route_request, route_request_versioned,
+    route_request_with_config, serialize_response,
 };
+pub use tool_schema::{SchemaTransforms, schema_title_from_tool_name};
+pub use trace_context::{TRACE_ID_META, TRACEPARENT, trace_id_from_traceparent};
 
 // Re-export McpHandler from core for unified architecture
 pub use turbomcp_core::handler::McpHandler;
@@ -181,15 +224,15 @@ pub mod prelude {
     };
 
     // Builder and transport
-    pub use super::{ServerBuilder, Transport};
+    pub use super::{ResourceDirOptions, ServerBuilder, Transport};
 
     // Context types
     pub use super::{RequestContext, TransportType};
 
     // Configuration types
     pub use super::{
-        ConnectionLimits, OriginValidationConfig, ProtocolConfig, RateLimitConfig, RateLimiter,
-        RequiredCapabilities, ServerConfig, ServerConfigBuilder,
+        ConnectionLimits, OriginValidationConfig, ProtocolConfig, RateLimitConfig,
+        RateLimitStrategy, RateLimiter, RequiredCapabilities, ServerConfig, ServerConfigBuilder,
     };
 
     // Re-export error types from turbomcp-core (unified error handling)
@@ -0,0 +1,391 @@
+//! Shared caching of list responses.
+//!
+//! When many clients connect at once, each `tools/list`, `resources/list`,
+//! `resources/templates/list`, and `prompts/list` recomputes the same answer.
+//! With [`ServerBuilder::with_list_cache`](crate::ServerBuilder::with_list_cache)
+//! each list is computed once and handed to every caller until it is
+//! invalidated. Concurrent callers that miss the cache wait for the in-flight
+//! computation instead of starting their own.
+//!
+//! # Invalidation
+//!
+//! A cached list is dropped whenever its `list_changed` notification is sent,
+//! either from a handler through its request context or to every client with
+//! [`NotificationBroadcaster::broadcast`](crate::NotificationBroadcaster::broadcast).
+//! A [`ListCacheInvalidator`] clears entries by hand for changes no
+//! notification reports.
+//!
+//! # Scoping
+//!
+//! Only the handler's own `list_*` output is cached; those methods take no
+//! request context, so the result is the same for every client. Filtering the
+//! router applies per request, such as authorization or session visibility,
+//! still runs on every request.
+
+use std::sync::Arc;
+
+use parking_lot::{Condvar, Mutex};
+use serde_json::Value;
+use turbomcp_core::context::RequestContext;
+use turbomcp_core::session::{McpSession, SessionFuture};
+use turbomcp_types::{ClientCapabilities, Prompt, Resource, ResourceTemplate, Tool};
+
+/// One cached list.
+#[derive(Debug)]
+struct Slot<T> {
+    state: Mutex<SlotState<T>>,
+    computed: Condvar,
+}
+
+#[derive(Debug)]
+struct SlotState<T> {
+    value: Option<Vec<T>>,
+    computing: bool,
+    /// Bumped on every invalidation, so a computation that started before
+    /// one is returned to its callers but not cached.
+    generation: u64,
+}
+
+impl<T> Default for Slot<T> {
+    fn default() -> Self {
+        Self {
+            state: Mutex::new(SlotState {
+                value: None,
+                computing: false,
+                generation: 0,
+            }),
+            computed: Condvar::new(),
+        }
+    }
+}
+
+impl<T: Clone> Slot<T> {
+    /// Return the cached list, computing it on a miss.
+    ///
+    /// The lock is released while `compute` runs; callers that miss in the
+    /// meantime wait for that computation rather than starting another.
+    fn get(&self, compute: impl FnOnce() -> Vec<T>) -> Vec<T> {
+        let mut state = self.state.lock();
+        while state.value.is_none() && state.computing {
+            self.computed.wait(&mut state);
+        }
+        if let Some(value) = &state.value {
+            return value.clone();
+        }
+        state.computing = true;
+        let generation = state.generation;
+        drop(state);
+
+        let _done = ComputeDone(self);
+        let value = compute();
+        let mut state = self.state.lock();
+        if state.generation == generation {
+            state.value = Some(value.clone());
+        }
+        value
+    }
+
+    fn invalidate(&self) {
+        let mut state = self.state.lock();
+        state.value = None;
+        state.generation += 1;
+    }
+}
+
+/// Wakes waiting callers once a computation ends, even by panicking.
+struct ComputeDone<'a, T>(&'a Slot<T>);
+
+impl<T> Drop for ComputeDone<'_, T> {
+    fn drop(&mut self) {
+        self.0.state.lock().computing = false;
+        self.0.computed.notify_all();
+    }
+}
+
+#[derive(Debug, Default)]
+struct ListCacheState {
+    tools: Slot<Tool>,
+    resources: Slot<Resource>,
+    resource_templates: Slot<ResourceTemplate>,
+    prompts: Slot<Prompt>,
+}
+
+/// List responses cached by the served handler.
+///
+/// Clones share the same cache, so one `ListCache` serves every connection.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ListCache {
+    state: Arc<ListCacheState>,
+}
+
+impl ListCache {
+    pub(crate) fn tools(&self, compute: impl FnOnce() -> Vec<Tool>) -> Vec<Tool> {
+        self.state.tools.get(compute)
+    }
+
+    pub(crate) fn resources(&self, compute: impl FnOnce() -> Vec<Resource>) -> Vec<Resource> {
+        self.state.resources.get(compute)
+    }
+
+    pub(crate) fn resource_templates(
+        &self,
+        compute: impl FnOnce() -> Vec<ResourceTemplate>,
+    ) -> Vec<ResourceTemplate> {
+        self.state.resource_templates.get(compute)
+    }
+
+    pub(crate) fn prompts(&self, compute: impl FnOnce() -> Vec<Prompt>) -> Vec<Prompt> {
+        self.state.prompts.get(compute)
+    }
+
+    /// Get a handle that clears this cache.
+    pub(crate) fn invalidator(&self) -> ListCacheInvalidator {
+        ListCacheInvalidator {
+            state: Arc::clone(&self.state),
+        }
+    }
+
+    /// A copy of `ctx` whose session clears this cache when a `list_changed`
+    /// notification is sent through it, or `None` if `ctx` has no session.
+    pub(crate) fn watch(&self, ctx: &RequestContext) -> Option<RequestContext> {
+        let session = ctx.session()?;
+        let watched = InvalidatingSession {
+            inner: Arc::clone(session),
+            invalidator: self.invalidator(),
+        };
+        Some(ctx.clone().with_session(Arc::new(watched)))
+    }
+}
+
+/// Clears entries of a server's list cache.
+///
+/// `list_changed` notifications clear the matching list automatically; use
+/// this for changes that are not announced to clients.
+#[derive(Debug, Clone)]
+pub struct ListCacheInvalidator {
+    state: Arc<ListCacheState>,
+}
+
+impl ListCacheInvalidator {
+    /// Drop the cached tool list.
+    pub fn invalidate_tools(&self) {
+        self.state.tools.invalidate();
+    }
+
+    /// Drop the cached resource and resource template lists.
+    pub fn invalidate_resources(&self) {
+        self.state.resources.invalidate();
+        self.state.resource_templates.invalidate();
+    }
+
+    /// Drop the cached prompt list.
+    pub fn invalidate_prompts(&self) {
+        self.state.prompts.invalidate();
+    }
+
+    /// Drop every cached list.
+    pub fn invalidate_all(&self) {
+        self.invalidate_tools();
+        self.invalidate_resources();
+        self.invalidate_prompts();
+    }
+
+    /// Drop the list a `notifications/*/list_changed` method refers to.
+    ///
+    /// Returns `false` (and clears nothing) for any other method.
+    pub fn invalidate_for_notification(&self, method: &str) -> bool {
+        match method {
+            "notifications/tools/list_changed" => self.invalidate_tools(),
+            "notifications/resources/list_changed" => self.invalidate_resources(),
+            "notifications/prompts/list_changed" => self.invalidate_prompts(),
+            _ => return false,
+        }
+        true
+    }
+}
+
+/// Session that clears the list cache before forwarding a `list_changed`
+/// notification.
+#[derive(Debug)]
+struct InvalidatingSession {
+    inner: Arc<dyn McpSession>,
+    invalidator: ListCacheInvalidator,
+}
+
+impl McpSession for InvalidatingSession {
+    fn client_capabilities<'a>(&'a self) -> SessionFuture<'a, Option<ClientCapabilities>> {
+        self.inner.client_capabilities()
+    }
+
+    fn call<'a>(&'a self, method: &'a str, params: Value) -> SessionFuture<'a, Value> {
+        self.inner.call(method, params)
+    }
+
+    fn notify<'a>(&'a self, method: &'a str, params: Value) -> SessionFuture<'a, ()> {
+        self.invalidator.invalidate_for_notification(method);
+        self.inner.notify(method, params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{McpServerExt, NotificationBroadcaster, ServerConfig};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+    use turbomcp_core::error::{McpError, McpResult};
+    use turbomcp_core::handler::McpHandler;
+    use turbomcp_core::marker::MaybeSend;
+    use turbomcp_types::{PromptResult, ResourceResult, ServerInfo, ToolResult};
+
+    #[derive(Clone, Default)]
+    struct CountingHandler {
+        tool_lists: Arc<AtomicUsize>,
+    }
+
+    #[allow(clippy::manual_async_fn)]
+    impl McpHandler for CountingHandler {
+        fn server_info(&self) -> ServerInfo {
+            ServerInfo::new("list-cache-test", "1.0.0")
+        }
+
+        fn list_tools(&self) -> Vec<Tool> {
+            self.tool_lists.fetch_add(1, Ordering::SeqCst);
+            // Keep the computation in flight long enough for callers to overlap.
+            std::thread::sleep(Duration::from_millis(50));
+            vec![Tool::new("search", "Search")]
+        }
+
+        fn list_resources(&self) -> Vec<Resource> {
+            vec![]
+        }
+
+        fn list_prompts(&self) -> Vec<Prompt> {
+            vec![]
+        }
+
+        fn call_tool<'a>(
+            &'a self,
+            _name: &'a str,
+            _args: Value,
+            ctx: &'a RequestContext,
+        ) -> impl std::future::Future<Output = McpResult<ToolResult>> + MaybeSend + 'a {
+            async move {
+                ctx.notify_client("notifications/tools/list_changed", Value::Null)
+                    .await?;
+                Ok(ToolResult::text("ok"))
+            }
+        }
+
+        fn read_resource<'a>(
+            &'a self,
+            uri: &'a str,
+            _ctx: &'a RequestContext,
+        ) -> impl std::future::Future<Output = McpResult<ResourceResult>> + MaybeSend + 'a {
+            let uri = uri.to_string();
+            async move { Err(McpError::resource_not_found(&uri)) }
+        }
+
+        fn get_prompt<'a>(
+            &'a self,
+            name: &'a str,
+            _args: Option<Value>,
+            _ctx: &'a RequestContext,
+        ) -> impl std::future::Future<Output = McpResult<PromptResult>> + MaybeSend + 'a {
+            let name = name.to_string();
+            async move { Err(McpError::prompt_not_found(&name)) }
+        }
+    }
+
+    #[derive(Debug)]
+    struct NullSession;
+
+    impl McpSession for NullSession {
+        fn call<'a>(&'a self, _method: &'a str, _params: Value) -> SessionFuture<'a, Value> {
+            Box::pin(async { Err(McpError::internal("not supported")) })
+        }
+
+        fn notify<'a>(&'a self, _method: &'a str, _params: Value) -> SessionFuture<'a, ()> {
+            Box::pin(async { Ok(()) })
+        }
+    }
+
+    #[test]
+    fn test_concurrent_list_requests_compute_once() {
+        let handler = CountingHandler::default();
+        let computed = Arc::clone(&handler.tool_lists);
+        let served = handler.builder().with_list_cache(true).served_handler();
+
+        std::thread::scope(|scope| {
+            for _ in 0..8 {
+                let served = served.clone();
+                scope.spawn(move || assert_eq!(served.list_tools().len(), 1));
+            }
+        });
+
+        assert_eq!(computed.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_invalidation_during_computation_is_not_cached() {
+        let slot = Slot::default();
+        let value = slot.get(|| {
+            slot.invalidate();
+            vec![1]
+        });
+        assert_eq!(value, vec![1]);
+        assert_eq!(slot.get(|| vec![2]), vec![2]);
+    }
+
+    #[tokio::test]
+    async fn test_list_changed_notifications_invalidate_cache() {
+        let handler = CountingHandler::default();
+        let computed = Arc::clone(&handler.tool_lists);
+        let builder = handler.builder().with_list_cache(true);
+        let invalidator = builder.list_cache_invalidator().unwrap();
+        let served = builder.served_handler();
+
+        served.list_tools();
+        assert!(!invalidator.invalidate_for_notification("notifications/progress"));
+        served.list_tools();
+        assert_eq!(computed.load(Ordering::SeqCst), 1);
+
+        // Sent by a tool through its request context.
+        let ctx = RequestContext::stdio().with_session(Arc::new(NullSession));
+        served.call_tool("search", Value::Null, &ctx).await.unwrap();
+        served.list_tools();
+        assert_eq!(computed.load(Ordering::SeqCst), 2);
+
+        // Broadcast to every client.
+        let broadcaster = NotificationBroadcaster::new();
+        broadcaster.invalidate_on_list_changed(invalidator);
+        broadcaster
+            .broadcast("notifications/tools/list_changed", Value::Null)
+            .await;
+        served.list_tools();
+        assert_eq!(computed.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_builder_subscribes_cache_to_configured_broadcaster() {
+        let handler = CountingHandler::default();
+        let computed = Arc::clone(&handler.tool_lists);
+        let broadcaster = NotificationBroadcaster::new();
+        let (served, _config) = handler
+            .builder()
+            .with_list_cache(true)
+            .with_config(
+                ServerConfig::builder()
+                    .notification_broadcaster(broadcaster.clone())
+                    .build(),
+            )
+            .into_parts();
+
+        served.list_tools();
+        broadcaster
+            .broadcast("notifications/tools/list_changed", Value::Null)
+            .await;
+        served.list_tools();
+        assert_eq!(computed.load(Ordering::SeqCst), 2);
+    }
+}
@@ -0,0 +1,320 @@
+//! Idempotency-key deduplication for tool calls.
+//!
+//! Clients with at-least-once delivery may retry a `tools/call` whose response
+//! was lost, which would run a non-idempotent tool twice. A client opts in by
+//! sending an `Idempotency-Key` string in the request's `_meta`:
+//!
+//! ```json
+//! {"name": "charge_card", "arguments": {...}, "_meta": {"Idempotency-Key": "order-1234"}}
+//! ```
+//!
+//! [`IdempotencyMiddleware`] runs the first call with a given key and caches
+//! its result for a TTL. Repeats with the same key inside that window get the
+//! cached result without re-executing the tool, including retries that arrive
+//! while the first call is still running. Calls without a key pass through.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use dashmap::DashMap;
+use serde_json::Value;
+use tokio::sync::OnceCell;
+use turbomcp_core::clock::{Clock, SystemClock};
+use turbomcp_core::context::RequestContext;
+use turbomcp_core::error::{McpError, McpResult};
+use turbomcp_types::ToolResult;
+
+use super::typed::{McpMiddleware, Next};
+
+/// `_meta` field carrying the client's idempotency key.
+pub const IDEMPOTENCY_KEY_META: &str = "Idempotency-Key";
+
+/// Default time a result stays cached: 10 minutes.
+const DEFAULT_TTL: Duration = Duration::from_secs(600);
+
+#[derive(Debug)]
+struct Entry {
+    tool: String,
+    args: Value,
+    created: Instant,
+    result: OnceCell<ToolResult>,
+}
+
+/// Middleware that deduplicates tool calls carrying an `Idempotency-Key`.
+///
+/// Keys are scoped to the session, so two clients cannot collide. Reusing a
+/// key for a different tool or different arguments is rejected with an
+/// invalid-params error. Failed calls are not cached, so a retry after an
+/// error runs the tool again. Clones share the same cache.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use std::time::Duration;
+/// use turbomcp_server::{IdempotencyMiddleware, MiddlewareStack};
+///
+/// let handler = MiddlewareStack::new(MyServer)
+///     .with_middleware(IdempotencyMiddleware::new().with_ttl(Duration::from_secs(300)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct IdempotencyMiddleware {
+    ttl: Duration,
+    clock: Arc<dyn Clock>,
+    entries: Arc<DashMap<(String, String), Arc<Entry>>>,
+}
+
+impl Default for IdempotencyMiddleware {
+    fn default() -> Self {
+        Self {
+            ttl: DEFAULT_TTL,
+            clock: SystemClock::shared(),
+            entries: Arc::new(DashMap::new()),
+        }
+    }
+}
+
+impl IdempotencyMiddleware {
+    /// Create an idempotency middleware with the default 10 minute TTL.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep cached results for `ttl` after the first call with a key.
+    #[must_use]
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Read time from `clock` instead of the system clock.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// How long results stay cached.
+    #[must_use]
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Number of keys currently cached, including expired ones not yet pruned.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether no keys are cached.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Find or create the cache entry for `key`, dropping expired entries.
+    fn entry(&self, ctx: &RequestContext, key: &str, tool: &str, args: &Value) -> Arc<Entry> {
+        let now = self.clock.instant();
+        self.entries
+            .retain(|_, entry| now.saturating_duration_since(entry.created) < self.ttl);
+
+        let session = ctx.session_id().unwrap_or_default().to_string();
+        let entry = self
+            .entries
+            .entry((session, key.to_string()))
+            .or_insert_with(|| {
+                Arc::new(Entry {
+                    tool: tool.to_string(),
+                    args: args.clone(),
+                    created: now,
+                    result: OnceCell::new(),
+                })
+            });
+        Arc::clone(&entry)
+    }
+}
+
+fn idempotency_key(ctx: &RequestContext) -> Option<&str> {
+    ctx.request_meta()?.get(IDEMPOTENCY_KEY_META)?.as_str()
+}
+
+impl McpMiddleware for IdempotencyMiddleware {
+    fn on_call_tool<'a>(
+        &'a self,
+        name: &'a str,
+        args: Value,
+        ctx: &'a RequestContext,
+        next: Next<'a>,
+    ) -> Pin<Box<dyn Future<Output = McpResult<ToolResult>> + Send + 'a>> {
+        Box::pin(async move {
+            let Some(key) = idempotency_key(ctx) else {
+                return next.call_tool(name, args, ctx).await;
+            };
+
+            let entry = self.entry(ctx, key, name, &args);
+            if entry.tool != name || entry.args != args {
+                return Err(McpError::invalid_params(format!(
+                    "Idempotency-Key '{key}' was already used for a different tool call"
+                )));
+            }
+
+            let mut executed = false;
+            let result = entry
+                .result
+                .get_or_try_init(|| {
+                    executed = true;
+                    next.call_tool(name, args, ctx)
+                })
+                .await?;
+            if !executed {
+                tracing::debug!(tool = name, key, "Returning cached idempotent tool result");
+            }
+            Ok(result.clone())
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::manual_async_fn)]
+mod tests {
+    use super::*;
+    use crate::middleware::MiddlewareStack;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use turbomcp_core::clock::MockClock;
+    use turbomcp_core::error::ErrorKind;
+    use turbomcp_core::handler::McpHandler;
+    use turbomcp_types::{Prompt, PromptResult, Resource, ResourceResult, ServerInfo, Tool};
+
+    #[derive(Clone, Default)]
+    struct ChargeHandler {
+        charges: Arc<AtomicU64>,
+    }
+
+    impl McpHandler for ChargeHandler {
+        fn server_info(&self) -> ServerInfo {
+            ServerInfo::new("idempotency-test", "1.0.0")
+        }
+
+        fn list_tools(&self) -> Vec<Tool> {
+            vec![Tool::new("charge", "Charge a card")]
+        }
+
+        fn list_resources(&self) -> Vec<Resource> {
+            vec![]
+        }
+
+        fn list_prompts(&self) -> Vec<Prompt> {
+            vec![]
+        }
+
+        fn call_tool<'a>(
+            &'a self,
+            _name: &'a str,
+            _args: Value,
+            _ctx: &'a RequestContext,
+        ) -> impl Future<Output = McpResult<ToolResult>> + Send + 'a {
+            async move {
+                let charge = self.charges.fetch_add(1, Ordering::SeqCst) + 1;
+                Ok(ToolResult::text(format!("charge #{charge}")))
+            }
+        }
+
+        fn read_resource<'a>(
+            &'a self,
+            uri: &'a str,
+            _ctx: &'a RequestContext,
+        ) -> impl Future<Output = McpResult<ResourceResult>> + Send + 'a {
+            let uri = uri.to_string();
+            async move { Err(McpError::resource_not_found(&uri)) }
+        }
+
+        fn get_prompt<'a>(
+            &'a self,
+            name: &'a str,
+            _args: Option<Value>,
+            _ctx: &'a RequestContext,
+        ) -> impl Future<Output = McpResult<PromptResult>> + Send + 'a {
+            let name = name.to_string();
+            async move { Err(McpError::prompt_not_found(&name)) }
+        }
+    }
+
+    fn keyed(key: &str) -> RequestContext {
+        RequestContext::stdio()
+            .with_session_id("s1")
+            .with_request_meta(serde_json::json!({ IDEMPOTENCY_KEY_META: key }))
+    }
+
+    #[tokio::test]
+    async fn test_repeated_key_runs_tool_once() {
+        let handler = ChargeHandler::default();
+        let charges = Arc::clone(&handler.charges);
+        let stack = MiddlewareStack::new(handler).with_middleware(IdempotencyMiddleware::new());
+        let args = serde_json::json!({"amount": 42});
+
+        let first = stack
+            .call_tool("charge", args.clone(), &keyed("order-1"))
+            .await;
+        let retry = stack
+            .call_tool("charge", args.clone(), &keyed("order-1"))
+            .await;
+        assert_eq!(first.unwrap(), retry.unwrap());
+        assert_eq!(charges.load(Ordering::SeqCst), 1);
+
+        // Calls without a key, or with a new key, always run.
+        let plain = RequestContext::stdio().with_session_id("s1");
+        stack
+            .call_tool("charge", args.clone(), &plain)
+            .await
+            .unwrap();
+        stack
+            .call_tool("charge", args.clone(), &keyed("order-2"))
+            .await
+            .unwrap();
+        assert_eq!(charges.load(Ordering::SeqCst), 3);
+
+        // Reusing a key for different arguments is rejected.
+        let err = stack
+            .call_tool(
+                "charge",
+                serde_json::json!({"amount": 1}),
+                &keyed("order-1"),
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind, ErrorKind::InvalidParams);
+    }
+
+    #[tokio::test]
+    async fn test_cached_result_expires_after_ttl() {
+        let handler = ChargeHandler::default();
+        let charges = Arc::clone(&handler.charges);
+        let clock = Arc::new(MockClock::new());
+        let idempotency = IdempotencyMiddleware::new()
+            .with_ttl(Duration::from_secs(60))
+            .with_clock(clock.clone());
+        let stack = MiddlewareStack::new(handler).with_middleware(idempotency.clone());
+        let args = serde_json::json!({"amount": 42});
+
+        stack
+            .call_tool("charge", args.clone(), &keyed("order-1"))
+            .await
+            .unwrap();
+        clock.advance(Duration::from_secs(59));
+        stack
+            .call_tool("charge", args.clone(), &keyed("order-1"))
+            .await
+            .unwrap();
+        assert_eq!(charges.load(Ordering::SeqCst), 1);
+
+        clock.advance(Duration::from_secs(1));
+        let result = stack
+            .call_tool("charge", args, &keyed("order-1"))
+            .await
+            .unwrap();
+        assert_eq!(result.first_text(), Some("charge #2"));
+        assert_eq!(idempotency.len(), 1);
+    }
+}
@@ -0,0 +1,303 @@
+//! Request/response logging middleware with sampling.
+//!
+//! Logging every request is too verbose at scale. [`LoggingMiddleware`] applies
+//! a [`LogSampling`] policy (every request, one in N, errors only, or slow
+//! requests only) to tool calls, resource reads, and prompt retrievals. Failed
+//! requests are always logged.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use serde_json::Value;
+use turbomcp_core::context::RequestContext;
+use turbomcp_core::error::McpResult;
+use turbomcp_types::{PromptResult, ResourceResult, ToolResult};
+
+use super::typed::{McpMiddleware, Next};
+use crate::config::{LogSampling, ServerConfig};
+
+/// Middleware that logs requests and their outcomes according to a
+/// [`LogSampling`] policy.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use turbomcp_server::{LogSampling, LoggingMiddleware, MiddlewareStack, ServerConfig};
+///
+/// let config = ServerConfig::builder()
+///     .log_sampling(LogSampling::OneIn(10))
+///     .build();
+///
+/// let handler = MiddlewareStack::new(MyServer)
+///     .with_middleware(LoggingMiddleware::from_config(&config));
+/// ```
+#[derive(Debug, Default)]
+pub struct LoggingMiddleware {
+    sampling: LogSampling,
+    seen: AtomicU64,
+    logged: AtomicU64,
+}
+
+impl LoggingMiddleware {
+    /// Create a logging middleware with the given sampling policy.
+    #[must_use]
+    pub fn new(sampling: LogSampling) -> Self {
+        Self {
+            sampling,
+            seen: AtomicU64::new(0),
+            logged: AtomicU64::new(0),
+        }
+    }
+
+    /// Create a logging middleware using the sampling policy from `config`,
+    /// logging every request if none is set.
+    #[must_use]
+    pub fn from_config(config: &ServerConfig) -> Self {
+        Self::new(config.log_sampling.unwrap_or_default())
+    }
+
+    /// The active sampling policy.
+    #[must_use]
+    pub fn sampling(&self) -> LogSampling {
+        self.sampling
+    }
+
+    /// Number of requests logged so far.
+    #[must_use]
+    pub fn logged_count(&self) -> u64 {
+        self.logged.load(Ordering::Relaxed)
+    }
+
+    fn should_log(&self, failed: bool, elapsed: Duration) -> bool {
+        let seen = self.seen.fetch_add(1, Ordering::Relaxed);
+        if failed {
+            return true;
+        }
+        match self.sampling {
+            LogSampling::All => true,
+            LogSampling::OneIn(n) => seen.is_multiple_of(u64::from(n.max(1))),
+            LogSampling::ErrorsOnly => false,
+            LogSampling::SlowerThan(threshold) => elapsed > threshold,
+        }
+    }
+
+    fn record<T>(
+        &self,
+        operation: &'static str,
+        target: &str,
+        ctx: &RequestContext,
+        started: Instant,
+        result: &McpResult<T>,
+    ) {
+        let elapsed = started.elapsed();
+        if !self.should_log(result.is_err(), elapsed) {
+            return;
+        }
+        self.logged.fetch_add(1, Ordering::Relaxed);
+
+        let elapsed_ms = elapsed.as_millis() as u64;
+        match result {
+            Ok(_) => tracing::info!(
+                operation,
+                target,
+                request_id = ctx.request_id(),
+                transport = %ctx.transport(),
+                elapsed_ms,
+                "request completed"
+            ),
+            Err(error) => tracing::warn!(
+                operation,
+                target,
+                request_id = ctx.request_id(),
+                transport = %ctx.transport(),
+                elapsed_ms,
+                error = %error,
+                "request failed"
+            ),
+        }
+    }
+}
+
+impl McpMiddleware for LoggingMiddleware {
+    fn on_call_tool<'a>(
+        &'a self,
+        name: &'a str,
+        args: Value,
+        ctx: &'a RequestContext,
+        next: Next<'a>,
+    ) -> Pin<Box<dyn Future<Output = McpResult<ToolResult>> + Send + 'a>> {
+        Box::pin(async move {
+            let started = Instant::now();
+            let result = next.call_tool(name, args, ctx).await;
+            self.record("tools/call", name, ctx, started, &result);
+            result
+        })
+    }
+
+    fn on_read_resource<'a>(
+        &'a self,
+        uri: &'a str,
+        ctx: &'a RequestContext,
+        next: Next<'a>,
+    ) -> Pin<Box<dyn Future<Output = McpResult<ResourceResult>> + Send + 'a>> {
+        Box::pin(async move {
+            let started = Instant::now();
+            let result = next.read_resource(uri, ctx).await;
+            self.record("resources/read", uri, ctx, started, &result);
+            result
+        })
+    }
+
+    fn on_get_prompt<'a>(
+        &'a self,
+        name: &'a str,
+        args: Option<Value>,
+        ctx: &'a RequestContext,
+        next: Next<'a>,
+    ) -> Pin<Box<dyn Future<Output = McpResult<PromptResult>> + Send + 'a>> {
+        Box::pin(async move {
+            let started = Instant::now();
+            let result = next.get_prompt(name, args, ctx).await;
+            self.record("prompts/get", name, ctx, started, &result);
+            result
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::manual_async_fn)]
+mod tests {
+    use super::*;
+    use crate::middleware::MiddlewareStack;
+    use turbomcp_core::error::McpError;
+    use turbomcp_core::handler::McpHandler;
+    use turbomcp_types::{Prompt, Resource, ServerInfo, Tool};
+
+    #[derive(Clone)]
+    struct EchoHandler;
+
+    impl McpHandler for EchoHandler {
+        fn server_info(&self) -> ServerInfo {
+            ServerInfo::new("logging-test", "1.0.0")
+        }
+
+        fn list_tools(&self) -> Vec<Tool> {
+            vec![Tool::new("echo", "Echo"), Tool::new("fail", "Always fails")]
+        }
+
+        fn list_resources(&self) -> Vec<Resource> {
+            vec![]
+        }
+
+        fn list_prompts(&self) -> Vec<Prompt> {
+            vec![]
+        }
+
+        fn call_tool<'a>(
+            &'a self,
+            name: &'a str,
+            _args: Value,
+            _ctx: &'a RequestContext,
+        ) -> impl Future<Output = McpResult<ToolResult>> + Send + 'a {
+            async move {
+                match name {
+                    "echo" => Ok(ToolResult::text("ok")),
+                    _ => Err(McpError::internal("boom")),
+                }
+            }
+        }
+
+        fn read_resource<'a>(
+            &'a self,
+            uri: &'a str,
+            _ctx: &'a RequestContext,
+        ) -> impl Future<Output = McpResult<ResourceResult>> + Send + 'a {
+            let uri = uri.to_string();
+            async move { Err(McpError::resource_not_found(&uri)) }
+        }
+
+        fn get_prompt<'a>(
+            &'a self,
+            name: &'a str,
+            _args: Option<Value>,
+            _ctx: &'a RequestContext,
+        ) -> impl Future<Output = McpResult<PromptResult>> + Send + 'a {
+            let name = name.to_string();
+            async move { Err(McpError::prompt_not_found(&name)) }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sampled_stack_logs_a_tenth_of_calls_and_every_error() {
+        let middleware = std::sync::Arc::new(LoggingMiddleware::new(LogSampling::OneIn(10)));
+        let stack = MiddlewareStack::new(EchoHandler)
+            .with_middleware(SharedLogging(std::sync::Arc::clone(&middleware)));
+        let ctx = RequestContext::stdio();
+
+        for _ in 0..100 {
+            stack.call_tool("echo", Value::Null, &ctx).await.unwrap();
+        }
+        let logged = middleware.logged_count();
+        assert_eq!(logged, 10, "1-in-10 sampling should log a tenth of calls");
+
+        for _ in 0..5 {
+            assert!(stack.call_tool("fail", Value::Null, &ctx).await.is_err());
+        }
+        assert_eq!(middleware.logged_count(), logged + 5);
+    }
+
+    /// Lets the test keep a handle on the middleware after it joins the stack.
+    struct SharedLogging(std::sync::Arc<LoggingMiddleware>);
+
+    impl McpMiddleware for SharedLogging {
+        fn on_call_tool<'a>(
+            &'a self,
+            name: &'a str,
+            args: Value,
+            ctx: &'a RequestContext,
+            next: Next<'a>,
+        ) -> Pin<Box<dyn Future<Output = McpResult<ToolResult>> + Send + 'a>> {
+            self.0.on_call_tool(name, args, ctx, next)
+        }
+    }
+
+    #[test]
+    fn test_one_in_ten_sampling_logs_a_tenth_and_all_errors() {
+        let middleware = LoggingMiddleware::new(LogSampling::OneIn(10));
+
+        let sampled = (0..100)
+            .filter(|_| middleware.should_log(false, Duration::ZERO))
+            .count();
+        assert_eq!(sampled, 10);
+
+        let errors = (0..25)
+            .filter(|_| middleware.should_log(true, Duration::ZERO))
+            .count();
+        assert_eq!(errors, 25);
+    }
+
+    #[test]
+    fn test_errors_only_and_slow_sampling() {
+        let errors_only = LoggingMiddleware::new(LogSampling::ErrorsOnly);
+        assert!(!errors_only.should_log(false, Duration::from_secs(5)));
+        assert!(errors_only.should_log(true, Duration::ZERO));
+
+        let slow = LoggingMiddleware::new(LogSampling::SlowerThan(Duration::from_millis(100)));
+        assert!(!slow.should_log(false, Duration::from_millis(50)));
+        assert!(slow.should_log(false, Duration::from_millis(150)));
+        assert!(slow.should_log(true, Duration::ZERO));
+    }
+
+    #[test]
+    fn test_from_config_uses_configured_sampling() {
+        let config = ServerConfig::builder()
+            .log_sampling(LogSampling::OneIn(10))
+            .build();
+        assert_eq!(
+            LoggingMiddleware::from_config(&config).sampling(),
+            LogSampling::OneIn(10)
+        );
+    }
+}
@@ -0,0 +1,698 @@
+//! Request metrics middleware with OpenMetrics text export.
+//!
+//! [`MetricsMiddleware`] counts tool calls, resource reads, and prompt
+//! retrievals, times them, and tracks how many are in flight. A
+//! [`ServerMetrics`] snapshot renders as OpenMetrics text (which Prometheus
+//! also scrapes) without depending on the telemetry crate:
+//!
+//! ```text
+//! # TYPE mcp_requests counter
+//! # HELP mcp_requests MCP requests handled.
+//! mcp_requests_total{operation="tools/call"} 3
+//! ...
+//! # TYPE mcp_requests_in_flight gauge
+//! # HELP mcp_requests_in_flight MCP requests currently being handled.
+//! mcp_requests_in_flight 1
+//! # EOF
+//! ```
+//!
+//! With the `http` feature, [`MetricsMiddleware::router`] serves the same
+//! text at `GET /metrics`. With any transport feature,
+//! [`MetricsMiddleware::with_collector`] also reports each tool call to a
+//! transport [`MetricsCollector`](turbomcp_transport::metrics::MetricsCollector).
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+use serde_json::Value;
+use turbomcp_core::context::RequestContext;
+use turbomcp_core::error::McpResult;
+use turbomcp_types::{PromptResult, ResourceResult, ToolResult};
+
+use super::typed::{McpMiddleware, Next};
+
+/// Upper bounds, in seconds, of the request duration histogram buckets.
+pub const LATENCY_BUCKETS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// `Content-Type` of an OpenMetrics text exposition.
+pub const OPENMETRICS_CONTENT_TYPE: &str =
+    "application/openmetrics-text; version=1.0.0; charset=utf-8";
+
+/// Totals for one MCP operation (`tools/call`, `resources/read`, ...).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OperationMetrics {
+    /// Requests that completed, successfully or not.
+    pub requests: u64,
+    /// Requests that returned an error.
+    pub errors: u64,
+    /// Total handling time, in seconds.
+    pub duration_seconds_sum: f64,
+    /// Requests that took at most the matching [`LATENCY_BUCKETS`] bound;
+    /// cumulative, as in a Prometheus histogram.
+    pub duration_buckets: Vec<u64>,
+}
+
+/// Totals for calls to one tool.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ToolCallMetrics {
+    /// Calls that completed, successfully or not.
+    pub calls: u64,
+    /// Calls that returned an error.
+    pub errors: u64,
+    /// Total call time, in seconds.
+    pub duration_seconds_sum: f64,
+}
+
+/// Point-in-time metrics recorded by a [`MetricsMiddleware`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ServerMetrics {
+    /// Requests currently being handled.
+    pub in_flight: u64,
+    /// Per-operation totals, keyed by MCP method name.
+    pub operations: BTreeMap<String, OperationMetrics>,
+    /// Per-tool totals, keyed by tool name.
+    pub tools: BTreeMap<String, ToolCallMetrics>,
+}
+
+impl ServerMetrics {
+    /// Render as OpenMetrics text, terminated by `# EOF`.
+    #[must_use]
+    pub fn to_openmetrics(&self) -> String {
+        let mut out = String::new();
+
+        family(&mut out, "mcp_requests", "counter", "MCP requests handled.");
+        for (operation, metrics) in &self.operations {
+            let labels = format!("operation=\"{}\"", escape_label_value(operation));
+            sample(&mut out, "mcp_requests_total", &labels, metrics.requests);
+        }
+
+        family(
+            &mut out,
+            "mcp_request_errors",
+            "counter",
+            "MCP requests that returned an error.",
+        );
+        for (operation, metrics) in &self.operations {
+            let labels = format!("operation=\"{}\"", escape_label_value(operation));
+            sample(
+                &mut out,
+                "mcp_request_errors_total",
+                &labels,
+                metrics.errors,
+            );
+        }
+
+        family(
+            &mut out,
+            "mcp_request_duration_seconds",
+            "histogram",
+            "Time spent handling MCP requests.",
+        );
+        let _ = writeln!(out, "# UNIT mcp_request_duration_seconds seconds");
+        for (operation, metrics) in &self.operations {
+            let operation = escape_label_value(operation);
+            for (bound, count) in LATENCY_BUCKETS.iter().zip(&metrics.duration_buckets) {
+                let labels = format!("operation=\"{operation}\",le=\"{bound:?}\"");
+                sample(
+                    &mut out,
+                    "mcp_request_duration_seconds_bucket",
+                    &labels,
+                    count,
+                );
+            }
+            let labels = format!("operation=\"{operation}\",le=\"+Inf\"");
+            sample(
+                &mut out,
+                "mcp_request_duration_seconds_bucket",
+                &labels,
+                metrics.requests,
+            );
+            let labels = format!("operation=\"{operation}\"");
+            sample(
+                &mut out,
+                "mcp_request_duration_seconds_count",
+                &labels,
+                metrics.requests,
+            );
+            sample(
+                &mut out,
+                "mcp_request_duration_seconds_sum",
+                &labels,
+                format_args!("{:?}", metrics.duration_seconds_sum),
+            );
+        }
+
+        family(&mut out, "mcp_tool_calls", "counter", "Tool calls handled.");
+        for (tool, metrics) in &self.tools {
+            let labels = format!("tool=\"{}\"", escape_label_value(tool));
+            sample(&mut out, "mcp_tool_calls_total", &labels, metrics.calls);
+        }
+
+        family(
+            &mut out,
+            "mcp_tool_errors",
+            "counter",
+            "Tool calls that returned an error.",
+        );
+        for (tool, metrics) in &self.tools {
+            let labels = format!("tool=\"{}\"", escape_label_value(tool));
+            sample(&mut out, "mcp_tool_errors_total", &labels, metrics.errors);
+        }
+
+        family(
+            &mut out,
+            "mcp_tool_duration_seconds",
+            "summary",
+            "Time spent in tool calls.",
+        );
+        let _ = writeln!(out, "# UNIT mcp_tool_duration_seconds seconds");
+        for (tool, metrics) in &self.tools {
+            let labels = format!("tool=\"{}\"", escape_label_value(tool));
+            sample(
+                &mut out,
+                "mcp_tool_duration_seconds_count",
+                &labels,
+                metrics.calls,
+            );
+            sample(
+                &mut out,
+                "mcp_tool_duration_seconds_sum",
+                &labels,
+                format_args!("{:?}", metrics.duration_seconds_sum),
+            );
+        }
+
+        family(
+            &mut out,
+            "mcp_requests_in_flight",
+            "gauge",
+            "MCP requests currently being handled.",
+        );
+        sample(&mut out, "mcp_requests_in_flight", "", self.in_flight);
+
+        out.push_str("# EOF\n");
+        out
+    }
+}
+
+fn family(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# TYPE {name} {kind}");
+    let _ = writeln!(out, "# HELP {name} {help}");
+}
+
+fn sample(out: &mut String, name: &str, labels: &str, value: impl std::fmt::Display) {
+    if labels.is_empty() {
+        let _ = writeln!(out, "{name} {value}");
+    } else {
+        let _ = writeln!(out, "{name}{{{labels}}} {value}");
+    }
+}
+
+/// Escape an OpenMetrics label value (backslash, double quote, newline).
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[derive(Debug, Default)]
+struct Recorded {
+    operations: BTreeMap<String, OperationMetrics>,
+    tools: BTreeMap<String, ToolCallMetrics>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    in_flight: AtomicU64,
+    recorded: Mutex<Recorded>,
+}
+
+/// Decrements the in-flight gauge when a request finishes or is dropped.
+struct InFlight<'a>(&'a AtomicU64);
+
+impl<'a> InFlight<'a> {
+    fn start(gauge: &'a AtomicU64) -> Self {
+        gauge.fetch_add(1, Ordering::Relaxed);
+        Self(gauge)
+    }
+}
+
+impl Drop for InFlight<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Middleware that records request counts, latencies, per-tool totals, and
+/// the number of requests in flight.
+///
+/// Clones share the same state, so a handle can be kept for exporting
+/// [`metrics`](Self::metrics) after the middleware joins a stack.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use turbomcp_server::{MetricsMiddleware, MiddlewareStack};
+///
+/// let metrics = MetricsMiddleware::new();
+/// let handler = MiddlewareStack::new(MyServer).with_middleware(metrics.clone());
+///
+/// // Serve `GET /metrics` next to the MCP router (requires `http`).
+/// let app = handler.builder().into_axum_router().merge(metrics.router());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MetricsMiddleware {
+    state: Arc<State>,
+    /// Also receives every tool call, if set.
+    #[cfg(any(
+        feature = "stdio",
+        feature = "http",
+        feature = "tcp",
+        feature = "unix",
+        feature = "channel"
+    ))]
+    collector: Option<turbomcp_transport::metrics::MetricsCollector>,
+}
+
+impl MetricsMiddleware {
+    /// Create a metrics middleware with nothing recorded.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also report each tool call to `collector`, for its
+    /// [`get_tool_metrics`](turbomcp_transport::metrics::MetricsCollector::get_tool_metrics)
+    /// and exporters.
+    #[cfg(any(
+        feature = "stdio",
+        feature = "http",
+        feature = "tcp",
+        feature = "unix",
+        feature = "channel"
+    ))]
+    #[must_use]
+    pub fn with_collector(
+        mut self,
+        collector: turbomcp_transport::metrics::MetricsCollector,
+    ) -> Self {
+        self.collector = Some(collector);
+        self
+    }
+
+    /// Snapshot of the metrics recorded so far.
+    #[must_use]
+    pub fn metrics(&self) -> ServerMetrics {
+        let recorded = self.state.recorded.lock();
+        ServerMetrics {
+            in_flight: self.state.in_flight.load(Ordering::Relaxed),
+            operations: recorded.operations.clone(),
+            tools: recorded.tools.clone(),
+        }
+    }
+
+    /// Current metrics rendered as OpenMetrics text.
+    #[must_use]
+    pub fn openmetrics(&self) -> String {
+        self.metrics().to_openmetrics()
+    }
+
+    /// Axum router serving [`openmetrics`](Self::openmetrics) at `GET /metrics`
+    #[cfg(feature = "http")]
+    pub fn router(self) -> axum::Router {
+        use axum::http::header::CONTENT_TYPE;
+
+        axum::Router::new().route(
+            "/metrics",
+            axum::routing::get(move || {
+                let body = self.openmetrics();
+                async move { ([(CONTENT_TYPE, OPENMETRICS_CONTENT_TYPE)], body) }
+            }),
+        )
+    }
+
+    /// Run `request`, timing it and counting it against `operation`.
+    async fn observe<T>(
+        &self,
+        operation: &str,
+        tool: Option<&str>,
+        request: impl Future<Output = McpResult<T>>,
+    ) -> McpResult<T> {
+        let in_flight = InFlight::start(&self.state.in_flight);
+        let started = Instant::now();
+        let result = request.await;
+        self.record(operation, tool, started.elapsed(), result.is_err());
+        drop(in_flight);
+        result
+    }
+
+    fn record(&self, operation: &str, tool: Option<&str>, elapsed: Duration, failed: bool) {
+        let seconds = elapsed.as_secs_f64();
+        let mut recorded = self.state.recorded.lock();
+
+        let op = recorded
+            .operations
+            .entry(operation.to_string())
+            .or_insert_with(|| OperationMetrics {
+                duration_buckets: vec![0; LATENCY_BUCKETS.len()],
+                ..OperationMetrics::default()
+            });
+        op.requests += 1;
+        op.errors += u64::from(failed);
+        op.duration_seconds_sum += seconds;
+        for (bound, count) in LATENCY_BUCKETS.iter().zip(&mut op.duration_buckets) {
+            if seconds <= *bound {
+                *count += 1;
+            }
+        }
+
+        if let Some(tool) = tool {
+            let stats = recorded.tools.entry(tool.to_string()).or_default();
+            stats.calls += 1;
+            stats.errors += u64::from(failed);
+            stats.duration_seconds_sum += seconds;
+
+            #[cfg(any(
+                feature = "stdio",
+                feature = "http",
+                feature = "tcp",
+                feature = "unix",
+                feature = "channel"
+            ))]
+            if let Some(collector) = &self.collector {
+                collector.record_tool_call(tool, !failed, elapsed);
+            }
+        }
+    }
+}
+
+impl McpMiddleware for MetricsMiddleware {
+    fn on_call_tool<'a>(
+        &'a self,
+        name: &'a str,
+        args: Value,
+        ctx: &'a RequestContext,
+        next: Next<'a>,
+    ) -> Pin<Box<dyn Future<Output = McpResult<ToolResult>> + Send + 'a>> {
+        Box::pin(self.observe("tools/call", Some(name), next.call_tool(name, args, ctx)))
+    }
+
+    fn on_read_resource<'a>(
+        &'a self,
+        uri: &'a str,
+        ctx: &'a RequestContext,
+        next: Next<'a>,
+    ) -> Pin<Box<dyn Future<Output = McpResult<ResourceResult>> + Send + 'a>> {
+        Box::pin(self.observe("resources/read", None, next.read_resource(uri, ctx)))
+    }
+
+    fn on_get_prompt<'a>(
+        &'a self,
+        name: &'a str,
+        args: Option<Value>,
+        ctx: &'a RequestContext,
+        next: Next<'a>,
+    ) -> Pin<Box<dyn Future<Output = McpResult<PromptResult>> + Send + 'a>> {
+        Box::pin(self.observe("prompts/get", None, next.get_prompt(name, args, ctx)))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::manual_async_fn)]
+mod tests {
+    use super::*;
+    use crate::middleware::MiddlewareStack;
+    use std::collections::HashMap;
+    use turbomcp_core::error::McpError;
+    use turbomcp_core::handler::McpHandler;
+    use turbomcp_types::{Prompt, Resource, ServerInfo, Tool};
+
+    #[derive(Clone)]
+    struct TestHandler;
+
+    impl McpHandler for TestHandler {
+        fn server_info(&self) -> ServerInfo {
+            ServerInfo::new("metrics-test", "1.0.0")
+        }
+
+        fn list_tools(&self) -> Vec<Tool> {
+            vec![Tool::new("echo", "Echo"), Tool::new("fail", "Always fails")]
+        }
+
+        fn list_resources(&self) -> Vec<Resource> {
+            vec![]
+        }
+
+        fn list_prompts(&self) -> Vec<Prompt> {
+            vec![]
+        }
+
+        fn call_tool<'a>(
+            &'a self,
+            name: &'a str,
+            args: Value,
+            _ctx: &'a RequestContext,
+        ) -> impl Future<Output = McpResult<ToolResult>> + Send + 'a {
+            async move {
+                match name {
+                    "fail" => Err(McpError::internal("boom")),
+                    _ => Ok(ToolResult::text(args.to_string())),
+                }
+            }
+        }
+
+        fn read_resource<'a>(
+            &'a self,
+            uri: &'a str,
+            _ctx: &'a RequestContext,
+        ) -> impl Future<Output = McpResult<ResourceResult>> + Send + 'a {
+            let uri = uri.to_string();
+            async move { Err(McpError::resource_not_found(&uri)) }
+        }
+
+        fn get_prompt<'a>(
+            &'a self,
+            name: &'a str,
+            _args: Option<Value>,
+            _ctx: &'a RequestContext,
+        ) -> impl Future<Output = McpResult<PromptResult>> + Send + 'a {
+            let name = name.to_string();
+            async move { Err(McpError::prompt_not_found(&name)) }
+        }
+    }
+
+    /// Check `text` against the OpenMetrics text format: `# TYPE` before each
+    /// family's samples, sample names matching their family's type, label
+    /// sets and values that parse, no interleaved families, and `# EOF` last.
+    fn assert_valid_openmetrics(text: &str) {
+        let body = text
+            .strip_suffix("# EOF\n")
+            .expect("exposition must end with # EOF");
+        let mut types: HashMap<&str, &str> = HashMap::new();
+        let mut current: Option<&str> = None;
+
+        for line in body.lines() {
+            if let Some(comment) = line.strip_prefix("# ") {
+                let mut parts = comment.splitn(3, ' ');
+                let keyword = parts.next().unwrap();
+                let name = parts.next().expect("metadata names a family");
+                let value = parts.next().expect("metadata has a value");
+                match keyword {
+                    "TYPE" => {
+                        assert!(
+                            matches!(value, "counter" | "gauge" | "histogram" | "summary"),
+                            "{line}"
+                        );
+                        assert!(types.insert(name, value).is_none(), "duplicate {line}");
+                        current = Some(name);
+                    }
+                    "HELP" | "UNIT" => assert_eq!(current, Some(name), "{line}"),
+                    _ => panic!("unknown metadata: {line}"),
+                }
+                continue;
+            }
+
+            let family = current.expect("sample before any # TYPE");
+            let (series, value) = line.rsplit_once(' ').expect("sample has a value");
+            let name = match series.split_once('{') {
+                Some((name, labels)) => {
+                    let labels = labels.strip_suffix('}').expect("closed label set");
+                    assert_valid_labels(labels, line);
+                    name
+                }
+                None => series,
+            };
+            let suffix = name
+                .strip_prefix(family)
+                .unwrap_or_else(|| panic!("{name} outside family {family}"));
+            let allowed: &[&str] = match types[family] {
+                "counter" => &["_total"],
+                "gauge" => &[""],
+                "histogram" => &["_bucket", "_count", "_sum"],
+                _ => &["", "_count", "_sum"],
+            };
+            assert!(allowed.contains(&suffix), "bad suffix: {line}");
+            assert!(
+                value == "+Inf" || value.parse::<f64>().is_ok(),
+                "bad value: {line}"
+            );
+        }
+    }
+
+    fn assert_valid_labels(mut labels: &str, line: &str) {
+        while !labels.is_empty() {
+            let (name, rest) = labels.split_once("=\"").expect(line);
+            assert!(
+                !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'),
+                "bad label name: {line}"
+            );
+            let mut escaped = false;
+            let end = rest
+                .char_indices()
+                .find(|&(_, c)| {
+                    let closes = c == '"' && !escaped;
+                    escaped = c == '\\' && !escaped;
+                    closes
+                })
+                .map(|(i, _)| i)
+                .expect(line);
+            labels = rest[end + 1..]
+                .strip_prefix(',')
+                .unwrap_or(&rest[end + 1..]);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_openmetrics_export_is_valid_and_complete() {
+        let metrics = MetricsMiddleware::new();
+        let stack = MiddlewareStack::new(TestHandler).with_middleware(metrics.clone());
+        let ctx = RequestContext::stdio();
+
+        stack
+            .call_tool("echo", serde_json::json!({}), &ctx)
+            .await
+            .unwrap();
+        stack
+            .call_tool("echo", serde_json::json!({}), &ctx)
+            .await
+            .unwrap();
+        assert!(
+            stack
+                .call_tool("fail", serde_json::json!({}), &ctx)
+                .await
+                .is_err()
+        );
+        assert!(stack.read_resource("file:///missing", &ctx).await.is_err());
+
+        let snapshot = metrics.metrics();
+        assert_eq!(snapshot.in_flight, 0);
+        assert_eq!(snapshot.operations["tools/call"].requests, 3);
+        assert_eq!(snapshot.tools["fail"].errors, 1);
+
+        let text = metrics.openmetrics();
+        assert_valid_openmetrics(&text);
+        for expected in [
+            "mcp_requests_total{operation=\"tools/call\"} 3",
+            "mcp_requests_total{operation=\"resources/read\"} 1",
+            "mcp_request_errors_total{operation=\"tools/call\"} 1",
+            "mcp_request_duration_seconds_bucket{operation=\"tools/call\",le=\"+Inf\"} 3",
+            "mcp_request_duration_seconds_count{operation=\"resources/read\"} 1",
+            "mcp_tool_calls_total{tool=\"echo\"} 2",
+            "mcp_tool_errors_total{tool=\"echo\"} 0",
+            "mcp_tool_errors_total{tool=\"fail\"} 1",
+            "mcp_tool_duration_seconds_count{tool=\"fail\"} 1",
+            "mcp_requests_in_flight 0",
+        ] {
+            assert!(
+                text.lines().any(|line| line == expected),
+                "missing `{expected}` in:\n{text}"
+            );
+        }
+    }
+
+    #[cfg(feature = "stdio")]
+    #[tokio::test]
+    async fn test_tool_calls_reach_transport_collector() {
+        let collector = turbomcp_transport::metrics::MetricsCollector::new();
+        let metrics = MetricsMiddleware::new().with_collector(collector.clone());
+        let stack = MiddlewareStack::new(TestHandler).with_middleware(metrics);
+        let ctx = RequestContext::stdio();
+
+        stack
+            .call_tool("echo", serde_json::json!({}), &ctx)
+            .await
+            .unwrap();
+        assert!(
+            stack
+                .call_tool("fail", serde_json::json!({}), &ctx)
+                .await
+                .is_err()
+        );
+        assert!(stack.read_resource("file:///missing", &ctx).await.is_err());
+
+        let echo = collector.get_tool_metrics("echo").unwrap();
+        assert_eq!((echo.invocations, echo.successes, echo.errors), (1, 1, 0));
+        let fail = collector.get_tool_metrics("fail").unwrap();
+        assert_eq!((fail.invocations, fail.successes, fail.errors), (1, 0, 1));
+        assert_eq!(collector.get_all_tool_metrics().len(), 2);
+    }
+
+    #[test]
+    fn test_in_flight_gauge_and_label_escaping() {
+        let metrics = ServerMetrics {
+            in_flight: 2,
+            tools: BTreeMap::from([(
+                "say \"hi\"\\\n".to_string(),
+                ToolCallMetrics {
+                    calls: 1,
+                    errors: 0,
+                    duration_seconds_sum: 0.25,
+                },
+            )]),
+            ..ServerMetrics::default()
+        };
+
+        let text = metrics.to_openmetrics();
+        assert_valid_openmetrics(&text);
+        assert!(text.contains("mcp_tool_calls_total{tool=\"say \\\"hi\\\"\\\\\\n\"} 1\n"));
+        assert!(
+            text.contains("mcp_tool_duration_seconds_sum{tool=\"say \\\"hi\\\"\\\\\\n\"} 0.25\n")
+        );
+        assert!(text.contains("\nmcp_requests_in_flight 2\n"));
+    }
+
+    #[cfg(feature = "http")]
+    #[tokio::test]
+    async fn test_metrics_endpoint_serves_openmetrics() {
+        use axum::body::{Body, to_bytes};
+        use tower::ServiceExt;
+
+        let metrics = MetricsMiddleware::new();
+        let request = axum::http::Request::builder()
+            .uri("/metrics")
+            .body(Body::empty())
+            .expect("request");
+        let response = metrics.router().oneshot(request).await.expect("response");
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+        assert_eq!(
+            response.headers()[axum::http::header::CONTENT_TYPE],
+            OPENMETRICS_CONTENT_TYPE
+        );
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body");
+        let text = String::from_utf8(body.to_vec()).expect("utf-8");
+        assert_valid_openmetrics(&text);
+        assert!(text.ends_with("mcp_requests_in_flight 0\n# EOF\n"));
+    }
+}
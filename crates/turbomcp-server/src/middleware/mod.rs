@@ -26,6 +26,19 @@
 //! }
 //! ```
 
+pub mod idempotency;
+pub mod logging;
+pub mod metrics;
+pub mod size;
 pub mod typed;
+pub mod upload;
 
+pub use idempotency::{IDEMPOTENCY_KEY_META, IdempotencyMiddleware};
+pub use logging::LoggingMiddleware;
+pub use metrics::{
+    LATENCY_BUCKETS, MetricsMiddleware, OPENMETRICS_CONTENT_TYPE, OperationMetrics, ServerMetrics,
+    ToolCallMetrics,
+};
+pub use size::{SizeAccountingMiddleware, SizeMetrics};
 pub use typed::{McpMiddleware, MiddlewareStack, Next};
+pub use upload::{ChunkedUploadMiddleware, DEFAULT_UPLOAD_ARGUMENT, UPLOAD_META};
@@ -0,0 +1,336 @@
+//! Request/response size accounting middleware.
+//!
+//! [`SizeAccountingMiddleware`] measures the serialized size of every tool
+//! call, resource read, and prompt retrieval along with the result it
+//! produces, and keeps running totals that can be exported as metrics. It can
+//! optionally enforce a per-session byte quota: once a session has exchanged
+//! more than the quota, further requests are rejected with a rate-limit error
+//! before they reach the handler.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use dashmap::DashMap;
+use serde::Serialize;
+use serde_json::Value;
+use turbomcp_core::context::RequestContext;
+use turbomcp_core::error::{McpError, McpResult};
+use turbomcp_types::{PromptResult, ResourceResult, ToolResult};
+
+use super::typed::{McpMiddleware, Next};
+
+/// Point-in-time totals recorded by a [`SizeAccountingMiddleware`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SizeMetrics {
+    /// Requests measured, including rejected ones.
+    pub requests: u64,
+    /// Serialized bytes of request parameters.
+    pub request_bytes: u64,
+    /// Serialized bytes of successful responses.
+    pub response_bytes: u64,
+    /// Largest single request seen, in bytes.
+    pub max_request_bytes: u64,
+    /// Largest single response seen, in bytes.
+    pub max_response_bytes: u64,
+    /// Requests rejected because their session exceeded its byte quota.
+    pub quota_rejections: u64,
+}
+
+#[derive(Debug, Default)]
+struct Counters {
+    requests: AtomicU64,
+    request_bytes: AtomicU64,
+    response_bytes: AtomicU64,
+    max_request_bytes: AtomicU64,
+    max_response_bytes: AtomicU64,
+    quota_rejections: AtomicU64,
+    session_bytes: DashMap<String, u64>,
+}
+
+/// Middleware that records request and response sizes and optionally
+/// enforces a per-session byte quota.
+///
+/// Clones share the same counters, so a handle can be kept for exporting
+/// [`metrics`](Self::metrics) after the middleware joins a stack. Requests
+/// without a session id are accounted together under one anonymous session.
+/// A session's usage is forgotten when the session closes.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use turbomcp_server::{MiddlewareStack, SizeAccountingMiddleware};
+///
+/// let sizes = SizeAccountingMiddleware::new().with_session_quota(10 * 1024 * 1024);
+/// let handler = MiddlewareStack::new(MyServer).with_middleware(sizes.clone());
+///
+/// // Later, e.g. from a metrics endpoint:
+/// let metrics = sizes.metrics();
+/// println!("{} bytes in, {} bytes out", metrics.request_bytes, metrics.response_bytes);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SizeAccountingMiddleware {
+    session_quota: Option<u64>,
+    counters: Arc<Counters>,
+}
+
+impl SizeAccountingMiddleware {
+    /// Create a size accounting middleware without a quota.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reject requests from a session once it has exchanged more than
+    /// `bytes` of requests and responses combined.
+    #[must_use]
+    pub fn with_session_quota(mut self, bytes: u64) -> Self {
+        self.session_quota = Some(bytes);
+        self
+    }
+
+    /// The configured per-session byte quota, if any.
+    #[must_use]
+    pub fn session_quota(&self) -> Option<u64> {
+        self.session_quota
+    }
+
+    /// Snapshot of the totals recorded so far.
+    #[must_use]
+    pub fn metrics(&self) -> SizeMetrics {
+        let c = &self.counters;
+        SizeMetrics {
+            requests: c.requests.load(Ordering::Relaxed),
+            request_bytes: c.request_bytes.load(Ordering::Relaxed),
+            response_bytes: c.response_bytes.load(Ordering::Relaxed),
+            max_request_bytes: c.max_request_bytes.load(Ordering::Relaxed),
+            max_response_bytes: c.max_response_bytes.load(Ordering::Relaxed),
+            quota_rejections: c.quota_rejections.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Bytes exchanged so far by `session_id`.
+    #[must_use]
+    pub fn session_bytes(&self, session_id: &str) -> u64 {
+        self.counters
+            .session_bytes
+            .get(session_id)
+            .map_or(0, |used| *used)
+    }
+
+    /// Forget the usage recorded for `session_id`, e.g. when it closes.
+    pub fn reset_session(&self, session_id: &str) {
+        self.counters.session_bytes.remove(session_id);
+    }
+
+    /// Record an incoming request, rejecting it if its session is over quota.
+    fn admit(&self, operation: &str, ctx: &RequestContext, bytes: u64) -> McpResult<()> {
+        let c = &self.counters;
+        c.requests.fetch_add(1, Ordering::Relaxed);
+        c.request_bytes.fetch_add(bytes, Ordering::Relaxed);
+        c.max_request_bytes.fetch_max(bytes, Ordering::Relaxed);
+
+        let session = ctx.session_id().unwrap_or_default();
+        let mut used = c.session_bytes.entry(session.to_string()).or_default();
+        if let Some(quota) = self.session_quota
+            && used.saturating_add(bytes) > quota
+        {
+            c.quota_rejections.fetch_add(1, Ordering::Relaxed);
+            tracing::warn!(
+                operation,
+                session_id = session,
+                used = *used,
+                request_bytes = bytes,
+                quota,
+                "byte quota exceeded"
+            );
+            return Err(McpError::rate_limited(format!(
+                "Byte quota exceeded: {operation} needs {bytes} bytes but session has used {} of {quota}",
+                *used
+            )));
+        }
+        *used += bytes;
+        Ok(())
+    }
+
+    /// Record the size of a successful response.
+    fn record_response<T: Serialize>(&self, ctx: &RequestContext, result: &McpResult<T>) {
+        let Ok(response) = result else {
+            return;
+        };
+        let bytes = serialized_len(response);
+        let c = &self.counters;
+        c.response_bytes.fetch_add(bytes, Ordering::Relaxed);
+        c.max_response_bytes.fetch_max(bytes, Ordering::Relaxed);
+        *c.session_bytes
+            .entry(ctx.session_id().unwrap_or_default().to_string())
+            .or_default() += bytes;
+    }
+}
+
+fn serialized_len<T: Serialize + ?Sized>(value: &T) -> u64 {
+    serde_json::to_vec(value).map_or(0, |bytes| bytes.len() as u64)
+}
+
+impl McpMiddleware for SizeAccountingMiddleware {
+    fn on_call_tool<'a>(
+        &'a self,
+        name: &'a str,
+        args: Value,
+        ctx: &'a RequestContext,
+        next: Next<'a>,
+    ) -> Pin<Box<dyn Future<Output = McpResult<ToolResult>> + Send + 'a>> {
+        Box::pin(async move {
+            let bytes = serialized_len(name) + serialized_len(&args);
+            self.admit("tools/call", ctx, bytes)?;
+            let result = next.call_tool(name, args, ctx).await;
+            self.record_response(ctx, &result);
+            result
+        })
+    }
+
+    fn on_read_resource<'a>(
+        &'a self,
+        uri: &'a str,
+        ctx: &'a RequestContext,
+        next: Next<'a>,
+    ) -> Pin<Box<dyn Future<Output = McpResult<ResourceResult>> + Send + 'a>> {
+        Box::pin(async move {
+            self.admit("resources/read", ctx, serialized_len(uri))?;
+            let result = next.read_resource(uri, ctx).await;
+            self.record_response(ctx, &result);
+            result
+        })
+    }
+
+    fn on_get_prompt<'a>(
+        &'a self,
+        name: &'a str,
+        args: Option<Value>,
+        ctx: &'a RequestContext,
+        next: Next<'a>,
+    ) -> Pin<Box<dyn Future<Output = McpResult<PromptResult>> + Send + 'a>> {
+        Box::pin(async move {
+            let bytes = serialized_len(name) + serialized_len(&args);
+            self.admit("prompts/get", ctx, bytes)?;
+            let result = next.get_prompt(name, args, ctx).await;
+            self.record_response(ctx, &result);
+            result
+        })
+    }
+
+    fn on_session_closed(&self, session_id: &str) {
+        self.reset_session(session_id);
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::manual_async_fn)]
+mod tests {
+    use super::*;
+    use crate::middleware::MiddlewareStack;
+    use turbomcp_core::error::ErrorKind;
+    use turbomcp_core::handler::McpHandler;
+    use turbomcp_types::{Prompt, Resource, ServerInfo, Tool};
+
+    #[derive(Clone)]
+    struct EchoHandler;
+
+    impl McpHandler for EchoHandler {
+        fn server_info(&self) -> ServerInfo {
+            ServerInfo::new("size-test", "1.0.0")
+        }
+
+        fn list_tools(&self) -> Vec<Tool> {
+            vec![Tool::new("echo", "Echo")]
+        }
+
+        fn list_resources(&self) -> Vec<Resource> {
+            vec![]
+        }
+
+        fn list_prompts(&self) -> Vec<Prompt> {
+            vec![]
+        }
+
+        fn call_tool<'a>(
+            &'a self,
+            _name: &'a str,
+            args: Value,
+            _ctx: &'a RequestContext,
+        ) -> impl Future<Output = McpResult<ToolResult>> + Send + 'a {
+            async move { Ok(ToolResult::text(args.to_string())) }
+        }
+
+        fn read_resource<'a>(
+            &'a self,
+            uri: &'a str,
+            _ctx: &'a RequestContext,
+        ) -> impl Future<Output = McpResult<ResourceResult>> + Send + 'a {
+            let uri = uri.to_string();
+            async move { Err(McpError::resource_not_found(&uri)) }
+        }
+
+        fn get_prompt<'a>(
+            &'a self,
+            name: &'a str,
+            _args: Option<Value>,
+            _ctx: &'a RequestContext,
+        ) -> impl Future<Output = McpResult<PromptResult>> + Send + 'a {
+            let name = name.to_string();
+            async move { Err(McpError::prompt_not_found(&name)) }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_records_request_and_response_sizes() {
+        let sizes = SizeAccountingMiddleware::new();
+        let stack = MiddlewareStack::new(EchoHandler).with_middleware(sizes.clone());
+        let ctx = RequestContext::stdio().with_session_id("s1");
+        let args = serde_json::json!({"text": "hello"});
+
+        let result = stack.call_tool("echo", args.clone(), &ctx).await.unwrap();
+
+        let request_bytes = serialized_len("echo") + serialized_len(&args);
+        let response_bytes = serialized_len(&result);
+        let metrics = sizes.metrics();
+        assert_eq!(metrics.requests, 1);
+        assert_eq!(metrics.request_bytes, request_bytes);
+        assert_eq!(metrics.response_bytes, response_bytes);
+        assert_eq!(metrics.max_response_bytes, response_bytes);
+        assert_eq!(sizes.session_bytes("s1"), request_bytes + response_bytes);
+
+        // Failed requests count toward request bytes only.
+        assert!(stack.read_resource("file:///missing", &ctx).await.is_err());
+        let metrics = sizes.metrics();
+        assert_eq!(metrics.requests, 2);
+        assert_eq!(metrics.response_bytes, response_bytes);
+    }
+
+    #[tokio::test]
+    async fn test_session_quota_breach_is_rejected() {
+        let sizes = SizeAccountingMiddleware::new().with_session_quota(64);
+        let stack = MiddlewareStack::new(EchoHandler).with_middleware(sizes.clone());
+        let ctx = RequestContext::stdio().with_session_id("s1");
+        let args = serde_json::json!({"text": "hi"});
+
+        stack.call_tool("echo", args.clone(), &ctx).await.unwrap();
+        let err = stack
+            .call_tool("echo", args.clone(), &ctx)
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind, ErrorKind::RateLimited);
+        assert!(err.message.contains("Byte quota exceeded"), "{err}");
+        assert_eq!(sizes.metrics().quota_rejections, 1);
+
+        // Other sessions have their own allowance.
+        let other = RequestContext::stdio().with_session_id("s2");
+        assert!(stack.call_tool("echo", args.clone(), &other).await.is_ok());
+
+        // Closing the session drops its usage.
+        stack.on_session_closed("s1");
+        assert!(!sizes.counters.session_bytes.contains_key("s1"));
+        assert!(stack.call_tool("echo", args, &ctx).await.is_ok());
+    }
+}
@@ -142,6 +142,12 @@ pub trait McpMiddleware: Send + Sync + 'static {
     ) -> Pin<Box<dyn Future<Output = McpResult<()>> + Send + 'a>> {
         Box::pin(async move { next.shutdown().await })
     }
+
+    /// Hook called when a client session ends.
+    ///
+    /// This is a notification rather than a chained call: every middleware
+    /// sees it, then the handler. Use it to drop per-session state.
+    fn on_session_closed(&self, _session_id: &str) {}
 }
 
 /// Continuation for calling the next middleware or handler.
@@ -187,25 +193,25 @@ impl<'a> Next<'a> {
     /// here would require making `Next::list_tools` async. For now we honor
     /// the single override invoked by [`MiddlewareStack`] and skip any deeper
     /// hooks. See `MiddlewareStack::list_tools` for the entry point.
-    pub fn list_tools(self) -> Vec<Tool> {
+    pub fn list_tools(&self) -> Vec<Tool> {
         self.handler.dyn_list_tools()
     }
 
     /// Forward `list_resources` directly to the wrapped handler. See
     /// [`Self::list_tools`] for the chaining caveat.
-    pub fn list_resources(self) -> Vec<Resource> {
+    pub fn list_resources(&self) -> Vec<Resource> {
         self.handler.dyn_list_resources()
     }
 
     /// Forward `list_resource_templates` directly to the wrapped handler. See
     /// [`Self::list_tools`] for the chaining caveat.
-    pub fn list_resource_templates(self) -> Vec<ResourceTemplate> {
+    pub fn list_resource_templates(&self) -> Vec<ResourceTemplate> {
         self.handler.dyn_list_resource_templates()
     }
 
     /// Forward `list_prompts` directly to the wrapped handler. See
     /// [`Self::list_tools`] for the chaining caveat.
-    pub fn list_prompts(self) -> Vec<Prompt> {
+    pub fn list_prompts(&self) -> Vec<Prompt> {
         self.handler.dyn_list_prompts()
     }
 
@@ -429,6 +435,11 @@ impl<H: McpHandler> MiddlewareStack<H> {
         self.middlewares.len()
     }
 
+    /// Borrow the wrapped handler.
+    pub(crate) fn handler(&self) -> &H {
+        &self.handler.handler
+    }
+
     fn next(&self) -> Next<'_> {
         Next::new(self.handler.as_ref(), &self.middlewares, 0)
     }
@@ -501,6 +512,13 @@ impl<H: McpHandler> McpHandler for MiddlewareStack<H> {
     ) -> impl std::future::Future<Output = McpResult<()>> + turbomcp_core::marker::MaybeSend {
         async move { self.next().shutdown().await }
     }
+
+    fn on_session_closed(&self, session_id: &str) {
+        for middleware in self.middlewares.iter() {
+            middleware.on_session_closed(session_id);
+        }
+        self.handler.handler.on_session_closed(session_id);
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,675 @@
+//! Chunked uploads for tool arguments.
+//!
+//! A tool that accepts a large file would otherwise receive the whole payload
+//! inline in one `tools/call`. With [`ChunkedUploadMiddleware`] a client
+//! instead sends the payload as base64 chunks over several calls to the same
+//! tool, describing each chunk under the [`UPLOAD_META`] key of the request's
+//! `_meta`:
+//!
+//! ```json
+//! {"name": "store_file", "arguments": {"path": "q3.csv"},
+//!  "_meta": {"upload": {"id": "u-1", "index": 0, "data": "aWQsdG90YWwK", "final": false}}}
+//! ```
+//!
+//! Chunks before the final one are buffered and acknowledged without running
+//! the tool. The final chunk runs the tool once, with the reassembled payload
+//! inserted as a base64 string under the configured argument (default
+//! [`DEFAULT_UPLOAD_ARGUMENT`]) alongside the final call's own arguments.
+//! Calls without upload metadata pass through.
+//!
+//! Uploads are keyed by session id. Only STDIO, which serves a single client,
+//! accepts chunks without one; elsewhere a sessionless chunk is rejected so
+//! clients sharing a connection-less key cannot touch each other's uploads.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use dashmap::DashMap;
+use serde::Deserialize;
+use serde_json::Value;
+use turbomcp_core::clock::{Clock, SystemClock};
+use turbomcp_core::context::{RequestContext, TransportType};
+use turbomcp_core::error::{McpError, McpResult};
+use turbomcp_types::ToolResult;
+
+use super::typed::{McpMiddleware, Next};
+
+/// `_meta` field describing one chunk of an upload.
+pub const UPLOAD_META: &str = "upload";
+
+/// Tool argument that receives the reassembled payload by default.
+pub const DEFAULT_UPLOAD_ARGUMENT: &str = "data";
+
+/// Default limit on the reassembled payload: 64 MiB.
+const DEFAULT_MAX_UPLOAD_SIZE: usize = 64 * 1024 * 1024;
+
+/// Default time an unfinished upload survives without a new chunk: 5 minutes.
+const DEFAULT_IDLE_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Default limit on unfinished uploads per session.
+const DEFAULT_MAX_CONCURRENT_UPLOADS: usize = 4;
+
+/// Default limit on bytes buffered across all unfinished uploads: 256 MiB.
+const DEFAULT_MAX_BUFFERED_BYTES: usize = 256 * 1024 * 1024;
+
+/// One chunk, as sent under [`UPLOAD_META`].
+#[derive(Debug, Deserialize)]
+struct Chunk {
+    id: String,
+    index: u64,
+    data: String,
+    #[serde(default, rename = "final")]
+    last: bool,
+}
+
+#[derive(Debug)]
+struct Upload {
+    tool: String,
+    next_index: u64,
+    data: Vec<u8>,
+    updated: Instant,
+}
+
+/// Middleware that reassembles tool arguments uploaded in chunks.
+///
+/// Upload ids are scoped to the session, and chunks must arrive in order
+/// starting at index 0, all for the same tool. A chunk that is out of order,
+/// is not valid base64, or takes the payload past the size limit fails with
+/// an invalid-params error and discards the upload. Opening more than
+/// [`with_max_concurrent_uploads`](Self::with_max_concurrent_uploads)
+/// uploads in one session, or buffering more than
+/// [`with_max_buffered_bytes`](Self::with_max_buffered_bytes) across all of
+/// them, fails with a rate-limit error. A session's uploads are dropped when
+/// it closes. Clones share the same buffers.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use turbomcp_server::{ChunkedUploadMiddleware, MiddlewareStack};
+///
+/// let handler = MiddlewareStack::new(MyServer).with_middleware(
+///     ChunkedUploadMiddleware::new()
+///         .with_argument("contents")
+///         .with_max_size(16 * 1024 * 1024),
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct ChunkedUploadMiddleware {
+    argument: String,
+    max_size: usize,
+    max_concurrent_uploads: usize,
+    max_buffered_bytes: usize,
+    idle_timeout: Duration,
+    clock: Arc<dyn Clock>,
+    uploads: Arc<DashMap<(String, String), Upload>>,
+    /// Bytes held across all entries of `uploads`.
+    buffered: Arc<AtomicUsize>,
+}
+
+impl Default for ChunkedUploadMiddleware {
+    fn default() -> Self {
+        Self {
+            argument: DEFAULT_UPLOAD_ARGUMENT.to_string(),
+            max_size: DEFAULT_MAX_UPLOAD_SIZE,
+            max_concurrent_uploads: DEFAULT_MAX_CONCURRENT_UPLOADS,
+            max_buffered_bytes: DEFAULT_MAX_BUFFERED_BYTES,
+            idle_timeout: DEFAULT_IDLE_TIMEOUT,
+            clock: SystemClock::shared(),
+            uploads: Arc::new(DashMap::new()),
+            buffered: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+}
+
+impl ChunkedUploadMiddleware {
+    /// Create an upload middleware with a 64 MiB limit.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pass the reassembled payload to the tool under `argument`.
+    #[must_use]
+    pub fn with_argument(mut self, argument: impl Into<String>) -> Self {
+        self.argument = argument.into();
+        self
+    }
+
+    /// Reject uploads whose decoded payload exceeds `max_size` bytes.
+    #[must_use]
+    pub fn with_max_size(mut self, max_size: usize) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// Allow at most `max` unfinished uploads per session (default 4).
+    #[must_use]
+    pub fn with_max_concurrent_uploads(mut self, max: usize) -> Self {
+        self.max_concurrent_uploads = max;
+        self
+    }
+
+    /// Buffer at most `bytes` across all unfinished uploads (default 256 MiB).
+    #[must_use]
+    pub fn with_max_buffered_bytes(mut self, bytes: usize) -> Self {
+        self.max_buffered_bytes = bytes;
+        self
+    }
+
+    /// Discard unfinished uploads that receive no chunk for `timeout`.
+    #[must_use]
+    pub fn with_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.idle_timeout = timeout;
+        self
+    }
+
+    /// Read time from `clock` instead of the system clock.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Maximum decoded payload size in bytes.
+    #[must_use]
+    pub fn max_size(&self) -> usize {
+        self.max_size
+    }
+
+    /// Bytes buffered across all unfinished uploads.
+    #[must_use]
+    pub fn buffered_bytes(&self) -> usize {
+        self.buffered.load(Ordering::Relaxed)
+    }
+
+    /// Number of unfinished uploads, including idle ones not yet pruned.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.uploads.len()
+    }
+
+    /// Whether no uploads are in progress.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.uploads.is_empty()
+    }
+
+    /// Buffer `chunk`, returning the whole payload once the final chunk
+    /// arrives.
+    fn accept(
+        &self,
+        ctx: &RequestContext,
+        tool: &str,
+        chunk: Chunk,
+    ) -> McpResult<(u64, Option<Vec<u8>>)> {
+        let session = match ctx.session_id() {
+            Some(session) => session,
+            None if ctx.transport == TransportType::Stdio => "",
+            None => {
+                return Err(McpError::invalid_request(
+                    "Chunked uploads require a session id",
+                ));
+            }
+        };
+        let now = self.clock.instant();
+        self.remove_where(|_, upload| {
+            now.saturating_duration_since(upload.updated) >= self.idle_timeout
+        });
+
+        let key = (session.to_string(), chunk.id.clone());
+        if !self.uploads.contains_key(&key) {
+            let open = self
+                .uploads
+                .iter()
+                .filter(|entry| entry.key().0 == session)
+                .count();
+            if open >= self.max_concurrent_uploads {
+                return Err(McpError::rate_limited(format!(
+                    "Too many unfinished uploads in this session (limit: {})",
+                    self.max_concurrent_uploads
+                )));
+            }
+        }
+        let result = self.append(&key, tool, &chunk, now);
+        match result {
+            Ok(received) if chunk.last => {
+                let upload = self.remove(&key);
+                Ok((received, upload))
+            }
+            Ok(received) => Ok((received, None)),
+            Err(e) => {
+                self.remove(&key);
+                Err(e)
+            }
+        }
+    }
+
+    /// Remove the upload under `key`, returning its payload.
+    fn remove(&self, key: &(String, String)) -> Option<Vec<u8>> {
+        let (_, upload) = self.uploads.remove(key)?;
+        self.buffered
+            .fetch_sub(upload.data.len(), Ordering::Relaxed);
+        Some(upload.data)
+    }
+
+    /// Remove every upload matching `discard`.
+    fn remove_where(&self, discard: impl Fn(&(String, String), &Upload) -> bool) {
+        self.uploads.retain(|key, upload| {
+            if discard(key, upload) {
+                self.buffered
+                    .fetch_sub(upload.data.len(), Ordering::Relaxed);
+                return false;
+            }
+            true
+        });
+    }
+
+    fn append(
+        &self,
+        key: &(String, String),
+        tool: &str,
+        chunk: &Chunk,
+        now: Instant,
+    ) -> McpResult<u64> {
+        let id = &chunk.id;
+        let mut upload = self.uploads.entry(key.clone()).or_insert_with(|| Upload {
+            tool: tool.to_string(),
+            next_index: 0,
+            data: Vec::new(),
+            updated: now,
+        });
+        if upload.tool != tool {
+            return Err(McpError::invalid_params(format!(
+                "Upload '{id}' was started for tool '{}'",
+                upload.tool
+            )));
+        }
+        if chunk.index != upload.next_index {
+            return Err(McpError::invalid_params(format!(
+                "Upload '{id}' expected chunk {}, got {}",
+                upload.next_index, chunk.index
+            )));
+        }
+        let bytes = STANDARD.decode(&chunk.data).map_err(|e| {
+            McpError::invalid_params(format!(
+                "Upload '{id}' chunk {} is not base64: {e}",
+                chunk.index
+            ))
+        })?;
+        if upload.data.len() + bytes.len() > self.max_size {
+            return Err(McpError::invalid_params(format!(
+                "Upload '{id}' exceeds the maximum size of {} bytes",
+                self.max_size
+            )));
+        }
+        let buffered = self.buffered.fetch_add(bytes.len(), Ordering::Relaxed);
+        if buffered + bytes.len() > self.max_buffered_bytes {
+            self.buffered.fetch_sub(bytes.len(), Ordering::Relaxed);
+            return Err(McpError::rate_limited(format!(
+                "Upload '{id}' would exceed the server's upload buffer of {} bytes",
+                self.max_buffered_bytes
+            )));
+        }
+        upload.data.extend_from_slice(&bytes);
+        upload.next_index += 1;
+        upload.updated = now;
+        Ok(upload.data.len() as u64)
+    }
+}
+
+fn upload_chunk(ctx: &RequestContext) -> Option<McpResult<Chunk>> {
+    let meta = ctx.request_meta()?.get(UPLOAD_META)?;
+    Some(
+        Chunk::deserialize(meta)
+            .map_err(|e| McpError::invalid_params(format!("Invalid upload metadata: {e}"))),
+    )
+}
+
+impl McpMiddleware for ChunkedUploadMiddleware {
+    fn on_call_tool<'a>(
+        &'a self,
+        name: &'a str,
+        args: Value,
+        ctx: &'a RequestContext,
+        next: Next<'a>,
+    ) -> Pin<Box<dyn Future<Output = McpResult<ToolResult>> + Send + 'a>> {
+        Box::pin(async move {
+            let Some(chunk) = upload_chunk(ctx) else {
+                return next.call_tool(name, args, ctx).await;
+            };
+            let chunk = chunk?;
+            let id = chunk.id.clone();
+            let index = chunk.index;
+
+            let (received, payload) = self.accept(ctx, name, chunk)?;
+            let Some(payload) = payload else {
+                tracing::debug!(tool = name, upload = %id, index, received, "Buffered upload chunk");
+                return Ok(ToolResult::text(format!(
+                    "Received chunk {index} of upload '{id}' ({received} bytes so far)"
+                )));
+            };
+
+            let mut args = match args {
+                Value::Object(map) => map,
+                Value::Null => serde_json::Map::new(),
+                _ => {
+                    return Err(McpError::invalid_params(
+                        "Tool arguments must be an object to receive an upload",
+                    ));
+                }
+            };
+            args.insert(
+                self.argument.clone(),
+                Value::String(STANDARD.encode(payload)),
+            );
+            tracing::debug!(tool = name, upload = %id, received, "Upload complete");
+            next.call_tool(name, Value::Object(args), ctx).await
+        })
+    }
+
+    fn on_session_closed(&self, session_id: &str) {
+        self.remove_where(|(session, _), _| session == session_id);
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::manual_async_fn)]
+mod tests {
+    use super::*;
+    use crate::middleware::MiddlewareStack;
+    use turbomcp_core::error::ErrorKind;
+    use turbomcp_core::handler::McpHandler;
+    use turbomcp_types::{Prompt, PromptResult, Resource, ResourceResult, ServerInfo, Tool};
+
+    /// Handler whose `store_file` tool reports what it received.
+    #[derive(Clone)]
+    struct StoreHandler;
+
+    impl McpHandler for StoreHandler {
+        fn server_info(&self) -> ServerInfo {
+            ServerInfo::new("upload-test", "1.0.0")
+        }
+
+        fn list_tools(&self) -> Vec<Tool> {
+            vec![Tool::new("store_file", "Store an uploaded file")]
+        }
+
+        fn list_resources(&self) -> Vec<Resource> {
+            vec![]
+        }
+
+        fn list_prompts(&self) -> Vec<Prompt> {
+            vec![]
+        }
+
+        fn call_tool<'a>(
+            &'a self,
+            _name: &'a str,
+            args: Value,
+            _ctx: &'a RequestContext,
+        ) -> impl Future<Output = McpResult<ToolResult>> + Send + 'a {
+            async move {
+                let data = STANDARD
+                    .decode(args["data"].as_str().unwrap_or_default())
+                    .map_err(|e| McpError::invalid_params(e.to_string()))?;
+                let text =
+                    String::from_utf8(data).map_err(|e| McpError::internal(e.to_string()))?;
+                Ok(ToolResult::text(format!(
+                    "{}: {text}",
+                    args["path"].as_str().unwrap_or_default()
+                )))
+            }
+        }
+
+        fn read_resource<'a>(
+            &'a self,
+            uri: &'a str,
+            _ctx: &'a RequestContext,
+        ) -> impl Future<Output = McpResult<ResourceResult>> + Send + 'a {
+            let uri = uri.to_string();
+            async move { Err(McpError::resource_not_found(&uri)) }
+        }
+
+        fn get_prompt<'a>(
+            &'a self,
+            name: &'a str,
+            _args: Option<Value>,
+            _ctx: &'a RequestContext,
+        ) -> impl Future<Output = McpResult<PromptResult>> + Send + 'a {
+            let name = name.to_string();
+            async move { Err(McpError::prompt_not_found(&name)) }
+        }
+    }
+
+    fn chunk(id: &str, index: u64, data: &[u8], last: bool) -> RequestContext {
+        chunk_in(
+            RequestContext::stdio().with_session_id("s1"),
+            id,
+            index,
+            data,
+            last,
+        )
+    }
+
+    fn chunk_in(
+        ctx: RequestContext,
+        id: &str,
+        index: u64,
+        data: &[u8],
+        last: bool,
+    ) -> RequestContext {
+        ctx.with_request_meta(serde_json::json!({
+            UPLOAD_META: {
+                "id": id,
+                "index": index,
+                "data": STANDARD.encode(data),
+                "final": last,
+            }
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_upload_in_three_chunks_is_reassembled() {
+        let uploads = ChunkedUploadMiddleware::new().with_max_size(64);
+        let stack = MiddlewareStack::new(StoreHandler).with_middleware(uploads.clone());
+        let args = serde_json::json!({"path": "q3.csv"});
+        let parts: [&[u8]; 3] = [b"id,total\n", b"1,42\n", b"2,17\n"];
+
+        for (index, part) in parts.iter().take(2).enumerate() {
+            let ack = stack
+                .call_tool(
+                    "store_file",
+                    args.clone(),
+                    &chunk("u-1", index as u64, part, false),
+                )
+                .await
+                .unwrap();
+            assert!(ack.first_text().unwrap().contains("upload 'u-1'"));
+        }
+        assert_eq!(uploads.len(), 1);
+
+        let result = stack
+            .call_tool("store_file", args.clone(), &chunk("u-1", 2, parts[2], true))
+            .await
+            .unwrap();
+        assert_eq!(result.first_text(), Some("q3.csv: id,total\n1,42\n2,17\n"));
+        assert!(uploads.is_empty());
+
+        // Plain calls pass straight through to the tool.
+        let plain = stack
+            .call_tool(
+                "store_file",
+                serde_json::json!({"path": "a.txt", "data": STANDARD.encode("inline")}),
+                &RequestContext::stdio(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(plain.first_text(), Some("a.txt: inline"));
+    }
+
+    #[tokio::test]
+    async fn test_upload_over_max_size_is_rejected() {
+        let uploads = ChunkedUploadMiddleware::new().with_max_size(8);
+        let stack = MiddlewareStack::new(StoreHandler).with_middleware(uploads.clone());
+        let args = serde_json::json!({"path": "big.bin"});
+
+        stack
+            .call_tool(
+                "store_file",
+                args.clone(),
+                &chunk("u-2", 0, b"12345", false),
+            )
+            .await
+            .unwrap();
+        let err = stack
+            .call_tool(
+                "store_file",
+                args.clone(),
+                &chunk("u-2", 1, b"67890", false),
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind, ErrorKind::InvalidParams);
+        assert!(err.message.contains("maximum size"), "{}", err.message);
+
+        // The oversized upload is discarded; continuing it starts over.
+        assert!(uploads.is_empty());
+        let err = stack
+            .call_tool("store_file", args, &chunk("u-2", 2, b"!", true))
+            .await
+            .unwrap_err();
+        assert!(err.message.contains("expected chunk 0"), "{}", err.message);
+    }
+
+    #[tokio::test]
+    async fn test_open_uploads_and_buffered_bytes_are_capped() {
+        let uploads = ChunkedUploadMiddleware::new()
+            .with_max_concurrent_uploads(2)
+            .with_max_buffered_bytes(12);
+        let stack = MiddlewareStack::new(StoreHandler).with_middleware(uploads.clone());
+        let args = serde_json::json!({"path": "many.bin"});
+        let s2 = || RequestContext::http().with_session_id("s2");
+
+        for id in ["u-1", "u-2"] {
+            stack
+                .call_tool("store_file", args.clone(), &chunk(id, 0, b"1234", false))
+                .await
+                .unwrap();
+        }
+        let err = stack
+            .call_tool("store_file", args.clone(), &chunk("u-3", 0, b"1", false))
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind, ErrorKind::RateLimited);
+        assert!(
+            err.message.contains("unfinished uploads"),
+            "{}",
+            err.message
+        );
+
+        // Another session has its own upload slots but shares the buffer.
+        let err = stack
+            .call_tool(
+                "store_file",
+                args.clone(),
+                &chunk_in(s2(), "u-1", 0, b"12345", false),
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind, ErrorKind::RateLimited);
+        assert!(err.message.contains("upload buffer"), "{}", err.message);
+        assert_eq!(uploads.buffered_bytes(), 8);
+        assert_eq!(uploads.len(), 2);
+
+        // Finishing an upload frees its slot and its bytes.
+        stack
+            .call_tool("store_file", args.clone(), &chunk("u-1", 1, b"", true))
+            .await
+            .unwrap();
+        assert_eq!(uploads.buffered_bytes(), 4);
+        stack
+            .call_tool(
+                "store_file",
+                args,
+                &chunk_in(s2(), "u-1", 0, b"12345", false),
+            )
+            .await
+            .unwrap();
+        assert_eq!(uploads.buffered_bytes(), 9);
+    }
+
+    #[tokio::test]
+    async fn test_uploads_are_isolated_between_sessions() {
+        let uploads = ChunkedUploadMiddleware::new();
+        let stack = MiddlewareStack::new(StoreHandler).with_middleware(uploads.clone());
+        let args = serde_json::json!({"path": "mine.txt"});
+        let s2 = RequestContext::http().with_session_id("s2");
+
+        stack
+            .call_tool("store_file", args.clone(), &chunk("u-1", 0, b"mine", false))
+            .await
+            .unwrap();
+        // The same id in another session is a different upload.
+        let err = stack
+            .call_tool(
+                "store_file",
+                args.clone(),
+                &chunk_in(s2, "u-1", 1, b"theirs", true),
+            )
+            .await
+            .unwrap_err();
+        assert!(err.message.contains("expected chunk 0"), "{}", err.message);
+
+        // Without a session id, only STDIO may upload.
+        let err = stack
+            .call_tool(
+                "store_file",
+                args.clone(),
+                &chunk_in(RequestContext::tcp(), "u-1", 1, b"theirs", true),
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind, ErrorKind::InvalidRequest);
+
+        let result = stack
+            .call_tool("store_file", args, &chunk("u-1", 1, b"!", true))
+            .await
+            .unwrap();
+        assert_eq!(result.first_text(), Some("mine.txt: mine!"));
+    }
+
+    #[tokio::test]
+    async fn test_closing_session_drops_its_uploads() {
+        let uploads = ChunkedUploadMiddleware::new();
+        let stack = MiddlewareStack::new(StoreHandler).with_middleware(uploads.clone());
+        let args = serde_json::json!({"path": "left.txt"});
+
+        stack
+            .call_tool("store_file", args.clone(), &chunk("u-1", 0, b"abc", false))
+            .await
+            .unwrap();
+        stack
+            .call_tool(
+                "store_file",
+                args,
+                &chunk_in(
+                    RequestContext::http().with_session_id("s2"),
+                    "u-1",
+                    0,
+                    b"de",
+                    false,
+                ),
+            )
+            .await
+            .unwrap();
+
+        stack.on_session_closed("s1");
+        assert_eq!(uploads.len(), 1);
+        assert_eq!(uploads.buffered_bytes(), 2);
+    }
+}
@@ -0,0 +1,324 @@
+//! Uniform post-processing of tool results.
+//!
+//! Deployments sometimes need every tool result rewritten the same way: a
+//! disclaimer appended, internal fields stripped from structured content, or
+//! text localized. An [`OutputTransforms`] list runs on each successful
+//! `tools/call` result before it is sent, and on tool results fetched later
+//! with `tasks/result`, and may mutate both the content blocks and the
+//! structured content.
+//!
+//! Register transforms with
+//! [`ServerBuilder::output_transform`](crate::ServerBuilder::output_transform).
+//! `OutputTransforms` is also an [`McpMiddleware`], so the same list can run
+//! in a custom [`MiddlewareStack`](crate::middleware::MiddlewareStack).
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use serde_json::Value;
+use turbomcp_core::context::RequestContext;
+use turbomcp_core::error::McpResult;
+use turbomcp_types::ToolResult;
+
+use super::middleware::{McpMiddleware, Next};
+
+type OutputTransform = dyn Fn(Option<&str>, &mut ToolResult) + Send + Sync;
+
+/// Ordered list of functions applied to every tool result.
+///
+/// Each transform receives the called tool's name and its result. The name
+/// is `None` for results fetched with `tasks/result`, which do not record the
+/// tool that produced them. Errors returned by the tool as JSON-RPC errors
+/// are not transformed; results with `isError` set are.
+#[derive(Clone, Default)]
+pub struct OutputTransforms {
+    transforms: Vec<Arc<OutputTransform>>,
+}
+
+impl OutputTransforms {
+    /// Append a transform; it runs after the ones already registered.
+    pub fn push(
+        &mut self,
+        transform: impl Fn(Option<&str>, &mut ToolResult) + Send + Sync + 'static,
+    ) {
+        self.transforms.push(Arc::new(transform));
+    }
+
+    /// Whether no transforms are registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.transforms.is_empty()
+    }
+
+    /// Number of registered transforms.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.transforms.len()
+    }
+
+    /// Run every transform over the result of a call to `tool`.
+    pub fn apply(&self, tool: Option<&str>, result: &mut ToolResult) {
+        for transform in &self.transforms {
+            transform(tool, result);
+        }
+    }
+
+    /// Run every transform over a `tasks/result` payload that holds a tool
+    /// result. Payloads of other shapes are returned unchanged.
+    pub(crate) fn apply_to_task_result(&self, result: Value) -> Value {
+        if self.is_empty() {
+            return result;
+        }
+        let Ok(mut tool_result) = serde_json::from_value::<ToolResult>(result.clone()) else {
+            return result;
+        };
+        self.apply(None, &mut tool_result);
+        serde_json::to_value(tool_result).unwrap_or(result)
+    }
+}
+
+impl fmt::Debug for OutputTransforms {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OutputTransforms")
+            .field("len", &self.transforms.len())
+            .finish()
+    }
+}
+
+impl McpMiddleware for OutputTransforms {
+    fn on_call_tool<'a>(
+        &'a self,
+        name: &'a str,
+        args: Value,
+        ctx: &'a RequestContext,
+        next: Next<'a>,
+    ) -> Pin<Box<dyn Future<Output = McpResult<ToolResult>> + Send + 'a>> {
+        Box::pin(async move {
+            let mut result = next.call_tool(name, args, ctx).await?;
+            self.apply(Some(name), &mut result);
+            Ok(result)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::McpServerExt;
+    use crate::transport::LineTransportRunner;
+    use serde_json::json;
+    use tokio::io::BufReader;
+    use turbomcp_core::error::McpError;
+    use turbomcp_core::handler::McpHandler;
+    use turbomcp_core::marker::MaybeSend;
+    use turbomcp_types::{
+        Content, Prompt, PromptResult, Resource, ResourceResult, ServerInfo, Tool,
+    };
+
+    const NOTICE: &str = "\n\nGenerated output; verify before use.";
+
+    #[derive(Clone)]
+    struct ReportHandler;
+
+    #[allow(clippy::manual_async_fn)]
+    impl McpHandler for ReportHandler {
+        fn server_info(&self) -> ServerInfo {
+            ServerInfo::new("output-transform-test", "1.0.0")
+        }
+
+        fn list_tools(&self) -> Vec<Tool> {
+            vec![
+                Tool::new("greet", "Say hello"),
+                Tool::new("stats", "Report usage statistics"),
+            ]
+        }
+
+        fn list_resources(&self) -> Vec<Resource> {
+            vec![]
+        }
+
+        fn list_prompts(&self) -> Vec<Prompt> {
+            vec![]
+        }
+
+        fn call_tool<'a>(
+            &'a self,
+            name: &'a str,
+            _args: Value,
+            _ctx: &'a RequestContext,
+        ) -> impl std::future::Future<Output = McpResult<ToolResult>> + MaybeSend + 'a {
+            async move {
+                match name {
+                    "greet" => Ok(ToolResult::text("Hello")),
+                    "stats" => Ok(ToolResult::text("2 calls")
+                        .with_structured(&json!({"calls": 2, "internal_id": "abc"}))),
+                    _ => Err(McpError::tool_not_found(name)),
+                }
+            }
+        }
+
+        fn read_resource<'a>(
+            &'a self,
+            uri: &'a str,
+            _ctx: &'a RequestContext,
+        ) -> impl std::future::Future<Output = McpResult<ResourceResult>> + MaybeSend + 'a {
+            let uri = uri.to_string();
+            async move { Err(McpError::resource_not_found(&uri)) }
+        }
+
+        fn get_prompt<'a>(
+            &'a self,
+            name: &'a str,
+            _args: Option<Value>,
+            _ctx: &'a RequestContext,
+        ) -> impl std::future::Future<Output = McpResult<PromptResult>> + MaybeSend + 'a {
+            let name = name.to_string();
+            async move { Err(McpError::prompt_not_found(&name)) }
+        }
+
+        fn get_task_result<'a>(
+            &'a self,
+            _task_id: &'a str,
+            _ctx: &'a RequestContext,
+        ) -> impl std::future::Future<Output = McpResult<Value>> + MaybeSend + 'a {
+            async { Ok(json!({"content": [{"type": "text", "text": "Report ready"}]})) }
+        }
+    }
+
+    fn append_notice(_tool: Option<&str>, result: &mut ToolResult) {
+        for content in &mut result.content {
+            if let Content::Text(text) = content {
+                text.text.push_str(NOTICE);
+            }
+        }
+    }
+
+    /// Serve `requests` through `handler` over the line transport after the
+    /// initialize handshake, returning each response by id.
+    async fn exchange(handler: impl McpHandler, requests: &[Value]) -> Vec<Value> {
+        let mut input = String::new();
+        for message in [
+            json!({
+                "jsonrpc": "2.0",
+                "id": 0,
+                "method": "initialize",
+                "params": {
+                    "protocolVersion": "2025-11-25",
+                    "clientInfo": {"name": "test-client", "version": "1.0.0"},
+                    "capabilities": {}
+                }
+            }),
+            json!({"jsonrpc": "2.0", "method": "notifications/initialized"}),
+        ]
+        .iter()
+        .chain(requests)
+        {
+            input.push_str(&message.to_string());
+            input.push('\n');
+        }
+        let mut output = Vec::new();
+        LineTransportRunner::new(handler)
+            .run(
+                BufReader::new(Cursor::new(input)),
+                &mut output,
+                RequestContext::stdio,
+            )
+            .await
+            .unwrap();
+
+        let responses: Vec<Value> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        (1..=requests.len())
+            .map(|id| {
+                responses
+                    .iter()
+                    .find(|response| response["id"] == json!(id))
+                    .cloned()
+                    .unwrap_or_else(|| panic!("no response to request {id}"))
+            })
+            .collect()
+    }
+
+    fn call(id: usize, name: &str) -> Value {
+        json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "method": "tools/call",
+            "params": {"name": name, "arguments": {}}
+        })
+    }
+
+    #[tokio::test]
+    async fn test_notice_is_appended_to_every_tool() {
+        let handler = ReportHandler
+            .builder()
+            .output_transform(append_notice)
+            .served_handler();
+
+        let responses = exchange(handler, &[call(1, "greet"), call(2, "stats")]).await;
+        for (response, text) in responses.iter().zip(["Hello", "2 calls"]) {
+            assert_eq!(
+                response["result"]["content"][0]["text"],
+                format!("{text}{NOTICE}")
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_notice_is_appended_to_task_results() {
+        let handler = ReportHandler
+            .builder()
+            .output_transform(append_notice)
+            .served_handler();
+
+        let task_result = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "tasks/result",
+            "params": {"taskId": "task-1"}
+        });
+        let responses = exchange(handler, &[task_result]).await;
+        assert_eq!(
+            responses[0]["result"]["content"][0]["text"],
+            format!("Report ready{NOTICE}")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_transforms_can_rewrite_structured_content() {
+        let handler = ReportHandler
+            .builder()
+            .output_transform(|tool: Option<&str>, result: &mut ToolResult| {
+                if tool == Some("stats")
+                    && let Some(Value::Object(data)) = result.structured_content.as_mut()
+                {
+                    data.remove("internal_id");
+                }
+            })
+            .served_handler();
+
+        let responses = exchange(handler, &[call(1, "stats"), call(2, "missing")]).await;
+        assert_eq!(
+            responses[0]["result"]["structuredContent"],
+            json!({"calls": 2})
+        );
+        // Unknown tools still fail as JSON-RPC errors.
+        assert!(responses[1]["error"].is_object());
+    }
+
+    #[test]
+    fn test_builder_registers_output_transforms() {
+        let builder = ReportHandler
+            .builder()
+            .output_transform(append_notice)
+            .output_transform(|_tool: Option<&str>, _result: &mut ToolResult| {});
+        assert_eq!(builder.output_transforms().len(), 2);
+    }
+}
@@ -0,0 +1,197 @@
+//! Handler panic isolation.
+//!
+//! A panic inside a tool, resource, or prompt handler would otherwise unwind
+//! through the transport task serving the connection. The [`PanicIsolation`]
+//! middleware catches it, logs the panic message, and answers the request
+//! with a redacted error (`-32002` for tools) so the server keeps running.
+//!
+//! [`ServerBuilder`](crate::ServerBuilder) installs it by default; disable it
+//! with
+//! [`with_panic_isolation(false)`](crate::ServerBuilder::with_panic_isolation).
+
+use std::any::Any;
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+
+use futures::FutureExt;
+use serde_json::Value;
+use turbomcp_core::context::RequestContext;
+use turbomcp_core::error::{McpError, McpResult};
+use turbomcp_types::{PromptResult, ResourceResult, ToolResult};
+
+use super::middleware::{McpMiddleware, Next};
+
+/// Middleware that turns handler panics into error responses.
+///
+/// Add it last so it sits closest to the handler and also covers the
+/// middleware added before it.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PanicIsolation;
+
+/// Best-effort text of a panic payload, for logging only.
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "non-string panic payload"
+    }
+}
+
+impl McpMiddleware for PanicIsolation {
+    fn on_call_tool<'a>(
+        &'a self,
+        name: &'a str,
+        args: Value,
+        ctx: &'a RequestContext,
+        next: Next<'a>,
+    ) -> Pin<Box<dyn Future<Output = McpResult<ToolResult>> + Send + 'a>> {
+        Box::pin(async move {
+            AssertUnwindSafe(next.call_tool(name, args, ctx))
+                .catch_unwind()
+                .await
+                .unwrap_or_else(|payload| {
+                    tracing::error!(
+                        tool = name,
+                        panic = panic_message(payload.as_ref()),
+                        "Tool handler panicked"
+                    );
+                    Err(McpError::tool_execution_failed(name, "internal error"))
+                })
+        })
+    }
+
+    fn on_read_resource<'a>(
+        &'a self,
+        uri: &'a str,
+        ctx: &'a RequestContext,
+        next: Next<'a>,
+    ) -> Pin<Box<dyn Future<Output = McpResult<ResourceResult>> + Send + 'a>> {
+        Box::pin(async move {
+            AssertUnwindSafe(next.read_resource(uri, ctx))
+                .catch_unwind()
+                .await
+                .unwrap_or_else(|payload| {
+                    tracing::error!(
+                        uri,
+                        panic = panic_message(payload.as_ref()),
+                        "Resource handler panicked"
+                    );
+                    Err(McpError::internal("Resource handler failed"))
+                })
+        })
+    }
+
+    fn on_get_prompt<'a>(
+        &'a self,
+        name: &'a str,
+        args: Option<Value>,
+        ctx: &'a RequestContext,
+        next: Next<'a>,
+    ) -> Pin<Box<dyn Future<Output = McpResult<PromptResult>> + Send + 'a>> {
+        Box::pin(async move {
+            AssertUnwindSafe(next.get_prompt(name, args, ctx))
+                .catch_unwind()
+                .await
+                .unwrap_or_else(|payload| {
+                    tracing::error!(
+                        prompt = name,
+                        panic = panic_message(payload.as_ref()),
+                        "Prompt handler panicked"
+                    );
+                    Err(McpError::internal("Prompt handler failed"))
+                })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::McpServerExt;
+    use crate::router::{JsonRpcIncoming, route_request};
+    use turbomcp_core::handler::McpHandler;
+    use turbomcp_core::marker::MaybeSend;
+    use turbomcp_types::{Prompt, Resource, ServerInfo, Tool};
+
+    #[derive(Clone)]
+    struct PanickyHandler;
+
+    #[allow(clippy::manual_async_fn)]
+    impl McpHandler for PanickyHandler {
+        fn server_info(&self) -> ServerInfo {
+            ServerInfo::new("panic-test", "1.0.0")
+        }
+
+        fn list_tools(&self) -> Vec<Tool> {
+            vec![Tool::new("explode", "Panics"), Tool::new("ok", "Succeeds")]
+        }
+
+        fn list_resources(&self) -> Vec<Resource> {
+            vec![]
+        }
+
+        fn list_prompts(&self) -> Vec<Prompt> {
+            vec![]
+        }
+
+        fn call_tool<'a>(
+            &'a self,
+            name: &'a str,
+            _args: Value,
+            _ctx: &'a RequestContext,
+        ) -> impl std::future::Future<Output = McpResult<ToolResult>> + MaybeSend + 'a {
+            async move {
+                if name == "explode" {
+                    panic!("secret database password leaked in panic");
+                }
+                Ok(ToolResult::text("ok"))
+            }
+        }
+
+        fn read_resource<'a>(
+            &'a self,
+            uri: &'a str,
+            _ctx: &'a RequestContext,
+        ) -> impl std::future::Future<Output = McpResult<ResourceResult>> + MaybeSend + 'a {
+            let uri = uri.to_string();
+            async move { Err(McpError::resource_not_found(&uri)) }
+        }
+
+        fn get_prompt<'a>(
+            &'a self,
+            name: &'a str,
+            _args: Option<Value>,
+            _ctx: &'a RequestContext,
+        ) -> impl std::future::Future<Output = McpResult<PromptResult>> + MaybeSend + 'a {
+            let name = name.to_string();
+            async move { Err(McpError::prompt_not_found(&name)) }
+        }
+    }
+
+    fn call(id: i64, tool: &str) -> JsonRpcIncoming {
+        JsonRpcIncoming {
+            jsonrpc: "2.0".to_string(),
+            id: Some(serde_json::json!(id)),
+            method: "tools/call".to_string(),
+            params: Some(serde_json::json!({ "name": tool, "arguments": {} })),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_panicking_tool_returns_error_and_server_keeps_serving() {
+        let handler = PanickyHandler.builder().served_handler();
+        let ctx = RequestContext::stdio();
+
+        let response = route_request(&handler, call(1, "explode"), &ctx).await;
+        let error = response.error.expect("panic must become an error response");
+        assert_eq!(error.code, -32002);
+        assert!(!error.message.contains("password"), "{}", error.message);
+
+        let response = route_request(&handler, call(2, "ok"), &ctx).await;
+        assert!(response.error.is_none());
+        assert!(response.result.is_some());
+    }
+}
@@ -0,0 +1,657 @@
+//! Directory-backed resources.
+//!
+//! [`ServerBuilder::resource_dir`](crate::ServerBuilder::resource_dir)
+//! exposes the files under a filesystem directory as resources: each file is
+//! listed under `root_uri` followed by its relative path and served on
+//! `resources/read`.
+//!
+//! Reads are confined to the directory: requested paths with `..`, absolute,
+//! or backslash components are rejected, and the resolved file must still lie
+//! under the canonical root after symlinks are followed. Files hidden by the
+//! include/exclude filters cannot be read either.
+
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use base64::Engine;
+use parking_lot::Mutex;
+use turbomcp_core::error::{McpError, McpResult};
+use turbomcp_types::{Resource, ResourceContents, ResourceResult, TextResourceContents};
+
+/// Default largest file served from a resource directory (10MB)
+pub const DEFAULT_MAX_RESOURCE_FILE_SIZE: u64 = 10 * 1024 * 1024;
+
+/// Filters and limits for a resource directory.
+#[derive(Debug, Clone)]
+pub struct ResourceDirOptions {
+    recursive: bool,
+    include: Vec<String>,
+    exclude: Vec<String>,
+    include_hidden: bool,
+    max_file_size: u64,
+}
+
+impl Default for ResourceDirOptions {
+    fn default() -> Self {
+        Self {
+            recursive: true,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            include_hidden: false,
+            max_file_size: DEFAULT_MAX_RESOURCE_FILE_SIZE,
+        }
+    }
+}
+
+impl ResourceDirOptions {
+    /// Recursive listing of every non-hidden file up to 10MB.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether to descend into subdirectories (default: `true`).
+    #[must_use]
+    pub fn recursive(mut self, recursive: bool) -> Self {
+        self.recursive = recursive;
+        self
+    }
+
+    /// Only expose files matching `pattern`.
+    ///
+    /// Patterns use `*` (any run of characters except `/`), `**` (any run
+    /// including `/`), and `?` (one character). A pattern without `/` matches
+    /// the file name; otherwise it matches the path relative to the root.
+    /// Can be called more than once; a file matching any pattern is included.
+    #[must_use]
+    pub fn include(mut self, pattern: impl Into<String>) -> Self {
+        self.include.push(pattern.into());
+        self
+    }
+
+    /// Hide files matching `pattern`, using the same syntax as
+    /// [`include`](Self::include). Exclusions win over inclusions.
+    #[must_use]
+    pub fn exclude(mut self, pattern: impl Into<String>) -> Self {
+        self.exclude.push(pattern.into());
+        self
+    }
+
+    /// Expose dot-files and dot-directories (default: `false`).
+    #[must_use]
+    pub fn include_hidden(mut self, include_hidden: bool) -> Self {
+        self.include_hidden = include_hidden;
+        self
+    }
+
+    /// Largest file, in bytes, that is listed and served.
+    #[must_use]
+    pub fn max_file_size(mut self, bytes: u64) -> Self {
+        self.max_file_size = bytes;
+        self
+    }
+
+    fn allows(&self, relative: &str) -> bool {
+        let hidden = relative.split('/').any(|part| part.starts_with('.'));
+        if hidden && !self.include_hidden {
+            return false;
+        }
+        let matches = |pattern: &String| {
+            let target = if pattern.contains('/') {
+                relative
+            } else {
+                relative.rsplit('/').next().unwrap_or(relative)
+            };
+            glob_match(pattern.as_bytes(), target.as_bytes())
+        };
+        (self.include.is_empty() || self.include.iter().any(matches))
+            && !self.exclude.iter().any(matches)
+    }
+}
+
+/// A directory whose files are served as resources.
+#[derive(Debug, Clone)]
+pub struct ResourceDir {
+    /// URI prefix, always ending in `/`
+    root_uri: String,
+    path: PathBuf,
+    options: ResourceDirOptions,
+}
+
+impl ResourceDir {
+    /// Serve files under `path` with URIs made of `root_uri` and the path
+    /// relative to the directory.
+    ///
+    /// A `/` is inserted after `root_uri` unless it already ends in one, so
+    /// both `docs://` and `file:///srv/docs` work as roots.
+    pub fn new(
+        root_uri: impl Into<String>,
+        path: impl Into<PathBuf>,
+        options: ResourceDirOptions,
+    ) -> Self {
+        let mut root_uri = root_uri.into();
+        if !root_uri.ends_with('/') {
+            root_uri.push('/');
+        }
+        Self {
+            root_uri,
+            path: path.into(),
+            options,
+        }
+    }
+
+    /// URI prefix of every resource in this directory, ending in `/`.
+    #[must_use]
+    pub fn root_uri(&self) -> &str {
+        &self.root_uri
+    }
+
+    /// Filesystem directory being served.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Check that the directory exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`McpError::configuration`] if the path is not a readable
+    /// directory.
+    pub fn validate(&self) -> McpResult<()> {
+        self.canonical_root().map(|_| ())
+    }
+
+    fn canonical_root(&self) -> McpResult<PathBuf> {
+        let root = self.path.canonicalize().map_err(|e| {
+            McpError::configuration(format!(
+                "Resource directory '{}' is not accessible: {e}",
+                self.path.display()
+            ))
+        })?;
+        if !root.is_dir() {
+            return Err(McpError::configuration(format!(
+                "Resource directory '{}' is not a directory",
+                self.path.display()
+            )));
+        }
+        Ok(root)
+    }
+
+    /// List the files in this directory as resources, sorted by URI.
+    pub fn list(&self) -> Vec<Resource> {
+        let root = match self.canonical_root() {
+            Ok(root) => root,
+            Err(e) => {
+                tracing::warn!(error = %e, "Skipping resource directory");
+                return Vec::new();
+            }
+        };
+        let mut resources = Vec::new();
+        self.collect(&root, &root, "", &mut resources);
+        resources.sort_by(|a, b| a.uri.cmp(&b.uri));
+        resources
+    }
+
+    fn collect(&self, root: &Path, dir: &Path, prefix: &str, resources: &mut Vec<Resource>) {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let (Some(name), Ok(file_type)) = (
+                entry.file_name().to_str().map(str::to_string),
+                entry.file_type(),
+            ) else {
+                continue;
+            };
+            let relative = format!("{prefix}{name}");
+            if file_type.is_dir() {
+                // Symlinked directories are not followed, which also rules
+                // out cycles.
+                if self.options.recursive && (self.options.include_hidden || !name.starts_with('.'))
+                {
+                    self.collect(root, &entry.path(), &format!("{relative}/"), resources);
+                }
+                continue;
+            }
+            // Symlinked files are listed only when their target stays under
+            // the root; reads re-check this.
+            let Ok(target) = entry.path().canonicalize() else {
+                continue;
+            };
+            let Ok(metadata) = std::fs::metadata(&target) else {
+                continue;
+            };
+            if target.starts_with(root)
+                && metadata.is_file()
+                && metadata.len() <= self.options.max_file_size
+                && self.options.allows(&relative)
+            {
+                let resource = Resource::new(format!("{}{relative}", self.root_uri), name)
+                    .with_mime_type(mime_type_for(&relative))
+                    .with_size(metadata.len());
+                resources.push(resource);
+            }
+        }
+    }
+
+    /// Relative path for `uri` if it names a resource in this directory.
+    fn relative_path<'a>(&self, uri: &'a str) -> Option<&'a str> {
+        uri.strip_prefix(self.root_uri.as_str())
+    }
+
+    /// Read the file named by `uri`, confined to this directory.
+    async fn read(&self, uri: &str, relative: &str) -> McpResult<ResourceResult> {
+        if !is_confined(relative) {
+            return Err(McpError::resource_access_denied(
+                uri,
+                "path escapes the resource directory",
+            ));
+        }
+        if !self.options.recursive && relative.contains('/') {
+            return Err(McpError::resource_not_found(uri));
+        }
+        if !self.options.allows(relative) {
+            return Err(McpError::resource_not_found(uri));
+        }
+
+        let root = self.canonical_root()?;
+        let path = match tokio::fs::canonicalize(root.join(relative)).await {
+            Ok(path) => path,
+            Err(_) => return Err(McpError::resource_not_found(uri)),
+        };
+        if !path.starts_with(&root) {
+            return Err(McpError::resource_access_denied(
+                uri,
+                "path escapes the resource directory",
+            ));
+        }
+        let metadata = tokio::fs::metadata(&path)
+            .await
+            .map_err(|_| McpError::resource_not_found(uri))?;
+        if !metadata.is_file() {
+            return Err(McpError::resource_not_found(uri));
+        }
+        if metadata.len() > self.options.max_file_size {
+            return Err(McpError::resource_access_denied(
+                uri,
+                format!("file exceeds the {} byte limit", self.options.max_file_size),
+            ));
+        }
+
+        let bytes = tokio::fs::read(&path)
+            .await
+            .map_err(|e| McpError::internal(format!("Failed to read resource file: {e}")))?;
+        let mime_type = mime_type_for(relative);
+        if is_text_mime(mime_type)
+            && let Ok(text) = std::str::from_utf8(&bytes)
+        {
+            return Ok(ResourceResult {
+                contents: vec![ResourceContents::Text(TextResourceContents {
+                    uri: uri.to_string(),
+                    mime_type: Some(mime_type.to_string()),
+                    text: text.to_string(),
+                    meta: None,
+                })],
+                ..Default::default()
+            });
+        }
+        Ok(ResourceResult::binary(
+            uri,
+            base64::engine::general_purpose::STANDARD.encode(&bytes),
+            mime_type,
+        ))
+    }
+}
+
+/// Whether `relative` stays inside its root: only normal components, with no
+/// backslashes that another platform would treat as separators.
+fn is_confined(relative: &str) -> bool {
+    !relative.is_empty()
+        && !relative.contains('\\')
+        && Path::new(relative)
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+}
+
+/// Match `text` against a glob `pattern` supporting `*`, `**`, and `?`.
+fn glob_match(pattern: &[u8], text: &[u8]) -> bool {
+    match pattern {
+        [] => text.is_empty(),
+        [b'*', b'*', rest @ ..] => {
+            let rest = rest.strip_prefix(b"/").unwrap_or(rest);
+            (0..=text.len()).any(|i| glob_match(rest, &text[i..]))
+        }
+        [b'*', rest @ ..] => (0..=text.len())
+            .take_while(|&i| i == 0 || text[i - 1] != b'/')
+            .any(|i| glob_match(rest, &text[i..])),
+        [b'?', rest @ ..] => {
+            matches!(text, [c, tail @ ..] if *c != b'/' && glob_match(rest, tail))
+        }
+        [p, rest @ ..] => matches!(text, [c, tail @ ..] if c == p && glob_match(rest, tail)),
+    }
+}
+
+/// MIME type inferred from a file extension.
+fn mime_type_for(path: &str) -> &'static str {
+    let extension = path
+        .rsplit_once('.')
+        .map(|(_, ext)| ext.to_ascii_lowercase())
+        .unwrap_or_default();
+    match extension.as_str() {
+        "txt" | "log" => "text/plain",
+        "md" | "markdown" => "text/markdown",
+        "html" | "htm" => "text/html",
+        "css" => "text/css",
+        "csv" => "text/csv",
+        "xml" => "application/xml",
+        "json" => "application/json",
+        "yaml" | "yml" => "application/yaml",
+        "toml" => "application/toml",
+        "js" | "mjs" => "text/javascript",
+        "rs" => "text/x-rust",
+        "py" => "text/x-python",
+        "sh" => "text/x-shellscript",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "svg" => "image/svg+xml",
+        "webp" => "image/webp",
+        "pdf" => "application/pdf",
+        "wasm" => "application/wasm",
+        "zip" => "application/zip",
+        _ => "application/octet-stream",
+    }
+}
+
+fn is_text_mime(mime_type: &str) -> bool {
+    mime_type.starts_with("text/")
+        || matches!(
+            mime_type,
+            "application/json"
+                | "application/xml"
+                | "application/yaml"
+                | "application/toml"
+                | "image/svg+xml"
+        )
+}
+
+/// How long a directory listing is reused before it is rescanned.
+const RESCAN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The resource directories a server exposes, with a cached listing.
+///
+/// Walking a directory tree is blocking filesystem work, so
+/// `resources/list` serves the last listing and rescans on a blocking thread
+/// once it is older than [`RESCAN_INTERVAL`]. Reads always go to disk, so
+/// files added since the last scan can be read before they are listed.
+#[derive(Debug, Clone)]
+pub(crate) struct ResourceDirs {
+    dirs: Arc<[ResourceDir]>,
+    listing: Arc<Mutex<Listing>>,
+}
+
+#[derive(Debug)]
+struct Listing {
+    resources: Vec<Resource>,
+    scanned_at: Instant,
+    rescanning: bool,
+}
+
+impl Listing {
+    fn scan(dirs: &[ResourceDir]) -> Self {
+        Self {
+            resources: dirs.iter().flat_map(ResourceDir::list).collect(),
+            scanned_at: Instant::now(),
+            rescanning: false,
+        }
+    }
+}
+
+impl ResourceDirs {
+    /// Serve `dirs`, scanning them once up front.
+    pub(crate) fn new(dirs: Vec<ResourceDir>) -> Self {
+        let listing = Listing::scan(&dirs);
+        Self {
+            dirs: dirs.into(),
+            listing: Arc::new(Mutex::new(listing)),
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.dirs.is_empty()
+    }
+
+    /// Files in every directory, as of the last scan.
+    pub(crate) fn list(&self) -> Vec<Resource> {
+        if self.dirs.is_empty() {
+            return Vec::new();
+        }
+        let mut listing = self.listing.lock();
+        if !listing.rescanning && listing.scanned_at.elapsed() >= RESCAN_INTERVAL {
+            if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+                listing.rescanning = true;
+                let dirs = Arc::clone(&self.dirs);
+                let shared = Arc::clone(&self.listing);
+                runtime.spawn_blocking(move || {
+                    let rescanned = Listing::scan(&dirs);
+                    *shared.lock() = rescanned;
+                });
+            } else {
+                // Outside a runtime there is no executor to block.
+                *listing = Listing::scan(&self.dirs);
+            }
+        }
+        listing.resources.clone()
+    }
+
+    /// Read `uri` if it names a file in one of the directories.
+    pub(crate) async fn read(&self, uri: &str) -> Option<McpResult<ResourceResult>> {
+        let (dir, relative) = self
+            .dirs
+            .iter()
+            .find_map(|dir| dir.relative_path(uri).map(|relative| (dir, relative)))?;
+        Some(dir.read(uri, relative).await)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::McpServerExt;
+    use serde_json::Value;
+    use turbomcp_core::context::RequestContext;
+    use turbomcp_core::handler::McpHandler;
+    use turbomcp_core::marker::MaybeSend;
+    use turbomcp_types::{Prompt, PromptResult, ServerInfo, Tool, ToolResult};
+
+    #[derive(Clone)]
+    struct EmptyHandler;
+
+    #[allow(clippy::manual_async_fn)]
+    impl McpHandler for EmptyHandler {
+        fn server_info(&self) -> ServerInfo {
+            ServerInfo::new("resource-dir-test", "1.0.0")
+        }
+
+        fn list_tools(&self) -> Vec<Tool> {
+            vec![]
+        }
+
+        fn list_resources(&self) -> Vec<Resource> {
+            vec![]
+        }
+
+        fn list_prompts(&self) -> Vec<Prompt> {
+            vec![]
+        }
+
+        fn call_tool<'a>(
+            &'a self,
+            name: &'a str,
+            _args: Value,
+            _ctx: &'a RequestContext,
+        ) -> impl std::future::Future<Output = McpResult<ToolResult>> + MaybeSend + 'a {
+            let name = name.to_string();
+            async move { Err(McpError::tool_not_found(&name)) }
+        }
+
+        fn read_resource<'a>(
+            &'a self,
+            uri: &'a str,
+            _ctx: &'a RequestContext,
+        ) -> impl std::future::Future<Output = McpResult<ResourceResult>> + MaybeSend + 'a {
+            let uri = uri.to_string();
+            async move { Err(McpError::resource_not_found(&uri)) }
+        }
+
+        fn get_prompt<'a>(
+            &'a self,
+            name: &'a str,
+            _args: Option<Value>,
+            _ctx: &'a RequestContext,
+        ) -> impl std::future::Future<Output = McpResult<PromptResult>> + MaybeSend + 'a {
+            let name = name.to_string();
+            async move { Err(McpError::prompt_not_found(&name)) }
+        }
+    }
+
+    fn text_of(result: &ResourceResult) -> &str {
+        match &result.contents[0] {
+            ResourceContents::Text(text) => &text.text,
+            other => panic!("expected text contents, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_lists_and_reads_files_and_blocks_traversal() {
+        let parent = tempfile::tempdir().unwrap();
+        let root = parent.path().join("docs");
+        std::fs::create_dir_all(root.join("guides")).unwrap();
+        std::fs::write(root.join("readme.md"), "# Readme").unwrap();
+        std::fs::write(root.join("guides/setup.txt"), "setup steps").unwrap();
+        std::fs::write(parent.path().join("secret.txt"), "top secret").unwrap();
+
+        let handler = EmptyHandler
+            .builder()
+            .resource_dir("docs://", &root, ResourceDirOptions::new())
+            .served_handler();
+
+        let uris: Vec<_> = handler
+            .list_resources()
+            .into_iter()
+            .map(|resource| (resource.uri, resource.mime_type))
+            .collect();
+        assert_eq!(
+            uris,
+            vec![
+                (
+                    "docs://guides/setup.txt".to_string(),
+                    Some("text/plain".to_string())
+                ),
+                (
+                    "docs://readme.md".to_string(),
+                    Some("text/markdown".to_string())
+                ),
+            ]
+        );
+        let resources = handler.server_capabilities().resources.unwrap();
+        assert_eq!(resources.list_changed, None);
+
+        let ctx = RequestContext::stdio();
+        let readme = handler
+            .read_resource("docs://readme.md", &ctx)
+            .await
+            .unwrap();
+        assert_eq!(text_of(&readme), "# Readme");
+        let setup = handler
+            .read_resource("docs://guides/setup.txt", &ctx)
+            .await
+            .unwrap();
+        assert_eq!(text_of(&setup), "setup steps");
+
+        for uri in [
+            "docs://../secret.txt",
+            "docs://guides/../../secret.txt",
+            "docs:///etc/passwd",
+            "docs://..\\secret.txt",
+        ] {
+            let err = handler.read_resource(uri, &ctx).await.unwrap_err();
+            assert_eq!(err.jsonrpc_code(), -32005, "{uri} should be denied");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_files_added_after_listing_are_readable() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::write(root.path().join("a.md"), "a").unwrap();
+        let dirs = ResourceDirs::new(vec![ResourceDir::new(
+            "notes://",
+            root.path(),
+            ResourceDirOptions::new(),
+        )]);
+        assert_eq!(dirs.list().len(), 1);
+
+        // The listing is cached, but reads always go to disk.
+        std::fs::write(root.path().join("b.md"), "b").unwrap();
+        assert_eq!(dirs.list().len(), 1);
+        let read = dirs.read("notes://b.md").await.unwrap().unwrap();
+        assert_eq!(text_of(&read), "b");
+        assert!(dirs.read("other://b.md").await.is_none());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_symlink_out_of_root_is_blocked() {
+        let parent = tempfile::tempdir().unwrap();
+        let root = parent.path().join("public");
+        std::fs::create_dir(&root).unwrap();
+        std::fs::write(parent.path().join("secret.txt"), "top secret").unwrap();
+        std::os::unix::fs::symlink(parent.path().join("secret.txt"), root.join("link.txt"))
+            .unwrap();
+
+        let dir = ResourceDir::new("files://", &root, ResourceDirOptions::new());
+        let err = dir.read("files://link.txt", "link.txt").await.unwrap_err();
+        assert_eq!(err.jsonrpc_code(), -32005);
+    }
+
+    #[tokio::test]
+    async fn test_filters_and_non_recursive_listing() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::create_dir(root.path().join("nested")).unwrap();
+        std::fs::write(root.path().join("a.md"), "a").unwrap();
+        std::fs::write(root.path().join("b.json"), "{}").unwrap();
+        std::fs::write(root.path().join("draft.md"), "draft").unwrap();
+        std::fs::write(root.path().join(".env"), "KEY=1").unwrap();
+        std::fs::write(root.path().join("nested/c.md"), "c").unwrap();
+
+        let options = ResourceDirOptions::new()
+            .recursive(false)
+            .include("*.md")
+            .exclude("draft*");
+        let dir = ResourceDir::new("notes://", root.path(), options);
+
+        let names: Vec<_> = dir.list().into_iter().map(|r| r.name).collect();
+        assert_eq!(names, vec!["a.md"]);
+
+        for (uri, relative) in [
+            ("notes://draft.md", "draft.md"),
+            ("notes://.env", ".env"),
+            ("notes://nested/c.md", "nested/c.md"),
+        ] {
+            let err = dir.read(uri, relative).await.unwrap_err();
+            assert_eq!(err.jsonrpc_code(), -32004, "{uri} should be hidden");
+        }
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match(b"*.md", b"readme.md"));
+        assert!(!glob_match(b"*.md", b"guides/readme.md"));
+        assert!(glob_match(b"**/*.md", b"guides/deep/readme.md"));
+        assert!(glob_match(b"**/*.md", b"readme.md"));
+        assert!(glob_match(b"guides/?.txt", b"guides/a.txt"));
+        assert!(!glob_match(b"guides/?.txt", b"guides/ab.txt"));
+    }
+}
@@ -18,15 +18,43 @@
 //! - Error codes follow JSON-RPC 2.0 standard
 
 use super::config::{ClientCapabilities, ServerConfig};
+use super::initialize::{InitializeAdvertisement, InitializeClient, InitializeHooks};
+use super::trace_context::{attach_trace_id, request_trace_id};
+use tracing::Instrument;
 use turbomcp_core::context::RequestContext;
 use turbomcp_core::error::McpError;
 use turbomcp_core::handler::McpHandler;
 use turbomcp_protocol::versioning::adapter::{VersionAdapter, adapter_for_version};
+use turbomcp_types::Implementation;
 
 // Re-export canonical JSON-RPC types from turbomcp-core
 pub use turbomcp_core::jsonrpc::{JsonRpcIncoming, JsonRpcOutgoing};
 // Re-export core router utilities
-pub use turbomcp_core::router::{parse_request, parse_request_from_value, serialize_response};
+pub use turbomcp_core::router::{
+    parse_request, parse_request_from_value_with_strictness, parse_request_with_strictness,
+    serialize_response,
+};
+
+/// Client notifications the server understands; the transports consume
+/// `notifications/cancelled` before routing.
+const KNOWN_NOTIFICATIONS: &[&str] = &[
+    "initialized",
+    "notifications/initialized",
+    "notifications/cancelled",
+    "notifications/progress",
+    "notifications/roots/list_changed",
+];
+
+/// Drop a notification without a response.
+///
+/// Notifications from newer protocol versions are ignored for forward
+/// compatibility rather than rejected; only requests get `METHOD_NOT_FOUND`.
+fn acknowledge_notification(method: &str) -> JsonRpcOutgoing {
+    if !KNOWN_NOTIFICATIONS.contains(&method) {
+        tracing::debug!(method, "Ignoring unknown notification");
+    }
+    JsonRpcOutgoing::notification_ack()
+}
 
 /// Route a JSON-RPC request to the appropriate handler method.
 ///
@@ -50,6 +78,9 @@ pub async fn route_request<H: McpHandler>(
 /// When a `ServerConfig` is provided, this function adds:
 /// - Protocol version negotiation
 /// - Required client capability validation
+///
+/// The response carries the request's trace id in `result._meta`; see
+/// [`crate::TRACE_ID_META`].
 pub async fn route_request_with_config<H: McpHandler>(
     handler: &H,
     request: JsonRpcIncoming,
@@ -57,9 +88,26 @@ pub async fn route_request_with_config<H: McpHandler>(
     config: Option<&ServerConfig>,
 ) -> JsonRpcOutgoing {
     if request.is_notification() {
-        return JsonRpcOutgoing::notification_ack();
+        return acknowledge_notification(&request.method);
     }
 
+    let trace_id = request_trace_id(&request, ctx);
+    let span = tracing::info_span!("mcp.trace", trace_id = %trace_id);
+    let response = async {
+        tracing::debug!(method = %request.method, "Routing request");
+        route_with_config(handler, request, ctx, config).await
+    }
+    .instrument(span)
+    .await;
+    attach_trace_id(response, &trace_id)
+}
+
+async fn route_with_config<H: McpHandler>(
+    handler: &H,
+    request: JsonRpcIncoming,
+    ctx: &RequestContext,
+    config: Option<&ServerConfig>,
+) -> JsonRpcOutgoing {
     let id = request.id.clone();
 
     // Validate message size against configured limit
@@ -154,13 +202,24 @@ pub async fn route_request_with_config<H: McpHandler>(
                 version
             }
             None => {
-                return JsonRpcOutgoing::error(
+                let error = McpError::invalid_request(format!(
+                    "Unsupported protocol version: {}. Supported versions: {:?}",
+                    protocol_version.unwrap_or("none"),
+                    protocol_config.supported_versions
+                ));
+                let supported: Vec<&str> = protocol_config
+                    .supported_versions
+                    .iter()
+                    .map(|version| version.as_str())
+                    .collect();
+                return initialize_error(
                     id,
-                    McpError::invalid_request(format!(
-                        "Unsupported protocol version: {}. Supported versions: {:?}",
-                        protocol_version.unwrap_or("none"),
-                        protocol_config.supported_versions
-                    )),
+                    error,
+                    serde_json::json!({
+                        "reason": "unsupportedProtocolVersion",
+                        "requested": protocol_version,
+                        "supported": supported,
+                    }),
                 );
             }
         };
@@ -171,24 +230,50 @@ pub async fn route_request_with_config<H: McpHandler>(
             let validation = cfg.required_capabilities.validate(&client_caps);
 
             if let Some(missing) = validation.missing() {
-                return JsonRpcOutgoing::error(
+                let error = McpError::invalid_request(format!(
+                    "Missing required client capabilities: {}",
+                    missing.join(", ")
+                ));
+                return initialize_error(
                     id,
-                    McpError::invalid_request(format!(
-                        "Missing required client capabilities: {}",
-                        missing.join(", ")
-                    )),
+                    error,
+                    serde_json::json!({
+                        "reason": "missingCapabilities",
+                        "missing": missing,
+                        "required": cfg.required_capabilities,
+                        "offered": client_caps,
+                    }),
                 );
             }
         }
 
+        // Describe the client now: the request is consumed by routing.
+        let initialize_client = config
+            .filter(|cfg| !cfg.initialize_hooks.is_empty())
+            .map(|_| InitializeClient {
+                client_info: serde_json::from_value(client_info.clone())
+                    .unwrap_or_else(|_| Implementation::new(name, version)),
+                capabilities: params
+                    .get("capabilities")
+                    .and_then(|caps| serde_json::from_value(caps.clone()).ok())
+                    .unwrap_or_default(),
+                protocol_version: negotiated_version.clone(),
+            });
+
         // Use core router with negotiated version
         let version_str = negotiated_version.as_str();
         let core_config = turbomcp_core::router::RouteConfig {
             protocol_version: Some(version_str),
         };
-        let response =
+        let mut response =
             turbomcp_core::router::route_request(handler, request, ctx, &core_config).await;
 
+        if let (Some(cfg), Some(client)) = (config, initialize_client.as_ref())
+            && let Some(result) = response.result.as_mut()
+        {
+            apply_initialize_hooks(&cfg.initialize_hooks, client, result);
+        }
+
         // Apply version adapter to the initialize response
         let adapter = adapter_for_version(&negotiated_version);
         return apply_adapter_to_response(adapter, "initialize", response);
@@ -200,6 +285,58 @@ pub async fn route_request_with_config<H: McpHandler>(
     turbomcp_core::router::route_request(handler, request, ctx, &core_config).await
 }
 
+/// Let the configured hooks rewrite the capabilities and instructions of a
+/// successful `initialize` result.
+fn apply_initialize_hooks(
+    hooks: &InitializeHooks,
+    client: &InitializeClient,
+    result: &mut serde_json::Value,
+) {
+    let Some(result) = result.as_object_mut() else {
+        return;
+    };
+    let computed = InitializeAdvertisement {
+        capabilities: result
+            .get("capabilities")
+            .and_then(|caps| serde_json::from_value(caps.clone()).ok())
+            .unwrap_or_default(),
+        instructions: result
+            .get("instructions")
+            .and_then(|text| text.as_str())
+            .map(str::to_string),
+    };
+
+    let advertised = hooks.apply(client, computed);
+    match serde_json::to_value(&advertised.capabilities) {
+        Ok(capabilities) => {
+            result.insert("capabilities".to_string(), capabilities);
+        }
+        Err(e) => tracing::warn!(error = %e, "Failed to serialize hooked capabilities"),
+    }
+    match advertised.instructions {
+        Some(instructions) => {
+            result.insert("instructions".to_string(), instructions.into());
+        }
+        None => {
+            result.remove("instructions");
+        }
+    }
+}
+
+/// Build an initialize error response carrying structured diagnostics in
+/// `error.data`, so clients can report what was offered and what was required.
+fn initialize_error(
+    id: Option<serde_json::Value>,
+    error: McpError,
+    data: serde_json::Value,
+) -> JsonRpcOutgoing {
+    let code = error.jsonrpc_code();
+    JsonRpcOutgoing::error(
+        id,
+        turbomcp_core::jsonrpc::JsonRpcError::with_data(code, error.message, data),
+    )
+}
+
 /// Route a JSON-RPC request with version-aware adapter filtering.
 ///
 /// This is the recommended entry point for post-initialize requests when the
@@ -210,6 +347,9 @@ pub async fn route_request_with_config<H: McpHandler>(
 ///
 /// Transport layers should store the negotiated [`turbomcp_protocol::types::ProtocolVersion`] from
 /// the initialize handshake and pass it here for all subsequent requests.
+///
+/// The response carries the request's trace id in `result._meta`; see
+/// [`crate::TRACE_ID_META`].
 pub async fn route_request_versioned<H: McpHandler>(
     handler: &H,
     request: JsonRpcIncoming,
@@ -217,9 +357,26 @@ pub async fn route_request_versioned<H: McpHandler>(
     negotiated_version: &turbomcp_types::ProtocolVersion,
 ) -> JsonRpcOutgoing {
     if request.is_notification() {
-        return JsonRpcOutgoing::notification_ack();
+        return acknowledge_notification(&request.method);
+    }
+
+    let trace_id = request_trace_id(&request, ctx);
+    let span = tracing::info_span!("mcp.trace", trace_id = %trace_id);
+    let response = async {
+        tracing::debug!(method = %request.method, "Routing request");
+        route_versioned(handler, request, ctx, negotiated_version).await
     }
+    .instrument(span)
+    .await;
+    attach_trace_id(response, &trace_id)
+}
 
+async fn route_versioned<H: McpHandler>(
+    handler: &H,
+    request: JsonRpcIncoming,
+    ctx: &RequestContext,
+    negotiated_version: &turbomcp_types::ProtocolVersion,
+) -> JsonRpcOutgoing {
     let adapter = adapter_for_version(negotiated_version);
     let method = request.method.clone();
 
@@ -304,6 +461,9 @@ mod tests {
             async move {
                 if name == "test_tool" {
                     Ok(ToolResult::text("Tool executed"))
+                } else if name == "report" {
+                    Ok(ToolResult::text("Report ready")
+                        .with_resource_link("file:///reports/q3.csv", "q3.csv"))
                 } else {
                     Err(McpError::tool_not_found(&name))
                 }
@@ -316,7 +476,13 @@ mod tests {
             _ctx: &RequestContext,
         ) -> impl std::future::Future<Output = McpResult<ResourceResult>> + Send {
             let uri = uri.to_string();
-            async move { Err(McpError::resource_not_found(&uri)) }
+            async move {
+                if uri == "file:///reports/q3.csv" {
+                    Ok(ResourceResult::text(&uri, "quarter,revenue\nq3,42"))
+                } else {
+                    Err(McpError::resource_not_found(&uri))
+                }
+            }
         }
 
         fn get_prompt(
@@ -376,6 +542,137 @@ mod tests {
         assert_eq!(result["capabilities"]["tools"]["listChanged"], true);
     }
 
+    #[tokio::test]
+    async fn test_initialize_hooks_customize_advertisement_per_client() {
+        let config = ServerConfig::builder()
+            // Offer sampling passthrough to clients that can sample...
+            .initialize_hook(
+                |client: &InitializeClient, mut advertised: InitializeAdvertisement| {
+                    if client.capabilities.sampling.is_some() {
+                        advertised
+                            .capabilities
+                            .experimental
+                            .get_or_insert_default()
+                            .insert("sampling".to_string(), serde_json::json!({}));
+                        advertised.instructions = Some("Ask before sampling.".to_string());
+                    }
+                    advertised
+                },
+            )
+            // ...except one known to mishandle it.
+            .initialize_hook(
+                |client: &InitializeClient, mut advertised: InitializeAdvertisement| {
+                    if client.client_info.name == "legacy-client"
+                        && let Some(experimental) = advertised.capabilities.experimental.as_mut()
+                    {
+                        experimental.remove("sampling");
+                        advertised.instructions = None;
+                    }
+                    advertised
+                },
+            )
+            .build();
+
+        let initialize = |client_name: &str| JsonRpcIncoming {
+            jsonrpc: "2.0".to_string(),
+            id: Some(serde_json::json!(1)),
+            method: "initialize".to_string(),
+            params: Some(serde_json::json!({
+                "protocolVersion": "2025-11-25",
+                "clientInfo": { "name": client_name, "version": "1.0.0" },
+                "capabilities": { "sampling": {} }
+            })),
+        };
+        let ctx = RequestContext::stdio();
+
+        let response = route_request_with_config(
+            &TestHandler,
+            initialize("modern-client"),
+            &ctx,
+            Some(&config),
+        )
+        .await;
+        let result = response.result.expect("initialize should succeed");
+        assert!(result["capabilities"]["experimental"]["sampling"].is_object());
+        assert_eq!(result["instructions"], "Ask before sampling.");
+        // Capabilities computed from the handler are kept.
+        assert_eq!(result["capabilities"]["tools"]["listChanged"], true);
+
+        let response = route_request_with_config(
+            &TestHandler,
+            initialize("legacy-client"),
+            &ctx,
+            Some(&config),
+        )
+        .await;
+        let result = response.result.expect("initialize should succeed");
+        assert!(
+            result["capabilities"]["experimental"]
+                .get("sampling")
+                .is_none()
+        );
+        assert!(result.get("instructions").is_none());
+        assert_eq!(result["capabilities"]["tools"]["listChanged"], true);
+    }
+
+    #[tokio::test]
+    async fn test_route_initialize_capability_conflict_has_diagnostics() {
+        let config = ServerConfig::builder()
+            .required_capabilities(
+                crate::config::RequiredCapabilities::none()
+                    .with_roots()
+                    .with_sampling(),
+            )
+            .build();
+        let request = JsonRpcIncoming {
+            jsonrpc: "2.0".to_string(),
+            id: Some(serde_json::json!(1)),
+            method: "initialize".to_string(),
+            params: Some(serde_json::json!({
+                "protocolVersion": "2025-11-25",
+                "clientInfo": { "name": "test-client", "version": "1.0.0" },
+                "capabilities": { "roots": {} }
+            })),
+        };
+
+        let response = route_request_with_config(
+            &TestHandler,
+            request,
+            &RequestContext::stdio(),
+            Some(&config),
+        )
+        .await;
+        let error = response.error.expect("capability conflict");
+        assert!(error.message.contains("sampling"));
+
+        let data = error.data.expect("structured diagnostics");
+        assert_eq!(data["reason"], "missingCapabilities");
+        assert_eq!(data["missing"], serde_json::json!(["sampling"]));
+        assert_eq!(data["required"]["sampling"], true);
+        assert_eq!(data["offered"]["roots"], true);
+        assert_eq!(data["offered"]["sampling"], false);
+    }
+
+    #[tokio::test]
+    async fn test_route_initialize_version_mismatch_has_diagnostics() {
+        let request = JsonRpcIncoming {
+            jsonrpc: "2.0".to_string(),
+            id: Some(serde_json::json!(1)),
+            method: "initialize".to_string(),
+            params: Some(serde_json::json!({
+                "protocolVersion": "1999-01-01",
+                "clientInfo": { "name": "test-client", "version": "1.0.0" },
+                "capabilities": {}
+            })),
+        };
+
+        let response = route_request(&TestHandler, request, &RequestContext::stdio()).await;
+        let data = response.error.and_then(|e| e.data).expect("diagnostics");
+        assert_eq!(data["reason"], "unsupportedProtocolVersion");
+        assert_eq!(data["requested"], "1999-01-01");
+        assert!(data["supported"].as_array().is_some_and(|v| !v.is_empty()));
+    }
+
     #[tokio::test]
     async fn test_route_initialize_missing_client_info() {
         let handler = TestHandler;
@@ -428,6 +725,25 @@ mod tests {
         assert!(!response.should_send());
     }
 
+    #[tokio::test]
+    async fn test_route_unknown_notification_is_ignored() {
+        let handler = TestHandler;
+        let ctx = RequestContext::stdio();
+        let notification = || JsonRpcIncoming {
+            jsonrpc: "2.0".to_string(),
+            id: None,
+            method: "notifications/from_the_future".to_string(),
+            params: Some(serde_json::json!({"detail": 1})),
+        };
+
+        let response = route_request(&handler, notification(), &ctx).await;
+        assert!(!response.should_send());
+
+        let version = turbomcp_types::ProtocolVersion::from("2025-11-25");
+        let response = route_request_versioned(&handler, notification(), &ctx, &version).await;
+        assert!(!response.should_send());
+    }
+
     #[tokio::test]
     async fn test_route_tools_list() {
         let handler = TestHandler;
@@ -467,6 +783,38 @@ mod tests {
         assert!(response.error.is_none());
     }
 
+    #[tokio::test]
+    async fn test_route_tools_call_resource_link_is_readable() {
+        let handler = TestHandler;
+        let ctx = RequestContext::stdio();
+        let call = JsonRpcIncoming {
+            jsonrpc: "2.0".to_string(),
+            id: Some(serde_json::json!(1)),
+            method: "tools/call".to_string(),
+            params: Some(serde_json::json!({ "name": "report", "arguments": {} })),
+        };
+
+        let result = route_request(&handler, call, &ctx)
+            .await
+            .result
+            .expect("tool result");
+        let link = &result["content"][1];
+        assert_eq!(link["type"], "resource_link");
+        assert_eq!(link["name"], "q3.csv");
+
+        let read = JsonRpcIncoming {
+            jsonrpc: "2.0".to_string(),
+            id: Some(serde_json::json!(2)),
+            method: "resources/read".to_string(),
+            params: Some(serde_json::json!({ "uri": link["uri"].clone() })),
+        };
+        let result = route_request(&handler, read, &ctx)
+            .await
+            .result
+            .expect("resource contents");
+        assert_eq!(result["contents"][0]["text"], "quarter,revenue\nq3,42");
+    }
+
     #[tokio::test]
     async fn test_route_unknown_method() {
         let handler = TestHandler;
@@ -485,4 +833,47 @@ mod tests {
         let error = response.error.unwrap();
         assert_eq!(error.code, -32601); // METHOD_NOT_FOUND
     }
+
+    #[tokio::test]
+    async fn test_response_carries_trace_id() {
+        let handler = TestHandler;
+        let ctx = RequestContext::stdio();
+        let call = |params: Value| JsonRpcIncoming {
+            jsonrpc: "2.0".to_string(),
+            id: Some(serde_json::json!(1)),
+            method: "tools/call".to_string(),
+            params: Some(params),
+        };
+
+        let result = route_request(
+            &handler,
+            call(serde_json::json!({ "name": "test_tool" })),
+            &ctx,
+        )
+        .await
+        .result
+        .expect("tool result");
+        let generated = result["_meta"][crate::TRACE_ID_META]
+            .as_str()
+            .expect("generated trace id");
+        assert_eq!(generated.len(), 32);
+
+        // An incoming W3C traceparent is reused rather than regenerated.
+        let traced = call(serde_json::json!({
+            "name": "test_tool",
+            "_meta": {
+                "traceparent": "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"
+            }
+        }));
+        let version = turbomcp_types::ProtocolVersion::LATEST;
+        let result = route_request_versioned(&handler, traced, &ctx, &version)
+            .await
+            .result
+            .expect("tool result");
+        assert_eq!(
+            result["_meta"][crate::TRACE_ID_META],
+            "4bf92f3577b34da6a3ce929d0e0e4736"
+        );
+        assert_eq!(result["content"][0]["text"], "Tool executed");
+    }
 }
@@ -0,0 +1,301 @@
+//! The handler [`ServerBuilder`](crate::ServerBuilder) actually serves.
+//!
+//! The builder's built-in features live in one [`ServedHandler`] layer
+//! instead of a wrapper type per feature: list rewriting (tool schema
+//! metadata and transforms, directory resources, icons) is applied here
+//! directly, and request-path features such as panic isolation run as
+//! [`McpMiddleware`](crate::middleware::McpMiddleware) in the layer's
+//! [`MiddlewareStack`]. The builder constructs it in exactly one place, so
+//! every way of serving a builder (`serve`, `serve_multi`,
+//! `into_axum_router`) and its
+//! [`diagnostics`](crate::ServerBuilder::diagnostics) see the same handler.
+
+use std::sync::Arc;
+
+use serde_json::Value;
+use turbomcp_core::context::RequestContext;
+use turbomcp_core::error::McpResult;
+use turbomcp_core::handler::McpHandler;
+use turbomcp_core::marker::MaybeSend;
+use turbomcp_types::{
+    Icon, ListTasksResult, Prompt, PromptResult, Resource, ResourceResult, ResourceTemplate,
+    ResourcesCapabilities, ServerCapabilities, ServerInfo, Task, Tool, ToolResult,
+};
+
+use super::drain::{DrainCoordinator, DrainTracker, InFlightGuard};
+use super::list_cache::ListCache;
+use super::middleware::MiddlewareStack;
+use super::output_transform::OutputTransforms;
+use super::resource_dir::ResourceDirs;
+use super::server_icon::add_icons;
+use super::tool_schema::{SchemaTransforms, add_schema_metadata};
+
+/// Configuration of the built-in layers, shared by every clone of a
+/// [`ServedHandler`].
+#[derive(Debug)]
+pub(crate) struct ServedLayers {
+    /// Add `$schema` and `title` to listed tool input schemas.
+    pub(crate) tool_schema_metadata: bool,
+    /// Applied to listed tool schemas after the metadata.
+    pub(crate) schema_transforms: SchemaTransforms,
+    /// Applied to tool results fetched with `tasks/result`; `tools/call`
+    /// results are transformed by the same list in the middleware stack.
+    pub(crate) output_transforms: OutputTransforms,
+    /// Added to the advertised server info.
+    pub(crate) icons: Vec<Icon>,
+    /// Directories whose files are listed and read as resources.
+    pub(crate) resource_dirs: ResourceDirs,
+    /// Shared cache of the handler's list responses, if enabled.
+    pub(crate) list_cache: Option<ListCache>,
+}
+
+/// A user handler with the builder's built-in layers applied.
+///
+/// Tool calls, resource reads, prompts, and lifecycle hooks go through the
+/// middleware stack; every other request goes straight to the handler. When
+/// lists are cached, the first three see a session that clears the cache as
+/// `list_changed` notifications are sent. A clone given a drain tracker with
+/// [`with_drain`](Self::with_drain) also counts its requests that do work.
+#[derive(Debug, Clone)]
+pub(crate) struct ServedHandler<H: McpHandler> {
+    stack: MiddlewareStack<H>,
+    layers: Arc<ServedLayers>,
+    /// Counts this transport's requests for a coordinated drain.
+    drain: Option<DrainTracker>,
+}
+
+impl<H: McpHandler> ServedHandler<H> {
+    /// Apply `layers` to the handler in `stack`.
+    pub(crate) fn new(stack: MiddlewareStack<H>, layers: ServedLayers) -> Self {
+        Self {
+            stack,
+            layers: Arc::new(layers),
+            drain: None,
+        }
+    }
+
+    /// Count requests served through this clone under `transport` in
+    /// `coordinator`, refusing new ones once it starts draining.
+    pub(crate) fn with_drain(
+        mut self,
+        coordinator: &DrainCoordinator,
+        transport: &'static str,
+    ) -> Self {
+        self.drain = Some(DrainTracker::new(coordinator, transport));
+        self
+    }
+
+    /// Count a request for the drain, if one is tracked.
+    fn admit(&self) -> McpResult<Option<InFlightGuard>> {
+        self.drain.as_ref().map(DrainTracker::admit).transpose()
+    }
+
+    fn inner(&self) -> &H {
+        self.stack.handler()
+    }
+
+    /// The handler's tools with schema metadata and transforms applied.
+    fn rewritten_tools(&self) -> Vec<Tool> {
+        let tools = self.inner().list_tools();
+        let layers = &self.layers;
+        if !layers.tool_schema_metadata && layers.schema_transforms.is_empty() {
+            return tools;
+        }
+        tools
+            .into_iter()
+            .map(|tool| {
+                let tool = if layers.tool_schema_metadata {
+                    add_schema_metadata(tool)
+                } else {
+                    tool
+                };
+                layers.schema_transforms.apply(tool)
+            })
+            .collect()
+    }
+
+    /// `ctx`, watched for `list_changed` notifications when lists are cached.
+    fn watch(&self, ctx: &RequestContext) -> Option<RequestContext> {
+        self.layers.list_cache.as_ref()?.watch(ctx)
+    }
+}
+
+#[allow(clippy::manual_async_fn)]
+impl<H: McpHandler> McpHandler for ServedHandler<H> {
+    fn server_info(&self) -> ServerInfo {
+        let mut info = self.inner().server_info();
+        add_icons(&mut info, &self.layers.icons);
+        info
+    }
+
+    fn server_capabilities(&self) -> ServerCapabilities {
+        let mut capabilities = self.inner().server_capabilities();
+        // Directory contents can change between lists, but no
+        // `list_changed` notification is sent, so none is advertised.
+        if !self.layers.resource_dirs.is_empty() && capabilities.resources.is_none() {
+            capabilities.resources = Some(ResourcesCapabilities::default());
+        }
+        capabilities
+    }
+
+    fn list_tools(&self) -> Vec<Tool> {
+        match &self.layers.list_cache {
+            Some(cache) => cache.tools(|| self.rewritten_tools()),
+            None => self.rewritten_tools(),
+        }
+    }
+
+    fn list_resources(&self) -> Vec<Resource> {
+        let mut resources = match &self.layers.list_cache {
+            Some(cache) => cache.resources(|| self.inner().list_resources()),
+            None => self.inner().list_resources(),
+        };
+        resources.extend(self.layers.resource_dirs.list());
+        resources
+    }
+
+    fn list_resource_templates(&self) -> Vec<ResourceTemplate> {
+        match &self.layers.list_cache {
+            Some(cache) => cache.resource_templates(|| self.inner().list_resource_templates()),
+            None => self.inner().list_resource_templates(),
+        }
+    }
+
+    fn list_prompts(&self) -> Vec<Prompt> {
+        match &self.layers.list_cache {
+            Some(cache) => cache.prompts(|| self.inner().list_prompts()),
+            None => self.inner().list_prompts(),
+        }
+    }
+
+    fn call_tool<'a>(
+        &'a self,
+        name: &'a str,
+        args: Value,
+        ctx: &'a RequestContext,
+    ) -> impl std::future::Future<Output = McpResult<ToolResult>> + MaybeSend + 'a {
+        async move {
+            let _guard = self.admit()?;
+            match self.watch(ctx) {
+                Some(ctx) => self.stack.call_tool(name, args, &ctx).await,
+                None => self.stack.call_tool(name, args, ctx).await,
+            }
+        }
+    }
+
+    fn read_resource<'a>(
+        &'a self,
+        uri: &'a str,
+        ctx: &'a RequestContext,
+    ) -> impl std::future::Future<Output = McpResult<ResourceResult>> + MaybeSend + 'a {
+        async move {
+            let _guard = self.admit()?;
+            if let Some(result) = self.layers.resource_dirs.read(uri).await {
+                return result;
+            }
+            match self.watch(ctx) {
+                Some(ctx) => self.stack.read_resource(uri, &ctx).await,
+                None => self.stack.read_resource(uri, ctx).await,
+            }
+        }
+    }
+
+    fn get_prompt<'a>(
+        &'a self,
+        name: &'a str,
+        args: Option<Value>,
+        ctx: &'a RequestContext,
+    ) -> impl std::future::Future<Output = McpResult<PromptResult>> + MaybeSend + 'a {
+        async move {
+            let _guard = self.admit()?;
+            match self.watch(ctx) {
+                Some(ctx) => self.stack.get_prompt(name, args, &ctx).await,
+                None => self.stack.get_prompt(name, args, ctx).await,
+            }
+        }
+    }
+
+    fn list_tasks<'a>(
+        &'a self,
+        cursor: Option<&'a str>,
+        limit: Option<usize>,
+        ctx: &'a RequestContext,
+    ) -> impl std::future::Future<Output = McpResult<ListTasksResult>> + MaybeSend + 'a {
+        self.inner().list_tasks(cursor, limit, ctx)
+    }
+
+    fn get_task<'a>(
+        &'a self,
+        task_id: &'a str,
+        ctx: &'a RequestContext,
+    ) -> impl std::future::Future<Output = McpResult<Task>> + MaybeSend + 'a {
+        self.inner().get_task(task_id, ctx)
+    }
+
+    fn cancel_task<'a>(
+        &'a self,
+        task_id: &'a str,
+        ctx: &'a RequestContext,
+    ) -> impl std::future::Future<Output = McpResult<Task>> + MaybeSend + 'a {
+        self.inner().cancel_task(task_id, ctx)
+    }
+
+    fn get_task_result<'a>(
+        &'a self,
+        task_id: &'a str,
+        ctx: &'a RequestContext,
+    ) -> impl std::future::Future<Output = McpResult<Value>> + MaybeSend + 'a {
+        async move {
+            let _guard = self.admit()?;
+            let result = self.inner().get_task_result(task_id, ctx).await?;
+            Ok(self.layers.output_transforms.apply_to_task_result(result))
+        }
+    }
+
+    fn subscribe<'a>(
+        &'a self,
+        uri: &'a str,
+        ctx: &'a RequestContext,
+    ) -> impl std::future::Future<Output = McpResult<()>> + MaybeSend + 'a {
+        self.inner().subscribe(uri, ctx)
+    }
+
+    fn unsubscribe<'a>(
+        &'a self,
+        uri: &'a str,
+        ctx: &'a RequestContext,
+    ) -> impl std::future::Future<Output = McpResult<()>> + MaybeSend + 'a {
+        self.inner().unsubscribe(uri, ctx)
+    }
+
+    fn set_log_level<'a>(
+        &'a self,
+        level: &'a str,
+        ctx: &'a RequestContext,
+    ) -> impl std::future::Future<Output = McpResult<()>> + MaybeSend + 'a {
+        self.inner().set_log_level(level, ctx)
+    }
+
+    fn complete<'a>(
+        &'a self,
+        params: Value,
+        ctx: &'a RequestContext,
+    ) -> impl std::future::Future<Output = McpResult<Value>> + MaybeSend + 'a {
+        async move {
+            let _guard = self.admit()?;
+            self.inner().complete(params, ctx).await
+        }
+    }
+
+    fn on_initialize(&self) -> impl std::future::Future<Output = McpResult<()>> + MaybeSend {
+        self.stack.on_initialize()
+    }
+
+    fn on_shutdown(&self) -> impl std::future::Future<Output = McpResult<()>> + MaybeSend {
+        self.stack.on_shutdown()
+    }
+
+    fn on_session_closed(&self, session_id: &str) {
+        self.stack.on_session_closed(session_id);
+    }
+}
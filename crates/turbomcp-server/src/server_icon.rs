@@ -0,0 +1,139 @@
+//! Server icons.
+//!
+//! [`ServerBuilder::icon`](crate::ServerBuilder::icon) adds icons to the
+//! `serverInfo` returned from `initialize`, so clients can show a logo for
+//! the server. Icons whose source is not an HTTP(S) URL or `data:` URI are
+//! dropped with a warning, whichever way the builder is served.
+
+use turbomcp_types::{Icon, ServerInfo};
+
+/// The icons in `icons` with a valid source, logging the rest.
+pub(crate) fn valid_icons(icons: &[Icon]) -> Vec<Icon> {
+    icons
+        .iter()
+        .filter(|icon| {
+            let valid = Icon::is_valid_src(&icon.src);
+            if !valid {
+                tracing::warn!(src = %icon.src, "Ignoring invalid server icon");
+            }
+            valid
+        })
+        .cloned()
+        .collect()
+}
+
+/// Advertise `icons` in `info` after any it already has.
+pub(crate) fn add_icons(info: &mut ServerInfo, icons: &[Icon]) {
+    if !icons.is_empty() {
+        info.icons
+            .get_or_insert_with(Vec::new)
+            .extend(icons.iter().cloned());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::McpServerExt;
+    use crate::router::{JsonRpcIncoming, route_request};
+    use serde_json::Value;
+    use turbomcp_core::context::RequestContext;
+    use turbomcp_core::error::{McpError, McpResult};
+    use turbomcp_core::handler::McpHandler;
+    use turbomcp_core::marker::MaybeSend;
+    use turbomcp_types::{Prompt, PromptResult, Resource, ResourceResult, Tool, ToolResult};
+
+    #[derive(Clone)]
+    struct IconHandler;
+
+    #[allow(clippy::manual_async_fn)]
+    impl McpHandler for IconHandler {
+        fn server_info(&self) -> ServerInfo {
+            ServerInfo::new("icon-test", "1.0.0")
+        }
+
+        fn list_tools(&self) -> Vec<Tool> {
+            vec![]
+        }
+
+        fn list_resources(&self) -> Vec<Resource> {
+            vec![]
+        }
+
+        fn list_prompts(&self) -> Vec<Prompt> {
+            vec![]
+        }
+
+        fn call_tool<'a>(
+            &'a self,
+            name: &'a str,
+            _args: Value,
+            _ctx: &'a RequestContext,
+        ) -> impl std::future::Future<Output = McpResult<ToolResult>> + MaybeSend + 'a {
+            let name = name.to_string();
+            async move { Err(McpError::tool_not_found(&name)) }
+        }
+
+        fn read_resource<'a>(
+            &'a self,
+            uri: &'a str,
+            _ctx: &'a RequestContext,
+        ) -> impl std::future::Future<Output = McpResult<ResourceResult>> + MaybeSend + 'a {
+            let uri = uri.to_string();
+            async move { Err(McpError::resource_not_found(&uri)) }
+        }
+
+        fn get_prompt<'a>(
+            &'a self,
+            name: &'a str,
+            _args: Option<Value>,
+            _ctx: &'a RequestContext,
+        ) -> impl std::future::Future<Output = McpResult<PromptResult>> + MaybeSend + 'a {
+            let name = name.to_string();
+            async move { Err(McpError::prompt_not_found(&name)) }
+        }
+    }
+
+    async fn initialize(handler: &impl McpHandler) -> Value {
+        let request = JsonRpcIncoming {
+            jsonrpc: "2.0".to_string(),
+            id: Some(serde_json::json!(1)),
+            method: "initialize".to_string(),
+            params: Some(serde_json::json!({
+                "protocolVersion": "2025-11-25",
+                "clientInfo": { "name": "test-client", "version": "1.0.0" },
+                "capabilities": {}
+            })),
+        };
+        let response = route_request(handler, request, &RequestContext::stdio()).await;
+        response.result.expect("initialize result")
+    }
+
+    #[tokio::test]
+    async fn test_icon_appears_in_initialize_result() {
+        let handler = IconHandler
+            .builder()
+            .icon("https://example.com/logo.png")
+            .served_handler();
+
+        let result = initialize(&handler).await;
+        assert_eq!(
+            result["serverInfo"]["icons"][0]["src"],
+            "https://example.com/logo.png"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_invalid_icons_are_dropped() {
+        let handler = IconHandler
+            .builder()
+            .icon("logo.png")
+            .icon("data:image/svg+xml,<svg/>")
+            .served_handler();
+
+        let result = initialize(&handler).await;
+        let icons = result["serverInfo"]["icons"].as_array().unwrap();
+        assert_eq!(icons.len(), 1);
+        assert_eq!(icons[0]["src"], "data:image/svg+xml,<svg/>");
+    }
+}
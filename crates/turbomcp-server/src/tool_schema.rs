@@ -0,0 +1,317 @@
+//! Tool input schema metadata.
+//!
+//! Some strict client validators reject tool input schemas that do not declare
+//! a JSON Schema dialect or lack a `title`. [`ServerBuilder`](crate::ServerBuilder)
+//! fills in `$schema` (JSON Schema 2020-12) and a `title` derived from the
+//! tool name on every listed tool. Values a tool already sets are left
+//! untouched. Disable it with
+//! [`with_tool_schema_metadata(false)`](crate::ServerBuilder::with_tool_schema_metadata).
+//!
+//! The builder then runs any [`SchemaTransforms`] registered with
+//! [`ServerBuilder::schema_transform`](crate::ServerBuilder::schema_transform),
+//! so applications can rewrite every tool input and output schema uniformly,
+//! e.g. to forbid additional properties or add `x-` extensions.
+
+use std::fmt;
+use std::sync::Arc;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use turbomcp_types::{JSON_SCHEMA_DIALECT_2020_12, Tool};
+
+type SchemaTransform = dyn Fn(&mut Value) + Send + Sync;
+
+/// Ordered list of functions applied to every listed tool schema.
+///
+/// Each transform receives a tool's input schema, then its output schema if
+/// it has one, as raw JSON. A transform that leaves a schema in a shape that
+/// no longer deserializes is logged and that schema is left unchanged.
+#[derive(Clone, Default)]
+pub struct SchemaTransforms {
+    transforms: Vec<Arc<SchemaTransform>>,
+}
+
+impl SchemaTransforms {
+    /// Append a transform; it runs after the ones already registered.
+    pub fn push(&mut self, transform: impl Fn(&mut Value) + Send + Sync + 'static) {
+        self.transforms.push(Arc::new(transform));
+    }
+
+    /// Whether no transforms are registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.transforms.is_empty()
+    }
+
+    /// Number of registered transforms.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.transforms.len()
+    }
+
+    /// Run every transform over `tool`'s input and output schemas.
+    pub fn apply(&self, mut tool: Tool) -> Tool {
+        if self.is_empty() {
+            return tool;
+        }
+        self.transform_schema(&tool.name, &mut tool.input_schema);
+        if let Some(output_schema) = tool.output_schema.as_mut() {
+            self.transform_schema(&tool.name, output_schema);
+        }
+        tool
+    }
+
+    fn transform_schema<S: Serialize + DeserializeOwned>(&self, tool: &str, schema: &mut S) {
+        let Ok(mut value) = serde_json::to_value(&*schema) else {
+            return;
+        };
+        for transform in &self.transforms {
+            transform(&mut value);
+        }
+        match serde_json::from_value(value) {
+            Ok(transformed) => *schema = transformed,
+            Err(error) => {
+                tracing::warn!(tool, error = %error, "Ignoring invalid transformed tool schema");
+            }
+        }
+    }
+}
+
+impl fmt::Debug for SchemaTransforms {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SchemaTransforms")
+            .field("len", &self.transforms.len())
+            .finish()
+    }
+}
+
+/// Derive a schema title from a tool name: `get_weather` → `Get Weather`.
+#[must_use]
+pub fn schema_title_from_tool_name(name: &str) -> String {
+    name.split(|c: char| c == '_' || c == '-' || c == '.' || c.is_whitespace())
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_uppercase().chain(chars).collect::<String>()
+            })
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Add `$schema` and a derived `title` to `tool`'s input schema unless it
+/// already sets them.
+pub(crate) fn add_schema_metadata(mut tool: Tool) -> Tool {
+    let keywords = &mut tool.input_schema.extra_keywords;
+    keywords
+        .entry("$schema".to_string())
+        .or_insert_with(|| Value::String(JSON_SCHEMA_DIALECT_2020_12.to_string()));
+    if !keywords.contains_key("title") {
+        keywords.insert(
+            "title".to_string(),
+            Value::String(schema_title_from_tool_name(&tool.name)),
+        );
+    }
+    tool
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::McpServerExt;
+    use turbomcp_core::context::RequestContext;
+    use turbomcp_core::error::{McpError, McpResult};
+    use turbomcp_core::handler::McpHandler;
+    use turbomcp_core::marker::MaybeSend;
+    use turbomcp_types::{
+        Prompt, PromptResult, Resource, ResourceResult, ServerInfo, ToolInputSchema, ToolResult,
+    };
+
+    #[derive(Clone)]
+    struct SchemaHandler;
+
+    #[allow(clippy::manual_async_fn)]
+    impl McpHandler for SchemaHandler {
+        fn server_info(&self) -> ServerInfo {
+            ServerInfo::new("schema-test", "1.0.0")
+        }
+
+        fn list_tools(&self) -> Vec<Tool> {
+            let mut bare = Tool::new("get_weather", "Weather lookup");
+            bare.input_schema.extra_keywords.clear();
+            let mut titled = Tool::new("add", "Add");
+            titled.input_schema = ToolInputSchema::default().with_title("Addition Input");
+            vec![bare, titled]
+        }
+
+        fn list_resources(&self) -> Vec<Resource> {
+            vec![]
+        }
+
+        fn list_prompts(&self) -> Vec<Prompt> {
+            vec![]
+        }
+
+        fn call_tool<'a>(
+            &'a self,
+            _name: &'a str,
+            _args: Value,
+            _ctx: &'a RequestContext,
+        ) -> impl std::future::Future<Output = McpResult<ToolResult>> + MaybeSend + 'a {
+            async { Ok(ToolResult::text("ok")) }
+        }
+
+        fn read_resource<'a>(
+            &'a self,
+            uri: &'a str,
+            _ctx: &'a RequestContext,
+        ) -> impl std::future::Future<Output = McpResult<ResourceResult>> + MaybeSend + 'a {
+            let uri = uri.to_string();
+            async move { Err(McpError::resource_not_found(&uri)) }
+        }
+
+        fn get_prompt<'a>(
+            &'a self,
+            name: &'a str,
+            _args: Option<Value>,
+            _ctx: &'a RequestContext,
+        ) -> impl std::future::Future<Output = McpResult<PromptResult>> + MaybeSend + 'a {
+            let name = name.to_string();
+            async move { Err(McpError::prompt_not_found(&name)) }
+        }
+    }
+
+    /// Tools whose input schemas leave `additionalProperties` unset.
+    #[derive(Clone)]
+    struct OpenSchemaHandler;
+
+    #[allow(clippy::manual_async_fn)]
+    impl McpHandler for OpenSchemaHandler {
+        fn server_info(&self) -> ServerInfo {
+            ServerInfo::new("open-schema-test", "1.0.0")
+        }
+
+        fn list_tools(&self) -> Vec<Tool> {
+            ["search", "fetch"]
+                .into_iter()
+                .map(|name| {
+                    let mut tool = Tool::new(name, name);
+                    tool.input_schema.additional_properties = None;
+                    tool
+                })
+                .collect()
+        }
+
+        fn list_resources(&self) -> Vec<Resource> {
+            vec![]
+        }
+
+        fn list_prompts(&self) -> Vec<Prompt> {
+            vec![]
+        }
+
+        fn call_tool<'a>(
+            &'a self,
+            _name: &'a str,
+            _args: Value,
+            _ctx: &'a RequestContext,
+        ) -> impl std::future::Future<Output = McpResult<ToolResult>> + MaybeSend + 'a {
+            async { Ok(ToolResult::text("ok")) }
+        }
+
+        fn read_resource<'a>(
+            &'a self,
+            uri: &'a str,
+            _ctx: &'a RequestContext,
+        ) -> impl std::future::Future<Output = McpResult<ResourceResult>> + MaybeSend + 'a {
+            let uri = uri.to_string();
+            async move { Err(McpError::resource_not_found(&uri)) }
+        }
+
+        fn get_prompt<'a>(
+            &'a self,
+            name: &'a str,
+            _args: Option<Value>,
+            _ctx: &'a RequestContext,
+        ) -> impl std::future::Future<Output = McpResult<PromptResult>> + MaybeSend + 'a {
+            let name = name.to_string();
+            async move { Err(McpError::prompt_not_found(&name)) }
+        }
+    }
+
+    #[test]
+    fn test_title_from_tool_name() {
+        assert_eq!(schema_title_from_tool_name("get_weather"), "Get Weather");
+        assert_eq!(
+            schema_title_from_tool_name("files.read-all"),
+            "Files Read All"
+        );
+        assert_eq!(schema_title_from_tool_name("ping"), "Ping");
+    }
+
+    #[test]
+    fn test_emitted_schema_includes_dialect_and_title() {
+        let tools = SchemaHandler.builder().served_handler().list_tools();
+
+        let schema = serde_json::to_value(&tools[0].input_schema).unwrap();
+        assert_eq!(schema["$schema"], JSON_SCHEMA_DIALECT_2020_12);
+        assert_eq!(schema["title"], "Get Weather");
+
+        // Explicit titles win over the derived one.
+        assert_eq!(tools[1].input_schema.title(), Some("Addition Input"));
+    }
+
+    #[test]
+    fn test_builder_can_disable_schema_metadata() {
+        let builder = SchemaHandler.builder().with_tool_schema_metadata(false);
+        assert!(!builder.tool_schema_metadata());
+        let schema = &builder.served_handler().list_tools()[0].input_schema;
+        assert!(schema.title().is_none());
+        assert!(!schema.extra_keywords.contains_key("$schema"));
+    }
+
+    #[test]
+    fn test_schema_transform_applies_to_all_tools() {
+        let builder = OpenSchemaHandler
+            .builder()
+            .schema_transform(|schema: &mut Value| {
+                schema["additionalProperties"] = Value::Bool(false);
+            })
+            .schema_transform(|schema: &mut Value| {
+                if let Some(schema) = schema.as_object_mut() {
+                    schema.remove("title");
+                }
+            });
+        assert_eq!(builder.schema_transforms().len(), 2);
+        assert!(
+            OpenSchemaHandler.list_tools()[0]
+                .input_schema
+                .additional_properties
+                .is_none()
+        );
+
+        for tool in builder.served_handler().list_tools() {
+            let schema = serde_json::to_value(&tool.input_schema).unwrap();
+            assert_eq!(schema["additionalProperties"], false, "{}", tool.name);
+            // Transforms run after the built-in metadata is added.
+            assert_eq!(schema["$schema"], JSON_SCHEMA_DIALECT_2020_12);
+            assert!(schema.get("title").is_none());
+        }
+    }
+
+    #[test]
+    fn test_invalid_transformed_schema_is_ignored() {
+        let builder = SchemaHandler
+            .builder()
+            .with_tool_schema_metadata(false)
+            .schema_transform(|schema: &mut Value| *schema = Value::from("not a schema"));
+        assert_eq!(
+            builder.served_handler().list_tools()[1]
+                .input_schema
+                .title(),
+            Some("Addition Input")
+        );
+    }
+}
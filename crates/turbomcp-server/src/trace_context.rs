@@ -0,0 +1,191 @@
+//! Per-request trace ids.
+//!
+//! Every routed request gets a trace id that is logged with the request and
+//! echoed to the client in the result's `_meta`, so a client can quote it when
+//! correlating with server logs:
+//!
+//! ```json
+//! {"jsonrpc": "2.0", "id": 1, "result": {"tools": [], "_meta": {"traceId": "4bf92f3577b34da6a3ce929d0e0e4736"}}}
+//! ```
+//!
+//! If the request carries a W3C `traceparent` (in its `_meta`, or as an HTTP
+//! header), its trace id is reused so the server joins the caller's trace.
+//! Otherwise a fresh random id is generated in the same 32-hex-digit format.
+//! Empty results, such as the reply to `ping`, are sent without one; the id
+//! is still logged.
+
+use serde_json::Value;
+use turbomcp_core::context::RequestContext;
+use turbomcp_core::jsonrpc::{JsonRpcIncoming, JsonRpcOutgoing};
+
+/// Result `_meta` field carrying the request's trace id.
+pub const TRACE_ID_META: &str = "traceId";
+
+/// W3C trace-context field, read from request `_meta` or the HTTP header.
+pub const TRACEPARENT: &str = "traceparent";
+
+/// Extract the trace id from a W3C `traceparent` value.
+///
+/// Accepts `{version}-{trace-id}-{parent-id}-{flags}` with lowercase hex
+/// fields, and rejects version `ff` and the all-zero trace and parent ids
+/// that the spec marks invalid.
+#[must_use]
+pub fn trace_id_from_traceparent(traceparent: &str) -> Option<&str> {
+    let mut parts = traceparent.trim().split('-');
+    let version = parts.next()?;
+    let trace_id = parts.next()?;
+    let parent_id = parts.next()?;
+    let flags = parts.next()?;
+
+    let is_hex = |s: &str, len: usize| {
+        s.len() == len && s.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+    };
+    let is_zero = |s: &str| s.bytes().all(|b| b == b'0');
+    if !is_hex(version, 2) || version == "ff" {
+        return None;
+    }
+    // Version 00 has exactly four fields; later versions may append more.
+    if version == "00" && parts.next().is_some() {
+        return None;
+    }
+    if !is_hex(trace_id, 32) || is_zero(trace_id) {
+        return None;
+    }
+    if !is_hex(parent_id, 16) || is_zero(parent_id) || !is_hex(flags, 2) {
+        return None;
+    }
+    Some(trace_id)
+}
+
+/// Trace id for `request`: reused from an incoming `traceparent`, or new.
+pub(crate) fn request_trace_id(request: &JsonRpcIncoming, ctx: &RequestContext) -> String {
+    let from_meta = request
+        .params
+        .as_ref()
+        .and_then(|params| params.get("_meta"))
+        .and_then(|meta| meta.get(TRACEPARENT))
+        .and_then(Value::as_str);
+    from_meta
+        .into_iter()
+        .chain(ctx.header(TRACEPARENT))
+        .find_map(trace_id_from_traceparent)
+        .map_or_else(|| uuid::Uuid::new_v4().simple().to_string(), str::to_string)
+}
+
+/// Record `trace_id` in the `_meta` of a successful, non-empty object result.
+///
+/// Empty results, such as the reply to `ping`, are sent unchanged.
+pub(crate) fn attach_trace_id(mut response: JsonRpcOutgoing, trace_id: &str) -> JsonRpcOutgoing {
+    if let Some(Value::Object(result)) = response.result.as_mut()
+        && !result.is_empty()
+    {
+        let meta = result
+            .entry("_meta")
+            .or_insert_with(|| Value::Object(serde_json::Map::new()));
+        if let Value::Object(meta) = meta {
+            meta.insert(
+                TRACE_ID_META.to_string(),
+                Value::String(trace_id.to_string()),
+            );
+        }
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const TRACEPARENT_VALUE: &str = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+
+    #[test]
+    fn test_trace_id_from_traceparent() {
+        assert_eq!(
+            trace_id_from_traceparent(TRACEPARENT_VALUE),
+            Some("4bf92f3577b34da6a3ce929d0e0e4736")
+        );
+        // Unknown future versions may carry extra fields.
+        assert!(
+            trace_id_from_traceparent(
+                "cc-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01-extra"
+            )
+            .is_some()
+        );
+
+        for invalid in [
+            "",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01-extra",
+            "ff-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+            "00-00000000000000000000000000000000-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-0000000000000000-01",
+            "00-4BF92F3577B34DA6A3CE929D0E0E4736-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6-00f067aa0ba902b7-01",
+        ] {
+            assert_eq!(trace_id_from_traceparent(invalid), None, "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_request_trace_id_prefers_meta_then_header() {
+        let request = |params: Option<Value>| JsonRpcIncoming {
+            jsonrpc: "2.0".to_string(),
+            id: Some(json!(1)),
+            method: "tools/list".to_string(),
+            params,
+        };
+        let header_ctx = RequestContext::http().with_headers(
+            [(
+                "Traceparent".to_string(),
+                "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01".to_string(),
+            )]
+            .into_iter()
+            .collect(),
+        );
+
+        let with_meta = request(Some(json!({ "_meta": { TRACEPARENT: TRACEPARENT_VALUE } })));
+        assert_eq!(
+            request_trace_id(&with_meta, &header_ctx),
+            "4bf92f3577b34da6a3ce929d0e0e4736"
+        );
+        assert_eq!(
+            request_trace_id(&request(None), &header_ctx),
+            "0af7651916cd43dd8448eb211c80319c"
+        );
+
+        let generated = request_trace_id(&request(None), &RequestContext::stdio());
+        assert_eq!(generated.len(), 32);
+        assert!(
+            trace_id_from_traceparent(&format!("00-{generated}-00f067aa0ba902b7-01")).is_some()
+        );
+        assert_ne!(
+            generated,
+            request_trace_id(&request(None), &RequestContext::stdio())
+        );
+    }
+
+    #[test]
+    fn test_attach_trace_id_keeps_existing_meta() {
+        let response = JsonRpcOutgoing::success(
+            Some(json!(1)),
+            json!({ "content": [], "_meta": { "dry_run": true } }),
+        );
+        let response = attach_trace_id(response, "abc");
+        let meta = &response.result.unwrap()["_meta"];
+        assert_eq!(meta["dry_run"], true);
+        assert_eq!(meta[TRACE_ID_META], "abc");
+
+        let error = JsonRpcOutgoing::error(
+            Some(json!(1)),
+            turbomcp_core::error::McpError::internal("x"),
+        );
+        assert!(attach_trace_id(error, "abc").result.is_none());
+    }
+
+    #[test]
+    fn test_attach_trace_id_leaves_empty_result() {
+        let pong = JsonRpcOutgoing::success(Some(json!(1)), json!({}));
+        assert_eq!(attach_trace_id(pong, "abc").result, Some(json!({})));
+    }
+}
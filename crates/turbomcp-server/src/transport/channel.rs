@@ -20,10 +20,12 @@ use std::sync::Arc;
 use dashmap::DashMap;
 use tokio::sync::{RwLock, mpsc, oneshot};
 use tokio_util::sync::CancellationToken;
+use tracing::Instrument;
 use turbomcp_core::error::{ErrorKind, McpError, McpResult};
 use turbomcp_core::handler::McpHandler;
 use turbomcp_types::{ClientCapabilities, ProtocolVersion};
 
+use crate::config::{DEFAULT_MAX_PENDING_SERVER_REQUESTS, ServerConfig};
 use crate::context::{Cancellable, McpSession, RequestContext, SessionFuture};
 use crate::router;
 use crate::transport::line::jsonrpc_id_key;
@@ -37,9 +39,6 @@ use turbomcp_transport::{
 /// Default channel buffer size.
 const DEFAULT_CHANNEL_BUFFER: usize = 256;
 
-/// Maximum number of in-flight server-to-client requests.
-const MAX_PENDING_REQUESTS: usize = 64;
-
 // ── ChannelTransport (client-side Transport impl) ───────────────────────
 
 /// An in-process transport that communicates via `mpsc` channels.
@@ -159,6 +158,8 @@ enum SessionCommand {
         method: String,
         params: serde_json::Value,
     },
+    /// A `call` stopped waiting before its response arrived.
+    Abandoned,
 }
 
 impl McpSession for ChannelSessionHandle {
@@ -171,21 +172,29 @@ impl McpSession for ChannelSessionHandle {
         method: &'a str,
         params: serde_json::Value,
     ) -> SessionFuture<'a, serde_json::Value> {
-        Box::pin(async move {
-            let (response_tx, response_rx) = oneshot::channel();
-            self.request_tx
-                .send(SessionCommand::Request {
-                    method: method.to_string(),
-                    params,
-                    response_tx,
-                })
-                .await
-                .map_err(|_| McpError::internal("Session closed"))?;
-
-            response_rx
-                .await
-                .map_err(|_| McpError::internal("Response channel closed"))?
-        })
+        Box::pin(
+            async move {
+                tracing::debug!("Sending server-initiated request");
+                let (response_tx, response_rx) = oneshot::channel();
+                self.request_tx
+                    .send(SessionCommand::Request {
+                        method: method.to_string(),
+                        params,
+                        response_tx,
+                    })
+                    .await
+                    .map_err(|_| McpError::internal("Session closed"))?;
+
+                let guard =
+                    super::AbandonedCallGuard::new(&self.request_tx, SessionCommand::Abandoned);
+                let response = response_rx
+                    .await
+                    .map_err(|_| McpError::internal("Response channel closed"))?;
+                guard.disarm();
+                response
+            }
+            .instrument(super::server_request_span(method)),
+        )
     }
 
     fn notify<'a>(&'a self, method: &'a str, params: serde_json::Value) -> SessionFuture<'a, ()> {
@@ -233,6 +242,23 @@ pub async fn run_in_process<H: McpHandler + 'static>(
 pub async fn run_in_process_with_buffer<H: McpHandler + 'static>(
     handler: &H,
     buffer_size: usize,
+) -> McpResult<(ChannelTransport, tokio::task::JoinHandle<McpResult<()>>)> {
+    spawn_server(handler, buffer_size, None).await
+}
+
+/// Like `run_in_process` but applying `config` to the session, such as its
+/// limit on pending server-to-client requests.
+pub async fn run_in_process_with_config<H: McpHandler + 'static>(
+    handler: &H,
+    config: &ServerConfig,
+) -> McpResult<(ChannelTransport, tokio::task::JoinHandle<McpResult<()>>)> {
+    spawn_server(handler, DEFAULT_CHANNEL_BUFFER, Some(config.clone())).await
+}
+
+async fn spawn_server<H: McpHandler + 'static>(
+    handler: &H,
+    buffer_size: usize,
+    config: Option<ServerConfig>,
 ) -> McpResult<(ChannelTransport, tokio::task::JoinHandle<McpResult<()>>)> {
     handler.on_initialize().await?;
 
@@ -245,7 +271,7 @@ pub async fn run_in_process_with_buffer<H: McpHandler + 'static>(
 
     let handler = handler.clone();
     let server_handle =
-        tokio::spawn(async move { run_server_loop(handler, server_rx, server_tx).await });
+        tokio::spawn(async move { run_server_loop(handler, config, server_rx, server_tx).await });
 
     Ok((client_transport, server_handle))
 }
@@ -261,6 +287,7 @@ pub async fn run_in_process_with_buffer<H: McpHandler + 'static>(
 /// - JSON is parsed once from `Bytes` payload (not from a `String`)
 async fn run_server_loop<H: McpHandler>(
     handler: H,
+    config: Option<ServerConfig>,
     mut incoming: mpsc::Receiver<TransportMessage>,
     outgoing: mpsc::Sender<TransportMessage>,
 ) -> McpResult<()> {
@@ -281,6 +308,19 @@ async fn run_server_loop<H: McpHandler>(
     // Server-to-client pending request tracking
     let mut pending_requests =
         HashMap::<serde_json::Value, oneshot::Sender<McpResult<serde_json::Value>>>::new();
+    let max_pending_server_requests = config
+        .as_ref()
+        .map_or(DEFAULT_MAX_PENDING_SERVER_REQUESTS, |config| {
+            config.max_pending_server_requests
+        });
+    let client_request_timeout = config
+        .as_ref()
+        .and_then(|config| config.client_request_timeout);
+    let new_ctx = || {
+        let mut ctx = RequestContext::channel();
+        ctx.client_request_timeout = client_request_timeout;
+        ctx
+    };
     let mut next_request_id = 1u64;
     let mut session_state = SessionState::Uninitialized;
 
@@ -330,7 +370,16 @@ async fn run_server_loop<H: McpHandler>(
                     // Parse as JSON-RPC request directly from the Value
                     // (avoids re-serializing to string then re-parsing like LineTransportRunner does)
                     match serde_json::from_value::<turbomcp_core::jsonrpc::JsonRpcIncoming>(value) {
-                        Ok(request) => {
+                        Ok(mut request) => {
+                            if let Some(config) = config.as_ref()
+                                && let Err(error) =
+                                    config.meta_allowlist.apply(request.params.as_mut())
+                            {
+                                if !request.is_notification() {
+                                    send_error_msg(&outgoing, request.id.clone(), error).await?;
+                                }
+                                continue;
+                            }
                             if request.method == "initialize" {
                                 let client_capabilities =
                                     super::client_capabilities_from_initialize_params(
@@ -348,12 +397,12 @@ async fn run_server_loop<H: McpHandler>(
                                 }
 
                                 let initialize_request_id = request.id.clone();
-                                let ctx = RequestContext::channel();
+                                let ctx = new_ctx();
                                 let response = router::route_request_with_config(
                                     &handler,
                                     request,
                                     &ctx,
-                                    None,
+                                    config.as_ref(),
                                 )
                                 .await;
 
@@ -401,7 +450,7 @@ async fn run_server_loop<H: McpHandler>(
                                 let h = handler.clone();
                                 let session = session_handle.clone();
                                 let resp_tx = response_tx.clone();
-                                let ctx = RequestContext::channel().with_session(session);
+                                let ctx = new_ctx().with_session(session);
 
                                 tokio::spawn(async move {
                                     let response = router::route_request(&h, request, &ctx).await;
@@ -412,7 +461,7 @@ async fn run_server_loop<H: McpHandler>(
                             {
                                 // Lifecycle permits ping before initialize has completed.
                                 let ctx =
-                                    RequestContext::channel().with_session(session_handle.clone());
+                                    new_ctx().with_session(session_handle.clone());
                                 let response = router::route_request(&handler, request, &ctx).await;
                                 if response.should_send() {
                                     send_response_msg(&outgoing, &response).await?;
@@ -464,7 +513,7 @@ async fn run_server_loop<H: McpHandler>(
                                 if let Some(ref key) = cancel_key {
                                     pending_handlers.insert(key.clone(), token.clone());
                                 }
-                                let ctx = RequestContext::channel()
+                                let ctx = new_ctx()
                                     .with_session(session)
                                     .with_cancellation_token(
                                         Arc::new(token) as Arc<dyn Cancellable>,
@@ -473,6 +522,7 @@ async fn run_server_loop<H: McpHandler>(
                                     Arc::clone(&pending_handlers),
                                     cancel_key,
                                 );
+                                let span = super::request_span(&request);
 
                                 tokio::spawn(async move {
                                     // RAII cleanup runs on every exit path,
@@ -483,7 +533,7 @@ async fn run_server_loop<H: McpHandler>(
                                     )
                                     .await;
                                     let _ = resp_tx.send(response).await;
-                                });
+                                }.instrument(span));
                             }
                         }
                         Err(e) => {
@@ -504,10 +554,14 @@ async fn run_server_loop<H: McpHandler>(
             Some(cmd) = cmd_rx.recv() => {
                 match cmd {
                     SessionCommand::Request { method, params, response_tx } => {
-                        if pending_requests.len() >= MAX_PENDING_REQUESTS {
-                            let _ = response_tx.send(Err(McpError::internal(
-                                "Too many pending server-to-client requests"
-                            )));
+                        cancel_abandoned(&outgoing, &mut pending_requests).await?;
+
+                        if pending_requests.len() >= max_pending_server_requests {
+                            let _ = response_tx.send(Err(
+                                super::too_many_pending_server_requests(
+                                    max_pending_server_requests,
+                                ),
+                            ));
                             continue;
                         }
 
@@ -549,6 +603,9 @@ async fn run_server_loop<H: McpHandler>(
                         .await
                         .map_err(|_| McpError::internal("Channel closed"))?;
                     }
+                    SessionCommand::Abandoned => {
+                        cancel_abandoned(&outgoing, &mut pending_requests).await?;
+                    }
                 }
             }
         }
@@ -586,6 +643,29 @@ async fn send_response_msg(
     Ok(())
 }
 
+/// Drop server-to-client requests whose caller gave up (for example because
+/// the originating request was cancelled) and tell the client.
+async fn cancel_abandoned(
+    tx: &mpsc::Sender<TransportMessage>,
+    pending_requests: &mut HashMap<
+        serde_json::Value,
+        oneshot::Sender<McpResult<serde_json::Value>>,
+    >,
+) -> McpResult<()> {
+    for id in super::take_abandoned_requests(pending_requests) {
+        tracing::debug!(id = %id, "Cancelling abandoned server-to-client request");
+        let payload = serde_json::to_vec(&super::cancelled_notification(&id))
+            .map_err(|e| McpError::internal(e.to_string()))?;
+        tx.send(TransportMessage::new(
+            turbomcp_protocol::MessageId::from("notification"),
+            payload.into(),
+        ))
+        .await
+        .map_err(|_| McpError::internal("Channel closed"))?;
+    }
+    Ok(())
+}
+
 /// Serialize and send a JSON-RPC error over the channel.
 ///
 /// Per JSON-RPC 2.0 §5.1, error responses to messages whose id could not be
@@ -812,4 +892,218 @@ mod tests {
         drop(transport);
         let _ = server_handle.await;
     }
+
+    #[tokio::test]
+    async fn test_channel_transport_applies_meta_allowlist() {
+        let config = ServerConfig::builder()
+            .meta_allowlist(
+                crate::config::MetaAllowlist::only(["progressToken"])
+                    .on_disallowed(crate::config::DisallowedMeta::Reject),
+            )
+            .build();
+        let (transport, server_handle) = run_in_process_with_config(&TestHandler, &config)
+            .await
+            .unwrap();
+
+        send_json(
+            &transport,
+            serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": "initialize",
+                "params": {
+                    "protocolVersion": "2025-11-25",
+                    "clientInfo": { "name": "test", "version": "1.0.0" },
+                    "capabilities": {},
+                    "_meta": { "vendor/debug": true }
+                }
+            }),
+        )
+        .await;
+        let rejected = receive_json(&transport).await;
+        assert_eq!(rejected["id"], 1);
+        assert_eq!(rejected["error"]["code"], -32602, "{rejected}");
+
+        drop(transport);
+        let _ = server_handle.await;
+    }
+
+    /// Handler whose tool asks the client's model for a sample.
+    #[derive(Clone)]
+    struct SamplingHandler;
+
+    impl McpHandler for SamplingHandler {
+        fn server_info(&self) -> ServerInfo {
+            ServerInfo::new("channel-test", "1.0.0")
+        }
+
+        fn list_tools(&self) -> Vec<Tool> {
+            vec![Tool::new("summarize", "Summarize via the client's model")]
+        }
+
+        fn list_resources(&self) -> Vec<Resource> {
+            vec![]
+        }
+
+        fn list_prompts(&self) -> Vec<Prompt> {
+            vec![]
+        }
+
+        async fn call_tool(
+            &self,
+            _name: &str,
+            _args: Value,
+            ctx: &CoreRequestContext,
+        ) -> McpResult<ToolResult> {
+            let request = turbomcp_types::CreateMessageRequest::builder()
+                .user("Summarize")
+                .max_tokens(16)
+                .build();
+            ctx.sample(request).await?;
+            Ok(ToolResult::text("summarized"))
+        }
+
+        async fn read_resource(
+            &self,
+            uri: &str,
+            _ctx: &CoreRequestContext,
+        ) -> McpResult<ResourceResult> {
+            Err(McpError::resource_not_found(uri))
+        }
+
+        async fn get_prompt(
+            &self,
+            name: &str,
+            _args: Option<Value>,
+            _ctx: &CoreRequestContext,
+        ) -> McpResult<PromptResult> {
+            Err(McpError::prompt_not_found(name))
+        }
+    }
+
+    async fn send_json(transport: &ChannelTransport, value: Value) {
+        let payload = serde_json::to_vec(&value).unwrap();
+        transport
+            .send(TransportMessage::new(
+                turbomcp_protocol::MessageId::from("test"),
+                payload.into(),
+            ))
+            .await
+            .unwrap();
+    }
+
+    async fn receive_json(transport: &ChannelTransport) -> Value {
+        let message = tokio::time::timeout(std::time::Duration::from_secs(5), transport.receive())
+            .await
+            .expect("server should answer")
+            .unwrap()
+            .unwrap();
+        serde_json::from_slice(&message.payload).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_channel_transport_honours_max_pending_server_requests() {
+        let config = ServerConfig::builder()
+            .max_pending_server_requests(1)
+            .build();
+        let (transport, server_handle) = run_in_process_with_config(&SamplingHandler, &config)
+            .await
+            .unwrap();
+
+        send_json(
+            &transport,
+            serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": "initialize",
+                "params": {
+                    "protocolVersion": "2025-11-25",
+                    "clientInfo": { "name": "test", "version": "1.0.0" },
+                    "capabilities": { "sampling": {} }
+                }
+            }),
+        )
+        .await;
+        receive_json(&transport).await;
+        send_json(
+            &transport,
+            serde_json::json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }),
+        )
+        .await;
+
+        let call = |id| {
+            serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "method": "tools/call",
+                "params": { "name": "summarize", "arguments": {} }
+            })
+        };
+        send_json(&transport, call(7)).await;
+        let sampling = receive_json(&transport).await;
+        assert_eq!(sampling["method"], "sampling/createMessage");
+
+        // The only slot is taken, so the second sampling request is refused.
+        send_json(&transport, call(8)).await;
+        let refused = receive_json(&transport).await;
+        assert_eq!(refused["id"], 8);
+        assert_eq!(refused["error"]["code"], -32010, "{refused}");
+
+        server_handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_channel_transport_times_out_unanswered_sampling() {
+        let config = ServerConfig::builder()
+            .client_request_timeout(Some(std::time::Duration::from_millis(100)))
+            .build();
+        let (transport, server_handle) = run_in_process_with_config(&SamplingHandler, &config)
+            .await
+            .unwrap();
+
+        send_json(
+            &transport,
+            serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": "initialize",
+                "params": {
+                    "protocolVersion": "2025-11-25",
+                    "clientInfo": { "name": "test", "version": "1.0.0" },
+                    "capabilities": { "sampling": {} }
+                }
+            }),
+        )
+        .await;
+        receive_json(&transport).await;
+        send_json(
+            &transport,
+            serde_json::json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }),
+        )
+        .await;
+        send_json(
+            &transport,
+            serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 7,
+                "method": "tools/call",
+                "params": { "name": "summarize", "arguments": {} }
+            }),
+        )
+        .await;
+        let sampling = receive_json(&transport).await;
+        assert_eq!(sampling["method"], "sampling/createMessage");
+
+        // Never answered: the call fails instead of waiting forever.
+        let response = loop {
+            let message = receive_json(&transport).await;
+            if message["id"] == 7 {
+                break message;
+            }
+        };
+        let error = response["error"]["message"].as_str().unwrap_or_default();
+        assert!(error.contains("timed out"), "{response}");
+
+        server_handle.abort();
+    }
 }
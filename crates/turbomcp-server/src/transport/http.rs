@@ -33,7 +33,9 @@ use axum::response::{IntoResponse, Response};
 use axum::routing::{get, post};
 use bytes::Bytes;
 use tokio::sync::{Mutex, RwLock, mpsc, oneshot};
+use tower_http::compression::CompressionLayer;
 use tower_http::limit::RequestBodyLimitLayer;
+use tracing::Instrument;
 use turbomcp_core::error::{McpError, McpResult};
 use turbomcp_core::handler::McpHandler;
 use turbomcp_core::jsonrpc::{JsonRpcResponse as CoreJsonRpcResponse, JsonRpcResponsePayload};
@@ -43,9 +45,15 @@ use turbomcp_transport::security::{
 use turbomcp_types::{ClientCapabilities, ProtocolVersion};
 use uuid::Uuid;
 
-use crate::config::{RateLimiter, ServerConfig};
+use crate::broadcast::{BroadcastRegistration, NotificationBroadcaster};
+use crate::config::{
+    ConnectionCounter, DEFAULT_MAX_CONNECTIONS, DEFAULT_MAX_PENDING_SERVER_REQUESTS,
+    DEFAULT_SLOW_CLIENT_TIMEOUT, DEFAULT_WRITE_HIGH_WATER_MARK, DuplicateRequestIds, RateLimiter,
+    ServerConfig,
+};
 use crate::context::{McpSession, RequestContext, SessionFuture};
-use crate::router::{self, JsonRpcIncoming, JsonRpcOutgoing};
+use crate::initialize::InitializeClient;
+use crate::router::{self, JsonRpcOutgoing};
 
 /// Maximum HTTP request body size for MCP requests.
 ///
@@ -58,9 +66,6 @@ const MAX_BODY_SIZE: usize = 10 * 1024 * 1024;
 /// SSE keep-alive interval.
 const SSE_KEEP_ALIVE_SECS: u64 = 30;
 
-/// Maximum in-flight server-to-client requests per HTTP session.
-const MAX_PENDING_SERVER_REQUESTS: usize = 64;
-
 /// Timeout for server-to-client request responses over Streamable HTTP.
 const SERVER_REQUEST_TIMEOUT_SECS: u64 = 60;
 
@@ -74,20 +79,27 @@ type PendingServerRequests = Arc<Mutex<HashMap<String, PendingServerResponse>>>;
 /// is, it MUST NOT broadcast the same message across multiple streams."
 /// We therefore track subscribers as a list of mpsc senders and route each
 /// outbound message to exactly one of them, dropping dead senders as we go.
+/// Each sender is bounded, so a stream whose client stops reading pushes
+/// back on the code sending to it instead of buffering without limit.
 #[derive(Debug)]
 struct SessionData {
     /// Ordered list of active SSE subscribers (newest last).
-    subscribers: Vec<mpsc::UnboundedSender<String>>,
+    subscribers: Vec<mpsc::Sender<String>>,
     /// Negotiated protocol version (set after successful initialize).
     protocol_version: Option<ProtocolVersion>,
     /// Client capabilities captured from the successful initialize request.
     client_capabilities: Option<ClientCapabilities>,
     /// Request IDs already used by the client within this session.
     seen_request_ids: HashSet<String>,
+    /// Serializes requests that reuse an in-flight ID under
+    /// [`DuplicateRequestIds::Queue`](crate::DuplicateRequestIds::Queue).
+    request_queue: super::RequestIdQueue,
     /// Pending responses for server-initiated requests sent over SSE.
     pending_server_requests: PendingServerRequests,
     /// Monotonic server request counter. IDs are rendered as `s-{n}`.
     next_server_request_id: u64,
+    /// Keeps the session registered for notification broadcasts.
+    broadcast_registration: Option<BroadcastRegistration>,
 }
 
 /// Session manager for SSE connections.
@@ -95,6 +107,15 @@ struct SessionData {
 pub struct SessionManager {
     /// Map of session ID to per-session data.
     sessions: Arc<RwLock<HashMap<String, SessionData>>>,
+    /// Maximum in-flight server-to-client requests per session.
+    max_pending_server_requests: usize,
+    /// Broadcaster that initialized sessions are registered with.
+    broadcaster: Option<NotificationBroadcaster>,
+    /// Messages buffered per SSE stream before senders wait.
+    write_high_water_mark: usize,
+    /// How long a send may wait on a full SSE stream before the stream is
+    /// dropped.
+    slow_client_timeout: Option<Duration>,
 }
 
 impl Default for SessionManager {
@@ -106,11 +127,41 @@ impl Default for SessionManager {
 impl SessionManager {
     /// Create a new session manager.
     pub fn new() -> Self {
+        Self::with_max_pending_server_requests(DEFAULT_MAX_PENDING_SERVER_REQUESTS)
+    }
+
+    /// Create a session manager that allows at most `max` server-to-client
+    /// requests awaiting a response per session.
+    pub fn with_max_pending_server_requests(max: usize) -> Self {
         Self {
             sessions: Arc::new(RwLock::new(HashMap::new())),
+            max_pending_server_requests: max.max(1),
+            broadcaster: None,
+            write_high_water_mark: DEFAULT_WRITE_HIGH_WATER_MARK,
+            slow_client_timeout: Some(DEFAULT_SLOW_CLIENT_TIMEOUT),
         }
     }
 
+    /// Register initialized sessions with `broadcaster` until they end.
+    #[must_use]
+    pub fn with_notification_broadcaster(mut self, broadcaster: NotificationBroadcaster) -> Self {
+        self.broadcaster = Some(broadcaster);
+        self
+    }
+
+    /// Buffer at most `high_water_mark` messages per SSE stream, and
+    /// disconnect a stream whose buffer stays full for `slow_client_timeout`.
+    #[must_use]
+    pub fn with_write_backpressure(
+        mut self,
+        high_water_mark: usize,
+        slow_client_timeout: Option<Duration>,
+    ) -> Self {
+        self.write_high_water_mark = high_water_mark.max(1);
+        self.slow_client_timeout = slow_client_timeout;
+        self
+    }
+
     /// Create a new session and return the session ID.
     pub async fn create_session(
         &self,
@@ -129,8 +180,10 @@ impl SessionManager {
                 protocol_version: None,
                 client_capabilities: None,
                 seen_request_ids,
+                request_queue: super::RequestIdQueue::default(),
                 pending_server_requests: Arc::new(Mutex::new(HashMap::new())),
                 next_server_request_id: 1,
+                broadcast_registration: None,
             },
         );
 
@@ -149,15 +202,12 @@ impl SessionManager {
 
     /// Subscribe to an existing session's SSE stream.
     ///
-    /// Each subscribe returns a dedicated [`mpsc::UnboundedReceiver`] that
-    /// only receives messages routed to this subscriber — never broadcasts.
-    pub async fn subscribe_session(
-        &self,
-        session_id: &str,
-    ) -> Option<mpsc::UnboundedReceiver<String>> {
+    /// Each subscribe returns a dedicated [`mpsc::Receiver`] that only
+    /// receives messages routed to this subscriber — never broadcasts.
+    pub async fn subscribe_session(&self, session_id: &str) -> Option<mpsc::Receiver<String>> {
         let mut sessions = self.sessions.write().await;
         let data = sessions.get_mut(session_id)?;
-        let (tx, rx) = mpsc::unbounded_channel();
+        let (tx, rx) = mpsc::channel(self.write_high_water_mark);
         data.subscribers.push(tx);
         Some(rx)
     }
@@ -173,27 +223,53 @@ impl SessionManager {
     /// exactly one of the session's currently connected streams (the most
     /// recently subscribed live one), dropping any closed senders along the
     /// way. Returns `true` if the message was delivered.
+    ///
+    /// Waits while the chosen stream's buffer is full. A stream that stays
+    /// full for the slow-client timeout is dropped, which ends its SSE
+    /// response, and the message moves on to the next live stream.
     pub(crate) async fn send_to_session(&self, session_id: &str, message: &str) -> bool {
-        let mut sessions = self.sessions.write().await;
-        let Some(data) = sessions.get_mut(session_id) else {
-            return false;
-        };
-        // Drain dead senders from the newest end forward until we find a
-        // live one that accepts the message. This gives new SSE connections
-        // priority over stale ones without closing streams that are idle.
-        while let Some(tx) = data.subscribers.last() {
-            if tx.is_closed() {
-                data.subscribers.pop();
-                continue;
-            }
-            if tx.send(message.to_string()).is_ok() {
+        loop {
+            // Pick the target under the lock, but wait for buffer space
+            // outside it so a slow stream does not stall every session.
+            let tx = {
+                let mut sessions = self.sessions.write().await;
+                let Some(data) = sessions.get_mut(session_id) else {
+                    return false;
+                };
+                // Drain dead senders from the newest end forward. This gives
+                // new SSE connections priority over stale ones without
+                // closing streams that are idle.
+                while data.subscribers.last().is_some_and(mpsc::Sender::is_closed) {
+                    data.subscribers.pop();
+                }
+                match data.subscribers.last() {
+                    Some(tx) => tx.clone(),
+                    None => return false,
+                }
+            };
+
+            let sent = match self.slow_client_timeout {
+                Some(limit) => match tx.send_timeout(message.to_string(), limit).await {
+                    Ok(()) => true,
+                    Err(mpsc::error::SendTimeoutError::Timeout(_)) => {
+                        tracing::warn!(
+                            session_id,
+                            timeout_ms = limit.as_millis() as u64,
+                            "SSE client stopped reading; dropping stream"
+                        );
+                        false
+                    }
+                    Err(mpsc::error::SendTimeoutError::Closed(_)) => false,
+                },
+                None => tx.send(message.to_string()).await.is_ok(),
+            };
+            if sent {
                 return true;
             }
-            // Send only fails here if the receiver was dropped between the
-            // is_closed check and send; pop and retry.
-            data.subscribers.pop();
+            if let Some(data) = self.sessions.write().await.get_mut(session_id) {
+                data.subscribers.retain(|other| !other.same_channel(&tx));
+            }
         }
-        false
     }
 
     /// Broadcast a message to one subscriber per session.
@@ -202,21 +278,9 @@ impl SessionManager {
     /// following the same per-session rule as [`Self::send_to_session`].
     #[allow(dead_code)] // Reserved for server-initiated push (not yet wired)
     pub(crate) async fn broadcast(&self, message: &str) {
-        let mut sessions = self.sessions.write().await;
-        for (session_id, data) in sessions.iter_mut() {
-            let mut delivered = false;
-            while let Some(tx) = data.subscribers.last() {
-                if tx.is_closed() {
-                    data.subscribers.pop();
-                    continue;
-                }
-                if tx.send(message.to_string()).is_ok() {
-                    delivered = true;
-                    break;
-                }
-                data.subscribers.pop();
-            }
-            if !delivered {
+        let session_ids: Vec<String> = self.sessions.read().await.keys().cloned().collect();
+        for session_id in session_ids {
+            if !self.send_to_session(&session_id, message).await {
                 tracing::warn!("No live subscriber for session {}", session_id);
             }
         }
@@ -229,15 +293,14 @@ impl SessionManager {
     }
 
     /// Store the initialized protocol version and client capabilities.
-    pub(crate) async fn set_initialized(
-        &self,
-        session_id: &str,
-        version: ProtocolVersion,
-        client_capabilities: ClientCapabilities,
-    ) {
+    pub(crate) async fn set_initialized(&self, session_id: &str, client: InitializeClient) {
         if let Some(data) = self.sessions.write().await.get_mut(session_id) {
-            data.protocol_version = Some(version);
-            data.client_capabilities = Some(client_capabilities);
+            data.broadcast_registration = self.broadcaster.as_ref().map(|broadcaster| {
+                let session = Arc::new(HttpSessionHandle::new(session_id, self.clone()));
+                broadcaster.register(session, client.clone())
+            });
+            data.protocol_version = Some(client.protocol_version);
+            data.client_capabilities = Some(client.capabilities);
         }
     }
 
@@ -279,6 +342,21 @@ impl SessionManager {
             .is_some_and(|data| data.seen_request_ids.insert(request_id))
     }
 
+    /// Take a place in line behind in-flight requests that share this
+    /// request's ID.
+    pub(crate) async fn queue_request_id(
+        &self,
+        session_id: &str,
+        request_id: Option<&serde_json::Value>,
+    ) -> Option<super::QueuedRequest> {
+        let request_id = request_id.and_then(super::request_id_key)?;
+        self.sessions
+            .read()
+            .await
+            .get(session_id)
+            .map(|data| data.request_queue.enqueue(request_id))
+    }
+
     /// Register a pending server-to-client request and return its JSON-RPC id.
     async fn register_pending_server_request(
         &self,
@@ -297,8 +375,10 @@ impl SessionManager {
         };
 
         let mut pending = pending.lock().await;
-        if pending.len() >= MAX_PENDING_SERVER_REQUESTS {
-            return Err(McpError::server_overloaded());
+        if pending.len() >= self.max_pending_server_requests {
+            return Err(super::too_many_pending_server_requests(
+                self.max_pending_server_requests,
+            ));
         }
 
         pending.insert(request_id.clone(), response_tx);
@@ -376,6 +456,48 @@ impl HttpSessionHandle {
     }
 }
 
+/// Cleans up a server-to-client request if its `call` future is dropped
+/// (for example because the originating request was cancelled): the pending
+/// entry is removed and the client is sent `notifications/cancelled`.
+struct AbandonedServerRequest<'a> {
+    session_id: &'a str,
+    session_manager: &'a SessionManager,
+    request_id: Option<String>,
+}
+
+impl AbandonedServerRequest<'_> {
+    fn disarm(mut self) {
+        self.request_id = None;
+    }
+}
+
+impl Drop for AbandonedServerRequest<'_> {
+    fn drop(&mut self) {
+        let Some(request_id) = self.request_id.take() else {
+            return;
+        };
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+        let session_id = self.session_id.to_string();
+        let session_manager = self.session_manager.clone();
+        runtime.spawn(async move {
+            if !session_manager
+                .remove_pending_server_request(&session_id, &request_id)
+                .await
+            {
+                return;
+            }
+            tracing::debug!(id = %request_id, "Cancelling abandoned server-to-client request");
+            let notification =
+                super::cancelled_notification(&serde_json::Value::String(request_id));
+            session_manager
+                .send_to_session(&session_id, &notification.to_string())
+                .await;
+        });
+    }
+}
+
 impl McpSession for HttpSessionHandle {
     fn client_capabilities<'a>(&'a self) -> SessionFuture<'a, Option<ClientCapabilities>> {
         Box::pin(async move {
@@ -391,48 +513,60 @@ impl McpSession for HttpSessionHandle {
         method: &'a str,
         params: serde_json::Value,
     ) -> SessionFuture<'a, serde_json::Value> {
-        Box::pin(async move {
-            let (response_tx, response_rx) = oneshot::channel();
-            let request_id = self
-                .session_manager
-                .register_pending_server_request(&self.session_id, response_tx)
-                .await?;
-
-            let request = serde_json::json!({
-                "jsonrpc": "2.0",
-                "id": request_id,
-                "method": method,
-                "params": params,
-            });
-            let payload = serde_json::to_string(&request)
-                .map_err(|e| McpError::serialization(e.to_string()))?;
-
-            if !self
-                .session_manager
-                .send_to_session(&self.session_id, &payload)
-                .await
-            {
-                self.session_manager
-                    .remove_pending_server_request(&self.session_id, &request_id)
-                    .await;
-                return Err(McpError::unavailable(
-                    "No active SSE stream for HTTP session",
-                ));
-            }
-
-            match tokio::time::timeout(self.request_timeout, response_rx).await {
-                Ok(Ok(result)) => result,
-                Ok(Err(_)) => Err(McpError::transport("HTTP session response channel closed")),
-                Err(_) => {
+        Box::pin(
+            async move {
+                tracing::debug!("Sending server-initiated request");
+                let (response_tx, response_rx) = oneshot::channel();
+                let request_id = self
+                    .session_manager
+                    .register_pending_server_request(&self.session_id, response_tx)
+                    .await?;
+
+                let request = serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "id": request_id,
+                    "method": method,
+                    "params": params,
+                });
+                let payload = serde_json::to_string(&request)
+                    .map_err(|e| McpError::serialization(e.to_string()))?;
+
+                let guard = AbandonedServerRequest {
+                    session_id: &self.session_id,
+                    session_manager: &self.session_manager,
+                    request_id: Some(request_id.clone()),
+                };
+                if !self
+                    .session_manager
+                    .send_to_session(&self.session_id, &payload)
+                    .await
+                {
+                    guard.disarm();
                     self.session_manager
                         .remove_pending_server_request(&self.session_id, &request_id)
                         .await;
-                    Err(McpError::timeout(format!(
-                        "Timed out waiting for response to server request {request_id}"
-                    )))
+                    return Err(McpError::unavailable(
+                        "No active SSE stream for HTTP session",
+                    ));
+                }
+
+                let response = tokio::time::timeout(self.request_timeout, response_rx).await;
+                guard.disarm();
+                match response {
+                    Ok(Ok(result)) => result,
+                    Ok(Err(_)) => Err(McpError::transport("HTTP session response channel closed")),
+                    Err(_) => {
+                        self.session_manager
+                            .remove_pending_server_request(&self.session_id, &request_id)
+                            .await;
+                        Err(McpError::timeout(format!(
+                            "Timed out waiting for response to server request {request_id}"
+                        )))
+                    }
                 }
             }
-        })
+            .instrument(super::server_request_span(method)),
+        )
     }
 
     fn notify<'a>(&'a self, method: &'a str, params: serde_json::Value) -> SessionFuture<'a, ()> {
@@ -516,26 +650,7 @@ pub async fn run<H: McpHandler>(handler: &H, addr: &str) -> McpResult<()> {
 /// to `drain` to complete. Pre-3.1 the HTTP transport had no shutdown hook at all
 /// — SIGTERM aborted in-flight requests mid-response.
 async fn shutdown_signal(drain: Option<Duration>) {
-    let ctrl_c = async {
-        let _ = tokio::signal::ctrl_c().await;
-    };
-
-    #[cfg(unix)]
-    let terminate = async {
-        if let Ok(mut sig) =
-            tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
-        {
-            sig.recv().await;
-        }
-    };
-
-    #[cfg(not(unix))]
-    let terminate = std::future::pending::<()>();
-
-    tokio::select! {
-        _ = ctrl_c => {},
-        _ = terminate => {},
-    }
+    super::shutdown_signal().await;
 
     tracing::info!("Shutdown signal received, draining HTTP server");
     if let Some(drain) = drain {
@@ -569,6 +684,17 @@ pub async fn run_with_shutdown<H: McpHandler>(
     addr: &str,
     config: &ServerConfig,
     graceful_shutdown: Option<Duration>,
+) -> McpResult<()> {
+    run_until(handler, addr, config, shutdown_signal(graceful_shutdown)).await
+}
+
+/// Serve until `shutdown` resolves, then stop accepting connections and wait
+/// for in-flight requests before running the shutdown hook.
+pub(crate) async fn run_until<H: McpHandler>(
+    handler: &H,
+    addr: &str,
+    config: &ServerConfig,
+    shutdown: impl Future<Output = ()> + Send + 'static,
 ) -> McpResult<()> {
     // Call lifecycle hooks
     handler.on_initialize().await?;
@@ -609,7 +735,7 @@ pub async fn run_with_shutdown<H: McpHandler>(
         listener,
         app.into_make_service_with_connect_info::<SocketAddr>(),
     )
-    .with_graceful_shutdown(shutdown_signal(graceful_shutdown))
+    .with_graceful_shutdown(shutdown)
     .await
     .map_err(|e| McpError::internal(format!("Server error: {}", e)))?;
 
@@ -624,6 +750,8 @@ pub(crate) struct SseState<H: McpHandler> {
     handler: H,
     session_manager: SessionManager,
     rate_limiter: Option<Arc<RateLimiter>>,
+    /// Slots for in-flight POST requests (`ConnectionLimits::max_http_concurrent`).
+    in_flight: Arc<ConnectionCounter>,
     config: Option<ServerConfig>,
 }
 
@@ -635,14 +763,34 @@ pub(crate) fn build_router<H: McpHandler>(
     let max_body_size = config
         .as_ref()
         .map_or(MAX_BODY_SIZE, |config| config.max_message_size);
+    let http_compression = config.as_ref().is_none_or(|config| config.http_compression);
+    let max_concurrent = config.as_ref().map_or(DEFAULT_MAX_CONNECTIONS, |config| {
+        config.connection_limits.max_http_concurrent
+    });
+    let max_pending_server_requests = config
+        .as_ref()
+        .map_or(DEFAULT_MAX_PENDING_SERVER_REQUESTS, |config| {
+            config.max_pending_server_requests
+        });
+    let mut session_manager =
+        SessionManager::with_max_pending_server_requests(max_pending_server_requests);
+    if let Some(config) = config.as_ref() {
+        session_manager = session_manager
+            .with_notification_broadcaster(config.notification_broadcaster.clone())
+            .with_write_backpressure(
+                config.connection_limits.write_high_water_mark,
+                config.connection_limits.slow_client_timeout,
+            );
+    }
     let state = SseState {
         handler,
-        session_manager: SessionManager::new(),
+        session_manager,
         rate_limiter,
+        in_flight: Arc::new(ConnectionCounter::new(max_concurrent)),
         config,
     };
 
-    Router::new()
+    let router = Router::new()
         .route(
             "/",
             post(handle_json_rpc::<H>)
@@ -662,7 +810,16 @@ pub(crate) fn build_router<H: McpHandler>(
         // our handler (which takes Request<Body>) ever reads the stream.
         .layer(DefaultBodyLimit::max(max_body_size))
         .layer(RequestBodyLimitLayer::new(max_body_size))
-        .with_state(state)
+        .with_state(state);
+
+    // Content-Encoding is negotiated from Accept-Encoding. The default
+    // predicate leaves text/event-stream and small bodies uncompressed, so
+    // SSE frames still flush as soon as they are written.
+    if http_compression {
+        router.layer(CompressionLayer::new())
+    } else {
+        router
+    }
 }
 
 /// Route a request with per-session version tracking.
@@ -683,22 +840,37 @@ async fn route_with_version_tracking<H: McpHandler>(
     session_manager: &SessionManager,
     config: Option<&ServerConfig>,
     session_id: Option<&str>,
+    headers: &HeaderMap,
 ) -> router::JsonRpcOutgoing {
-    let ctx = http_request_context(session_manager, session_id, request.id.as_ref());
+    let max_message_size = config.map_or(MAX_BODY_SIZE, |config| config.max_message_size);
+    let mut ctx = http_request_context(
+        session_manager,
+        session_id,
+        request.id.as_ref(),
+        max_message_size,
+        headers,
+    )
+    .with_elicitation_fallback(
+        config.map_or_else(Default::default, |config| config.elicitation_fallback),
+    );
+    ctx.client_request_timeout = config.and_then(|config| config.client_request_timeout);
+    if let Some(config) = config {
+        config.context_extractors.apply(&mut ctx);
+    }
 
     if request.method == "initialize" {
-        let client_capabilities =
-            super::client_capabilities_from_initialize_params(request.params.as_ref());
+        let initialize_params = request.params.clone();
         let response = router::route_request_with_config(handler, request, &ctx, config).await;
 
         // If successful and we have a session, extract and store the negotiated version.
         if let (Some(sid), Some(result)) = (session_id, response.result.as_ref())
             && let Some(version_str) = result.get("protocolVersion").and_then(|v| v.as_str())
         {
-            let version = ProtocolVersion::from(version_str);
-            session_manager
-                .set_initialized(sid, version, client_capabilities)
-                .await;
+            let client = super::initialize_client_from_params(
+                initialize_params.as_ref(),
+                ProtocolVersion::from(version_str),
+            );
+            session_manager.set_initialized(sid, client).await;
             tracing::debug!(
                 session_id = sid,
                 protocol_version = version_str,
@@ -710,22 +882,39 @@ async fn route_with_version_tracking<H: McpHandler>(
     }
 
     // For post-initialize requests: use versioned routing if session has a stored version.
+    let span = super::request_span(&request);
     if let Some(sid) = session_id
         && let Some(version) = session_manager.get_protocol_version(sid).await
     {
-        return router::route_request_versioned(handler, request, &ctx, &version).await;
+        return router::route_request_versioned(handler, request, &ctx, &version)
+            .instrument(span)
+            .await;
     }
 
     // Pre-initialize or sessionless: route with config for proper validation.
-    router::route_request_with_config(handler, request, &ctx, config).await
+    router::route_request_with_config(handler, request, &ctx, config)
+        .instrument(span)
+        .await
 }
 
 fn http_request_context(
     session_manager: &SessionManager,
     session_id: Option<&str>,
     request_id: Option<&serde_json::Value>,
+    max_message_size: usize,
+    headers: &HeaderMap,
 ) -> RequestContext {
-    let mut ctx = RequestContext::http();
+    // Non-UTF-8 header values are skipped; extractors only see text headers.
+    let headers = headers
+        .iter()
+        .filter_map(|(name, value)| {
+            let value = value.to_str().ok()?;
+            Some((name.as_str().to_string(), value.to_string()))
+        })
+        .collect();
+    let mut ctx = RequestContext::http()
+        .with_max_message_size(max_message_size)
+        .with_headers(headers);
 
     if let Some(request_id) = request_id.and_then(super::request_id_key) {
         ctx = ctx.with_request_id(request_id);
@@ -1016,6 +1205,17 @@ async fn handle_json_rpc<H: McpHandler>(
         }
     }
 
+    // Held until the response is built, so the slot frees as soon as this
+    // request finishes.
+    let Some(_slot) = state.in_flight.try_acquire_arc() else {
+        tracing::warn!(
+            "HTTP request rejected: at capacity ({}/{})",
+            state.in_flight.current(),
+            state.in_flight.max()
+        );
+        return empty_response(StatusCode::SERVICE_UNAVAILABLE);
+    };
+
     // Reject oversized bodies with 413 Payload Too Large rather than 400 so
     // clients can tell "body is malformed" from "body too big to accept".
     // Prefer the Content-Length header as a fast, stream-free check, then
@@ -1052,15 +1252,29 @@ async fn handle_json_rpc<H: McpHandler>(
         return handle_client_json_rpc_response(&state, &headers, response).await;
     }
 
-    let request = match serde_json::from_value::<JsonRpcIncoming>(payload) {
+    let strictness = state
+        .config
+        .as_ref()
+        .map(|config| config.jsonrpc_strictness)
+        .unwrap_or_default();
+    let mut request = match router::parse_request_from_value_with_strictness(payload, strictness) {
         Ok(request) => request,
         Err(_) => return empty_response(StatusCode::BAD_REQUEST),
     };
+    if let Some(config) = state.config.as_ref()
+        && let Err(error) = config.meta_allowlist.apply(request.params.as_mut())
+    {
+        if request.is_notification() {
+            return empty_response(StatusCode::ACCEPTED);
+        }
+        return json_response(
+            StatusCode::OK,
+            JsonRpcOutgoing::error(request.id.clone(), error),
+        );
+    }
     let is_initialize = request.method == "initialize";
-    let client_capabilities = if is_initialize {
-        Some(super::client_capabilities_from_initialize_params(
-            request.params.as_ref(),
-        ))
+    let initialize_params = if is_initialize {
+        request.params.clone()
     } else {
         None
     };
@@ -1069,19 +1283,35 @@ async fn handle_json_rpc<H: McpHandler>(
         Err(status) => return empty_response(status),
     };
 
-    if let Some(session_id) = session_id.as_deref()
-        && !state
+    let duplicate_request_ids = state
+        .config
+        .as_ref()
+        .map(|config| config.duplicate_request_ids)
+        .unwrap_or_default();
+    let mut _request_turn = None;
+    if let Some(session_id) = session_id.as_deref() {
+        if duplicate_request_ids == DuplicateRequestIds::Queue {
+            // Held until this request has been answered.
+            if let Some(queued) = state
+                .session_manager
+                .queue_request_id(session_id, request.id.as_ref())
+                .await
+            {
+                _request_turn = Some(queued.wait().await);
+            }
+        } else if !state
             .session_manager
             .register_request_id(session_id, request.id.as_ref())
             .await
-    {
-        return json_response(
-            StatusCode::OK,
-            JsonRpcOutgoing::error(
-                request.id.clone(),
-                McpError::invalid_request("Request ID already used in this session"),
-            ),
-        );
+        {
+            return json_response(
+                StatusCode::OK,
+                JsonRpcOutgoing::error(
+                    request.id.clone(),
+                    McpError::invalid_request("Request ID already used in this session"),
+                ),
+            );
+        }
     }
 
     let initialize_request_id = request.id.clone();
@@ -1091,6 +1321,7 @@ async fn handle_json_rpc<H: McpHandler>(
         &state.session_manager,
         state.config.as_ref(),
         session_id.as_deref(),
+        &headers,
     )
     .await;
 
@@ -1110,8 +1341,10 @@ async fn handle_json_rpc<H: McpHandler>(
             .session_manager
             .set_initialized(
                 &session_id,
-                ProtocolVersion::from(version_str),
-                client_capabilities.unwrap_or_default(),
+                super::initialize_client_from_params(
+                    initialize_params.as_ref(),
+                    ProtocolVersion::from(version_str),
+                ),
             )
             .await;
 
@@ -1257,6 +1490,7 @@ async fn handle_delete_session<H: McpHandler>(
     }
 
     if state.session_manager.remove_session(&session_id).await {
+        state.handler.on_session_closed(&session_id);
         return empty_response(StatusCode::NO_CONTENT);
     }
 
@@ -1297,9 +1531,16 @@ mod tests {
             &self,
             name: &str,
             _args: Value,
-            _ctx: &CoreRequestContext,
+            ctx: &CoreRequestContext,
         ) -> McpResult<ToolResult> {
-            Err(McpError::tool_not_found(name))
+            match name {
+                "request_id" => Ok(ToolResult::text(
+                    ctx.get_metadata("request_id")
+                        .and_then(Value::as_str)
+                        .unwrap_or("missing"),
+                )),
+                _ => Err(McpError::tool_not_found(name)),
+            }
         }
 
         async fn read_resource(
@@ -1376,6 +1617,41 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn send_to_session_waits_on_full_stream_then_drops_it() {
+        let manager = SessionManager::new()
+            .with_write_backpressure(2, Some(std::time::Duration::from_millis(200)));
+        let session_id = manager.create_session(None).await;
+        let mut rx = manager
+            .subscribe_session(&session_id)
+            .await
+            .expect("subscribe");
+
+        assert!(manager.send_to_session(&session_id, "one").await);
+        assert!(manager.send_to_session(&session_id, "two").await);
+
+        // The buffer is full: the next send waits for the client instead of
+        // growing the buffer.
+        let blocked = tokio::time::timeout(
+            std::time::Duration::from_millis(50),
+            manager.send_to_session(&session_id, "three"),
+        )
+        .await;
+        assert!(blocked.is_err(), "send should wait on a full stream");
+
+        // Reading frees a slot for the waiting sender.
+        assert_eq!(rx.recv().await.as_deref(), Some("one"));
+        assert!(manager.send_to_session(&session_id, "three").await);
+
+        // A client that never reads again is disconnected after the timeout.
+        let started = std::time::Instant::now();
+        assert!(!manager.send_to_session(&session_id, "four").await);
+        assert!(started.elapsed() >= std::time::Duration::from_millis(200));
+        assert_eq!(rx.recv().await.as_deref(), Some("two"));
+        assert_eq!(rx.recv().await.as_deref(), Some("three"));
+        assert_eq!(rx.recv().await, None, "slow stream should be closed");
+    }
+
     #[tokio::test]
     async fn build_router_uses_configured_http_body_limit() {
         let config = ServerConfig::builder()
@@ -1395,5 +1671,128 @@ mod tests {
         assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
     }
 
+    #[tokio::test]
+    async fn build_router_enforces_max_http_concurrent() {
+        let limits = |max_http_concurrent| crate::config::ConnectionLimits {
+            max_http_concurrent,
+            ..Default::default()
+        };
+        let initialize = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "initialize",
+            "params": {
+                "protocolVersion": "2025-11-25",
+                "capabilities": {},
+                "clientInfo": {"name": "test", "version": "1.0"}
+            }
+        });
+
+        // Each finished request frees its slot for the next one.
+        let config = ServerConfig::builder()
+            .connection_limits(limits(1))
+            .allow_any_origin(true)
+            .build();
+        let app = build_router(TestHandler, None, Some(config));
+        post_json(&app, None, None, initialize.clone()).await;
+        post_json(&app, None, None, initialize.clone()).await;
+
+        let config = ServerConfig::builder()
+            .connection_limits(limits(0))
+            .allow_any_origin(true)
+            .build();
+        let app = build_router(TestHandler, None, Some(config));
+        let request = axum::http::Request::builder()
+            .method("POST")
+            .uri("/mcp")
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(Body::from(initialize.to_string()))
+            .expect("request");
+
+        let response = app.oneshot(request).await.expect("response");
+
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    async fn post_json(
+        app: &Router,
+        session_id: Option<&str>,
+        extra_header: Option<(&str, &str)>,
+        body: Value,
+    ) -> (Option<String>, Value) {
+        let mut request = axum::http::Request::builder()
+            .method("POST")
+            .uri("/mcp")
+            .header(axum::http::header::CONTENT_TYPE, "application/json");
+        if let Some(session_id) = session_id {
+            request = request.header("mcp-session-id", session_id);
+        }
+        if let Some((name, value)) = extra_header {
+            request = request.header(name, value);
+        }
+        let request = request.body(Body::from(body.to_string())).expect("request");
+
+        let response = app.clone().oneshot(request).await.expect("response");
+        assert_eq!(response.status(), StatusCode::OK);
+        let session_id = response
+            .headers()
+            .get("mcp-session-id")
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body");
+        (
+            session_id,
+            serde_json::from_slice(&body).expect("json body"),
+        )
+    }
+
+    #[tokio::test]
+    async fn context_extractor_copies_request_id_header_into_metadata() {
+        let config = ServerConfig::builder()
+            .allow_any_origin(true)
+            .context_extractor(|ctx: &mut RequestContext| {
+                if let Some(id) = ctx.header("x-request-id").map(str::to_owned) {
+                    ctx.insert_metadata("request_id", id);
+                }
+            })
+            .build();
+        let app = build_router(TestHandler, None, Some(config));
+
+        let (session_id, _) = post_json(
+            &app,
+            None,
+            None,
+            serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": "initialize",
+                "params": {
+                    "protocolVersion": "2025-11-25",
+                    "capabilities": {},
+                    "clientInfo": {"name": "test", "version": "1.0.0"}
+                }
+            }),
+        )
+        .await;
+        let session_id = session_id.expect("initialize assigns a session");
+
+        let (_, body) = post_json(
+            &app,
+            Some(&session_id),
+            Some(("X-Request-Id", "req-42")),
+            serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 2,
+                "method": "tools/call",
+                "params": {"name": "request_id", "arguments": {}}
+            }),
+        )
+        .await;
+
+        assert_eq!(body["result"]["content"][0]["text"], "req-42");
+    }
+
     // HTTP route-level tests live in /tests/ because they need a bound port.
 }
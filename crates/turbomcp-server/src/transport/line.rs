@@ -11,16 +11,21 @@
 
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use dashmap::DashMap;
 use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncWrite, AsyncWriteExt};
-use tokio::sync::{RwLock, mpsc, oneshot};
+use tokio::sync::{RwLock, mpsc, oneshot, watch};
 use tokio_util::sync::CancellationToken;
+use tracing::Instrument;
 use turbomcp_core::error::{ErrorKind, McpError, McpResult};
 use turbomcp_core::handler::McpHandler;
 use turbomcp_types::{ClientCapabilities, ProtocolVersion};
 
-use crate::config::ServerConfig;
+use crate::config::{
+    DEFAULT_MAX_PENDING_SERVER_REQUESTS, DEFAULT_SLOW_CLIENT_TIMEOUT,
+    DEFAULT_WRITE_HIGH_WATER_MARK, DuplicateRequestIds, ServerConfig,
+};
 use crate::context::{Cancellable, McpSession, RequestContext, SessionFuture};
 use crate::router;
 
@@ -35,10 +40,7 @@ pub(crate) fn jsonrpc_id_key(id: &serde_json::Value) -> String {
     }
 }
 
-use super::{MAX_MESSAGE_SIZE, SessionState};
-
-/// Maximum number of in-flight server-to-client requests before back-pressure.
-const MAX_PENDING_REQUESTS: usize = 64;
+use super::{ConnectionTimers, MAX_MESSAGE_SIZE, SessionState};
 
 /// Trait for types that can read lines.
 pub trait LineReader: AsyncBufRead + Unpin + Send {}
@@ -66,6 +68,8 @@ enum SessionCommand {
         method: String,
         params: serde_json::Value,
     },
+    /// A `call` stopped waiting before its response arrived.
+    Abandoned,
 }
 
 impl McpSession for SessionHandle {
@@ -78,21 +82,29 @@ impl McpSession for SessionHandle {
         method: &'a str,
         params: serde_json::Value,
     ) -> SessionFuture<'a, serde_json::Value> {
-        Box::pin(async move {
-            let (response_tx, response_rx) = oneshot::channel();
-            self.request_tx
-                .send(SessionCommand::Request {
-                    method: method.to_string(),
-                    params,
-                    response_tx,
-                })
-                .await
-                .map_err(|_| McpError::internal("Session closed"))?;
-
-            response_rx
-                .await
-                .map_err(|_| McpError::internal("Response channel closed"))?
-        })
+        Box::pin(
+            async move {
+                tracing::debug!("Sending server-initiated request");
+                let (response_tx, response_rx) = oneshot::channel();
+                self.request_tx
+                    .send(SessionCommand::Request {
+                        method: method.to_string(),
+                        params,
+                        response_tx,
+                    })
+                    .await
+                    .map_err(|_| McpError::internal("Session closed"))?;
+
+                let guard =
+                    super::AbandonedCallGuard::new(&self.request_tx, SessionCommand::Abandoned);
+                let response = response_rx
+                    .await
+                    .map_err(|_| McpError::internal("Response channel closed"))?;
+                guard.disarm();
+                response
+            }
+            .instrument(super::server_request_span(method)),
+        )
     }
 
     fn notify<'a>(&'a self, method: &'a str, params: serde_json::Value) -> SessionFuture<'a, ()> {
@@ -117,6 +129,9 @@ type HandlerResponse = router::JsonRpcOutgoing;
 pub struct LineTransportRunner<H: McpHandler> {
     handler: H,
     config: Option<ServerConfig>,
+    max_lifetime: Option<Duration>,
+    idle_timeout: Option<Duration>,
+    shutdown: Option<watch::Receiver<bool>>,
 }
 
 impl<H: McpHandler> LineTransportRunner<H> {
@@ -127,6 +142,9 @@ impl<H: McpHandler> LineTransportRunner<H> {
         Self {
             handler,
             config: None,
+            max_lifetime: None,
+            idle_timeout: None,
+            shutdown: None,
         }
     }
 
@@ -138,9 +156,39 @@ impl<H: McpHandler> LineTransportRunner<H> {
         Self {
             handler,
             config: Some(config),
+            max_lifetime: None,
+            idle_timeout: None,
+            shutdown: None,
         }
     }
 
+    /// Close the connection once it is older than `max_lifetime` or has
+    /// exchanged no message for `idle_timeout`. The idle timeout does not
+    /// run out while a request is being handled.
+    ///
+    /// In-flight handlers are cancelled and pending server-to-client
+    /// requests are abandoned when the connection is closed this way.
+    #[must_use]
+    pub fn with_connection_timeouts(
+        mut self,
+        max_lifetime: Option<Duration>,
+        idle_timeout: Option<Duration>,
+    ) -> Self {
+        self.max_lifetime = max_lifetime;
+        self.idle_timeout = idle_timeout;
+        self
+    }
+
+    /// Stop reading once `shutdown` receives `true`.
+    ///
+    /// The run then finishes like it does at end of input: responses to
+    /// requests already in flight are written before it returns.
+    #[must_use]
+    pub fn with_shutdown(mut self, shutdown: watch::Receiver<bool>) -> Self {
+        self.shutdown = Some(shutdown);
+        self
+    }
+
     /// Run the transport loop.
     ///
     /// Handler dispatch is spawned on separate tasks to prevent deadlocks
@@ -158,8 +206,34 @@ impl<H: McpHandler> LineTransportRunner<H> {
         W: LineWriter,
         F: Fn() -> RequestContext,
     {
-        // Channel for session commands (server-to-client requests/notifications)
-        let (cmd_tx, mut cmd_rx) = mpsc::channel::<SessionCommand>(32);
+        let elicitation_fallback = self
+            .config
+            .as_ref()
+            .map_or_else(Default::default, |config| config.elicitation_fallback);
+        let client_request_timeout = self
+            .config
+            .as_ref()
+            .and_then(|config| config.client_request_timeout);
+        let ctx_factory = || {
+            let mut ctx = ctx_factory()
+                .with_max_message_size(MAX_MESSAGE_SIZE)
+                .with_elicitation_fallback(elicitation_fallback);
+            ctx.client_request_timeout = client_request_timeout;
+            ctx
+        };
+
+        // Channel for session commands (server-to-client requests/notifications).
+        // Its capacity is the write high-water mark: once that many messages
+        // wait behind a slow client, `notify` and `call` await instead of
+        // queueing more.
+        let write_high_water_mark = self
+            .config
+            .as_ref()
+            .map_or(DEFAULT_WRITE_HIGH_WATER_MARK, |config| {
+                config.connection_limits.write_high_water_mark
+            })
+            .max(1);
+        let (cmd_tx, mut cmd_rx) = mpsc::channel::<SessionCommand>(write_high_water_mark);
         let session_handle = Arc::new(SessionHandle {
             request_tx: cmd_tx,
             client_capabilities: Arc::new(RwLock::new(None)),
@@ -173,8 +247,22 @@ impl<H: McpHandler> LineTransportRunner<H> {
         // cleared when the task finishes, and signalled when the client sends
         // `notifications/cancelled` per MCP 2025-11-25 §Cancellation.
         let pending_handlers: Arc<DashMap<String, CancellationToken>> = Arc::new(DashMap::new());
-
-        // Server-to-client pending request tracking
+        let duplicate_request_ids = self
+            .config
+            .as_ref()
+            .map(|config| config.duplicate_request_ids)
+            .unwrap_or_default();
+        // Serializes requests that reuse an in-flight ID, when configured.
+        let request_queue = (duplicate_request_ids == DuplicateRequestIds::Queue)
+            .then(super::RequestIdQueue::default);
+
+        // Server-to-client pending request tracking, bounded per connection
+        let max_pending_requests = self
+            .config
+            .as_ref()
+            .map_or(DEFAULT_MAX_PENDING_SERVER_REQUESTS, |config| {
+                config.max_pending_server_requests
+            });
         let mut pending_requests =
             HashMap::<serde_json::Value, oneshot::Sender<McpResult<serde_json::Value>>>::new();
         // Use string-prefixed IDs to avoid collision with client-originated integer IDs
@@ -183,17 +271,48 @@ impl<H: McpHandler> LineTransportRunner<H> {
         // MCP session lifecycle state. Enforces that `initialize` succeeds
         // before any other requests are processed, and prevents duplicate init.
         let mut session_state = SessionState::Uninitialized;
+        // Keeps this connection registered for notification broadcasts.
+        let mut _broadcast_registration = None;
 
         let mut line = String::new();
+        let mut timers = ConnectionTimers::new(self.max_lifetime, self.idle_timeout);
+        let shutdown = self.shutdown.clone().map(super::shutdown_requested);
+        let mut shutdown = std::pin::pin!(async move {
+            match shutdown {
+                Some(shutdown) => shutdown.await,
+                None => std::future::pending().await,
+            }
+        });
 
         loop {
             tokio::select! {
                 biased;
 
+                () = &mut shutdown => {
+                    tracing::info!("Shutdown requested; finishing in-flight requests");
+                    break;
+                }
+
+                // Connection recycling: max lifetime / idle timeout
+                reason = timers.expired() => {
+                    let busy = !pending_handlers.is_empty()
+                        || pending_requests.values().any(|tx| !tx.is_closed());
+                    if !timers.should_close(reason, busy) {
+                        continue;
+                    }
+                    tracing::info!(reason = reason.as_str(), "Closing connection");
+                    for handler in pending_handlers.iter() {
+                        handler.value().cancel();
+                    }
+                    pending_requests.clear();
+                    break;
+                }
+
                 // Incoming from client
                 res = reader.read_line(&mut line) => {
                     let bytes_read = res.map_err(|e| McpError::internal(format!("Failed to read line: {e}")))?;
                     if bytes_read == 0 { break; }
+                    timers.touch();
 
                     let trimmed = line.trim();
                     if trimmed.is_empty() {
@@ -242,8 +361,24 @@ impl<H: McpHandler> LineTransportRunner<H> {
                     } else {
                         // Reuse the already-parsed `Value` rather than re-parsing
                         // the raw line — saves one full JSON parse per message.
-                        match router::parse_request_from_value(value) {
-                            Ok(request) => {
+                        let strictness = self
+                            .config
+                            .as_ref()
+                            .map(|config| config.jsonrpc_strictness)
+                            .unwrap_or_default();
+                        match router::parse_request_from_value_with_strictness(value, strictness) {
+                            Ok(mut request) => {
+                                if let Some(config) = self.config.as_ref()
+                                    && let Err(error) =
+                                        config.meta_allowlist.apply(request.params.as_mut())
+                                {
+                                    if !request.is_notification() {
+                                        self.send_error(&mut writer, request.id.clone(), error)
+                                            .await?;
+                                    }
+                                    line.clear();
+                                    continue;
+                                }
                                 if request.method == "initialize" {
                                     let client_capabilities =
                                         super::client_capabilities_from_initialize_params(
@@ -275,6 +410,7 @@ impl<H: McpHandler> LineTransportRunner<H> {
                                     // here and cannot process the server-to-client
                                     // request, which would deadlock.
                                     let initialize_request_id = request.id.clone();
+                                    let initialize_params = request.params.clone();
                                     let ctx = ctx_factory();
                                     let response = router::route_request_with_config(
                                         &self.handler,
@@ -297,11 +433,22 @@ impl<H: McpHandler> LineTransportRunner<H> {
                                             version = %version,
                                             "Protocol version negotiated"
                                         );
+                                        if let Some(config) = self.config.as_ref() {
+                                            _broadcast_registration =
+                                                Some(config.notification_broadcaster.register(
+                                                    session_handle.clone(),
+                                                    super::initialize_client_from_params(
+                                                        initialize_params.as_ref(),
+                                                        version.clone(),
+                                                    ),
+                                                ));
+                                        }
                                         session_state = SessionState::Initialized(
                                             super::InitializedSessionState::new(
                                                 version,
                                                 initialize_request_id.as_ref(),
-                                            ),
+                                            )
+                                            .with_duplicate_request_ids(duplicate_request_ids),
                                         );
                                         *session_handle.client_capabilities.write().await =
                                             Some(client_capabilities);
@@ -411,31 +558,33 @@ impl<H: McpHandler> LineTransportRunner<H> {
                                     let resp_tx = response_tx.clone();
                                     let token = CancellationToken::new();
                                     let cancel_key = request.id.as_ref().map(jsonrpc_id_key);
-                                    if let Some(ref key) = cancel_key {
-                                        pending_handlers.insert(key.clone(), token.clone());
-                                    }
+                                    let admission = super::HandlerAdmission::new(
+                                        &pending_handlers,
+                                        request_queue.as_ref(),
+                                        cancel_key,
+                                        &token,
+                                    );
                                     let ctx = ctx_factory()
                                         .with_session(session)
                                         .with_cancellation_token(
                                             Arc::new(token) as Arc<dyn Cancellable>,
                                         );
-                                    let guard = super::PendingHandlerGuard::new(
-                                        Arc::clone(&pending_handlers),
-                                        cancel_key,
-                                    );
+                                    let span = super::request_span(&request);
 
                                     tokio::spawn(async move {
-                                        // RAII: the guard removes the registry
-                                        // entry on every exit path, including a
-                                        // panic in the handler.
-                                        let _guard = guard;
+                                        // Waits behind an in-flight request with
+                                        // the same ID when queueing. RAII: the
+                                        // turn removes the registry entry on
+                                        // every exit path, including a panic in
+                                        // the handler.
+                                        let _turn = admission.ready().await;
                                         let response = router::route_request_versioned(
                                             &handler, request, &ctx, &version,
                                         )
                                         .await;
                                         // If channel is closed the transport loop has exited; ignore.
                                         let _ = resp_tx.send(response).await;
-                                    });
+                                    }.instrument(span));
                                 }
                             }
                             Err(e) => {
@@ -448,6 +597,7 @@ impl<H: McpHandler> LineTransportRunner<H> {
 
                 // Completed handler responses ready to write back
                 Some(response) = response_rx.recv() => {
+                    timers.touch();
                     if response.should_send() {
                         self.send_response(&mut writer, &response).await?;
                     }
@@ -455,17 +605,20 @@ impl<H: McpHandler> LineTransportRunner<H> {
 
                 // Outgoing server-to-client requests/notifications
                 Some(cmd) = cmd_rx.recv() => {
+                    timers.touch();
                     match cmd {
                         SessionCommand::Request { method, params, response_tx } => {
+                            self.cancel_abandoned(&mut writer, &mut pending_requests).await?;
+
                             // Guard against unbounded pending request growth
-                            if pending_requests.len() >= MAX_PENDING_REQUESTS {
-                                tracing::error!(
+                            if pending_requests.len() >= max_pending_requests {
+                                tracing::warn!(
                                     count = pending_requests.len(),
                                     "Too many pending server-to-client requests"
                                 );
-                                let _ = response_tx.send(Err(McpError::internal(
-                                    "Too many pending server-to-client requests"
-                                )));
+                                let _ = response_tx.send(Err(
+                                    super::too_many_pending_server_requests(max_pending_requests)
+                                ));
                                 continue;
                             }
 
@@ -484,12 +637,7 @@ impl<H: McpHandler> LineTransportRunner<H> {
 
                             let req_str = serde_json::to_string(&request)
                                 .map_err(|e| McpError::internal(e.to_string()))?;
-                            writer.write_all(req_str.as_bytes()).await
-                                .map_err(|e| McpError::internal(format!("Failed to write: {e}")))?;
-                            writer.write_all(b"\n").await
-                                .map_err(|e| McpError::internal(format!("Failed to write newline: {e}")))?;
-                            writer.flush().await
-                                .map_err(|e| McpError::internal(format!("Failed to flush: {e}")))?;
+                            self.write_line(&mut writer, &req_str).await?;
                         }
                         SessionCommand::Notify { method, params } => {
                             let notification = serde_json::json!({
@@ -500,12 +648,10 @@ impl<H: McpHandler> LineTransportRunner<H> {
 
                             let notif_str = serde_json::to_string(&notification)
                                 .map_err(|e| McpError::internal(e.to_string()))?;
-                            writer.write_all(notif_str.as_bytes()).await
-                                .map_err(|e| McpError::internal(format!("Failed to write: {e}")))?;
-                            writer.write_all(b"\n").await
-                                .map_err(|e| McpError::internal(format!("Failed to write newline: {e}")))?;
-                            writer.flush().await
-                                .map_err(|e| McpError::internal(format!("Failed to flush: {e}")))?;
+                            self.write_line(&mut writer, &notif_str).await?;
+                        }
+                        SessionCommand::Abandoned => {
+                            self.cancel_abandoned(&mut writer, &mut pending_requests).await?;
                         }
                     }
                 }
@@ -533,6 +679,25 @@ impl<H: McpHandler> LineTransportRunner<H> {
         Ok(())
     }
 
+    /// Drop server-to-client requests whose caller gave up (for example
+    /// because the originating request was cancelled) and tell the client.
+    async fn cancel_abandoned<W: LineWriter>(
+        &self,
+        writer: &mut W,
+        pending_requests: &mut HashMap<
+            serde_json::Value,
+            oneshot::Sender<McpResult<serde_json::Value>>,
+        >,
+    ) -> Result<(), McpError> {
+        for id in super::take_abandoned_requests(pending_requests) {
+            tracing::debug!(id = %id, "Cancelling abandoned server-to-client request");
+            let notification = serde_json::to_string(&super::cancelled_notification(&id))
+                .map_err(|e| McpError::internal(e.to_string()))?;
+            self.write_line(writer, &notification).await?;
+        }
+        Ok(())
+    }
+
     /// Send a JSON-RPC response.
     async fn send_response<W: LineWriter>(
         &self,
@@ -540,19 +705,35 @@ impl<H: McpHandler> LineTransportRunner<H> {
         response: &router::JsonRpcOutgoing,
     ) -> Result<(), McpError> {
         let response_str = router::serialize_response(response)?;
-        writer
-            .write_all(response_str.as_bytes())
-            .await
-            .map_err(|e| McpError::internal(format!("Failed to write response: {e}")))?;
-        writer
-            .write_all(b"\n")
-            .await
-            .map_err(|e| McpError::internal(format!("Failed to write newline: {e}")))?;
-        writer
-            .flush()
-            .await
-            .map_err(|e| McpError::internal(format!("Failed to flush: {e}")))?;
-        Ok(())
+        self.write_line(writer, &response_str).await
+    }
+
+    /// Write one newline-terminated message and flush it.
+    ///
+    /// Fails with a timeout error if the client accepts no bytes for
+    /// `ConnectionLimits::slow_client_timeout`, which ends the run and closes
+    /// the connection. A slow write that keeps making progress is not cut off.
+    async fn write_line<W: LineWriter>(&self, writer: &mut W, line: &str) -> Result<(), McpError> {
+        let limit = self
+            .config
+            .as_ref()
+            .map_or(Some(DEFAULT_SLOW_CLIENT_TIMEOUT), |config| {
+                config.connection_limits.slow_client_timeout
+            });
+        for mut bytes in [line.as_bytes(), b"\n".as_slice()] {
+            while !bytes.is_empty() {
+                let written = with_progress_timeout(limit, writer.write(bytes))
+                    .await?
+                    .map_err(|e| McpError::internal(format!("Failed to write: {e}")))?;
+                if written == 0 {
+                    return Err(McpError::internal("Failed to write: connection closed"));
+                }
+                bytes = &bytes[written..];
+            }
+        }
+        with_progress_timeout(limit, writer.flush())
+            .await?
+            .map_err(|e| McpError::internal(format!("Failed to flush: {e}")))
     }
 
     /// Send a JSON-RPC error response.
@@ -575,6 +756,23 @@ impl<H: McpHandler> LineTransportRunner<H> {
     }
 }
 
+/// Await one write step, failing if it makes no progress within `limit`.
+async fn with_progress_timeout<T>(
+    limit: Option<Duration>,
+    step: impl std::future::Future<Output = T>,
+) -> Result<T, McpError> {
+    let Some(limit) = limit else {
+        return Ok(step.await);
+    };
+    tokio::time::timeout(limit, step).await.map_err(|_| {
+        tracing::warn!(
+            timeout_ms = limit.as_millis() as u64,
+            "Client stopped reading; closing connection"
+        );
+        McpError::timeout("Client is not reading from the connection")
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -685,6 +883,33 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_line_transport_ignores_unknown_notification() {
+        let runner = LineTransportRunner::new(TestHandler);
+
+        let unknown = r#"{"jsonrpc":"2.0","method":"notifications/from_the_future","params":{}}"#;
+        let ping = r#"{"jsonrpc":"2.0","id":1,"method":"ping"}"#;
+        let input = format!("{}{}\n{}\n", init_handshake(), unknown, ping);
+        let reader = BufReader::new(Cursor::new(input));
+        let mut output = Vec::new();
+
+        runner
+            .run(reader, &mut output, RequestContext::stdio)
+            .await
+            .unwrap();
+
+        // Only the initialize and ping requests are answered, and the session
+        // keeps serving after the unknown notification.
+        let responses: Vec<Value> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(responses.len(), 2, "{responses:?}");
+        assert_eq!(responses[1]["id"], 1);
+        assert!(responses.iter().all(|r| r.get("error").is_none()));
+    }
+
     #[tokio::test]
     async fn test_line_transport_allows_ping_before_init() {
         let handler = TestHandler;
@@ -869,4 +1094,823 @@ mod tests {
         assert!(result.is_ok(), "Clean EOF should return Ok");
         assert!(output.is_empty(), "No output on empty input");
     }
+
+    #[tokio::test]
+    async fn test_line_transport_closes_idle_connection() {
+        let runner = LineTransportRunner::new(TestHandler)
+            .with_connection_timeouts(None, Some(std::time::Duration::from_millis(100)));
+
+        // Keep the client side open without sending anything: only the idle
+        // timeout can end the run.
+        let (client, server) = tokio::io::duplex(1024);
+        let (server_read, server_write) = tokio::io::split(server);
+        let started = std::time::Instant::now();
+
+        let result = tokio::time::timeout(
+            std::time::Duration::from_secs(5),
+            runner.run(
+                BufReader::new(server_read),
+                server_write,
+                RequestContext::tcp,
+            ),
+        )
+        .await
+        .expect("idle connection should be closed");
+
+        assert!(result.is_ok());
+        assert!(started.elapsed() >= std::time::Duration::from_millis(100));
+        drop(client);
+    }
+
+    #[tokio::test]
+    async fn test_line_transport_activity_defers_idle_close() {
+        use tokio::io::AsyncWriteExt;
+
+        let runner = LineTransportRunner::new(TestHandler)
+            .with_connection_timeouts(None, Some(std::time::Duration::from_millis(150)));
+        let (mut client, server) = tokio::io::duplex(4096);
+        let (server_read, server_write) = tokio::io::split(server);
+        let started = std::time::Instant::now();
+
+        let run = tokio::spawn(async move {
+            runner
+                .run(
+                    BufReader::new(server_read),
+                    server_write,
+                    RequestContext::tcp,
+                )
+                .await
+        });
+
+        // Three pings 100ms apart keep the connection alive past a single
+        // idle period.
+        for id in 1..=3 {
+            let ping = format!("{{\"jsonrpc\":\"2.0\",\"id\":{id},\"method\":\"ping\"}}\n");
+            client.write_all(ping.as_bytes()).await.unwrap();
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
+
+        run.await.unwrap().unwrap();
+        assert!(started.elapsed() >= std::time::Duration::from_millis(300));
+    }
+
+    /// Handler whose only tool asks the client for a sampling completion.
+    #[derive(Clone)]
+    struct SamplingHandler;
+
+    #[allow(clippy::manual_async_fn)]
+    impl McpHandler for SamplingHandler {
+        fn server_info(&self) -> ServerInfo {
+            ServerInfo::new("test", "1.0.0")
+        }
+
+        fn list_tools(&self) -> Vec<Tool> {
+            vec![Tool::new("summarize", "Summarize via the client's model")]
+        }
+
+        fn list_resources(&self) -> Vec<Resource> {
+            vec![]
+        }
+
+        fn list_prompts(&self) -> Vec<Prompt> {
+            vec![]
+        }
+
+        fn call_tool<'a>(
+            &'a self,
+            _name: &'a str,
+            _args: Value,
+            ctx: &'a CoreRequestContext,
+        ) -> impl std::future::Future<Output = McpResult<ToolResult>> + Send + 'a {
+            async move {
+                let request = turbomcp_types::CreateMessageRequest::builder()
+                    .user("Summarize")
+                    .max_tokens(16)
+                    .build();
+                ctx.sample(request).await?;
+                Ok(ToolResult::text("summarized"))
+            }
+        }
+
+        fn read_resource<'a>(
+            &'a self,
+            uri: &'a str,
+            _ctx: &'a CoreRequestContext,
+        ) -> impl std::future::Future<Output = McpResult<ResourceResult>> + Send + 'a {
+            let uri = uri.to_string();
+            async move { Err(McpError::resource_not_found(&uri)) }
+        }
+
+        fn get_prompt<'a>(
+            &'a self,
+            name: &'a str,
+            _args: Option<Value>,
+            _ctx: &'a CoreRequestContext,
+        ) -> impl std::future::Future<Output = McpResult<PromptResult>> + Send + 'a {
+            let name = name.to_string();
+            async move { Err(McpError::prompt_not_found(&name)) }
+        }
+    }
+
+    /// `io::Write` sink that appends to a shared buffer.
+    #[derive(Clone, Default)]
+    struct LogCapture(Arc<std::sync::Mutex<Vec<u8>>>);
+
+    impl std::io::Write for LogCapture {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sampling_request_logs_carry_parent_request_id() {
+        use tokio::io::{AsyncBufReadExt, AsyncWriteExt};
+
+        let logs = LogCapture::default();
+        let writer = logs.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer(move || writer.clone())
+            .with_max_level(tracing::Level::DEBUG)
+            .with_ansi(false)
+            .finish();
+        // The test runtime is single-threaded, so spawned handler tasks log
+        // through this subscriber too.
+        let _default = tracing::subscriber::set_default(subscriber);
+
+        let runner = LineTransportRunner::new(SamplingHandler);
+        let (client, server) = tokio::io::duplex(8192);
+        let (server_read, server_write) = tokio::io::split(server);
+        let (client_read, mut client_write) = tokio::io::split(client);
+        let mut client_lines = BufReader::new(client_read).lines();
+
+        let run = tokio::spawn(async move {
+            runner
+                .run(
+                    BufReader::new(server_read),
+                    server_write,
+                    RequestContext::stdio,
+                )
+                .await
+        });
+
+        let init = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 0,
+            "method": "initialize",
+            "params": {
+                "protocolVersion": "2025-11-25",
+                "clientInfo": { "name": "test-client", "version": "1.0.0" },
+                "capabilities": { "sampling": {} }
+            }
+        });
+        let initialized = r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#;
+        let call = r#"{"jsonrpc":"2.0","id":7,"method":"tools/call","params":{"name":"summarize","arguments":{}}}"#;
+        let input = format!("{init}\n{initialized}\n{call}\n");
+        client_write.write_all(input.as_bytes()).await.unwrap();
+
+        // Skip the initialize response, then answer the sampling request.
+        client_lines
+            .next_line()
+            .await
+            .unwrap()
+            .expect("init response");
+        let sampling: Value =
+            serde_json::from_str(&client_lines.next_line().await.unwrap().unwrap()).unwrap();
+        assert_eq!(sampling["method"], "sampling/createMessage");
+        let reply = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": sampling["id"],
+            "result": {
+                "role": "assistant",
+                "content": { "type": "text", "text": "short" },
+                "model": "test-model"
+            }
+        });
+        client_write
+            .write_all(format!("{reply}\n").as_bytes())
+            .await
+            .unwrap();
+
+        let result: Value =
+            serde_json::from_str(&client_lines.next_line().await.unwrap().unwrap()).unwrap();
+        assert_eq!(result["id"], 7);
+        assert!(result.get("result").is_some(), "tool call should succeed");
+
+        // Dropping one half of a split duplex does not close it.
+        client_write.shutdown().await.unwrap();
+        run.await.unwrap().unwrap();
+
+        let logs = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+        let line = logs
+            .lines()
+            .find(|l| l.contains("Sending server-initiated request"))
+            .expect("sampling request should be logged");
+        assert!(
+            line.contains("request_id=7"),
+            "missing correlation id: {line}"
+        );
+        assert!(line.contains("method=tools/call"), "{line}");
+        assert!(
+            line.contains("server_method=sampling/createMessage"),
+            "{line}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cancelling_tool_cancels_pending_sampling_request() {
+        use tokio::io::{AsyncBufReadExt, AsyncWriteExt};
+
+        let runner = LineTransportRunner::new(SamplingHandler);
+        let (client, server) = tokio::io::duplex(8192);
+        let (server_read, server_write) = tokio::io::split(server);
+        let (client_read, mut client_write) = tokio::io::split(client);
+        let mut client_lines = BufReader::new(client_read).lines();
+
+        let run = tokio::spawn(async move {
+            runner
+                .run(
+                    BufReader::new(server_read),
+                    server_write,
+                    RequestContext::stdio,
+                )
+                .await
+        });
+
+        let init = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 0,
+            "method": "initialize",
+            "params": {
+                "protocolVersion": "2025-11-25",
+                "clientInfo": { "name": "test-client", "version": "1.0.0" },
+                "capabilities": { "sampling": {} }
+            }
+        });
+        let initialized = r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#;
+        let call = r#"{"jsonrpc":"2.0","id":7,"method":"tools/call","params":{"name":"summarize","arguments":{}}}"#;
+        let input = format!("{init}\n{initialized}\n{call}\n");
+        client_write.write_all(input.as_bytes()).await.unwrap();
+
+        client_lines
+            .next_line()
+            .await
+            .unwrap()
+            .expect("init response");
+        let sampling: Value =
+            serde_json::from_str(&client_lines.next_line().await.unwrap().unwrap()).unwrap();
+        assert_eq!(sampling["method"], "sampling/createMessage");
+
+        // Cancel the tool call while the client still owes the sampling reply.
+        let cancel =
+            r#"{"jsonrpc":"2.0","method":"notifications/cancelled","params":{"requestId":7}}"#;
+        client_write
+            .write_all(format!("{cancel}\n").as_bytes())
+            .await
+            .unwrap();
+
+        // The server drops the sampling correlation and cancels it upstream.
+        let mut sampling_cancelled = false;
+        let mut tool_failed = false;
+        while !(sampling_cancelled && tool_failed) {
+            let line = tokio::time::timeout(Duration::from_secs(5), client_lines.next_line())
+                .await
+                .expect("server should react to the cancellation")
+                .unwrap()
+                .expect("server closed the connection");
+            let message: Value = serde_json::from_str(&line).unwrap();
+            if message["method"] == "notifications/cancelled" {
+                assert_eq!(message["params"]["requestId"], sampling["id"]);
+                sampling_cancelled = true;
+            } else if message["id"] == 7 {
+                assert!(message.get("error").is_some(), "{message}");
+                tool_failed = true;
+            }
+        }
+
+        // A late reply matches nothing and the session keeps working.
+        let late = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": sampling["id"],
+            "result": {
+                "role": "assistant",
+                "content": { "type": "text", "text": "too late" },
+                "model": "test-model"
+            }
+        });
+        let ping = r#"{"jsonrpc":"2.0","id":8,"method":"ping"}"#;
+        client_write
+            .write_all(format!("{late}\n{ping}\n").as_bytes())
+            .await
+            .unwrap();
+        let pong: Value =
+            serde_json::from_str(&client_lines.next_line().await.unwrap().unwrap()).unwrap();
+        assert_eq!(pong["id"], 8);
+
+        // Dropping one half of a split duplex does not close it.
+        client_write.shutdown().await.unwrap();
+        run.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_server_requests_beyond_limit_fail_fast() {
+        use tokio::io::{AsyncBufReadExt, AsyncWriteExt};
+
+        let config = ServerConfig::builder()
+            .max_pending_server_requests(1)
+            .build();
+        let runner = LineTransportRunner::with_config(SamplingHandler, config);
+        let (client, server) = tokio::io::duplex(8192);
+        let (server_read, server_write) = tokio::io::split(server);
+        let (client_read, mut client_write) = tokio::io::split(client);
+        let mut client_lines = BufReader::new(client_read).lines();
+
+        let run = tokio::spawn(async move {
+            runner
+                .run(
+                    BufReader::new(server_read),
+                    server_write,
+                    RequestContext::stdio,
+                )
+                .await
+        });
+
+        let init = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 0,
+            "method": "initialize",
+            "params": {
+                "protocolVersion": "2025-11-25",
+                "clientInfo": { "name": "test-client", "version": "1.0.0" },
+                "capabilities": { "sampling": {} }
+            }
+        });
+        let initialized = r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#;
+        let first = r#"{"jsonrpc":"2.0","id":7,"method":"tools/call","params":{"name":"summarize","arguments":{}}}"#;
+        let input = format!("{init}\n{initialized}\n{first}\n");
+        client_write.write_all(input.as_bytes()).await.unwrap();
+
+        client_lines
+            .next_line()
+            .await
+            .unwrap()
+            .expect("init response");
+        let sampling: Value =
+            serde_json::from_str(&client_lines.next_line().await.unwrap().unwrap()).unwrap();
+        assert_eq!(sampling["method"], "sampling/createMessage");
+
+        // The only slot is taken, so the second sampling request is refused
+        // without waiting for the client.
+        let second = r#"{"jsonrpc":"2.0","id":8,"method":"tools/call","params":{"name":"summarize","arguments":{}}}"#;
+        client_write
+            .write_all(format!("{second}\n").as_bytes())
+            .await
+            .unwrap();
+        let refused: Value = serde_json::from_str(
+            &tokio::time::timeout(Duration::from_secs(5), client_lines.next_line())
+                .await
+                .expect("second call should fail fast")
+                .unwrap()
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(refused["id"], 8);
+        assert_eq!(refused["error"]["code"], -32010, "{refused}");
+
+        // The pending request still completes normally.
+        let reply = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": sampling["id"],
+            "result": {
+                "role": "assistant",
+                "content": { "type": "text", "text": "short" },
+                "model": "test-model"
+            }
+        });
+        client_write
+            .write_all(format!("{reply}\n").as_bytes())
+            .await
+            .unwrap();
+        let result: Value =
+            serde_json::from_str(&client_lines.next_line().await.unwrap().unwrap()).unwrap();
+        assert_eq!(result["id"], 7);
+        assert_eq!(result["result"]["content"][0]["text"], "summarized");
+
+        // Dropping one half of a split duplex does not close it.
+        client_write.shutdown().await.unwrap();
+        run.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_line_transport_rejects_disallowed_meta() {
+        let config = ServerConfig::builder()
+            .meta_allowlist(
+                crate::config::MetaAllowlist::only(["progressToken"])
+                    .on_disallowed(crate::config::DisallowedMeta::Reject),
+            )
+            .build();
+        let runner = LineTransportRunner::with_config(TestHandler, config);
+
+        let injected = r#"{"jsonrpc":"2.0","id":3,"method":"tools/call","params":{"name":"ping","arguments":{},"_meta":{"x-tenant":"admin"}}}"#;
+        let allowed = r#"{"jsonrpc":"2.0","id":4,"method":"tools/call","params":{"name":"ping","arguments":{},"_meta":{"progressToken":1}}}"#;
+        let input = format!("{}{injected}\n{allowed}\n", init_handshake());
+        let mut output = Vec::new();
+        runner
+            .run(
+                BufReader::new(Cursor::new(input)),
+                &mut output,
+                RequestContext::stdio,
+            )
+            .await
+            .unwrap();
+
+        let responses: Vec<Value> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        let injected = responses.iter().find(|r| r["id"] == 3).unwrap();
+        assert_eq!(injected["error"]["code"], -32602, "{injected}");
+        let allowed = responses.iter().find(|r| r["id"] == 4).unwrap();
+        assert_eq!(allowed["result"]["content"][0]["text"], "pong", "{allowed}");
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_reaches_initialized_connection() {
+        use tokio::io::{AsyncBufReadExt, AsyncWriteExt};
+
+        let broadcaster = crate::NotificationBroadcaster::new();
+        let config = ServerConfig::builder()
+            .notification_broadcaster(broadcaster.clone())
+            .build();
+        let runner = LineTransportRunner::with_config(TestHandler, config);
+        let (client, server) = tokio::io::duplex(8192);
+        let (server_read, server_write) = tokio::io::split(server);
+        let (client_read, mut client_write) = tokio::io::split(client);
+        let mut client_lines = BufReader::new(client_read).lines();
+
+        let run = tokio::spawn(async move {
+            runner
+                .run(
+                    BufReader::new(server_read),
+                    server_write,
+                    RequestContext::stdio,
+                )
+                .await
+        });
+
+        client_write
+            .write_all(init_handshake().as_bytes())
+            .await
+            .unwrap();
+        client_lines
+            .next_line()
+            .await
+            .unwrap()
+            .expect("init response");
+        assert_eq!(broadcaster.len(), 1);
+
+        let skipped = broadcaster
+            .broadcast_filtered("notifications/job/completed", Value::Null, |client| {
+                client.client_info.name == "other-client"
+            })
+            .await;
+        assert_eq!(skipped, 0);
+
+        let delivered = broadcaster
+            .broadcast(
+                "notifications/job/completed",
+                serde_json::json!({ "job": 42 }),
+            )
+            .await;
+        assert_eq!(delivered, 1);
+        let notification: Value =
+            serde_json::from_str(&client_lines.next_line().await.unwrap().unwrap()).unwrap();
+        assert_eq!(notification["method"], "notifications/job/completed");
+        assert_eq!(notification["params"]["job"], 42);
+        assert!(notification.get("id").is_none(), "{notification}");
+
+        // Dropping one half of a split duplex does not close it.
+        client_write.shutdown().await.unwrap();
+        run.await.unwrap().unwrap();
+        assert!(broadcaster.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_slow_client_applies_backpressure_then_disconnects() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use tokio::io::{AsyncBufReadExt, AsyncWriteExt};
+
+        let broadcaster = crate::NotificationBroadcaster::new();
+        let config = ServerConfig::builder()
+            .connection_limits(
+                crate::config::ConnectionLimits::default()
+                    .with_write_high_water_mark(2)
+                    .with_slow_client_timeout(Some(Duration::from_millis(300))),
+            )
+            .notification_broadcaster(broadcaster.clone())
+            .build();
+        let runner = LineTransportRunner::with_config(TestHandler, config);
+        let (client, server) = tokio::io::duplex(256);
+        let (server_read, server_write) = tokio::io::split(server);
+        let (client_read, mut client_write) = tokio::io::split(client);
+        let mut client_lines = BufReader::new(client_read).lines();
+
+        let started = std::time::Instant::now();
+        let run = tokio::spawn(async move {
+            runner
+                .run(
+                    BufReader::new(server_read),
+                    server_write,
+                    RequestContext::stdio,
+                )
+                .await
+        });
+
+        client_write
+            .write_all(init_handshake().as_bytes())
+            .await
+            .unwrap();
+        client_lines
+            .next_line()
+            .await
+            .unwrap()
+            .expect("init response");
+
+        // From here on the client never reads, so the 256-byte pipe fills up.
+        let delivered = Arc::new(AtomicUsize::new(0));
+        let notifier = tokio::spawn({
+            let broadcaster = broadcaster.clone();
+            let delivered = Arc::clone(&delivered);
+            async move {
+                let payload = serde_json::json!({ "data": "x".repeat(200) });
+                while broadcaster
+                    .broadcast("notifications/job/progress", payload.clone())
+                    .await
+                    == 1
+                {
+                    delivered.fetch_add(1, Ordering::SeqCst);
+                }
+            }
+        });
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let queued = delivered.load(Ordering::SeqCst);
+        assert!(
+            queued <= 4,
+            "notifier should wait on the slow client, but queued {queued} messages"
+        );
+
+        let error = tokio::time::timeout(Duration::from_secs(5), run)
+            .await
+            .expect("slow client should be disconnected")
+            .unwrap()
+            .expect_err("run should fail once the client stops reading");
+        assert!(error.is_timeout(), "{error:?}");
+        assert!(started.elapsed() >= Duration::from_millis(300));
+
+        tokio::time::timeout(Duration::from_secs(5), notifier)
+            .await
+            .expect("notifier should stop once the session closes")
+            .unwrap();
+        assert!(broadcaster.is_empty());
+        drop((client_lines, client_write));
+    }
+
+    #[tokio::test]
+    async fn test_slow_write_that_keeps_progressing_is_not_cut_off() {
+        use tokio::io::AsyncReadExt;
+
+        let config = ServerConfig::builder()
+            .connection_limits(
+                crate::config::ConnectionLimits::default()
+                    .with_slow_client_timeout(Some(Duration::from_millis(100))),
+            )
+            .build();
+        let runner = LineTransportRunner::with_config(TestHandler, config);
+        let (mut client, mut server) = tokio::io::duplex(64);
+        // Reads 64 bytes every 20ms: the whole line takes well over the
+        // timeout, but no single write waits that long.
+        let reader = tokio::spawn(async move {
+            let mut received = Vec::new();
+            let mut chunk = [0u8; 64];
+            while !received.ends_with(b"\n") {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                let n = client.read(&mut chunk).await.unwrap();
+                assert_ne!(n, 0, "server closed the connection");
+                received.extend_from_slice(&chunk[..n]);
+            }
+            received
+        });
+
+        let line = "x".repeat(1024);
+        runner.write_line(&mut server, &line).await.unwrap();
+        assert_eq!(reader.await.unwrap().len(), line.len() + 1);
+    }
+
+    /// Handler whose only tool echoes `text` after sleeping `delay_ms`.
+    #[derive(Clone)]
+    struct DelayedEchoHandler;
+
+    #[allow(clippy::manual_async_fn)]
+    impl McpHandler for DelayedEchoHandler {
+        fn server_info(&self) -> ServerInfo {
+            ServerInfo::new("delayed-echo", "1.0.0")
+        }
+
+        fn list_tools(&self) -> Vec<Tool> {
+            vec![Tool::new("echo", "Echo after a delay")]
+        }
+
+        fn list_resources(&self) -> Vec<Resource> {
+            vec![]
+        }
+
+        fn list_prompts(&self) -> Vec<Prompt> {
+            vec![]
+        }
+
+        fn call_tool<'a>(
+            &'a self,
+            _name: &'a str,
+            args: Value,
+            _ctx: &'a CoreRequestContext,
+        ) -> impl std::future::Future<Output = McpResult<ToolResult>> + Send + 'a {
+            async move {
+                let delay = args["delay_ms"].as_u64().unwrap_or(0);
+                tokio::time::sleep(Duration::from_millis(delay)).await;
+                Ok(ToolResult::text(args["text"].as_str().unwrap_or_default()))
+            }
+        }
+
+        fn read_resource<'a>(
+            &'a self,
+            uri: &'a str,
+            _ctx: &'a CoreRequestContext,
+        ) -> impl std::future::Future<Output = McpResult<ResourceResult>> + Send + 'a {
+            let uri = uri.to_string();
+            async move { Err(McpError::resource_not_found(&uri)) }
+        }
+
+        fn get_prompt<'a>(
+            &'a self,
+            name: &'a str,
+            _args: Option<Value>,
+            _ctx: &'a CoreRequestContext,
+        ) -> impl std::future::Future<Output = McpResult<PromptResult>> + Send + 'a {
+            let name = name.to_string();
+            async move { Err(McpError::prompt_not_found(&name)) }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_line_transport_idle_timeout_waits_for_in_flight_handler() {
+        use tokio::io::{AsyncBufReadExt, AsyncWriteExt};
+
+        let runner = LineTransportRunner::new(DelayedEchoHandler)
+            .with_connection_timeouts(None, Some(Duration::from_millis(100)));
+        let (client, server) = tokio::io::duplex(64 * 1024);
+        let (server_read, server_write) = tokio::io::split(server);
+        let (client_read, mut client_write) = tokio::io::split(client);
+        let mut client_lines = BufReader::new(client_read).lines();
+        let run = tokio::spawn(async move {
+            runner
+                .run(
+                    BufReader::new(server_read),
+                    server_write,
+                    RequestContext::tcp,
+                )
+                .await
+        });
+
+        // The handler sleeps well past the idle timeout without sending
+        // anything, and the client stays silent while it waits.
+        let slow = r#"{"jsonrpc":"2.0","id":1,"method":"tools/call","params":{"name":"echo","arguments":{"text":"done","delay_ms":300}}}"#;
+        client_write
+            .write_all(format!("{}{slow}\n", init_handshake()).as_bytes())
+            .await
+            .unwrap();
+        let response = loop {
+            let line = client_lines.next_line().await.unwrap().expect("response");
+            let message: Value = serde_json::from_str(&line).unwrap();
+            if message["id"] == 1 {
+                break message;
+            }
+        };
+        assert_eq!(response["result"]["content"][0]["text"], "done");
+
+        // The connection is still open, and the idle timeout restarts now.
+        client_write
+            .write_all(b"{\"jsonrpc\":\"2.0\",\"id\":2,\"method\":\"ping\"}\n")
+            .await
+            .unwrap();
+        let pong: Value =
+            serde_json::from_str(&client_lines.next_line().await.unwrap().expect("pong")).unwrap();
+        assert_eq!(pong["id"], 2);
+
+        tokio::time::timeout(Duration::from_secs(5), run)
+            .await
+            .expect("connection should close once idle again")
+            .unwrap()
+            .unwrap();
+    }
+
+    /// Send two `echo` calls sharing id 5, the first slow, without waiting
+    /// for either response; return the responses in the order written.
+    async fn run_duplicate_id_calls(policy: DuplicateRequestIds) -> Vec<Value> {
+        let config = ServerConfig::builder()
+            .duplicate_request_ids(policy)
+            .build();
+        let runner = LineTransportRunner::with_config(DelayedEchoHandler, config);
+
+        let slow = r#"{"jsonrpc":"2.0","id":5,"method":"tools/call","params":{"name":"echo","arguments":{"text":"first","delay_ms":200}}}"#;
+        let fast = r#"{"jsonrpc":"2.0","id":5,"method":"tools/call","params":{"name":"echo","arguments":{"text":"second"}}}"#;
+        let input = format!("{}{slow}\n{fast}\n", init_handshake());
+        let mut output = Vec::new();
+        runner
+            .run(
+                BufReader::new(Cursor::new(input)),
+                &mut output,
+                RequestContext::stdio,
+            )
+            .await
+            .unwrap();
+
+        String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str::<Value>(line).unwrap())
+            .filter(|message| message["id"] == 5)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_line_transport_rejects_in_flight_duplicate_id() {
+        let responses = run_duplicate_id_calls(DuplicateRequestIds::Reject).await;
+
+        assert_eq!(responses.len(), 2, "{responses:?}");
+        // The duplicate is refused at once; the original still completes.
+        assert_eq!(responses[0]["error"]["code"], -32600);
+        assert_eq!(
+            responses[1]["result"]["content"][0]["text"], "first",
+            "{responses:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_line_transport_queues_in_flight_duplicate_id() {
+        let responses = run_duplicate_id_calls(DuplicateRequestIds::Queue).await;
+
+        // The duplicate waits for the slow original instead of overtaking it.
+        let texts: Vec<_> = responses
+            .iter()
+            .map(|response| response["result"]["content"][0]["text"].clone())
+            .collect();
+        assert_eq!(texts, vec!["first", "second"], "{responses:?}");
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_answers_in_flight_requests() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        let runner = LineTransportRunner::new(DelayedEchoHandler).with_shutdown(shutdown_rx);
+
+        // The client never closes its side: only the shutdown can end the run.
+        let (mut client, server) = tokio::io::duplex(64 * 1024);
+        let (server_read, server_write) = tokio::io::split(server);
+        let slow = r#"{"jsonrpc":"2.0","id":1,"method":"tools/call","params":{"name":"echo","arguments":{"text":"done","delay_ms":200}}}"#;
+        client
+            .write_all(format!("{}{slow}\n", init_handshake()).as_bytes())
+            .await
+            .unwrap();
+
+        let run = runner.run(
+            BufReader::new(server_read),
+            server_write,
+            RequestContext::stdio,
+        );
+        let stop = async {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            shutdown_tx.send(true).unwrap();
+        };
+        let (result, ()) =
+            tokio::time::timeout(Duration::from_secs(5), async { tokio::join!(run, stop) })
+                .await
+                .expect("shutdown should end the run");
+        assert!(result.is_ok());
+
+        let mut output = String::new();
+        client.read_to_string(&mut output).await.unwrap();
+        let response = output
+            .lines()
+            .map(|line| serde_json::from_str::<Value>(line).unwrap())
+            .find(|message| message["id"] == 1)
+            .expect("in-flight request should be answered");
+        assert_eq!(response["result"]["content"][0]["text"], "done");
+    }
 }
@@ -20,11 +20,19 @@ mod line;
 
 use std::collections::HashSet;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
 
 use dashmap::DashMap;
 use serde_json::Value;
+use tokio::sync::oneshot;
+use tokio::time::Instant;
 use tokio_util::sync::CancellationToken;
-use turbomcp_types::{ClientCapabilities, ProtocolVersion};
+use turbomcp_core::error::{ErrorKind, McpError};
+use turbomcp_types::{ClientCapabilities, Implementation, ProtocolVersion};
+
+use crate::config::DuplicateRequestIds;
+use crate::initialize::InitializeClient;
 
 /// RAII guard that removes a pending-handler entry from the per-connection
 /// cancellation registry when dropped.
@@ -59,6 +67,296 @@ impl Drop for PendingHandlerGuard {
     }
 }
 
+/// Per-connection queue that serializes requests sharing a request ID, used
+/// under [`DuplicateRequestIds::Queue`].
+///
+/// Each ID keeps the completion signal of the last request that claimed it.
+/// [`enqueue`](Self::enqueue) takes a place in line synchronously, so
+/// requests run in arrival order even though their handler tasks are
+/// spawned independently.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RequestIdQueue {
+    tails: Arc<DashMap<String, (u64, oneshot::Receiver<()>)>>,
+    next: Arc<AtomicU64>,
+}
+
+impl RequestIdQueue {
+    /// Claim the next turn for `key`.
+    pub(crate) fn enqueue(&self, key: String) -> QueuedRequest {
+        let generation = self.next.fetch_add(1, Ordering::Relaxed);
+        let (done, finished) = oneshot::channel();
+        let previous = self
+            .tails
+            .insert(key.clone(), (generation, finished))
+            .map(|(_, previous)| previous);
+        QueuedRequest {
+            previous,
+            turn: RequestIdTurn {
+                tails: Arc::clone(&self.tails),
+                key,
+                generation,
+                _done: done,
+            },
+        }
+    }
+}
+
+/// A place in a [`RequestIdQueue`].
+pub(crate) struct QueuedRequest {
+    /// Resolves once the previous request with the same ID has completed.
+    previous: Option<oneshot::Receiver<()>>,
+    turn: RequestIdTurn,
+}
+
+impl QueuedRequest {
+    /// Wait until every earlier request with the same ID has completed.
+    pub(crate) async fn wait(self) -> RequestIdTurn {
+        if let Some(previous) = self.previous {
+            // An error only means the previous handler's task ended without
+            // completing normally; either way it is done.
+            let _ = previous.await;
+        }
+        self.turn
+    }
+}
+
+/// Held while a queued request runs; dropping it lets the next request with
+/// the same ID start.
+pub(crate) struct RequestIdTurn {
+    tails: Arc<DashMap<String, (u64, oneshot::Receiver<()>)>>,
+    key: String,
+    generation: u64,
+    _done: oneshot::Sender<()>,
+}
+
+impl Drop for RequestIdTurn {
+    fn drop(&mut self) {
+        // Only the last request in line clears the entry; otherwise a later
+        // request is already waiting on it.
+        self.tails.remove_if(&self.key, |_, (generation, _)| {
+            *generation == self.generation
+        });
+    }
+}
+
+/// Admission of a spawned request handler into the cancellation registry.
+///
+/// Registers the handler's [`CancellationToken`] under its request ID and,
+/// when the connection queues duplicate IDs, holds the handler back until
+/// the earlier request with the same ID has finished. A queued handler
+/// registers its token only once it starts, so `notifications/cancelled`
+/// always targets the request currently running.
+pub(crate) struct HandlerAdmission {
+    handlers: Arc<DashMap<String, CancellationToken>>,
+    key: Option<String>,
+    token: CancellationToken,
+    queued: Option<QueuedRequest>,
+}
+
+impl HandlerAdmission {
+    pub(crate) fn new(
+        handlers: &Arc<DashMap<String, CancellationToken>>,
+        queue: Option<&RequestIdQueue>,
+        key: Option<String>,
+        token: &CancellationToken,
+    ) -> Self {
+        let queued = queue
+            .zip(key.clone())
+            .map(|(queue, key)| queue.enqueue(key));
+        let waiting = queued.as_ref().is_some_and(|q| q.previous.is_some());
+        if !waiting && let Some(ref key) = key {
+            handlers.insert(key.clone(), token.clone());
+        }
+        Self {
+            handlers: Arc::clone(handlers),
+            key,
+            token: token.clone(),
+            queued,
+        }
+    }
+
+    /// Wait for this request's turn, then hold its registration until the
+    /// returned guard is dropped.
+    pub(crate) async fn ready(self) -> HandlerTurn {
+        let turn = match self.queued {
+            Some(queued) => {
+                let waited = queued.previous.is_some();
+                let turn = queued.wait().await;
+                if waited && let Some(ref key) = self.key {
+                    self.handlers.insert(key.clone(), self.token.clone());
+                }
+                Some(turn)
+            }
+            None => None,
+        };
+        HandlerTurn {
+            _guard: PendingHandlerGuard::new(self.handlers, self.key),
+            _turn: turn,
+        }
+    }
+}
+
+/// Guard returned by [`HandlerAdmission::ready`].
+///
+/// Fields drop in declaration order: the registry entry is removed before
+/// the next request with the same ID is released.
+pub(crate) struct HandlerTurn {
+    _guard: PendingHandlerGuard,
+    _turn: Option<RequestIdTurn>,
+}
+
+/// Guard held by a session `call` while it awaits the client's response.
+///
+/// If the call future is dropped first (typically because the originating
+/// request was cancelled), the guard queues `on_drop` so the transport loop
+/// can drop the correlation entry and tell the client via
+/// [`cancelled_notification`]. A full queue only delays the cleanup: loops
+/// also sweep abandoned entries whenever they send a new request.
+pub(crate) struct AbandonedCallGuard<'a, C> {
+    request_tx: &'a tokio::sync::mpsc::Sender<C>,
+    on_drop: Option<C>,
+}
+
+impl<'a, C> AbandonedCallGuard<'a, C> {
+    pub(crate) fn new(request_tx: &'a tokio::sync::mpsc::Sender<C>, on_drop: C) -> Self {
+        Self {
+            request_tx,
+            on_drop: Some(on_drop),
+        }
+    }
+
+    /// The call completed; nothing to clean up.
+    pub(crate) fn disarm(mut self) {
+        self.on_drop = None;
+    }
+}
+
+impl<C> Drop for AbandonedCallGuard<'_, C> {
+    fn drop(&mut self) {
+        if let Some(command) = self.on_drop.take() {
+            let _ = self.request_tx.try_send(command);
+        }
+    }
+}
+
+/// Remove server-to-client requests whose caller stopped waiting, returning
+/// their ids.
+pub(crate) fn take_abandoned_requests<T>(
+    pending: &mut std::collections::HashMap<Value, tokio::sync::oneshot::Sender<T>>,
+) -> Vec<Value> {
+    let abandoned: Vec<Value> = pending
+        .iter()
+        .filter(|(_, tx)| tx.is_closed())
+        .map(|(id, _)| id.clone())
+        .collect();
+    for id in &abandoned {
+        pending.remove(id);
+    }
+    abandoned
+}
+
+/// Error for a server-to-client request refused because `limit` requests are
+/// already awaiting a response on the connection.
+pub(crate) fn too_many_pending_server_requests(limit: usize) -> McpError {
+    McpError::new(
+        ErrorKind::ServerOverloaded,
+        format!("Too many pending server-to-client requests (limit: {limit})"),
+    )
+}
+
+/// `notifications/cancelled` for a server-to-client request the server no
+/// longer needs answered.
+pub(crate) fn cancelled_notification(id: &Value) -> Value {
+    serde_json::json!({
+        "jsonrpc": "2.0",
+        "method": "notifications/cancelled",
+        "params": {
+            "requestId": id,
+            "reason": "originating request was cancelled"
+        }
+    })
+}
+
+/// Why a transport closed a connection on its own initiative.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ConnectionCloseReason {
+    /// The connection outlived `ConnectionLimits::max_connection_lifetime`.
+    MaxLifetime,
+    /// No message was exchanged within `ConnectionLimits::idle_timeout`.
+    Idle,
+}
+
+impl ConnectionCloseReason {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::MaxLifetime => "maximum connection lifetime exceeded",
+            Self::Idle => "idle timeout exceeded",
+        }
+    }
+}
+
+/// Per-connection deadlines derived from the configured
+/// `max_connection_lifetime` and `idle_timeout`.
+///
+/// Transport loops call [`touch`](Self::touch) whenever a message is sent or
+/// received, and select on [`expired`](Self::expired) to close the
+/// connection once either deadline passes. A connection with requests in
+/// flight is busy rather than idle, so loops pass an idle expiry through
+/// [`should_close`](Self::should_close) first.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ConnectionTimers {
+    lifetime_deadline: Option<Instant>,
+    idle_timeout: Option<Duration>,
+    idle_deadline: Option<Instant>,
+}
+
+impl ConnectionTimers {
+    pub(crate) fn new(max_lifetime: Option<Duration>, idle_timeout: Option<Duration>) -> Self {
+        let now = Instant::now();
+        Self {
+            lifetime_deadline: max_lifetime.map(|lifetime| now + lifetime),
+            idle_timeout,
+            idle_deadline: idle_timeout.map(|timeout| now + timeout),
+        }
+    }
+
+    /// Record activity on the connection, pushing back the idle deadline.
+    pub(crate) fn touch(&mut self) {
+        if let Some(timeout) = self.idle_timeout {
+            self.idle_deadline = Some(Instant::now() + timeout);
+        }
+    }
+
+    /// Whether the deadline that passed with `reason` closes the connection.
+    ///
+    /// While `busy`, an idle deadline is pushed back by a full
+    /// `idle_timeout` instead; the response that ends the last request
+    /// touches the timers again, so idleness is measured from then.
+    pub(crate) fn should_close(&mut self, reason: ConnectionCloseReason, busy: bool) -> bool {
+        if reason == ConnectionCloseReason::Idle && busy {
+            self.touch();
+            return false;
+        }
+        true
+    }
+
+    /// Resolve once a deadline passes; never resolves if neither is set.
+    pub(crate) async fn expired(self) -> ConnectionCloseReason {
+        tokio::select! {
+            biased;
+            () = sleep_until(self.lifetime_deadline) => ConnectionCloseReason::MaxLifetime,
+            () = sleep_until(self.idle_deadline) => ConnectionCloseReason::Idle,
+        }
+    }
+}
+
+async fn sleep_until(deadline: Option<Instant>) {
+    match deadline {
+        Some(deadline) => tokio::time::sleep_until(deadline).await,
+        None => std::future::pending().await,
+    }
+}
+
 /// MCP session lifecycle state for per-connection/session version tracking.
 ///
 /// Enforces the MCP spec initialization lifecycle:
@@ -80,6 +378,7 @@ pub(crate) enum SessionState {
 pub(crate) struct InitializedSessionState {
     protocol_version: ProtocolVersion,
     seen_request_ids: HashSet<String>,
+    duplicate_request_ids: DuplicateRequestIds,
 }
 
 impl InitializedSessionState {
@@ -95,14 +394,29 @@ impl InitializedSessionState {
         Self {
             protocol_version,
             seen_request_ids,
+            duplicate_request_ids: DuplicateRequestIds::default(),
         }
     }
 
+    /// Set how reused request IDs are handled; see [`register_request_id`](Self::register_request_id).
+    pub(crate) fn with_duplicate_request_ids(mut self, policy: DuplicateRequestIds) -> Self {
+        self.duplicate_request_ids = policy;
+        self
+    }
+
     pub(crate) fn protocol_version(&self) -> &ProtocolVersion {
         &self.protocol_version
     }
 
+    /// Record a request ID, returning `false` if the request must be
+    /// rejected as a duplicate.
+    ///
+    /// Under [`DuplicateRequestIds::Queue`] every ID is admitted; the
+    /// transport serializes requests that share one instead.
     pub(crate) fn register_request_id(&mut self, request_id: Option<&Value>) -> bool {
+        if self.duplicate_request_ids == DuplicateRequestIds::Queue {
+            return true;
+        }
         let Some(request_id) = request_id.and_then(request_id_key) else {
             return true;
         };
@@ -115,6 +429,44 @@ pub(crate) fn request_id_key(id: &Value) -> Option<String> {
     serde_json::to_string(id).ok()
 }
 
+/// Span for handling one client request.
+///
+/// Handler tasks run inside it, so their logs carry the request's id and
+/// method. Server-initiated requests the handler makes open a
+/// [`server_request_span`] beneath it, which ties sampling and elicitation
+/// logs back to the originating tool call.
+pub(crate) fn request_span(request: &crate::router::JsonRpcIncoming) -> tracing::Span {
+    let request_id = request
+        .id
+        .as_ref()
+        .map(Value::to_string)
+        .unwrap_or_default();
+    tracing::info_span!("mcp.request", request_id = %request_id, method = %request.method)
+}
+
+/// Span for a server-to-client request, opened as a child of the current
+/// [`request_span`].
+pub(crate) fn server_request_span(method: &str) -> tracing::Span {
+    tracing::debug_span!("mcp.server_request", server_method = %method)
+}
+
+/// Describe the client of a successful `initialize` request for
+/// [`NotificationBroadcaster`](crate::NotificationBroadcaster) filters.
+pub(crate) fn initialize_client_from_params(
+    params: Option<&Value>,
+    protocol_version: ProtocolVersion,
+) -> InitializeClient {
+    InitializeClient {
+        client_info: params
+            .and_then(|params| params.get("clientInfo"))
+            .cloned()
+            .and_then(|info| serde_json::from_value(info).ok())
+            .unwrap_or_else(|| Implementation::new("unknown", "0.0.0")),
+        capabilities: client_capabilities_from_initialize_params(params),
+        protocol_version,
+    }
+}
+
 pub(crate) fn client_capabilities_from_initialize_params(
     params: Option<&Value>,
 ) -> ClientCapabilities {
@@ -125,6 +477,39 @@ pub(crate) fn client_capabilities_from_initialize_params(
         .unwrap_or_default()
 }
 
+/// Wait for SIGINT (Ctrl-C) and, on Unix, SIGTERM. Returns when either fires.
+pub(crate) async fn shutdown_signal() {
+    let ctrl_c = async {
+        let _ = tokio::signal::ctrl_c().await;
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        if let Ok(mut sig) =
+            tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+        {
+            sig.recv().await;
+        }
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+}
+
+/// Resolve once `shutdown` holds `true`. A dropped sender never stops the
+/// transport.
+pub(crate) async fn shutdown_requested(mut shutdown: tokio::sync::watch::Receiver<bool>) {
+    let requested = shutdown.wait_for(|stop| *stop).await.is_ok();
+    if !requested {
+        std::future::pending::<()>().await;
+    }
+}
+
 #[cfg(feature = "stdio")]
 pub mod stdio;
 
@@ -1,15 +1,124 @@
 //! STDIO transport implementation.
 //!
 //! Provides line-based JSON-RPC over stdin/stdout.
+//!
+//! Stdin is read on a dedicated OS thread that feeds the async side through a
+//! bounded channel ([`BlockingReader`]), so a blocked or idle stdin never ties
+//! up a runtime worker.
+
+use std::io::{self, Read};
+use std::pin::Pin;
+use std::task::{Context, Poll, ready};
 
-use tokio::io::BufReader;
-use turbomcp_core::error::McpResult;
+use tokio::io::{AsyncRead, BufReader, ReadBuf};
+use tokio::sync::{mpsc, watch};
+use turbomcp_core::error::{McpError, McpResult};
 use turbomcp_core::handler::McpHandler;
 
 use super::line::LineTransportRunner;
-use crate::config::ServerConfig;
+use crate::config::{DEFAULT_STDIO_BUFFER_DEPTH, ServerConfig};
 use crate::context::RequestContext;
 
+/// Size of each read issued by the reader thread.
+const READ_CHUNK_SIZE: usize = 8 * 1024;
+
+/// Async reader fed by a dedicated blocking reader thread.
+///
+/// The thread performs blocking reads on the wrapped reader and forwards each
+/// chunk through a bounded channel of `depth` chunks. When the channel is full
+/// the thread blocks, applying back-pressure to the source instead of
+/// buffering without limit. Dropping the `BlockingReader` stops the thread
+/// after its current read returns.
+#[derive(Debug)]
+pub struct BlockingReader {
+    rx: mpsc::Receiver<io::Result<Vec<u8>>>,
+    chunk: Vec<u8>,
+    pos: usize,
+}
+
+impl BlockingReader {
+    /// Spawn a reader thread over `reader` with a channel of `depth` chunks.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the OS refuses to spawn the thread.
+    pub fn spawn<R>(reader: R, depth: usize) -> io::Result<Self>
+    where
+        R: Read + Send + 'static,
+    {
+        let (tx, rx) = mpsc::channel(depth.max(1));
+        std::thread::Builder::new()
+            .name("turbomcp-stdin".to_string())
+            .spawn(move || read_loop(reader, &tx))?;
+
+        Ok(Self {
+            rx,
+            chunk: Vec::new(),
+            pos: 0,
+        })
+    }
+
+    /// Spawn a reader thread over the process's stdin.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the OS refuses to spawn the thread.
+    pub fn stdin(depth: usize) -> io::Result<Self> {
+        Self::spawn(io::stdin(), depth)
+    }
+}
+
+fn read_loop<R: Read>(mut reader: R, tx: &mpsc::Sender<io::Result<Vec<u8>>>) {
+    let mut buf = vec![0u8; READ_CHUNK_SIZE];
+    loop {
+        match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => {
+                if tx.blocking_send(Ok(buf[..n].to_vec())).is_err() {
+                    break;
+                }
+            }
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => {
+                let _ = tx.blocking_send(Err(e));
+                break;
+            }
+        }
+    }
+}
+
+impl AsyncRead for BlockingReader {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        if self.pos >= self.chunk.len() {
+            match ready!(self.rx.poll_recv(cx)) {
+                Some(Ok(chunk)) => {
+                    self.chunk = chunk;
+                    self.pos = 0;
+                }
+                Some(Err(e)) => return Poll::Ready(Err(e)),
+                // Reader thread finished: EOF.
+                None => return Poll::Ready(Ok(())),
+            }
+        }
+
+        let this = &mut *self;
+        let n = buf.remaining().min(this.chunk.len() - this.pos);
+        buf.put_slice(&this.chunk[this.pos..this.pos + n]);
+        this.pos += n;
+        Poll::Ready(Ok(()))
+    }
+}
+
+fn stdin_reader(depth: usize) -> McpResult<BufReader<BlockingReader>> {
+    BlockingReader::stdin(depth)
+        .map(BufReader::new)
+        .map_err(|e| McpError::internal(format!("Failed to spawn stdin reader thread: {e}")))
+}
+
 /// Run a handler on STDIO transport.
 ///
 /// This is the default transport for MCP servers, reading JSON-RPC
@@ -29,9 +138,8 @@ pub async fn run<H: McpHandler>(handler: &H) -> McpResult<()> {
     // Call lifecycle hooks
     handler.on_initialize().await?;
 
-    let stdin = tokio::io::stdin();
+    let reader = stdin_reader(DEFAULT_STDIO_BUFFER_DEPTH)?;
     let stdout = tokio::io::stdout();
-    let reader = BufReader::new(stdin);
 
     let runner = LineTransportRunner::new(handler.clone());
     let result = runner.run(reader, stdout, RequestContext::stdio).await;
@@ -63,9 +171,8 @@ pub async fn run_with_config<H: McpHandler>(handler: &H, config: &ServerConfig)
     // Call lifecycle hooks
     handler.on_initialize().await?;
 
-    let stdin = tokio::io::stdin();
+    let reader = stdin_reader(config.stdio_buffer_depth)?;
     let stdout = tokio::io::stdout();
-    let reader = BufReader::new(stdin);
 
     let runner = LineTransportRunner::with_config(handler.clone(), config.clone());
     let result = runner.run(reader, stdout, RequestContext::stdio).await;
@@ -76,8 +183,104 @@ pub async fn run_with_config<H: McpHandler>(handler: &H, config: &ServerConfig)
     result
 }
 
+/// Run a handler on STDIO transport until end of input or until `shutdown`
+/// receives `true`.
+///
+/// On shutdown, stdin is no longer read and responses to requests already
+/// in flight are written before the shutdown hook runs.
+pub async fn run_with_shutdown<H: McpHandler>(
+    handler: &H,
+    config: &ServerConfig,
+    shutdown: watch::Receiver<bool>,
+) -> McpResult<()> {
+    // Call lifecycle hooks
+    handler.on_initialize().await?;
+
+    let reader = stdin_reader(config.stdio_buffer_depth)?;
+    let stdout = tokio::io::stdout();
+
+    let runner =
+        LineTransportRunner::with_config(handler.clone(), config.clone()).with_shutdown(shutdown);
+    let result = runner.run(reader, stdout, RequestContext::stdio).await;
+
+    // Call shutdown hook regardless of result
+    handler.on_shutdown().await?;
+
+    result
+}
+
 #[cfg(test)]
 mod tests {
-    // STDIO tests require actual stdin/stdout, so they're integration tests
-    // See /tests/integration_test.rs for STDIO tests
+    // End-to-end STDIO tests require actual stdin/stdout, so they're
+    // integration tests. See /tests/integration_test.rs for STDIO tests.
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, mpsc as std_mpsc};
+    use std::time::Duration;
+    use tokio::io::AsyncBufReadExt;
+
+    /// A blocking reader whose `read` parks until the test sends bytes.
+    struct ParkedReader(std_mpsc::Receiver<Vec<u8>>);
+
+    impl Read for ParkedReader {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match self.0.recv() {
+                Ok(bytes) => {
+                    buf[..bytes.len()].copy_from_slice(&bytes);
+                    Ok(bytes.len())
+                }
+                Err(_) => Ok(0),
+            }
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_blocked_stdin_does_not_starve_runtime() {
+        let (tx, rx) = std_mpsc::channel();
+        let mut reader = BufReader::new(BlockingReader::spawn(ParkedReader(rx), 4).unwrap());
+
+        let reading = tokio::spawn(async move {
+            let mut line = String::new();
+            reader.read_line(&mut line).await.unwrap();
+            line
+        });
+
+        // On a single-threaded runtime, a read that blocked the runtime thread
+        // would prevent these ticks from ever running.
+        let ticks = Arc::new(AtomicUsize::new(0));
+        let ticker = {
+            let ticks = Arc::clone(&ticks);
+            tokio::spawn(async move {
+                for _ in 0..5 {
+                    tokio::time::sleep(Duration::from_millis(5)).await;
+                    ticks.fetch_add(1, Ordering::SeqCst);
+                }
+            })
+        };
+        tokio::time::timeout(Duration::from_secs(2), ticker)
+            .await
+            .expect("async work starved while stdin was blocked")
+            .unwrap();
+        assert_eq!(ticks.load(Ordering::SeqCst), 5);
+        assert!(!reading.is_finished());
+
+        tx.send(b"{\"jsonrpc\":\"2.0\"}\n".to_vec()).unwrap();
+        let line = tokio::time::timeout(Duration::from_secs(2), reading)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(line, "{\"jsonrpc\":\"2.0\"}\n");
+    }
+
+    #[tokio::test]
+    async fn test_blocking_reader_reports_eof() {
+        let mut reader =
+            BufReader::new(BlockingReader::spawn(io::Cursor::new(b"a\nb\n".to_vec()), 1).unwrap());
+        let mut lines = Vec::new();
+        let mut line = String::new();
+        while reader.read_line(&mut line).await.unwrap() > 0 {
+            lines.push(std::mem::take(&mut line));
+        }
+        assert_eq!(lines, vec!["a\n", "b\n"]);
+    }
 }
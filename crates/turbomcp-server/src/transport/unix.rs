@@ -7,6 +7,7 @@ use std::sync::Arc;
 use tokio::io::BufReader;
 use tokio::net::UnixListener;
 use tokio::sync::watch;
+use tokio::task::JoinSet;
 use turbomcp_core::error::{McpError, McpResult};
 use turbomcp_core::handler::McpHandler;
 
@@ -49,10 +50,9 @@ pub async fn run_with_config<H: McpHandler>(
 
     // Set up signal handling for graceful shutdown
     let signal_task = tokio::spawn(async move {
-        if let Ok(()) = tokio::signal::ctrl_c().await {
-            tracing::info!("Received shutdown signal, stopping Unix socket server...");
-            let _ = shutdown_tx.send(true);
-        }
+        super::shutdown_signal().await;
+        tracing::info!("Received shutdown signal, stopping Unix socket server...");
+        let _ = shutdown_tx.send(true);
     });
 
     let result = run_with_shutdown(handler, path, config, shutdown_rx).await;
@@ -63,6 +63,9 @@ pub async fn run_with_config<H: McpHandler>(
 
 /// Run a handler on Unix socket transport with explicit shutdown signal.
 ///
+/// On shutdown the listener closes and open connections stop reading; each
+/// writes the responses to requests already in flight before it closes.
+///
 /// # Arguments
 ///
 /// * `handler` - The MCP handler
@@ -90,7 +93,7 @@ pub async fn run_with_shutdown<H: McpHandler>(
     handler: &H,
     path: &str,
     config: &ServerConfig,
-    mut shutdown: watch::Receiver<bool>,
+    shutdown: watch::Receiver<bool>,
 ) -> McpResult<()> {
     // Call lifecycle hooks
     handler.on_initialize().await?;
@@ -118,16 +121,21 @@ pub async fn run_with_shutdown<H: McpHandler>(
         max_connections
     );
 
+    let mut connections = JoinSet::new();
+    let stopped = super::shutdown_requested(shutdown.clone());
+    tokio::pin!(stopped);
+
     loop {
         tokio::select! {
             // Check for shutdown signal
-            _ = shutdown.changed() => {
-                if *shutdown.borrow() {
-                    tracing::info!("Unix socket server shutting down...");
-                    break;
-                }
+            () = &mut stopped => {
+                tracing::info!("Unix socket server shutting down...");
+                break;
             }
 
+            // Reap closed connections
+            Some(_) = connections.join_next(), if !connections.is_empty() => {}
+
             // Accept new connections
             accept_result = listener.accept() => {
                 let (stream, _) = accept_result
@@ -157,14 +165,20 @@ pub async fn run_with_shutdown<H: McpHandler>(
                 // Spawn handler task
                 let handler = handler.clone();
                 let conn_config = config.clone();
-                tokio::spawn(async move {
+                let conn_shutdown = shutdown.clone();
+                connections.spawn(async move {
                     // Guard dropped when task completes, releasing connection slot
                     let _guard = guard;
 
                     let (reader, writer) = stream.into_split();
                     let reader = BufReader::new(reader);
 
-                    let runner = LineTransportRunner::with_config(handler, conn_config);
+                    let limits = &conn_config.connection_limits;
+                    let (max_lifetime, idle_timeout) =
+                        (limits.max_connection_lifetime, limits.idle_timeout);
+                    let runner = LineTransportRunner::with_config(handler, conn_config)
+                        .with_connection_timeouts(max_lifetime, idle_timeout)
+                        .with_shutdown(conn_shutdown);
                     if let Err(e) = runner.run(reader, writer, RequestContext::unix).await {
                         tracing::error!("Unix socket connection error: {}", e);
                     }
@@ -175,6 +189,10 @@ pub async fn run_with_shutdown<H: McpHandler>(
         }
     }
 
+    // Let open connections finish the requests they are handling.
+    drop(listener);
+    while connections.join_next().await.is_some() {}
+
     // Clean up socket file
     if std::path::Path::new(&socket_path).exists() {
         let _ = std::fs::remove_file(&socket_path);
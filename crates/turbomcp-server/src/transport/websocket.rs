@@ -10,25 +10,33 @@
 //! - Duplicate `initialize` requests are rejected.
 //! - Post-initialize requests are routed through `route_request_versioned`,
 //!   which applies the negotiated `ProtocolVersion` adapter for response filtering.
+//!
+//! Initialized connections are registered with the configured
+//! [`NotificationBroadcaster`](crate::NotificationBroadcaster), so broadcasts
+//! reach WebSocket clients too.
 
 use std::net::SocketAddr;
 use std::sync::Arc;
 
 use axum::Router;
-use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::ws::{CloseFrame, Message, WebSocket, WebSocketUpgrade, close_code};
 use axum::http::{HeaderMap, StatusCode};
 use axum::routing::get;
 use dashmap::DashMap;
 use futures::{SinkExt, StreamExt};
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, watch};
 use tokio_util::sync::CancellationToken;
+use tracing::Instrument;
 use turbomcp_core::error::{McpError, McpResult};
 use turbomcp_core::handler::McpHandler;
 use turbomcp_types::ProtocolVersion;
 
-use super::SessionState;
-use crate::config::{ConnectionCounter, RateLimiter, ServerConfig};
-use crate::context::{Cancellable, RequestContext};
+use super::{ConnectionTimers, SessionState};
+use crate::config::{
+    ConnectionCounter, DEFAULT_WRITE_HIGH_WATER_MARK, DuplicateRequestIds, RateLimiter,
+    ServerConfig,
+};
+use crate::context::{Cancellable, McpSession, RequestContext, SessionFuture};
 use crate::router::{self, JsonRpcOutgoing};
 use crate::transport::line::jsonrpc_id_key;
 use turbomcp_transport::security::{
@@ -38,6 +46,38 @@ use turbomcp_transport::security::{
 /// Maximum WebSocket message size (10MB).
 const MAX_MESSAGE_SIZE: usize = 10 * 1024 * 1024;
 
+/// Server-to-client notifications for one WebSocket connection.
+///
+/// Only notifications are supported; the WebSocket transport does not track
+/// responses to server-initiated requests.
+#[derive(Debug)]
+struct WebSocketSession {
+    notify_tx: mpsc::Sender<(String, serde_json::Value)>,
+}
+
+impl McpSession for WebSocketSession {
+    fn call<'a>(
+        &'a self,
+        method: &'a str,
+        _params: serde_json::Value,
+    ) -> SessionFuture<'a, serde_json::Value> {
+        Box::pin(async move {
+            Err(McpError::capability_not_supported(format!(
+                "{method} over WebSocket"
+            )))
+        })
+    }
+
+    fn notify<'a>(&'a self, method: &'a str, params: serde_json::Value) -> SessionFuture<'a, ()> {
+        Box::pin(async move {
+            self.notify_tx
+                .send((method.to_string(), params))
+                .await
+                .map_err(|_| McpError::internal("Session closed"))
+        })
+    }
+}
+
 /// Run a handler on WebSocket transport.
 ///
 /// # Arguments
@@ -67,6 +107,35 @@ pub async fn run_with_config<H: McpHandler>(
     handler: &H,
     addr: &str,
     config: &ServerConfig,
+) -> McpResult<()> {
+    run_until(handler, addr, config, std::future::pending()).await
+}
+
+/// Run a handler on WebSocket transport until `shutdown` receives `true`.
+///
+/// On shutdown the server stops accepting connections; connections that are
+/// already upgraded keep running until their clients close them.
+///
+/// # Arguments
+///
+/// * `handler` - The MCP handler
+/// * `addr` - Address to bind to
+/// * `config` - Server configuration (rate limits, connection limits, etc.)
+/// * `shutdown` - Watch receiver that triggers shutdown when `true` is received
+pub async fn run_with_shutdown<H: McpHandler>(
+    handler: &H,
+    addr: &str,
+    config: &ServerConfig,
+    shutdown: watch::Receiver<bool>,
+) -> McpResult<()> {
+    run_until(handler, addr, config, super::shutdown_requested(shutdown)).await
+}
+
+async fn run_until<H: McpHandler>(
+    handler: &H,
+    addr: &str,
+    config: &ServerConfig,
+    shutdown: impl Future<Output = ()> + Send + 'static,
 ) -> McpResult<()> {
     // Call lifecycle hooks
     handler.on_initialize().await?;
@@ -123,6 +192,7 @@ pub async fn run_with_config<H: McpHandler>(
         listener,
         app.into_make_service_with_connect_info::<SocketAddr>(),
     )
+    .with_graceful_shutdown(shutdown)
     .await
     .map_err(|e| McpError::internal(format!("Server error: {}", e)))?;
 
@@ -249,24 +319,92 @@ async fn handle_websocket<H: McpHandler>(
     let max_message_size = config
         .as_ref()
         .map_or(MAX_MESSAGE_SIZE, |config| config.max_message_size);
+    let jsonrpc_strictness = config
+        .as_ref()
+        .map(|config| config.jsonrpc_strictness)
+        .unwrap_or_default();
+    let elicitation_fallback = config
+        .as_ref()
+        .map_or_else(Default::default, |config| config.elicitation_fallback);
+    let duplicate_request_ids = config
+        .as_ref()
+        .map(|config| config.duplicate_request_ids)
+        .unwrap_or_default();
+    let client_request_timeout = config
+        .as_ref()
+        .and_then(|config| config.client_request_timeout);
+    let new_ctx = || {
+        let mut ctx = RequestContext::websocket()
+            .with_max_message_size(max_message_size)
+            .with_elicitation_fallback(elicitation_fallback);
+        ctx.client_request_timeout = client_request_timeout;
+        ctx
+    };
     let (mut sender, mut receiver) = socket.split();
 
     // Per-connection MCP session lifecycle state.
     let mut session_state = SessionState::Uninitialized;
 
+    // Broadcast notifications, queued up to the write high-water mark.
+    let write_high_water_mark = config
+        .as_ref()
+        .map_or(DEFAULT_WRITE_HIGH_WATER_MARK, |config| {
+            config.connection_limits.write_high_water_mark
+        })
+        .max(1);
+    let (notify_tx, mut notify_rx) = mpsc::channel(write_high_water_mark);
+    let session = Arc::new(WebSocketSession { notify_tx });
+    // Keeps this connection registered for notification broadcasts.
+    let mut _broadcast_registration = None;
+
     // Channel for handler responses produced by spawned tasks.
     let (response_tx, mut response_rx) = mpsc::channel::<JsonRpcOutgoing>(32);
 
     // In-flight handler cancellation tokens, keyed by JSON-RPC id; signalled
     // by `notifications/cancelled` per MCP 2025-11-25.
     let pending_handlers: Arc<DashMap<String, CancellationToken>> = Arc::new(DashMap::new());
+    // Serializes requests that reuse an in-flight ID, when configured.
+    let request_queue =
+        (duplicate_request_ids == DuplicateRequestIds::Queue).then(super::RequestIdQueue::default);
+
+    let mut timers = ConnectionTimers::new(
+        config
+            .as_ref()
+            .and_then(|config| config.connection_limits.max_connection_lifetime),
+        config
+            .as_ref()
+            .and_then(|config| config.connection_limits.idle_timeout),
+    );
 
     loop {
         tokio::select! {
             biased;
 
+            // Connection recycling: max lifetime / idle timeout.
+            reason = timers.expired() => {
+                if !timers.should_close(reason, !pending_handlers.is_empty()) {
+                    continue;
+                }
+                tracing::info!(
+                    client = %client_addr,
+                    reason = reason.as_str(),
+                    "Closing WebSocket connection"
+                );
+                for handler in pending_handlers.iter() {
+                    handler.value().cancel();
+                }
+                let _ = sender
+                    .send(Message::Close(Some(CloseFrame {
+                        code: close_code::NORMAL,
+                        reason: reason.as_str().into(),
+                    })))
+                    .await;
+                break;
+            }
+
             // Outgoing: completed handler responses.
             Some(response) = response_rx.recv() => {
+                timers.touch();
                 if response.should_send()
                     && let Ok(response_str) = router::serialize_response(&response)
                     && sender.send(Message::Text(response_str.into())).await.is_err()
@@ -277,9 +415,29 @@ async fn handle_websocket<H: McpHandler>(
                 continue;
             }
 
+            // Outgoing: broadcast notifications.
+            Some((method, params)) = notify_rx.recv() => {
+                timers.touch();
+                let notification = serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "method": method,
+                    "params": params
+                });
+                if sender
+                    .send(Message::Text(notification.to_string().into()))
+                    .await
+                    .is_err()
+                {
+                    tracing::error!("Failed to send WebSocket notification");
+                    break;
+                }
+                continue;
+            }
+
             // Incoming: client → server frames.
             maybe_msg = receiver.next() => {
                 let Some(msg) = maybe_msg else { break };
+                timers.touch();
                 let msg = match msg {
                     Ok(msg) => msg,
                     Err(e) => {
@@ -319,7 +477,7 @@ async fn handle_websocket<H: McpHandler>(
                     continue;
                 }
 
-                let parsed = match router::parse_request(&text) {
+                let mut parsed = match router::parse_request_with_strictness(&text, jsonrpc_strictness) {
                     Ok(req) => req,
                     Err(e) => {
                         let error = JsonRpcOutgoing::error(
@@ -332,11 +490,22 @@ async fn handle_websocket<H: McpHandler>(
                         continue;
                     }
                 };
+                if let Some(config) = config.as_ref()
+                    && let Err(e) = config.meta_allowlist.apply(parsed.params.as_mut())
+                {
+                    if !parsed.is_notification() {
+                        let error = JsonRpcOutgoing::error(parsed.id.clone(), e);
+                        if let Ok(error_str) = router::serialize_response(&error) {
+                            let _ = sender.send(Message::Text(error_str.into())).await;
+                        }
+                    }
+                    continue;
+                }
 
                 // `initialize` mutates `session_state`, so it must run inline
                 // on the loop task.
                 if parsed.method == "initialize" {
-                    let ctx = RequestContext::websocket();
+                    let ctx = new_ctx();
                     let response = if matches!(session_state, SessionState::Initialized(_)) {
                         JsonRpcOutgoing::error(
                             parsed.id.clone(),
@@ -344,6 +513,7 @@ async fn handle_websocket<H: McpHandler>(
                         )
                     } else {
                         let initialize_request_id = parsed.id.clone();
+                        let initialize_params = parsed.params.clone();
                         let resp = router::route_request_with_config(
                             &handler,
                             parsed,
@@ -361,11 +531,22 @@ async fn handle_websocket<H: McpHandler>(
                                 client = %client_addr,
                                 "Protocol version negotiated"
                             );
+                            if let Some(config) = config.as_ref() {
+                                _broadcast_registration =
+                                    Some(config.notification_broadcaster.register(
+                                        session.clone(),
+                                        super::initialize_client_from_params(
+                                            initialize_params.as_ref(),
+                                            version.clone(),
+                                        ),
+                                    ));
+                            }
                             session_state = SessionState::Initialized(
                                 super::InitializedSessionState::new(
                                     version,
                                     initialize_request_id.as_ref(),
-                                ),
+                                )
+                                .with_duplicate_request_ids(duplicate_request_ids),
                             );
                         }
                         resp
@@ -413,7 +594,7 @@ async fn handle_websocket<H: McpHandler>(
                 // `notifications/initialized` is a lifecycle no-op (no id, no
                 // response). Route it inline since there's nothing to spawn.
                 if parsed.method == "notifications/initialized" {
-                    let ctx = RequestContext::websocket();
+                    let ctx = new_ctx();
                     let _ = router::route_request(&handler, parsed, &ctx).await;
                     continue;
                 }
@@ -422,7 +603,7 @@ async fn handle_websocket<H: McpHandler>(
                     && matches!(session_state, SessionState::Uninitialized)
                 {
                     // Lifecycle permits ping before initialize has completed.
-                    let ctx = RequestContext::websocket();
+                    let ctx = new_ctx();
                     let response = router::route_request(&handler, parsed, &ctx).await;
                     if response.should_send()
                         && let Ok(response_str) = router::serialize_response(&response)
@@ -485,26 +666,33 @@ async fn handle_websocket<H: McpHandler>(
                 let resp_tx = response_tx.clone();
                 let token = CancellationToken::new();
                 let cancel_key = parsed.id.as_ref().map(jsonrpc_id_key);
-                if let Some(ref key) = cancel_key {
-                    pending_handlers.insert(key.clone(), token.clone());
-                }
-                let ctx = RequestContext::websocket()
-                    .with_cancellation_token(Arc::new(token) as Arc<dyn Cancellable>);
-                let guard = super::PendingHandlerGuard::new(
-                    Arc::clone(&pending_handlers),
+                let admission = super::HandlerAdmission::new(
+                    &pending_handlers,
+                    request_queue.as_ref(),
                     cancel_key,
+                    &token,
+                );
+                let ctx = new_ctx()
+                    .with_cancellation_token(Arc::new(token) as Arc<dyn Cancellable>);
+                let span = super::request_span(&parsed);
+
+                tokio::spawn(
+                    async move {
+                        // Waits behind an in-flight request with the same ID
+                        // when queueing; RAII cleanup runs on every exit path,
+                        // including handler panic.
+                        let _turn = admission.ready().await;
+                        let response = router::route_request_versioned(
+                            &handler_clone,
+                            parsed,
+                            &ctx,
+                            &version,
+                        )
+                        .await;
+                        let _ = resp_tx.send(response).await;
+                    }
+                    .instrument(span),
                 );
-
-                tokio::spawn(async move {
-                    // RAII cleanup runs on every exit path, including handler
-                    // panic.
-                    let _guard = guard;
-                    let response = router::route_request_versioned(
-                        &handler_clone, parsed, &ctx, &version,
-                    )
-                    .await;
-                    let _ = resp_tx.send(response).await;
-                });
             }
         }
     }
@@ -0,0 +1,153 @@
+//! Connection limits on socket transports: connections past
+//! `ConnectionLimits::max_tcp_connections` are refused, and a slot frees up as
+//! soon as an open connection closes.
+
+#![cfg(feature = "tcp")]
+
+use std::time::Duration;
+
+use serde_json::{Value, json};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tokio::sync::watch;
+use turbomcp_core::context::RequestContext;
+use turbomcp_core::error::{McpError, McpResult};
+use turbomcp_core::handler::McpHandler;
+use turbomcp_server::{ServerBuilder, Transport};
+use turbomcp_types::{
+    Prompt, PromptResult, Resource, ResourceResult, ServerInfo, Tool, ToolResult,
+};
+
+#[derive(Clone)]
+struct EmptyHandler;
+
+impl McpHandler for EmptyHandler {
+    fn server_info(&self) -> ServerInfo {
+        ServerInfo::new("connection-limits-test", "1.0.0")
+    }
+
+    fn list_tools(&self) -> Vec<Tool> {
+        vec![]
+    }
+
+    fn list_resources(&self) -> Vec<Resource> {
+        vec![]
+    }
+
+    fn list_prompts(&self) -> Vec<Prompt> {
+        vec![]
+    }
+
+    fn call_tool<'a>(
+        &'a self,
+        name: &'a str,
+        _args: Value,
+        _ctx: &'a RequestContext,
+    ) -> impl std::future::Future<Output = McpResult<ToolResult>> + Send + 'a {
+        let name = name.to_string();
+        async move { Err(McpError::tool_not_found(&name)) }
+    }
+
+    fn read_resource<'a>(
+        &'a self,
+        uri: &'a str,
+        _ctx: &'a RequestContext,
+    ) -> impl std::future::Future<Output = McpResult<ResourceResult>> + Send + 'a {
+        let uri = uri.to_string();
+        async move { Err(McpError::resource_not_found(&uri)) }
+    }
+
+    fn get_prompt<'a>(
+        &'a self,
+        name: &'a str,
+        _args: Option<Value>,
+        _ctx: &'a RequestContext,
+    ) -> impl std::future::Future<Output = McpResult<PromptResult>> + Send + 'a {
+        let name = name.to_string();
+        async move { Err(McpError::prompt_not_found(&name)) }
+    }
+}
+
+async fn free_tcp_addr() -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    listener.local_addr().unwrap().to_string()
+}
+
+async fn connect_tcp(addr: &str) -> TcpStream {
+    for _ in 0..100 {
+        if let Ok(stream) = TcpStream::connect(addr).await {
+            return stream;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    panic!("tcp transport never started listening on {addr}");
+}
+
+/// Send `initialize` and return the first message the server writes back.
+async fn initialize(stream: &mut TcpStream) -> Value {
+    let request = json!({
+        "jsonrpc": "2.0", "id": 0, "method": "initialize",
+        "params": {
+            "protocolVersion": "2025-11-25",
+            "clientInfo": { "name": "test-client", "version": "1.0.0" },
+            "capabilities": {}
+        }
+    });
+    // A refused connection may already be closed for writing.
+    let _ = stream.write_all(format!("{request}\n").as_bytes()).await;
+
+    let mut line = String::new();
+    BufReader::new(stream).read_line(&mut line).await.unwrap();
+    serde_json::from_str(&line).expect("server replies with a JSON-RPC message")
+}
+
+fn is_capacity_refusal(response: &Value) -> bool {
+    response["id"].is_null()
+        && response["error"]["message"]
+            .as_str()
+            .is_some_and(|message| message.contains("maximum capacity"))
+}
+
+#[tokio::test]
+async fn tcp_refuses_connections_past_the_limit() {
+    let addr = free_tcp_addr().await;
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+    let server = tokio::spawn(
+        ServerBuilder::new(EmptyHandler)
+            .with_connection_limit(2)
+            .serve_multi_with_shutdown(vec![Transport::tcp(addr.clone())], shutdown_rx),
+    );
+
+    // Fill both slots with live sessions.
+    let mut first = connect_tcp(&addr).await;
+    assert!(initialize(&mut first).await["result"].is_object());
+    let mut second = connect_tcp(&addr).await;
+    assert!(initialize(&mut second).await["result"].is_object());
+
+    let mut refused = connect_tcp(&addr).await;
+    let response = initialize(&mut refused).await;
+    assert!(is_capacity_refusal(&response), "{response}");
+
+    // Closing a session frees its slot once the server notices the EOF.
+    drop(first);
+    let mut admitted = false;
+    for _ in 0..50 {
+        let mut next = connect_tcp(&addr).await;
+        let response = initialize(&mut next).await;
+        if response["result"].is_object() {
+            admitted = true;
+            break;
+        }
+        assert!(is_capacity_refusal(&response), "{response}");
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert!(admitted, "slot was not released after a connection closed");
+
+    drop(second);
+    shutdown_tx.send(true).unwrap();
+    tokio::time::timeout(Duration::from_secs(5), server)
+        .await
+        .expect("server stops on shutdown")
+        .unwrap()
+        .unwrap();
+}
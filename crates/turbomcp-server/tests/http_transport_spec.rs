@@ -132,6 +132,60 @@ impl McpHandler for SamplingHandler {
     }
 }
 
+/// Size of the text returned by [`LargeResultHandler`], well above the
+/// compression layer's minimum body size.
+const LARGE_TEXT_LEN: usize = 64 * 1024;
+
+#[derive(Clone)]
+struct LargeResultHandler;
+
+impl McpHandler for LargeResultHandler {
+    fn server_info(&self) -> ServerInfo {
+        ServerInfo::new("large-result-http-test", "1.0.0")
+    }
+
+    fn list_tools(&self) -> Vec<Tool> {
+        vec![Tool::new("large_text", "Return a large text payload")]
+    }
+
+    fn list_resources(&self) -> Vec<Resource> {
+        Vec::new()
+    }
+
+    fn list_prompts(&self) -> Vec<Prompt> {
+        Vec::new()
+    }
+
+    async fn call_tool(
+        &self,
+        name: &str,
+        _args: serde_json::Value,
+        _ctx: &CoreRequestContext,
+    ) -> McpResult<ToolResult> {
+        if name != "large_text" {
+            return Err(McpError::tool_not_found(name));
+        }
+        Ok(ToolResult::text("turbomcp ".repeat(LARGE_TEXT_LEN / 9)))
+    }
+
+    async fn read_resource(
+        &self,
+        uri: &str,
+        _ctx: &CoreRequestContext,
+    ) -> McpResult<ResourceResult> {
+        Err(McpError::resource_not_found(uri))
+    }
+
+    async fn get_prompt(
+        &self,
+        name: &str,
+        _args: Option<serde_json::Value>,
+        _ctx: &CoreRequestContext,
+    ) -> McpResult<PromptResult> {
+        Err(McpError::prompt_not_found(name))
+    }
+}
+
 async fn spawn_server() -> (String, JoinHandle<()>) {
     let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
     let addr = listener.local_addr().unwrap();
@@ -176,6 +230,22 @@ async fn spawn_server_with_config(config: ServerConfig) -> (String, JoinHandle<(
     (format!("http://{}", addr), handle)
 }
 
+async fn spawn_large_result_server(config: ServerConfig) -> (String, JoinHandle<()>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    drop(listener);
+
+    let addr_string = addr.to_string();
+    let handle = tokio::spawn(async move {
+        http::run_with_config(&LargeResultHandler, &addr_string, &config)
+            .await
+            .unwrap();
+    });
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    (format!("http://{}", addr), handle)
+}
+
 fn initialize_request() -> serde_json::Value {
     initialize_request_with_capabilities(json!({}))
 }
@@ -1053,3 +1123,86 @@ async fn duplicate_request_ids_are_rejected() {
 
     handle.abort();
 }
+
+async fn call_large_text(
+    client: &Client,
+    base_url: &str,
+    session_id: &str,
+    id: u64,
+    accept_encoding: Option<&str>,
+) -> reqwest::Response {
+    let mut request = client
+        .post(format!("{}/mcp", base_url))
+        .header(header::ACCEPT, "application/json, text/event-stream")
+        .header("Mcp-Session-Id", session_id)
+        .header("MCP-Protocol-Version", "2025-11-25");
+    if let Some(encoding) = accept_encoding {
+        request = request.header(header::ACCEPT_ENCODING, encoding);
+    }
+
+    let response = request
+        .json(&json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "method": "tools/call",
+            "params": {
+                "name": "large_text",
+                "arguments": {}
+            }
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    response
+}
+
+#[tokio::test]
+async fn large_response_is_gzip_encoded_when_client_accepts_it() {
+    let (base_url, handle) = spawn_large_result_server(ServerConfig::default()).await;
+    // Raw client: sends our Accept-Encoding verbatim and leaves the body encoded.
+    let raw = Client::builder().gzip(false).build().unwrap();
+    let session_id = initialize_session(&raw, &base_url).await;
+
+    let encoded = call_large_text(&raw, &base_url, &session_id, 2, Some("gzip")).await;
+    assert_eq!(
+        encoded
+            .headers()
+            .get(header::CONTENT_ENCODING)
+            .and_then(|value| value.to_str().ok()),
+        Some("gzip")
+    );
+    let encoded_len = encoded.bytes().await.unwrap().len();
+    assert!(
+        encoded_len < LARGE_TEXT_LEN,
+        "gzip body should be smaller than the payload, got {encoded_len} bytes"
+    );
+
+    // Decoding client: advertises gzip itself and transparently inflates.
+    let decoding = Client::builder().gzip(true).build().unwrap();
+    let decoded: serde_json::Value = call_large_text(&decoding, &base_url, &session_id, 3, None)
+        .await
+        .json()
+        .await
+        .unwrap();
+    let text = decoded["result"]["content"][0]["text"].as_str().unwrap();
+    assert!(text.len() >= LARGE_TEXT_LEN - 9);
+    assert!(text.starts_with("turbomcp turbomcp"));
+
+    handle.abort();
+}
+
+#[tokio::test]
+async fn response_compression_can_be_disabled() {
+    let config = ServerConfig::builder().http_compression(false).build();
+    let (base_url, handle) = spawn_large_result_server(config).await;
+    let raw = Client::builder().gzip(false).build().unwrap();
+    let session_id = initialize_session(&raw, &base_url).await;
+
+    let response = call_large_text(&raw, &base_url, &session_id, 2, Some("gzip, zstd")).await;
+    assert!(response.headers().get(header::CONTENT_ENCODING).is_none());
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert!(body["result"]["content"][0]["text"].is_string());
+
+    handle.abort();
+}
@@ -0,0 +1,280 @@
+//! Serving several transports from one `ServerBuilder`: the same handler, and
+//! therefore the same tools and state, must be reachable through each of them,
+//! and shutdown must drain them together.
+
+#![cfg(all(feature = "tcp", feature = "unix", unix))]
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use serde_json::{Value, json};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::sync::watch;
+use turbomcp_core::context::RequestContext;
+use turbomcp_core::error::{McpError, McpResult};
+use turbomcp_core::handler::McpHandler;
+use turbomcp_server::{DrainCoordinator, ServerBuilder, Transport};
+use turbomcp_types::{
+    Prompt, PromptResult, Resource, ResourceResult, ServerInfo, Tool, ToolResult,
+};
+
+/// Counts `hit` calls across every transport it is served on; `slow` calls
+/// take 300ms and are counted as they start and finish.
+#[derive(Clone, Default)]
+struct CounterHandler {
+    hits: Arc<AtomicU64>,
+    slow_started: Arc<AtomicU64>,
+    slow_finished: Arc<AtomicU64>,
+}
+
+#[allow(clippy::manual_async_fn)]
+impl McpHandler for CounterHandler {
+    fn server_info(&self) -> ServerInfo {
+        ServerInfo::new("multi-transport-test", "1.0.0")
+    }
+
+    fn list_tools(&self) -> Vec<Tool> {
+        vec![
+            Tool::new("hit", "Increment and return the shared counter"),
+            Tool::new("slow", "Finish after 300ms"),
+        ]
+    }
+
+    fn list_resources(&self) -> Vec<Resource> {
+        vec![]
+    }
+
+    fn list_prompts(&self) -> Vec<Prompt> {
+        vec![]
+    }
+
+    fn call_tool<'a>(
+        &'a self,
+        name: &'a str,
+        _args: Value,
+        _ctx: &'a RequestContext,
+    ) -> impl std::future::Future<Output = McpResult<ToolResult>> + Send + 'a {
+        async move {
+            match name {
+                "hit" => {
+                    let hits = self.hits.fetch_add(1, Ordering::SeqCst) + 1;
+                    Ok(ToolResult::text(hits.to_string()))
+                }
+                "slow" => {
+                    self.slow_started.fetch_add(1, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(300)).await;
+                    self.slow_finished.fetch_add(1, Ordering::SeqCst);
+                    Ok(ToolResult::text("done"))
+                }
+                _ => Err(McpError::tool_not_found(name)),
+            }
+        }
+    }
+
+    fn read_resource<'a>(
+        &'a self,
+        uri: &'a str,
+        _ctx: &'a RequestContext,
+    ) -> impl std::future::Future<Output = McpResult<ResourceResult>> + Send + 'a {
+        let uri = uri.to_string();
+        async move { Err(McpError::resource_not_found(&uri)) }
+    }
+
+    fn get_prompt<'a>(
+        &'a self,
+        name: &'a str,
+        _args: Option<Value>,
+        _ctx: &'a RequestContext,
+    ) -> impl std::future::Future<Output = McpResult<PromptResult>> + Send + 'a {
+        let name = name.to_string();
+        async move { Err(McpError::prompt_not_found(&name)) }
+    }
+}
+
+/// Initialize a line-based session and call `tool`, returning the tool's text.
+async fn call_tool<S: AsyncRead + AsyncWrite + Unpin>(stream: S, tool: &str) -> String {
+    let (read, mut write) = tokio::io::split(stream);
+    let requests = [
+        json!({
+            "jsonrpc": "2.0", "id": 0, "method": "initialize",
+            "params": {
+                "protocolVersion": "2025-11-25",
+                "clientInfo": { "name": "test-client", "version": "1.0.0" },
+                "capabilities": {}
+            }
+        }),
+        json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }),
+        json!({
+            "jsonrpc": "2.0", "id": 1, "method": "tools/call",
+            "params": { "name": tool, "arguments": {} }
+        }),
+    ];
+    for request in requests {
+        write
+            .write_all(format!("{request}\n").as_bytes())
+            .await
+            .unwrap();
+    }
+    write.flush().await.unwrap();
+
+    let mut lines = BufReader::new(read).lines();
+    while let Some(line) = lines.next_line().await.unwrap() {
+        let response: Value = serde_json::from_str(&line).unwrap();
+        if response["id"] == 1 {
+            return response["result"]["content"][0]["text"]
+                .as_str()
+                .expect("tool text")
+                .to_string();
+        }
+    }
+    panic!("connection closed before tools/call response");
+}
+
+async fn free_tcp_addr() -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    listener.local_addr().unwrap().to_string()
+}
+
+async fn connect_tcp(addr: &str) -> tokio::net::TcpStream {
+    for _ in 0..100 {
+        if let Ok(stream) = tokio::net::TcpStream::connect(addr).await {
+            return stream;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    panic!("tcp transport never started listening on {addr}");
+}
+
+async fn connect_unix(path: &std::path::Path) -> tokio::net::UnixStream {
+    for _ in 0..100 {
+        if let Ok(stream) = tokio::net::UnixStream::connect(path).await {
+            return stream;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    panic!(
+        "unix transport never started listening on {}",
+        path.display()
+    );
+}
+
+#[tokio::test]
+async fn same_tool_is_callable_on_every_transport() {
+    let handler = CounterHandler::default();
+    let hits = Arc::clone(&handler.hits);
+    let dir = tempfile::tempdir().unwrap();
+    let socket = dir.path().join("mcp.sock");
+    let addr = free_tcp_addr().await;
+
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+    let server = tokio::spawn(ServerBuilder::new(handler).serve_multi_with_shutdown(
+        vec![
+            Transport::tcp(addr.clone()),
+            Transport::unix(socket.to_string_lossy()),
+        ],
+        shutdown_rx,
+    ));
+
+    assert_eq!(call_tool(connect_tcp(&addr).await, "hit").await, "1");
+    assert_eq!(call_tool(connect_unix(&socket).await, "hit").await, "2");
+    assert_eq!(hits.load(Ordering::SeqCst), 2);
+
+    shutdown_tx.send(true).unwrap();
+    tokio::time::timeout(Duration::from_secs(5), server)
+        .await
+        .expect("all transports stop on shutdown")
+        .unwrap()
+        .unwrap();
+}
+
+#[tokio::test]
+async fn shutdown_drains_in_flight_requests_on_every_transport() {
+    let handler = CounterHandler::default();
+    let started = Arc::clone(&handler.slow_started);
+    let finished = Arc::clone(&handler.slow_finished);
+    let dir = tempfile::tempdir().unwrap();
+    let socket = dir.path().join("mcp.sock");
+    let addr = free_tcp_addr().await;
+    let drain = DrainCoordinator::new();
+
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+    let server = tokio::spawn(
+        ServerBuilder::new(handler)
+            .with_graceful_shutdown(Duration::from_secs(5))
+            .with_drain_coordinator(drain.clone())
+            .serve_multi_with_shutdown(
+                vec![
+                    Transport::tcp(addr.clone()),
+                    Transport::unix(socket.to_string_lossy()),
+                ],
+                shutdown_rx,
+            ),
+    );
+
+    let tcp_stream = connect_tcp(&addr).await;
+    let unix_stream = connect_unix(&socket).await;
+    let tcp_call = tokio::spawn(async move { call_tool(tcp_stream, "slow").await });
+    let unix_call = tokio::spawn(async move { call_tool(unix_stream, "slow").await });
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while started.load(Ordering::SeqCst) < 2 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("both slow requests start");
+
+    let in_flight = drain.in_flight();
+    assert_eq!(in_flight.total(), 2);
+    assert!(in_flight.by_transport().all(|(_, count)| count == 1));
+
+    shutdown_tx.send(true).unwrap();
+    tokio::time::timeout(Duration::from_secs(5), server)
+        .await
+        .expect("transports stop once drained")
+        .unwrap()
+        .unwrap();
+
+    // Shutdown returned only after both transports finished their request
+    // and wrote its response.
+    assert_eq!(finished.load(Ordering::SeqCst), 2);
+    assert_eq!(drain.in_flight().total(), 0);
+    for call in [tcp_call, unix_call] {
+        let text = tokio::time::timeout(Duration::from_secs(5), call)
+            .await
+            .expect("response was written before shutdown returned")
+            .unwrap();
+        assert_eq!(text, "done");
+    }
+}
+
+#[tokio::test]
+async fn failing_transport_stops_the_others() {
+    let dir = tempfile::tempdir().unwrap();
+    let socket = dir.path().join("mcp.sock");
+    let (_shutdown_tx, shutdown_rx) = watch::channel(false);
+
+    let result = tokio::time::timeout(
+        Duration::from_secs(5),
+        ServerBuilder::new(CounterHandler::default()).serve_multi_with_shutdown(
+            vec![
+                Transport::unix(socket.to_string_lossy()),
+                Transport::tcp("not-an-address"),
+            ],
+            shutdown_rx,
+        ),
+    )
+    .await
+    .expect("a failed transport shuts the server down");
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn serve_multi_requires_a_transport() {
+    let (_shutdown_tx, shutdown_rx) = watch::channel(false);
+    let result = ServerBuilder::new(CounterHandler::default())
+        .serve_multi_with_shutdown(vec![], shutdown_rx)
+        .await;
+    assert!(result.is_err());
+}
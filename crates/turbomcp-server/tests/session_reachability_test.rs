@@ -82,6 +82,42 @@ async fn ctx_elicit_form_reaches_session() {
     assert_eq!(params["requestedSchema"], schema);
 }
 
+#[tokio::test]
+async fn ctx_elicit_form_forwards_complete_schema() {
+    let session = Arc::new(RecordingSession::new());
+    session.set_canned_response(json!({ "action": "decline" }));
+
+    let ctx = RequestContext::http().with_session(session.clone() as Arc<dyn McpSession>);
+
+    let schema = json!({
+        "type": "object",
+        "properties": {
+            "plan": {
+                "type": "string",
+                "description": "Subscription plan",
+                "oneOf": [
+                    { "const": "free", "title": "Free" },
+                    { "const": "pro", "title": "Pro" }
+                ],
+                "default": "free"
+            },
+            "seats": { "type": "integer", "minimum": 1, "default": 5 },
+            "tags": {
+                "type": "array",
+                "items": { "type": "string", "enum": ["a", "b"] },
+                "default": ["a"]
+            }
+        },
+        "required": ["plan"]
+    });
+    ctx.elicit_form("Choose a plan", schema.clone())
+        .await
+        .expect("elicit_form should succeed");
+
+    let (_, params) = &session.calls()[0];
+    assert_eq!(params["requestedSchema"], schema);
+}
+
 #[tokio::test]
 async fn ctx_elicit_url_reaches_session() {
     let session = Arc::new(RecordingSession::new());
@@ -23,15 +23,15 @@ use bytes::Bytes;
 use futures::StreamExt;
 use tokio::io::{AsyncRead, AsyncWrite, BufReader};
 use tokio::process::Child;
-use tokio::sync::{Mutex as TokioMutex, mpsc};
+use tokio::sync::{Mutex as TokioMutex, broadcast, mpsc};
 use tokio_util::codec::{FramedRead, FramedWrite, LinesCodec};
 use tracing::{debug, error, trace, warn};
 use turbomcp_protocol::MessageId;
 use turbomcp_transport_traits::{
     AtomicMetrics, Transport, TransportCapabilities, TransportConfig, TransportError,
-    TransportEventEmitter, TransportFactory, TransportMessage, TransportMessageMetadata,
-    TransportMetrics, TransportResult, TransportState, TransportType, validate_request_size,
-    validate_response_size,
+    TransportEvent, TransportEventEmitter, TransportFactory, TransportMessage,
+    TransportMessageMetadata, TransportMetrics, TransportResult, TransportState, TransportType,
+    validate_request_size, validate_response_size,
 };
 use uuid::Uuid;
 
@@ -120,6 +120,9 @@ pub struct StdioTransport {
     /// Event emitter
     event_emitter: TransportEventEmitter,
 
+    /// Protocol version negotiated with the peer during `initialize`
+    peer_protocol_version: Arc<Mutex<Option<String>>>,
+
     /// Source of streams (process stdio or child process)
     stream_source: Arc<TokioMutex<StreamSource>>,
 
@@ -175,6 +178,7 @@ impl StdioTransport {
             })),
             metrics: Arc::new(AtomicMetrics::default()),
             event_emitter,
+            peer_protocol_version: Arc::new(Mutex::new(None)),
             stream_source: Arc::new(TokioMutex::new(StreamSource::ProcessStdio)),
             stdin_reader: Arc::new(TokioMutex::new(None)),
             stdout_writer: Arc::new(TokioMutex::new(None)),
@@ -289,6 +293,7 @@ impl StdioTransport {
             })),
             metrics: Arc::new(AtomicMetrics::default()),
             event_emitter,
+            peer_protocol_version: Arc::new(Mutex::new(None)),
             stream_source: Arc::new(TokioMutex::new(StreamSource::Raw {
                 reader: Some(boxed_reader),
                 writer: Some(boxed_writer),
@@ -331,6 +336,7 @@ impl StdioTransport {
             })),
             metrics: Arc::new(AtomicMetrics::default()),
             event_emitter,
+            peer_protocol_version: Arc::new(Mutex::new(None)),
             stream_source: Arc::new(TokioMutex::new(StreamSource::ProcessStdio)),
             stdin_reader: Arc::new(TokioMutex::new(None)),
             stdout_writer: Arc::new(TokioMutex::new(None)),
@@ -737,6 +743,18 @@ impl Transport for StdioTransport {
         Some("stdio://".to_string())
     }
 
+    fn peer_protocol_version(&self) -> Option<String> {
+        self.peer_protocol_version.lock().clone()
+    }
+
+    fn set_peer_protocol_version(&self, version: &str) {
+        *self.peer_protocol_version.lock() = Some(version.to_string());
+    }
+
+    fn subscribe_events(&self) -> Option<broadcast::Receiver<TransportEvent>> {
+        Some(self.event_emitter.subscribe())
+    }
+
     fn configure(
         &self,
         config: TransportConfig,
@@ -1134,6 +1152,33 @@ line2"}}"#;
         assert_eq!(client_transport.state().await, TransportState::Disconnected);
     }
 
+    #[tokio::test]
+    async fn test_disconnect_publishes_event_to_subscribers() {
+        let (_peer_tx, reader) = tokio::io::duplex(1024);
+        let (writer, _peer_rx) = tokio::io::duplex(1024);
+        let transport = StdioTransport::from_raw(reader, writer).unwrap();
+        let mut events = transport.subscribe_events().unwrap();
+
+        transport.connect().await.unwrap();
+        transport.disconnect().await.unwrap();
+
+        assert!(matches!(
+            events.recv().await.unwrap(),
+            TransportEvent::Connected { .. }
+        ));
+        match events.recv().await.unwrap() {
+            TransportEvent::Disconnected {
+                transport_type,
+                reason,
+                ..
+            } => {
+                assert_eq!(transport_type, TransportType::Stdio);
+                assert_eq!(reason, None);
+            }
+            other => panic!("expected Disconnected, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_stream_source_debug() {
         // Test Debug impl for StreamSource
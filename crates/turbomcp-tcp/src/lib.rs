@@ -10,7 +10,9 @@
 //! - **Bidirectional Communication**: Full-duplex message exchange
 //! - **Backpressure Handling**: Bounded channels prevent memory exhaustion
 //! - **Graceful Shutdown**: Clean task termination on disconnect
-//! - **Message Framing**: Uses LinesCodec for reliable newline-delimited JSON
+//! - **Message Framing**: Uses LinesCodec for reliable newline-delimited JSON, or
+//!   length-prefixed binary frames ([`TcpFraming::LengthDelimited`]) for codecs
+//!   such as MessagePack, which skip UTF-8 validation
 //!
 //! ## Quick Start
 //!
@@ -72,10 +74,10 @@
 
 mod transport;
 
-pub use transport::{TcpConfig, TcpTransport, TcpTransportBuilder};
+pub use transport::{TcpConfig, TcpFraming, TcpTransport, TcpTransportBuilder};
 
 // Re-export transport traits for convenience
 pub use turbomcp_transport_traits::{
-    AtomicMetrics, Transport, TransportCapabilities, TransportError, TransportMessage,
-    TransportMetrics, TransportResult, TransportState, TransportType,
+    AtomicMetrics, TimeoutConfig, Transport, TransportCapabilities, TransportError,
+    TransportMessage, TransportMetrics, TransportResult, TransportState, TransportType,
 };
@@ -1,6 +1,6 @@
 //! TCP transport implementation for MCP
 
-use bytes::Bytes;
+use bytes::{Bytes, BytesMut};
 use futures::{SinkExt, StreamExt};
 use parking_lot::Mutex;
 use std::collections::HashMap;
@@ -9,18 +9,171 @@ use std::net::SocketAddr;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::sync::atomic::Ordering;
+use std::time::Duration;
 use tokio::net::{TcpListener, TcpStream};
 use tokio::sync::{broadcast, mpsc};
 use tokio::task::JoinSet;
-use tokio_util::codec::{Framed, LinesCodec};
+use tokio_util::codec::{
+    Decoder, Encoder, Framed, LengthDelimitedCodec, LengthDelimitedCodecError, LinesCodec,
+    LinesCodecError,
+};
 use tracing::{debug, error, info, warn};
 
 use turbomcp_protocol::MessageId;
 use turbomcp_transport_traits::{
-    AtomicMetrics, Transport, TransportCapabilities, TransportError, TransportMessage,
-    TransportMetrics, TransportResult, TransportState, TransportType,
+    AtomicMetrics, PriorityReceiver, PrioritySender, TimeoutConfig, Transport,
+    TransportCapabilities, TransportError, TransportMessage, TransportMetrics, TransportResult,
+    TransportState, TransportType, TrySendError, priority_channel,
 };
 
+/// Wire framing used on TCP connections
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TcpFraming {
+    /// Newline-delimited text, the standard MCP framing for JSON-RPC.
+    ///
+    /// Frames must be UTF-8: incoming lines are decoded as text and parsed as
+    /// JSON, and `send` rejects payloads that are not valid UTF-8.
+    #[default]
+    Lines,
+    /// Length-prefixed binary frames (4-byte big-endian length header).
+    ///
+    /// For binary codecs such as MessagePack. Payloads pass through unchanged
+    /// in both directions, with no UTF-8 validation or JSON parsing.
+    LengthDelimited,
+}
+
+impl TcpFraming {
+    /// Whether frames carry UTF-8 text and are validated as such
+    pub const fn is_text(self) -> bool {
+        matches!(self, Self::Lines)
+    }
+}
+
+/// Frame codec for a connection, selected by [`TcpFraming`]
+///
+/// Records frame counts, partial reads, and oversized frames in the
+/// transport's [`AtomicMetrics`].
+#[derive(Debug)]
+struct FrameCodec {
+    inner: FrameCodecKind,
+    metrics: Arc<AtomicMetrics>,
+}
+
+#[derive(Debug)]
+enum FrameCodecKind {
+    Lines(LinesCodec),
+    LengthDelimited(LengthDelimitedCodec),
+}
+
+impl FrameCodec {
+    fn new(framing: TcpFraming, metrics: Arc<AtomicMetrics>) -> Self {
+        let inner = match framing {
+            TcpFraming::Lines => FrameCodecKind::Lines(LinesCodec::new_with_max_length(
+                turbomcp_protocol::MAX_MESSAGE_SIZE,
+            )),
+            TcpFraming::LengthDelimited => FrameCodecKind::LengthDelimited(
+                LengthDelimitedCodec::builder()
+                    .max_frame_length(turbomcp_protocol::MAX_MESSAGE_SIZE)
+                    .new_codec(),
+            ),
+        };
+        Self { inner, metrics }
+    }
+
+    /// Count a decode outcome; `available` is the buffer length before decoding
+    fn record_decode(
+        &self,
+        available: usize,
+        result: Result<Option<Bytes>, std::io::Error>,
+    ) -> Result<Option<Bytes>, std::io::Error> {
+        match &result {
+            Ok(Some(frame)) => self.metrics.record_frame_received(frame.len() as u64),
+            Ok(None) if available > 0 => self.metrics.record_partial_frame(),
+            Ok(None) => {}
+            Err(e) => self.record_error(e),
+        }
+        result
+    }
+
+    fn record_error(&self, e: &std::io::Error) {
+        if is_oversized_frame(e) {
+            self.metrics.record_oversized_frame();
+        }
+    }
+}
+
+fn lines_codec_error(e: LinesCodecError) -> std::io::Error {
+    match e {
+        LinesCodecError::Io(e) => e,
+        other => std::io::Error::new(std::io::ErrorKind::InvalidData, other),
+    }
+}
+
+/// Whether a codec error is a max-length rejection rather than an I/O failure
+fn is_oversized_frame(e: &std::io::Error) -> bool {
+    e.get_ref().is_some_and(|inner| {
+        inner.is::<LengthDelimitedCodecError>()
+            || matches!(
+                inner.downcast_ref::<LinesCodecError>(),
+                Some(LinesCodecError::MaxLineLengthExceeded)
+            )
+    })
+}
+
+impl Decoder for FrameCodec {
+    type Item = Bytes;
+    type Error = std::io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Bytes>, Self::Error> {
+        let available = src.len();
+        let result = match &mut self.inner {
+            FrameCodecKind::Lines(codec) => codec
+                .decode(src)
+                .map(|line| line.map(Bytes::from))
+                .map_err(lines_codec_error),
+            FrameCodecKind::LengthDelimited(codec) => {
+                codec.decode(src).map(|frame| frame.map(BytesMut::freeze))
+            }
+        };
+        self.record_decode(available, result)
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> Result<Option<Bytes>, Self::Error> {
+        let result = match &mut self.inner {
+            FrameCodecKind::Lines(codec) => codec
+                .decode_eof(src)
+                .map(|line| line.map(Bytes::from))
+                .map_err(lines_codec_error),
+            FrameCodecKind::LengthDelimited(codec) => codec
+                .decode_eof(src)
+                .map(|frame| frame.map(BytesMut::freeze)),
+        };
+        // Leftover bytes at EOF are an error, not a partial read to wait on.
+        self.record_decode(0, result)
+    }
+}
+
+impl Encoder<Bytes> for FrameCodec {
+    type Error = std::io::Error;
+
+    fn encode(&mut self, item: Bytes, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        let payload_len = item.len() as u64;
+        let result = match &mut self.inner {
+            FrameCodecKind::Lines(codec) => {
+                let line = std::str::from_utf8(&item)
+                    .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+                codec.encode(line, dst).map_err(lines_codec_error)
+            }
+            FrameCodecKind::LengthDelimited(codec) => codec.encode(item, dst),
+        };
+        match &result {
+            Ok(()) => self.metrics.record_frame_sent(payload_len),
+            Err(e) => self.record_error(e),
+        }
+        result
+    }
+}
+
 /// TCP transport implementation
 pub struct TcpTransport {
     /// Local address to bind to
@@ -31,8 +184,9 @@ pub struct TcpTransport {
     sender: Arc<tokio::sync::Mutex<Option<mpsc::Sender<TransportMessage>>>>,
     /// Message receiver for incoming messages (tokio mutex - crosses await)
     receiver: Arc<tokio::sync::Mutex<Option<mpsc::Receiver<TransportMessage>>>>,
-    /// Active connections map: connection ID -> outgoing message sender (std mutex - short-lived)
-    connections: Arc<Mutex<HashMap<String, mpsc::Sender<String>>>>,
+    /// Active connections map: connection ID -> outgoing frame queue (std mutex - short-lived).
+    /// Each connection flushes its queue highest-priority first.
+    connections: Arc<Mutex<HashMap<String, PrioritySender<Bytes>>>>,
     /// Transport capabilities (immutable)
     capabilities: TransportCapabilities,
     /// Current state (std mutex - short-lived)
@@ -47,8 +201,20 @@ pub struct TcpTransport {
     max_connections: usize,
     /// Idle connection timeout (zombie connection prevention)
     idle_timeout: std::time::Duration,
+    /// Deadline for establishing the client connection
+    connect_timeout: Duration,
+    /// Deadline for the next incoming frame; `None` leaves only `idle_timeout`
+    read_timeout: Option<Duration>,
+    /// Deadline for the peer to accept one outgoing frame
+    write_timeout: Option<Duration>,
+    /// Write timeout that closed the client connection, reported by `send`
+    write_error: Arc<Mutex<Option<TransportError>>>,
     /// Strict mode: disconnect on invalid JSON (default: false, log and continue)
     strict_mode: bool,
+    /// Wire framing; decides whether payloads are UTF-8 validated
+    framing: TcpFraming,
+    /// Protocol version negotiated with the peer during `initialize`
+    peer_protocol_version: Arc<Mutex<Option<String>>>,
 }
 
 // Manual Debug implementation since broadcast::Sender doesn't implement Debug
@@ -60,11 +226,19 @@ impl std::fmt::Debug for TcpTransport {
             .field("capabilities", &self.capabilities)
             .field("state", &self.state)
             .field("metrics", &self.metrics)
+            .field("framing", &self.framing)
             .finish()
     }
 }
 
 impl TcpTransport {
+    /// How long a connection may go without an incoming frame: the read
+    /// timeout when set, capped by the idle timeout.
+    fn read_deadline(&self) -> Duration {
+        self.read_timeout
+            .map_or(self.idle_timeout, |read| read.min(self.idle_timeout))
+    }
+
     /// Create a new TCP transport for server mode
     #[must_use]
     pub fn new_server(bind_addr: SocketAddr) -> Self {
@@ -87,7 +261,13 @@ impl TcpTransport {
             shutdown_tx,
             max_connections: 256,
             idle_timeout: std::time::Duration::from_secs(300),
+            connect_timeout: Duration::from_millis(DEFAULT_CONNECT_TIMEOUT_MS),
+            read_timeout: None,
+            write_timeout: Some(Duration::from_millis(DEFAULT_WRITE_TIMEOUT_MS)),
+            write_error: Arc::new(Mutex::new(None)),
             strict_mode: false,
+            framing: TcpFraming::Lines,
+            peer_protocol_version: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -113,7 +293,13 @@ impl TcpTransport {
             shutdown_tx,
             max_connections: 256,
             idle_timeout: std::time::Duration::from_secs(300),
+            connect_timeout: Duration::from_millis(DEFAULT_CONNECT_TIMEOUT_MS),
+            read_timeout: None,
+            write_timeout: Some(Duration::from_millis(DEFAULT_WRITE_TIMEOUT_MS)),
+            write_error: Arc::new(Mutex::new(None)),
             strict_mode: false,
+            framing: TcpFraming::Lines,
+            peer_protocol_version: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -139,8 +325,12 @@ impl TcpTransport {
         let task_handles = Arc::clone(&self.task_handles);
         let mut shutdown_rx = self.shutdown_tx.subscribe();
         let max_connections = self.max_connections;
-        let idle_timeout = self.idle_timeout;
+        let idle_timeout = self.read_deadline();
+        let write_timeout = self.write_timeout;
+        let write_error = Arc::clone(&self.write_error);
         let strict_mode = self.strict_mode;
+        let framing = self.framing;
+        let metrics = Arc::clone(&self.metrics);
 
         // Spawn accept loop and store handle
         task_handles.lock().await.spawn(async move {
@@ -177,9 +367,13 @@ impl TcpTransport {
                                 info!("Accepted TCP connection from {}", addr);
                                 let incoming_sender = tx.clone();
                                 let connections_ref = connections.clone();
+                                let metrics = Arc::clone(&metrics);
+                                let write_error = Arc::clone(&write_error);
 
                                 // Generate UUID-based connection ID (NAT-safe)
                                 let conn_id = format!("tcp-{}-{}", addr, uuid::Uuid::new_v4());
+                                let outgoing_receiver =
+                                    register_connection(&connections_ref, &conn_id);
 
                                 // ✅ Handle connection in separate task and store handle
                                 connection_tasks.spawn(async move {
@@ -187,10 +381,15 @@ impl TcpTransport {
                                         stream,
                                         addr,
                                         conn_id,
+                                        outgoing_receiver,
                                         incoming_sender,
                                         connections_ref,
                                         idle_timeout,
+                                        write_timeout,
+                                        write_error,
                                         strict_mode,
+                                        framing,
+                                        metrics,
                                     )
                                     .await
                                     {
@@ -228,12 +427,29 @@ impl TcpTransport {
         info!("Connecting to TCP server at {}", remote_addr);
         *self.state.lock() = TransportState::Connecting;
 
-        let stream = TcpStream::connect(remote_addr).await.map_err(|e| {
-            *self.state.lock() = TransportState::Failed {
-                reason: format!("Failed to connect: {e}"),
+        let stream =
+            match tokio::time::timeout(self.connect_timeout, TcpStream::connect(remote_addr)).await
+            {
+                Ok(Ok(stream)) => stream,
+                Ok(Err(e)) => {
+                    *self.state.lock() = TransportState::Failed {
+                        reason: format!("Failed to connect: {e}"),
+                    };
+                    return Err(TransportError::ConnectionFailed(format!(
+                        "Failed to connect: {e}"
+                    )));
+                }
+                Err(_) => {
+                    *self.state.lock() = TransportState::Failed {
+                        reason: format!("Connect timed out after {:?}", self.connect_timeout),
+                    };
+                    return Err(TransportError::ConnectionTimeout {
+                        operation: format!("connect to {remote_addr}"),
+                        timeout: self.connect_timeout,
+                    });
+                }
             };
-            TransportError::ConnectionFailed(format!("Failed to connect: {e}"))
-        })?;
+        *self.write_error.lock() = None;
 
         // Same rationale as the server-side accept path: small, latency-sensitive
         // MCP frames don't benefit from Nagle's coalescing. Errors are non-fatal.
@@ -241,27 +457,34 @@ impl TcpTransport {
             debug!(error = %e, addr = %remote_addr, "set_nodelay failed on client connect");
         }
 
+        // Generate UUID-based connection ID for client
+        let conn_id = format!("tcp-client-{}-{}", remote_addr, uuid::Uuid::new_v4());
+        let connections = self.connections.clone();
+        // Registered before reporting connected, so a send straight after
+        // `connect` returns finds the connection.
+        let outgoing_receiver = register_connection(&connections, &conn_id);
+
         let (tx, rx) = mpsc::channel(1000); // Bounded channel for backpressure control
         *self.sender.lock().await = Some(tx.clone());
         *self.receiver.lock().await = Some(rx);
         *self.state.lock() = TransportState::Connected;
 
         // Handle connection with proper task tracking
-        let connections = self.connections.clone();
         let task_handles = Arc::clone(&self.task_handles);
         let mut shutdown_rx = self.shutdown_tx.subscribe();
-        let idle_timeout = self.idle_timeout;
+        let idle_timeout = self.read_deadline();
+        let write_timeout = self.write_timeout;
+        let write_error = Arc::clone(&self.write_error);
         let strict_mode = self.strict_mode;
-
-        // Generate UUID-based connection ID for client
-        let conn_id = format!("tcp-client-{}-{}", remote_addr, uuid::Uuid::new_v4());
+        let framing = self.framing;
+        let metrics = Arc::clone(&self.metrics);
 
         task_handles.lock().await.spawn(async move {
             tokio::select! {
                 _ = shutdown_rx.recv() => {
                     info!("TCP client connection received shutdown signal");
                 }
-                result = handle_tcp_connection_framed(stream, remote_addr, conn_id, tx, connections, idle_timeout, strict_mode) => {
+                result = handle_tcp_connection_framed(stream, remote_addr, conn_id, outgoing_receiver, tx, connections, idle_timeout, write_timeout, write_error, strict_mode, framing, metrics) => {
                     if let Err(e) = result {
                         error!("TCP client connection handler failed: {}", e);
                     }
@@ -273,46 +496,89 @@ impl TcpTransport {
     }
 }
 
-/// Handle a TCP connection using tokio-util::codec::Framed with LinesCodec
-/// This provides proven newline-delimited JSON framing with proper bidirectional communication
+/// Add an outgoing queue for `conn_id` to `connections`, returning its receiver
+///
+/// The queue is bounded for backpressure. Frames buffered behind a slow
+/// socket are flushed by priority, so control messages overtake queued bulk
+/// data.
+fn register_connection(
+    connections: &Mutex<HashMap<String, PrioritySender<Bytes>>>,
+    conn_id: &str,
+) -> PriorityReceiver<Bytes> {
+    let (outgoing_sender, outgoing_receiver) = priority_channel::<Bytes>(100);
+    connections
+        .lock()
+        .insert(conn_id.to_string(), outgoing_sender);
+    outgoing_receiver
+}
+
+/// Handle a TCP connection using tokio-util::codec::Framed with the configured [`TcpFraming`]
+///
+/// Text framing parses each line as JSON-RPC; binary framing hands frames
+/// through untouched so non-JSON codecs are never UTF-8 or JSON validated.
+///
+/// Reads and writes have separate deadlines: `idle_timeout` bounds the wait
+/// for the next incoming frame, `write_timeout` bounds how long the peer may
+/// take to accept one outgoing frame. A write timeout stops sending on the
+/// connection (a partly written frame cannot be resumed) and records the
+/// error in `write_error`, but keeps reading.
+#[allow(clippy::too_many_arguments)]
 async fn handle_tcp_connection_framed(
     stream: TcpStream,
     addr: SocketAddr,
     conn_id: String,
+    mut outgoing_receiver: PriorityReceiver<Bytes>,
     incoming_sender: mpsc::Sender<TransportMessage>,
-    connections: Arc<Mutex<HashMap<String, mpsc::Sender<String>>>>,
+    connections: Arc<Mutex<HashMap<String, PrioritySender<Bytes>>>>,
     idle_timeout: std::time::Duration,
+    write_timeout: Option<Duration>,
+    write_error: Arc<Mutex<Option<TransportError>>>,
     strict_mode: bool,
+    framing: TcpFraming,
+    metrics: Arc<AtomicMetrics>,
 ) -> TransportResult<()> {
     debug!(
-        "Handling TCP connection from {} (ID: {}) using Framed<TcpStream, LinesCodec>",
-        addr, conn_id
+        "Handling TCP connection from {} (ID: {}) using Framed<TcpStream, {:?}>",
+        addr, conn_id, framing
     );
 
-    // Create framed transport using LinesCodec for newline-delimited messages
-    let framed = Framed::new(stream, LinesCodec::new());
+    let framed = Framed::new(stream, FrameCodec::new(framing, Arc::clone(&metrics)));
     let (mut sink, mut stream) = framed.split();
 
-    // Channel for outgoing messages to this specific connection (bounded for backpressure)
-    let (outgoing_sender, mut outgoing_receiver) = mpsc::channel::<String>(100);
-
-    // Register this connection in the connections map with UUID-based key
-    connections.lock().insert(conn_id.clone(), outgoing_sender);
-
     // Clone for cleanup
     let connections_cleanup = connections.clone();
     let cleanup_conn_id = conn_id.clone();
 
     // Spawn task to handle outgoing messages (responses from server to client)
     let send_conn_id = conn_id.clone();
+    let send_connections = connections.clone();
     let send_task = tokio::spawn(async move {
         while let Some(message) = outgoing_receiver.recv().await {
             debug!(
-                "Sending message to connection {}: {}",
-                send_conn_id, message
+                "Sending {} byte frame to connection {}",
+                message.len(),
+                send_conn_id
             );
 
-            if let Err(e) = sink.send(message).await {
+            let sent = match write_timeout {
+                Some(limit) => match tokio::time::timeout(limit, sink.send(message)).await {
+                    Ok(sent) => sent,
+                    Err(_) => {
+                        warn!(
+                            "Write to TCP connection {} timed out after {:?}; closing it for writes",
+                            send_conn_id, limit
+                        );
+                        send_connections.lock().remove(&send_conn_id);
+                        *write_error.lock() = Some(TransportError::WriteTimeout {
+                            operation: format!("send to {addr}"),
+                            timeout: limit,
+                        });
+                        break;
+                    }
+                },
+                None => sink.send(message).await,
+            };
+            if let Err(e) = sent {
                 error!(
                     "Failed to send message to TCP connection {}: {}",
                     send_conn_id, e
@@ -328,17 +594,18 @@ async fn handle_tcp_connection_framed(
         match tokio::time::timeout(idle_timeout, stream.next()).await {
             Ok(Some(result)) => {
                 match result {
-                    Ok(line) => {
-                        if line.is_empty() {
+                    Ok(frame) => {
+                        if frame.is_empty() {
                             continue;
                         }
 
                         // Validate message size (1MB limit for security)
                         let max_size = turbomcp_protocol::MAX_MESSAGE_SIZE;
-                        if line.len() > max_size {
+                        if frame.len() > max_size {
+                            metrics.record_oversized_frame();
                             error!(
                                 "Message size {} exceeds limit {} from {} (ID: {})",
-                                line.len(),
+                                frame.len(),
                                 max_size,
                                 addr,
                                 conn_id
@@ -346,62 +613,71 @@ async fn handle_tcp_connection_framed(
                             break;
                         }
 
-                        debug!("Received message from {} (ID: {}): {}", addr, conn_id, line);
-
-                        // Parse and validate JSON-RPC message
-                        match serde_json::from_str::<serde_json::Value>(&line) {
-                            Ok(value) => {
-                                // Extract message ID for transport tracking
-                                let id = value.get("id").cloned().unwrap_or_else(|| {
-                                    serde_json::Value::String(uuid::Uuid::new_v4().to_string())
-                                });
-
-                                let message_id = match id {
-                                    serde_json::Value::String(s) => MessageId::from(s),
-                                    serde_json::Value::Number(n) => {
-                                        MessageId::from(n.as_i64().unwrap_or_default())
-                                    }
-                                    _ => MessageId::from(uuid::Uuid::new_v4()),
-                                };
-
-                                // Create transport message with JSON bytes
-                                let transport_msg =
-                                    TransportMessage::new(message_id, Bytes::from(line));
+                        debug!(
+                            "Received {} byte frame from {} (ID: {})",
+                            frame.len(),
+                            addr,
+                            conn_id
+                        );
 
-                                // Use try_send with backpressure handling
-                                match incoming_sender.try_send(transport_msg) {
-                                    Ok(()) => {}
-                                    Err(mpsc::error::TrySendError::Full(_)) => {
-                                        warn!(
-                                            "Message channel full, applying backpressure to connection {} (ID: {})",
-                                            addr, conn_id
-                                        );
-                                        // Apply backpressure by dropping this message
-                                        continue;
+                        let message_id = if framing.is_text() {
+                            // Parse and validate JSON-RPC message
+                            match serde_json::from_slice::<serde_json::Value>(&frame) {
+                                Ok(value) => {
+                                    // Extract message ID for transport tracking
+                                    match value.get("id") {
+                                        Some(serde_json::Value::String(s)) => {
+                                            MessageId::from(s.clone())
+                                        }
+                                        Some(serde_json::Value::Number(n)) => {
+                                            MessageId::from(n.as_i64().unwrap_or_default())
+                                        }
+                                        None => MessageId::from(uuid::Uuid::new_v4().to_string()),
+                                        _ => MessageId::from(uuid::Uuid::new_v4()),
                                     }
-                                    Err(mpsc::error::TrySendError::Closed(_)) => {
+                                }
+                                Err(e) => {
+                                    error!(
+                                        "Failed to parse JSON-RPC message from {} (ID: {}): {}",
+                                        addr, conn_id, e
+                                    );
+                                    // In strict mode, disconnect on invalid JSON
+                                    if strict_mode {
                                         warn!(
-                                            "Message receiver dropped, closing connection to {} (ID: {})",
+                                            "Strict mode enabled: closing connection {} (ID: {}) due to invalid JSON",
                                             addr, conn_id
                                         );
                                         break;
                                     }
+                                    // In permissive mode (default), skip invalid messages but keep connection open
+                                    continue;
                                 }
                             }
-                            Err(e) => {
-                                error!(
-                                    "Failed to parse JSON-RPC message from {} (ID: {}): {}",
-                                    addr, conn_id, e
+                        } else {
+                            // Binary frames are opaque here; decoding belongs
+                            // to the codec that produced them.
+                            MessageId::from(uuid::Uuid::new_v4())
+                        };
+
+                        let transport_msg = TransportMessage::new(message_id, frame);
+
+                        // Use try_send with backpressure handling
+                        match incoming_sender.try_send(transport_msg) {
+                            Ok(()) => {}
+                            Err(TrySendError::Full(_)) => {
+                                warn!(
+                                    "Message channel full, applying backpressure to connection {} (ID: {})",
+                                    addr, conn_id
                                 );
-                                // In strict mode, disconnect on invalid JSON
-                                if strict_mode {
-                                    warn!(
-                                        "Strict mode enabled: closing connection {} (ID: {}) due to invalid JSON",
-                                        addr, conn_id
-                                    );
-                                    break;
-                                }
-                                // In permissive mode (default), skip invalid messages but keep connection open
+                                // Apply backpressure by dropping this message
+                                continue;
+                            }
+                            Err(TrySendError::Closed(_)) => {
+                                warn!(
+                                    "Message receiver dropped, closing connection to {} (ID: {})",
+                                    addr, conn_id
+                                );
+                                break;
                             }
                         }
                     }
@@ -420,7 +696,7 @@ async fn handle_tcp_connection_framed(
                 break;
             }
             Err(_) => {
-                // Idle timeout
+                // Read / idle timeout
                 warn!(
                     "TCP connection {} (ID: {}) idle for {:?}, closing",
                     addr, conn_id, idle_timeout
@@ -525,14 +801,17 @@ impl Transport for TcpTransport {
                 .bytes_sent
                 .fetch_add(message.size() as u64, Ordering::Relaxed);
 
-            // JSON-RPC requires valid UTF-8 — refuse non-UTF-8 payloads
-            // explicitly rather than `from_utf8_lossy` mangling unexpected
-            // bytes into U+FFFD and silently corrupting the wire frame.
-            let json_str = std::str::from_utf8(&message.payload).map_err(|e| {
-                TransportError::SerializationFailed(format!(
-                    "TCP send rejected non-UTF-8 payload: {e}"
-                ))
-            })?;
+            // Text framing carries JSON-RPC, which requires valid UTF-8 —
+            // refuse non-UTF-8 payloads explicitly rather than letting them
+            // corrupt the line-delimited stream. Binary framing is
+            // length-prefixed and skips the check.
+            if self.framing.is_text() {
+                std::str::from_utf8(&message.payload).map_err(|e| {
+                    TransportError::SerializationFailed(format!(
+                        "TCP send rejected non-UTF-8 payload: {e}"
+                    ))
+                })?;
+            }
 
             // Send to all active connections (broadcast for server mode).
             // In client mode there is exactly one connection. **Server mode is
@@ -542,6 +821,9 @@ impl Transport for TcpTransport {
             // transport's server mode until per-connection send is added.
             let connections = self.connections.lock();
             if connections.is_empty() {
+                if let Some(error) = self.write_error.lock().clone() {
+                    return Err(error);
+                }
                 return Err(TransportError::ConnectionFailed(
                     "No active TCP connections".into(),
                 ));
@@ -558,13 +840,13 @@ impl Transport for TcpTransport {
             let mut failed_connections = Vec::new();
             for (conn_id, sender) in connections.iter() {
                 // Use try_send with backpressure handling
-                match sender.try_send(json_str.to_string()) {
+                match sender.try_send(message.priority(), message.payload.clone()) {
                     Ok(()) => {}
-                    Err(mpsc::error::TrySendError::Full(_)) => {
+                    Err(TrySendError::Full(_)) => {
                         warn!("Connection {} channel full, applying backpressure", conn_id);
                         // Don't mark as failed, just apply backpressure
                     }
-                    Err(mpsc::error::TrySendError::Closed(_)) => {
+                    Err(TrySendError::Closed(_)) => {
                         warn!("Failed to send message to TCP connection {}", conn_id);
                         failed_connections.push(conn_id.clone());
                     }
@@ -628,10 +910,33 @@ impl Transport for TcpTransport {
             Some(format!("tcp://{}", self.bind_addr))
         }
     }
+
+    fn peer_protocol_version(&self) -> Option<String> {
+        self.peer_protocol_version.lock().clone()
+    }
+
+    fn set_peer_protocol_version(&self, version: &str) {
+        *self.peer_protocol_version.lock() = Some(version.to_string());
+    }
+}
+
+/// Default deadline for establishing a client connection, in milliseconds
+const DEFAULT_CONNECT_TIMEOUT_MS: u64 = 5000;
+
+/// Default deadline for the peer to accept one outgoing frame, in milliseconds
+const DEFAULT_WRITE_TIMEOUT_MS: u64 = 30_000;
+
+/// Saturating conversion of a [`Duration`] to whole milliseconds
+fn duration_ms(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
 }
 
 /// TCP transport configuration
+///
+/// Start from `Default` and set the remaining options with the `with_*`
+/// setters, or configure a [`TcpTransportBuilder`] directly.
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct TcpConfig {
     /// Bind address for server mode
     pub bind_addr: SocketAddr,
@@ -639,6 +944,10 @@ pub struct TcpConfig {
     pub remote_addr: Option<SocketAddr>,
     /// Connection timeout in milliseconds
     pub connect_timeout_ms: u64,
+    /// Set with [`with_read_timeout_ms`](Self::with_read_timeout_ms).
+    pub(crate) read_timeout_ms: Option<u64>,
+    /// Set with [`with_write_timeout_ms`](Self::with_write_timeout_ms).
+    pub(crate) write_timeout_ms: Option<u64>,
     /// Keep-alive settings
     pub keep_alive: bool,
     /// Buffer sizes
@@ -649,6 +958,35 @@ pub struct TcpConfig {
     pub idle_timeout_secs: u64,
     /// Strict mode: disconnect on invalid JSON (default: false, log and continue)
     pub strict_mode: bool,
+    /// Set with [`with_framing`](Self::with_framing).
+    pub(crate) framing: TcpFraming,
+}
+
+impl TcpConfig {
+    /// Set the wire framing (default: newline-delimited text)
+    #[must_use]
+    pub const fn with_framing(mut self, framing: TcpFraming) -> Self {
+        self.framing = framing;
+        self
+    }
+
+    /// Set the read timeout in milliseconds: close a connection that
+    /// delivers no frame for this long. `None` (the default) leaves only the
+    /// idle timeout.
+    #[must_use]
+    pub const fn with_read_timeout_ms(mut self, timeout: Option<u64>) -> Self {
+        self.read_timeout_ms = timeout;
+        self
+    }
+
+    /// Set the write timeout in milliseconds: stop sending on a connection
+    /// whose peer does not accept a frame within this long. `None` waits
+    /// indefinitely (default: 30 seconds).
+    #[must_use]
+    pub const fn with_write_timeout_ms(mut self, timeout: Option<u64>) -> Self {
+        self.write_timeout_ms = timeout;
+        self
+    }
 }
 
 impl Default for TcpConfig {
@@ -658,12 +996,15 @@ impl Default for TcpConfig {
                 .parse()
                 .expect("Default TCP bind address should be valid"),
             remote_addr: None,
-            connect_timeout_ms: 5000,
+            connect_timeout_ms: DEFAULT_CONNECT_TIMEOUT_MS,
+            read_timeout_ms: None,
+            write_timeout_ms: Some(DEFAULT_WRITE_TIMEOUT_MS),
             keep_alive: true,
             buffer_size: 8192,
             max_connections: 256,
             idle_timeout_secs: 300,
             strict_mode: false,
+            framing: TcpFraming::Lines,
         }
     }
 }
@@ -704,6 +1045,32 @@ impl TcpTransportBuilder {
         self
     }
 
+    /// Set read timeout
+    #[must_use]
+    pub const fn read_timeout_ms(mut self, timeout: u64) -> Self {
+        self.config.read_timeout_ms = Some(timeout);
+        self
+    }
+
+    /// Set write timeout
+    #[must_use]
+    pub const fn write_timeout_ms(mut self, timeout: u64) -> Self {
+        self.config.write_timeout_ms = Some(timeout);
+        self
+    }
+
+    /// Apply the connect, read, and write deadlines of a [`TimeoutConfig`]
+    ///
+    /// The request and total timeouts are enforced by the protocol client,
+    /// not the transport, and are ignored here.
+    #[must_use]
+    pub fn timeouts(mut self, timeouts: &TimeoutConfig) -> Self {
+        self.config.connect_timeout_ms = duration_ms(timeouts.connect);
+        self.config.read_timeout_ms = timeouts.read.map(duration_ms);
+        self.config.write_timeout_ms = timeouts.write().map(duration_ms);
+        self
+    }
+
     /// Enable or disable keep-alive
     #[must_use]
     pub const fn keep_alive(mut self, enabled: bool) -> Self {
@@ -739,6 +1106,16 @@ impl TcpTransportBuilder {
         self
     }
 
+    /// Set the wire framing
+    ///
+    /// Use [`TcpFraming::LengthDelimited`] for binary codecs such as
+    /// MessagePack; the default newline framing only carries UTF-8 text.
+    #[must_use]
+    pub const fn framing(mut self, framing: TcpFraming) -> Self {
+        self.config.framing = framing;
+        self
+    }
+
     /// Build the TCP transport
     #[must_use]
     pub fn build(self) -> TcpTransport {
@@ -750,7 +1127,11 @@ impl TcpTransportBuilder {
 
         transport.max_connections = self.config.max_connections;
         transport.idle_timeout = std::time::Duration::from_secs(self.config.idle_timeout_secs);
+        transport.connect_timeout = Duration::from_millis(self.config.connect_timeout_ms);
+        transport.read_timeout = self.config.read_timeout_ms.map(Duration::from_millis);
+        transport.write_timeout = self.config.write_timeout_ms.map(Duration::from_millis);
         transport.strict_mode = self.config.strict_mode;
+        transport.framing = self.config.framing;
         transport
     }
 }
@@ -803,6 +1184,177 @@ mod tests {
         assert_eq!(transport.remote_addr, Some(remote_addr));
     }
 
+    /// MessagePack encoding of `{"jsonrpc": "2.0", "id": 1, "method": "ping"}`.
+    /// The leading map marker (0x83) is not valid UTF-8.
+    fn msgpack_ping() -> Bytes {
+        let mut frame = vec![0x83, 0xa7];
+        frame.extend_from_slice(b"jsonrpc");
+        frame.push(0xa3);
+        frame.extend_from_slice(b"2.0");
+        frame.push(0xa2);
+        frame.extend_from_slice(b"id");
+        frame.push(0x01);
+        frame.push(0xa6);
+        frame.extend_from_slice(b"method");
+        frame.push(0xa4);
+        frame.extend_from_slice(b"ping");
+        Bytes::from(frame)
+    }
+
+    #[tokio::test]
+    async fn test_binary_framing_skips_utf8_validation() {
+        let payload = msgpack_ping();
+        assert!(std::str::from_utf8(&payload).is_err());
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let remote_addr = listener.local_addr().unwrap();
+        let transport = TcpTransportBuilder::new()
+            .bind_addr("127.0.0.1:0".parse().unwrap())
+            .remote_addr(remote_addr)
+            .framing(TcpFraming::LengthDelimited)
+            .build();
+        transport.connect().await.unwrap();
+
+        let (peer, _) = listener.accept().await.unwrap();
+        let mut peer = Framed::new(peer, LengthDelimitedCodec::new());
+
+        // Peer -> transport: binary frame arrives byte-for-byte.
+        peer.send(payload.clone()).await.unwrap();
+        let received = tokio::time::timeout(std::time::Duration::from_secs(5), transport.receive())
+            .await
+            .unwrap()
+            .unwrap()
+            .expect("frame");
+        assert_eq!(received.payload, payload);
+
+        // Transport -> peer: binary payload is not rejected.
+        transport
+            .send(TransportMessage::new(MessageId::from(1), payload.clone()))
+            .await
+            .unwrap();
+        let echoed = tokio::time::timeout(std::time::Duration::from_secs(5), peer.next())
+            .await
+            .unwrap()
+            .expect("frame")
+            .unwrap();
+        assert_eq!(echoed.freeze(), payload);
+
+        transport.disconnect().await.unwrap();
+    }
+
+    #[test]
+    fn test_frame_codec_counts_partial_then_complete_frame() {
+        let metrics = Arc::new(AtomicMetrics::new());
+        let mut codec = FrameCodec::new(TcpFraming::Lines, Arc::clone(&metrics));
+
+        let mut buf = BytesMut::from(&br#"{"jsonrpc":"2.0","#[..]);
+        assert!(codec.decode(&mut buf).unwrap().is_none());
+
+        buf.extend_from_slice(b"\"method\":\"ping\"}\n");
+        let frame = codec.decode(&mut buf).unwrap().expect("complete frame");
+        assert_eq!(&frame[..], br#"{"jsonrpc":"2.0","method":"ping"}"#);
+        // Drained buffer: nothing pending, so not a partial read.
+        assert!(codec.decode(&mut buf).unwrap().is_none());
+
+        let framing = metrics.snapshot().framing().expect("framing recorded");
+        assert_eq!(framing.partial_frames, 1);
+        assert_eq!(framing.frames_received, 1);
+        assert_eq!(framing.frame_bytes_received, frame.len() as u64);
+        assert_eq!(framing.oversized_frames, 0);
+    }
+
+    #[test]
+    fn test_frame_codec_counts_oversized_frames() {
+        let metrics = Arc::new(AtomicMetrics::new());
+        let mut codec = FrameCodec::new(TcpFraming::LengthDelimited, Arc::clone(&metrics));
+
+        let too_long = u32::try_from(turbomcp_protocol::MAX_MESSAGE_SIZE + 1).unwrap();
+        let mut buf = BytesMut::from(&too_long.to_be_bytes()[..]);
+        assert!(codec.decode(&mut buf).is_err());
+
+        codec
+            .encode(Bytes::from_static(b"\x81\xa2id\x01"), &mut BytesMut::new())
+            .unwrap();
+
+        let framing = metrics.snapshot().framing().expect("framing recorded");
+        assert_eq!(framing.oversized_frames, 1);
+        assert_eq!(framing.frames_received, 0);
+        assert_eq!(framing.frames_sent, 1);
+        assert_eq!(framing.frame_bytes_sent, 5);
+    }
+
+    #[tokio::test]
+    async fn test_line_framing_rejects_non_utf8() {
+        let transport = TcpTransportBuilder::new().build();
+        let err = transport
+            .send(TransportMessage::new(MessageId::from(1), msgpack_ping()))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, TransportError::SerializationFailed(_)));
+    }
+
+    #[tokio::test]
+    async fn test_slow_write_trips_write_timeout_but_not_reads() {
+        use tokio::io::AsyncWriteExt;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let remote_addr = listener.local_addr().unwrap();
+        let transport = TcpTransportBuilder::new()
+            .bind_addr("127.0.0.1:0".parse().unwrap())
+            .remote_addr(remote_addr)
+            .timeouts(&{
+                let mut timeouts =
+                    TimeoutConfig::default().with_write(Some(Duration::from_millis(200)));
+                timeouts.read = Some(Duration::from_secs(5));
+                timeouts
+            })
+            .build();
+        transport.connect().await.unwrap();
+
+        // The peer writes but never reads, so our socket buffers fill up.
+        let (peer, _) = listener.accept().await.unwrap();
+        let (_peer_read, mut peer_write) = peer.into_split();
+        let frame = Bytes::from(format!(r#"{{"data":"{}"}}"#, "x".repeat(512 * 1024)));
+
+        let started = std::time::Instant::now();
+        let err = loop {
+            match transport
+                .send(TransportMessage::new(MessageId::from(1), frame.clone()))
+                .await
+            {
+                Ok(()) => {
+                    assert!(
+                        started.elapsed() < Duration::from_secs(10),
+                        "write timeout never fired"
+                    );
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                }
+                Err(err) => break err,
+            }
+        };
+        assert!(
+            matches!(err, TransportError::WriteTimeout { timeout, .. } if timeout == Duration::from_millis(200)),
+            "{err:?}"
+        );
+
+        // Reads keep working on the same connection.
+        peer_write
+            .write_all(b"{\"jsonrpc\":\"2.0\",\"id\":7,\"method\":\"ping\"}\n")
+            .await
+            .unwrap();
+        let received = tokio::time::timeout(Duration::from_secs(5), transport.receive())
+            .await
+            .expect("read should not time out")
+            .unwrap()
+            .expect("frame");
+        assert_eq!(
+            &received.payload[..],
+            br#"{"jsonrpc":"2.0","id":7,"method":"ping"}"#
+        );
+
+        transport.disconnect().await.unwrap();
+    }
+
     #[tokio::test]
     async fn test_tcp_transport_state() {
         let transport = TcpTransportBuilder::new().build();
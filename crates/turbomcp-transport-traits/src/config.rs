@@ -1,6 +1,7 @@
 //! Transport configuration types.
 
 use serde::{Deserialize, Serialize};
+use std::sync::{Arc, PoisonError, RwLock};
 use std::time::Duration;
 
 /// TLS protocol version specification.
@@ -73,6 +74,100 @@ impl TlsConfig {
     }
 }
 
+/// A TLS identity: a PEM certificate chain and its PEM private key.
+///
+/// Clients present it for mutual TLS. The HTTP and gRPC servers do not
+/// terminate TLS themselves, so server certificates are configured and
+/// rotated wherever TLS is terminated in front of them.
+#[derive(Clone, PartialEq, Eq)]
+pub struct TlsIdentity {
+    /// PEM-encoded certificate chain, leaf first.
+    pub cert_chain_pem: Vec<u8>,
+
+    /// PEM-encoded private key for the leaf certificate.
+    pub key_pem: Vec<u8>,
+}
+
+impl TlsIdentity {
+    /// Create an identity from a PEM certificate chain and PEM private key.
+    #[must_use]
+    pub fn from_pem(cert_chain_pem: impl Into<Vec<u8>>, key_pem: impl Into<Vec<u8>>) -> Self {
+        Self {
+            cert_chain_pem: cert_chain_pem.into(),
+            key_pem: key_pem.into(),
+        }
+    }
+
+    /// Certificate chain and key concatenated into a single PEM bundle.
+    #[must_use]
+    pub fn to_pem_bundle(&self) -> Vec<u8> {
+        let mut bundle = Vec::with_capacity(self.cert_chain_pem.len() + self.key_pem.len() + 1);
+        bundle.extend_from_slice(&self.cert_chain_pem);
+        if !bundle.ends_with(b"\n") {
+            bundle.push(b'\n');
+        }
+        bundle.extend_from_slice(&self.key_pem);
+        bundle
+    }
+}
+
+impl std::fmt::Debug for TlsIdentity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TlsIdentity")
+            .field(
+                "cert_chain_pem",
+                &format_args!("{} bytes", self.cert_chain_pem.len()),
+            )
+            .field("key_pem", &"<redacted>")
+            .finish()
+    }
+}
+
+/// A client TLS identity that can be replaced while the transport is running.
+///
+/// Clones share the same slot, so an application keeps one handle and calls
+/// [`reload`](Self::reload) when its certificate is rotated. Transports check
+/// [`generation`](Self::generation) before opening a connection and pick up the
+/// new identity for every connection made after the reload; connections that
+/// are already established keep the identity they were opened with.
+#[derive(Debug, Clone)]
+pub struct ReloadableTlsIdentity {
+    slot: Arc<RwLock<(u64, Arc<TlsIdentity>)>>,
+}
+
+impl ReloadableTlsIdentity {
+    /// Create a reloadable identity starting at generation 0.
+    #[must_use]
+    pub fn new(identity: TlsIdentity) -> Self {
+        Self {
+            slot: Arc::new(RwLock::new((0, Arc::new(identity)))),
+        }
+    }
+
+    /// The current identity and its generation.
+    #[must_use]
+    pub fn current(&self) -> (u64, Arc<TlsIdentity>) {
+        let slot = self.slot.read().unwrap_or_else(PoisonError::into_inner);
+        (slot.0, Arc::clone(&slot.1))
+    }
+
+    /// Generation of the current identity; incremented by every reload.
+    #[must_use]
+    pub fn generation(&self) -> u64 {
+        self.slot.read().unwrap_or_else(PoisonError::into_inner).0
+    }
+
+    /// Replace the identity used for subsequent connections.
+    ///
+    /// Returns the new generation.
+    pub fn reload(&self, identity: TlsIdentity) -> u64 {
+        let mut slot = self.slot.write().unwrap_or_else(PoisonError::into_inner);
+        slot.0 += 1;
+        slot.1 = Arc::new(identity);
+        slot.0
+    }
+}
+
 /// Configuration for request and response size limits.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LimitsConfig {
@@ -121,7 +216,15 @@ impl LimitsConfig {
 }
 
 /// Configuration for request and operation timeouts.
+///
+/// `connect`, `read`, and `write` are socket-level deadlines applied by the
+/// transports independently of one another: a peer that is slow to accept our
+/// bytes trips `write` without affecting `read`, and vice versa.
+///
+/// Start from `Default` or a preset and set the fields you need; the write
+/// deadline is set with [`with_write`](Self::with_write).
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct TimeoutConfig {
     /// Connection establishment timeout.
     pub connect: Duration,
@@ -137,6 +240,12 @@ pub struct TimeoutConfig {
     /// Read timeout (for streaming responses).
     /// `None` = no timeout
     pub read: Option<Duration>,
+
+    /// Write timeout: how long a single outgoing frame may take to be
+    /// accepted by the peer.
+    /// `None` = no timeout
+    #[serde(default)]
+    pub(crate) write: Option<Duration>,
 }
 
 impl Default for TimeoutConfig {
@@ -146,6 +255,7 @@ impl Default for TimeoutConfig {
             request: Some(Duration::from_secs(60)),
             total: Some(Duration::from_secs(120)),
             read: Some(Duration::from_secs(30)),
+            write: Some(Duration::from_secs(30)),
         }
     }
 }
@@ -159,6 +269,7 @@ impl TimeoutConfig {
             request: Some(Duration::from_secs(10)),
             total: Some(Duration::from_secs(15)),
             read: Some(Duration::from_secs(5)),
+            write: Some(Duration::from_secs(5)),
         }
     }
 
@@ -170,6 +281,7 @@ impl TimeoutConfig {
             request: None,
             total: None,
             read: None,
+            write: None,
         }
     }
 
@@ -181,8 +293,23 @@ impl TimeoutConfig {
             request: Some(Duration::from_secs(300)), // 5 minutes
             total: Some(Duration::from_secs(600)),   // 10 minutes
             read: Some(Duration::from_secs(120)),    // 2 minutes
+            write: Some(Duration::from_secs(120)),   // 2 minutes
         }
     }
+
+    /// Set the write timeout (`None` = no timeout).
+    #[must_use]
+    pub const fn with_write(mut self, write: Option<Duration>) -> Self {
+        self.write = write;
+        self
+    }
+
+    /// Write timeout: how long a single outgoing frame may take to be
+    /// accepted by the peer.
+    #[must_use]
+    pub const fn write(&self) -> Option<Duration> {
+        self.write
+    }
 }
 
 #[cfg(test)]
@@ -196,6 +323,20 @@ mod tests {
         assert!(!config.is_insecure());
     }
 
+    #[test]
+    fn test_reloadable_identity_bumps_generation() {
+        let identity = ReloadableTlsIdentity::new(TlsIdentity::from_pem("cert-a", "key-a"));
+        let handle = identity.clone();
+        assert_eq!(identity.generation(), 0);
+
+        assert_eq!(handle.reload(TlsIdentity::from_pem("cert-b", "key-b")), 1);
+        let (generation, current) = identity.current();
+        assert_eq!(generation, 1);
+        assert_eq!(current.cert_chain_pem, b"cert-b");
+        assert_eq!(current.to_pem_bundle(), b"cert-b\nkey-b");
+        assert!(!format!("{current:?}").contains("key-b"));
+    }
+
     #[test]
     fn test_limits_config_default() {
         let config = LimitsConfig::default();
@@ -208,5 +349,6 @@ mod tests {
         let config = TimeoutConfig::default();
         assert_eq!(config.connect, Duration::from_secs(30));
         assert_eq!(config.request, Some(Duration::from_secs(60)));
+        assert_eq!(config.write, Some(Duration::from_secs(30)));
     }
 }
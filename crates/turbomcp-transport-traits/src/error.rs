@@ -97,6 +97,20 @@ pub enum TransportError {
         timeout: Duration,
     },
 
+    /// Write operation timed out: the peer stopped accepting data.
+    #[error(
+        "Write timed out after {timeout:?} for operation: {operation}. \
+         If this is expected, increase the timeout with \
+         `TimeoutConfig::with_write(Some(Duration::from_secs({})))`",
+        timeout.as_secs().max(1) * 2
+    )]
+    WriteTimeout {
+        /// The operation that timed out
+        operation: String,
+        /// The timeout duration that was exceeded
+        timeout: Duration,
+    },
+
     /// The transport was configured with invalid parameters.
     #[error("Configuration error: {0}")]
     ConfigurationError(String),
@@ -222,6 +236,13 @@ impl From<TransportError> for turbomcp_protocol::McpError {
                     timeout, operation
                 ),
             ),
+            TransportError::WriteTimeout { operation, timeout } => (
+                ErrorKind::Timeout,
+                format!(
+                    "Write timed out after {:?} for operation: {}",
+                    timeout, operation
+                ),
+            ),
             TransportError::ConfigurationError(msg) => (
                 ErrorKind::Configuration,
                 format!("Configuration error: {}", msg),
@@ -3,7 +3,7 @@
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 
-use tokio::sync::mpsc;
+use tokio::sync::{broadcast, mpsc};
 use turbomcp_protocol::MessageId;
 
 use crate::error::TransportError;
@@ -63,10 +63,20 @@ pub enum TransportEvent {
     },
 }
 
+/// Capacity of the broadcast channel behind [`TransportEventEmitter::subscribe`].
+///
+/// Subscribers that fall further behind than this observe
+/// [`broadcast::error::RecvError::Lagged`] and skip ahead.
+pub const TRANSPORT_EVENT_CAPACITY: usize = 256;
+
 /// An emitter for broadcasting `TransportEvent`s to listeners.
+///
+/// Events go to the receiver returned by [`Self::new`] and to every
+/// subscription created with [`Self::subscribe`].
 #[derive(Debug, Clone)]
 pub struct TransportEventEmitter {
     sender: mpsc::Sender<TransportEvent>,
+    subscribers: broadcast::Sender<TransportEvent>,
     /// Counter incremented every time an event is dropped because the channel is full.
     /// Observers can read it via [`Self::dropped_events`] to detect lossy emission.
     dropped: Arc<AtomicU64>,
@@ -77,9 +87,11 @@ impl TransportEventEmitter {
     #[must_use]
     pub fn new() -> (Self, mpsc::Receiver<TransportEvent>) {
         let (sender, receiver) = mpsc::channel(500);
+        let (subscribers, _) = broadcast::channel(TRANSPORT_EVENT_CAPACITY);
         (
             Self {
                 sender,
+                subscribers,
                 dropped: Arc::new(AtomicU64::new(0)),
             },
             receiver,
@@ -89,11 +101,23 @@ impl TransportEventEmitter {
     /// Emits an event, dropping it (and incrementing the dropped-events counter)
     /// if the channel is full to avoid blocking.
     pub fn emit(&self, event: TransportEvent) {
+        // broadcast::send only fails when nobody is subscribed, which is fine.
+        let _ = self.subscribers.send(event.clone());
         if self.sender.try_send(event).is_err() {
             self.dropped.fetch_add(1, Ordering::Relaxed);
         }
     }
 
+    /// Subscribes to every event emitted from now on.
+    ///
+    /// Each call returns an independent receiver, so several parts of an
+    /// application (a status bar, an alerting hook) can observe the same
+    /// transport.
+    #[must_use]
+    pub fn subscribe(&self) -> broadcast::Receiver<TransportEvent> {
+        self.subscribers.subscribe()
+    }
+
     /// Returns the number of events dropped due to a full channel since this
     /// emitter was created. Use to surface backpressure loss in observability tooling.
     #[must_use]
@@ -172,4 +196,20 @@ mod tests {
             _ => panic!("Unexpected event variant"),
         }
     }
+
+    #[tokio::test]
+    async fn test_subscribers_receive_events() {
+        let (emitter, _receiver) = TransportEventEmitter::new();
+        let mut first = emitter.subscribe();
+        let mut second = emitter.subscribe();
+
+        emitter.emit_disconnected(TransportType::Stdio, "stdio://".to_string(), None);
+
+        for subscription in [&mut first, &mut second] {
+            assert!(matches!(
+                subscription.recv().await.unwrap(),
+                TransportEvent::Disconnected { .. }
+            ));
+        }
+    }
 }
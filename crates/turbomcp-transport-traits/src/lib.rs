@@ -11,6 +11,7 @@
 //! - **Errors**: [`TransportError`], [`TransportResult`]
 //! - **Config**: [`LimitsConfig`], [`TimeoutConfig`], [`TlsConfig`]
 //! - **Metrics**: [`TransportMetrics`], [`AtomicMetrics`]
+//! - **Send ordering**: [`PriorityQueue`], [`priority_channel`]
 //!
 //! ## Usage
 //!
@@ -45,16 +46,23 @@ mod error;
 mod events;
 mod message;
 mod metrics;
+mod priority;
 mod traits;
 mod types;
 
 // Re-export all public items
 pub use bidirectional::{ConnectionState, CorrelationContext, MessageDirection};
-pub use config::{LimitsConfig, TimeoutConfig, TlsConfig, TlsVersion};
+pub use config::{
+    LimitsConfig, ReloadableTlsIdentity, TimeoutConfig, TlsConfig, TlsIdentity, TlsVersion,
+};
 pub use error::{TransportError, TransportResult};
-pub use events::{TransportEvent, TransportEventEmitter};
+pub use events::{TRANSPORT_EVENT_CAPACITY, TransportEvent, TransportEventEmitter};
 pub use message::{TransportMessage, TransportMessageMetadata};
-pub use metrics::{AtomicMetrics, TransportMetrics};
+pub use metrics::{AtomicMetrics, FRAMING_METADATA_KEY, FrameMetrics, TransportMetrics};
+pub use priority::{
+    CONTROL_PRIORITY, DEFAULT_PRIORITY, PriorityQueue, PriorityReceiver, PrioritySender,
+    TrySendError, priority_channel,
+};
 pub use traits::{BidirectionalTransport, Transport, TransportFactory};
 pub use types::{TransportCapabilities, TransportConfig, TransportState, TransportType};
 
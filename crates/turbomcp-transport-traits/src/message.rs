@@ -75,6 +75,11 @@ impl TransportMessage {
     pub fn correlation_id(&self) -> Option<&str> {
         self.metadata.correlation_id.as_deref()
     }
+
+    /// Returns the send priority, [`DEFAULT_PRIORITY`](crate::DEFAULT_PRIORITY) if unset.
+    pub fn priority(&self) -> u8 {
+        self.metadata.priority.unwrap_or(crate::DEFAULT_PRIORITY)
+    }
 }
 
 /// Metadata associated with a `TransportMessage`.
@@ -40,6 +40,47 @@ pub struct TransportMetrics {
     pub metadata: HashMap<String, serde_json::Value>,
 }
 
+/// Key of the [`FrameMetrics`] entry in [`TransportMetrics::metadata`].
+pub const FRAMING_METADATA_KEY: &str = "framing";
+
+impl TransportMetrics {
+    /// Wire framing statistics, if this transport recorded any.
+    ///
+    /// Only the TCP transport splits its byte stream into frames and records
+    /// these; every other transport returns `None`.
+    pub fn framing(&self) -> Option<FrameMetrics> {
+        self.metadata
+            .get(FRAMING_METADATA_KEY)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+    }
+}
+
+/// Framing statistics for diagnosing partial reads and oversized frames.
+///
+/// Recorded only by the TCP transport, and reported through
+/// [`TransportMetrics::framing`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct FrameMetrics {
+    /// Complete frames written to the wire.
+    pub frames_sent: u64,
+
+    /// Complete frames decoded from the wire.
+    pub frames_received: u64,
+
+    /// Payload bytes of frames written, excluding delimiters and headers.
+    pub frame_bytes_sent: u64,
+
+    /// Payload bytes of frames decoded, excluding delimiters and headers.
+    pub frame_bytes_received: u64,
+
+    /// Reads that ended mid-frame and had to wait for more data.
+    pub partial_frames: u64,
+
+    /// Frames rejected for exceeding the maximum frame size.
+    pub oversized_frames: u64,
+}
+
 /// A lock-free, atomic structure for high-performance metrics updates.
 #[derive(Debug)]
 pub struct AtomicMetrics {
@@ -72,6 +113,13 @@ pub struct AtomicMetrics {
 
     /// Total bytes after compression.
     compressed_bytes: AtomicU64,
+
+    frames_sent: AtomicU64,
+    frames_received: AtomicU64,
+    frame_bytes_sent: AtomicU64,
+    frame_bytes_received: AtomicU64,
+    partial_frames: AtomicU64,
+    oversized_frames: AtomicU64,
 }
 
 impl Default for AtomicMetrics {
@@ -87,6 +135,12 @@ impl Default for AtomicMetrics {
             avg_latency_us: AtomicU64::new(0),
             uncompressed_bytes: AtomicU64::new(0),
             compressed_bytes: AtomicU64::new(0),
+            frames_sent: AtomicU64::new(0),
+            frames_received: AtomicU64::new(0),
+            frame_bytes_sent: AtomicU64::new(0),
+            frame_bytes_received: AtomicU64::new(0),
+            partial_frames: AtomicU64::new(0),
+            oversized_frames: AtomicU64::new(0),
         }
     }
 }
@@ -118,6 +172,30 @@ impl AtomicMetrics {
             .fetch_add(compressed_size, Ordering::Relaxed);
     }
 
+    /// Records a complete frame with `payload_len` bytes written to the wire.
+    pub fn record_frame_sent(&self, payload_len: u64) {
+        self.frames_sent.fetch_add(1, Ordering::Relaxed);
+        self.frame_bytes_sent
+            .fetch_add(payload_len, Ordering::Relaxed);
+    }
+
+    /// Records a complete frame with `payload_len` bytes decoded from the wire.
+    pub fn record_frame_received(&self, payload_len: u64) {
+        self.frames_received.fetch_add(1, Ordering::Relaxed);
+        self.frame_bytes_received
+            .fetch_add(payload_len, Ordering::Relaxed);
+    }
+
+    /// Records a read that ended mid-frame.
+    pub fn record_partial_frame(&self) {
+        self.partial_frames.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Records a frame rejected for exceeding the maximum frame size.
+    pub fn record_oversized_frame(&self) {
+        self.oversized_frames.fetch_add(1, Ordering::Relaxed);
+    }
+
     /// Creates a serializable `TransportMetrics` snapshot from the current atomic values.
     pub fn snapshot(&self) -> TransportMetrics {
         let avg_latency_us = self.avg_latency_us.load(Ordering::Relaxed);
@@ -130,6 +208,21 @@ impl AtomicMetrics {
             None
         };
 
+        let framing = FrameMetrics {
+            frames_sent: self.frames_sent.load(Ordering::Relaxed),
+            frames_received: self.frames_received.load(Ordering::Relaxed),
+            frame_bytes_sent: self.frame_bytes_sent.load(Ordering::Relaxed),
+            frame_bytes_received: self.frame_bytes_received.load(Ordering::Relaxed),
+            partial_frames: self.partial_frames.load(Ordering::Relaxed),
+            oversized_frames: self.oversized_frames.load(Ordering::Relaxed),
+        };
+        let mut metadata = HashMap::new();
+        if framing != FrameMetrics::default()
+            && let Ok(value) = serde_json::to_value(framing)
+        {
+            metadata.insert(FRAMING_METADATA_KEY.to_string(), value);
+        }
+
         TransportMetrics {
             bytes_sent: self.bytes_sent.load(Ordering::Relaxed),
             bytes_received: self.bytes_received.load(Ordering::Relaxed),
@@ -140,7 +233,7 @@ impl AtomicMetrics {
             active_connections: self.active_connections.load(Ordering::Relaxed),
             average_latency_ms: (avg_latency_us as f64) / 1000.0,
             compression_ratio,
-            metadata: HashMap::new(),
+            metadata,
         }
     }
 
@@ -156,6 +249,12 @@ impl AtomicMetrics {
         self.avg_latency_us.store(0, Ordering::Relaxed);
         self.uncompressed_bytes.store(0, Ordering::Relaxed);
         self.compressed_bytes.store(0, Ordering::Relaxed);
+        self.frames_sent.store(0, Ordering::Relaxed);
+        self.frames_received.store(0, Ordering::Relaxed);
+        self.frame_bytes_sent.store(0, Ordering::Relaxed);
+        self.frame_bytes_received.store(0, Ordering::Relaxed);
+        self.partial_frames.store(0, Ordering::Relaxed);
+        self.oversized_frames.store(0, Ordering::Relaxed);
     }
 }
 
@@ -192,6 +291,27 @@ mod tests {
         assert_eq!(snapshot.bytes_sent, 0);
     }
 
+    #[test]
+    fn test_frame_metrics_snapshot_and_reset() {
+        let metrics = AtomicMetrics::new();
+        assert_eq!(metrics.snapshot().framing(), None);
+        metrics.record_partial_frame();
+        metrics.record_frame_received(42);
+        metrics.record_frame_sent(7);
+        metrics.record_oversized_frame();
+
+        let framing = metrics.snapshot().framing().expect("framing recorded");
+        assert_eq!(framing.partial_frames, 1);
+        assert_eq!(framing.frames_received, 1);
+        assert_eq!(framing.frame_bytes_received, 42);
+        assert_eq!(framing.frames_sent, 1);
+        assert_eq!(framing.frame_bytes_sent, 7);
+        assert_eq!(framing.oversized_frames, 1);
+
+        metrics.reset();
+        assert_eq!(metrics.snapshot().framing(), None);
+    }
+
     #[test]
     fn test_ema_overflow_protection() {
         let metrics = AtomicMetrics::new();
@@ -0,0 +1,304 @@
+//! Priority-ordered send queues for bidirectional transports.
+//!
+//! Under load a transport may buffer many outgoing frames. Control messages
+//! such as `notifications/cancelled` or `ping` should not wait behind bulk
+//! data, so transports drain their buffers through a [`PriorityQueue`]: frames
+//! with a higher [`TransportMessageMetadata::priority`] are flushed first, and
+//! frames of equal priority keep their send order.
+//!
+//! [`TransportMessageMetadata::priority`]: crate::TransportMessageMetadata::priority
+
+use std::cmp::Ordering as CmpOrdering;
+use std::collections::BinaryHeap;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use tokio::sync::Notify;
+pub use tokio::sync::mpsc::error::TrySendError;
+
+/// Priority for messages without one: sent after everything prioritized.
+pub const DEFAULT_PRIORITY: u8 = 0;
+
+/// Priority for control messages (cancellation, ping) that should be sent
+/// ahead of any buffered data.
+pub const CONTROL_PRIORITY: u8 = u8::MAX;
+
+/// Queue that yields the highest-priority item first, FIFO within a priority.
+pub struct PriorityQueue<T> {
+    heap: BinaryHeap<Entry<T>>,
+    next_seq: u64,
+}
+
+struct Entry<T> {
+    priority: u8,
+    seq: u64,
+    item: T,
+}
+
+impl<T> PartialEq for Entry<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == CmpOrdering::Equal
+    }
+}
+
+impl<T> Eq for Entry<T> {}
+
+impl<T> PartialOrd for Entry<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<CmpOrdering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Entry<T> {
+    fn cmp(&self, other: &Self) -> CmpOrdering {
+        // Max-heap: higher priority wins, then the earlier sequence number.
+        self.priority
+            .cmp(&other.priority)
+            .then_with(|| other.seq.cmp(&self.seq))
+    }
+}
+
+impl<T> PriorityQueue<T> {
+    /// Create an empty queue.
+    pub fn new() -> Self {
+        Self {
+            heap: BinaryHeap::new(),
+            next_seq: 0,
+        }
+    }
+
+    /// Add an item at the given priority.
+    pub fn push(&mut self, priority: u8, item: T) {
+        let seq = self.next_seq;
+        self.next_seq = self.next_seq.wrapping_add(1);
+        self.heap.push(Entry {
+            priority,
+            seq,
+            item,
+        });
+    }
+
+    /// Remove the next item to send.
+    pub fn pop(&mut self) -> Option<T> {
+        self.heap.pop().map(|entry| entry.item)
+    }
+
+    /// Number of queued items.
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Whether the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Drop every queued item.
+    pub fn clear(&mut self) {
+        self.heap.clear();
+    }
+}
+
+impl<T> Default for PriorityQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for PriorityQueue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PriorityQueue")
+            .field("len", &self.heap.len())
+            .finish()
+    }
+}
+
+/// Create a bounded channel whose receiver yields items by priority.
+///
+/// It mirrors `tokio::sync::mpsc`: [`PrioritySender::try_send`] fails with
+/// [`TrySendError::Full`] once `capacity` items are buffered and with
+/// [`TrySendError::Closed`] after the receiver is dropped, and
+/// [`PriorityReceiver::recv`] returns `None` once every sender is gone and the
+/// buffer is drained.
+pub fn priority_channel<T>(capacity: usize) -> (PrioritySender<T>, PriorityReceiver<T>) {
+    let shared = Arc::new(Shared {
+        queue: Mutex::new(PriorityQueue::new()),
+        notify: Notify::new(),
+        capacity: capacity.max(1),
+        senders: AtomicUsize::new(1),
+        receiver_closed: AtomicBool::new(false),
+    });
+    (
+        PrioritySender {
+            shared: Arc::clone(&shared),
+        },
+        PriorityReceiver { shared },
+    )
+}
+
+struct Shared<T> {
+    queue: Mutex<PriorityQueue<T>>,
+    notify: Notify,
+    capacity: usize,
+    senders: AtomicUsize,
+    receiver_closed: AtomicBool,
+}
+
+impl<T> Shared<T> {
+    fn queue(&self) -> MutexGuard<'_, PriorityQueue<T>> {
+        self.queue.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Sending half of a [`priority_channel`].
+pub struct PrioritySender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> PrioritySender<T> {
+    /// Buffer `item` at `priority` without waiting.
+    pub fn try_send(&self, priority: u8, item: T) -> Result<(), TrySendError<T>> {
+        if self.shared.receiver_closed.load(Ordering::Acquire) {
+            return Err(TrySendError::Closed(item));
+        }
+        {
+            let mut queue = self.shared.queue();
+            if queue.len() >= self.shared.capacity {
+                return Err(TrySendError::Full(item));
+            }
+            queue.push(priority, item);
+        }
+        self.shared.notify.notify_one();
+        Ok(())
+    }
+
+    /// Whether the receiver has been dropped.
+    pub fn is_closed(&self) -> bool {
+        self.shared.receiver_closed.load(Ordering::Acquire)
+    }
+}
+
+impl<T> Clone for PrioritySender<T> {
+    fn clone(&self) -> Self {
+        self.shared.senders.fetch_add(1, Ordering::Relaxed);
+        Self {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<T> Drop for PrioritySender<T> {
+    fn drop(&mut self) {
+        if self.shared.senders.fetch_sub(1, Ordering::AcqRel) == 1 {
+            // Wake the receiver so it can observe the closed channel.
+            self.shared.notify.notify_one();
+        }
+    }
+}
+
+impl<T> fmt::Debug for PrioritySender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PrioritySender")
+            .field("closed", &self.is_closed())
+            .finish()
+    }
+}
+
+/// Receiving half of a [`priority_channel`].
+pub struct PriorityReceiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> PriorityReceiver<T> {
+    /// Wait for the highest-priority buffered item.
+    ///
+    /// Returns `None` once all senders are dropped and nothing is buffered.
+    pub async fn recv(&mut self) -> Option<T> {
+        loop {
+            if let Some(item) = self.try_recv() {
+                return Some(item);
+            }
+            if self.shared.senders.load(Ordering::Acquire) == 0 {
+                // A send may have raced with the last sender's drop.
+                return self.try_recv();
+            }
+            // `notify_one` stores a permit, so a send between the check
+            // above and this await is not missed.
+            self.shared.notify.notified().await;
+        }
+    }
+
+    /// Take the highest-priority buffered item, if any.
+    pub fn try_recv(&mut self) -> Option<T> {
+        self.shared.queue().pop()
+    }
+}
+
+impl<T> Drop for PriorityReceiver<T> {
+    fn drop(&mut self) {
+        self.shared.receiver_closed.store(true, Ordering::Release);
+    }
+}
+
+impl<T> fmt::Debug for PriorityReceiver<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PriorityReceiver")
+            .field("buffered", &self.shared.queue().len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_queue_orders_by_priority_then_fifo() {
+        let mut queue = PriorityQueue::new();
+        queue.push(DEFAULT_PRIORITY, "bulk-1");
+        queue.push(DEFAULT_PRIORITY, "bulk-2");
+        queue.push(5, "progress");
+        queue.push(CONTROL_PRIORITY, "cancel");
+        queue.push(DEFAULT_PRIORITY, "bulk-3");
+
+        let drained: Vec<_> = std::iter::from_fn(|| queue.pop()).collect();
+        assert_eq!(
+            drained,
+            vec!["cancel", "progress", "bulk-1", "bulk-2", "bulk-3"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_channel_sends_cancellation_before_buffered_bulk() {
+        let (tx, mut rx) = priority_channel(16);
+        for chunk in 0..3 {
+            tx.try_send(DEFAULT_PRIORITY, format!("bulk-{chunk}"))
+                .unwrap();
+        }
+        tx.try_send(CONTROL_PRIORITY, "cancel".to_string()).unwrap();
+
+        assert_eq!(rx.recv().await.as_deref(), Some("cancel"));
+        assert_eq!(rx.recv().await.as_deref(), Some("bulk-0"));
+
+        drop(tx);
+        assert_eq!(rx.recv().await.as_deref(), Some("bulk-1"));
+        assert_eq!(rx.recv().await.as_deref(), Some("bulk-2"));
+        assert_eq!(rx.recv().await, None);
+    }
+
+    #[test]
+    fn test_channel_reports_full_and_closed() {
+        let (tx, rx) = priority_channel(1);
+        tx.try_send(DEFAULT_PRIORITY, 1).unwrap();
+        assert!(matches!(
+            tx.try_send(CONTROL_PRIORITY, 2),
+            Err(TrySendError::Full(2))
+        ));
+        drop(rx);
+        assert!(matches!(
+            tx.try_send(DEFAULT_PRIORITY, 3),
+            Err(TrySendError::Closed(3))
+        ));
+    }
+}
@@ -4,7 +4,10 @@ use std::future::Future;
 use std::pin::Pin;
 use std::time::Duration;
 
+use tokio::sync::broadcast;
+
 use crate::error::TransportResult;
+use crate::events::TransportEvent;
 use crate::message::TransportMessage;
 use crate::metrics::TransportMetrics;
 use crate::types::{TransportCapabilities, TransportConfig, TransportState, TransportType};
@@ -35,6 +38,16 @@ pub trait Transport: Send + Sync + std::fmt::Debug {
         message: TransportMessage,
     ) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>>;
 
+    /// Writes out any messages buffered by previous [`send`](Self::send) calls.
+    ///
+    /// Transports that batch or compress writes may hold messages back after
+    /// `send` returns; callers that need delivery at a boundary (for example
+    /// before awaiting a response) call `flush`. The default is a no-op for
+    /// transports that write every message immediately.
+    fn flush(&self) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
+        Box::pin(async { Ok(()) })
+    }
+
     /// Receives a single message from the transport in a non-blocking way.
     fn receive(
         &self,
@@ -53,6 +66,33 @@ pub trait Transport: Send + Sync + std::fmt::Debug {
         None
     }
 
+    /// Subscribes to this transport's lifecycle events.
+    ///
+    /// Returns `None` if the transport does not publish events. Otherwise the
+    /// receiver observes every [`TransportEvent`] emitted after the call, such
+    /// as `Connected`, `Disconnected`, and `Error`.
+    fn subscribe_events(&self) -> Option<broadcast::Receiver<TransportEvent>> {
+        None
+    }
+
+    /// Returns the MCP protocol version negotiated with the peer, if known.
+    ///
+    /// Set by the client once `initialize` completes, so proxies and
+    /// middleware that only hold the transport can pick version-appropriate
+    /// behavior without re-parsing the handshake. Returns `None` before the
+    /// handshake and for transports that do not track it.
+    fn peer_protocol_version(&self) -> Option<String> {
+        None
+    }
+
+    /// Records the protocol version negotiated during `initialize`.
+    ///
+    /// The default discards it; transports that track the peer's version
+    /// return it from [`peer_protocol_version`](Self::peer_protocol_version).
+    fn set_peer_protocol_version(&self, version: &str) {
+        let _ = version;
+    }
+
     /// Applies a new configuration to the transport.
     fn configure(
         &self,
@@ -0,0 +1,294 @@
+//! Write batching for transports
+//!
+//! [`BatchingTransport`] holds outgoing messages in memory instead of writing
+//! each one as it is sent. Queued messages are written, in order, when
+//! [`Transport::flush`] is called, when the queue reaches its batch size, or
+//! before the transport disconnects. This suits bursts of notifications
+//! (progress, log messages) over transports where each write is costly.
+//!
+//! Callers that need a message on the wire at a particular point, such as
+//! before awaiting a response, must call `flush`.
+
+use std::future::Future;
+use std::pin::Pin;
+
+use tokio::sync::{Mutex, broadcast};
+
+use crate::core::{
+    Transport, TransportCapabilities, TransportConfig, TransportEvent, TransportMessage,
+    TransportMetrics, TransportResult, TransportState, TransportType,
+};
+
+/// Default number of messages queued before an automatic flush
+pub const DEFAULT_MAX_BATCH: usize = 64;
+
+/// Transport wrapper that batches outgoing messages until flushed
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use turbomcp_transport::{BatchingTransport, StdioTransport, Transport};
+///
+/// # async fn example() -> turbomcp_transport::core::TransportResult<()> {
+/// let transport = BatchingTransport::new(StdioTransport::new()).with_max_batch(16);
+/// transport.connect().await?;
+///
+/// // Queued, not yet written
+/// # let progress = turbomcp_transport::core::TransportMessage::new(
+/// #     turbomcp_protocol::MessageId::from("n"),
+/// #     bytes::Bytes::new(),
+/// # );
+/// transport.send(progress).await?;
+///
+/// // Written out
+/// transport.flush().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct BatchingTransport<T: Transport> {
+    inner: T,
+    max_batch: usize,
+    queue: Mutex<Vec<TransportMessage>>,
+}
+
+impl<T: Transport> BatchingTransport<T> {
+    /// Wrap `inner`, flushing automatically every [`DEFAULT_MAX_BATCH`] messages
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            max_batch: DEFAULT_MAX_BATCH,
+            queue: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Set the number of queued messages that triggers an automatic flush
+    ///
+    /// A value of `0` or `1` disables batching: every send is written at once.
+    #[must_use]
+    pub fn with_max_batch(mut self, max_batch: usize) -> Self {
+        self.max_batch = max_batch;
+        self
+    }
+
+    /// Number of queued messages that triggers an automatic flush
+    pub fn max_batch(&self) -> usize {
+        self.max_batch
+    }
+
+    /// Number of messages waiting for the next flush
+    pub async fn pending(&self) -> usize {
+        self.queue.lock().await.len()
+    }
+
+    /// Borrow the wrapped transport
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Write every queued message in order while holding the queue lock, so
+    /// concurrent sends cannot interleave with the batch. On failure the
+    /// unsent messages stay queued for the next flush.
+    async fn write_queued(&self, queue: &mut Vec<TransportMessage>) -> TransportResult<()> {
+        let mut written = 0;
+        let mut result = Ok(());
+        for message in queue.iter() {
+            if let Err(e) = self.inner.send(message.clone()).await {
+                result = Err(e);
+                break;
+            }
+            written += 1;
+        }
+        queue.drain(..written);
+        result?;
+        self.inner.flush().await
+    }
+}
+
+impl<T: Transport> Transport for BatchingTransport<T> {
+    fn transport_type(&self) -> TransportType {
+        self.inner.transport_type()
+    }
+
+    fn capabilities(&self) -> &TransportCapabilities {
+        self.inner.capabilities()
+    }
+
+    fn state(&self) -> Pin<Box<dyn Future<Output = TransportState> + Send + '_>> {
+        self.inner.state()
+    }
+
+    fn connect(&self) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
+        self.inner.connect()
+    }
+
+    fn disconnect(&self) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
+        Box::pin(async move {
+            let flushed = self.flush().await;
+            if let Err(e) = &flushed {
+                tracing::warn!(error = %e, "Failed to flush batched messages before disconnect");
+            }
+            self.inner.disconnect().await
+        })
+    }
+
+    fn send(
+        &self,
+        message: TransportMessage,
+    ) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
+        Box::pin(async move {
+            let mut queue = self.queue.lock().await;
+            queue.push(message);
+            if queue.len() >= self.max_batch {
+                self.write_queued(&mut queue).await?;
+            }
+            Ok(())
+        })
+    }
+
+    fn flush(&self) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
+        Box::pin(async move {
+            let mut queue = self.queue.lock().await;
+            self.write_queued(&mut queue).await
+        })
+    }
+
+    fn receive(
+        &self,
+    ) -> Pin<Box<dyn Future<Output = TransportResult<Option<TransportMessage>>> + Send + '_>> {
+        self.inner.receive()
+    }
+
+    fn metrics(&self) -> Pin<Box<dyn Future<Output = TransportMetrics> + Send + '_>> {
+        self.inner.metrics()
+    }
+
+    fn is_connected(&self) -> Pin<Box<dyn Future<Output = bool> + Send + '_>> {
+        self.inner.is_connected()
+    }
+
+    fn endpoint(&self) -> Option<String> {
+        self.inner.endpoint()
+    }
+
+    fn subscribe_events(&self) -> Option<broadcast::Receiver<TransportEvent>> {
+        self.inner.subscribe_events()
+    }
+
+    fn peer_protocol_version(&self) -> Option<String> {
+        self.inner.peer_protocol_version()
+    }
+
+    fn set_peer_protocol_version(&self, version: &str) {
+        self.inner.set_peer_protocol_version(version);
+    }
+
+    fn configure(
+        &self,
+        config: TransportConfig,
+    ) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
+        self.inner.configure(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use std::sync::Mutex as StdMutex;
+    use turbomcp_protocol::MessageId;
+
+    /// Records every message written to it.
+    #[derive(Debug, Default)]
+    struct RecordingTransport {
+        capabilities: TransportCapabilities,
+        written: StdMutex<Vec<String>>,
+    }
+
+    impl RecordingTransport {
+        fn written(&self) -> Vec<String> {
+            self.written.lock().expect("log poisoned").clone()
+        }
+    }
+
+    impl Transport for RecordingTransport {
+        fn transport_type(&self) -> TransportType {
+            TransportType::Stdio
+        }
+
+        fn capabilities(&self) -> &TransportCapabilities {
+            &self.capabilities
+        }
+
+        fn state(&self) -> Pin<Box<dyn Future<Output = TransportState> + Send + '_>> {
+            Box::pin(async { TransportState::Connected })
+        }
+
+        fn connect(&self) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
+            Box::pin(async { Ok(()) })
+        }
+
+        fn disconnect(&self) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
+            Box::pin(async { Ok(()) })
+        }
+
+        fn send(
+            &self,
+            message: TransportMessage,
+        ) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
+            self.written
+                .lock()
+                .expect("log poisoned")
+                .push(String::from_utf8_lossy(&message.payload).into_owned());
+            Box::pin(async { Ok(()) })
+        }
+
+        fn receive(
+            &self,
+        ) -> Pin<Box<dyn Future<Output = TransportResult<Option<TransportMessage>>> + Send + '_>>
+        {
+            Box::pin(async { Ok(None) })
+        }
+
+        fn metrics(&self) -> Pin<Box<dyn Future<Output = TransportMetrics> + Send + '_>> {
+            Box::pin(async { TransportMetrics::default() })
+        }
+    }
+
+    fn message(body: &str) -> TransportMessage {
+        TransportMessage::new(MessageId::from(body), Bytes::from(body.to_string()))
+    }
+
+    #[tokio::test]
+    async fn test_buffered_writes_delivered_on_flush() {
+        let transport = BatchingTransport::new(RecordingTransport::default());
+
+        transport.send(message("one")).await.unwrap();
+        transport.send(message("two")).await.unwrap();
+        assert!(transport.inner().written().is_empty());
+        assert_eq!(transport.pending().await, 2);
+
+        transport.flush().await.unwrap();
+        assert_eq!(transport.inner().written(), vec!["one", "two"]);
+        assert_eq!(transport.pending().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_full_batch_flushes_automatically() {
+        let transport = BatchingTransport::new(RecordingTransport::default()).with_max_batch(2);
+
+        transport.send(message("one")).await.unwrap();
+        assert!(transport.inner().written().is_empty());
+        transport.send(message("two")).await.unwrap();
+        assert_eq!(transport.inner().written(), vec!["one", "two"]);
+    }
+
+    #[tokio::test]
+    async fn test_disconnect_flushes_pending() {
+        let transport = BatchingTransport::new(RecordingTransport::default());
+
+        transport.send(message("last")).await.unwrap();
+        transport.disconnect().await.unwrap();
+        assert_eq!(transport.inner().written(), vec!["last"]);
+    }
+}
@@ -540,6 +540,10 @@ impl<T: Transport> Transport for BidirectionalTransportWrapper<T> {
         })
     }
 
+    fn flush(&self) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
+        self.inner.flush()
+    }
+
     fn receive(
         &self,
     ) -> Pin<Box<dyn Future<Output = TransportResult<Option<TransportMessage>>> + Send + '_>> {
@@ -556,6 +560,20 @@ impl<T: Transport> Transport for BidirectionalTransportWrapper<T> {
     fn metrics(&self) -> Pin<Box<dyn Future<Output = crate::core::TransportMetrics> + Send + '_>> {
         Box::pin(async move { self.inner.metrics().await })
     }
+
+    fn subscribe_events(
+        &self,
+    ) -> Option<tokio::sync::broadcast::Receiver<crate::core::TransportEvent>> {
+        self.inner.subscribe_events()
+    }
+
+    fn peer_protocol_version(&self) -> Option<String> {
+        self.inner.peer_protocol_version()
+    }
+
+    fn set_peer_protocol_version(&self, version: &str) {
+        self.inner.set_peer_protocol_version(version);
+    }
 }
 
 // Implement BidirectionalTransport trait
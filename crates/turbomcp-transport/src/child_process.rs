@@ -23,7 +23,7 @@ use std::time::Duration;
 use bytes::Bytes;
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, BufWriter};
 use tokio::process::{Child, Command};
-use tokio::sync::{Mutex as TokioMutex, mpsc};
+use tokio::sync::{Mutex as TokioMutex, broadcast, mpsc};
 use tokio::time::timeout;
 use tracing::{debug, error, info, trace, warn};
 
@@ -110,6 +110,9 @@ pub struct ChildProcessTransport {
     /// Event emitter
     event_emitter: TransportEventEmitter,
 
+    /// Protocol version negotiated with the peer during `initialize`
+    peer_protocol_version: Arc<Mutex<Option<String>>>,
+
     /// STDIO communication channels (tokio::sync::Mutex - crosses await boundaries)
     stdin_sender: Arc<TokioMutex<Option<mpsc::Sender<String>>>>,
     stdout_receiver: Arc<TokioMutex<Option<mpsc::Receiver<String>>>>,
@@ -142,6 +145,7 @@ impl ChildProcessTransport {
             capabilities,
             metrics: Arc::new(AtomicMetrics::default()),
             event_emitter: TransportEventEmitter::new().0,
+            peer_protocol_version: Arc::new(Mutex::new(None)),
             stdin_sender: Arc::new(TokioMutex::new(None)),
             stdout_receiver: Arc::new(TokioMutex::new(None)),
             _stdin_task: Arc::new(TokioMutex::new(None)),
@@ -539,6 +543,18 @@ impl Transport for ChildProcessTransport {
             self.metrics.snapshot()
         })
     }
+
+    fn subscribe_events(&self) -> Option<broadcast::Receiver<TransportEvent>> {
+        Some(self.event_emitter.subscribe())
+    }
+
+    fn peer_protocol_version(&self) -> Option<String> {
+        self.peer_protocol_version.lock().clone()
+    }
+
+    fn set_peer_protocol_version(&self, version: &str) {
+        *self.peer_protocol_version.lock() = Some(version.to_string());
+    }
 }
 
 impl Drop for ChildProcessTransport {
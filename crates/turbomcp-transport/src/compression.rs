@@ -2,8 +2,10 @@
 
 use std::io::{Read, Write};
 
-use crate::core::{TransportError, TransportResult};
+use crate::core::{TransportError, TransportMessage, TransportMessageMetadata, TransportResult};
+use bytes::Bytes;
 use serde_json::Value;
+use turbomcp_protocol::MessageId;
 
 /// Default cap on decompressed payload size: 16 MiB.
 ///
@@ -29,15 +31,56 @@ pub enum CompressionType {
     Lz4,
 }
 
+impl CompressionType {
+    /// Content encoding name recorded in message metadata, or `None` for
+    /// [`CompressionType::None`].
+    #[must_use]
+    pub const fn encoding(self) -> Option<&'static str> {
+        match self {
+            Self::None => None,
+            #[cfg(feature = "flate2")]
+            Self::Gzip => Some("gzip"),
+            #[cfg(feature = "brotli")]
+            Self::Brotli => Some("br"),
+            #[cfg(feature = "lz4_flex")]
+            Self::Lz4 => Some("lz4"),
+        }
+    }
+
+    /// Parse an algorithm name such as `gzip`, `br`/`brotli`, or `lz4`.
+    ///
+    /// Returns `None` for unknown names and for algorithms whose feature is
+    /// not compiled in.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "none" | "identity" => Some(Self::None),
+            #[cfg(feature = "flate2")]
+            "gzip" => Some(Self::Gzip),
+            #[cfg(feature = "brotli")]
+            "br" | "brotli" => Some(Self::Brotli),
+            #[cfg(feature = "lz4_flex")]
+            "lz4" => Some(Self::Lz4),
+            _ => None,
+        }
+    }
+}
+
 /// Message compressor/decompressor.
 ///
 /// Decompression is bounded by `max_decompressed_size` to prevent decompression
 /// bombs. The default cap is [`DEFAULT_MAX_DECOMPRESSED_SIZE`] (16 MiB);
 /// override with [`MessageCompressor::with_max_decompressed_size`].
+///
+/// [`compress_message`](Self::compress_message) skips compression for
+/// messages smaller than the configured threshold and records the encoding it
+/// used in the message metadata, so
+/// [`decompress_message`](Self::decompress_message) handles both.
 #[derive(Debug)]
 pub struct MessageCompressor {
     compression_type: CompressionType,
     max_decompressed_size: usize,
+    threshold: usize,
 }
 
 impl MessageCompressor {
@@ -48,9 +91,28 @@ impl MessageCompressor {
         Self {
             compression_type,
             max_decompressed_size: DEFAULT_MAX_DECOMPRESSED_SIZE,
+            threshold: 0,
         }
     }
 
+    /// Send messages smaller than `bytes` uncompressed from
+    /// [`compress_message`](Self::compress_message).
+    ///
+    /// Compressing small messages costs CPU and often makes them larger;
+    /// around 1 KiB is a reasonable threshold. Each transport that compresses
+    /// sets its own.
+    #[must_use]
+    pub const fn with_threshold(mut self, bytes: usize) -> Self {
+        self.threshold = bytes;
+        self
+    }
+
+    /// Currently configured compression threshold, in bytes.
+    #[must_use]
+    pub const fn threshold(&self) -> usize {
+        self.threshold
+    }
+
     /// Override the maximum decompressed payload size, in bytes.
     ///
     /// Decompression that would exceed this cap returns
@@ -109,6 +171,63 @@ impl MessageCompressor {
         }
     }
 
+    /// Serialize `message` into a transport message, compressing it only if
+    /// its serialized size reaches the threshold.
+    ///
+    /// A compressed payload has its metadata `encoding` set to the
+    /// algorithm's name; an uncompressed one leaves it unset.
+    pub fn compress_message(
+        &self,
+        id: MessageId,
+        message: &Value,
+    ) -> TransportResult<TransportMessage> {
+        let json_bytes = serde_json::to_vec(message)
+            .map_err(|e| TransportError::SerializationFailed(e.to_string()))?;
+        let Some(encoding) = self.compression_type.encoding() else {
+            return Ok(TransportMessage::new(id, Bytes::from(json_bytes)));
+        };
+        if json_bytes.len() < self.threshold {
+            return Ok(TransportMessage::new(id, Bytes::from(json_bytes)));
+        }
+
+        let compressed = self.compress(message)?;
+        let metadata = TransportMessageMetadata {
+            encoding: Some(encoding.to_string()),
+            ..Default::default()
+        };
+        Ok(TransportMessage::with_metadata(
+            id,
+            Bytes::from(compressed),
+            metadata,
+        ))
+    }
+
+    /// Decode a message produced by
+    /// [`compress_message`](Self::compress_message), decompressing it only if
+    /// its metadata marks it as compressed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload is not valid JSON, exceeds
+    /// [`Self::max_decompressed_size`], or is marked with an encoding other
+    /// than this compressor's.
+    pub fn decompress_message(&self, message: &TransportMessage) -> TransportResult<Value> {
+        if !message.is_compressed() {
+            return Self::new(CompressionType::None)
+                .with_max_decompressed_size(self.max_decompressed_size)
+                .decompress(&message.payload);
+        }
+        let encoding = message.metadata.encoding.as_deref().unwrap_or_default();
+        if CompressionType::from_name(encoding).and_then(CompressionType::encoding)
+            != self.compression_type.encoding()
+        {
+            return Err(TransportError::ProtocolError(format!(
+                "Unexpected message encoding: {encoding}"
+            )));
+        }
+        self.decompress(&message.payload)
+    }
+
     /// Decompress a message back to JSON.
     ///
     /// Output is bounded by [`Self::max_decompressed_size`]; payloads that
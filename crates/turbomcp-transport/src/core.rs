@@ -20,6 +20,7 @@ pub use turbomcp_transport_traits::{
     // Bidirectional utilities
     ConnectionState,
     CorrelationContext,
+    FrameMetrics,
     // Config
     LimitsConfig,
     MessageDirection,
@@ -134,6 +134,8 @@
     clippy::default_trait_access  // Default::default() is sometimes clearer
 )]
 
+/// Write batching wrapper flushed with `Transport::flush`.
+pub mod batching;
 /// Bidirectional transport wrappers and utilities.
 pub mod bidirectional;
 /// Core transport traits, types, and errors.
@@ -219,7 +221,7 @@ pub mod websocket_bidirectional {
 #[cfg(feature = "tcp")]
 #[cfg_attr(docsrs, doc(cfg(feature = "tcp")))]
 pub mod tcp {
-    pub use turbomcp_tcp::{TcpConfig, TcpTransport, TcpTransportBuilder};
+    pub use turbomcp_tcp::{TcpConfig, TcpFraming, TcpTransport, TcpTransportBuilder};
 }
 
 /// Unix domain socket transport for inter-process communication.
@@ -247,6 +249,10 @@ pub mod compression;
 pub mod config;
 /// Metrics and performance monitoring for transports.
 pub mod metrics;
+/// Session recording and deterministic replay for tests.
+#[cfg(feature = "test-utils")]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-utils")))]
+pub mod replay;
 /// Resilience patterns like circuit breakers and retries.
 pub mod resilience;
 /// Security features for transports, including authentication and rate limiting.
@@ -265,9 +271,9 @@ pub use bidirectional::{
 
 // Re-export core transport traits and types
 pub use core::{
-    BidirectionalTransport, Transport, TransportCapabilities, TransportConfig, TransportError,
-    TransportEvent, TransportMessage, TransportMetrics, TransportResult, TransportState,
-    TransportType, validate_request_size, validate_response_size,
+    BidirectionalTransport, FrameMetrics, Transport, TransportCapabilities, TransportConfig,
+    TransportError, TransportEvent, TransportMessage, TransportMetrics, TransportResult,
+    TransportState, TransportType, validate_request_size, validate_response_size,
 };
 
 // Re-export server transport functionality
@@ -298,6 +304,7 @@ pub use unix::UnixTransport;
 pub use child_process::{ChildProcessConfig, ChildProcessTransport};
 
 // Re-export utilities
+pub use batching::BatchingTransport;
 pub use config::{LimitsConfig, TransportConfigBuilder};
 pub use resilience::{
     CircuitBreakerConfig, CircuitBreakerStats, CircuitState, HealthCheckConfig, HealthInfo,
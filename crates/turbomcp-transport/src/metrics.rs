@@ -21,6 +21,9 @@ pub struct MetricsCollector {
     /// Histogram for latency tracking
     latency_histogram: Arc<RwLock<LatencyHistogram>>,
 
+    /// Per-tool call metrics, keyed by tool name
+    tool_metrics: Arc<RwLock<HashMap<String, ToolMetrics>>>,
+
     /// Start time for uptime calculation
     start_time: Instant,
 }
@@ -59,6 +62,22 @@ pub struct GlobalMetrics {
     pub peak_concurrent_connections: u64,
 }
 
+/// Call metrics for a single tool
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ToolMetrics {
+    /// Total number of calls
+    pub invocations: u64,
+
+    /// Calls that completed successfully
+    pub successes: u64,
+
+    /// Calls that returned an error
+    pub errors: u64,
+
+    /// Average call latency in milliseconds
+    pub average_latency_ms: f64,
+}
+
 /// Latency histogram for performance tracking
 #[derive(Debug, Clone)]
 pub struct LatencyHistogram {
@@ -113,6 +132,10 @@ pub struct MetricsSnapshot {
     /// Percentile latencies
     pub latency_percentiles: LatencyPercentiles,
 
+    /// Per-tool call metrics
+    #[serde(default)]
+    pub tools: HashMap<String, ToolMetrics>,
+
     /// Uptime in seconds
     pub uptime_seconds: u64,
 }
@@ -140,6 +163,7 @@ impl MetricsCollector {
             transport_metrics: Arc::new(RwLock::new(HashMap::new())),
             global_metrics: Arc::new(RwLock::new(GlobalMetrics::default())),
             latency_histogram: Arc::new(RwLock::new(LatencyHistogram::new())),
+            tool_metrics: Arc::new(RwLock::new(HashMap::new())),
             start_time: Instant::now(),
         }
     }
@@ -243,6 +267,34 @@ impl MetricsCollector {
         }
     }
 
+    /// Record a tool call and its outcome
+    pub fn record_tool_call(&self, tool_name: &str, success: bool, latency: Duration) {
+        let latency_ms = latency.as_secs_f64() * 1000.0;
+
+        let mut tools = self.tool_metrics.write();
+        let metrics = tools.entry(tool_name.to_string()).or_default();
+        metrics.invocations += 1;
+        if success {
+            metrics.successes += 1;
+        } else {
+            metrics.errors += 1;
+        }
+        metrics.average_latency_ms +=
+            (latency_ms - metrics.average_latency_ms) / metrics.invocations as f64;
+    }
+
+    /// Get call metrics for a specific tool
+    #[must_use]
+    pub fn get_tool_metrics(&self, tool_name: &str) -> Option<ToolMetrics> {
+        self.tool_metrics.read().get(tool_name).cloned()
+    }
+
+    /// Get call metrics for every tool that has been called
+    #[must_use]
+    pub fn get_all_tool_metrics(&self) -> HashMap<String, ToolMetrics> {
+        self.tool_metrics.read().clone()
+    }
+
     /// Get current metrics snapshot
     #[must_use]
     pub fn snapshot(&self) -> MetricsSnapshot {
@@ -252,6 +304,7 @@ impl MetricsCollector {
 
         let latency_distribution = histogram.buckets.clone();
         let latency_percentiles = histogram.calculate_percentiles();
+        let tools = self.tool_metrics.read().clone();
 
         MetricsSnapshot {
             timestamp: chrono::Utc::now(),
@@ -259,6 +312,7 @@ impl MetricsCollector {
             transports,
             latency_distribution,
             latency_percentiles,
+            tools,
             uptime_seconds: self.start_time.elapsed().as_secs(),
         }
     }
@@ -268,6 +322,7 @@ impl MetricsCollector {
         self.transport_metrics.write().clear();
         *self.global_metrics.write() = GlobalMetrics::default();
         *self.latency_histogram.write() = LatencyHistogram::new();
+        self.tool_metrics.write().clear();
     }
 
     /// Get metrics for a specific transport type
@@ -436,10 +491,41 @@ impl MetricsExporter for PrometheusExporter {
             snapshot.latency_percentiles.p99
         ));
 
+        // Per-tool metrics, sorted for stable output
+        let mut tools: Vec<_> = snapshot.tools.iter().collect();
+        tools.sort_by(|a, b| a.0.cmp(b.0));
+        for (name, tool) in tools {
+            let label = escape_label_value(name);
+            output.push_str(&format!(
+                "mcp_tool_invocations_total{{tool=\"{label}\"}} {}\n",
+                tool.invocations
+            ));
+            output.push_str(&format!(
+                "mcp_tool_successes_total{{tool=\"{label}\"}} {}\n",
+                tool.successes
+            ));
+            output.push_str(&format!(
+                "mcp_tool_errors_total{{tool=\"{label}\"}} {}\n",
+                tool.errors
+            ));
+            output.push_str(&format!(
+                "mcp_tool_average_latency_ms{{tool=\"{label}\"}} {}\n",
+                tool.average_latency_ms
+            ));
+        }
+
         Ok(output)
     }
 }
 
+/// Escape a Prometheus label value (backslash, double quote, newline)
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
 /// JSON metrics exporter
 #[derive(Debug)]
 pub struct JsonExporter;
@@ -584,6 +670,34 @@ mod tests {
         assert!(output.contains("mcp_active_transports 1"));
     }
 
+    #[test]
+    fn test_per_tool_metrics() {
+        let collector = MetricsCollector::new();
+
+        collector.record_tool_call("search", true, Duration::from_millis(10));
+        collector.record_tool_call("search", true, Duration::from_millis(30));
+        collector.record_tool_call("deploy", false, Duration::from_millis(5));
+
+        let search = collector.get_tool_metrics("search").unwrap();
+        assert_eq!(search.invocations, 2);
+        assert_eq!(search.successes, 2);
+        assert_eq!(search.errors, 0);
+        assert!((search.average_latency_ms - 20.0).abs() < f64::EPSILON);
+
+        let deploy = collector.get_tool_metrics("deploy").unwrap();
+        assert_eq!(deploy.invocations, 1);
+        assert_eq!(deploy.successes, 0);
+        assert_eq!(deploy.errors, 1);
+
+        assert!(collector.get_tool_metrics("missing").is_none());
+        assert_eq!(collector.get_all_tool_metrics().len(), 2);
+
+        let output = PrometheusExporter.export(&collector.snapshot()).unwrap();
+        assert!(output.contains("mcp_tool_invocations_total{tool=\"search\"} 2"));
+        assert!(output.contains("mcp_tool_errors_total{tool=\"deploy\"} 1"));
+        assert!(output.contains("mcp_tool_successes_total{tool=\"deploy\"} 0"));
+    }
+
     #[test]
     fn test_json_exporter() {
         let collector = MetricsCollector::new();
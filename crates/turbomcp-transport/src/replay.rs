@@ -0,0 +1,494 @@
+//! Session recording and deterministic replay for tests
+//!
+//! [`RecordingTransport`] wraps a live transport and appends every frame it
+//! sends or receives to a JSON Lines file. [`ReplayTransport`] reads such a
+//! file back and answers each request with the server frames recorded after
+//! it, so a protocol interaction captured once against a real server can be
+//! reproduced in tests without that server.
+//!
+//! Each line of a recording is a [`RecordedFrame`]:
+//!
+//! ```text
+//! {"direction":"sent","payload":{"jsonrpc":"2.0","id":1,"method":"tools/call",...}}
+//! {"direction":"received","payload":{"jsonrpc":"2.0","id":1,"result":{...}}}
+//! ```
+//!
+//! Request ids usually differ between runs, so on replay the id of each
+//! recorded response is rewritten to the id of the live request it answers.
+
+use std::collections::VecDeque;
+use std::fs::File;
+use std::future::Future;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::pin::Pin;
+
+use bytes::Bytes;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::sync::broadcast;
+use turbomcp_protocol::MessageId;
+
+use crate::core::{
+    Transport, TransportCapabilities, TransportConfig, TransportError, TransportEvent,
+    TransportMessage, TransportMetrics, TransportResult, TransportState, TransportType,
+};
+
+/// Which side of the session produced a recorded frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FrameDirection {
+    /// Written by the local side (the client, for a client transport)
+    Sent,
+    /// Read from the peer (the server, for a client transport)
+    Received,
+}
+
+/// One frame of a recorded session
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedFrame {
+    /// Which side produced the frame
+    pub direction: FrameDirection,
+    /// The frame payload; non-JSON payloads are stored as a string
+    pub payload: Value,
+}
+
+impl RecordedFrame {
+    fn from_message(direction: FrameDirection, message: &TransportMessage) -> Self {
+        let payload = serde_json::from_slice(&message.payload).unwrap_or_else(|_| {
+            Value::String(String::from_utf8_lossy(&message.payload).into_owned())
+        });
+        Self { direction, payload }
+    }
+}
+
+/// Transport wrapper that tees every frame to a JSON Lines recording
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use turbomcp_transport::replay::RecordingTransport;
+/// use turbomcp_transport::StdioTransport;
+///
+/// # fn example() -> std::io::Result<()> {
+/// let transport = RecordingTransport::create(StdioTransport::new(), "session.jsonl")?;
+/// // Use `transport` as usual; every frame lands in session.jsonl.
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct RecordingTransport<T: Transport> {
+    inner: T,
+    file: Mutex<BufWriter<File>>,
+}
+
+impl<T: Transport> RecordingTransport<T> {
+    /// Wrap `inner`, recording to `path` (created or truncated)
+    pub fn create(inner: T, path: impl AsRef<Path>) -> std::io::Result<Self> {
+        Ok(Self {
+            inner,
+            file: Mutex::new(BufWriter::new(File::create(path)?)),
+        })
+    }
+
+    /// Borrow the wrapped transport
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Append one frame, flushing so the recording survives a crash mid-test
+    fn record(&self, direction: FrameDirection, message: &TransportMessage) -> TransportResult<()> {
+        let frame = RecordedFrame::from_message(direction, message);
+        let mut file = self.file.lock();
+        serde_json::to_writer(&mut *file, &frame)
+            .map_err(|e| TransportError::SerializationFailed(e.to_string()))?;
+        file.write_all(b"\n")
+            .and_then(|()| file.flush())
+            .map_err(|e| TransportError::Io(e.to_string()))
+    }
+}
+
+impl<T: Transport> Transport for RecordingTransport<T> {
+    fn transport_type(&self) -> TransportType {
+        self.inner.transport_type()
+    }
+
+    fn capabilities(&self) -> &TransportCapabilities {
+        self.inner.capabilities()
+    }
+
+    fn state(&self) -> Pin<Box<dyn Future<Output = TransportState> + Send + '_>> {
+        self.inner.state()
+    }
+
+    fn connect(&self) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
+        self.inner.connect()
+    }
+
+    fn disconnect(&self) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
+        self.inner.disconnect()
+    }
+
+    fn send(
+        &self,
+        message: TransportMessage,
+    ) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
+        Box::pin(async move {
+            self.record(FrameDirection::Sent, &message)?;
+            self.inner.send(message).await
+        })
+    }
+
+    fn flush(&self) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
+        self.inner.flush()
+    }
+
+    fn receive(
+        &self,
+    ) -> Pin<Box<dyn Future<Output = TransportResult<Option<TransportMessage>>> + Send + '_>> {
+        Box::pin(async move {
+            let message = self.inner.receive().await?;
+            if let Some(message) = &message {
+                self.record(FrameDirection::Received, message)?;
+            }
+            Ok(message)
+        })
+    }
+
+    fn metrics(&self) -> Pin<Box<dyn Future<Output = TransportMetrics> + Send + '_>> {
+        self.inner.metrics()
+    }
+
+    fn is_connected(&self) -> Pin<Box<dyn Future<Output = bool> + Send + '_>> {
+        self.inner.is_connected()
+    }
+
+    fn endpoint(&self) -> Option<String> {
+        self.inner.endpoint()
+    }
+
+    fn subscribe_events(&self) -> Option<broadcast::Receiver<TransportEvent>> {
+        self.inner.subscribe_events()
+    }
+
+    fn peer_protocol_version(&self) -> Option<String> {
+        self.inner.peer_protocol_version()
+    }
+
+    fn set_peer_protocol_version(&self, version: &str) {
+        self.inner.set_peer_protocol_version(version);
+    }
+
+    fn configure(
+        &self,
+        config: TransportConfig,
+    ) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
+        self.inner.configure(config)
+    }
+}
+
+/// A recorded local frame and the peer frames that followed it
+#[derive(Debug)]
+struct Exchange {
+    sent: Value,
+    replies: Vec<Value>,
+}
+
+#[derive(Debug, Default)]
+struct ReplayState {
+    exchanges: VecDeque<Exchange>,
+    inbox: VecDeque<Value>,
+}
+
+/// Transport that serves the peer side of a recorded session
+///
+/// Frames received before the first recorded send are available immediately.
+/// Each [`send`](Transport::send) consumes the next recorded local frame and
+/// makes the peer frames recorded after it available to
+/// [`receive`](Transport::receive); `receive` returns `None` when nothing is
+/// pending. A send whose method differs from the recording fails, so a test
+/// notices when the code under test diverges from the recorded session.
+#[derive(Debug)]
+pub struct ReplayTransport {
+    capabilities: TransportCapabilities,
+    state: Mutex<ReplayState>,
+}
+
+impl ReplayTransport {
+    /// Replay the recording at `path`
+    pub fn open(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let mut frames = Vec::new();
+        for line in BufReader::new(File::open(path)?).lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            frames.push(serde_json::from_str(&line)?);
+        }
+        Ok(Self::from_frames(frames))
+    }
+
+    /// Replay an in-memory recording
+    pub fn from_frames(frames: impl IntoIterator<Item = RecordedFrame>) -> Self {
+        let mut state = ReplayState::default();
+        for frame in frames {
+            match frame.direction {
+                FrameDirection::Sent => state.exchanges.push_back(Exchange {
+                    sent: frame.payload,
+                    replies: Vec::new(),
+                }),
+                FrameDirection::Received => match state.exchanges.back_mut() {
+                    Some(exchange) => exchange.replies.push(frame.payload),
+                    None => state.inbox.push_back(frame.payload),
+                },
+            }
+        }
+        Self {
+            capabilities: TransportCapabilities::default(),
+            state: Mutex::new(state),
+        }
+    }
+
+    /// Number of recorded local frames not yet replayed
+    pub fn remaining(&self) -> usize {
+        self.state.lock().exchanges.len()
+    }
+
+    fn replay_send(&self, message: &TransportMessage) -> TransportResult<()> {
+        let live: Value = serde_json::from_slice(&message.payload)
+            .map_err(|e| TransportError::SerializationFailed(e.to_string()))?;
+        let mut state = self.state.lock();
+        let exchange = state.exchanges.pop_front().ok_or_else(|| {
+            TransportError::SendFailed(format!(
+                "replay exhausted: no recorded frame for {}",
+                live.get("method").unwrap_or(&live)
+            ))
+        })?;
+        if exchange.sent.get("method") != live.get("method") {
+            return Err(TransportError::ProtocolError(format!(
+                "replay diverged: recording expected {}, got {}",
+                exchange.sent.get("method").unwrap_or(&Value::Null),
+                live.get("method").unwrap_or(&Value::Null)
+            )));
+        }
+        let recorded_id = exchange.sent.get("id").cloned();
+        let live_id = live.get("id").cloned();
+        for mut reply in exchange.replies {
+            // Answers to the recorded request take the live request's id.
+            if let (Some(recorded_id), Some(live_id)) = (&recorded_id, &live_id)
+                && reply.get("method").is_none()
+                && reply.get("id") == Some(recorded_id)
+            {
+                reply["id"] = live_id.clone();
+            }
+            state.inbox.push_back(reply);
+        }
+        Ok(())
+    }
+}
+
+impl Transport for ReplayTransport {
+    fn transport_type(&self) -> TransportType {
+        TransportType::Channel
+    }
+
+    fn capabilities(&self) -> &TransportCapabilities {
+        &self.capabilities
+    }
+
+    fn state(&self) -> Pin<Box<dyn Future<Output = TransportState> + Send + '_>> {
+        Box::pin(async { TransportState::Connected })
+    }
+
+    fn connect(&self) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn disconnect(&self) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn send(
+        &self,
+        message: TransportMessage,
+    ) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
+        let result = self.replay_send(&message);
+        Box::pin(async move { result })
+    }
+
+    fn receive(
+        &self,
+    ) -> Pin<Box<dyn Future<Output = TransportResult<Option<TransportMessage>>> + Send + '_>> {
+        let reply = self.state.lock().inbox.pop_front();
+        Box::pin(async move {
+            let Some(reply) = reply else {
+                return Ok(None);
+            };
+            let payload = match reply {
+                Value::String(raw) => Bytes::from(raw),
+                json => Bytes::from(
+                    serde_json::to_vec(&json)
+                        .map_err(|e| TransportError::SerializationFailed(e.to_string()))?,
+                ),
+            };
+            Ok(Some(TransportMessage::new(
+                MessageId::from("replay"),
+                payload,
+            )))
+        })
+    }
+
+    fn metrics(&self) -> Pin<Box<dyn Future<Output = TransportMetrics> + Send + '_>> {
+        Box::pin(async { TransportMetrics::default() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    /// A minimal live server: answers `initialize` and `tools/call`.
+    #[derive(Debug, Default)]
+    struct ToolServer {
+        capabilities: TransportCapabilities,
+        outbox: Mutex<VecDeque<TransportMessage>>,
+        calls: AtomicU64,
+    }
+
+    impl Transport for ToolServer {
+        fn transport_type(&self) -> TransportType {
+            TransportType::Stdio
+        }
+
+        fn capabilities(&self) -> &TransportCapabilities {
+            &self.capabilities
+        }
+
+        fn state(&self) -> Pin<Box<dyn Future<Output = TransportState> + Send + '_>> {
+            Box::pin(async { TransportState::Connected })
+        }
+
+        fn connect(&self) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
+            Box::pin(async { Ok(()) })
+        }
+
+        fn disconnect(&self) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
+            Box::pin(async { Ok(()) })
+        }
+
+        fn send(
+            &self,
+            message: TransportMessage,
+        ) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
+            let request: Value = serde_json::from_slice(&message.payload).unwrap();
+            let result = match request["method"].as_str() {
+                Some("initialize") => Some(serde_json::json!({
+                    "protocolVersion": "2025-11-25",
+                    "capabilities": {"tools": {}},
+                    "serverInfo": {"name": "live", "version": "1.0.0"}
+                })),
+                Some("tools/call") => {
+                    let n = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+                    Some(serde_json::json!({
+                        "content": [{"type": "text", "text": format!("call #{n}")}]
+                    }))
+                }
+                _ => None,
+            };
+            if let Some(result) = result {
+                let response =
+                    serde_json::json!({"jsonrpc": "2.0", "id": request["id"], "result": result});
+                self.outbox.lock().push_back(TransportMessage::new(
+                    MessageId::from("live"),
+                    serde_json::to_vec(&response).unwrap().into(),
+                ));
+            }
+            Box::pin(async { Ok(()) })
+        }
+
+        fn receive(
+            &self,
+        ) -> Pin<Box<dyn Future<Output = TransportResult<Option<TransportMessage>>> + Send + '_>>
+        {
+            let message = self.outbox.lock().pop_front();
+            Box::pin(async move { Ok(message) })
+        }
+
+        fn metrics(&self) -> Pin<Box<dyn Future<Output = TransportMetrics> + Send + '_>> {
+            Box::pin(async { TransportMetrics::default() })
+        }
+    }
+
+    fn request(id: &str, method: &str, params: Value) -> TransportMessage {
+        let request = serde_json::json!({
+            "jsonrpc": "2.0", "id": id, "method": method, "params": params
+        });
+        TransportMessage::new(
+            MessageId::from(id),
+            serde_json::to_vec(&request).unwrap().into(),
+        )
+    }
+
+    /// Run initialize + two tool calls, returning each response.
+    async fn tool_session(transport: &impl Transport, id_prefix: &str) -> Vec<Value> {
+        let mut responses = Vec::new();
+        let requests = [
+            (
+                "initialize",
+                serde_json::json!({"protocolVersion": "2025-11-25"}),
+            ),
+            ("tools/call", serde_json::json!({"name": "count"})),
+            ("tools/call", serde_json::json!({"name": "count"})),
+        ];
+        for (n, (method, params)) in requests.into_iter().enumerate() {
+            let id = format!("{id_prefix}-{n}");
+            transport.send(request(&id, method, params)).await.unwrap();
+            let response = transport.receive().await.unwrap().expect("response");
+            let response: Value = serde_json::from_slice(&response.payload).unwrap();
+            assert_eq!(response["id"], id);
+            responses.push(response);
+        }
+        responses
+    }
+
+    #[tokio::test]
+    async fn test_recorded_tool_session_replays_exact_responses() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("session.jsonl");
+
+        let recording = RecordingTransport::create(ToolServer::default(), &path).unwrap();
+        let live = tool_session(&recording, "live").await;
+        drop(recording);
+
+        let replay = ReplayTransport::open(&path).unwrap();
+        let replayed = tool_session(&replay, "replay").await;
+        assert_eq!(replay.remaining(), 0);
+
+        for (live, replayed) in live.iter().zip(&replayed) {
+            assert_eq!(live["result"], replayed["result"]);
+        }
+        assert_eq!(replayed[2]["result"]["content"][0]["text"], "call #2");
+    }
+
+    #[tokio::test]
+    async fn test_replay_rejects_divergent_request() {
+        let replay = ReplayTransport::from_frames([RecordedFrame {
+            direction: FrameDirection::Sent,
+            payload: serde_json::json!({"jsonrpc": "2.0", "id": 1, "method": "tools/list"}),
+        }]);
+
+        let err = replay
+            .send(request("1", "tools/call", Value::Null))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("replay diverged"), "{err}");
+
+        let err = replay
+            .send(request("2", "tools/list", Value::Null))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("replay exhausted"), "{err}");
+    }
+}
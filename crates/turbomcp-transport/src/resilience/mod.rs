@@ -5,6 +5,7 @@
 //! - **Circuit breaker** pattern for fault tolerance and fast failure
 //! - **Health checking** and monitoring for proactive failure detection
 //! - **Message deduplication** to prevent duplicate processing
+//! - **Reconnect buffering** so sends issued during a reconnect are delivered
 //! - **Metrics collection** for observability and monitoring
 //!
 //! ## Architecture
@@ -18,6 +19,7 @@
 //! ├── health.rs           # Health checking and monitoring
 //! ├── metrics.rs          # Comprehensive metrics collection
 //! ├── deduplication.rs    # Message deduplication cache
+//! ├── reconnect_buffer.rs # Bounded queue for sends during reconnect
 //! └── transport.rs        # Main TurboTransport wrapper
 //! ```
 //!
@@ -55,13 +57,11 @@
 //!
 //! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
 //! // Custom retry configuration - explicit and discoverable
-//! let retry_config = RetryConfig {
-//!     max_attempts: 5,
-//!     base_delay: Duration::from_millis(200),
-//!     max_delay: Duration::from_secs(30),
-//!     backoff_multiplier: 2.0,
-//!     ..Default::default()
-//! };
+//! let mut retry_config = RetryConfig::default();
+//! retry_config.max_attempts = 5;
+//! retry_config.base_delay = Duration::from_millis(200);
+//! retry_config.max_delay = Duration::from_secs(30);
+//! retry_config.backoff_multiplier = 2.0;
 //!
 //! // Custom circuit breaker configuration
 //! let circuit_config = CircuitBreakerConfig {
@@ -92,6 +92,7 @@ pub mod circuit_breaker;
 pub mod deduplication;
 pub mod health;
 pub mod metrics;
+pub mod reconnect_buffer;
 pub mod retry;
 pub mod transport;
 
@@ -102,5 +103,6 @@ pub use circuit_breaker::{
 pub use deduplication::{DeduplicationCache, DeduplicationConfig, DeduplicationStats};
 pub use health::{HealthCheckConfig, HealthCheckable, HealthChecker, HealthInfo, HealthStatus};
 pub use metrics::{LatencyTracker, MetricsSnapshot, TurboTransportMetrics};
+pub use reconnect_buffer::ReconnectBuffer;
 pub use retry::{RetryCondition, RetryConfig};
-pub use transport::TurboTransport;
+pub use transport::{DEFAULT_RECONNECT_TIMEOUT, TurboTransport};
@@ -0,0 +1,89 @@
+//! Bounded buffer for sends issued while a transport reconnects
+//!
+//! When enabled on [`TurboTransport`](super::TurboTransport), messages sent
+//! while the inner transport is not connected are parked here instead of
+//! failing. They are written, in order, once `connect()` succeeds, and each
+//! caller's `send` completes with the outcome of its own write. If the
+//! reconnect fails, or the transport is explicitly disconnected, every parked
+//! send fails. A send that finds the buffer full fails immediately, and one
+//! still parked after the reconnect timeout fails with
+//! [`TransportError::Timeout`] and is dropped from the buffer.
+
+use std::collections::VecDeque;
+
+use tokio::sync::{Mutex, oneshot};
+
+use crate::core::{TransportError, TransportMessage, TransportResult};
+
+/// A parked message and the channel its sender is waiting on.
+#[derive(Debug)]
+pub(crate) struct PendingSend {
+    pub(crate) message: TransportMessage,
+    pub(crate) done: oneshot::Sender<TransportResult<()>>,
+}
+
+/// Bounded FIFO of sends waiting for a reconnect
+#[derive(Debug)]
+pub struct ReconnectBuffer {
+    capacity: usize,
+    pending: Mutex<VecDeque<PendingSend>>,
+}
+
+impl ReconnectBuffer {
+    /// Create a buffer holding at most `capacity` messages
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            pending: Mutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+
+    /// Maximum number of parked messages
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of messages currently parked
+    pub async fn len(&self) -> usize {
+        self.pending.lock().await.len()
+    }
+
+    /// Whether no messages are parked
+    pub async fn is_empty(&self) -> bool {
+        self.pending.lock().await.is_empty()
+    }
+
+    /// Lock the queue; held while deciding whether to park a send so a
+    /// concurrent flush cannot miss it.
+    pub(crate) async fn lock(&self) -> tokio::sync::MutexGuard<'_, VecDeque<PendingSend>> {
+        self.pending.lock().await
+    }
+
+    /// Park `message` in a locked queue, returning the receiver for its outcome
+    pub(crate) fn park(
+        &self,
+        queue: &mut VecDeque<PendingSend>,
+        message: TransportMessage,
+    ) -> TransportResult<oneshot::Receiver<TransportResult<()>>> {
+        // Sends whose caller gave up no longer take a slot.
+        queue.retain(|pending| !pending.done.is_closed());
+        if queue.len() >= self.capacity {
+            return Err(TransportError::SendFailed(format!(
+                "Reconnect buffer full ({} messages)",
+                self.capacity
+            )));
+        }
+        let (done, rx) = oneshot::channel();
+        queue.push_back(PendingSend { message, done });
+        Ok(rx)
+    }
+
+    /// Fail every parked send with `error`
+    pub(crate) async fn fail_all(&self, error: &TransportError) {
+        let drained: Vec<_> = self.pending.lock().await.drain(..).collect();
+        for pending in drained {
+            // The caller may have given up waiting; nothing to report then.
+            let _ = pending.done.send(Err(error.clone()));
+        }
+    }
+}
@@ -10,7 +10,12 @@ use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
 /// Retry configuration for transport operations
+///
+/// Start from `Default` (or [`RetryConfig::new`]) and set the fields you need;
+/// retriable error codes are set with
+/// [`with_retriable_error_codes`](Self::with_retriable_error_codes).
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct RetryConfig {
     /// Maximum number of retry attempts
     pub max_attempts: u32,
@@ -28,6 +33,13 @@ pub struct RetryConfig {
     pub retry_on_timeout: bool,
     /// Custom retry conditions
     pub custom_retry_conditions: Vec<RetryCondition>,
+    /// JSON-RPC error codes the client retries with backoff
+    ///
+    /// Transport retries only see transport failures; these codes let a client
+    /// also retry error *responses* such as `SERVER_OVERLOADED` (-32010) or
+    /// `RATE_LIMITED` (-32009). Empty by default.
+    #[serde(default)]
+    pub(crate) retriable_error_codes: Vec<i32>,
 }
 
 /// Custom retry condition based on error patterns
@@ -52,6 +64,7 @@ impl Default for RetryConfig {
             retry_on_connection_error: true,
             retry_on_timeout: true,
             custom_retry_conditions: Vec::new(),
+            retriable_error_codes: Vec::new(),
         }
     }
 }
@@ -62,6 +75,23 @@ impl RetryConfig {
         Self::default()
     }
 
+    /// Treat the given JSON-RPC error codes as retriable
+    #[must_use]
+    pub fn with_retriable_error_codes(mut self, codes: impl IntoIterator<Item = i32>) -> Self {
+        self.retriable_error_codes.extend(codes);
+        self
+    }
+
+    /// JSON-RPC error codes the client retries with backoff
+    pub fn retriable_error_codes(&self) -> &[i32] {
+        &self.retriable_error_codes
+    }
+
+    /// Check if a JSON-RPC error response with `code` should be retried
+    pub fn should_retry_code(&self, code: i32, attempt: u32) -> bool {
+        attempt < self.max_attempts && self.retriable_error_codes.contains(&code)
+    }
+
     /// Calculate the delay for a given attempt with exponential backoff and jitter
     pub fn calculate_delay(&self, attempt: u32) -> Duration {
         if attempt == 0 {
@@ -208,4 +238,15 @@ mod tests {
             Some(Duration::from_millis(500))
         );
     }
+
+    #[test]
+    fn test_should_retry_code() {
+        let config = RetryConfig::default().with_retriable_error_codes([-32010, -32009]);
+
+        assert!(config.should_retry_code(-32010, 0));
+        assert!(config.should_retry_code(-32009, 2));
+        assert!(!config.should_retry_code(-32010, 3));
+        assert!(!config.should_retry_code(-32603, 0));
+        assert!(!RetryConfig::default().should_retry_code(-32010, 0));
+    }
 }
@@ -5,6 +5,7 @@
 //! - Circuit breaker pattern for fast failure
 //! - Health checking and monitoring
 //! - Message deduplication
+//! - Optional buffering of sends during a reconnect
 //! - Comprehensive metrics collection
 
 use std::future::Future;
@@ -13,11 +14,11 @@ use std::sync::Arc;
 use std::sync::atomic::Ordering;
 use std::time::{Duration, Instant};
 
-use tokio::sync::{Mutex, RwLock};
+use tokio::sync::{Mutex, RwLock, broadcast};
 use tokio::time::sleep;
 
 use crate::core::{
-    Transport, TransportConfig, TransportError, TransportMessage, TransportMetrics,
+    Transport, TransportConfig, TransportError, TransportEvent, TransportMessage, TransportMetrics,
     TransportResult, TransportState, TransportType,
 };
 
@@ -25,6 +26,7 @@ use super::circuit_breaker::{CircuitBreaker, CircuitBreakerConfig, CircuitBreake
 use super::deduplication::DeduplicationCache;
 use super::health::{HealthCheckConfig, HealthChecker, HealthInfo, HealthStatus};
 use super::metrics::TurboTransportMetrics;
+use super::reconnect_buffer::ReconnectBuffer;
 use super::retry::RetryConfig;
 
 /// TurboTransport - Enhanced transport with retry, circuit breaker, and health checking
@@ -42,6 +44,9 @@ pub struct TurboTransport {
     /// rationale as `cached_transport_type` — the previous `try_lock`-based
     /// accessor returned `None` on contention.
     cached_endpoint: Option<String>,
+    /// Event subscription snapshotted at construction, resubscribed per
+    /// caller so `subscribe_events` stays lock-free like the accessors above.
+    cached_events: Option<broadcast::Receiver<TransportEvent>>,
     /// Retry configuration
     retry_config: RetryConfig,
     /// Circuit breaker
@@ -52,8 +57,15 @@ pub struct TurboTransport {
     metrics: Arc<TurboTransportMetrics>,
     /// Message deduplication cache
     dedup_cache: Arc<RwLock<DeduplicationCache>>,
+    /// Sends parked while the inner transport reconnects (disabled if `None`)
+    reconnect_buffer: Option<ReconnectBuffer>,
+    /// How long a parked send waits for the reconnect
+    reconnect_timeout: Duration,
 }
 
+/// Default time a send waits in the reconnect buffer
+pub const DEFAULT_RECONNECT_TIMEOUT: Duration = Duration::from_secs(30);
+
 impl TurboTransport {
     /// Create a new TurboTransport wrapper
     pub fn new(
@@ -74,16 +86,20 @@ impl TurboTransport {
         // so we can call its sync accessors before wrapping it in the Mutex.
         let cached_transport_type = transport.transport_type();
         let cached_endpoint = transport.endpoint();
+        let cached_events = transport.subscribe_events();
 
         Self {
             inner: Arc::new(Mutex::new(transport)),
             cached_transport_type,
             cached_endpoint,
+            cached_events,
             retry_config,
             circuit_breaker,
             health_checker,
             metrics,
             dedup_cache,
+            reconnect_buffer: None,
+            reconnect_timeout: DEFAULT_RECONNECT_TIMEOUT,
         }
     }
 
@@ -97,6 +113,57 @@ impl TurboTransport {
         )
     }
 
+    /// Buffer up to `capacity` sends while the inner transport is not connected
+    ///
+    /// Instead of failing, a send issued while the inner transport is
+    /// disconnected or connecting waits in a bounded queue. The queue is
+    /// flushed in order when [`connect`](Transport::connect) next succeeds.
+    /// Waiting sends fail if that connect fails or the transport is
+    /// disconnected, and a send that finds the queue full fails immediately.
+    /// A send still waiting after the
+    /// [reconnect timeout](Self::with_reconnect_timeout) fails with
+    /// [`TransportError::Timeout`]. A capacity of `0` disables buffering (the
+    /// default).
+    #[must_use]
+    pub fn with_reconnect_buffer(mut self, capacity: usize) -> Self {
+        self.reconnect_buffer = (capacity > 0).then(|| ReconnectBuffer::new(capacity));
+        self
+    }
+
+    /// Fail sends parked in the reconnect buffer after `timeout`
+    ///
+    /// Defaults to [`DEFAULT_RECONNECT_TIMEOUT`]. A send that times out is
+    /// dropped from the buffer and not written by a later reconnect.
+    #[must_use]
+    pub fn with_reconnect_timeout(mut self, timeout: Duration) -> Self {
+        self.reconnect_timeout = timeout;
+        self
+    }
+
+    /// The reconnect buffer, if enabled
+    pub fn reconnect_buffer(&self) -> Option<&ReconnectBuffer> {
+        self.reconnect_buffer.as_ref()
+    }
+
+    /// Write parked sends in order, completing each waiting caller
+    async fn flush_reconnect_buffer(&self, buffer: &ReconnectBuffer) {
+        // Hold the queue for the whole flush so sends arriving meanwhile
+        // are ordered after the parked ones.
+        let mut queue = buffer.lock().await;
+        while let Some(pending) = queue.pop_front() {
+            if pending.done.is_closed() {
+                // The caller timed out or gave up; don't send on its behalf.
+                continue;
+            }
+            let result = {
+                let transport = self.inner.lock().await;
+                transport.send(pending.message).await
+            };
+            // The caller may have given up waiting; nothing to report then.
+            let _ = pending.done.send(result);
+        }
+    }
+
     /// Execute operation with retry logic
     async fn execute_with_retry<F, Fut, T>(&self, mut operation: F) -> TransportResult<T>
     where
@@ -273,21 +340,40 @@ impl Transport for TurboTransport {
     fn connect(&self) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
         Box::pin(async move {
             let inner = self.inner.clone();
-            self.execute_with_retry(move || {
-                let inner = inner.clone();
-                async move {
-                    let transport = inner.lock().await;
-                    transport.connect().await
+            let result = self
+                .execute_with_retry(move || {
+                    let inner = inner.clone();
+                    async move {
+                        let transport = inner.lock().await;
+                        transport.connect().await
+                    }
+                })
+                .await;
+
+            if let Some(buffer) = &self.reconnect_buffer {
+                match &result {
+                    Ok(()) => self.flush_reconnect_buffer(buffer).await,
+                    Err(error) => buffer.fail_all(error).await,
                 }
-            })
-            .await
+            }
+            result
         })
     }
 
     fn disconnect(&self) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
         Box::pin(async move {
-            let inner = self.inner.lock().await;
-            inner.disconnect().await
+            let result = {
+                let inner = self.inner.lock().await;
+                inner.disconnect().await
+            };
+            if let Some(buffer) = &self.reconnect_buffer {
+                buffer
+                    .fail_all(&TransportError::ConnectionLost(
+                        "Transport disconnected before reconnecting".to_string(),
+                    ))
+                    .await;
+            }
+            result
         })
     }
 
@@ -319,6 +405,25 @@ impl Transport for TurboTransport {
                 }
             }
 
+            if let Some(buffer) = &self.reconnect_buffer {
+                let mut queue = buffer.lock().await;
+                let state = self.inner.lock().await.state().await;
+                if matches!(
+                    state,
+                    TransportState::Disconnected | TransportState::Connecting
+                ) {
+                    let parked = buffer.park(&mut queue, message);
+                    drop(queue);
+                    return match tokio::time::timeout(self.reconnect_timeout, parked?).await {
+                        Ok(Ok(result)) => result,
+                        Ok(Err(_)) => Err(TransportError::ConnectionLost(
+                            "Reconnect buffer dropped before flushing".to_string(),
+                        )),
+                        Err(_) => Err(TransportError::Timeout),
+                    };
+                }
+            }
+
             let inner = self.inner.clone();
             let msg = message.clone();
             self.execute_with_retry(move || {
@@ -333,6 +438,10 @@ impl Transport for TurboTransport {
         })
     }
 
+    fn flush(&self) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
+        Box::pin(async move { self.inner.lock().await.flush().await })
+    }
+
     fn receive(
         &self,
     ) -> Pin<Box<dyn Future<Output = TransportResult<Option<TransportMessage>>> + Send + '_>> {
@@ -363,6 +472,12 @@ impl Transport for TurboTransport {
         self.cached_endpoint.clone()
     }
 
+    fn subscribe_events(&self) -> Option<broadcast::Receiver<TransportEvent>> {
+        self.cached_events
+            .as_ref()
+            .map(broadcast::Receiver::resubscribe)
+    }
+
     fn configure(
         &self,
         config: TransportConfig,
@@ -373,3 +488,158 @@ impl Transport for TurboTransport {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::TransportCapabilities;
+    use bytes::Bytes;
+    use std::sync::atomic::AtomicBool;
+    use turbomcp_protocol::MessageId;
+
+    /// Records sends; only accepts them while connected.
+    #[derive(Debug, Default)]
+    struct ReconnectingTransport {
+        connected: Arc<AtomicBool>,
+        sent: Arc<std::sync::Mutex<Vec<MessageId>>>,
+    }
+
+    impl Transport for ReconnectingTransport {
+        fn transport_type(&self) -> TransportType {
+            TransportType::WebSocket
+        }
+
+        fn capabilities(&self) -> &crate::core::TransportCapabilities {
+            static CAPS: std::sync::LazyLock<TransportCapabilities> =
+                std::sync::LazyLock::new(TransportCapabilities::default);
+            &CAPS
+        }
+
+        fn state(&self) -> Pin<Box<dyn Future<Output = TransportState> + Send + '_>> {
+            Box::pin(async move {
+                if self.connected.load(Ordering::SeqCst) {
+                    TransportState::Connected
+                } else {
+                    TransportState::Disconnected
+                }
+            })
+        }
+
+        fn connect(&self) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
+            Box::pin(async move {
+                self.connected.store(true, Ordering::SeqCst);
+                Ok(())
+            })
+        }
+
+        fn disconnect(&self) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
+            Box::pin(async move {
+                self.connected.store(false, Ordering::SeqCst);
+                Ok(())
+            })
+        }
+
+        fn send(
+            &self,
+            message: TransportMessage,
+        ) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
+            Box::pin(async move {
+                if !self.connected.load(Ordering::SeqCst) {
+                    return Err(TransportError::SendFailed("not connected".to_string()));
+                }
+                self.sent.lock().unwrap().push(message.id);
+                Ok(())
+            })
+        }
+
+        fn receive(
+            &self,
+        ) -> Pin<Box<dyn Future<Output = TransportResult<Option<TransportMessage>>> + Send + '_>>
+        {
+            Box::pin(async move { Ok(None) })
+        }
+
+        fn metrics(&self) -> Pin<Box<dyn Future<Output = TransportMetrics> + Send + '_>> {
+            Box::pin(async move { TransportMetrics::default() })
+        }
+    }
+
+    fn message(id: i64) -> TransportMessage {
+        TransportMessage::new(MessageId::from(id), Bytes::from_static(b"{}"))
+    }
+
+    async fn wait_for_parked(turbo: &TurboTransport, count: usize) {
+        let buffer = turbo.reconnect_buffer().expect("buffer enabled");
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while buffer.len().await < count {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .expect("send was not parked");
+    }
+
+    #[tokio::test]
+    async fn test_send_during_reconnect_is_delivered_after_connect() {
+        let mock = ReconnectingTransport::default();
+        let sent = Arc::clone(&mock.sent);
+        let turbo =
+            Arc::new(TurboTransport::with_defaults(Box::new(mock)).with_reconnect_buffer(4));
+
+        let pending = tokio::spawn({
+            let turbo = Arc::clone(&turbo);
+            async move { turbo.send(message(1)).await }
+        });
+        wait_for_parked(&turbo, 1).await;
+        assert!(sent.lock().unwrap().is_empty());
+
+        turbo.connect().await.expect("reconnect");
+
+        pending.await.unwrap().expect("buffered send succeeds");
+        assert_eq!(*sent.lock().unwrap(), vec![MessageId::from(1)]);
+        assert!(turbo.reconnect_buffer().unwrap().is_empty().await);
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_buffer_overflow_and_disconnect_fail_sends() {
+        let turbo = Arc::new(
+            TurboTransport::with_defaults(Box::new(ReconnectingTransport::default()))
+                .with_reconnect_buffer(1),
+        );
+
+        let pending = tokio::spawn({
+            let turbo = Arc::clone(&turbo);
+            async move { turbo.send(message(1)).await }
+        });
+        wait_for_parked(&turbo, 1).await;
+
+        let overflow = turbo.send(message(2)).await;
+        assert!(matches!(overflow, Err(TransportError::SendFailed(_))));
+
+        turbo.disconnect().await.unwrap();
+        let parked = pending.await.unwrap();
+        assert!(matches!(parked, Err(TransportError::ConnectionLost(_))));
+    }
+
+    #[tokio::test]
+    async fn test_parked_send_times_out_and_is_not_sent_later() {
+        let mock = ReconnectingTransport::default();
+        let sent = Arc::clone(&mock.sent);
+        let turbo = TurboTransport::with_defaults(Box::new(mock))
+            .with_reconnect_buffer(1)
+            .with_reconnect_timeout(Duration::from_millis(50));
+
+        let parked = turbo.send(message(1)).await;
+        assert!(matches!(parked, Err(TransportError::Timeout)));
+
+        // The timed-out send frees its slot and is skipped on reconnect.
+        let pending = turbo.send(message(2));
+        let connect = async {
+            wait_for_parked(&turbo, 1).await;
+            turbo.connect().await.expect("reconnect");
+        };
+        let (result, ()) = tokio::join!(pending, connect);
+        result.expect("second send is delivered");
+        assert_eq!(*sent.lock().unwrap(), vec![MessageId::from(2)]);
+    }
+}
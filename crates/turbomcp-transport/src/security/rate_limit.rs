@@ -10,6 +10,7 @@ use std::collections::HashMap;
 use std::net::IpAddr;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
+use turbomcp_protocol::mcp_core::clock::{Clock, SystemClock};
 
 /// Rate limiting configuration
 #[derive(Clone, Debug)]
@@ -73,16 +74,23 @@ struct RateLimiterState {
 pub struct RateLimiter {
     config: RateLimitConfig,
     state: Arc<Mutex<RateLimiterState>>,
+    clock: Arc<dyn Clock>,
 }
 
 impl RateLimiter {
     /// Create a new rate limiter with the given configuration
     pub fn new(config: RateLimitConfig) -> Self {
+        Self::with_clock(config, SystemClock::shared())
+    }
+
+    /// Create a rate limiter that reads time from the given clock
+    pub fn with_clock(config: RateLimitConfig, clock: Arc<dyn Clock>) -> Self {
         Self {
             config,
             state: Arc::new(Mutex::new(RateLimiterState {
                 requests: HashMap::new(),
             })),
+            clock,
         }
     }
 
@@ -102,7 +110,7 @@ impl RateLimiter {
         }
 
         let mut state = self.state.lock();
-        let now = Instant::now();
+        let now = self.clock.instant();
 
         // Cap the number of tracked IPs to prevent unbounded memory growth under IP spoofing.
         if !state.requests.contains_key(&client_ip)
@@ -176,7 +184,7 @@ impl RateLimiter {
     /// Get current request count for a client
     pub fn get_request_count(&self, client_ip: IpAddr) -> usize {
         let mut state = self.state.lock();
-        let now = Instant::now();
+        let now = self.clock.instant();
 
         if let Some(requests) = state.requests.get_mut(&client_ip) {
             // Clean up expired requests
@@ -202,7 +210,7 @@ impl RateLimiter {
     /// Clean up expired entries for all clients
     pub fn cleanup_expired(&self) -> usize {
         let mut state = self.state.lock();
-        let now = Instant::now();
+        let now = self.clock.instant();
         let mut cleaned_count = 0;
 
         state.requests.retain(|_, requests| {
@@ -332,6 +340,27 @@ mod tests {
         assert!(rate_limiter.check_rate_limit(client_ip).is_ok());
     }
 
+    #[test]
+    fn test_rate_limiter_window_refills_with_mock_clock() {
+        use turbomcp_protocol::mcp_core::clock::MockClock;
+
+        let config = RateLimitConfig {
+            max_requests: 1,
+            window: Duration::from_secs(60),
+            enabled: true,
+            ..RateLimitConfig::default()
+        };
+        let clock = MockClock::new();
+        let rate_limiter = RateLimiter::with_clock(config, Arc::new(clock.clone()));
+        let client_ip = "127.0.0.1".parse().unwrap();
+
+        assert!(rate_limiter.check_rate_limit(client_ip).is_ok());
+        assert!(rate_limiter.check_rate_limit(client_ip).is_err());
+
+        clock.advance(Duration::from_secs(61));
+        assert!(rate_limiter.check_rate_limit(client_ip).is_ok());
+    }
+
     #[test]
     fn test_rate_limiter_different_ips() {
         let config = RateLimitConfig {
@@ -6,11 +6,11 @@
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, broadcast};
 
 use crate::core::{
-    Transport, TransportCapabilities, TransportConfig, TransportMessage, TransportMetrics,
-    TransportResult, TransportState, TransportType,
+    Transport, TransportCapabilities, TransportConfig, TransportEvent, TransportMessage,
+    TransportMetrics, TransportResult, TransportState, TransportType,
 };
 
 /// Thread-safe wrapper for sharing Transport instances across async tasks
@@ -73,6 +73,9 @@ pub struct SharedTransport<T: Transport> {
     capabilities: TransportCapabilities,
     /// Cached endpoint (immutable after construction)
     endpoint: Option<String>,
+    /// Event subscription taken at construction; resubscribed per caller so
+    /// `subscribe_events` never has to lock the transport
+    events: Option<broadcast::Receiver<TransportEvent>>,
 }
 
 impl<T: Transport> SharedTransport<T> {
@@ -88,12 +91,14 @@ impl<T: Transport> SharedTransport<T> {
         let transport_type = transport.transport_type();
         let capabilities = transport.capabilities().clone();
         let endpoint = transport.endpoint();
+        let events = transport.subscribe_events();
 
         Self {
             inner: Arc::new(Mutex::new(transport)),
             transport_type,
             capabilities,
             endpoint,
+            events,
         }
     }
 
@@ -196,6 +201,7 @@ impl<T: Transport> Clone for SharedTransport<T> {
             transport_type: self.transport_type,
             capabilities: self.capabilities.clone(),
             endpoint: self.endpoint.clone(),
+            events: self.events.as_ref().map(broadcast::Receiver::resubscribe),
         }
     }
 }
@@ -242,6 +248,10 @@ impl<T: Transport> Transport for SharedTransport<T> {
         Box::pin(async move { self.inner.lock().await.send(message).await })
     }
 
+    fn flush(&self) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
+        Box::pin(async move { self.inner.lock().await.flush().await })
+    }
+
     fn receive(
         &self,
     ) -> Pin<Box<dyn Future<Output = TransportResult<Option<TransportMessage>>> + Send + '_>> {
@@ -261,6 +271,10 @@ impl<T: Transport> Transport for SharedTransport<T> {
         self.endpoint.clone()
     }
 
+    fn subscribe_events(&self) -> Option<broadcast::Receiver<TransportEvent>> {
+        self.events.as_ref().map(broadcast::Receiver::resubscribe)
+    }
+
     fn configure(
         &self,
         config: TransportConfig,
@@ -271,6 +271,31 @@ mod compression_tests {
         );
     }
 
+    #[cfg(feature = "flate2")]
+    #[test]
+    fn test_threshold_skips_small_messages() {
+        use turbomcp_protocol::MessageId;
+
+        let compressor = MessageCompressor::new(CompressionType::Gzip).with_threshold(1024);
+        let small = json!({"jsonrpc": "2.0", "id": 1, "result": {}});
+        let large = json!({"jsonrpc": "2.0", "id": 2, "result": {"text": "x".repeat(2048)}});
+
+        let sent = compressor
+            .compress_message(MessageId::from(1), &small)
+            .unwrap();
+        assert!(!sent.is_compressed());
+        assert_eq!(sent.payload, serde_json::to_vec(&small).unwrap());
+        assert_eq!(compressor.decompress_message(&sent).unwrap(), small);
+
+        let sent = compressor
+            .compress_message(MessageId::from(2), &large)
+            .unwrap();
+        assert!(sent.is_compressed());
+        assert_eq!(sent.metadata.encoding.as_deref(), Some("gzip"));
+        assert!(sent.size() < serde_json::to_vec(&large).unwrap().len());
+        assert_eq!(compressor.decompress_message(&sent).unwrap(), large);
+    }
+
     #[cfg(feature = "brotli")]
     #[test]
     fn test_brotli_compression() {
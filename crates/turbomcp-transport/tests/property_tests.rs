@@ -139,16 +139,15 @@ fn retry_config_strategy() -> impl Strategy<Value = RetryConfig> {
     )
         .prop_map(
             |(max_attempts, base_delay_ms, max_delay_ms, backoff_multiplier, jitter_factor)| {
-                RetryConfig {
-                    max_attempts,
-                    base_delay: Duration::from_millis(base_delay_ms),
-                    max_delay: Duration::from_millis(max_delay_ms),
-                    backoff_multiplier,
-                    jitter_factor,
-                    retry_on_connection_error: true,
-                    retry_on_timeout: true,
-                    custom_retry_conditions: vec![],
-                }
+                let mut config = RetryConfig::default();
+                config.max_attempts = max_attempts;
+                config.base_delay = Duration::from_millis(base_delay_ms);
+                config.max_delay = Duration::from_millis(max_delay_ms);
+                config.backoff_multiplier = backoff_multiplier;
+                config.jitter_factor = jitter_factor;
+                config.retry_on_connection_error = true;
+                config.retry_on_timeout = true;
+                config
             },
         )
 }
@@ -377,16 +376,14 @@ proptest! {
         backoff_multiplier in 1.0f64..=10.0,
         jitter_factor in 0.0f64..=1.0
     ) {
-        let config = RetryConfig {
-            max_attempts,
-            base_delay: Duration::from_millis(base_delay_ms),
-            max_delay: Duration::from_millis(max_delay_ms),
-            backoff_multiplier,
-            jitter_factor,
-            retry_on_connection_error: true,
-            retry_on_timeout: true,
-            custom_retry_conditions: vec![],
-        };
+        let mut config = RetryConfig::default();
+        config.max_attempts = max_attempts;
+        config.base_delay = Duration::from_millis(base_delay_ms);
+        config.max_delay = Duration::from_millis(max_delay_ms);
+        config.backoff_multiplier = backoff_multiplier;
+        config.jitter_factor = jitter_factor;
+        config.retry_on_connection_error = true;
+        config.retry_on_timeout = true;
 
         // Should not panic when calculating delays
         for attempt in 0..max_attempts {
@@ -42,16 +42,15 @@ mod tcp_tests {
         let bind_addr: SocketAddr = "192.168.1.100:9090".parse().unwrap();
         let remote_addr: SocketAddr = "192.168.1.101:9091".parse().unwrap();
 
-        let config = TcpConfig {
-            bind_addr,
-            remote_addr: Some(remote_addr),
-            connect_timeout_ms: 10000,
-            keep_alive: false,
-            buffer_size: 16384,
-            max_connections: 512,
-            idle_timeout_secs: 600,
-            strict_mode: false,
-        };
+        let mut config = TcpConfig::default();
+        config.bind_addr = bind_addr;
+        config.remote_addr = Some(remote_addr);
+        config.connect_timeout_ms = 10000;
+        config.keep_alive = false;
+        config.buffer_size = 16384;
+        config.max_connections = 512;
+        config.idle_timeout_secs = 600;
+        config.strict_mode = false;
 
         assert_eq!(config.bind_addr, bind_addr);
         assert_eq!(config.remote_addr, Some(remote_addr));
@@ -381,34 +380,26 @@ mod tcp_tests {
     #[test]
     fn test_tcp_config_timeout_boundaries() {
         // Test minimum timeout
-        let config1 = TcpConfig {
-            connect_timeout_ms: 0,
-            ..Default::default()
-        };
+        let mut config1 = TcpConfig::default();
+        config1.connect_timeout_ms = 0;
         assert_eq!(config1.connect_timeout_ms, 0);
 
         // Test maximum reasonable timeout
-        let config2 = TcpConfig {
-            connect_timeout_ms: u64::MAX,
-            ..Default::default()
-        };
+        let mut config2 = TcpConfig::default();
+        config2.connect_timeout_ms = u64::MAX;
         assert_eq!(config2.connect_timeout_ms, u64::MAX);
     }
 
     #[test]
     fn test_tcp_config_buffer_size_boundaries() {
         // Test minimum buffer size
-        let config1 = TcpConfig {
-            buffer_size: 1,
-            ..Default::default()
-        };
+        let mut config1 = TcpConfig::default();
+        config1.buffer_size = 1;
         assert_eq!(config1.buffer_size, 1);
 
         // Test large buffer size
-        let config2 = TcpConfig {
-            buffer_size: 1024 * 1024, // 1MB
-            ..Default::default()
-        };
+        let mut config2 = TcpConfig::default();
+        config2.buffer_size = 1024 * 1024; // 1MB
         assert_eq!(config2.buffer_size, 1024 * 1024);
     }
 }
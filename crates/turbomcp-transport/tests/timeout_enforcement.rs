@@ -56,12 +56,11 @@ fn test_timeout_config_patient_preset() {
 
 #[test]
 fn test_transport_config_builder_with_custom_timeouts() {
-    let custom_timeouts = TimeoutConfig {
-        connect: Duration::from_secs(10),
-        request: Some(Duration::from_secs(30)),
-        total: Some(Duration::from_secs(90)),
-        read: Some(Duration::from_secs(15)),
-    };
+    let mut custom_timeouts = TimeoutConfig::default();
+    custom_timeouts.connect = Duration::from_secs(10);
+    custom_timeouts.request = Some(Duration::from_secs(30));
+    custom_timeouts.total = Some(Duration::from_secs(90));
+    custom_timeouts.read = Some(Duration::from_secs(15));
 
     let config = TransportConfigBuilder::new(TransportType::Stdio)
         .timeouts(custom_timeouts.clone())
@@ -105,12 +104,7 @@ fn test_timeout_config_serialization() {
 
 #[test]
 fn test_timeout_config_with_none_values() {
-    let config = TimeoutConfig {
-        connect: Duration::from_secs(30),
-        request: None,
-        total: None,
-        read: None,
-    };
+    let config = TimeoutConfig::unlimited();
 
     // Should serialize/deserialize correctly with None values
     let json = serde_json::to_string(&config).expect("Failed to serialize");
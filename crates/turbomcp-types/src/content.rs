@@ -94,6 +94,32 @@ impl Content {
         })
     }
 
+    /// Create text content with annotations.
+    #[must_use]
+    pub fn text_with_annotations(text: impl Into<String>, annotations: Annotations) -> Self {
+        Self::text(text).with_annotations(annotations)
+    }
+
+    /// Create image content from base64 data with annotations.
+    #[must_use]
+    pub fn image_with_annotations(
+        data: impl Into<String>,
+        mime_type: impl Into<String>,
+        annotations: Annotations,
+    ) -> Self {
+        Self::image(data, mime_type).with_annotations(annotations)
+    }
+
+    /// Create audio content from base64 data with annotations.
+    #[must_use]
+    pub fn audio_with_annotations(
+        data: impl Into<String>,
+        mime_type: impl Into<String>,
+        annotations: Annotations,
+    ) -> Self {
+        Self::audio(data, mime_type).with_annotations(annotations)
+    }
+
     /// Create audio content from base64 data.
     #[must_use]
     pub fn audio(data: impl Into<String>, mime_type: impl Into<String>) -> Self {
@@ -108,17 +134,16 @@ impl Content {
     /// Create a resource link.
     #[must_use]
     pub fn resource_link(resource: crate::definitions::Resource) -> Self {
-        Self::ResourceLink(ResourceLink {
-            uri: resource.uri,
-            name: resource.name,
-            description: resource.description,
-            title: resource.title,
-            icons: resource.icons,
-            mime_type: resource.mime_type,
-            annotations: resource.annotations,
-            size: resource.size,
-            meta: resource.meta,
-        })
+        Self::ResourceLink(resource.into())
+    }
+
+    /// Create a resource link referencing `uri` instead of inlining its contents.
+    ///
+    /// Use [`ResourceLink`]'s builder methods (or `Content::from(link)`) to
+    /// attach a MIME type, description, or size.
+    #[must_use]
+    pub fn link(uri: impl Into<String>, name: impl Into<String>) -> Self {
+        Self::ResourceLink(ResourceLink::new(uri, name))
     }
 
     /// Create embedded resource content.
@@ -151,6 +176,21 @@ impl Content {
         }
     }
 
+    /// Get the annotations attached to this content, if any.
+    ///
+    /// Resource links carry [`ResourceAnnotations`](crate::definitions::ResourceAnnotations)
+    /// instead and return `None` here.
+    #[must_use]
+    pub fn annotations(&self) -> Option<&Annotations> {
+        match self {
+            Self::Text(t) => t.annotations.as_ref(),
+            Self::Image(i) => i.annotations.as_ref(),
+            Self::Audio(a) => a.annotations.as_ref(),
+            Self::Resource(r) => r.annotations.as_ref(),
+            Self::ResourceLink(_) => None,
+        }
+    }
+
     /// Check if this is image content.
     #[must_use]
     pub fn is_image(&self) -> bool {
@@ -169,6 +209,15 @@ impl Content {
         matches!(self, Self::ResourceLink(_))
     }
 
+    /// Get the resource link if this is resource link content.
+    #[must_use]
+    pub fn as_resource_link(&self) -> Option<&ResourceLink> {
+        match self {
+            Self::ResourceLink(link) => Some(link),
+            _ => None,
+        }
+    }
+
     /// Check if this is resource content.
     #[must_use]
     pub fn is_resource(&self) -> bool {
@@ -460,6 +509,78 @@ pub struct ResourceLink {
     pub meta: Option<HashMap<String, Value>>,
 }
 
+impl ResourceLink {
+    /// Create a resource link with just a URI and name.
+    #[must_use]
+    pub fn new(uri: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            uri: uri.into(),
+            name: name.into(),
+            description: None,
+            title: None,
+            icons: None,
+            mime_type: None,
+            annotations: None,
+            size: None,
+            meta: None,
+        }
+    }
+
+    /// Set the description.
+    #[must_use]
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the MIME type of the linked resource.
+    #[must_use]
+    pub fn with_mime_type(mut self, mime_type: impl Into<String>) -> Self {
+        self.mime_type = Some(mime_type.into());
+        self
+    }
+
+    /// Set the size in bytes of the linked resource.
+    #[must_use]
+    pub fn with_size(mut self, size: u64) -> Self {
+        self.size = Some(size);
+        self
+    }
+}
+
+impl From<crate::definitions::Resource> for ResourceLink {
+    fn from(resource: crate::definitions::Resource) -> Self {
+        Self {
+            uri: resource.uri,
+            name: resource.name,
+            description: resource.description,
+            title: resource.title,
+            icons: resource.icons,
+            mime_type: resource.mime_type,
+            annotations: resource.annotations,
+            size: resource.size,
+            meta: resource.meta,
+        }
+    }
+}
+
+impl From<ResourceLink> for Content {
+    fn from(link: ResourceLink) -> Self {
+        Self::ResourceLink(link)
+    }
+}
+
+/// Embeds the contents inline, e.g. a block returned from `resources/read`.
+impl From<ResourceContents> for Content {
+    fn from(resource: ResourceContents) -> Self {
+        Self::Resource(EmbeddedResource {
+            resource,
+            annotations: None,
+            meta: None,
+        })
+    }
+}
+
 /// Embedded resource content in a message.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct EmbeddedResource {
@@ -582,6 +703,12 @@ pub struct Annotations {
 }
 
 impl Annotations {
+    /// Start building annotations.
+    #[must_use]
+    pub fn builder() -> AnnotationsBuilder {
+        AnnotationsBuilder::default()
+    }
+
     /// Create annotations for user audience only.
     #[must_use]
     pub fn for_user() -> Self {
@@ -615,6 +742,57 @@ impl Annotations {
     }
 }
 
+/// Builder for [`Annotations`].
+///
+/// ```
+/// use turbomcp_types::{Annotations, Content, Role};
+///
+/// let content = Content::text_with_annotations(
+///     "Build finished with 2 warnings",
+///     Annotations::builder()
+///         .audience(Role::User)
+///         .priority(0.8)
+///         .build(),
+/// );
+/// assert_eq!(content.annotations().unwrap().priority, Some(0.8));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AnnotationsBuilder {
+    annotations: Annotations,
+}
+
+impl AnnotationsBuilder {
+    /// Add a role to the intended audience.
+    #[must_use]
+    pub fn audience(mut self, role: Role) -> Self {
+        let audience = self.annotations.audience.get_or_insert_with(Vec::new);
+        if !audience.contains(&role) {
+            audience.push(role);
+        }
+        self
+    }
+
+    /// Set the priority, clamped to the spec range `0.0..=1.0`.
+    #[must_use]
+    pub fn priority(mut self, priority: f64) -> Self {
+        self.annotations.priority = Some(priority.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Set the last modified timestamp (ISO 8601).
+    #[must_use]
+    pub fn last_modified(mut self, timestamp: impl Into<String>) -> Self {
+        self.annotations.last_modified = Some(timestamp.into());
+        self
+    }
+
+    /// Finish building.
+    #[must_use]
+    pub fn build(self) -> Annotations {
+        self.annotations
+    }
+}
+
 // =============================================================================
 // Message (PromptMessage per spec)
 // =============================================================================
@@ -722,6 +900,28 @@ mod tests {
         assert!(parsed.is_resource_link());
     }
 
+    #[test]
+    fn test_resource_link_constructor() {
+        let link = Content::link("file:///reports/q3.csv", "q3.csv");
+        assert_eq!(
+            serde_json::to_value(&link).unwrap(),
+            serde_json::json!({
+                "type": "resource_link",
+                "uri": "file:///reports/q3.csv",
+                "name": "q3.csv"
+            })
+        );
+
+        let detailed: Content = ResourceLink::new("file:///reports/q3.csv", "q3.csv")
+            .with_mime_type("text/csv")
+            .with_size(1024)
+            .into();
+        let link = detailed.as_resource_link().expect("resource link");
+        assert_eq!(link.mime_type.as_deref(), Some("text/csv"));
+        assert_eq!(link.size, Some(1024));
+        assert!(Content::text("x").as_resource_link().is_none());
+    }
+
     #[test]
     fn test_sampling_content_tool_use_serde() {
         let content = SamplingContent::ToolUse(ToolUseContent {
@@ -792,6 +992,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_text_annotations_round_trip() {
+        let annotations = Annotations::builder()
+            .audience(Role::User)
+            .audience(Role::Assistant)
+            .audience(Role::User)
+            .priority(1.5)
+            .last_modified("2025-01-12T15:00:58Z")
+            .build();
+        assert_eq!(
+            annotations.audience,
+            Some(vec![Role::User, Role::Assistant])
+        );
+        assert_eq!(annotations.priority, Some(1.0));
+
+        let content = Content::text_with_annotations("Hello", annotations.clone());
+        let json = serde_json::to_value(&content).unwrap();
+        assert_eq!(
+            json["annotations"]["audience"],
+            serde_json::json!(["user", "assistant"])
+        );
+        assert_eq!(json["annotations"]["priority"], serde_json::json!(1.0));
+        assert_eq!(json["annotations"]["lastModified"], "2025-01-12T15:00:58Z");
+
+        let parsed: Content = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed.annotations(), Some(&annotations));
+        assert_eq!(parsed, content);
+    }
+
     // C-1: ResourceContents untagged deserialization disambiguation
     #[test]
     fn test_resource_contents_text_deser() {
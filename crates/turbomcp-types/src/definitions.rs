@@ -151,6 +151,26 @@ impl Icon {
         self.theme = Some(theme);
         self
     }
+
+    /// Whether `src` is usable as an icon source.
+    ///
+    /// Accepts `http://` and `https://` URLs with a host, and `data:` URIs
+    /// with a payload (`data:image/png;base64,...`).
+    #[must_use]
+    pub fn is_valid_src(src: &str) -> bool {
+        if let Some(rest) = src
+            .strip_prefix("https://")
+            .or_else(|| src.strip_prefix("http://"))
+        {
+            return rest
+                .split(['/', '?', '#'])
+                .next()
+                .is_some_and(|host| !host.is_empty() && !host.contains(char::is_whitespace));
+        }
+        src.strip_prefix("data:")
+            .and_then(|rest| rest.split_once(','))
+            .is_some_and(|(_, payload)| !payload.is_empty())
+    }
 }
 
 /// Tool definition.
@@ -409,6 +429,20 @@ impl ToolInputSchema {
         }
         self
     }
+
+    /// Set the schema `title` (builder style).
+    #[must_use]
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.extra_keywords
+            .insert("title".to_string(), Value::String(title.into()));
+        self
+    }
+
+    /// The schema `title`, if set.
+    #[must_use]
+    pub fn title(&self) -> Option<&str> {
+        self.extra_keywords.get("title").and_then(Value::as_str)
+    }
 }
 
 /// JSON Schema for a tool's structured output (`outputSchema` per MCP spec).
@@ -881,6 +915,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_icon_src_validation() {
+        assert!(Icon::is_valid_src("https://example.com/icon.png"));
+        assert!(Icon::is_valid_src("http://localhost:8080/icon.svg"));
+        assert!(Icon::is_valid_src("data:image/png;base64,iVBORw0KGgo="));
+
+        assert!(!Icon::is_valid_src("icon.png"));
+        assert!(!Icon::is_valid_src("ftp://example.com/icon.png"));
+        assert!(!Icon::is_valid_src("https:///icon.png"));
+        assert!(!Icon::is_valid_src("data:image/png;base64,"));
+        assert!(!Icon::is_valid_src("data:image/png"));
+    }
+
     #[test]
     fn test_tool_builder() {
         // Test with_annotations directly
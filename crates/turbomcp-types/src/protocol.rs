@@ -362,6 +362,144 @@ pub struct CreateMessageRequest {
     pub meta: Option<HashMap<String, Value>>,
 }
 
+impl CreateMessageRequest {
+    /// Start building a sampling request.
+    ///
+    /// ```
+    /// use turbomcp_types::{CreateMessageRequest, SamplingMessage};
+    ///
+    /// let request = CreateMessageRequest::builder()
+    ///     .user("Summarize the release notes")
+    ///     .max_tokens(256)
+    ///     .system_prompt("You are a concise assistant.")
+    ///     .temperature(0.2)
+    ///     .build();
+    /// assert_eq!(request.messages, vec![SamplingMessage::user("Summarize the release notes")]);
+    /// assert_eq!(request.max_tokens, 256);
+    /// ```
+    #[must_use]
+    pub fn builder() -> CreateMessageRequestBuilder {
+        CreateMessageRequestBuilder::default()
+    }
+}
+
+/// Fluent builder for [`CreateMessageRequest`].
+///
+/// `maxTokens` is required by the spec and must be greater than 0; set it
+/// with [`max_tokens`](Self::max_tokens) before calling [`build`](Self::build).
+/// Sampling rejects a request left at 0 with an invalid-params error.
+#[derive(Debug, Clone, Default)]
+pub struct CreateMessageRequestBuilder {
+    request: CreateMessageRequest,
+}
+
+impl CreateMessageRequestBuilder {
+    /// Append a message to the conversation.
+    #[must_use]
+    pub fn message(mut self, message: SamplingMessage) -> Self {
+        self.request.messages.push(message);
+        self
+    }
+
+    /// Append several messages to the conversation.
+    #[must_use]
+    pub fn messages(mut self, messages: impl IntoIterator<Item = SamplingMessage>) -> Self {
+        self.request.messages.extend(messages);
+        self
+    }
+
+    /// Append a user message with text content.
+    #[must_use]
+    pub fn user(self, text: impl Into<String>) -> Self {
+        self.message(SamplingMessage::user(text))
+    }
+
+    /// Append an assistant message with text content.
+    #[must_use]
+    pub fn assistant(self, text: impl Into<String>) -> Self {
+        self.message(SamplingMessage::assistant(text))
+    }
+
+    /// Set the maximum number of tokens to sample.
+    #[must_use]
+    pub fn max_tokens(mut self, max_tokens: u32) -> Self {
+        self.request.max_tokens = max_tokens;
+        self
+    }
+
+    /// Set the system prompt.
+    #[must_use]
+    pub fn system_prompt(mut self, prompt: impl Into<String>) -> Self {
+        self.request.system_prompt = Some(prompt.into());
+        self
+    }
+
+    /// Set the sampling temperature.
+    #[must_use]
+    pub fn temperature(mut self, temperature: f64) -> Self {
+        self.request.temperature = Some(temperature);
+        self
+    }
+
+    /// Add a stop sequence.
+    #[must_use]
+    pub fn stop_sequence(mut self, sequence: impl Into<String>) -> Self {
+        self.request
+            .stop_sequences
+            .get_or_insert_with(Vec::new)
+            .push(sequence.into());
+        self
+    }
+
+    /// Add several stop sequences.
+    #[must_use]
+    pub fn stop_sequences<I, S>(mut self, sequences: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.request
+            .stop_sequences
+            .get_or_insert_with(Vec::new)
+            .extend(sequences.into_iter().map(Into::into));
+        self
+    }
+
+    /// Set the model selection preferences.
+    #[must_use]
+    pub fn model_preferences(mut self, preferences: ModelPreferences) -> Self {
+        self.request.model_preferences = Some(preferences);
+        self
+    }
+
+    /// Add a model name hint to the model preferences.
+    #[must_use]
+    pub fn model_hint(mut self, name: impl Into<String>) -> Self {
+        self.request
+            .model_preferences
+            .get_or_insert_with(ModelPreferences::default)
+            .hints
+            .get_or_insert_with(Vec::new)
+            .push(ModelHint {
+                name: Some(name.into()),
+            });
+        self
+    }
+
+    /// Set which server context the client should include.
+    #[must_use]
+    pub fn include_context(mut self, include: IncludeContext) -> Self {
+        self.request.include_context = Some(include);
+        self
+    }
+
+    /// Finish building.
+    #[must_use]
+    pub fn build(self) -> CreateMessageRequest {
+        self.request
+    }
+}
+
 /// Message in a sampling request.
 ///
 /// Per MCP 2025-11-25, `content` can be a single `SamplingMessageContentBlock`
@@ -836,6 +974,51 @@ mod tests {
         assert_eq!(parsed, IncludeContext::ThisServer);
     }
 
+    #[test]
+    fn test_create_message_request_builder_matches_literal() {
+        let built = CreateMessageRequest::builder()
+            .user("What is the capital of France?")
+            .assistant("Paris.")
+            .max_tokens(100)
+            .system_prompt("Answer briefly.")
+            .temperature(0.5)
+            .stop_sequence("\n\n")
+            .stop_sequences(["END"])
+            .model_preferences(ModelPreferences {
+                intelligence_priority: Some(0.8),
+                ..Default::default()
+            })
+            .model_hint("claude")
+            .build();
+
+        let literal = CreateMessageRequest {
+            messages: vec![
+                SamplingMessage::user("What is the capital of France?"),
+                SamplingMessage::assistant("Paris."),
+            ],
+            max_tokens: 100,
+            model_preferences: Some(ModelPreferences {
+                hints: Some(vec![ModelHint {
+                    name: Some("claude".into()),
+                }]),
+                cost_priority: None,
+                speed_priority: None,
+                intelligence_priority: Some(0.8),
+            }),
+            system_prompt: Some("Answer briefly.".into()),
+            include_context: None,
+            temperature: Some(0.5),
+            stop_sequences: Some(vec!["\n\n".into(), "END".into()]),
+            task: None,
+            tools: None,
+            tool_choice: None,
+            metadata: None,
+            meta: None,
+        };
+
+        assert_eq!(built, literal);
+    }
+
     #[test]
     fn test_tool_choice_mode_optional() {
         // mode is optional, should serialize empty when None
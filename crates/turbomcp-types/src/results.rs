@@ -96,6 +96,15 @@ impl ToolResult {
         self.with_content(Content::image(data, mime_type))
     }
 
+    /// Add a link to a resource instead of inlining its contents.
+    ///
+    /// Useful for large outputs: the client fetches the linked resource with
+    /// `resources/read` only if it needs it.
+    #[must_use]
+    pub fn with_resource_link(self, uri: impl Into<String>, name: impl Into<String>) -> Self {
+        self.with_content(Content::link(uri, name))
+    }
+
     /// Set metadata.
     #[must_use]
     pub fn with_meta(mut self, meta: HashMap<String, Value>) -> Self {
@@ -108,6 +117,20 @@ impl ToolResult {
     pub fn first_text(&self) -> Option<&str> {
         self.content.first().and_then(|c| c.as_text())
     }
+
+    /// Confirmation returned for a dry-run call that passed validation.
+    ///
+    /// Carries `"dry_run": true` in `_meta` so clients can tell it apart from
+    /// a real result.
+    #[must_use]
+    pub fn dry_run(tool: &str) -> Self {
+        let mut meta = HashMap::new();
+        meta.insert("dry_run".into(), Value::Bool(true));
+        Self::text(alloc::format!(
+            "Dry run: '{tool}' validated its arguments and would execute"
+        ))
+        .with_meta(meta)
+    }
 }
 
 /// Result from reading a resource.
@@ -189,6 +212,78 @@ impl ResourceResult {
         self
     }
 
+    /// Tag this version of the resource with an ETag.
+    ///
+    /// The ETag is returned in `_meta.etag`. A later read whose
+    /// `_meta.ifNoneMatch` equals it is answered with [`ResourceResult::not_modified`]
+    /// instead of the contents.
+    #[must_use]
+    pub fn with_etag(mut self, etag: impl Into<String>) -> Self {
+        self.meta
+            .get_or_insert_with(HashMap::new)
+            .insert("etag".into(), Value::String(etag.into()));
+        self
+    }
+
+    /// The ETag of this result, if the handler set one.
+    #[must_use]
+    pub fn etag(&self) -> Option<&str> {
+        self.meta.as_ref()?.get("etag")?.as_str()
+    }
+
+    /// Create the empty result sent when a conditional read's `_meta.ifNoneMatch`
+    /// still matches `etag`.
+    #[must_use]
+    pub fn not_modified(etag: impl Into<String>) -> Self {
+        let mut meta = HashMap::new();
+        meta.insert("etag".into(), Value::String(etag.into()));
+        meta.insert("notModified".into(), Value::Bool(true));
+        Self {
+            contents: Vec::new(),
+            meta: Some(meta),
+        }
+    }
+
+    /// Whether this is a [`not_modified`](Self::not_modified) result.
+    #[must_use]
+    pub fn is_not_modified(&self) -> bool {
+        self.meta
+            .as_ref()
+            .and_then(|meta| meta.get("notModified"))
+            .and_then(Value::as_bool)
+            .unwrap_or(false)
+    }
+
+    /// Whether a `_meta.ifNoneMatch` value from the client matches this result's
+    /// ETag. `*` matches any tagged result.
+    #[must_use]
+    pub fn etag_matches(&self, if_none_match: &str) -> bool {
+        self.etag()
+            .is_some_and(|etag| if_none_match == "*" || if_none_match == etag)
+    }
+
+    /// Create a text resource result holding only the requested byte range
+    /// of `content`.
+    ///
+    /// The result's `_meta.range` records the returned `offset` and `length`
+    /// along with the `total` size of the full resource. Returns `None` if the
+    /// range starts past the end of `content` or splits a UTF-8 character.
+    #[must_use]
+    pub fn text_range(uri: impl Into<String>, content: &str, range: ByteRange) -> Option<Self> {
+        let span = range.resolve(content.len())?;
+        let slice = content.get(span.clone())?;
+        let mut meta = HashMap::new();
+        meta.insert(
+            "range".into(),
+            serde_json::json!({
+                "offset": span.start,
+                "length": slice.len(),
+                "total": content.len(),
+            }),
+        );
+        Some(Self::text(uri, slice).with_meta(meta))
+    }
+
     /// Get the first content's text if present.
     #[must_use]
     pub fn first_text(&self) -> Option<&str> {
@@ -199,6 +294,59 @@ impl ResourceResult {
     }
 }
 
+/// Byte range requested by a `resources/read` call.
+///
+/// Handlers that support partial reads apply it to the resource and return the
+/// requested slice (see [`ResourceResult::text_range`]); handlers that don't
+/// ignore it and return the full resource.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ByteRange {
+    /// Offset of the first byte to return.
+    pub offset: u64,
+    /// Maximum number of bytes to return. `None` reads to the end.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub length: Option<u64>,
+}
+
+impl ByteRange {
+    /// Range of at most `length` bytes starting at `offset`.
+    #[must_use]
+    pub fn new(offset: u64, length: u64) -> Self {
+        Self {
+            offset,
+            length: Some(length),
+        }
+    }
+
+    /// Range from `offset` to the end of the resource.
+    #[must_use]
+    pub fn from_offset(offset: u64) -> Self {
+        Self {
+            offset,
+            length: None,
+        }
+    }
+
+    /// Resolve this range against a resource of `total` bytes.
+    ///
+    /// The end is clamped to `total`. Returns `None` if the offset is at or
+    /// past the end of a non-empty resource.
+    #[must_use]
+    pub fn resolve(&self, total: usize) -> Option<core::ops::Range<usize>> {
+        let start = usize::try_from(self.offset).ok()?;
+        if start > total || (start == total && total > 0) {
+            return None;
+        }
+        let end = match self.length {
+            Some(length) => start
+                .saturating_add(usize::try_from(length).unwrap_or(usize::MAX))
+                .min(total),
+            None => total,
+        };
+        Some(start..end)
+    }
+}
+
 /// Result from getting a prompt.
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub struct PromptResult {
@@ -268,6 +416,23 @@ impl PromptResult {
         self
     }
 
+    /// Add a message with arbitrary content (image, resource link, ...).
+    #[must_use]
+    pub fn add_content(mut self, role: Role, content: impl Into<Content>) -> Self {
+        self.messages.push(Message::new(role, content.into()));
+        self
+    }
+
+    /// Add a user message embedding resource contents inline.
+    ///
+    /// Pass the contents of a [`ResourceResult`] to include a file in the
+    /// prompt; use [`add_content`](Self::add_content) with a
+    /// [`ResourceLink`](crate::ResourceLink) to reference it instead.
+    #[must_use]
+    pub fn add_resource(self, contents: ResourceContents) -> Self {
+        self.add_content(Role::User, contents)
+    }
+
     /// Set metadata.
     #[must_use]
     pub fn with_meta(mut self, meta: HashMap<String, Value>) -> Self {
@@ -314,6 +479,29 @@ mod tests {
         assert!(!result.is_error());
     }
 
+    #[test]
+    fn test_prompt_result_embeds_resources() {
+        let file = ResourceResult::text("file:///notes.md", "# Notes");
+        let result = PromptResult::user("Review this file:")
+            .add_resource(file.contents[0].clone())
+            .add_content(
+                Role::User,
+                crate::ResourceLink::new("file:///spec.md", "spec.md"),
+            );
+
+        let json = serde_json::to_value(&result).unwrap();
+        let embedded = &json["messages"][1]["content"];
+        assert_eq!(embedded["type"], "resource");
+        assert_eq!(embedded["resource"]["uri"], "file:///notes.md");
+        assert_eq!(embedded["resource"]["text"], "# Notes");
+        let link = &json["messages"][2]["content"];
+        assert_eq!(link["type"], "resource_link");
+        assert_eq!(link["uri"], "file:///spec.md");
+
+        let parsed: PromptResult = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, result);
+    }
+
     #[test]
     fn test_resource_result_text() {
         let result = ResourceResult::text("file:///test.txt", "content");
@@ -336,6 +524,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_resource_result_text_range() {
+        let log = "line one\nline two\nline three\n";
+        let result =
+            ResourceResult::text_range("file:///app.log", log, ByteRange::new(9, 8)).unwrap();
+        assert_eq!(result.first_text(), Some("line two"));
+
+        let range = &result.meta.as_ref().unwrap()["range"];
+        assert_eq!(range["offset"], 9);
+        assert_eq!(range["length"], 8);
+        assert_eq!(range["total"], log.len());
+
+        // Length past the end is clamped; offsets past the end are rejected.
+        let tail =
+            ResourceResult::text_range("file:///app.log", log, ByteRange::new(18, 100)).unwrap();
+        assert_eq!(tail.first_text(), Some("line three\n"));
+        assert!(
+            ResourceResult::text_range("file:///app.log", log, ByteRange::from_offset(64))
+                .is_none()
+        );
+        // Splitting a multi-byte character is rejected.
+        assert!(ResourceResult::text_range("file:///u.txt", "é", ByteRange::new(1, 1)).is_none());
+    }
+
+    #[test]
+    fn test_resource_result_etag() {
+        let result = ResourceResult::text("file:///a.txt", "a").with_etag("\"v1\"");
+        assert_eq!(result.etag(), Some("\"v1\""));
+        assert!(result.etag_matches("\"v1\""));
+        assert!(result.etag_matches("*"));
+        assert!(!result.etag_matches("\"v0\""));
+        assert!(!ResourceResult::text("file:///a.txt", "a").etag_matches("*"));
+
+        let unchanged = ResourceResult::not_modified("\"v1\"");
+        assert!(unchanged.is_not_modified());
+        assert!(unchanged.contents.is_empty());
+        assert_eq!(unchanged.etag(), Some("\"v1\""));
+        assert!(!result.is_not_modified());
+    }
+
     #[test]
     fn test_prompt_result_builder() {
         let result = PromptResult::user("Hello")
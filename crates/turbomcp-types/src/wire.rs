@@ -159,10 +159,44 @@ impl CallToolResult {
         self.content.first().and_then(Content::as_text)
     }
 
+    /// Iterates over the resource link blocks, in content order.
+    ///
+    /// Follow a link by reading its `uri` with `resources/read`.
+    pub fn resource_links(&self) -> impl Iterator<Item = &crate::content::ResourceLink> {
+        self.content.iter().filter_map(Content::as_resource_link)
+    }
+
     /// Whether `is_error` is explicitly `true`.
     pub fn has_error(&self) -> bool {
         self.is_error.unwrap_or(false)
     }
+
+    /// Whether the tool reported an error; the same check as [`has_error`](Self::has_error).
+    pub fn is_error(&self) -> bool {
+        self.has_error()
+    }
+
+    /// Returns the first text block, skipping any non-text content before it.
+    pub fn as_text(&self) -> Option<&str> {
+        self.content.iter().find_map(Content::as_text)
+    }
+
+    /// Deserializes the result into `T`.
+    ///
+    /// Uses `structured_content` when present, otherwise parses the first text
+    /// block as JSON. Error results are decoded the same way; check
+    /// [`is_error`](Self::is_error) first when the two shapes differ.
+    pub fn as_json<T: serde::de::DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        if let Some(structured) = &self.structured_content {
+            return T::deserialize(structured);
+        }
+        match self.as_text() {
+            Some(text) => serde_json::from_str(text),
+            None => Err(serde::de::Error::custom(
+                "tool result has no structured or text content",
+            )),
+        }
+    }
 }
 
 // =============================================================================
@@ -181,3 +215,55 @@ pub struct GetPromptResult {
     #[serde(rename = "_meta", default, skip_serializing_if = "Option::is_none")]
     pub meta: Option<HashMap<String, Value>>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Forecast {
+        city: String,
+        high: i32,
+    }
+
+    #[test]
+    fn test_call_tool_result_text_extraction() {
+        let result = CallToolResult::contents(vec![
+            Content::image("aGVsbG8=", "image/png"),
+            Content::text(r#"{"city":"Oslo","high":12}"#),
+        ]);
+
+        assert!(!result.is_error());
+        assert_eq!(result.first_text(), None);
+        assert_eq!(result.as_text(), Some(r#"{"city":"Oslo","high":12}"#));
+        assert_eq!(
+            result.as_json::<Forecast>().unwrap(),
+            Forecast {
+                city: "Oslo".into(),
+                high: 12
+            }
+        );
+        assert!(CallToolResult::default().as_json::<Value>().is_err());
+    }
+
+    #[test]
+    fn test_call_tool_result_prefers_structured_content() {
+        let result = CallToolResult {
+            content: vec![Content::text("Oslo: 12 degrees")],
+            structured_content: Some(serde_json::json!({"city": "Oslo", "high": 12})),
+            ..Default::default()
+        };
+
+        assert_eq!(result.as_text(), Some("Oslo: 12 degrees"));
+        assert_eq!(result.as_json::<Forecast>().unwrap().high, 12);
+    }
+
+    #[test]
+    fn test_call_tool_result_error() {
+        let result = CallToolResult::error("city not found");
+
+        assert!(result.is_error());
+        assert_eq!(result.as_text(), Some("city not found"));
+        assert!(result.as_json::<Forecast>().is_err());
+    }
+}
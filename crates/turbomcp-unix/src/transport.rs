@@ -46,6 +46,8 @@ pub struct UnixTransport {
     task_handles: Arc<tokio::sync::Mutex<JoinSet<()>>>,
     /// Shutdown signal broadcaster
     shutdown_tx: broadcast::Sender<()>,
+    /// Protocol version negotiated with the peer during `initialize`
+    peer_protocol_version: Arc<Mutex<Option<String>>>,
 }
 
 // Manual Debug implementation since broadcast::Sender doesn't implement Debug
@@ -95,6 +97,7 @@ impl UnixTransport {
             metrics: Arc::new(AtomicMetrics::default()),
             task_handles: Arc::new(tokio::sync::Mutex::new(JoinSet::new())),
             shutdown_tx,
+            peer_protocol_version: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -119,6 +122,7 @@ impl UnixTransport {
             metrics: Arc::new(AtomicMetrics::default()),
             task_handles: Arc::new(tokio::sync::Mutex::new(JoinSet::new())),
             shutdown_tx,
+            peer_protocol_version: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -637,6 +641,14 @@ impl Transport for UnixTransport {
     fn endpoint(&self) -> Option<String> {
         Some(format!("unix://{}", self.socket_path.display()))
     }
+
+    fn peer_protocol_version(&self) -> Option<String> {
+        self.peer_protocol_version.lock().clone()
+    }
+
+    fn set_peer_protocol_version(&self, version: &str) {
+        *self.peer_protocol_version.lock() = Some(version.to_string());
+    }
 }
 
 /// Unix socket transport configuration
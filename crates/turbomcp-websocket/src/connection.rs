@@ -16,8 +16,8 @@ use uuid::Uuid;
 
 use super::types::{WebSocketBidirectionalTransport, WebSocketConnectionStats};
 use turbomcp_transport_traits::{
-    ConnectionState, TransportError, TransportEvent, TransportEventEmitter, TransportResult,
-    TransportState, TransportType,
+    ConnectionState, PriorityQueue, TransportError, TransportEvent, TransportEventEmitter,
+    TransportResult, TransportState, TransportType,
 };
 
 impl WebSocketBidirectionalTransport {
@@ -48,6 +48,7 @@ impl WebSocketBidirectionalTransport {
             )),
             event_emitter: Arc::new(event_emitter),
             writer: Arc::new(Mutex::new(None)),
+            outgoing: Arc::new(parking_lot::Mutex::new(PriorityQueue::new())),
             reader: Arc::new(Mutex::new(None)),
             correlations: Arc::new(dashmap::DashMap::new()),
             elicitations: Arc::new(dashmap::DashMap::new()),
@@ -59,6 +60,7 @@ impl WebSocketBidirectionalTransport {
             shutdown_tx: Arc::new(shutdown_tx),
             reconnect_allowed: Arc::new(std::sync::atomic::AtomicBool::new(reconnect_enabled)),
             session_id: Uuid::new_v4().to_string(),
+            peer_protocol_version: Arc::new(parking_lot::Mutex::new(None)),
             incoming_rx: Arc::new(Mutex::new(incoming_rx)),
             incoming_tx,
         })
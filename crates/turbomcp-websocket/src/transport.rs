@@ -3,19 +3,63 @@
 //! This module implements the Transport trait for WebSocketBidirectionalTransport,
 //! providing the core send/receive operations and transport management.
 
-use futures::SinkExt;
+use futures::{Sink, SinkExt};
 use std::future::Future;
 use std::pin::Pin;
 use std::time::Duration;
+use tokio::sync::{broadcast, oneshot};
 use tokio_tungstenite::tungstenite::Message;
 use tracing::trace;
 
-use super::types::WebSocketBidirectionalTransport;
+use super::types::{QueuedSend, WebSocketBidirectionalTransport};
 use turbomcp_transport_traits::{
-    Transport, TransportCapabilities, TransportConfig, TransportError, TransportMessage,
-    TransportMetrics, TransportResult, TransportState, TransportType,
+    Transport, TransportCapabilities, TransportConfig, TransportError, TransportEvent,
+    TransportMessage, TransportMetrics, TransportResult, TransportState, TransportType,
 };
 
+impl WebSocketBidirectionalTransport {
+    /// Write one queued message, flushing it.
+    async fn write_queued<S>(
+        &self,
+        writer: Option<&mut S>,
+        message: &TransportMessage,
+    ) -> TransportResult<()>
+    where
+        S: Sink<Message, Error = tokio_tungstenite::tungstenite::Error> + Unpin,
+    {
+        let Some(writer) = writer else {
+            return Err(TransportError::SendFailed(
+                "WebSocket not connected".to_string(),
+            ));
+        };
+        let text = String::from_utf8(message.payload.to_vec())
+            .map_err(|e| TransportError::SendFailed(format!("Failed to serialize: {}", e)))?;
+
+        // Log at trace level — payload can be large and these used to log
+        // payload previews at info, which both spams operator logs and
+        // risks leaking secrets/PII embedded in JSON-RPC params.
+        trace!(
+            session = %self.session_id,
+            bytes = text.len(),
+            priority = message.priority(),
+            "websocket send"
+        );
+
+        // Send message and flush (SinkExt::send = feed + flush)
+        writer
+            .send(Message::Text(text.into()))
+            .await
+            .map_err(|e| TransportError::SendFailed(format!("WebSocket send failed: {}", e)))?;
+
+        self.metrics.write().await.messages_sent += 1;
+        trace!(
+            "Sent and flushed message {} in session {}",
+            message.id, self.session_id
+        );
+        Ok(())
+    }
+}
+
 impl Transport for WebSocketBidirectionalTransport {
     fn transport_type(&self) -> TransportType {
         TransportType::WebSocket
@@ -42,36 +86,37 @@ impl Transport for WebSocketBidirectionalTransport {
         message: TransportMessage,
     ) -> Pin<Box<dyn Future<Output = TransportResult<()>> + Send + '_>> {
         Box::pin(async move {
-            if let Some(ref mut writer) = *self.writer.lock().await {
-                let text = String::from_utf8(message.payload.to_vec()).map_err(|e| {
-                    TransportError::SendFailed(format!("Failed to serialize: {}", e))
-                })?;
-
-                // Log at trace level — payload can be large and these used to log
-                // payload previews at info, which both spams operator logs and
-                // risks leaking secrets/PII embedded in JSON-RPC params.
-                trace!(
-                    session = %self.session_id,
-                    bytes = text.len(),
-                    "websocket send"
-                );
+            std::str::from_utf8(&message.payload)
+                .map_err(|e| TransportError::SendFailed(format!("Failed to serialize: {}", e)))?;
 
-                // Send message and flush (SinkExt::send = feed + flush)
-                writer.send(Message::Text(text.into())).await.map_err(|e| {
-                    TransportError::SendFailed(format!("WebSocket send failed: {}", e))
-                })?;
+            // Queue before waiting for the writer: whoever holds the writer
+            // next flushes every queued message, highest priority first, and
+            // reports each outcome to the send that queued it.
+            let (done, outcome) = oneshot::channel();
+            self.outgoing
+                .lock()
+                .push(message.priority(), QueuedSend { message, done });
 
-                self.metrics.write().await.messages_sent += 1;
-                trace!(
-                    "Sent and flushed message {} in session {}",
-                    message.id, self.session_id
-                );
-                Ok(())
-            } else {
+            {
+                let mut writer = self.writer.lock().await;
+                loop {
+                    // Bind first so the queue lock is released before awaiting.
+                    let next = self.outgoing.lock().pop();
+                    let Some(QueuedSend { message, done }) = next else {
+                        break;
+                    };
+                    let result = self.write_queued(writer.as_mut(), &message).await;
+                    let _ = done.send(result);
+                }
+            }
+
+            // Only writes happen under the writer lock, so our message has
+            // been written by now unless the send writing it was dropped.
+            outcome.await.unwrap_or_else(|_| {
                 Err(TransportError::SendFailed(
-                    "WebSocket not connected".to_string(),
+                    "Send abandoned before the message was written".to_string(),
                 ))
-            }
+            })
         })
     }
 
@@ -159,6 +204,18 @@ impl Transport for WebSocketBidirectionalTransport {
         })
     }
 
+    fn peer_protocol_version(&self) -> Option<String> {
+        self.peer_protocol_version.lock().clone()
+    }
+
+    fn set_peer_protocol_version(&self, version: &str) {
+        *self.peer_protocol_version.lock() = Some(version.to_string());
+    }
+
+    fn subscribe_events(&self) -> Option<broadcast::Receiver<TransportEvent>> {
+        Some(self.event_emitter.subscribe())
+    }
+
     fn configure(
         &self,
         config: TransportConfig,
@@ -398,6 +455,70 @@ mod tests {
         let result = transport.send(message).await;
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("not connected"));
+        assert!(transport.outgoing.lock().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_send_flushes_control_messages_first() {
+        use futures::StreamExt;
+        use std::sync::Arc;
+        use turbomcp_transport_traits::CONTROL_PRIORITY;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+            let mut frames = Vec::new();
+            while frames.len() < 4 {
+                match ws.next().await {
+                    Some(Ok(Message::Text(text))) => frames.push(text.to_string()),
+                    Some(Ok(_)) => continue,
+                    _ => break,
+                }
+            }
+            frames
+        });
+
+        let config = WebSocketBidirectionalConfig::default();
+        let transport = Arc::new(WebSocketBidirectionalTransport::new(config).await.unwrap());
+        transport
+            .connect_client(&format!("ws://{addr}"))
+            .await
+            .unwrap();
+
+        // Hold the writer so every send below queues behind it.
+        let busy_writer = transport.writer.lock().await;
+        let mut sends = Vec::new();
+        for payload in ["bulk-0", "bulk-1", "bulk-2"] {
+            let transport = Arc::clone(&transport);
+            sends.push(tokio::spawn(async move {
+                transport
+                    .send(TransportMessage::new(
+                        MessageId::from(Uuid::new_v4()),
+                        Bytes::from(payload),
+                    ))
+                    .await
+            }));
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        let cancel = TransportMessage::with_metadata(
+            MessageId::from(Uuid::new_v4()),
+            Bytes::from("cancel"),
+            TransportMessageMetadata::default().with_priority(CONTROL_PRIORITY),
+        );
+        let transport_for_cancel = Arc::clone(&transport);
+        sends.push(tokio::spawn(async move {
+            transport_for_cancel.send(cancel).await
+        }));
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        drop(busy_writer);
+
+        for send in sends {
+            send.await.unwrap().unwrap();
+        }
+        let frames = server.await.unwrap();
+        assert_eq!(frames, vec!["cancel", "bulk-0", "bulk-1", "bulk-2"]);
     }
 
     #[tokio::test]
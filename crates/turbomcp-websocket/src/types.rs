@@ -19,8 +19,8 @@ use turbomcp_protocol::types::{ElicitRequestParams, ElicitResult};
 use uuid::Uuid;
 
 use turbomcp_transport_traits::{
-    ConnectionState, CorrelationContext, TransportCapabilities, TransportEventEmitter,
-    TransportMessage, TransportMetrics, TransportState,
+    ConnectionState, CorrelationContext, PriorityQueue, TransportCapabilities,
+    TransportEventEmitter, TransportMessage, TransportMetrics, TransportState,
 };
 
 use super::config::WebSocketBidirectionalConfig;
@@ -32,6 +32,18 @@ pub type WebSocketWriter =
 /// WebSocket reader handle for receiving messages (thread-safe, async-safe)
 pub type WebSocketReader =
     Arc<Mutex<Option<SplitStream<WebSocketStream<MaybeTlsStream<TcpStream>>>>>>;
+/// Messages waiting for the writer, each with the channel its sender awaits
+pub type OutgoingQueue = Arc<parking_lot::Mutex<PriorityQueue<QueuedSend>>>;
+
+/// A message waiting for the writer
+#[derive(Debug)]
+pub struct QueuedSend {
+    /// The message to write
+    pub message: TransportMessage,
+
+    /// Receives the outcome of writing this message
+    pub done: oneshot::Sender<turbomcp_transport_traits::TransportResult<()>>,
+}
 
 /// Pending elicitation request
 #[derive(Debug)]
@@ -109,6 +121,14 @@ pub struct WebSocketBidirectionalTransport {
     /// WebSocket write half (sender)
     pub writer: WebSocketWriter,
 
+    /// Messages waiting for the writer, flushed highest-priority first
+    ///
+    /// `Transport::send` enqueues here before taking the writer lock, so
+    /// while one send is in progress, a control message sent later still
+    /// goes out ahead of bulk messages queued before it. Whoever holds the
+    /// writer reports each message's outcome to the send that queued it.
+    pub outgoing: OutgoingQueue,
+
     /// WebSocket read half (receiver)
     pub reader: WebSocketReader,
 
@@ -158,6 +178,9 @@ pub struct WebSocketBidirectionalTransport {
     /// Session ID for this connection
     pub session_id: String,
 
+    /// Protocol version negotiated with the peer during `initialize`
+    pub peer_protocol_version: Arc<parking_lot::Mutex<Option<String>>>,
+
     /// Channel receiver for incoming messages (consumed by `Transport::receive()`)
     ///
     /// The background `spawn_message_reader_task()` reads from the WebSocket stream
@@ -6,7 +6,7 @@
 //! ## Design Philosophy
 //!
 //! - **Wire format**: JSON-RPC 2.0 (MCP protocol standard)
-//! - **Extensible**: Support for alternative formats (MessagePack, etc.)
+//! - **Extensible**: Support for alternative formats (MessagePack, CBOR, etc.)
 //! - **Zero-copy ready**: Integration with rkyv for internal message passing
 //! - **`no_std` compatible**: Works in embedded and WASM environments
 //!
@@ -38,6 +38,7 @@
 //! - `json` - Compatibility alias; JSON is always available
 //! - `simd` - SIMD-accelerated JSON (sonic-rs)
 //! - `msgpack` - MessagePack binary format
+//! - `cbor` - CBOR binary format
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![deny(unsafe_code)]
@@ -98,6 +99,63 @@ impl CodecError {
     pub fn decode(message: impl Into<String>) -> Self {
         Self::new(alloc::format!("decode: {}", message.into()))
     }
+
+    /// Create an error for JSON nested deeper than `max_depth`
+    pub fn depth_exceeded(max_depth: usize) -> Self {
+        Self::decode(alloc::format!(
+            "JSON nesting depth exceeds maximum of {max_depth}"
+        ))
+    }
+}
+
+/// Default maximum nesting depth accepted when decoding JSON.
+///
+/// Counts arrays and objects; `{"a":[1]}` has depth 2. MCP messages stay far
+/// below this, while attacker-controlled input nested thousands of levels deep
+/// is rejected before any recursive parser sees it.
+pub const DEFAULT_MAX_JSON_DEPTH: usize = 64;
+
+/// Reject JSON whose array/object nesting exceeds `max_depth`.
+///
+/// This is a single non-recursive pass over the bytes, so it is safe to run on
+/// untrusted input before handing it to a recursive deserializer. It does not
+/// validate the JSON otherwise; malformed input is left for the parser to
+/// report.
+///
+/// # Errors
+///
+/// Returns [`CodecError::depth_exceeded`] if the limit is exceeded.
+pub fn check_json_depth(bytes: &[u8], max_depth: usize) -> CodecResult<()> {
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for &byte in bytes {
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if byte == b'\\' {
+                escaped = true;
+            } else if byte == b'"' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        match byte {
+            b'"' => in_string = true,
+            b'[' | b'{' => {
+                depth += 1;
+                if depth > max_depth {
+                    return Err(CodecError::depth_exceeded(max_depth));
+                }
+            }
+            b']' | b'}' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+
+    Ok(())
 }
 
 impl From<CodecError> for McpError {
@@ -135,10 +193,30 @@ pub type CodecResult<T> = Result<T, CodecError>;
 /// - [`JsonCodec`] - Standard JSON encoding (default)
 /// - `SimdJsonCodec` - SIMD-accelerated JSON (requires `simd` feature)
 /// - `MsgPackCodec` - MessagePack binary format (requires `msgpack` feature)
+/// - `CborCodec` - CBOR binary format (requires `cbor` feature)
 pub trait Codec: Send + Sync {
     /// Encode a value to bytes
     fn encode<T: Serialize>(&self, value: &T) -> CodecResult<Vec<u8>>;
 
+    /// Encode a value directly into a writer
+    ///
+    /// Produces the same bytes as [`encode`](Self::encode). The default
+    /// implementation buffers the whole encoding first; codecs that can
+    /// serialize incrementally override it so large payloads (such as big
+    /// resource contents) are never held in memory at once.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    fn encode_to_writer<W: std::io::Write, T: Serialize>(
+        &self,
+        writer: &mut W,
+        value: &T,
+    ) -> CodecResult<()> {
+        let bytes = self.encode(value)?;
+        writer
+            .write_all(&bytes)
+            .map_err(|e| CodecError::encode(e.to_string()))
+    }
+
     /// Decode bytes to a value
     fn decode<T: DeserializeOwned>(&self, bytes: &[u8]) -> CodecResult<T>;
 
@@ -158,10 +236,16 @@ pub trait Codec: Send + Sync {
 ///
 /// This is the default codec for MCP protocol compliance.
 /// It produces human-readable JSON suitable for debugging and logging.
+///
+/// Input nested deeper than [`max_depth`](Self::max_depth) (default
+/// [`DEFAULT_MAX_JSON_DEPTH`]) is rejected before deserialization.
 #[derive(Debug, Clone, Default)]
 pub struct JsonCodec {
     /// Pretty print output (default: false)
     pub pretty: bool,
+    /// Maximum nesting depth accepted when decoding
+    /// (default: `None`, meaning [`DEFAULT_MAX_JSON_DEPTH`])
+    pub max_depth: Option<usize>,
 }
 
 impl JsonCodec {
@@ -172,7 +256,22 @@ impl JsonCodec {
 
     /// Create a JSON codec with pretty printing enabled
     pub fn pretty() -> Self {
-        Self { pretty: true }
+        Self {
+            pretty: true,
+            ..Self::default()
+        }
+    }
+
+    /// Set the maximum nesting depth accepted by [`Codec::decode`]
+    #[must_use]
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Maximum nesting depth accepted by [`Codec::decode`]
+    pub fn max_depth(&self) -> usize {
+        self.max_depth.unwrap_or(DEFAULT_MAX_JSON_DEPTH)
     }
 }
 
@@ -186,7 +285,22 @@ impl Codec for JsonCodec {
         .map_err(|e| CodecError::encode(e.to_string()))
     }
 
+    #[cfg(feature = "std")]
+    fn encode_to_writer<W: std::io::Write, T: Serialize>(
+        &self,
+        writer: &mut W,
+        value: &T,
+    ) -> CodecResult<()> {
+        if self.pretty {
+            serde_json::to_writer_pretty(writer, value)
+        } else {
+            serde_json::to_writer(writer, value)
+        }
+        .map_err(|e| CodecError::encode(e.to_string()))
+    }
+
     fn decode<T: DeserializeOwned>(&self, bytes: &[u8]) -> CodecResult<T> {
+        check_json_depth(bytes, self.max_depth())?;
         serde_json::from_slice(bytes).map_err(|e| CodecError::decode(e.to_string()))
     }
 
@@ -207,18 +321,48 @@ impl Codec for JsonCodec {
 ///
 /// This codec uses SIMD instructions for faster JSON parsing on supported
 /// targets. `sonic-rs` provides its own runtime feature detection and scalar
-/// fallback paths internally; this codec is a thin wrapper and does not add
-/// an additional fallback to `serde_json`.
+/// fallback paths internally.
+///
+/// If `sonic-rs` rejects an input, decoding is retried with `serde_json` so
+/// this codec never fails on a message [`JsonCodec`] would accept. The
+/// fallback is logged at debug level; when both parsers fail, the `sonic-rs`
+/// error is returned.
+///
+/// Like [`JsonCodec`], input nested deeper than
+/// [`max_depth`](Self::max_depth) is rejected before either parser runs.
 #[cfg(feature = "simd")]
 #[cfg_attr(docsrs, doc(cfg(feature = "simd")))]
-#[derive(Debug, Clone, Default)]
-pub struct SimdJsonCodec;
+#[derive(Debug, Clone)]
+pub struct SimdJsonCodec {
+    max_depth: usize,
+}
+
+#[cfg(feature = "simd")]
+impl Default for SimdJsonCodec {
+    fn default() -> Self {
+        Self {
+            max_depth: DEFAULT_MAX_JSON_DEPTH,
+        }
+    }
+}
 
 #[cfg(feature = "simd")]
 impl SimdJsonCodec {
     /// Create a new SIMD JSON codec
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// Set the maximum nesting depth accepted by [`Codec::decode`]
+    #[must_use]
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Maximum nesting depth accepted by [`Codec::decode`]
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
     }
 }
 
@@ -229,7 +373,8 @@ impl Codec for SimdJsonCodec {
     }
 
     fn decode<T: DeserializeOwned>(&self, bytes: &[u8]) -> CodecResult<T> {
-        sonic_rs::from_slice(bytes).map_err(|e| CodecError::decode(e.to_string()))
+        check_json_depth(bytes, self.max_depth)?;
+        decode_with_fallback(bytes, |bytes| sonic_rs::from_slice(bytes))
     }
 
     fn content_type(&self) -> &'static str {
@@ -245,6 +390,34 @@ impl Codec for SimdJsonCodec {
     }
 }
 
+/// Decode with `primary`, retrying with `serde_json` if it fails.
+#[cfg(feature = "simd")]
+fn decode_with_fallback<T, E>(
+    bytes: &[u8],
+    primary: impl FnOnce(&[u8]) -> Result<T, E>,
+) -> CodecResult<T>
+where
+    T: DeserializeOwned,
+    E: fmt::Display,
+{
+    let primary_err = match primary(bytes) {
+        Ok(value) => return Ok(value),
+        Err(e) => e,
+    };
+
+    match serde_json::from_slice(bytes) {
+        Ok(value) => {
+            #[cfg(feature = "std")]
+            tracing::debug!(
+                error = %primary_err,
+                "sonic-rs rejected input, decoded with serde_json fallback"
+            );
+            Ok(value)
+        }
+        Err(_) => Err(CodecError::decode(primary_err.to_string())),
+    }
+}
+
 /// MessagePack binary codec
 ///
 /// This codec produces compact binary output, suitable for
@@ -267,7 +440,7 @@ impl Codec for SimdJsonCodec {
 ///
 /// MessagePack can encode arbitrarily large binary/string fields. Applications should:
 /// - Enforce maximum message size limits at the transport layer
-/// - Use streaming decoders for large payloads when possible
+/// - Use [`StreamingMsgPackDecoder`] for large payloads over length-prefixed streams
 /// - Set appropriate memory limits in production environments
 ///
 /// ## Type Confusion
@@ -301,6 +474,15 @@ impl Codec for MsgPackCodec {
         rmp_serde::to_vec_named(value).map_err(|e| CodecError::encode(e.to_string()))
     }
 
+    #[cfg(feature = "std")]
+    fn encode_to_writer<W: std::io::Write, T: Serialize>(
+        &self,
+        writer: &mut W,
+        value: &T,
+    ) -> CodecResult<()> {
+        rmp_serde::encode::write_named(writer, value).map_err(|e| CodecError::encode(e.to_string()))
+    }
+
     fn decode<T: DeserializeOwned>(&self, bytes: &[u8]) -> CodecResult<T> {
         rmp_serde::from_slice(bytes).map_err(|e| CodecError::decode(e.to_string()))
     }
@@ -318,6 +500,68 @@ impl Codec for MsgPackCodec {
     }
 }
 
+/// CBOR binary codec
+///
+/// This codec produces compact binary output for peers that speak CBOR
+/// (RFC 8949), such as constrained IoT clients.
+///
+/// **Note**: Like MessagePack, CBOR is not MCP-compliant for external
+/// communication. Use it only on trusted boundaries where both sides have
+/// agreed on the format.
+///
+/// # Security Considerations
+///
+/// The concerns listed for [`MsgPackCodec`] apply equally here. `ciborium`
+/// bounds decoder recursion (256 levels by default), but a definite-length
+/// byte or text string can still declare a very large size, so enforce
+/// message size limits at the transport layer before decoding untrusted input.
+#[cfg(feature = "cbor")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cbor")))]
+#[derive(Debug, Clone, Default)]
+pub struct CborCodec;
+
+#[cfg(feature = "cbor")]
+impl CborCodec {
+    /// Create a new CBOR codec
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[cfg(feature = "cbor")]
+impl Codec for CborCodec {
+    fn encode<T: Serialize>(&self, value: &T) -> CodecResult<Vec<u8>> {
+        let mut bytes = Vec::new();
+        ciborium::into_writer(value, &mut bytes).map_err(|e| CodecError::encode(e.to_string()))?;
+        Ok(bytes)
+    }
+
+    #[cfg(feature = "std")]
+    fn encode_to_writer<W: std::io::Write, T: Serialize>(
+        &self,
+        writer: &mut W,
+        value: &T,
+    ) -> CodecResult<()> {
+        ciborium::into_writer(value, writer).map_err(|e| CodecError::encode(e.to_string()))
+    }
+
+    fn decode<T: DeserializeOwned>(&self, bytes: &[u8]) -> CodecResult<T> {
+        ciborium::from_reader(bytes).map_err(|e| CodecError::decode(e.to_string()))
+    }
+
+    fn content_type(&self) -> &'static str {
+        "application/cbor"
+    }
+
+    fn supports_streaming(&self) -> bool {
+        false
+    }
+
+    fn name(&self) -> &'static str {
+        "cbor"
+    }
+}
+
 /// Maximum streaming buffer size (1MB) - prevents DoS via unbounded memory growth
 const MAX_STREAMING_BUFFER_SIZE: usize = 1024 * 1024;
 
@@ -336,6 +580,10 @@ const MAX_STREAMING_BUFFER_SIZE: usize = 1024 * 1024;
 pub struct StreamingJsonDecoder {
     buffer: Vec<u8>,
     max_buffer_size: usize,
+    /// Per-message limit checked before a complete line is decoded. `None`
+    /// leaves lines bounded only by `max_buffer_size`.
+    max_line_size: Option<usize>,
+    max_depth: usize,
     /// Set to `true` when [`feed`] discarded data due to overflow. The next
     /// call to [`try_decode`] returns `CodecError::buffer_overflow` and clears
     /// the flag. Surfaces an explicit error to callers instead of the previous
@@ -355,6 +603,8 @@ impl StreamingJsonDecoder {
         Self {
             buffer: Vec::new(),
             max_buffer_size: MAX_STREAMING_BUFFER_SIZE,
+            max_line_size: None,
+            max_depth: DEFAULT_MAX_JSON_DEPTH,
             overflowed: false,
         }
     }
@@ -367,6 +617,8 @@ impl StreamingJsonDecoder {
         Self {
             buffer: Vec::with_capacity(capacity.min(MAX_STREAMING_BUFFER_SIZE)),
             max_buffer_size: MAX_STREAMING_BUFFER_SIZE,
+            max_line_size: None,
+            max_depth: DEFAULT_MAX_JSON_DEPTH,
             overflowed: false,
         }
     }
@@ -385,10 +637,31 @@ impl StreamingJsonDecoder {
         Self {
             buffer: Vec::new(),
             max_buffer_size: max_size.min(10 * 1024 * 1024), // Cap at 10MB
+            max_line_size: None,
+            max_depth: DEFAULT_MAX_JSON_DEPTH,
             overflowed: false,
         }
     }
 
+    /// Set the maximum nesting depth accepted per decoded line
+    #[must_use]
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Set the maximum size of a single message line, in bytes
+    ///
+    /// A complete line longer than this is discarded without being parsed
+    /// and [`Self::try_decode`] returns an error for it; later lines decode
+    /// normally. This is independent of the overall buffer cap, which only
+    /// bounds how much unterminated data may accumulate.
+    #[must_use]
+    pub fn with_max_line_size(mut self, max_line_size: usize) -> Self {
+        self.max_line_size = Some(max_line_size);
+        self
+    }
+
     /// Feed data into the decoder.
     ///
     /// # Security
@@ -447,16 +720,25 @@ impl StreamingJsonDecoder {
                 continue;
             }
 
+            if let Some(max_line_size) = self.max_line_size
+                && line.len() > max_line_size
+            {
+                let len = line.len();
+                self.buffer.drain(..=pos);
+                return Err(CodecError::decode(alloc::format!(
+                    "message of {len} bytes exceeds maximum line size of {max_line_size} bytes"
+                )));
+            }
+
             // Try to decode
-            let result = serde_json::from_slice(line);
+            let result = check_json_depth(line, self.max_depth).and_then(|()| {
+                serde_json::from_slice(line).map_err(|e| CodecError::decode(e.to_string()))
+            });
 
             // Remove processed data (including newline)
             self.buffer.drain(..=pos);
 
-            return match result {
-                Ok(value) => Ok(Some(value)),
-                Err(e) => Err(CodecError::decode(e.to_string())),
-            };
+            return result.map(Some);
         }
 
         Ok(None)
@@ -481,6 +763,379 @@ impl StreamingJsonDecoder {
     pub fn max_buffer_size(&self) -> usize {
         self.max_buffer_size
     }
+
+    /// Get maximum nesting depth per decoded line
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    /// Get maximum size of a single message line, if one is set
+    pub fn max_line_size(&self) -> Option<usize> {
+        self.max_line_size
+    }
+}
+
+/// Size of the big-endian length header that precedes each MessagePack frame
+#[cfg(feature = "msgpack")]
+const MSGPACK_FRAME_HEADER_LEN: usize = 4;
+
+/// Streaming MessagePack decoder for length-prefixed byte streams
+///
+/// Each frame is a `u32` big-endian length header followed by that many
+/// bytes of MessagePack. Bytes may arrive in arbitrary chunks; one value is
+/// decoded per complete frame.
+///
+/// # Security
+///
+/// Like [`StreamingJsonDecoder`], the decoder enforces a maximum buffer size
+/// of 1MB by default. A frame whose declared length cannot fit in the buffer
+/// is rejected as soon as its header arrives, before its body is buffered.
+/// Length-prefixed streams have no delimiter to resync on, so after an
+/// overflow or an oversized header the buffered bytes are discarded and the
+/// caller should treat the stream as desynchronised.
+#[cfg(feature = "msgpack")]
+#[cfg_attr(docsrs, doc(cfg(feature = "msgpack")))]
+#[derive(Debug)]
+pub struct StreamingMsgPackDecoder {
+    buffer: Vec<u8>,
+    max_buffer_size: usize,
+    /// Set to `true` when [`Self::feed`] discarded data due to overflow. The
+    /// next call to [`Self::try_decode`] returns an error and clears the flag.
+    overflowed: bool,
+}
+
+#[cfg(feature = "msgpack")]
+impl Default for StreamingMsgPackDecoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "msgpack")]
+impl StreamingMsgPackDecoder {
+    /// Create a new streaming decoder with default 1MB buffer limit
+    pub fn new() -> Self {
+        Self {
+            buffer: Vec::new(),
+            max_buffer_size: MAX_STREAMING_BUFFER_SIZE,
+            overflowed: false,
+        }
+    }
+
+    /// Create with custom maximum buffer size
+    ///
+    /// The limit covers the length header plus the frame body, so the largest
+    /// accepted body is `max_size - 4` bytes. The value is capped at 10MB
+    /// regardless of input.
+    pub fn with_max_size(max_size: usize) -> Self {
+        Self {
+            buffer: Vec::new(),
+            max_buffer_size: max_size.min(10 * 1024 * 1024), // Cap at 10MB
+            overflowed: false,
+        }
+    }
+
+    /// Feed data into the decoder.
+    ///
+    /// # Security
+    ///
+    /// If the buffered bytes exceed `max_buffer_size`, the buffer is cleared
+    /// and the next call to [`Self::try_decode`] returns a [`CodecError`].
+    pub fn feed(&mut self, data: &[u8]) {
+        self.buffer.extend_from_slice(data);
+
+        if self.buffer.len() > self.max_buffer_size {
+            #[cfg(feature = "std")]
+            tracing::warn!(
+                buffer_size = self.buffer.len(),
+                max_size = self.max_buffer_size,
+                "streaming buffer exceeded maximum size, dropping buffered frames"
+            );
+            self.buffer.clear();
+            self.overflowed = true;
+        }
+    }
+
+    /// Try to decode the next complete frame
+    ///
+    /// Returns `Some(T)` if a complete frame was decoded, or `None` if the
+    /// header or body of the next frame has not fully arrived yet. A frame
+    /// whose body fails to decode is consumed, so later frames still decode.
+    pub fn try_decode<T: DeserializeOwned>(&mut self) -> CodecResult<Option<T>> {
+        if self.overflowed {
+            self.overflowed = false;
+            return Err(CodecError::decode(
+                "streaming buffer overflowed; buffered frames discarded",
+            ));
+        }
+
+        let Some(header) = self.buffer.first_chunk::<MSGPACK_FRAME_HEADER_LEN>() else {
+            return Ok(None);
+        };
+        let body_len = u32::from_be_bytes(*header) as usize;
+        let frame_len = MSGPACK_FRAME_HEADER_LEN.saturating_add(body_len);
+
+        if frame_len > self.max_buffer_size {
+            self.buffer.clear();
+            return Err(CodecError::decode(alloc::format!(
+                "frame of {body_len} bytes exceeds maximum buffer size of {} bytes",
+                self.max_buffer_size
+            )));
+        }
+
+        if self.buffer.len() < frame_len {
+            return Ok(None);
+        }
+
+        let result = rmp_serde::from_slice(&self.buffer[MSGPACK_FRAME_HEADER_LEN..frame_len])
+            .map_err(|e| CodecError::decode(e.to_string()));
+
+        self.buffer.drain(..frame_len);
+
+        result.map(Some)
+    }
+
+    /// Clear the internal buffer
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+    }
+
+    /// Check if buffer is empty
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Get current buffer length
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Get maximum buffer size
+    pub fn max_buffer_size(&self) -> usize {
+        self.max_buffer_size
+    }
+}
+
+/// Receiver for codec timing and payload size measurements
+///
+/// Implement this to forward measurements to a metrics backend, or use
+/// [`CodecMetrics`] for in-process counters.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub trait CodecMetricsSink: Send + Sync {
+    /// Record a successful encode that produced `output_bytes` bytes
+    fn record_encode(
+        &self,
+        codec: &'static str,
+        duration: std::time::Duration,
+        output_bytes: usize,
+    );
+
+    /// Record a successful decode of `input_bytes` bytes
+    fn record_decode(&self, codec: &'static str, duration: std::time::Duration, input_bytes: usize);
+}
+
+/// Atomic counters for codec activity
+///
+/// The default [`CodecMetricsSink`]. Totals accumulate across all codecs
+/// that share it; read them with [`snapshot`](Self::snapshot).
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Debug, Default)]
+pub struct CodecMetrics {
+    encode_count: core::sync::atomic::AtomicU64,
+    encode_bytes: core::sync::atomic::AtomicU64,
+    encode_nanos: core::sync::atomic::AtomicU64,
+    decode_count: core::sync::atomic::AtomicU64,
+    decode_bytes: core::sync::atomic::AtomicU64,
+    decode_nanos: core::sync::atomic::AtomicU64,
+}
+
+/// Point-in-time copy of [`CodecMetrics`]
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CodecMetricsSnapshot {
+    /// Number of successful encodes
+    pub encode_count: u64,
+    /// Total bytes produced by encodes
+    pub encode_bytes: u64,
+    /// Total time spent encoding
+    pub encode_time: std::time::Duration,
+    /// Number of successful decodes
+    pub decode_count: u64,
+    /// Total bytes consumed by decodes
+    pub decode_bytes: u64,
+    /// Total time spent decoding
+    pub decode_time: std::time::Duration,
+}
+
+#[cfg(feature = "std")]
+impl CodecMetrics {
+    /// Create zeroed counters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Read the current totals
+    pub fn snapshot(&self) -> CodecMetricsSnapshot {
+        use core::sync::atomic::Ordering::Relaxed;
+        CodecMetricsSnapshot {
+            encode_count: self.encode_count.load(Relaxed),
+            encode_bytes: self.encode_bytes.load(Relaxed),
+            encode_time: std::time::Duration::from_nanos(self.encode_nanos.load(Relaxed)),
+            decode_count: self.decode_count.load(Relaxed),
+            decode_bytes: self.decode_bytes.load(Relaxed),
+            decode_time: std::time::Duration::from_nanos(self.decode_nanos.load(Relaxed)),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+fn saturating_nanos(duration: std::time::Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+#[cfg(feature = "std")]
+impl CodecMetricsSink for CodecMetrics {
+    fn record_encode(
+        &self,
+        _codec: &'static str,
+        duration: std::time::Duration,
+        output_bytes: usize,
+    ) {
+        use core::sync::atomic::Ordering::Relaxed;
+        self.encode_count.fetch_add(1, Relaxed);
+        self.encode_bytes.fetch_add(output_bytes as u64, Relaxed);
+        self.encode_nanos
+            .fetch_add(saturating_nanos(duration), Relaxed);
+    }
+
+    fn record_decode(
+        &self,
+        _codec: &'static str,
+        duration: std::time::Duration,
+        input_bytes: usize,
+    ) {
+        use core::sync::atomic::Ordering::Relaxed;
+        self.decode_count.fetch_add(1, Relaxed);
+        self.decode_bytes.fetch_add(input_bytes as u64, Relaxed);
+        self.decode_nanos
+            .fetch_add(saturating_nanos(duration), Relaxed);
+    }
+}
+
+/// Codec wrapper that reports timing and sizes to a [`CodecMetricsSink`]
+///
+/// Wraps any [`Codec`]; only successful operations are recorded. Encodes
+/// report the output size, decodes the input size.
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use turbomcp_wire::{Codec, CodecMetrics, InstrumentedCodec, JsonCodec};
+///
+/// let metrics = Arc::new(CodecMetrics::new());
+/// let codec = InstrumentedCodec::new(JsonCodec::new(), metrics.clone());
+///
+/// let bytes = codec.encode(&42).unwrap();
+/// let _: u32 = codec.decode(&bytes).unwrap();
+/// assert_eq!(metrics.snapshot().encode_count, 1);
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Clone)]
+pub struct InstrumentedCodec<C> {
+    inner: C,
+    sink: std::sync::Arc<dyn CodecMetricsSink>,
+}
+
+#[cfg(feature = "std")]
+impl<C: fmt::Debug> fmt::Debug for InstrumentedCodec<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InstrumentedCodec")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C: Codec> InstrumentedCodec<C> {
+    /// Wrap `inner`, reporting to `sink`
+    pub fn new(inner: C, sink: std::sync::Arc<dyn CodecMetricsSink>) -> Self {
+        Self { inner, sink }
+    }
+
+    /// Borrow the wrapped codec
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C: Codec> Codec for InstrumentedCodec<C> {
+    fn encode<T: Serialize>(&self, value: &T) -> CodecResult<Vec<u8>> {
+        let start = std::time::Instant::now();
+        let bytes = self.inner.encode(value)?;
+        self.sink
+            .record_encode(self.inner.name(), start.elapsed(), bytes.len());
+        Ok(bytes)
+    }
+
+    fn encode_to_writer<W: std::io::Write, T: Serialize>(
+        &self,
+        writer: &mut W,
+        value: &T,
+    ) -> CodecResult<()> {
+        let start = std::time::Instant::now();
+        let mut counting = CountingWriter {
+            inner: writer,
+            written: 0,
+        };
+        self.inner.encode_to_writer(&mut counting, value)?;
+        self.sink
+            .record_encode(self.inner.name(), start.elapsed(), counting.written);
+        Ok(())
+    }
+
+    fn decode<T: DeserializeOwned>(&self, bytes: &[u8]) -> CodecResult<T> {
+        let start = std::time::Instant::now();
+        let value = self.inner.decode(bytes)?;
+        self.sink
+            .record_decode(self.inner.name(), start.elapsed(), bytes.len());
+        Ok(value)
+    }
+
+    fn content_type(&self) -> &'static str {
+        self.inner.content_type()
+    }
+
+    fn supports_streaming(&self) -> bool {
+        self.inner.supports_streaming()
+    }
+
+    fn name(&self) -> &'static str {
+        self.inner.name()
+    }
+}
+
+/// Writer that counts the bytes passed through to `inner`
+#[cfg(feature = "std")]
+struct CountingWriter<'a, W> {
+    inner: &'a mut W,
+    written: usize,
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> std::io::Write for CountingWriter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.written += n;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
 }
 
 /// Enum wrapper for all codec types
@@ -499,6 +1154,10 @@ pub enum AnyCodec {
     #[cfg(feature = "msgpack")]
     #[cfg_attr(docsrs, doc(cfg(feature = "msgpack")))]
     MsgPack(MsgPackCodec),
+    /// CBOR binary codec
+    #[cfg(feature = "cbor")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cbor")))]
+    Cbor(CborCodec),
 }
 
 impl AnyCodec {
@@ -508,6 +1167,7 @@ impl AnyCodec {
     /// - `"json"` - Standard JSON codec
     /// - `"simd"` or `"simd-json"` - SIMD-accelerated JSON (requires `simd` feature)
     /// - `"msgpack"` - MessagePack binary (requires `msgpack` feature)
+    /// - `"cbor"` - CBOR binary (requires `cbor` feature)
     pub fn from_name(name: &str) -> Option<Self> {
         match name {
             "json" => Some(Self::Json(JsonCodec::new())),
@@ -515,10 +1175,28 @@ impl AnyCodec {
             "simd" | "simd-json" => Some(Self::SimdJson(SimdJsonCodec::new())),
             #[cfg(feature = "msgpack")]
             "msgpack" => Some(Self::MsgPack(MsgPackCodec::new())),
+            #[cfg(feature = "cbor")]
+            "cbor" => Some(Self::Cbor(CborCodec::new())),
             _ => None,
         }
     }
 
+    /// Set the maximum JSON nesting depth for JSON-based codecs.
+    ///
+    /// Binary codecs are returned unchanged.
+    #[must_use]
+    pub fn with_max_json_depth(self, max_depth: usize) -> Self {
+        match self {
+            Self::Json(c) => Self::Json(c.with_max_depth(max_depth)),
+            #[cfg(feature = "simd")]
+            Self::SimdJson(c) => Self::SimdJson(c.with_max_depth(max_depth)),
+            #[cfg(feature = "msgpack")]
+            other @ Self::MsgPack(_) => other,
+            #[cfg(feature = "cbor")]
+            other @ Self::Cbor(_) => other,
+        }
+    }
+
     /// List available codec names
     pub fn available_names() -> &'static [&'static str] {
         &[
@@ -527,6 +1205,8 @@ impl AnyCodec {
             "simd-json",
             #[cfg(feature = "msgpack")]
             "msgpack",
+            #[cfg(feature = "cbor")]
+            "cbor",
         ]
     }
 
@@ -538,6 +1218,27 @@ impl AnyCodec {
             Self::SimdJson(c) => c.encode(value),
             #[cfg(feature = "msgpack")]
             Self::MsgPack(c) => c.encode(value),
+            #[cfg(feature = "cbor")]
+            Self::Cbor(c) => c.encode(value),
+        }
+    }
+
+    /// Encode a value directly into a writer
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn encode_to_writer<W: std::io::Write, T: Serialize>(
+        &self,
+        writer: &mut W,
+        value: &T,
+    ) -> CodecResult<()> {
+        match self {
+            Self::Json(c) => c.encode_to_writer(writer, value),
+            #[cfg(feature = "simd")]
+            Self::SimdJson(c) => c.encode_to_writer(writer, value),
+            #[cfg(feature = "msgpack")]
+            Self::MsgPack(c) => c.encode_to_writer(writer, value),
+            #[cfg(feature = "cbor")]
+            Self::Cbor(c) => c.encode_to_writer(writer, value),
         }
     }
 
@@ -549,6 +1250,8 @@ impl AnyCodec {
             Self::SimdJson(c) => c.decode(bytes),
             #[cfg(feature = "msgpack")]
             Self::MsgPack(c) => c.decode(bytes),
+            #[cfg(feature = "cbor")]
+            Self::Cbor(c) => c.decode(bytes),
         }
     }
 
@@ -560,6 +1263,8 @@ impl AnyCodec {
             Self::SimdJson(c) => c.content_type(),
             #[cfg(feature = "msgpack")]
             Self::MsgPack(c) => c.content_type(),
+            #[cfg(feature = "cbor")]
+            Self::Cbor(c) => c.content_type(),
         }
     }
 
@@ -571,6 +1276,8 @@ impl AnyCodec {
             Self::SimdJson(c) => c.name(),
             #[cfg(feature = "msgpack")]
             Self::MsgPack(c) => c.name(),
+            #[cfg(feature = "cbor")]
+            Self::Cbor(c) => c.name(),
         }
     }
 }
@@ -588,6 +1295,33 @@ mod tests {
         params: Option<serde_json::Value>,
     }
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_instrumented_codec_records_roundtrip() {
+        let metrics = std::sync::Arc::new(CodecMetrics::new());
+        let codec = InstrumentedCodec::new(JsonCodec::new(), metrics.clone());
+        let msg = TestMessage {
+            id: 7,
+            method: "tools/list".into(),
+            params: Some(serde_json::json!({ "cursor": "abc" })),
+        };
+
+        let bytes = codec.encode(&msg).unwrap();
+        let decoded: TestMessage = codec.decode(&bytes).unwrap();
+        assert_eq!(decoded, msg);
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.encode_count, 1);
+        assert_eq!(snapshot.decode_count, 1);
+        assert_eq!(snapshot.encode_bytes, bytes.len() as u64);
+        assert_eq!(snapshot.decode_bytes, bytes.len() as u64);
+        assert!(snapshot.encode_time + snapshot.decode_time > std::time::Duration::ZERO);
+
+        // Failed decodes are not recorded.
+        assert!(codec.decode::<TestMessage>(b"not json").is_err());
+        assert_eq!(metrics.snapshot().decode_count, 1);
+    }
+
     #[test]
     fn test_json_codec_roundtrip() {
         let codec = JsonCodec::new();
@@ -619,6 +1353,30 @@ mod tests {
         assert!(output.contains('\n'));
     }
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_encode_to_writer_matches_encode() {
+        let msg = TestMessage {
+            id: 9,
+            method: "resources/read".into(),
+            params: Some(serde_json::json!({
+                "contents": [{ "uri": "file:///big.txt", "text": "x".repeat(1 << 20) }]
+            })),
+        };
+
+        for codec in [JsonCodec::new(), JsonCodec::pretty()] {
+            let mut written = Vec::new();
+            codec.encode_to_writer(&mut written, &msg).unwrap();
+            assert_eq!(written, codec.encode(&msg).unwrap());
+        }
+
+        let metrics = std::sync::Arc::new(CodecMetrics::new());
+        let codec = InstrumentedCodec::new(JsonCodec::new(), metrics.clone());
+        let mut written = Vec::new();
+        codec.encode_to_writer(&mut written, &msg).unwrap();
+        assert_eq!(metrics.snapshot().encode_bytes, written.len() as u64);
+    }
+
     #[test]
     fn test_codec_content_type() {
         let json = JsonCodec::new();
@@ -713,6 +1471,29 @@ mod tests {
         assert_eq!(decoder.max_buffer_size(), 1024 * 1024); // 1MB default
     }
 
+    #[test]
+    fn test_streaming_decoder_line_limit() {
+        let mut decoder = StreamingJsonDecoder::new().with_max_line_size(64);
+        assert_eq!(decoder.max_line_size(), Some(64));
+
+        let oversized = alloc::format!("{{\"method\":\"{}\"}}\n", "x".repeat(100));
+        decoder.feed(oversized.as_bytes());
+        decoder.feed(b"{\"id\":1,\"method\":\"ok\"}\n");
+
+        let err = decoder.try_decode::<serde_json::Value>().unwrap_err();
+        assert!(
+            err.message
+                .contains("exceeds maximum line size of 64 bytes"),
+            "unexpected error: {}",
+            err.message
+        );
+
+        // The oversized line is dropped; the next message still decodes.
+        let next: serde_json::Value = decoder.try_decode().unwrap().unwrap();
+        assert_eq!(next["method"], "ok");
+        assert!(decoder.is_empty());
+    }
+
     #[test]
     fn test_any_codec() {
         let codec = AnyCodec::from_name("json").unwrap();
@@ -732,6 +1513,44 @@ mod tests {
         assert!(err.message.contains("decode"));
     }
 
+    fn nested_arrays(depth: usize) -> Vec<u8> {
+        let mut bytes = vec![b'['; depth];
+        bytes.extend(core::iter::repeat_n(b']', depth));
+        bytes
+    }
+
+    #[test]
+    fn test_decode_rejects_excessive_nesting() {
+        let codec = JsonCodec::new();
+        let err = codec
+            .decode::<serde_json::Value>(&nested_arrays(100_000))
+            .unwrap_err();
+        assert!(err.message.contains("nesting depth exceeds maximum of 64"));
+
+        let shallow = JsonCodec::new().with_max_depth(3);
+        assert!(
+            shallow
+                .decode::<serde_json::Value>(&nested_arrays(3))
+                .is_ok()
+        );
+        assert!(
+            shallow
+                .decode::<serde_json::Value>(&nested_arrays(4))
+                .is_err()
+        );
+
+        // Brackets inside strings do not count towards the depth.
+        let bytes = br#"{"method":"[[[[[[","params":{"x":"\"{{{{"}}"#;
+        assert!(shallow.decode::<serde_json::Value>(bytes).is_ok());
+
+        let mut decoder = StreamingJsonDecoder::new().with_max_depth(3);
+        let mut line = nested_arrays(4);
+        line.push(b'\n');
+        decoder.feed(&line);
+        assert!(decoder.try_decode::<serde_json::Value>().is_err());
+        assert!(decoder.is_empty());
+    }
+
     #[cfg(feature = "simd")]
     #[test]
     fn test_simd_codec_roundtrip() {
@@ -748,6 +1567,21 @@ mod tests {
         assert_eq!(msg, decoded);
     }
 
+    #[cfg(feature = "simd")]
+    #[test]
+    fn test_simd_decode_falls_back_to_serde_json() {
+        let bytes = br#"{"id":7,"method":"fallback/test"}"#;
+        let rejecting = |_: &[u8]| Err::<TestMessage, _>("simulated sonic-rs error");
+
+        let decoded: TestMessage = decode_with_fallback(bytes, rejecting).unwrap();
+        assert_eq!(decoded.id, 7);
+        assert_eq!(decoded.method, "fallback/test");
+
+        // When serde_json also fails, the primary error is surfaced.
+        let err = decode_with_fallback(b"not json", rejecting).unwrap_err();
+        assert!(err.message.contains("simulated sonic-rs error"));
+    }
+
     #[cfg(feature = "msgpack")]
     #[test]
     fn test_msgpack_codec_roundtrip() {
@@ -764,4 +1598,114 @@ mod tests {
         assert_eq!(msg, decoded);
         assert_eq!(codec.content_type(), "application/msgpack");
     }
+
+    #[cfg(feature = "msgpack")]
+    fn msgpack_frame(msg: &TestMessage) -> Vec<u8> {
+        let body = MsgPackCodec::new().encode(msg).unwrap();
+        let mut frame = (body.len() as u32).to_be_bytes().to_vec();
+        frame.extend_from_slice(&body);
+        frame
+    }
+
+    #[cfg(feature = "msgpack")]
+    #[test]
+    fn test_streaming_msgpack_decoder_byte_by_byte() {
+        let msg = TestMessage {
+            id: 5,
+            method: "msgpack/stream".into(),
+            params: Some(serde_json::json!({"chunk": true})),
+        };
+        let frame = msgpack_frame(&msg);
+
+        let mut decoder = StreamingMsgPackDecoder::new();
+        for byte in &frame[..frame.len() - 1] {
+            decoder.feed(core::slice::from_ref(byte));
+            assert!(decoder.try_decode::<TestMessage>().unwrap().is_none());
+        }
+
+        decoder.feed(&frame[frame.len() - 1..]);
+        let decoded: TestMessage = decoder.try_decode().unwrap().unwrap();
+        assert_eq!(decoded, msg);
+        assert!(decoder.is_empty());
+    }
+
+    #[cfg(feature = "msgpack")]
+    #[test]
+    fn test_streaming_msgpack_decoder_multiple_frames() {
+        let messages: Vec<TestMessage> = (1..=3)
+            .map(|id| TestMessage {
+                id,
+                method: alloc::format!("method/{id}"),
+                params: None,
+            })
+            .collect();
+
+        let mut bytes: Vec<u8> = messages.iter().flat_map(msgpack_frame).collect();
+        // Trailing partial header for a frame that has not arrived yet.
+        bytes.extend_from_slice(&[0, 0]);
+
+        let mut decoder = StreamingMsgPackDecoder::new();
+        decoder.feed(&bytes);
+
+        for expected in &messages {
+            let decoded: TestMessage = decoder.try_decode().unwrap().unwrap();
+            assert_eq!(&decoded, expected);
+        }
+        assert!(decoder.try_decode::<TestMessage>().unwrap().is_none());
+        assert_eq!(decoder.len(), 2);
+    }
+
+    #[cfg(feature = "msgpack")]
+    #[test]
+    fn test_streaming_msgpack_decoder_rejects_bad_frames() {
+        let mut decoder = StreamingMsgPackDecoder::new();
+
+        // A malformed body is consumed; the following frame still decodes.
+        let msg = TestMessage {
+            id: 9,
+            method: "after/garbage".into(),
+            params: None,
+        };
+        decoder.feed(&[0, 0, 0, 1, 0xc1]);
+        decoder.feed(&msgpack_frame(&msg));
+        assert!(decoder.try_decode::<TestMessage>().is_err());
+        assert_eq!(decoder.try_decode::<TestMessage>().unwrap().unwrap(), msg);
+
+        // An oversized length header is rejected before the body is buffered.
+        let mut decoder = StreamingMsgPackDecoder::with_max_size(64);
+        decoder.feed(&1024u32.to_be_bytes());
+        let err = decoder.try_decode::<TestMessage>().unwrap_err();
+        assert!(
+            err.message
+                .contains("exceeds maximum buffer size of 64 bytes")
+        );
+        assert!(decoder.is_empty());
+
+        // Feeding past the buffer limit surfaces an overflow error once.
+        decoder.feed(&[0u8; 65]);
+        assert!(decoder.try_decode::<TestMessage>().is_err());
+        assert!(decoder.try_decode::<TestMessage>().unwrap().is_none());
+    }
+
+    #[cfg(feature = "cbor")]
+    #[test]
+    fn test_cbor_codec_roundtrip() {
+        let codec = CborCodec::new();
+        let msg = TestMessage {
+            id: 88,
+            method: "cbor/test".into(),
+            params: None,
+        };
+
+        let encoded = codec.encode(&msg).unwrap();
+        let decoded: TestMessage = codec.decode(&encoded).unwrap();
+
+        assert_eq!(msg, decoded);
+        assert_eq!(codec.content_type(), "application/cbor");
+
+        let any = AnyCodec::from_name("cbor").unwrap();
+        assert_eq!(any.name(), "cbor");
+        assert!(AnyCodec::available_names().contains(&"cbor"));
+        assert_eq!(any.decode::<TestMessage>(&encoded).unwrap(), msg);
+    }
 }
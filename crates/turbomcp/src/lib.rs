@@ -138,14 +138,15 @@ pub use turbomcp_macros::{description, prompt, resource, server, tool};
 
 // Re-export core types
 pub use turbomcp_core::context::RequestContext;
-pub use turbomcp_core::error::{McpError, McpResult};
+pub use turbomcp_core::error::{McpError, McpErrorExt, McpResult};
 pub use turbomcp_core::handler::McpHandler;
 
 // Re-export types
 pub use turbomcp_types::{
-    IntoPromptResult, IntoResourceResult, IntoToolResult, Message, Prompt, PromptArgument,
-    PromptResult, Resource, ResourceContents, ResourceLink, ResourceResult, Role, SamplingContent,
-    SamplingContentBlock, ServerInfo, Tool, ToolInputSchema, ToolResult,
+    CreateMessageRequest, CreateMessageRequestBuilder, IntoPromptResult, IntoResourceResult,
+    IntoToolResult, Message, Prompt, PromptArgument, PromptResult, Resource, ResourceContents,
+    ResourceLink, ResourceResult, Role, SamplingContent, SamplingContentBlock, SamplingMessage,
+    ServerInfo, Tool, ToolInputSchema, ToolResult,
 };
 
 // Re-export server builder and transport
@@ -159,6 +160,9 @@ pub use turbomcp_server::McpServerExt;
 /// Builder for configuring and launching MCP servers with transports
 pub use turbomcp_server::ServerBuilder;
 
+/// Runtime catalog of registered components, from `McpHandlerExt::describe_all`
+pub use turbomcp_server::{CatalogEntry, ComponentCatalog, REQUIRED_SCOPES_META};
+
 /// Protocol version negotiation configuration
 ///
 /// Use `ProtocolConfig::multi_version()` to accept older MCP clients.
@@ -332,8 +336,9 @@ pub mod prelude {
 
     // Common protocol types
     pub use super::{
-        CallToolRequest, CallToolResult, Message, Prompt, PromptArgument, RequestContext, Resource,
-        ResourceContents, Role, ServerInfo, Tool, ToolInputSchema,
+        CallToolRequest, CallToolResult, CreateMessageRequest, Message, Prompt, PromptArgument,
+        RequestContext, Resource, ResourceContents, ResourceLink, Role, SamplingMessage,
+        ServerInfo, Tool, ToolInputSchema,
     };
 
     // Unified response types
@@ -201,6 +201,67 @@ fn tool_attributes_emit_2025_11_25_metadata() {
     assert_eq!(pr.icons.as_ref().expect("prompt icons").len(), 1);
 }
 
+#[derive(Clone)]
+struct IconServer;
+
+#[server(name = "icon-server", icon = "https://example.com/logo.png")]
+impl IconServer {
+    #[tool]
+    async fn ping(&self) -> String {
+        "pong".to_string()
+    }
+}
+
+// `#[server(icon = ...)]` lands in the `serverInfo` clients see at initialize.
+#[test]
+fn server_icon_attribute_populates_server_info() {
+    let info = IconServer.server_info();
+    let icons = info.icons.expect("server icons");
+    assert_eq!(icons.len(), 1);
+    assert_eq!(icons[0].src, "https://example.com/logo.png");
+
+    let json = serde_json::to_value(IconServer.server_info()).expect("serverInfo serializable");
+    assert_eq!(json["icons"][0]["src"], "https://example.com/logo.png");
+}
+
+#[derive(Clone)]
+struct RawRequestServer;
+
+#[server(name = "raw-request-server", version = "1.0.0")]
+impl RawRequestServer {
+    /// Echo the trace id from the request `_meta`
+    #[tool]
+    async fn trace(&self, request: CallToolRequest, _ctx: &RequestContext) -> String {
+        let trace_id = request
+            ._meta
+            .as_ref()
+            .and_then(|meta| meta.get("traceId"))
+            .and_then(|v| v.as_str())
+            .unwrap_or("none");
+        let shape = request
+            .arguments
+            .as_ref()
+            .and_then(|args| args.get("shape"))
+            .map_or_else(|| "none".to_string(), |v| v.to_string());
+        format!("{}:{trace_id}:{shape}", request.name)
+    }
+}
+
+// `#[tool]` handlers taking `CallToolRequest` see the call as sent, including
+// `_meta` and arguments with no declared schema.
+#[tokio::test]
+async fn raw_request_tool_reads_meta() {
+    let server = RawRequestServer;
+    let ctx = RequestContext::stdio().with_request_meta(serde_json::json!({ "traceId": "t-1" }));
+
+    let result = server
+        .call_tool("trace", serde_json::json!({ "shape": [1, 2] }), &ctx)
+        .await
+        .expect("raw request tool");
+    assert!(!result.is_error());
+    assert_eq!(result.first_text(), Some("trace:t-1:[1,2]"));
+}
+
 // SEP-1613: every macro-generated tool schema must declare the JSON Schema
 // 2020-12 dialect via `$schema`. Clients use this to pick the right validator.
 #[test]
@@ -277,3 +338,206 @@ async fn dangerous_uri_schemes_are_still_rejected() {
         .expect_err("vbscript: scheme must be rejected");
     assert!(err.to_string().contains("vbscript"));
 }
+
+#[derive(Clone, Default)]
+struct DryRunServer {
+    deletions: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+}
+
+#[server(name = "dry-run-server", version = "1.0.0")]
+impl DryRunServer {
+    /// Delete a branch
+    #[tool(supports_dry_run)]
+    async fn delete_branch(&self, name: String) -> String {
+        self.deletions
+            .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        format!("deleted {name}")
+    }
+
+    /// Delete a tag
+    #[tool]
+    async fn delete_tag(&self, name: String) -> String {
+        self.deletions
+            .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        format!("deleted {name}")
+    }
+}
+
+// `_meta.dry_run` validates arguments without running the tool body, and only
+// for tools that opted in with `supports_dry_run`.
+#[tokio::test]
+async fn dry_run_validates_without_executing() {
+    let server = DryRunServer::default();
+    let deletions = || server.deletions.load(std::sync::atomic::Ordering::SeqCst);
+    let dry_run = RequestContext::stdio().with_request_meta(serde_json::json!({ "dry_run": true }));
+
+    let result = server
+        .call_tool(
+            "delete_branch",
+            serde_json::json!({ "name": "main" }),
+            &dry_run,
+        )
+        .await
+        .expect("dry run");
+    assert!(!result.is_error());
+    assert!(
+        result
+            .first_text()
+            .unwrap_or_default()
+            .starts_with("Dry run")
+    );
+    assert_eq!(result.meta.as_ref().unwrap()["dry_run"], true);
+    assert_eq!(deletions(), 0);
+
+    // Validation still runs.
+    let result = server
+        .call_tool("delete_branch", serde_json::json!({}), &dry_run)
+        .await
+        .expect("validation error is a tool result");
+    assert!(result.is_error());
+    assert_eq!(deletions(), 0);
+
+    // Tools that did not opt in refuse rather than execute.
+    let result = server
+        .call_tool("delete_tag", serde_json::json!({ "name": "v1" }), &dry_run)
+        .await
+        .expect("refusal is a tool result");
+    assert!(result.is_error());
+    assert_eq!(deletions(), 0);
+
+    let result = server
+        .call_tool(
+            "delete_branch",
+            serde_json::json!({ "name": "main" }),
+            &RequestContext::stdio(),
+        )
+        .await
+        .expect("real run");
+    assert_eq!(result.first_text(), Some("deleted main"));
+    assert_eq!(deletions(), 1);
+}
+
+// #[prompt] results may embed resource contents or link to resources.
+#[derive(Clone)]
+struct EmbeddingServer;
+
+#[server(name = "embedding", version = "1.0.0")]
+impl EmbeddingServer {
+    #[resource("file:///notes.md")]
+    async fn notes(&self, _uri: String, _ctx: &RequestContext) -> McpResult<String> {
+        Ok("# Notes\n- ship it".to_string())
+    }
+
+    /// Review the project notes
+    #[prompt]
+    async fn review_notes(&self, ctx: &RequestContext) -> McpResult<PromptResult> {
+        let notes = self.read_resource("file:///notes.md", ctx).await?;
+        Ok(PromptResult::user("Review these notes:")
+            .add_resource(notes.contents[0].clone())
+            .add_content(Role::User, ResourceLink::new("file:///spec.md", "spec.md")))
+    }
+}
+
+#[tokio::test]
+async fn prompt_embeds_resource_contents() {
+    use turbomcp::__macro_support::{turbomcp_server, turbomcp_types};
+
+    let request = turbomcp_server::JsonRpcIncoming {
+        jsonrpc: "2.0".to_string(),
+        id: Some(serde_json::json!(1)),
+        method: "prompts/get".to_string(),
+        params: Some(serde_json::json!({ "name": "review_notes" })),
+    };
+    let response =
+        turbomcp_server::route_request(&EmbeddingServer, request, &RequestContext::stdio()).await;
+    let wire = turbomcp_server::serialize_response(&response).unwrap();
+
+    // Decode the way the client does.
+    let envelope: serde_json::Value = serde_json::from_str(&wire).unwrap();
+    let result: turbomcp_types::GetPromptResult =
+        serde_json::from_value(envelope["result"].clone()).unwrap();
+    assert_eq!(result.messages.len(), 3);
+
+    let turbomcp_types::Content::Resource(embedded) = &result.messages[1].content else {
+        panic!("expected embedded resource: {:?}", result.messages[1]);
+    };
+    assert_eq!(embedded.resource.uri(), "file:///notes.md");
+    assert_eq!(embedded.resource.text(), Some("# Notes\n- ship it"));
+
+    let link = result.messages[2]
+        .content
+        .as_resource_link()
+        .expect("resource link");
+    assert_eq!(link.uri, "file:///spec.md");
+}
+
+// describe_all() reports every macro-registered component with its metadata.
+#[derive(Clone)]
+struct CatalogServer;
+
+#[server(name = "catalog", version = "1.0.0")]
+impl CatalogServer {
+    /// Look up an order
+    #[tool(read_only = true, idempotent = true, tags = ["orders"])]
+    async fn get_order(&self, id: String) -> String {
+        format!("order {id}")
+    }
+
+    /// Cancel an order
+    #[tool(title = "Cancel order", destructive = true, tags = ["orders", "admin"], version = "2.0")]
+    async fn cancel_order(&self, id: String, reason: Option<String>) -> String {
+        format!("cancelled {id}: {}", reason.unwrap_or_default())
+    }
+
+    /// Summarize an order
+    #[prompt]
+    async fn summarize_order(&self, id: String, _ctx: &RequestContext) -> McpResult<PromptResult> {
+        Ok(PromptResult::user(format!("Summarize order {id}")))
+    }
+}
+
+#[test]
+fn describe_all_catalogs_annotated_tools() {
+    let catalog = CatalogServer.describe_all();
+
+    let names: Vec<_> = catalog
+        .tools
+        .iter()
+        .map(|entry| entry.definition.name.as_str())
+        .collect();
+    assert_eq!(names, vec!["cancel_order", "get_order"]);
+    assert_eq!(catalog.prompts.len(), 1);
+    assert!(catalog.resources.is_empty());
+
+    let cancel = catalog.tool("cancel_order").expect("cancel_order");
+    assert_eq!(cancel.tags, vec!["orders", "admin"]);
+    assert_eq!(cancel.version.as_deref(), Some("2.0"));
+    assert!(cancel.required_scopes.is_empty());
+
+    // The catalog JSON matches what tools/list advertises, plus metadata.
+    let json = serde_json::to_value(&catalog).unwrap();
+    let listed = serde_json::to_value(CatalogServer.list_tools()).unwrap();
+    for tool in json["tools"].as_array().unwrap() {
+        let advertised = listed
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|t| t["name"] == tool["name"])
+            .expect("catalog tool is listed");
+        for (key, value) in advertised.as_object().unwrap() {
+            assert_eq!(&tool[key], value, "{key}");
+        }
+    }
+    assert_eq!(json["tools"][0]["title"], "Cancel order");
+    assert_eq!(json["tools"][0]["description"], "Cancel an order");
+    assert_eq!(json["tools"][0]["annotations"]["destructiveHint"], true);
+    assert_eq!(
+        json["tools"][0]["inputSchema"]["required"],
+        serde_json::json!(["id"])
+    );
+    assert_eq!(json["tools"][1]["annotations"]["readOnlyHint"], true);
+    assert_eq!(json["tools"][1]["annotations"]["idempotentHint"], true);
+    assert_eq!(json["tools"][1]["tags"], serde_json::json!(["orders"]));
+    assert_eq!(json["prompts"][0]["name"], "summarize_order");
+    assert_eq!(json["prompts"][0]["arguments"][0]["name"], "id");
+}
@@ -0,0 +1,102 @@
+//! End-to-end check that `NotificationBroadcaster` reaches WebSocket clients.
+
+#![cfg(all(feature = "websocket", feature = "full-client"))]
+
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+use tokio::sync::mpsc;
+use turbomcp::prelude::*;
+use turbomcp_client::handlers::{CustomNotificationHandler, HandlerResult};
+use turbomcp_client::{Client, WebSocketBidirectionalConfig, WebSocketBidirectionalTransport};
+use turbomcp_server::NotificationBroadcaster;
+
+#[derive(Clone)]
+struct JobServer;
+
+#[server(name = "job-server", version = "1.0.0")]
+impl JobServer {
+    #[tool]
+    async fn status(&self) -> String {
+        "idle".to_string()
+    }
+}
+
+/// Forwards every custom notification to the test.
+#[derive(Debug)]
+struct ForwardingHandler(mpsc::UnboundedSender<(String, Option<serde_json::Value>)>);
+
+impl CustomNotificationHandler for ForwardingHandler {
+    fn handle_custom_notification(
+        &self,
+        method: String,
+        params: Option<serde_json::Value>,
+    ) -> Pin<Box<dyn Future<Output = HandlerResult<()>> + Send + '_>> {
+        let _ = self.0.send((method, params));
+        Box::pin(async { Ok(()) })
+    }
+}
+
+#[tokio::test]
+async fn broadcast_reaches_websocket_client_handler() {
+    let addr = {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        listener.local_addr().unwrap()
+    };
+    let broadcaster = NotificationBroadcaster::new();
+    let config = ServerConfig::builder()
+        .notification_broadcaster(broadcaster.clone())
+        .build();
+    let server = tokio::spawn(
+        JobServer
+            .builder()
+            .transport(Transport::websocket(addr.to_string()))
+            .with_config(config)
+            .serve(),
+    );
+
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let mut connected = None;
+    for _ in 0..50 {
+        let transport = WebSocketBidirectionalTransport::new(WebSocketBidirectionalConfig::client(
+            format!("ws://{addr}/ws"),
+        ))
+        .await
+        .unwrap();
+        let client = Client::new(transport);
+        client.set_custom_notification_handler(std::sync::Arc::new(ForwardingHandler(tx.clone())));
+        if client.initialize().await.is_ok() {
+            connected = Some(client);
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    let _client = connected.expect("server should accept WebSocket clients");
+
+    // The session is registered once the initialize response is sent.
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while broadcaster.is_empty() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("WebSocket session should register with the broadcaster");
+
+    let delivered = broadcaster
+        .broadcast(
+            "notifications/job/completed",
+            serde_json::json!({ "job": 42 }),
+        )
+        .await;
+    assert_eq!(delivered, 1);
+
+    let (method, params) = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+        .await
+        .expect("client handler should receive the broadcast")
+        .unwrap();
+    assert_eq!(method, "notifications/job/completed");
+    assert_eq!(params, Some(serde_json::json!({ "job": 42 })));
+
+    server.abort();
+}